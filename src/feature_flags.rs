@@ -56,6 +56,57 @@
 //! dfdx = { version = "...", features = ["numpy"] }
 //! ```
 //!
+//! # "safetensors"
+//!
+//! Enables loading nn from .safetensors files, such as pytorch state dicts saved with
+//! `safetensors.torch.save_file`. See [crate::nn::LoadFromSafetensors].
+//!
+//! Example:
+//! ```toml
+//! dfdx = { version = "...", features = ["safetensors"] }
+//! ```
+//!
+//! # "image"
+//!
+//! Enables loading images (e.g. PNG & JPEG files) into `(channel, height, width)` tensors, and
+//! saving them back. See [crate::tensor::TensorFromImage].
+//!
+//! Example:
+//! ```toml
+//! dfdx = { version = "...", features = ["image"] }
+//! ```
+//!
+//! # "onnx"
+//!
+//! Enables exporting feed-forward and convolutional nn to .onnx files.
+//! See [crate::nn::ExportOnnx].
+//!
+//! Example:
+//! ```toml
+//! dfdx = { version = "...", features = ["onnx"] }
+//! ```
+//!
+//! # "rayon"
+//!
+//! Parallelizes the cpu reduction kernels (e.g. [crate::tensor_ops::SumTo], [crate::tensor_ops::MaxTo],
+//! and [crate::tensor_ops::MinTo]) across threads with [rayon](https://crates.io/crates/rayon).
+//! Work is split into fixed size chunks, so results are the same regardless of the number of threads.
+//!
+//! Example:
+//! ```toml
+//! dfdx = { version = "...", features = ["rayon"] }
+//! ```
+//!
+//! # "multi-gpu"
+//!
+//! Enables the `cuda` feature and the tests that run [crate::nn::DataParallel] across
+//! multiple `Cuda` devices. Requires at least two gpus.
+//!
+//! Example:
+//! ```toml
+//! dfdx = { version = "...", features = ["multi-gpu"] }
+//! ```
+//!
 //! # "nightly"
 //!
 //! Enables using all features that currently require the nightly rust compiler.
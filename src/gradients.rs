@@ -1,12 +1,15 @@
 //! Implementations of [GradientTape] and generic Nd array containers via [Gradients].
 #![allow(clippy::type_complexity)]
 
-use std::collections::HashMap;
-use std::{boxed::Box, vec::Vec};
+use std::collections::{HashMap, HashSet};
+use std::{boxed::Box, rc::Rc, string::String, vec::Vec};
 
-use crate::shapes::{Shape, Unit};
+use crate::nn::tensor_collection::{
+    RecursiveWalker, TensorCollection, TensorOptions, TensorVisitor, ViewTensorRef,
+};
+use crate::shapes::{Dtype, Shape, Unit};
 use crate::tensor::{
-    storage_traits::{AllocGrad, DeviceStorage},
+    storage_traits::{AllocGrad, DeviceStorage, SquaredNormStorage, ZeroFillStorage},
     Tensor,
 };
 use crate::unique_id::{unique_id, UniqueId};
@@ -22,12 +25,16 @@ use crate::unique_id::{unique_id, UniqueId};
 #[derive(Clone, Debug)]
 pub struct Gradients<E: Unit, D: DeviceStorage> {
     gradient_by_id: HashMap<UniqueId, D::Vec<E>>,
+    /// The tensors whose gradient was backpropagated by the op that produced them,
+    /// i.e. everything but the leaves of the graph.
+    intermediates: HashSet<UniqueId>,
 }
 
 impl<E: Unit, D: DeviceStorage> Default for Gradients<E, D> {
     fn default() -> Self {
         Self {
             gradient_by_id: Default::default(),
+            intermediates: Default::default(),
         }
     }
 }
@@ -57,6 +64,11 @@ impl<E: Unit, D: DeviceStorage> Gradients<E, D> {
         self.gradient_by_id.remove_entry(&t.id).map(|(_, v)| v)
     }
 
+    /// Returns whether there is data associated with `t`.
+    pub(crate) fn contains<S: Shape, T>(&self, t: &Tensor<S, E, D, T>) -> bool {
+        self.gradient_by_id.contains_key(&t.id)
+    }
+
     /// Returns a mutable reference to the data associated with `t`.
     ///
     /// **Panics** if data associated with `t` is not found. This indicates an unrecoverable bug.
@@ -64,13 +76,23 @@ impl<E: Unit, D: DeviceStorage> Gradients<E, D> {
         self.gradient_by_id.get_mut(&t.id).unwrap()
     }
 
-    /// Returns a mutable reference to the data associated with `t`.
+    /// Returns a reference to the gradient of `t`, in order to backprop it. This marks
+    /// `t` as an intermediate tensor (see [Gradients::drop_intermediates]).
     ///
     /// **Panics** if data associated with `t` is not found. This indicates an unrecoverable bug.
     pub(crate) fn get_ref<S: Shape, T>(&mut self, t: &Tensor<S, E, D, T>) -> &D::Vec<E> {
+        self.intermediates.insert(t.id);
         self.gradient_by_id.get(&t.id).unwrap()
     }
 
+    /// Drops the gradients of every tensor that was backpropagated through, keeping only
+    /// the gradients of the leaves of the graph (e.g. parameters and inputs).
+    pub(crate) fn drop_intermediates(&mut self) {
+        for id in self.intermediates.drain() {
+            self.gradient_by_id.remove(&id);
+        }
+    }
+
     /// Clones the gradient and transforms it into a tensor.
     ///
     /// # Panics
@@ -84,10 +106,31 @@ impl<E: Unit, D: DeviceStorage> Gradients<E, D> {
             shape: t.shape,
             strides: t.strides,
             device: t.device.clone(),
+            param_opts: Default::default(),
             tape: Default::default(),
         }
     }
 
+    /// Returns the gradient associated with `id`, or `None` if there isn't one. Unlike
+    /// [Gradients::get], this doesn't need the original tensor.
+    ///
+    /// ```rust
+    /// # use dfdx::{prelude::*, unique_id::HasUniqueId};
+    /// # let dev: Cpu = Default::default();
+    /// let x = dev.tensor([1.0f32, 2.0, 3.0]);
+    /// let grads = x.trace().sum().backward();
+    /// assert_eq!(grads.get_by_id(*x.id()), Some(&vec![1.0; 3]));
+    /// ```
+    pub fn get_by_id(&self, id: UniqueId) -> Option<&D::Vec<E>> {
+        self.gradient_by_id.get(&id)
+    }
+
+    /// Iterates over every `(id, gradient)` pair, in no particular order. This includes
+    /// the gradients of intermediate tensors, not just of the parameters.
+    pub fn iter(&self) -> impl Iterator<Item = (UniqueId, &D::Vec<E>)> {
+        self.gradient_by_id.iter().map(|(id, grad)| (*id, grad))
+    }
+
     /// Borrows a pair of a gradients `(&mut L, &R)`.
     /// `l` is the gradient to update, and `r` is the gradient to backprop.
     ///
@@ -105,6 +148,24 @@ impl<E: Unit, D: DeviceStorage> Gradients<E, D> {
         (l_ref, r_ref)
     }
 
+    /// Borrows a triplet of gradients `(&mut L, &R1, &R2)`.
+    pub(crate) fn mut_and_refs<L: Shape, R1: Shape, R2: Shape>(
+        &mut self,
+        l: &Tensor<L, E, D>,
+        r1: &Tensor<R1, E, D>,
+        r2: &Tensor<R2, E, D>,
+    ) -> (&mut D::Vec<E>, &D::Vec<E>, &D::Vec<E>) {
+        assert_ne!(l.id, r1.id);
+        assert_ne!(l.id, r2.id);
+        let l_ptr = self.get_mut(l) as *mut _;
+        let r1_ptr = self.get_ref(r1) as *const _;
+        let r2_ptr = self.get_ref(r2) as *const _;
+        let l_ref = unsafe { &mut *l_ptr };
+        let r1_ref = unsafe { &*r1_ptr };
+        let r2_ref = unsafe { &*r2_ptr };
+        (l_ref, r1_ref, r2_ref)
+    }
+
     /// Borrows a triplet of gradients `(&mut L1, &mut L2, &R)`.
     pub(crate) fn muts_and_ref<L1: Shape, L2: Shape, R: Shape>(
         &mut self,
@@ -124,6 +185,31 @@ impl<E: Unit, D: DeviceStorage> Gradients<E, D> {
         (l1_ref, l2_ref, r_ref)
     }
 
+    /// Borrows a quadruplet of gradients `(&mut L1, &mut L2, &mut L3, &R)`.
+    pub(crate) fn three_muts_and_ref<L1: Shape, L2: Shape, L3: Shape, R: Shape>(
+        &mut self,
+        l1: &Tensor<L1, E, D>,
+        l2: &Tensor<L2, E, D>,
+        l3: &Tensor<L3, E, D>,
+        r: &Tensor<R, E, D>,
+    ) -> (&mut D::Vec<E>, &mut D::Vec<E>, &mut D::Vec<E>, &D::Vec<E>) {
+        let ids = [l1.id, l2.id, l3.id, r.id];
+        for i in 0..ids.len() {
+            for j in (i + 1)..ids.len() {
+                assert_ne!(ids[i], ids[j]);
+            }
+        }
+        let l1_ptr = self.get_mut(l1) as *mut _;
+        let l2_ptr = self.get_mut(l2) as *mut _;
+        let l3_ptr = self.get_mut(l3) as *mut _;
+        let r_ptr = self.get_ref(r) as *const _;
+        let l1_ref = unsafe { &mut *l1_ptr };
+        let l2_ref = unsafe { &mut *l2_ptr };
+        let l3_ref = unsafe { &mut *l3_ptr };
+        let r_ref = unsafe { &*r_ptr };
+        (l1_ref, l2_ref, l3_ref, r_ref)
+    }
+
     #[inline]
     pub(crate) fn many_and_ref<L: Shape, R: Shape>(
         &mut self,
@@ -149,6 +235,91 @@ impl<E: Unit, D: DeviceStorage> Gradients<E, D> {
     }
 }
 
+impl<E: Unit, D: ZeroFillStorage<E>> Gradients<E, D> {
+    /// Sets all stored gradients to zero, keeping their allocations. Use this
+    /// to reuse the same [Gradients] with [crate::tensor_ops::Backward::backward_into].
+    pub fn zero_grad(&mut self, device: &D) {
+        self.try_zero_grad(device).unwrap()
+    }
+
+    /// Fallible version of [Gradients::zero_grad]
+    pub fn try_zero_grad(&mut self, device: &D) -> Result<(), D::Err> {
+        for grad in self.gradient_by_id.values_mut() {
+            device.try_fill_with_zeros(grad)?;
+        }
+        Ok(())
+    }
+}
+
+/// Computes the global L2 norm `sqrt(sum(g^2))` of the gradients of `model`'s parameters,
+/// without modifying them. This is meant for monitoring training, e.g. logging the
+/// gradient norm each step.
+///
+/// Like the optimizers, only the tensors of `model` that are updated with gradients
+/// are included. Parameters without a gradient count as zero.
+///
+/// This takes `model` as well as `grads` because [Gradients] can't tell parameters apart
+/// from other tensors: after [crate::tensor_ops::Backward::backward] it also holds the
+/// gradients of every intermediate tensor, and of inputs that were traced (e.g. with
+/// `x.traced()`), which would both inflate the norm. Walking `model` picks out the
+/// same tensors that an optimizer would update.
+///
+/// Each buffer is reduced on its device, so for `Cuda` only one number per buffer is
+/// copied back to the host.
+///
+/// ```rust
+/// # use dfdx::{prelude::*, gradients::grad_global_norm};
+/// # let dev: Cpu = Default::default();
+/// let x = dev.tensor([3.0f32, 4.0]);
+/// let grads = x.trace().square().sum().backward();
+/// // the gradient of `x` is `2 * x`
+/// assert_eq!(grad_global_norm(&x, &grads), 10.0);
+/// ```
+pub fn grad_global_norm<E: Dtype, D: SquaredNormStorage<E>, M: TensorCollection<E, D>>(
+    model: &M,
+    grads: &Gradients<E, D>,
+) -> f64 {
+    try_grad_global_norm(model, grads).unwrap()
+}
+
+/// Fallible version of [grad_global_norm]
+pub fn try_grad_global_norm<E: Dtype, D: SquaredNormStorage<E>, M: TensorCollection<E, D>>(
+    model: &M,
+    grads: &Gradients<E, D>,
+) -> Result<f64, D::Err> {
+    let mut op = SquaredNormOp { grads, sum_sq: 0.0 };
+    M::iter_tensors(&mut RecursiveWalker {
+        m: model,
+        f: &mut op,
+        path: &mut Vec::new(),
+    })?;
+    Ok(op.sum_sq.sqrt())
+}
+
+struct SquaredNormOp<'a, E: Unit, D: DeviceStorage> {
+    grads: &'a Gradients<E, D>,
+    sum_sq: f64,
+}
+
+impl<'a, E: Dtype, D: SquaredNormStorage<E>> TensorVisitor<E, D> for SquaredNormOp<'a, E, D> {
+    type Viewer = ViewTensorRef;
+    type Err = D::Err;
+
+    fn visit<S: Shape>(
+        &mut self,
+        _: String,
+        opts: TensorOptions<S, E, D>,
+        t: &Tensor<S, E, D>,
+    ) -> Result<(), D::Err> {
+        if opts.do_gradient_update {
+            if let Some(grad) = self.grads.get_by_id(t.id) {
+                self.sum_sq += t.device.try_squared_norm(grad)?;
+            }
+        }
+        Ok(())
+    }
+}
+
 /// Contains a [Gradients] and list of backward operations.
 pub struct OwnedTape<E: Unit, D: DeviceStorage> {
     /// A list of (Time, BackwardOp) pairs. The Time is used to ensure operations
@@ -166,6 +337,51 @@ impl<E: Unit, D: DeviceStorage> Default for OwnedTape<E, D> {
     }
 }
 
+mod private {
+    /// Copies a tape so that several tensors can record onto it. The copies share the
+    /// reusable backward operations, so merging them back together only runs those
+    /// once, but each copy gets its own zeroed gradient buffers.
+    pub trait DuplicateTape<E: crate::shapes::Unit, D: crate::tensor::DeviceStorage>:
+        Sized
+    {
+        fn try_duplicate(&self, device: &D) -> Result<Self, D::Err>;
+    }
+}
+
+pub(crate) use private::DuplicateTape;
+
+impl<E: Unit, D: DeviceStorage> DuplicateTape<E, D> for OwnedTape<E, D> {
+    fn try_duplicate(&self, device: &D) -> Result<Self, D::Err> {
+        let mut gradients: Gradients<E, D> = Default::default();
+        for (id, grad) in self.gradients.gradient_by_id.iter() {
+            gradients
+                .gradient_by_id
+                .insert(*id, device.try_alloc_grad(grad)?);
+        }
+        let operations = self
+            .operations
+            .iter()
+            .map(|(id, op)| {
+                let op = match op {
+                    BackwardOp::Reusable(op) => BackwardOp::Reusable(Rc::clone(op)),
+                    _ => BackwardOp::Missing,
+                };
+                (*id, op)
+            })
+            .collect();
+        Ok(Self {
+            operations,
+            gradients,
+        })
+    }
+}
+
+impl<E: Unit, D: DeviceStorage> DuplicateTape<E, D> for NoneTape {
+    fn try_duplicate(&self, _: &D) -> Result<Self, D::Err> {
+        Ok(NoneTape)
+    }
+}
+
 impl<E: Unit, D: DeviceStorage> std::fmt::Debug for OwnedTape<E, D> {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         f.debug_struct("OwnedTape")
@@ -178,32 +394,75 @@ impl<E: Unit, D: DeviceStorage> std::fmt::Debug for OwnedTape<E, D> {
 impl<E: Unit, D: DeviceStorage> OwnedTape<E, D> {
     /// Compute the [Gradients]! This just runs all the operations on a new [Gradients] struct.
     ///
-    /// Note that this method takes ownership of self, so it can't be called twice!
-    pub(crate) fn execute(mut self) -> Result<Gradients<E, D>, D::Err> {
+    /// Note that this method takes ownership of self, so it can't be called twice! To run the same
+    /// operations more than once, clone the tape first with [Tensor::retain_graph].
+    pub(crate) fn execute(self) -> Result<Gradients<E, D>, D::Err> {
+        self.run(Default::default())
+    }
+
+    /// Same as [OwnedTape::execute], but gradients are added onto the values already in `grads`.
+    /// Only the gradients of the leaves are kept afterwards, so that accumulating over many
+    /// batches doesn't keep the buffers of every batch's intermediate tensors around.
+    pub(crate) fn execute_into(self, grads: Gradients<E, D>) -> Result<Gradients<E, D>, D::Err> {
+        let mut gradients = self.run(grads)?;
+        gradients.drop_intermediates();
+        Ok(gradients)
+    }
+
+    fn run(mut self, grads: Gradients<E, D>) -> Result<Gradients<E, D>, D::Err> {
+        self.gradients.gradient_by_id.extend(grads.gradient_by_id);
         // We must ensure that the operations are sorted in execution time order.
         // Otherwise an backward operation may not be executed in the right order
         // if multiple tapes were merged together.
-        self.operations.sort_by_key(|(k, _)| *k);
+        // Duplicated tapes share operations, which must only run once if those tapes
+        // are merged back together. The original operation is sorted before the
+        // placeholders of duplicated tapes, so it is the one that's kept.
+        self.operations
+            .sort_by_key(|(k, op)| (*k, matches!(op, BackwardOp::Missing)));
+        self.operations.dedup_by_key(|(k, _)| *k);
         for (_, operation) in self.operations.drain(..).rev() {
-            (operation)(&mut self.gradients)?;
+            match operation {
+                BackwardOp::Once(op) => (op)(&mut self.gradients)?,
+                BackwardOp::Reusable(op) => (op)(&mut self.gradients)?,
+                BackwardOp::Missing => panic!(
+                    "A duplicated tape (e.g. from `Tensor::retain_graph`) can't run operations \
+                    recorded with `Tape::add_backward_op`, use `Tape::add_reusable_backward_op` instead"
+                ),
+            }
         }
         Ok(self.gradients)
     }
 }
 
-type BackwardOp<E, D, Err> = Box<dyn FnOnce(&mut Gradients<E, D>) -> Result<(), Err>>;
+enum BackwardOp<E: Unit, D: DeviceStorage, Err> {
+    /// Recorded with [Tape::add_backward_op], can only be run by the tape that recorded it.
+    Once(Box<dyn FnOnce(&mut Gradients<E, D>) -> Result<(), Err>>),
+    /// Recorded with [Tape::add_reusable_backward_op], shared by duplicated tapes.
+    Reusable(Rc<dyn Fn(&mut Gradients<E, D>) -> Result<(), Err>>),
+    /// The place of a [BackwardOp::Once] in a duplicated tape.
+    Missing,
+}
 
 /// Contains nothing. When [Tape::add_backward_op] is called, this struct does nothing.
 #[derive(Default, Debug, Clone, Copy)]
 pub struct NoneTape;
 
 /// Something that can add a gradient operation to [GradientTape].
-pub trait Tape<E: Unit, D: DeviceStorage>: Default + Merge<Self> + Merge<NoneTape> {
+pub trait Tape<E: Unit, D: DeviceStorage>:
+    Default + Merge<Self> + Merge<NoneTape> + DuplicateTape<E, D>
+{
     /// Whether this object currently owns the [GradientTape]. This is known at compile time.
     const OWNS_TAPE: bool;
     fn add_backward_op<F>(&mut self, operation: F)
     where
         F: 'static + FnOnce(&mut Gradients<E, D>) -> Result<(), D::Err>;
+
+    /// Same as [Tape::add_backward_op], but `operation` can be run more than once, so
+    /// that the graph can be reused by tapes duplicated with [Tensor::retain_graph].
+    /// All the operations in dfdx use this.
+    fn add_reusable_backward_op<F>(&mut self, operation: F)
+    where
+        F: 'static + Fn(&mut Gradients<E, D>) -> Result<(), D::Err>;
     fn try_alloc_grad<S: Shape>(&mut self, t: &Tensor<S, E, D>) -> Result<(), D::Err>;
 }
 
@@ -213,7 +472,15 @@ impl<E: Unit, D: DeviceStorage> Tape<E, D> for OwnedTape<E, D> {
     where
         F: 'static + FnOnce(&mut Gradients<E, D>) -> Result<(), D::Err>,
     {
-        self.operations.push((unique_id(), Box::new(operation)));
+        self.operations
+            .push((unique_id(), BackwardOp::Once(Box::new(operation))));
+    }
+    fn add_reusable_backward_op<F>(&mut self, operation: F)
+    where
+        F: 'static + Fn(&mut Gradients<E, D>) -> Result<(), D::Err>,
+    {
+        self.operations
+            .push((unique_id(), BackwardOp::Reusable(Rc::new(operation))));
     }
     fn try_alloc_grad<S: Shape>(&mut self, t: &Tensor<S, E, D>) -> Result<(), D::Err> {
         self.gradients.try_alloc_for(t)
@@ -227,6 +494,11 @@ impl<E: Unit, D: DeviceStorage> Tape<E, D> for NoneTape {
         F: 'static + FnOnce(&mut Gradients<E, D>) -> Result<(), D::Err>,
     {
     }
+    fn add_reusable_backward_op<F>(&mut self, _: F)
+    where
+        F: 'static + Fn(&mut Gradients<E, D>) -> Result<(), D::Err>,
+    {
+    }
     fn try_alloc_grad<S: Shape>(&mut self, _: &Tensor<S, E, D>) -> Result<(), D::Err> {
         Ok(())
     }
@@ -254,8 +526,157 @@ impl<E: Unit, D: DeviceStorage> Merge<OwnedTape<E, D>> for OwnedTape<E, D> {
     fn merge(mut self, mut other: Self) -> Self {
         self.gradients
             .gradient_by_id
-            .extend(other.gradients.gradient_by_id.drain());
+            .extend(other.gradients.gradient_by_id);
         self.operations.append(&mut other.operations);
         self
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::unique_id::HasUniqueId;
+    use crate::{nn::builders::*, nn::*, shapes::*, tensor::*, tensor_ops::*, tests::*};
+
+    #[test]
+    fn test_grad_global_norm() {
+        let dev: TestDevice = Default::default();
+        let model: (
+            Tensor<Rank1<2>, TestDtype, _>,
+            Tensor<Rank1<3>, TestDtype, _>,
+        ) = (dev.ones(), dev.ones());
+        let a: Tensor<_, TestDtype, _> = dev.tensor([3.0, -4.0]);
+        let b: Tensor<_, TestDtype, _> = dev.tensor([1.0, 2.0, -2.0]);
+
+        let loss = (model.0.trace() * a.clone()).sum() + (model.1.trace() * b.clone()).sum();
+        let grads = loss.backward();
+        // 9 + 16 + 1 + 4 + 4 = 34, the intermediate buffers aren't included
+        let norm = grad_global_norm(&model, &grads);
+        assert!((norm - 34.0f64.sqrt()).abs() < 1e-6, "{norm}");
+
+        // the gradients are left untouched
+        assert_eq!(grads.get(&model.0).array(), a.array());
+        assert_eq!(grads.get(&model.1).array(), b.array());
+
+        // params without gradients are skipped
+        let grads = (model.0.trace() * a).sum().backward();
+        assert!((grad_global_norm(&model, &grads) - 5.0).abs() < 1e-6);
+
+        let empty: Gradients<TestDtype, TestDevice> = Default::default();
+        assert_eq!(grad_global_norm(&model, &empty), 0.0);
+    }
+
+    /// Copies a gradient buffer of `t` to the host.
+    fn grad_vec<S: Shape>(
+        t: &Tensor<S, TestDtype, TestDevice>,
+        grad: &<TestDevice as DeviceStorage>::Vec<TestDtype>,
+    ) -> Vec<TestDtype> {
+        Tensor {
+            id: unique_id(),
+            data: std::sync::Arc::new(grad.clone()),
+            shape: t.shape,
+            strides: t.strides,
+            device: t.device.clone(),
+            param_opts: Default::default(),
+            tape: NoneTape,
+        }
+        .as_vec()
+    }
+
+    #[test]
+    fn test_gradients_by_id() {
+        let dev: TestDevice = Default::default();
+        let model = dev.build_module::<Linear<3, 2>, TestDtype>();
+        let x: Tensor<Rank2<4, 3>, TestDtype, _> = dev.sample_normal();
+        let grads = model.forward(x.trace()).square().mean().backward();
+
+        let weight = grads.get_by_id(*model.weight.id()).unwrap();
+        assert_eq!(
+            grad_vec(&model.weight, weight),
+            grads.get(&model.weight).as_vec()
+        );
+        let other: Tensor<Rank0, TestDtype, _> = dev.zeros();
+        assert!(grads.get_by_id(*other.id()).is_none());
+
+        // iter yields the same buffers as get_by_id, once per id
+        let by_id: HashMap<UniqueId, &_> = grads.iter().collect();
+        assert_eq!(by_id.len(), grads.iter().count());
+        for (id, grad) in grads.iter() {
+            assert!(std::ptr::eq(grads.get_by_id(id).unwrap(), grad));
+        }
+        assert_eq!(
+            grad_vec(&model.weight, by_id[model.weight.id()]),
+            grads.get(&model.weight).as_vec()
+        );
+        assert_eq!(
+            grad_vec(&model.bias, by_id[model.bias.id()]),
+            grads.get(&model.bias).as_vec()
+        );
+        assert_eq!(grad_vec(&x, by_id[x.id()]), grads.get(&x).as_vec());
+    }
+
+    #[test]
+    fn test_duplicated_tapes_own_gradients() {
+        let dev: TestDevice = Default::default();
+        let x: Tensor<_, TestDtype, _> = dev.tensor([[1.0, -2.0, 3.0], [0.5, 1.0, -1.0]]);
+        let rows = x.trace().square().unbind::<Axis<0>>();
+        assert_eq!(rows.len(), 2);
+        let mut rows = rows.into_iter();
+        let (r0, r1) = (rows.next().unwrap(), rows.next().unwrap());
+
+        // each output's backward pass starts from zeroed buffers
+        let g0 = r0.retain_graph().sum().backward();
+        let g1 = (r1.retain_graph() * 3.0).sum().backward();
+        assert_close(&g0.get(&x).array(), &[[2.0, -4.0, 6.0], [0.0, 0.0, 0.0]]);
+        assert_close(&g1.get(&x).array(), &[[0.0, 0.0, 0.0], [3.0, 6.0, -6.0]]);
+
+        // and merging them back together runs the shared ops once
+        let g = (r0.sum() + (r1 * 3.0).sum()).backward();
+        assert_close(&g.get(&x).array(), &[[2.0, -4.0, 6.0], [3.0, 6.0, -6.0]]);
+    }
+
+    #[test]
+    fn test_backward_op_moves_captures() {
+        let dev: TestDevice = Default::default();
+        let x: Tensor<Rank1<2>, TestDtype, _> = dev.tensor([1.0, 2.0]);
+        let y: Tensor<Rank1<3>, TestDtype, _> = dev.zeros();
+        let (loss, mut tape) = x.trace().sum().split_tape();
+        let moved = y.clone();
+        tape.add_backward_op(move |grads| {
+            let moved = moved;
+            grads.try_alloc_for(&moved)
+        });
+        let grads = loss.put_tape(tape).backward();
+        assert_eq!(grads.get(&x).array(), [1.0; 2]);
+        assert_eq!(grads.get(&y).array(), [0.0; 3]);
+    }
+
+    #[test]
+    #[should_panic = "can't run operations recorded with `Tape::add_backward_op`"]
+    fn test_retained_tape_with_backward_op() {
+        let dev: TestDevice = Default::default();
+        let x: Tensor<Rank1<2>, TestDtype, _> = dev.tensor([1.0, 2.0]);
+        let (x, mut tape) = x.trace().split_tape();
+        let moved = x.clone();
+        tape.add_backward_op(move |grads| grads.try_alloc_for(&moved));
+        let x = x.put_tape(tape);
+        let _ = x.retain_graph().sum().backward();
+    }
+
+    #[test]
+    fn test_retain_graph_two_outputs() {
+        let dev: TestDevice = Default::default();
+        let x: Tensor<_, TestDtype, _> = dev.tensor([1.0, -2.0, 3.0]);
+        let h = x.trace().square();
+
+        // both outputs reuse the forward pass of `h`
+        let g1 = h.retain_graph().sum().backward();
+        let g2 = h.retain_graph().square().sum().backward();
+        assert_close(&g1.get(&x).array(), &[2.0, -4.0, 6.0]);
+        assert_close(&g2.get(&x).array(), &[4.0, -32.0, 108.0]);
+
+        // merging the retained tapes back together runs the shared ops once
+        let g = (h.retain_graph().sum() + h.square().sum()).backward();
+        assert_close(&g.get(&x).array(), &[6.0, -36.0, 114.0]);
+    }
+}
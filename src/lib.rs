@@ -99,7 +99,7 @@
 
 #![no_std]
 #![allow(incomplete_features)]
-#![cfg_attr(feature = "nightly", feature(generic_const_exprs))]
+#![cfg_attr(feature = "nightly", feature(generic_const_exprs, portable_simd))]
 
 extern crate alloc;
 extern crate no_std_compat as std;
@@ -1,6 +1,11 @@
 //! Standard loss functions such as [mse_loss()], [cross_entropy_with_logits_loss()], and more.
 
-use crate::{gradients::Tape, shapes::*, tensor::Tensor, tensor_ops::*};
+use crate::{
+    gradients::{Merge, Tape},
+    shapes::*,
+    tensor::Tensor,
+    tensor_ops::*,
+};
 
 /// [Mean Squared Error](https://en.wikipedia.org/wiki/Mean_squared_error).
 /// This computes `(pred - targ).square().mean()`.
@@ -59,12 +64,15 @@ pub fn huber_loss<S: Shape, E: Dtype, D: Device<E>, T: Tape<E, D>>(
 
 /// Smooth l1 loss (closely related to [Huber Loss](https://en.wikipedia.org/wiki/Huber_loss))
 /// uses absolute error when the error is higher than `beta`, and squared error when the
-/// error is lower than `beta`.
+/// error is lower than `beta`. This matches pytorch's `SmoothL1Loss` with mean reduction.
 ///
 /// It computes:
 /// 1. if `|x - y| < beta`: `0.5 * (x - y)^2 / beta`
 /// 2. otherwise: `|x - y| - 0.5 * beta`
 ///
+/// Both the loss and its gradient are continuous at `|x - y| == beta`. As `beta`
+/// goes to 0 this approaches [mae_loss()].
+///
 /// # Example
 /// ```rust
 /// # use dfdx::{prelude::*};
@@ -76,9 +84,9 @@ pub fn huber_loss<S: Shape, E: Dtype, D: Device<E>, T: Tape<E, D>>(
 pub fn smooth_l1_loss<S: Shape, E: Dtype, D: Device<E>, T: Tape<E, D>>(
     pred: Tensor<S, E, D, T>,
     targ: Tensor<S, E, D>,
-    delta: E,
+    beta: E,
 ) -> Tensor<Rank0, E, D, T> {
-    huber_loss(pred, targ, delta) / delta
+    huber_loss(pred, targ, beta) / beta
 }
 
 /// [Cross entropy loss](https://en.wikipedia.org/wiki/Cross_entropy#Cross-entropy_loss_function_and_logistic_regression).
@@ -111,6 +119,32 @@ where
     (logits.log_softmax::<Ax>() * target_probs).mean().negate() * last_axis_numel
 }
 
+/// [cross_entropy_with_logits_loss()] with label smoothing. The target is mixed with a uniform
+/// distribution before computing the loss: `target_probs * (1 - label_smoothing) + label_smoothing / num_classes`.
+///
+/// **Pytorch equivalent**: `F.cross_entropy(logits, target_probs, label_smoothing=label_smoothing)`
+///
+/// # Example
+/// ```rust
+/// # use dfdx::{prelude::*};
+/// # let dev: Cpu = Default::default();
+/// let logits = dev.tensor([-1.0, -0.5]);
+/// let target_probs = dev.tensor([1.0, 0.0]);
+/// let loss = smoothed_cross_entropy_with_logits_loss(logits.traced(), target_probs, 0.1);
+/// ```
+pub fn smoothed_cross_entropy_with_logits_loss<Ax: Axes, S, E: Dtype, D: Device<E>, T: Tape<E, D>>(
+    logits: Tensor<S, E, D, T>,
+    target_probs: Tensor<S, E, D>,
+    label_smoothing: E,
+) -> Tensor<Rank0, E, D, T>
+where
+    S: Shape<LastAxis = Ax> + ReduceShape<Ax>,
+{
+    let num_classes = E::from_usize(<S as HasAxes<Ax>>::size(logits.shape())).unwrap();
+    let target_probs = target_probs * (E::ONE - label_smoothing) + label_smoothing / num_classes;
+    cross_entropy_with_logits_loss(logits, target_probs)
+}
+
 /// [KL Divergence loss](https://en.wikipedia.org/wiki/Kullback%E2%80%93Leibler_divergence).
 /// This computes `(target_probs * (target_probs.log() - logits.log_softmax())).sum(-1).mean()`
 ///
@@ -169,6 +203,118 @@ pub fn binary_cross_entropy_with_logits_loss<S: Shape, E: Dtype, D: Device<E>, T
     logits.bce_with_logits(target_probs).mean()
 }
 
+/// Margin ranking loss. Computes `max(0, -target * (x1 - x2) + margin).mean()`.
+///
+/// `target` should be `1` where `x1` should be ranked higher than `x2`, and `-1` where
+/// `x2` should be ranked higher. Pairs that are ranked correctly by at least `margin`
+/// contribute nothing to the loss.
+///
+/// # Example
+/// ```rust
+/// # use dfdx::{prelude::*};
+/// # let dev: Cpu = Default::default();
+/// let x1 = dev.tensor([0.5, -0.5]);
+/// let x2 = dev.tensor([0.0, 1.0]);
+/// let target = dev.tensor([1.0, -1.0]);
+/// let loss = margin_ranking_loss(x1.traced(), x2, target, 0.1);
+/// ```
+pub fn margin_ranking_loss<
+    S: Shape,
+    E: Dtype,
+    D: Device<E>,
+    T: Tape<E, D> + Merge<R>,
+    R: Default,
+>(
+    x1: Tensor<S, E, D, T>,
+    x2: Tensor<S, E, D, R>,
+    target: Tensor<S, E, D>,
+    margin: E,
+) -> Tensor<Rank0, E, D, T> {
+    (((x1 - x2) * target).negate() + margin)
+        .maximum_scalar(E::default())
+        .mean()
+}
+
+/// Cosine embedding loss. Uses the [cosine_similarity()] along the last axis of `a` and `b`
+/// to compute:
+/// 1. if `target == 1`: `1 - cos(a, b)`
+/// 2. if `target == -1`: `max(0, cos(a, b) - margin)`
+///
+/// and then takes the mean over all the entries of `target`.
+///
+/// # Example
+/// ```rust
+/// # use dfdx::{prelude::*};
+/// # let dev: Cpu = Default::default();
+/// let a = dev.tensor([[1.0, 0.0], [1.0, 1.0]]);
+/// let b = dev.tensor([[1.0, 1.0], [-1.0, 0.0]]);
+/// let target = dev.tensor([1.0, -1.0]);
+/// let loss = cosine_embedding_loss(a.traced(), b, target, 0.0);
+/// ```
+pub fn cosine_embedding_loss<
+    Ax: Axes,
+    S,
+    E: Dtype,
+    D: Device<E>,
+    T: Tape<E, D> + Merge<R>,
+    R: Tape<E, D>,
+>(
+    a: Tensor<S, E, D, T>,
+    b: Tensor<S, E, D, R>,
+    target: Tensor<S::Reduced, E, D>,
+    margin: E,
+) -> Tensor<Rank0, E, D, T>
+where
+    S: Shape<LastAxis = Ax> + ReduceShape<Ax>,
+{
+    let half = E::from_f32(0.5).unwrap();
+    let similar = (target.clone() + E::ONE) * half;
+    let dissimilar = (target.negate() + E::ONE) * half;
+    let cos = a.cosine_similarity::<Ax, R>(b, E::from_f32(1e-8).unwrap());
+    let pull = (cos.retaped::<T>().negate() + E::ONE) * similar;
+    let push = (cos - margin).maximum_scalar(E::default()) * dissimilar;
+    (pull + push).mean()
+}
+
+/// Triplet margin loss. Computes `max(0, d(anchor, positive) - d(anchor, negative) + margin).mean()`,
+/// where `d` is the `p`-[norm()] of the difference along the last axis.
+///
+/// Like pytorch, `1e-6` is added to the differences so the distance is differentiable
+/// when two inputs are equal.
+///
+/// # Example
+/// ```rust
+/// # use dfdx::{prelude::*};
+/// # let dev: Cpu = Default::default();
+/// let anchor = dev.tensor([[0.0, 0.0], [1.0, 1.0]]);
+/// let positive = dev.tensor([[0.5, 0.0], [1.0, 2.0]]);
+/// let negative = dev.tensor([[2.0, 0.0], [1.0, 1.5]]);
+/// let loss = triplet_margin_loss(anchor.traced(), positive, negative, 1.0, 2.0);
+/// ```
+pub fn triplet_margin_loss<
+    Ax: Axes,
+    S,
+    E: Dtype,
+    D: Device<E>,
+    T: Tape<E, D> + Merge<R1> + Merge<R2>,
+    R1: Default,
+    R2: Default,
+>(
+    anchor: Tensor<S, E, D, T>,
+    positive: Tensor<S, E, D, R1>,
+    negative: Tensor<S, E, D, R2>,
+    margin: E,
+    p: E,
+) -> Tensor<Rank0, E, D, T>
+where
+    S: Shape<LastAxis = Ax> + ReduceShape<Ax>,
+{
+    let eps = E::from_f32(1e-6).unwrap();
+    let d_pos = (anchor.retaped::<T>() - positive + eps).norm::<Ax>(p);
+    let d_neg = (anchor - negative + eps).norm::<Ax>(p);
+    (d_pos - d_neg + margin).maximum_scalar(E::default()).mean()
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -419,4 +565,137 @@ mod tests {
             ],
         );
     }
+
+    #[test]
+    fn test_smooth_l1_loss_regimes() {
+        let dev: TestDevice = Default::default();
+        let x: Tensor<_, TestDtype, _> = dev.zeros::<Rank1<4>>();
+        let y: Tensor<_, TestDtype, _> = dev.tensor([0.1, -0.2, 2.0, -3.0]);
+
+        // the first two are quadratic, the last two are linear
+        let loss = smooth_l1_loss(x.trace(), y, 0.5);
+        assert_close(&loss.array(), &((0.01 + 0.04 + 1.75 + 2.75) / 4.0));
+        let g = loss.backward();
+        assert_close(&g.get(&x).array(), &[-0.05, 0.1, -0.25, 0.25]);
+    }
+
+    #[test]
+    fn test_smooth_l1_loss_continuous_at_beta() {
+        let dev: TestDevice = Default::default();
+        let x: Tensor<_, TestDtype, _> = dev.tensor([0.5 - 1e-4, 0.5, 0.5 + 1e-4]);
+        let y: Tensor<_, TestDtype, _> = dev.zeros();
+
+        let losses = (x.clone().huber_error(y.clone(), 0.5) / 0.5).array();
+        for l in losses {
+            assert!((l - 0.25).abs() < 1e-3, "{l}");
+        }
+
+        let g = smooth_l1_loss(x.trace(), y, 0.5).backward();
+        for g in g.get(&x).array() {
+            assert!((g - 1.0 / 3.0).abs() < 1e-3, "{g}");
+        }
+    }
+
+    #[test]
+    fn test_margin_ranking_loss() {
+        let dev: TestDevice = Default::default();
+        let x1: Tensor<_, TestDtype, _> = dev.tensor([2.0, -1.0, 0.5, 0.0]);
+        let x2: Tensor<_, TestDtype, _> = dev.tensor([1.0, 1.0, 1.0, -0.25]);
+        let target = dev.tensor([1.0, -1.0, 1.0, -1.0]);
+
+        // the first two pairs satisfy the margin, the last two don't
+        let loss = margin_ranking_loss(x1.trace(), x2.trace(), target.clone(), 0.5);
+        assert_close(&loss.array(), &((1.0 + 0.75) / 4.0));
+        let g = loss.backward();
+        assert_close(&g.get(&x1).array(), &[0.0, 0.0, -0.25, 0.25]);
+        assert_close(&g.get(&x2).array(), &[0.0, 0.0, 0.25, -0.25]);
+
+        let loss = margin_ranking_loss(x1.trace(), x2.trace(), target.clone(), 0.0);
+        assert_close(&loss.array(), &(0.75 / 4.0));
+
+        let x1: Tensor<_, TestDtype, _> = dev.tensor([2.0, -1.0, 1.5, -1.0]);
+        let loss = margin_ranking_loss(x1.trace(), x2, target, 0.5);
+        assert_eq!(loss.array(), 0.0);
+    }
+
+    #[test]
+    fn test_cosine_embedding_loss() {
+        let dev: TestDevice = Default::default();
+        let a: Tensor<_, TestDtype, _> = dev.tensor([
+            [1.0, 2.0, 3.0],
+            [1.0, 0.0, 0.0],
+            [1.0, 0.0, 0.0],
+            [1.0, 1.0, 0.0],
+        ]);
+        let b: Tensor<_, TestDtype, _> = dev.tensor([
+            [2.0, 4.0, 6.0],
+            [0.0, 1.0, 0.0],
+            [0.0, 0.0, 2.0],
+            [2.0, 2.0, 0.0],
+        ]);
+
+        // parallel & similar, orthogonal & similar, orthogonal & dissimilar, parallel & dissimilar
+        let target = dev.tensor([1.0, 1.0, -1.0, -1.0]);
+        let loss = cosine_embedding_loss(a.trace(), b.trace(), target, 0.5);
+        assert_close(&loss.array(), &((0.0 + 1.0 + 0.0 + 0.5) / 4.0));
+
+        let g = loss.backward();
+        for v in g.get(&a).array().iter().chain(g.get(&b).array().iter()) {
+            assert!(v.iter().all(|x| x.is_finite()));
+        }
+        // the orthogonal & similar pair is pulled together
+        assert_close(&g.get(&a).array()[1], &[0.0, -0.25, 0.0]);
+        assert_close(&g.get(&b).array()[1], &[-0.25, 0.0, 0.0]);
+    }
+
+    #[test]
+    fn test_triplet_margin_loss() {
+        let dev: TestDevice = Default::default();
+        let anchor: Tensor<_, TestDtype, _> = dev.zeros::<Rank2<2, 2>>();
+
+        // the positive is closer than the negative by more than the margin
+        let positive: Tensor<_, TestDtype, _> = dev.tensor([[1.0, 0.0], [0.0, 0.5]]);
+        let negative: Tensor<_, TestDtype, _> = dev.tensor([[3.0, 0.0], [0.0, -2.0]]);
+        let loss = triplet_margin_loss(anchor.trace(), positive, negative, 1.0, 2.0);
+        assert_eq!(loss.array(), 0.0);
+
+        // the first triplet is satisfied, the second is reversed
+        let positive: Tensor<_, TestDtype, _> = dev.tensor([[1.0, 0.0], [0.0, 3.0]]);
+        let negative: Tensor<_, TestDtype, _> = dev.tensor([[3.0, 0.0], [1.0, 0.0]]);
+        let loss =
+            triplet_margin_loss(anchor.trace(), positive.trace(), negative.trace(), 1.0, 2.0);
+        assert_close_with_tolerance(&loss.array(), &1.5, 1e-4);
+
+        let g = loss.backward();
+        assert_close_with_tolerance(&g.get(&anchor).array(), &[[0.0, 0.0], [0.5, -0.5]], 1e-4);
+        assert_close_with_tolerance(&g.get(&positive).array(), &[[0.0, 0.0], [0.0, 0.5]], 1e-4);
+        assert_close_with_tolerance(&g.get(&negative).array(), &[[0.0, 0.0], [-0.5, 0.0]], 1e-4);
+    }
+
+    #[test]
+    fn test_smoothed_cross_entropy() {
+        let dev: TestDevice = Default::default();
+        let x: Tensor<_, TestDtype, _> = dev.tensor([[20.0, -20.0, -20.0], [-20.0, -20.0, 20.0]]);
+        let y: Tensor<_, TestDtype, _> = dev.tensor([[1.0, 0.0, 0.0], [0.0, 0.0, 1.0]]);
+
+        // a perfect prediction only has zero loss without smoothing
+        let loss = smoothed_cross_entropy_with_logits_loss(x.trace(), y.clone(), 0.0);
+        assert_close(&loss.array(), &0.0);
+        let loss = smoothed_cross_entropy_with_logits_loss(x.trace(), y.clone(), 0.3);
+        assert!(loss.array() > 1.0);
+
+        // the gradient is the difference between the prediction and the smoothed target
+        let g = loss.backward();
+        assert_close(
+            &g.get(&x).array(),
+            &[[0.1, -0.05, -0.05], [-0.05, -0.05, 0.1]],
+        );
+
+        let smoothed = dev.tensor([[0.8, 0.1, 0.1], [0.1, 0.1, 0.8]]);
+        let x: Tensor<_, TestDtype, _> = dev.sample_normal::<Rank2<2, 3>>();
+        assert_close(
+            &smoothed_cross_entropy_with_logits_loss(x.clone(), y, 0.3).array(),
+            &cross_entropy_with_logits_loss(x, smoothed).array(),
+        );
+    }
 }
@@ -1,4 +1,4 @@
-use crate::{shapes::Dtype, tensor::*};
+use crate::{shapes::Dtype, tensor::*, tensor_ops::TryAdd};
 
 use super::{tensor_collection::*, BuildModule, BuildOnDevice, Module, ModuleMut, ToDevice};
 
@@ -46,15 +46,10 @@ impl<T: ToDevice<D>, D> ToDevice<D> for AddInto<T> {
     }
 }
 
-macro_rules! sum {
-    ($H:tt) => { $H };
-    ($H:tt, $($T:tt),+) => { $H + sum!($($T),+) };
-}
-
 macro_rules! add_into_impls {
     ($([$Mod:tt $ModVar:tt $Inp:tt $InpVar:tt]),+) => {
         impl<
-            Out: std::ops::Add<Out, Output = Out>,
+            Out: TryAdd<Out> + HasErr<Err = A::Error>,
             Ai, $($Inp, )+
             A: Module<Ai, Output = Out>,
             $($Mod: Module<$Inp, Output = Out, Error = A::Error>, )+
@@ -68,11 +63,13 @@ macro_rules! add_into_impls {
                 let (a_i, $($InpVar, )+) = x;
                 let a_i = a.try_forward(a_i)?;
                 $(let $InpVar = $ModVar.try_forward($InpVar)?;)+
-                Ok(sum!(a_i, $($InpVar),*))
+                let out = a_i;
+                $(let out = out.try_add($InpVar)?;)+
+                Ok(out)
             }
         }
         impl<
-            Out: std::ops::Add<Out, Output = Out>,
+            Out: TryAdd<Out> + HasErr<Err = A::Error>,
             Ai, $($Inp, )+
             A: ModuleMut<Ai, Output = Out>,
             $($Mod: ModuleMut<$Inp, Output = Out, Error = A::Error>, )+
@@ -86,7 +83,9 @@ macro_rules! add_into_impls {
                 let (a_i, $($InpVar, )+) = x;
                 let a_i = a.try_forward_mut(a_i)?;
                 $(let $InpVar = $ModVar.try_forward_mut($InpVar)?;)+
-                Ok(sum!(a_i, $($InpVar),*))
+                let out = a_i;
+                $(let out = out.try_add($InpVar)?;)+
+                Ok(out)
             }
         }
     };
@@ -105,7 +104,8 @@ mod tests {
         gradients::OwnedTape,
         nn::{builders::*, DeviceBuildExt},
         shapes::*,
-        tests::{TestDevice, TestDtype},
+        tensor_ops::*,
+        tests::*,
     };
 
     type TestAddIntoCpu = AddInto<(Linear<2, 5>, Linear<3, 5>)>;
@@ -127,6 +127,30 @@ mod tests {
         ));
     }
 
+    #[test]
+    fn test_add_into_grads() {
+        let dev: TestDevice = Default::default();
+        type Model = AddInto<(Linear<2, 5>, Linear<3, 5>)>;
+        let m = dev.build_module::<Model, TestDtype>();
+        let a: Tensor<Rank1<2>, TestDtype, _> = dev.sample_normal();
+        let b: Tensor<Rank1<3>, TestDtype, _> = dev.sample_normal();
+
+        let y = m.forward((a.trace(), b.trace()));
+        let expected = m.0 .0.forward(a.clone()) + m.0 .1.forward(b.clone());
+        assert_close(&y.array(), &expected.array());
+
+        let g = y.square().mean().backward();
+        assert_ne!(g.get(&m.0 .0.weight).array(), [[0.0; 2]; 5]);
+        assert_ne!(g.get(&m.0 .0.bias).array(), [0.0; 5]);
+        assert_ne!(g.get(&m.0 .1.weight).array(), [[0.0; 3]; 5]);
+        assert_ne!(g.get(&m.0 .1.bias).array(), [0.0; 5]);
+        assert_ne!(g.get(&a).array(), [0.0; 2]);
+        assert_ne!(g.get(&b).array(), [0.0; 3]);
+
+        // both branches see the same gradient wrt their output, so the biases match
+        assert_close(&g.get(&m.0 .0.bias).array(), &g.get(&m.0 .1.bias).array());
+    }
+
     #[test]
     fn test_add_into_3() {
         let dev: TestDevice = Default::default();
@@ -0,0 +1,287 @@
+use crate::{gradients::Tape, shapes::*, tensor::*, tensor_ops::*};
+
+use super::{tensor_collection::*, BuildModule, BuildOnDevice, Module, NonMutableModule, ToDevice};
+
+use num_traits::Float;
+use rand_distr::{uniform::SampleUniform, Uniform};
+
+pub mod builder {
+    #[derive(Debug, Copy, Clone, Eq, PartialEq)]
+    pub struct Bilinear<const I1: usize, const I2: usize, const O: usize>;
+}
+
+impl<const I1: usize, const I2: usize, const O: usize, E: Dtype, D: Device<E>> BuildOnDevice<D, E>
+    for builder::Bilinear<I1, I2, O>
+where
+    Bilinear<I1, I2, O, E, D>: BuildModule<D, E>,
+{
+    type Built = Bilinear<I1, I2, O, E, D>;
+    fn try_build_on_device(device: &D) -> Result<Self::Built, <D>::Err> {
+        Self::Built::try_build(device)
+    }
+}
+
+/// A bilinear transformation of the form `x1ᵀ * weight * x2 + bias`, where `weight` is a 3d tensor
+/// with one `(I1, I2)` matrix per output, and `bias` is a vector.
+///
+/// Initializes [Self::weight] and [Self::bias] from a Uniform distribution
+/// between [-1 / sqrt(I1), 1 / sqrt(I1)].
+///
+/// **Pytorch equivalent**: `torch.nn.Bilinear(I1, I2, O)`
+///
+/// # Generics
+/// - `I1` The size of the first input.
+/// - `I2` The size of the second input.
+/// - `O` The "output" size of vectors & matrices.
+///
+/// # Examples
+/// `Bilinear<5, 3, 2>` can act on pairs of vectors with 5 and 3 elements, and results in vectors with 2 elements.
+/// ```rust
+/// # use dfdx::prelude::*;
+/// # let dev: Cpu = Default::default();
+/// type Model = Bilinear<5, 3, 2>;
+/// let model = dev.build_module::<Model, f32>();
+/// // single item forward
+/// let _: Tensor<Rank1<2>, f32, _> = model.forward((dev.zeros::<Rank1<5>>(), dev.zeros::<Rank1<3>>()));
+/// // batched forward
+/// let _: Tensor<Rank2<10, 2>, f32, _> =
+///     model.forward((dev.zeros::<Rank2<10, 5>>(), dev.zeros::<Rank2<10, 3>>()));
+/// ```
+#[derive(Debug, Clone)]
+pub struct Bilinear<const I1: usize, const I2: usize, const O: usize, E: Dtype, D: DeviceStorage> {
+    /// Weight tensor, shape (O, I1, I2)
+    pub weight: Tensor<Rank3<O, I1, I2>, E, D>,
+
+    /// Bias vector, shape (O, )
+    pub bias: Tensor<Rank1<O>, E, D>,
+}
+
+impl<const I1: usize, const I2: usize, const O: usize, E: Dtype, D: DeviceStorage> NonMutableModule
+    for Bilinear<I1, I2, O, E, D>
+{
+}
+
+impl<
+        const I1: usize,
+        const I2: usize,
+        const O: usize,
+        E: Dtype + Float + SampleUniform,
+        D: Device<E>,
+    > BuildModule<D, E> for Bilinear<I1, I2, O, E, D>
+{
+    fn try_build(device: &D) -> Result<Self, D::Err> {
+        let b: E = E::ONE / E::from_usize(I1).unwrap().sqrt();
+        let weight = device.try_sample(Uniform::new(-b, b))?;
+        let bias = device.try_sample(Uniform::new(-b, b))?;
+        Ok(Self { weight, bias })
+    }
+}
+
+impl<
+        const I1: usize,
+        const I2: usize,
+        const O: usize,
+        E: Dtype + Float + SampleUniform,
+        D: SampleTensor<E>,
+    > TensorCollection<E, D> for Bilinear<I1, I2, O, E, D>
+{
+    fn iter_tensors<V: ModuleVisitor<Self, E, D>>(visitor: &mut V) -> Result<(), V::Err> {
+        visitor.visit_tensor(
+            "weight",
+            |s| &s.weight,
+            |s| &mut s.weight,
+            TensorOptions::reset_with(|t| {
+                let b: E = E::ONE / E::from_usize(I1).unwrap().sqrt();
+                t.try_fill_with_distr(Uniform::new(-b, b))
+            }),
+        )?;
+        visitor.visit_tensor(
+            "bias",
+            |s| &s.bias,
+            |s| &mut s.bias,
+            TensorOptions::reset_with(|t| {
+                let b: E = E::ONE / E::from_usize(I1).unwrap().sqrt();
+                t.try_fill_with_distr(Uniform::new(-b, b))
+            }),
+        )
+    }
+}
+
+impl<const I1: usize, const I2: usize, const O: usize, E: Dtype, D1: Device<E>, D2: Device<E>>
+    ToDevice<D2> for Bilinear<I1, I2, O, E, D1>
+{
+    type Output = Bilinear<I1, I2, O, E, D2>;
+    fn to_device(&self, device: &D2) -> Self::Output {
+        Bilinear {
+            weight: self.weight.to_device(device),
+            bias: self.bias.to_device(device),
+        }
+    }
+}
+
+impl<const I1: usize, const I2: usize, const O: usize, E: Dtype, D: Device<E>>
+    Bilinear<I1, I2, O, E, D>
+{
+    /// [Self::weight] as a `(I1, O * I2)` matrix, so `x1` can be multiplied with it
+    #[allow(clippy::type_complexity)]
+    fn weight_by_input<T: Tape<E, D>>(
+        &self,
+    ) -> Result<Tensor<(Const<I1>, usize), E, D, T>, D::Err> {
+        self.weight
+            .retaped::<T>()
+            .try_permute::<Rank3<I1, O, I2>, Axes3<1, 0, 2>>()?
+            .try_reshape_like(&(Const::<I1>, O * I2))
+    }
+}
+
+impl<const I1: usize, const I2: usize, const O: usize, E: Dtype, D: Device<E>, T: Tape<E, D>>
+    Module<(Tensor<Rank1<I1>, E, D, T>, Tensor<Rank1<I2>, E, D, T>)> for Bilinear<I1, I2, O, E, D>
+{
+    type Output = Tensor<Rank1<O>, E, D, T>;
+    type Error = D::Err;
+
+    /// Contracts `x1` with [Self::weight] using a [matmul()], and then contracts the
+    /// resulting `(O, I2)` matrix with `x2`, so no `(O, I1, I2)` intermediate is needed.
+    fn try_forward(
+        &self,
+        (x1, x2): (Tensor<Rank1<I1>, E, D, T>, Tensor<Rank1<I2>, E, D, T>),
+    ) -> Result<Self::Output, D::Err> {
+        let w = self.weight_by_input::<T>()?;
+        let z = x1
+            .try_matmul(w)?
+            .try_reshape_like(&(Const::<O>, Const::<I2>))?;
+        let x2 = x2.try_reshape_like(&(Const::<I2>, Const::<1>))?;
+        z.try_matmul(x2)?
+            .try_reshape_like(&(Const::<O>,))?
+            .try_add(self.bias.clone())
+    }
+}
+
+impl<
+        B: Dim,
+        const I1: usize,
+        const I2: usize,
+        const O: usize,
+        E: Dtype,
+        D: Device<E>,
+        T: Tape<E, D>,
+    >
+    Module<(
+        Tensor<(B, Const<I1>), E, D, T>,
+        Tensor<(B, Const<I2>), E, D, T>,
+    )> for Bilinear<I1, I2, O, E, D>
+{
+    type Output = Tensor<(B, Const<O>), E, D, T>;
+    type Error = D::Err;
+
+    fn try_forward(
+        &self,
+        (x1, x2): (
+            Tensor<(B, Const<I1>), E, D, T>,
+            Tensor<(B, Const<I2>), E, D, T>,
+        ),
+    ) -> Result<Self::Output, D::Err> {
+        let batch = x1.shape().0;
+        let w = self.weight_by_input::<T>()?;
+        let z = x1
+            .try_matmul(w)?
+            .try_reshape_like(&(batch, Const::<O>, Const::<I2>))?;
+        // matmul only batches over a `Const` batch size, so contract with `x2` elementwise
+        let x2 = x2.try_broadcast_like::<_, Axis<1>>(z.shape())?;
+        let b = self
+            .bias
+            .retaped::<T>()
+            .try_broadcast_like(&(batch, Const))?;
+        z.try_mul(x2)?.try_sum::<_, Axis<2>>()?.try_add(b)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{nn::DeviceBuildExt, tests::*};
+
+    #[test]
+    fn test_bilinear_ondevice() {
+        let dev: TestDevice = Default::default();
+        let _: Bilinear<1, 2, 3, TestDtype, _> = BuildModule::build(&dev);
+        let _ = dev.build_module::<builder::Bilinear<1, 2, 3>, TestDtype>();
+    }
+
+    #[test]
+    fn test_bilinear_forward_1d() {
+        let dev: TestDevice = Default::default();
+        let model = dev.build_module::<builder::Bilinear<3, 2, 2>, TestDtype>();
+        let x1: Tensor<Rank1<3>, TestDtype, _> = dev.sample_normal();
+        let x2: Tensor<Rank1<2>, TestDtype, _> = dev.sample_normal();
+
+        let y = model.forward((x1.trace(), x2.trace()));
+
+        let w = model.weight.array();
+        let b = model.bias.array();
+        let (a1, a2) = (x1.array(), x2.array());
+        let mut expected = [0.0; 2];
+        for o in 0..2 {
+            expected[o] = b[o];
+            for i in 0..3 {
+                for j in 0..2 {
+                    expected[o] += a1[i] * w[o][i][j] * a2[j];
+                }
+            }
+        }
+        assert_close(&y.array(), &expected);
+
+        let g = y.sum().backward();
+        let mut expected_w = [[[0.0; 2]; 3]; 2];
+        for row in expected_w.iter_mut() {
+            for i in 0..3 {
+                for j in 0..2 {
+                    row[i][j] = a1[i] * a2[j];
+                }
+            }
+        }
+        assert_close(&g.get(&model.weight).array(), &expected_w);
+        assert_eq!(g.get(&model.bias).array(), [1.0; 2]);
+    }
+
+    #[test]
+    fn test_bilinear_forward_2d() {
+        let dev: TestDevice = Default::default();
+        let model = dev.build_module::<builder::Bilinear<3, 2, 4>, TestDtype>();
+        let x1: Tensor<Rank2<5, 3>, TestDtype, _> = dev.sample_normal();
+        let x2: Tensor<Rank2<5, 2>, TestDtype, _> = dev.sample_normal();
+
+        let y = model.forward((x1.trace(), x2.trace()));
+        assert_eq!(y.shape(), &(Const::<5>, Const::<4>));
+
+        let w = model.weight.array();
+        let b = model.bias.array();
+        let (a1, a2) = (x1.array(), x2.array());
+        let mut expected = [[0.0; 4]; 5];
+        for n in 0..5 {
+            for o in 0..4 {
+                expected[n][o] = b[o];
+                for i in 0..3 {
+                    for j in 0..2 {
+                        expected[n][o] += a1[n][i] * w[o][i][j] * a2[n][j];
+                    }
+                }
+            }
+        }
+        assert_close(&y.array(), &expected);
+
+        let g = y.sum().backward();
+        assert_eq!(g.get(&model.bias).array(), [5.0; 4]);
+        let mut expected_w = [[[0.0; 2]; 3]; 4];
+        for row in expected_w.iter_mut() {
+            for i in 0..3 {
+                for j in 0..2 {
+                    row[i][j] = (0..5).map(|n| a1[n][i] * a2[n][j]).sum();
+                }
+            }
+        }
+        assert_close(&g.get(&model.weight).array(), &expected_w);
+        assert_ne!(g.get(&x1).array(), [[0.0; 3]; 5]);
+        assert_ne!(g.get(&x2).array(), [[0.0; 2]; 5]);
+    }
+}
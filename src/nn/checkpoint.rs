@@ -0,0 +1,206 @@
+use super::{
+    tensor_collection::{
+        RecursiveWalker, TensorCollection, TensorOptions, TensorVisitor, ViewTensorRef,
+    },
+    Module,
+};
+
+use crate::{
+    gradients::{Gradients, OwnedTape, Tape},
+    shapes::*,
+    tensor::*,
+    tensor_ops::axpy::AxpyKernel,
+};
+
+use std::{string::String, vec::Vec};
+
+/// Runs `m` on `x` without recording any of its intermediate values on the tape,
+/// and instead re-runs `m` during the backward pass to compute gradients.
+///
+/// This trades an extra forward pass of `m` for not having to keep all of the
+/// activations of `m` in memory until backward is called. The gradients of `x`
+/// and of all parameters of `m` are the same as calling `m.forward(x)`.
+///
+/// `m` is cloned into the backward operation, which for modules is cheap since
+/// tensors share their storage.
+///
+/// # Randomness
+///
+/// The recomputation draws from the device's rng again, and the rng state of the
+/// original forward pass is **not** restored. So `m` must be deterministic: if it
+/// samples anything (e.g. a dropout mask), the recomputed forward pass uses different
+/// samples than the one that produced the output, and the gradients are wrong.
+/// [super::modules::Dropout] can't be checkpointed anyway, because `checkpoint` uses
+/// [Module], which dropout only implements for inputs without a tape.
+///
+/// Example:
+/// ```rust
+/// # use dfdx::prelude::*;
+/// # let dev: Cpu = Default::default();
+/// type Model = (Linear<3, 5>, ReLU, Linear<5, 2>);
+/// let model = dev.build_module::<Model, f32>();
+/// let x: Tensor<Rank1<3>, f32, _> = dev.sample_normal();
+/// let y = checkpoint(&model, x.trace());
+/// let grads = y.square().mean().backward();
+/// ```
+pub fn checkpoint<M, S: Shape, O: Shape, E: Dtype, D: AxpyKernel<E>>(
+    m: &M,
+    x: Tensor<S, E, D, OwnedTape<E, D>>,
+) -> Tensor<O, E, D, OwnedTape<E, D>>
+where
+    M: 'static + Clone + TensorCollection<E, D>,
+    M: Module<Tensor<S, E, D>, Output = Tensor<O, E, D>, Error = D::Err>,
+    M: Module<
+        Tensor<S, E, D, OwnedTape<E, D>>,
+        Output = Tensor<O, E, D, OwnedTape<E, D>>,
+        Error = D::Err,
+    >,
+{
+    try_checkpoint(m, x).unwrap()
+}
+
+/// Fallible version of [checkpoint]
+#[allow(clippy::type_complexity)]
+pub fn try_checkpoint<M, S: Shape, O: Shape, E: Dtype, D: AxpyKernel<E>>(
+    m: &M,
+    x: Tensor<S, E, D, OwnedTape<E, D>>,
+) -> Result<Tensor<O, E, D, OwnedTape<E, D>>, D::Err>
+where
+    M: 'static + Clone + TensorCollection<E, D>,
+    M: Module<Tensor<S, E, D>, Output = Tensor<O, E, D>, Error = D::Err>,
+    M: Module<
+        Tensor<S, E, D, OwnedTape<E, D>>,
+        Output = Tensor<O, E, D, OwnedTape<E, D>>,
+        Error = D::Err,
+    >,
+{
+    let (x, mut tape) = x.split_tape();
+    let out = m.try_forward(x.clone())?;
+    if out.id == x.id {
+        // `m` returned `x` itself (e.g. the identity), so there is nothing to recompute
+        return Ok(out.put_tape(tape));
+    }
+    let phantom_out = out.clone();
+    let m = m.clone();
+    tape.try_alloc_grad(&x)?;
+    tape.try_alloc_grad(&out)?;
+    tape.add_reusable_backward_op(move |grads| {
+        let grad_out = grads.get_ref(&phantom_out).clone();
+
+        // recompute the forward pass, this time recording it on a tape
+        let (recomputed, mut inner_tape) = m.try_forward(x.trace())?.split_tape();
+        inner_tape.add_reusable_backward_op(move |inner| {
+            *inner.get_mut(&recomputed) = grad_out.clone();
+            Ok(())
+        });
+        let mut inner = inner_tape.execute()?;
+
+        if let Some(grad) = inner.remove(&x) {
+            AxpyKernel::forward(&x.device, grads.get_mut(&x), E::ONE, &grad, E::ONE)?;
+        }
+        M::iter_tensors(&mut RecursiveWalker {
+            m: &m,
+            f: &mut AccumulateOp {
+                dst: grads,
+                src: &mut inner,
+            },
+            path: &mut Vec::new(),
+        })
+    });
+    Ok(out.put_tape(tape))
+}
+
+/// Adds the gradients of visited tensors in `src` into `dst`.
+struct AccumulateOp<'a, E: Unit, D: DeviceStorage> {
+    dst: &'a mut Gradients<E, D>,
+    src: &'a mut Gradients<E, D>,
+}
+
+impl<'a, E: Dtype, D: AxpyKernel<E>> TensorVisitor<E, D> for AccumulateOp<'a, E, D> {
+    type Viewer = ViewTensorRef;
+    type Err = D::Err;
+
+    fn visit<S: Shape>(
+        &mut self,
+        _: String,
+        _: TensorOptions<S, E, D>,
+        t: &Tensor<S, E, D>,
+    ) -> Result<(), Self::Err> {
+        if let Some(grad) = self.src.remove(t) {
+            let dst = self.dst.get_or_alloc_mut(t)?;
+            AxpyKernel::forward(&t.device, dst, E::ONE, &grad, E::ONE)?;
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        nn::{builders::*, DeviceBuildExt},
+        tensor_ops::*,
+        tests::*,
+    };
+
+    #[test]
+    fn test_checkpoint_matches_forward() {
+        let dev: TestDevice = Default::default();
+        type Model = (Linear<3, 5>, ReLU, Linear<5, 5>, Tanh, Linear<5, 2>);
+        let m = dev.build_module::<Model, TestDtype>();
+        let x: Tensor<Rank2<4, 3>, TestDtype, _> = dev.sample_normal();
+
+        let y = m.forward(x.trace());
+        let g = y.square().mean().backward();
+
+        let y2 = checkpoint(&m, x.trace());
+        let g2 = y2.square().mean().backward();
+
+        assert_close(&g2.get(&x).array(), &g.get(&x).array());
+        assert_close(&g2.get(&m.0.weight).array(), &g.get(&m.0.weight).array());
+        assert_close(&g2.get(&m.0.bias).array(), &g.get(&m.0.bias).array());
+        assert_close(&g2.get(&m.2.weight).array(), &g.get(&m.2.weight).array());
+        assert_close(&g2.get(&m.4.weight).array(), &g.get(&m.4.weight).array());
+        assert_close(&g2.get(&m.4.bias).array(), &g.get(&m.4.bias).array());
+    }
+
+    #[test]
+    fn test_nested_checkpoint_accumulates() {
+        let dev: TestDevice = Default::default();
+        let m = dev.build_module::<Linear<3, 3>, TestDtype>();
+        let x: Tensor<Rank1<3>, TestDtype, _> = dev.sample_normal();
+
+        let g = (m.forward(m.forward(x.trace())) + x.clone())
+            .exp()
+            .sum()
+            .backward();
+
+        let h = checkpoint(&m, x.trace());
+        let g2 = (checkpoint(&m, h) + x.clone()).exp().sum().backward();
+
+        assert_close(&g2.get(&x).array(), &g.get(&x).array());
+        assert_close(&g2.get(&m.weight).array(), &g.get(&m.weight).array());
+        assert_close(&g2.get(&m.bias).array(), &g.get(&m.bias).array());
+    }
+
+    #[derive(Default, Clone)]
+    struct Identity;
+
+    impl crate::nn::ZeroSizedModule for Identity {}
+
+    impl<S: Shape, E: Unit, D: DeviceStorage, T> Module<Tensor<S, E, D, T>> for Identity {
+        type Output = Tensor<S, E, D, T>;
+        type Error = D::Err;
+        fn try_forward(&self, x: Self::Output) -> Result<Self::Output, D::Err> {
+            Ok(x)
+        }
+    }
+
+    #[test]
+    fn test_checkpoint_identity() {
+        let dev: TestDevice = Default::default();
+        let x: Tensor<Rank1<3>, TestDtype, _> = dev.tensor([1.0, -2.0, 3.0]);
+        let g = checkpoint(&Identity, x.trace()).exp().sum().backward();
+        assert_close(&g.get(&x).array(), &x.exp().array());
+    }
+}
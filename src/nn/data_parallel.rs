@@ -0,0 +1,228 @@
+use super::tensor_collection::{
+    RecursiveWalker, TensorCollection, TensorOptions, TensorVisitor, ViewTensorRef,
+};
+
+use crate::{gradients::Gradients, shapes::*, tensor::*};
+
+use std::{string::String, vec::Vec};
+
+/// Replicates a module across multiple devices for data parallel training.
+///
+/// Each replica should be run on its own shard of the batch, then
+/// [DataParallel::all_reduce_grads()] averages the resulting [Gradients] across all replicas.
+/// Since the averaged gradients are identical, updating every replica with its own
+/// gradients (using the same optimizer settings) keeps the replicas in sync.
+///
+/// This works for any device. For example with multiple `Cuda` devices:
+/// `Cuda::try_build(ordinal, seed)` for each gpu.
+///
+/// # Limitations
+///
+/// This is a functional reference implementation, not a fast one:
+/// - [DataParallel::all_reduce_grads()] copies every gradient to host memory, averages
+///   it there, and copies it back to each device. There are no device-to-device copies,
+///   so with `Cuda` devices each step pays two host transfers per parameter per replica.
+/// - Inputs aren't scattered for you. The caller splits the batch and moves each shard
+///   to its replica's device (e.g. with [ToDevice]), as in the example below.
+///
+/// Example:
+/// ```rust
+/// # use dfdx::prelude::*;
+/// let devs: [Cpu; 2] = [Cpu::seed_from_u64(0), Cpu::seed_from_u64(1)];
+/// let model = devs[0].build_module::<Linear<3, 2>, f32>();
+/// let dp = DataParallel::new(&model, &devs);
+///
+/// let mut grads = std::vec::Vec::new();
+/// for (replica, dev) in dp.replicas.iter().zip(devs.iter()) {
+///     // each replica gets a different shard of the batch
+///     let x: Tensor<Rank2<4, 3>, f32, _> = dev.sample_normal();
+///     let loss = replica.forward(x.trace()).square().mean();
+///     grads.push(loss.backward());
+/// }
+/// dp.all_reduce_grads(&mut grads);
+/// ```
+#[derive(Debug, Clone)]
+pub struct DataParallel<M> {
+    pub replicas: Vec<M>,
+}
+
+impl<M> DataParallel<M> {
+    /// Copies `module` onto each of `devices`.
+    pub fn new<Src: ToDevice<D, Output = M>, D>(module: &Src, devices: &[D]) -> Self {
+        Self {
+            replicas: devices.iter().map(|dev| module.to_device(dev)).collect(),
+        }
+    }
+
+    /// Replaces the gradients of every trainable parameter of replica `i` in `grads[i]`
+    /// with the average of that parameter's gradients across all replicas.
+    ///
+    /// Parameters without a gradient on any replica are left alone, and a parameter
+    /// missing a gradient on only some replicas is treated as having a zero gradient there.
+    ///
+    /// **Panics** if `grads` does not have one entry per replica.
+    pub fn all_reduce_grads<E: Dtype, D: CopySlice<E>>(&self, grads: &mut [Gradients<E, D>])
+    where
+        M: TensorCollection<E, D>,
+    {
+        self.try_all_reduce_grads(grads).unwrap()
+    }
+
+    /// Fallible version of [DataParallel::all_reduce_grads()]
+    pub fn try_all_reduce_grads<E: Dtype, D: CopySlice<E>>(
+        &self,
+        grads: &mut [Gradients<E, D>],
+    ) -> Result<(), D::Err>
+    where
+        M: TensorCollection<E, D>,
+    {
+        assert_eq!(grads.len(), self.replicas.len());
+        M::iter_tensors(&mut RecursiveWalker {
+            m: self.replicas.iter().collect::<Vec<_>>(),
+            f: &mut AllReduceOp { grads },
+            path: &mut Vec::new(),
+        })
+    }
+}
+
+struct AllReduceOp<'a, E: Unit, D: DeviceStorage> {
+    grads: &'a mut [Gradients<E, D>],
+}
+
+impl<'a, E: Dtype, D: CopySlice<E>> TensorVisitor<E, D> for AllReduceOp<'a, E, D> {
+    type Viewer = Vec<ViewTensorRef>;
+    type Err = D::Err;
+
+    fn visit<S: Shape>(
+        &mut self,
+        _: String,
+        opts: TensorOptions<S, E, D>,
+        ts: Vec<&Tensor<S, E, D>>,
+    ) -> Result<(), Self::Err> {
+        if !opts.do_gradient_update {
+            return Ok(());
+        }
+
+        let used = ts
+            .iter()
+            .zip(self.grads.iter())
+            .any(|(t, grads)| grads.contains(t));
+        if !used {
+            return Ok(());
+        }
+
+        let mut sum: Vec<E> = std::vec![Default::default(); ts[0].shape.num_elements()];
+        let mut buf = sum.clone();
+        for (t, grads) in ts.iter().zip(self.grads.iter_mut()) {
+            grads.try_alloc_for(t)?;
+            grads.get(*t).copy_into(&mut buf);
+            for (s, g) in sum.iter_mut().zip(buf.iter()) {
+                *s += *g;
+            }
+        }
+
+        let n = E::from_usize(ts.len()).unwrap();
+        for s in sum.iter_mut() {
+            *s /= n;
+        }
+
+        for (t, grads) in ts.iter().zip(self.grads.iter_mut()) {
+            let mut g = grads.get(*t);
+            g.copy_from(&sum);
+            *grads.get_mut(*t) =
+                std::sync::Arc::try_unwrap(g.data).unwrap_or_else(|d| (*d).clone());
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        nn::{builders::*, DeviceBuildExt, Module},
+        tensor_ops::*,
+        tests::*,
+    };
+
+    #[test]
+    fn test_data_parallel_matches_full_batch() {
+        let dev: TestDevice = Default::default();
+        let model = dev.build_module::<Linear<4, 2>, TestDtype>();
+        let x: Tensor<Rank2<6, 4>, TestDtype, _> = dev.sample_normal();
+        let g = model.forward(x.trace()).square().mean().backward();
+
+        let x = x.array();
+        let x0 = dev.tensor([x[0], x[1], x[2]]);
+        let x1 = dev.tensor([x[3], x[4], x[5]]);
+
+        let devs: [TestDevice; 2] = [Default::default(), Default::default()];
+        let dp = DataParallel::new(&model, &devs);
+        let mut grads: Vec<_> = dp
+            .replicas
+            .iter()
+            .zip([x0, x1])
+            .zip(devs.iter())
+            .map(|((m, x), d)| m.forward(x.to_device(d).trace()).square().mean().backward())
+            .collect();
+        dp.all_reduce_grads(&mut grads);
+
+        for (m, grads) in dp.replicas.iter().zip(grads.iter()) {
+            assert_close(&grads.get(&m.weight).array(), &g.get(&model.weight).array());
+            assert_close(&grads.get(&m.bias).array(), &g.get(&model.bias).array());
+        }
+    }
+
+    #[test]
+    fn test_data_parallel_skips_unused() {
+        let dev: TestDevice = Default::default();
+        let model = dev.build_module::<(Linear<2, 2>, Linear<2, 2>), TestDtype>();
+        let devs: [TestDevice; 2] = [Default::default(), Default::default()];
+        let dp = DataParallel::new(&model, &devs);
+
+        let mut grads: Vec<_> = dp
+            .replicas
+            .iter()
+            .zip(devs.iter())
+            .map(|(m, d)| m.0.forward(d.ones::<Rank1<2>>().trace()).sum().backward())
+            .collect();
+        dp.all_reduce_grads(&mut grads);
+
+        for (m, grads) in dp.replicas.iter().zip(grads.iter()) {
+            assert!(grads.contains(&m.0.weight));
+            assert!(!grads.contains(&m.1.weight));
+        }
+    }
+
+    #[cfg(feature = "multi-gpu")]
+    #[test]
+    fn test_data_parallel_multi_gpu() {
+        let cpu: Cpu = Default::default();
+        let model = cpu.build_module::<Linear<4, 2>, f32>();
+        let x: Tensor<Rank2<6, 4>, f32, _> = cpu.sample_normal();
+        let g = model.forward(x.trace()).square().mean().backward();
+
+        let x = x.array();
+        let x0 = cpu.tensor([x[0], x[1], x[2]]);
+        let x1 = cpu.tensor([x[3], x[4], x[5]]);
+
+        let devs = [
+            Cuda::try_build(0, 0).unwrap(),
+            Cuda::try_build(1, 0).unwrap(),
+        ];
+        let dp = DataParallel::new(&model, &devs);
+        let mut grads: Vec<_> = dp
+            .replicas
+            .iter()
+            .zip([x0, x1])
+            .zip(devs.iter())
+            .map(|((m, x), d)| m.forward(x.to_device(d).trace()).square().mean().backward())
+            .collect();
+        dp.all_reduce_grads(&mut grads);
+
+        for (m, grads) in dp.replicas.iter().zip(grads.iter()) {
+            assert_close(&grads.get(&m.weight).array(), &g.get(&model.weight).array());
+            assert_close(&grads.get(&m.bias).array(), &g.get(&model.bias).array());
+        }
+    }
+}
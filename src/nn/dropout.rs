@@ -72,7 +72,10 @@ impl<const N: usize, S: Shape, E: Dtype, D: Device<E>> ModuleMut<Tensor<S, E, D,
     }
 }
 
-/// Does nothing as a [Module], and calls [dropout()] as [ModuleMut] with probability `1.0 / N`.
+/// Does nothing as a [Module], and calls [dropout()] as [ModuleMut] with probability [Self::p].
+///
+/// The mask is generated from a seed drawn from the input tensor's device, so
+/// seeding the device (e.g. [crate::tensor::Cpu::seed_from_u64]) makes the masks reproducible.
 ///
 /// To prevent programmer error, [Module] and [ModuleMut] are only implemented for specific tapes:
 /// 1. [Module] requires that the input tensor has a [NoneTape]. i.e. that gradients are not being
@@ -98,8 +101,8 @@ impl<const N: usize, S: Shape, E: Dtype, D: Device<E>> ModuleMut<Tensor<S, E, D,
 /// dropout.forward_mut(dev.zeros::<Rank1<5>>());
 /// ```
 ///
-/// Generics:
-/// - `N`: p is set as `1.0 / N`
+/// Fields:
+/// - `p`: the probability of zeroing an element. Defaults to `0.5`.
 ///
 /// Examples:
 /// ```rust
@@ -147,11 +150,165 @@ impl<S: Shape, E: Dtype, D: Device<E>> ModuleMut<Tensor<S, E, D, OwnedTape<E, D>
     }
 }
 
+/// Like [Dropout], but the masks come from a seed stored in the module instead of
+/// the device's rng, using [Tensor::dropout_with_seed]. Each call to [ModuleMut] uses
+/// [Self::seed] and then increments it, so two modules constructed with the same seed
+/// produce the same sequence of masks no matter what else draws from the device.
+///
+/// [Module] does nothing, same as [Dropout].
+///
+/// Fields:
+/// - `p`: the probability of zeroing an element.
+/// - `seed`: the seed used for the next mask.
+///
+/// Examples:
+/// ```rust
+/// # use dfdx::prelude::*;
+/// # let dev: Cpu = Default::default();
+/// let mut a = SeededDropout { p: 0.5, seed: 3 };
+/// let mut b = SeededDropout { p: 0.5, seed: 3 };
+/// let x: Tensor<Rank2<2, 5>, f32, _> = dev.ones();
+/// let ra = a.forward_mut(x.trace());
+/// let rb = b.forward_mut(x.trace());
+/// assert_eq!(ra.array(), rb.array());
+/// assert_eq!(a.seed, 4);
+/// ```
+#[derive(Clone, Debug)]
+pub struct SeededDropout {
+    pub p: f32,
+    pub seed: u64,
+}
+
+impl Default for SeededDropout {
+    /// Sets `self.p` to `0.5` and `self.seed` to `0`
+    fn default() -> Self {
+        Self { p: 0.5, seed: 0 }
+    }
+}
+
+impl ZeroSizedModule for SeededDropout {}
+
+impl<S: Shape, E: Dtype, D: Device<E>> Module<Tensor<S, E, D, NoneTape>> for SeededDropout {
+    type Output = Tensor<S, E, D, NoneTape>;
+    type Error = D::Err;
+
+    /// Does nothing.
+    fn try_forward(&self, input: Tensor<S, E, D, NoneTape>) -> Result<Self::Output, D::Err> {
+        Ok(input)
+    }
+}
+
+impl<S: Shape, E: Dtype, D: Device<E>> ModuleMut<Tensor<S, E, D, OwnedTape<E, D>>>
+    for SeededDropout
+{
+    type Output = Tensor<S, E, D, OwnedTape<E, D>>;
+    type Error = D::Err;
+
+    /// Calls [Tensor::dropout_with_seed] with [Self::seed], then increments it.
+    fn try_forward_mut(
+        &mut self,
+        input: Tensor<S, E, D, OwnedTape<E, D>>,
+    ) -> Result<Self::Output, D::Err> {
+        let seed = self.seed;
+        self.seed = self.seed.wrapping_add(1);
+        input.try_dropout_with_seed(E::from_f32(self.p).unwrap(), seed)
+    }
+}
+
+/// Does nothing as a [Module], and as [ModuleMut] zeros entire `(H, W)` feature maps
+/// with probability [Self::p], scaling the remaining maps by `1 / (1 - p)`.
+///
+/// Each channel of each item in the batch is dropped independently. Since neighboring pixels
+/// within a feature map are strongly correlated, this regularizes convolutional networks
+/// better than dropping individual elements with [Dropout].
+///
+/// Described in paper: [Efficient Object Localization Using Convolutional Networks](https://arxiv.org/abs/1411.4280)
+///
+/// The mask is generated with [dropout()] on a tensor of ones with shape `(B, C)`, using a
+/// seed drawn from the input tensor's device. Like [Dropout], [Module] requires a [NoneTape]
+/// and [ModuleMut] requires an [OwnedTape].
+///
+/// Fields:
+/// - `p`: the probability of zeroing a feature map. Defaults to `0.5`.
+///
+/// Examples:
+/// ```rust
+/// # use dfdx::prelude::*;
+/// # let dev: Cpu = Default::default();
+/// let mut dropout = SpatialDropout2D { p: 0.5 };
+/// let x: Tensor<Rank4<1, 4, 2, 2>, f32, _> = dev.ones();
+/// let r = dropout.forward_mut(x.trace());
+/// assert_eq!(r.array(), [[[[2.0; 2]; 2], [[2.0; 2]; 2], [[2.0; 2]; 2], [[0.0; 2]; 2]]]);
+/// ```
+#[derive(Clone, Debug)]
+pub struct SpatialDropout2D {
+    pub p: f32,
+}
+
+impl Default for SpatialDropout2D {
+    /// Sets `self.p` to `0.5`
+    fn default() -> Self {
+        Self { p: 0.5 }
+    }
+}
+
+impl ZeroSizedModule for SpatialDropout2D {}
+
+impl<S: Shape, E: Dtype, D: Device<E>> Module<Tensor<S, E, D, NoneTape>> for SpatialDropout2D {
+    type Output = Tensor<S, E, D, NoneTape>;
+    type Error = D::Err;
+
+    /// Does nothing.
+    fn try_forward(&self, input: Tensor<S, E, D, NoneTape>) -> Result<Self::Output, D::Err> {
+        Ok(input)
+    }
+}
+
+impl<C: Dim, H: Dim, W: Dim, E: Dtype, D: Device<E>>
+    ModuleMut<Tensor<(C, H, W), E, D, OwnedTape<E, D>>> for SpatialDropout2D
+{
+    type Output = Tensor<(C, H, W), E, D, OwnedTape<E, D>>;
+    type Error = D::Err;
+
+    /// Drops each of the `C` feature maps with probability [Self::p].
+    fn try_forward_mut(
+        &mut self,
+        input: Tensor<(C, H, W), E, D, OwnedTape<E, D>>,
+    ) -> Result<Self::Output, D::Err> {
+        let shape = *input.shape();
+        let mask = input
+            .device
+            .try_ones_like(&(shape.0,))?
+            .try_dropout(E::from_f32(self.p).unwrap())?;
+        input.try_mul(mask.try_broadcast_like::<_, Axes2<1, 2>>(&shape)?)
+    }
+}
+
+impl<B: Dim, C: Dim, H: Dim, W: Dim, E: Dtype, D: Device<E>>
+    ModuleMut<Tensor<(B, C, H, W), E, D, OwnedTape<E, D>>> for SpatialDropout2D
+{
+    type Output = Tensor<(B, C, H, W), E, D, OwnedTape<E, D>>;
+    type Error = D::Err;
+
+    /// Drops each of the `B * C` feature maps with probability [Self::p].
+    fn try_forward_mut(
+        &mut self,
+        input: Tensor<(B, C, H, W), E, D, OwnedTape<E, D>>,
+    ) -> Result<Self::Output, D::Err> {
+        let shape = *input.shape();
+        let mask = input
+            .device
+            .try_ones_like(&(shape.0, shape.1))?
+            .try_dropout(E::from_f32(self.p).unwrap())?;
+        input.try_mul(mask.try_broadcast_like::<_, Axes2<2, 3>>(&shape)?)
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use crate::{
         shapes::Rank1,
-        tensor::{AsArray, OnesTensor},
+        tensor::{AsArray, OnesTensor, SampleTensor},
         tests::*,
     };
 
@@ -179,6 +336,89 @@ mod tests {
         assert_eq!(t.array(), r.array());
     }
 
+    #[test]
+    fn test_dropout_zeroes_p_fraction_and_scales() {
+        let dev: TestDevice = Default::default();
+        let mut dropout = Dropout { p: 0.25 };
+        let t: Tensor<Rank1<1000>, TestDtype, _> = dev.ones();
+        let r = dropout.forward_mut(t.trace()).as_vec();
+        let num_zeros = r.iter().filter(|&&x| x == 0.0).count();
+        assert!((200..300).contains(&num_zeros), "{num_zeros}");
+        for x in r {
+            assert!(x == 0.0 || (x - 1.0 / 0.75).abs() < 1e-6, "{x}");
+        }
+    }
+
+    #[test]
+    fn test_seeded_dropout_reproduces() {
+        let dev: TestDevice = Default::default();
+        let t: Tensor<Rank1<100>, TestDtype, _> = dev.ones();
+        let mut d1 = SeededDropout { p: 0.5, seed: 10 };
+        let mut d2 = SeededDropout { p: 0.5, seed: 10 };
+        let mut d3 = SeededDropout { p: 0.5, seed: 11 };
+        let r1 = d1.forward_mut(t.trace());
+        let _ = Dropout { p: 0.5 }.forward_mut(t.trace());
+        let r2 = d2.forward_mut(t.trace());
+        let r3 = d3.forward_mut(t.trace());
+        assert_eq!(r1.array(), r2.array());
+        assert_ne!(r1.array(), r3.array());
+
+        // the next mask is the one for seed 11
+        let r1_2 = d1.forward_mut(t.trace());
+        assert_ne!(r1.array(), r1_2.array());
+        assert_eq!(r1_2.array(), r3.array());
+    }
+
+    #[test]
+    fn test_spatial_dropout_drops_whole_maps() {
+        let dev: TestDevice = Default::default();
+        let mut dropout = SpatialDropout2D { p: 0.25 };
+        let t: Tensor<Rank4<8, 16, 3, 4>, TestDtype, _> = dev.sample_normal();
+        let r = dropout.forward_mut(t.trace());
+        let (x, y) = (t.array(), r.array());
+
+        let mut num_dropped = 0;
+        for b in 0..8 {
+            for c in 0..16 {
+                let dropped = y[b][c][0][0] == 0.0;
+                num_dropped += dropped as usize;
+                for h in 0..3 {
+                    for w in 0..4 {
+                        if dropped {
+                            assert_eq!(y[b][c][h][w], 0.0);
+                        } else {
+                            assert_close(&y[b][c][h][w], &(x[b][c][h][w] / 0.75));
+                        }
+                    }
+                }
+            }
+        }
+        assert!((16..48).contains(&num_dropped), "{num_dropped}");
+
+        // gradients only flow through the kept maps, with the same scale
+        let g = r.sum().backward();
+        let g = g.get(&t).array();
+        for b in 0..8 {
+            for c in 0..16 {
+                let expected = if y[b][c][0][0] == 0.0 {
+                    0.0
+                } else {
+                    1.0 / 0.75
+                };
+                assert_close(&g[b][c], &[[expected; 4]; 3]);
+            }
+        }
+    }
+
+    #[test]
+    fn test_spatial_dropout_no_tape() {
+        let dev: TestDevice = Default::default();
+        let dropout = SpatialDropout2D { p: 0.5 };
+        let t: Tensor<Rank3<4, 2, 2>, TestDtype, _> = dev.sample_normal();
+        let r = dropout.forward(t.clone());
+        assert_eq!(t.array(), r.array());
+    }
+
     #[test]
     fn test_dropout_tape() {
         let dev: TestDevice = Default::default();
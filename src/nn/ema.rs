@@ -48,6 +48,53 @@ pub trait ModelEMA<E: Dtype, D: AxpyKernel<E>>: TensorCollection<E, D> {
 }
 impl<E: Dtype, D: AxpyKernel<E>, M: TensorCollection<E, D>> ModelEMA<E, D> for M {}
 
+/// Keeps an exponential moving average copy of a model, using [ModelEMA].
+///
+/// Examples:
+/// ```rust
+/// # use dfdx::prelude::*;
+/// # let dev: Cpu = Default::default();
+/// let model = dev.build_module::<Linear<2, 5>, f32>();
+/// let mut ema = Ema::new(&model);
+/// // ... update model with an optimizer ...
+/// ema.update(&model, 0.999);
+/// let y = ema.model.forward(dev.zeros::<Rank1<2>>());
+/// ```
+#[derive(Debug, Clone)]
+pub struct Ema<M> {
+    pub model: M,
+}
+
+impl<M: Clone> Ema<M> {
+    /// Starts the average at a copy of `model`.
+    pub fn new(model: &M) -> Self {
+        Self {
+            model: model.clone(),
+        }
+    }
+
+    /// Does `ema = ema * decay + model * (1 - decay)` for all trainable parameters.
+    /// See [ModelEMA::ema].
+    pub fn update<E: Dtype, D: AxpyKernel<E>>(&mut self, model: &M, decay: E)
+    where
+        M: TensorCollection<E, D>,
+    {
+        self.model.ema(model, decay)
+    }
+
+    /// Fallible version of [Ema::update]
+    pub fn try_update<E: Dtype, D: AxpyKernel<E>>(
+        &mut self,
+        model: &M,
+        decay: E,
+    ) -> Result<(), D::Err>
+    where
+        M: TensorCollection<E, D>,
+    {
+        self.model.try_ema(model, decay)
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -115,4 +162,25 @@ mod tests {
             );
         }
     }
+
+    #[test]
+    fn test_ema_tracks_changing_param() {
+        let dev: TestDevice = Default::default();
+        let mut model = dev.build_module::<Linear<2, 2>, TestDtype>();
+        model.weight = dev.zeros();
+        model.bias = dev.zeros();
+        let mut ema = Ema::new(&model);
+
+        let decay: TestDtype = 0.9;
+        let mut expected: TestDtype = 0.0;
+        for step in 1..=5 {
+            let value = step as TestDtype;
+            model.weight = dev.ones() * value;
+            ema.update(&model, decay);
+            expected = expected * decay + value * (1.0 - decay);
+            assert_close(&ema.model.weight.array(), &[[expected; 2]; 2]);
+        }
+        assert_close(&ema.model.bias.array(), &[0.0; 2]);
+        assert_close(&[expected], &[1.31441]);
+    }
 }
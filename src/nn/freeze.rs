@@ -0,0 +1,60 @@
+use super::tensor_collection::{
+    RecursiveWalker, TensorCollection, TensorOptions, TensorVisitor, ViewTensorMut,
+};
+
+use crate::{shapes::*, tensor::*};
+
+use std::{convert::Infallible, string::String, vec::Vec};
+
+struct Freezer(bool);
+impl<E: Dtype, D: DeviceStorage> TensorVisitor<E, D> for Freezer {
+    type Viewer = ViewTensorMut;
+    type Err = Infallible;
+
+    fn visit<S: Shape>(
+        &mut self,
+        _: String,
+        _: TensorOptions<S, E, D>,
+        t: &mut Tensor<S, E, D>,
+    ) -> Result<(), Self::Err> {
+        t.param_opts.frozen = self.0;
+        Ok(())
+    }
+}
+
+fn set_frozen<E: Dtype, D: DeviceStorage, M: TensorCollection<E, D>>(module: &mut M, frozen: bool) {
+    let result = M::iter_tensors(&mut RecursiveWalker {
+        m: module,
+        f: &mut Freezer(frozen),
+        path: &mut Vec::new(),
+    });
+    match result {
+        Ok(()) => (),
+        Err(e) => match e {},
+    }
+}
+
+/// Freezes all the tensors of `module`, so that [TensorOptions::do_gradient_update]
+/// is `false` for them. Optimizers don't update frozen tensors, and they aren't counted
+/// by [super::NumParams::num_trainable_params]. This is stored with the tensors, so it
+/// is kept when the module is cloned.
+///
+/// ```rust
+/// # use dfdx::prelude::*;
+/// # let dev: Cpu = Default::default();
+/// type Model = (Linear<2, 3>, ReLU, Linear<3, 2>);
+/// let mut model = dev.build_module::<Model, f32>();
+/// freeze(&mut model.0);
+/// assert_eq!(model.num_trainable_params(), 8);
+/// unfreeze(&mut model.0);
+/// assert_eq!(model.num_trainable_params(), 17);
+/// ```
+pub fn freeze<E: Dtype, D: DeviceStorage, M: TensorCollection<E, D>>(module: &mut M) {
+    set_frozen(module, true)
+}
+
+/// Unfreezes all the tensors of `module` that were frozen with [freeze]. Tensors that
+/// the module never updates with gradients (e.g. running statistics) stay that way.
+pub fn unfreeze<E: Dtype, D: DeviceStorage, M: TensorCollection<E, D>>(module: &mut M) {
+    set_frozen(module, false)
+}
@@ -152,6 +152,23 @@ mod tests {
         assert_ne!(model.1.bias.array(), m0.1.bias.array());
     }
 
+    #[test]
+    fn test_3_tuple_linear_relu_linear() {
+        let dev: TestDevice = Default::default();
+        type Model = (Linear<4, 8>, ReLU, Linear<8, 2>);
+        let model = Model::build_on_device(&dev);
+
+        let x: Tensor<Rank2<5, 4>, TestDtype, _> = dev.sample_normal();
+        let y = model.forward(x.trace());
+        assert_eq!(y.shape(), &(Const::<5>, Const::<2>));
+
+        let g = y.square().mean().backward();
+        assert_ne!(g.get(&model.0.weight).array(), [[0.0; 4]; 8]);
+        assert_ne!(g.get(&model.2.weight).array(), [[0.0; 8]; 2]);
+        assert_ne!(g.get(&model.2.bias).array(), [0.0; 2]);
+        assert_ne!(g.get(&x).array(), [[0.0; 4]; 5]);
+    }
+
     /// A struct to test the forward method of tuples. This sets the `I`th valuein a 1d tensors of size `N` to 1.0.
     #[derive(Debug, Default, Clone)]
     struct SetTo1<const I: usize, const N: usize>;
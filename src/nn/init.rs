@@ -0,0 +1,202 @@
+//! Weight initialization schemes that fill an existing tensor in place.
+//!
+//! Fan in & fan out are computed the same way as pytorch: for a tensor with shape
+//! `(d0, d1, ...)`, fan in is `d1 * receptive` and fan out is `d0 * receptive`, where
+//! `receptive` is the product of all the remaining dimensions. This matches the
+//! `(Out, In)` layout of [crate::nn::modules::Linear::weight] and the
+//! `(Out, In, K, K)` layout of convolution weights.
+//!
+//! Example:
+//! ```rust
+//! # use dfdx::prelude::*;
+//! # let dev: Cpu = Default::default();
+//! use dfdx::nn::init;
+//! let mut w: Tensor<Rank2<3, 5>, f32, _> = dev.zeros();
+//! init::kaiming_uniform(&mut w);
+//! init::xavier_normal(&mut w);
+//! ```
+
+use crate::{shapes::*, tensor::*};
+
+use num_traits::Float;
+use rand_distr::{uniform::SampleUniform, Distribution, Normal, StandardNormal, Uniform};
+
+/// Returns `(fan_in, fan_out)` of `shape`.
+///
+/// Rank 0 tensors have fans of 1, and rank 1 tensors use their only dimension for both.
+pub fn fans<S: Shape>(shape: &S) -> (usize, usize) {
+    let dims: std::vec::Vec<usize> = shape.concrete().into();
+    match dims.len() {
+        0 => (1, 1),
+        1 => (dims[0], dims[0]),
+        _ => {
+            let receptive: usize = dims[2..].iter().product();
+            (dims[1] * receptive, dims[0] * receptive)
+        }
+    }
+}
+
+fn kaiming_std<S: Shape>(shape: &S) -> f64 {
+    let (fan_in, _) = fans(shape);
+    (2.0 / fan_in as f64).sqrt()
+}
+
+fn xavier_std<S: Shape>(shape: &S) -> f64 {
+    let (fan_in, fan_out) = fans(shape);
+    (2.0 / (fan_in + fan_out) as f64).sqrt()
+}
+
+fn fill_uniform<S: Shape, E: Dtype + Float + SampleUniform, D: SampleTensor<E>, T>(
+    t: &mut Tensor<S, E, D, T>,
+    std: f64,
+) -> Result<(), D::Err> {
+    // a uniform distribution on [-b, b] has standard deviation b / sqrt(3)
+    let b = E::from_f64(3.0f64.sqrt() * std).unwrap();
+    t.try_fill_with_distr(Uniform::new(-b, b))
+}
+
+fn fill_normal<S: Shape, E: Dtype + Float, D: SampleTensor<E>, T>(
+    t: &mut Tensor<S, E, D, T>,
+    std: f64,
+) -> Result<(), D::Err>
+where
+    StandardNormal: Distribution<E>,
+{
+    let std = E::from_f64(std).unwrap();
+    t.try_fill_with_distr(Normal::new(E::zero(), std).unwrap())
+}
+
+/// Fills `t` with values from `Uniform(-b, b)` where `b = sqrt(6 / fan_in)`.
+///
+/// This is He initialization for ReLU networks, see [Delving Deep into Rectifiers](https://arxiv.org/abs/1502.01852).
+///
+/// **Pytorch equivalent**: `torch.nn.init.kaiming_uniform_(t, nonlinearity="relu")`
+pub fn kaiming_uniform<S: Shape, E: Dtype + Float + SampleUniform, D: SampleTensor<E>, T>(
+    t: &mut Tensor<S, E, D, T>,
+) {
+    try_kaiming_uniform(t).unwrap()
+}
+
+/// Fallible version of [kaiming_uniform]
+pub fn try_kaiming_uniform<S: Shape, E: Dtype + Float + SampleUniform, D: SampleTensor<E>, T>(
+    t: &mut Tensor<S, E, D, T>,
+) -> Result<(), D::Err> {
+    let std = kaiming_std(t.shape());
+    fill_uniform(t, std)
+}
+
+/// Fills `t` with values from `Normal(0, std)` where `std = sqrt(2 / fan_in)`.
+///
+/// **Pytorch equivalent**: `torch.nn.init.kaiming_normal_(t, nonlinearity="relu")`
+pub fn kaiming_normal<S: Shape, E: Dtype + Float, D: SampleTensor<E>, T>(t: &mut Tensor<S, E, D, T>)
+where
+    StandardNormal: Distribution<E>,
+{
+    try_kaiming_normal(t).unwrap()
+}
+
+/// Fallible version of [kaiming_normal]
+pub fn try_kaiming_normal<S: Shape, E: Dtype + Float, D: SampleTensor<E>, T>(
+    t: &mut Tensor<S, E, D, T>,
+) -> Result<(), D::Err>
+where
+    StandardNormal: Distribution<E>,
+{
+    let std = kaiming_std(t.shape());
+    fill_normal(t, std)
+}
+
+/// Fills `t` with values from `Uniform(-b, b)` where `b = sqrt(6 / (fan_in + fan_out))`.
+///
+/// This is Glorot initialization, see [Understanding the difficulty of training deep feedforward neural networks](https://proceedings.mlr.press/v9/glorot10a.html).
+///
+/// **Pytorch equivalent**: `torch.nn.init.xavier_uniform_(t)`
+pub fn xavier_uniform<S: Shape, E: Dtype + Float + SampleUniform, D: SampleTensor<E>, T>(
+    t: &mut Tensor<S, E, D, T>,
+) {
+    try_xavier_uniform(t).unwrap()
+}
+
+/// Fallible version of [xavier_uniform]
+pub fn try_xavier_uniform<S: Shape, E: Dtype + Float + SampleUniform, D: SampleTensor<E>, T>(
+    t: &mut Tensor<S, E, D, T>,
+) -> Result<(), D::Err> {
+    let std = xavier_std(t.shape());
+    fill_uniform(t, std)
+}
+
+/// Fills `t` with values from `Normal(0, std)` where `std = sqrt(2 / (fan_in + fan_out))`.
+///
+/// **Pytorch equivalent**: `torch.nn.init.xavier_normal_(t)`
+pub fn xavier_normal<S: Shape, E: Dtype + Float, D: SampleTensor<E>, T>(t: &mut Tensor<S, E, D, T>)
+where
+    StandardNormal: Distribution<E>,
+{
+    try_xavier_normal(t).unwrap()
+}
+
+/// Fallible version of [xavier_normal]
+pub fn try_xavier_normal<S: Shape, E: Dtype + Float, D: SampleTensor<E>, T>(
+    t: &mut Tensor<S, E, D, T>,
+) -> Result<(), D::Err>
+where
+    StandardNormal: Distribution<E>,
+{
+    let std = xavier_std(t.shape());
+    fill_normal(t, std)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::tests::*;
+
+    fn std<S: Shape>(t: &Tensor<S, TestDtype, TestDevice>) -> f64 {
+        let v = t.as_vec();
+        let n = v.len() as f64;
+        let mean = v.iter().map(|&x| x as f64).sum::<f64>() / n;
+        let var = v.iter().map(|&x| (x as f64 - mean).powi(2)).sum::<f64>() / n;
+        var.sqrt()
+    }
+
+    #[test]
+    fn test_fans() {
+        assert_eq!(fans(&()), (1, 1));
+        assert_eq!(fans(&(Const::<3>,)), (3, 3));
+        assert_eq!(fans(&(Const::<3>, Const::<5>)), (5, 3));
+        assert_eq!(
+            fans(&(Const::<4>, Const::<2>, Const::<3>, Const::<3>)),
+            (18, 36)
+        );
+    }
+
+    #[test]
+    fn test_kaiming() {
+        let dev: TestDevice = Default::default();
+        let mut t: Tensor<Rank2<256, 512>, TestDtype, _> = dev.zeros();
+        let expected = (2.0f64 / 512.0).sqrt();
+
+        kaiming_uniform(&mut t);
+        assert!((std(&t) - expected).abs() < 0.02 * expected, "{}", std(&t));
+        let b = (6.0f64 / 512.0).sqrt() as TestDtype;
+        assert!(t.as_vec().iter().all(|&x| -b <= x && x <= b));
+
+        kaiming_normal(&mut t);
+        assert!((std(&t) - expected).abs() < 0.02 * expected, "{}", std(&t));
+    }
+
+    #[test]
+    fn test_xavier() {
+        let dev: TestDevice = Default::default();
+        let mut t: Tensor<Rank2<256, 512>, TestDtype, _> = dev.zeros();
+        let expected = (2.0f64 / 768.0).sqrt();
+
+        xavier_uniform(&mut t);
+        assert!((std(&t) - expected).abs() < 0.02 * expected, "{}", std(&t));
+        let b = (6.0f64 / 768.0).sqrt() as TestDtype;
+        assert!(t.as_vec().iter().all(|&x| -b <= x && x <= b));
+
+        xavier_normal(&mut t);
+        assert!((std(&t) - expected).abs() < 0.02 * expected, "{}", std(&t));
+    }
+}
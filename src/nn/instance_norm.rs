@@ -0,0 +1,414 @@
+#![allow(clippy::type_complexity)]
+
+use crate::{gradients::*, shapes::*, tensor::*, tensor_ops::*};
+
+use super::{tensor_collection::*, BuildModule, BuildOnDevice, Module, ModuleMut, ToDevice};
+
+pub mod builder {
+    #[derive(Debug, Copy, Clone, Eq, PartialEq)]
+    pub struct InstanceNorm2D<const C: usize, const AFFINE: bool = true>;
+}
+
+impl<const C: usize, const AFFINE: bool, E: Dtype, D: Device<E>> BuildOnDevice<D, E>
+    for builder::InstanceNorm2D<C, AFFINE>
+where
+    InstanceNorm2D<C, E, D, AFFINE>: BuildModule<D, E>,
+{
+    type Built = InstanceNorm2D<C, E, D, AFFINE>;
+    fn try_build_on_device(device: &D) -> Result<Self::Built, D::Err> {
+        Self::Built::try_build(device)
+    }
+}
+
+/// Instance normalization for images as described in
+/// [Instance Normalization: The Missing Ingredient for Fast Stylization](https://arxiv.org/abs/1607.08022)
+///
+/// Each `(batch, channel)` map is normalized over its spatial extent, so unlike [super::modules::BatchNorm2D]
+/// the statistics of one item in the batch never affect another.
+///
+/// Generics:
+///
+/// - `C` the number of channels. For 3d tensors this is the 0th dimension. For 4d tensors,
+///   this is the 1st dimension.
+/// - `AFFINE` whether to apply the affine transform. Defaults to true.
+///
+/// # Affine transform
+///
+/// If `AFFINE` is true, the normalized maps are scaled by [Self::scale] and shifted by
+/// [Self::bias]. Otherwise these are `None`, so they aren't allocated or visited as part
+/// of the [TensorCollection], and optimizers never see them.
+///
+/// # Running statistics
+///
+/// If [Self::track_running_stats] is true (defaults to false):
+/// 1. [ModuleMut] normalizes with the instance statistics, and updates [Self::running_mean]
+///    and [Self::running_var] with momentum.
+/// 2. [Module] normalizes with the running statistics.
+///
+/// Otherwise both [Module] and [ModuleMut] normalize with the instance statistics.
+///
+/// Examples:
+/// ```rust
+/// # use dfdx::prelude::*;
+/// # let dev: Cpu = Default::default();
+/// type Model = InstanceNorm2D<3>;
+/// let m = dev.build_module::<Model, f32>();
+/// let _ = m.forward(dev.zeros::<Rank3<3, 2, 2>>());
+/// let _ = m.forward(dev.zeros::<Rank4<4, 3, 2, 2>>());
+///
+/// let m = dev.build_module::<InstanceNorm2D<3, false>, f32>();
+/// assert_eq!(m.num_trainable_params(), 0);
+/// ```
+#[derive(Clone, Debug)]
+pub struct InstanceNorm2D<const C: usize, E: Dtype, D: DeviceStorage, const AFFINE: bool = true> {
+    /// Scale for affine transform. Defaults to 1.0, and is `None` if `AFFINE` is false
+    pub scale: Option<Tensor<Rank1<C>, E, D>>,
+    /// Bias for affine transform. Defaults to 0.0, and is `None` if `AFFINE` is false
+    pub bias: Option<Tensor<Rank1<C>, E, D>>,
+    /// Channel mean that is updated by [ModuleMut] when tracking running stats. Defaults to 0.0
+    pub running_mean: Tensor<Rank1<C>, E, D>,
+    /// Channel variance that is updated by [ModuleMut] when tracking running stats. Defaults to 1.0
+    pub running_var: Tensor<Rank1<C>, E, D>,
+    /// Added to variance before taking sqrt for numerical stability. Defaults to 1e-5
+    pub epsilon: E,
+    /// Controls exponential moving average of running stats. Defaults to 0.1
+    ///
+    /// `running_stat * (1.0 - momentum) + stat * momentum`.
+    pub momentum: E,
+    /// Whether to keep and use running statistics. Defaults to false
+    pub track_running_stats: bool,
+}
+
+impl<const C: usize, E: Dtype, D: Device<E>, const AFFINE: bool> InstanceNorm2D<C, E, D, AFFINE> {
+    /// Normalizes with the statistics of each map, which are also returned off tape
+    fn instance_fwd<B: Dim, H: Dim, W: Dim, T: Tape<E, D>>(
+        &self,
+        x: Tensor<(B, Const<C>, H, W), E, D, T>,
+    ) -> Result<
+        (
+            Tensor<(B, Const<C>, H, W), E, D, T>,
+            Tensor<(B, Const<C>), E, D>,
+            Tensor<(B, Const<C>), E, D>,
+        ),
+        D::Err,
+    > {
+        let shape = *x.shape();
+
+        let mean = x.retaped::<T>().try_mean::<_, Axes2<2, 3>>()?;
+        let centered = x.try_sub(
+            mean.retaped::<T>()
+                .try_broadcast_like::<_, Axes2<2, 3>>(&shape)?,
+        )?;
+        let var = centered
+            .retaped::<T>()
+            .try_square()?
+            .try_mean::<_, Axes2<2, 3>>()?;
+        let inv_std = var
+            .retaped::<T>()
+            .try_add(self.epsilon)?
+            .try_rsqrt()?
+            .try_broadcast_like::<_, Axes2<2, 3>>(&shape)?;
+        Ok((centered.try_mul(inv_std)?, mean.retaped(), var.retaped()))
+    }
+
+    /// Normalizes with [Self::running_mean] and [Self::running_var]
+    fn running_fwd<B: Dim, H: Dim, W: Dim, T: Tape<E, D>>(
+        &self,
+        x: Tensor<(B, Const<C>, H, W), E, D, T>,
+    ) -> Result<Tensor<(B, Const<C>, H, W), E, D, T>, D::Err> {
+        let shape = *x.shape();
+        let mean = self.running_mean.clone();
+        let inv_std = (self.running_var.clone() + self.epsilon).try_rsqrt()?;
+        x.try_sub(mean.try_broadcast_like::<_, Axes3<0, 2, 3>>(&shape)?)?
+            .try_mul(inv_std.try_broadcast_like::<_, Axes3<0, 2, 3>>(&shape)?)
+    }
+
+    fn try_affine<B: Dim, H: Dim, W: Dim, T: Tape<E, D>>(
+        &self,
+        x: Tensor<(B, Const<C>, H, W), E, D, T>,
+    ) -> Result<Tensor<(B, Const<C>, H, W), E, D, T>, D::Err> {
+        let (scale, bias) = match (&self.scale, &self.bias) {
+            (Some(scale), Some(bias)) => (scale.retaped::<T>(), bias.retaped::<T>()),
+            _ => return Ok(x),
+        };
+        let shape = *x.shape();
+        x.try_mul(scale.try_broadcast_like::<_, Axes3<0, 2, 3>>(&shape)?)?
+            .try_add(bias.try_broadcast_like::<_, Axes3<0, 2, 3>>(&shape)?)
+    }
+}
+
+impl<
+        B: Dim,
+        const C: usize,
+        H: Dim,
+        W: Dim,
+        E: Dtype,
+        D: Device<E>,
+        T: Tape<E, D>,
+        const AFFINE: bool,
+    > Module<Tensor<(B, Const<C>, H, W), E, D, T>> for InstanceNorm2D<C, E, D, AFFINE>
+{
+    type Output = Tensor<(B, Const<C>, H, W), E, D, T>;
+    type Error = D::Err;
+
+    /// 4d forward - does **not** update [Self::running_mean] and [Self::running_var]
+    fn try_forward(&self, x: Tensor<(B, Const<C>, H, W), E, D, T>) -> Result<Self::Output, D::Err> {
+        let x = if self.track_running_stats {
+            self.running_fwd(x)?
+        } else {
+            self.instance_fwd(x)?.0
+        };
+        self.try_affine(x)
+    }
+}
+
+impl<
+        B: Dim,
+        const C: usize,
+        H: Dim,
+        W: Dim,
+        E: Dtype,
+        D: Device<E>,
+        T: Tape<E, D>,
+        const AFFINE: bool,
+    > ModuleMut<Tensor<(B, Const<C>, H, W), E, D, T>> for InstanceNorm2D<C, E, D, AFFINE>
+{
+    type Output = Tensor<(B, Const<C>, H, W), E, D, T>;
+    type Error = D::Err;
+
+    /// 4d forward - updates [Self::running_mean] and [Self::running_var] if
+    /// [Self::track_running_stats] is true
+    fn try_forward_mut(
+        &mut self,
+        x: Tensor<(B, Const<C>, H, W), E, D, T>,
+    ) -> Result<Self::Output, D::Err> {
+        let n = E::from_usize(x.shape().2.size() * x.shape().3.size()).unwrap();
+        let (x, mean, var) = self.instance_fwd(x)?;
+        if self.track_running_stats {
+            let mean_chan = mean.try_mean::<Rank1<C>, _>()?;
+            // NOTE: uses unbiased variance in running estimate
+            let var_chan = var.try_mean::<Rank1<C>, _>()?;
+            self.running_mean
+                .try_axpy(E::ONE - self.momentum, &mean_chan, self.momentum)?;
+            // a single element has no unbiased variance, so the biased one is used
+            let correction = if n > E::ONE { n / (n - E::ONE) } else { E::ONE };
+            self.running_var.try_axpy(
+                E::ONE - self.momentum,
+                &var_chan,
+                self.momentum * correction,
+            )?;
+        }
+        self.try_affine(x)
+    }
+}
+
+impl<const C: usize, H: Dim, W: Dim, E: Dtype, D: Device<E>, T: Tape<E, D>, const AFFINE: bool>
+    Module<Tensor<(Const<C>, H, W), E, D, T>> for InstanceNorm2D<C, E, D, AFFINE>
+{
+    type Output = Tensor<(Const<C>, H, W), E, D, T>;
+    type Error = D::Err;
+
+    /// 3d forward - does **not** update [Self::running_mean] and [Self::running_var]
+    fn try_forward(&self, x: Tensor<(Const<C>, H, W), E, D, T>) -> Result<Self::Output, D::Err> {
+        let (c, h, w) = *x.shape();
+        let x = x.try_reshape_like(&(Const::<1>, c, h, w))?;
+        self.try_forward(x)?.try_reshape_like(&(c, h, w))
+    }
+}
+
+impl<const C: usize, H: Dim, W: Dim, E: Dtype, D: Device<E>, T: Tape<E, D>, const AFFINE: bool>
+    ModuleMut<Tensor<(Const<C>, H, W), E, D, T>> for InstanceNorm2D<C, E, D, AFFINE>
+{
+    type Output = Tensor<(Const<C>, H, W), E, D, T>;
+    type Error = D::Err;
+
+    /// 3d forward - updates [Self::running_mean] and [Self::running_var] if
+    /// [Self::track_running_stats] is true
+    fn try_forward_mut(
+        &mut self,
+        x: Tensor<(Const<C>, H, W), E, D, T>,
+    ) -> Result<Self::Output, D::Err> {
+        let (c, h, w) = *x.shape();
+        let x = x.try_reshape_like(&(Const::<1>, c, h, w))?;
+        self.try_forward_mut(x)?.try_reshape_like(&(c, h, w))
+    }
+}
+
+impl<const C: usize, E: Dtype, D: Device<E>, const AFFINE: bool> BuildModule<D, E>
+    for InstanceNorm2D<C, E, D, AFFINE>
+{
+    fn try_build(device: &D) -> Result<Self, D::Err> {
+        Ok(Self {
+            scale: AFFINE.then(|| device.try_ones()).transpose()?,
+            bias: AFFINE.then(|| device.try_zeros()).transpose()?,
+            running_mean: device.try_zeros()?,
+            running_var: device.try_ones()?,
+            epsilon: E::from_f32(1e-5).unwrap(),
+            momentum: E::from_f32(0.1).unwrap(),
+            track_running_stats: false,
+        })
+    }
+}
+
+impl<const C: usize, E: Dtype, D: Device<E>, const AFFINE: bool> TensorCollection<E, D>
+    for InstanceNorm2D<C, E, D, AFFINE>
+{
+    fn iter_tensors<V: ModuleVisitor<Self, E, D>>(visitor: &mut V) -> Result<(), V::Err> {
+        if AFFINE {
+            visitor.visit_tensor(
+                "scale",
+                |s| s.scale.as_ref().unwrap(),
+                |s| s.scale.as_mut().unwrap(),
+                TensorOptions::reset_to_ones(),
+            )?;
+            visitor.visit_tensor(
+                "bias",
+                |s| s.bias.as_ref().unwrap(),
+                |s| s.bias.as_mut().unwrap(),
+                TensorOptions::reset_to_zeros(),
+            )?;
+        }
+        visitor.visit_tensor(
+            "running_mean",
+            |s| &s.running_mean,
+            |s| &mut s.running_mean,
+            TensorOptions::detached(|t| t.try_fill_with_zeros()),
+        )?;
+        visitor.visit_tensor(
+            "running_var",
+            |s| &s.running_var,
+            |s| &mut s.running_var,
+            TensorOptions::detached(|t| t.try_fill_with_ones()),
+        )
+    }
+}
+
+impl<const C: usize, E: Dtype, D1: Device<E>, D2: Device<E>, const AFFINE: bool> ToDevice<D2>
+    for InstanceNorm2D<C, E, D1, AFFINE>
+{
+    type Output = InstanceNorm2D<C, E, D2, AFFINE>;
+    fn to_device(&self, device: &D2) -> Self::Output {
+        InstanceNorm2D {
+            scale: self.scale.as_ref().map(|t| t.to_device(device)),
+            bias: self.bias.as_ref().map(|t| t.to_device(device)),
+            running_mean: self.running_mean.to_device(device),
+            running_var: self.running_var.to_device(device),
+            epsilon: self.epsilon,
+            momentum: self.momentum,
+            track_running_stats: self.track_running_stats,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::builder::InstanceNorm2D;
+    use crate::{nn::*, optim::*, shapes::*, tensor::*, tensor_ops::*, tests::*};
+
+    #[test]
+    fn test_instance_norm_normalizes_each_map() {
+        let dev: TestDevice = Default::default();
+        let mut m = dev.build_module::<InstanceNorm2D<2>, TestDtype>();
+        m.scale = Some(dev.tensor([2.0, -1.0]));
+        m.bias = Some(dev.tensor([0.5, 1.0]));
+
+        // channels with very different statistics
+        let x: Tensor<Rank3<2, 2, 2>, TestDtype, _> =
+            dev.tensor([[[1.0, 2.0], [3.0, 4.0]], [[-10.0, 30.0], [50.0, 10.0]]]);
+        let r = m.forward(x.trace());
+        let a = 1.0 / (1.25f64 + 1e-5).sqrt();
+        let b = 1.0 / (500.0f64 + 1e-5).sqrt();
+        let expected = [
+            [[-1.5 * a, -0.5 * a], [0.5 * a, 1.5 * a]],
+            [[-30.0 * b, 10.0 * b], [30.0 * b, -10.0 * b]],
+        ];
+        let expected = [0, 1].map(|c| {
+            let (s, o) = ([2.0, -1.0][c], [0.5, 1.0][c]);
+            expected[c].map(|row| row.map(|v| (s * v + o) as TestDtype))
+        });
+        assert_close(&r.array(), &expected);
+
+        // the normalized maps sum to 0 and have a sum of squares of `H * W`,
+        // so the affine gradients are independent of the input
+        let g = r.sum().backward();
+        let (scale, bias) = (m.scale.as_ref().unwrap(), m.bias.as_ref().unwrap());
+        assert_close_with_tolerance(&g.get(scale).array(), &[0.0; 2], 1e-5);
+        assert_eq!(g.get(bias).array(), [4.0; 2]);
+
+        let g = m.forward(x.trace()).square().sum().backward();
+        let s = g.get(scale).array();
+        let b = g.get(bias).array();
+        // d/dscale = 2 * sum(y * xhat) = 2 * scale * sum(xhat^2) + 2 * bias * sum(xhat)
+        assert_close_with_tolerance(&s, &[16.0, -8.0], 1e-3);
+        // d/dbias = 2 * sum(y) = 2 * H * W * bias
+        assert_close_with_tolerance(&b, &[4.0, 8.0], 1e-3);
+    }
+
+    #[test]
+    fn test_instance_norm_batch_independent() {
+        let dev: TestDevice = Default::default();
+        let m = dev.build_module::<InstanceNorm2D<3>, TestDtype>();
+        let x: Tensor<Rank4<2, 3, 4, 5>, TestDtype, _> = dev.sample_normal();
+        let r = m.forward(x.clone());
+        let r0 = m.forward(x.clone().select(dev.tensor(0)));
+        let r1 = m.forward(x.select(dev.tensor(1)));
+        assert_close(&r.array(), &[r0.array(), r1.array()]);
+
+        let mean = r.clone().mean::<Rank2<2, 3>, _>();
+        let var = r.square().mean::<Rank2<2, 3>, _>();
+        assert_close_with_tolerance(&mean.array(), &[[0.0; 3]; 2], 1e-5);
+        assert_close_with_tolerance(&var.array(), &[[1.0; 3]; 2], 1e-3);
+    }
+
+    #[test]
+    fn test_instance_norm_running_stats() {
+        let dev: TestDevice = Default::default();
+        let mut m = dev.build_module::<InstanceNorm2D<2>, TestDtype>();
+        m.track_running_stats = true;
+
+        let x: Tensor<Rank4<1, 2, 1, 2>, TestDtype, _> = dev.tensor([[[[1.0, 3.0]], [[0.0, 0.0]]]]);
+        let _ = m.forward_mut(x.clone());
+        assert_close(&m.running_mean.array(), &[0.2, 0.0]);
+        // unbiased variance of [1, 3] is 2
+        assert_close(&m.running_var.array(), &[0.9 + 0.2, 0.9]);
+
+        // inference uses the running statistics
+        let r = m.forward(x);
+        let std = [(1.1f64 + 1e-5).sqrt(), (0.9f64 + 1e-5).sqrt()];
+        assert_close(
+            &r.array(),
+            &[[
+                [[(0.8 / std[0]) as TestDtype, (2.8 / std[0]) as TestDtype]],
+                [[0.0; 2]],
+            ]],
+        );
+    }
+
+    #[test]
+    fn test_instance_norm_no_affine() {
+        let dev: TestDevice = Default::default();
+        let mut m = dev.build_module::<InstanceNorm2D<2, false>, TestDtype>();
+        assert_eq!(m.num_trainable_params(), 0);
+        assert!(m.scale.is_none() && m.bias.is_none());
+
+        let x: Tensor<Rank3<2, 1, 2>, TestDtype, _> = dev.tensor([[[1.0, 3.0]], [[-1.0, 1.0]]]);
+        let r = m.forward(x.clone());
+        let a = (1.0 / (1.0f64 + 1e-5).sqrt()) as TestDtype;
+        assert_close(&r.array(), &[[[-a, a]], [[-a, a]]]);
+
+        let mut opt = Sgd::new(&m, Default::default());
+        let g = m.forward(x.trace()).square().sum().backward();
+        opt.update(&mut m, g).expect("");
+    }
+
+    #[test]
+    fn test_instance_norm_running_stats_single_element() {
+        let dev: TestDevice = Default::default();
+        let mut m = dev.build_module::<InstanceNorm2D<1>, TestDtype>();
+        m.track_running_stats = true;
+        let x: Tensor<Rank4<2, 1, 1, 1>, TestDtype, _> = dev.tensor([[[[1.0]]], [[[3.0]]]]);
+        let _ = m.forward_mut(x);
+        assert_close(&m.running_mean.array(), &[0.2]);
+        assert_close(&m.running_var.array(), &[0.9]);
+    }
+}
@@ -1,17 +1,30 @@
 use crate::{gradients::Tape, shapes::*, tensor::*, tensor_ops::*};
 
-use super::{tensor_collection::*, BuildModule, BuildOnDevice, Module, NonMutableModule, ToDevice};
+use super::{
+    tensor_collection::*, unbiased_linear::UnbiasedLinear, BuildModule, BuildOnDevice, Module,
+    NonMutableModule, ToDevice,
+};
 
 use num_traits::Float;
 use rand_distr::{uniform::SampleUniform, Uniform};
 
 pub mod builder {
+    /// Builds a [super::Linear] when `BIAS` is `true` (the default), and an
+    /// [super::UnbiasedLinear] when `BIAS` is `false`.
+    ///
+    /// ```rust
+    /// # use dfdx::{prelude::*, nn::modules};
+    /// # let dev: Cpu = Default::default();
+    /// let _: modules::Linear<5, 2, f32, Cpu> = dev.build_module::<Linear<5, 2>, f32>();
+    /// let _: modules::UnbiasedLinear<5, 2, f32, Cpu> =
+    ///     dev.build_module::<Linear<5, 2, false>, f32>();
+    /// ```
     #[derive(Debug, Copy, Clone, Eq, PartialEq)]
-    pub struct Linear<const I: usize, const O: usize>;
+    pub struct Linear<const I: usize, const O: usize, const BIAS: bool = true>;
 }
 
 impl<const I: usize, const O: usize, E: Dtype, D: Device<E>> BuildOnDevice<D, E>
-    for builder::Linear<I, O>
+    for builder::Linear<I, O, true>
 where
     Linear<I, O, E, D>: BuildModule<D, E>,
 {
@@ -21,12 +34,26 @@ where
     }
 }
 
+impl<const I: usize, const O: usize, E: Dtype, D: Device<E>> BuildOnDevice<D, E>
+    for builder::Linear<I, O, false>
+where
+    UnbiasedLinear<I, O, E, D>: BuildModule<D, E>,
+{
+    type Built = UnbiasedLinear<I, O, E, D>;
+    fn try_build_on_device(device: &D) -> Result<Self::Built, <D>::Err> {
+        Self::Built::try_build(device)
+    }
+}
+
 /// A linear transformation of the form `weight * x + bias`, where `weight` is a matrix, `x` is a vector or matrix,
 /// and `bias` is a vector.
 ///
 /// Initializes [Self::weight] and [Self::bias] from a Uniform distribution
 /// between [-1 / sqrt(I), 1 / sqrt(I)].
 ///
+/// To build a linear layer without a bias, use `builder::Linear<I, O, false>`, which
+/// builds an [UnbiasedLinear].
+///
 /// # Generics
 /// - `I` The "input" size of vectors & matrices.
 /// - `O` The "output" size of vectors & matrices.
@@ -171,7 +198,10 @@ impl<'a, B: Dim, S: Dim, const M: usize, E: Dtype, D: Device<E>, T: Tape<E, D>>
 #[cfg(test)]
 mod tests {
     use super::*;
-    use crate::{nn::DeviceBuildExt, tests::*};
+    use crate::{
+        nn::{DeviceBuildExt, NumParams},
+        tests::*,
+    };
 
     const W: [[TestDtype; 5]; 2] = [
         [-0.3458893, -0.30371523, -0.3712057, 0.14303583, -0.0268966],
@@ -300,4 +330,20 @@ mod tests {
         );
         assert_close(&g.get(&model.bias).array(), &[0.40265593, -0.2874091]);
     }
+
+    #[test]
+    fn test_linear_without_bias() {
+        let dev: TestDevice = Default::default();
+
+        let biased = dev.build_module::<builder::Linear<5, 2>, TestDtype>();
+        let mut unbiased = dev.build_module::<builder::Linear<5, 2, false>, TestDtype>();
+        assert_eq!(biased.num_trainable_params(), 12);
+        assert_eq!(unbiased.num_trainable_params(), 10);
+
+        unbiased.weight = dev.tensor(W);
+        let x: Tensor<Rank2<3, 5>, TestDtype, _> = dev.sample_normal();
+        let y = unbiased.forward(x.clone());
+        let w_t: Tensor<Rank2<5, 2>, TestDtype, _> = dev.tensor(W).permute();
+        assert_close(&y.array(), &x.matmul(w_t).array());
+    }
 }
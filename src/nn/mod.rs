@@ -22,6 +22,7 @@
 //! - [modules::BatchNorm2D]
 //! - [modules::DropoutOneIn]
 //! - [modules::Dropout]
+//! - [modules::SeededDropout]
 //!
 //! # Initializing
 //!
@@ -104,7 +105,10 @@
 //! mlp.load_state_dict(state_dict)
 //! ```
 
+mod freeze;
+pub mod init;
 mod num_params;
+mod param_group;
 mod reset_params;
 pub mod tensor_collection;
 
@@ -112,33 +116,54 @@ mod activations;
 mod add_into;
 mod batchnorm2d;
 mod bias2d;
+mod bilinear;
+mod checkpoint;
 mod conv;
+mod data_parallel;
 mod dropout;
 mod ema;
 mod embedding;
 mod flatten;
 mod generalized_residual;
 mod impl_module_for_tuples;
+mod instance_norm;
 mod layer_norm;
 mod linear;
 mod module;
 #[cfg(feature = "numpy")]
 mod npz;
+#[cfg(feature = "onnx")]
+mod onnx;
 mod pool2d;
 mod pool_global;
 mod repeated;
 mod residual;
+mod rms_norm;
+#[cfg(feature = "safetensors")]
+mod safetensors;
 mod split_into;
 mod transformer;
 mod unbiased_linear;
 
 pub use module::*;
 
-pub use ema::ModelEMA;
+pub use checkpoint::{checkpoint, try_checkpoint};
+pub use data_parallel::DataParallel;
+pub use ema::{Ema, ModelEMA};
+pub use freeze::{freeze, unfreeze};
 #[cfg(feature = "numpy")]
 pub use npz::{LoadFromNpz, SaveToNpz};
 pub use num_params::NumParams;
+#[cfg(feature = "onnx")]
+pub use onnx::{
+    ExportOnnx, OnnxAttribute, OnnxDtype, OnnxExportError, OnnxGraph, OnnxGraphBuilder,
+    OnnxInitializer, OnnxModel, OnnxNode, OnnxValue,
+};
+pub use param_group::set_param_group;
 pub use reset_params::ResetParams;
+#[cfg(feature = "safetensors")]
+pub use safetensors::LoadFromSafetensors;
+pub use transformer::{causal_mask, try_causal_mask};
 
 pub mod modules {
     /// Structs containing initialized Tensors & impls for [super::Module]. See
@@ -148,13 +173,15 @@ pub mod modules {
     pub use super::add_into::AddInto;
     pub use super::batchnorm2d::BatchNorm2D;
     pub use super::bias2d::Bias2D;
+    pub use super::bilinear::Bilinear;
     #[cfg(feature = "nightly")]
     pub use super::conv::Conv2D;
-    pub use super::dropout::{Dropout, DropoutOneIn};
+    pub use super::dropout::{Dropout, DropoutOneIn, SeededDropout, SpatialDropout2D};
     pub use super::embedding::Embedding;
     #[cfg(feature = "nightly")]
     pub use super::flatten::Flatten2D;
     pub use super::generalized_residual::GeneralizedResidual;
+    pub use super::instance_norm::InstanceNorm2D;
     pub use super::layer_norm::LayerNorm1D;
     pub use super::linear::Linear;
     #[cfg(feature = "nightly")]
@@ -162,6 +189,7 @@ pub mod modules {
     pub use super::pool_global::{AvgPoolGlobal, MaxPoolGlobal, MinPoolGlobal};
     pub use super::repeated::Repeated;
     pub use super::residual::Residual;
+    pub use super::rms_norm::RmsNorm;
     pub use super::split_into::SplitInto;
     #[cfg(feature = "nightly")]
     pub use super::transformer::*;
@@ -175,13 +203,15 @@ pub mod builders {
     pub use super::add_into::AddInto;
     pub use super::batchnorm2d::builder::BatchNorm2D;
     pub use super::bias2d::builder::Bias2D;
+    pub use super::bilinear::builder::Bilinear;
     #[cfg(feature = "nightly")]
     pub use super::conv::builder::Conv2D;
-    pub use super::dropout::{Dropout, DropoutOneIn};
+    pub use super::dropout::{Dropout, DropoutOneIn, SeededDropout, SpatialDropout2D};
     pub use super::embedding::builder::Embedding;
     #[cfg(feature = "nightly")]
     pub use super::flatten::Flatten2D;
     pub use super::generalized_residual::GeneralizedResidual;
+    pub use super::instance_norm::builder::InstanceNorm2D;
     pub use super::layer_norm::builder::LayerNorm1D;
     pub use super::linear::builder::Linear;
     #[cfg(feature = "nightly")]
@@ -189,6 +219,7 @@ pub mod builders {
     pub use super::pool_global::{AvgPoolGlobal, MaxPoolGlobal, MinPoolGlobal};
     pub use super::repeated::Repeated;
     pub use super::residual::Residual;
+    pub use super::rms_norm::builder::RmsNorm;
     pub use super::split_into::SplitInto;
     #[cfg(feature = "nightly")]
     pub use super::transformer::builder::*;
@@ -0,0 +1,751 @@
+mod proto;
+
+use crate::{
+    shapes::{Dtype, HasShape, Shape},
+    tensor::{DeviceStorage, Tensor},
+};
+
+use super::{
+    activations::*,
+    bias2d::Bias2D,
+    linear::Linear,
+    residual::Residual,
+    tensor_collection::{
+        RecursiveWalker, TensorCollection, TensorOptions, TensorVisitor, ViewTensorRef,
+    },
+    unbiased_linear::UnbiasedLinear,
+    Module,
+};
+
+#[cfg(feature = "nightly")]
+use super::{conv::Conv2D, pool2d::*};
+
+use std::{
+    format,
+    path::Path,
+    string::{String, ToString},
+    vec,
+    vec::Vec,
+};
+
+/// Something that can be exported to an [ONNX](https://onnx.ai/) graph.
+///
+/// The initializers of the graph are the tensors of the module, found by walking its
+/// [TensorCollection] and named by their path, e.g. `0.weight` for the weight of the first
+/// module in a tuple. The nodes are built from the structure of the module, e.g. a tuple of
+/// modules becomes each of its modules applied in order, and [Linear] becomes a `MatMul` with
+/// its transposed weight followed by an `Add`.
+///
+/// Supported modules are [Linear], [UnbiasedLinear], [Bias2D], [Residual], [Softmax], most
+/// activations, and tuples of these. With the "nightly" feature `Conv2D`, `AvgPool2D` and
+/// `MaxPool2D` are also supported, for both batched and unbatched images.
+///
+/// Example:
+/// ```rust
+/// # use dfdx::prelude::*;
+/// # let dev: Cpu = Default::default();
+/// type Model = (Linear<3, 4>, ReLU, Linear<4, 2>);
+/// let model = dev.build_module::<Model, f32>();
+/// let x: Tensor<Rank2<8, 3>, f32, _> = dev.zeros();
+/// let onnx = model.to_onnx(x);
+/// assert_eq!(onnx.graph.nodes.len(), 7);
+/// assert_eq!(onnx.graph.initializers[0].name, "0.weight");
+/// // onnx.save("model.onnx").unwrap();
+/// ```
+pub trait ExportOnnx<E: OnnxDtype, D: DeviceStorage> {
+    /// Adds the operations of this module to `graph`, applied to the value named `input`.
+    /// Returns the name of the output value. The tensors of this module are already in
+    /// `graph`, see [OnnxGraphBuilder::tensor].
+    fn add_to_onnx(
+        &self,
+        graph: &mut OnnxGraphBuilder<E>,
+        input: String,
+    ) -> Result<String, OnnxExportError<D>>;
+
+    /// Exports this module as an [OnnxModel] with the shape of `x` as the input shape.
+    /// `x` is passed through the module to find the output shape.
+    fn to_onnx<S: Shape, O: Shape>(&self, x: Tensor<S, E, D>) -> OnnxModel
+    where
+        Self: TensorCollection<E, D>
+            + Module<Tensor<S, E, D>, Output = Tensor<O, E, D>, Error = D::Err>,
+    {
+        self.try_to_onnx(x).unwrap()
+    }
+
+    /// Fallible version of [ExportOnnx::to_onnx]
+    fn try_to_onnx<S: Shape, O: Shape>(
+        &self,
+        x: Tensor<S, E, D>,
+    ) -> Result<OnnxModel, OnnxExportError<D>>
+    where
+        Self: TensorCollection<E, D>
+            + Module<Tensor<S, E, D>, Output = Tensor<O, E, D>, Error = D::Err>,
+    {
+        let input = OnnxValue {
+            name: "input".to_string(),
+            dims: x.shape().concrete().into_iter().collect(),
+        };
+        let y = self.try_forward(x).map_err(OnnxExportError::DeviceError)?;
+
+        let mut builder = OnnxGraphBuilder {
+            nodes: Vec::new(),
+            initializers: Vec::new(),
+            path: Vec::new(),
+            rank: S::NUM_DIMS,
+            marker: std::marker::PhantomData,
+        };
+        Self::iter_tensors(&mut RecursiveWalker {
+            m: self,
+            f: &mut builder,
+            path: &mut Vec::new(),
+        })
+        .map_err(OnnxExportError::DeviceError)?;
+        let output = self.add_to_onnx(&mut builder, input.name.clone())?;
+        Ok(OnnxModel {
+            graph: OnnxGraph {
+                nodes: builder.nodes,
+                initializers: builder.initializers,
+                input,
+                output: OnnxValue {
+                    name: output,
+                    dims: y.shape().concrete().into_iter().collect(),
+                },
+                data_type: E::ONNX_DATA_TYPE,
+            },
+        })
+    }
+}
+
+/// An exported ONNX model. See [ExportOnnx].
+#[derive(Debug, Clone)]
+pub struct OnnxModel {
+    pub graph: OnnxGraph,
+}
+
+impl OnnxModel {
+    /// Encodes the model as an `onnx.ModelProto`.
+    pub fn to_bytes(&self) -> Vec<u8> {
+        proto::encode_model(self)
+    }
+
+    /// Saves the model to a `.onnx` file located at `path`.
+    pub fn save<P: AsRef<Path>>(&self, path: P) -> std::io::Result<()> {
+        std::fs::write(path, self.to_bytes())
+    }
+}
+
+/// The graph of an [OnnxModel], with a single input & output.
+#[derive(Debug, Clone)]
+pub struct OnnxGraph {
+    pub nodes: Vec<OnnxNode>,
+    pub initializers: Vec<OnnxInitializer>,
+    pub input: OnnxValue,
+    pub output: OnnxValue,
+    /// The `onnx.TensorProto.DataType` of the input, output & tensors of the module.
+    pub data_type: i32,
+}
+
+/// A single operation in an [OnnxGraph].
+#[derive(Debug, Clone)]
+pub struct OnnxNode {
+    pub name: String,
+    pub op_type: String,
+    pub inputs: Vec<String>,
+    pub outputs: Vec<String>,
+    pub attributes: Vec<(String, OnnxAttribute)>,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum OnnxAttribute {
+    Int(i64),
+    Ints(Vec<i64>),
+}
+
+/// A named constant tensor (e.g. a weight) in an [OnnxGraph].
+#[derive(Debug, Clone)]
+pub struct OnnxInitializer {
+    pub name: String,
+    pub dims: Vec<usize>,
+    pub data_type: i32,
+    /// The data in little endian order.
+    pub raw_data: Vec<u8>,
+}
+
+/// A named input or output of an [OnnxGraph].
+#[derive(Debug, Clone)]
+pub struct OnnxValue {
+    pub name: String,
+    pub dims: Vec<usize>,
+}
+
+/// Error that can happen while exporting a module with [ExportOnnx].
+#[derive(Debug)]
+pub enum OnnxExportError<D: DeviceStorage> {
+    /// The ONNX operator `op` can't be applied to an input with `rank` dimensions.
+    UnsupportedRank {
+        op: &'static str,
+        rank: usize,
+    },
+    DeviceError(D::Err),
+}
+
+impl<D: DeviceStorage> std::fmt::Display for OnnxExportError<D> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::UnsupportedRank { op, rank } => {
+                write!(f, "{op} doesn't support inputs with {rank} dimensions")
+            }
+            Self::DeviceError(err) => write!(f, "{err}"),
+        }
+    }
+}
+
+impl<D: DeviceStorage + std::fmt::Debug> std::error::Error for OnnxExportError<D> {}
+
+/// Adds nodes & initializers to an [OnnxGraph].
+///
+/// The tensors of the exported module are added as initializers before any nodes, by
+/// walking the module with [RecursiveWalker]. [OnnxGraphBuilder::add_module] keeps track of
+/// the path of the current module, so that [OnnxGraphBuilder::tensor] can find its tensors.
+#[derive(Debug)]
+pub struct OnnxGraphBuilder<E> {
+    nodes: Vec<OnnxNode>,
+    initializers: Vec<OnnxInitializer>,
+    path: Vec<String>,
+    rank: usize,
+    marker: std::marker::PhantomData<E>,
+}
+
+impl<E: OnnxDtype, D: DeviceStorage> TensorVisitor<E, D> for OnnxGraphBuilder<E> {
+    type Viewer = ViewTensorRef;
+    type Err = D::Err;
+
+    fn visit<S: Shape>(
+        &mut self,
+        full_path: String,
+        _: TensorOptions<S, E, D>,
+        t: &Tensor<S, E, D>,
+    ) -> Result<(), Self::Err> {
+        let data = t.as_vec();
+        let mut raw_data = Vec::with_capacity(std::mem::size_of_val(data.as_slice()));
+        for v in data.iter() {
+            v.write_le_bytes(&mut raw_data);
+        }
+        self.initializers.push(OnnxInitializer {
+            name: full_path,
+            dims: t.shape().concrete().into_iter().collect(),
+            data_type: E::ONNX_DATA_TYPE,
+            raw_data,
+        });
+        Ok(())
+    }
+}
+
+impl<E: OnnxDtype> OnnxGraphBuilder<E> {
+    /// The number of dimensions of the values in the graph. All the supported operations
+    /// keep the rank of their input, so this is the rank of the graph's input.
+    pub fn rank(&self) -> usize {
+        self.rank
+    }
+
+    /// Adds a node that applies `op_type` to `inputs`, and returns the name of its output.
+    pub fn add_node(
+        &mut self,
+        op_type: &str,
+        inputs: Vec<String>,
+        attributes: Vec<(String, OnnxAttribute)>,
+    ) -> String {
+        let name = format!("{op_type}_{}", self.nodes.len());
+        self.nodes.push(OnnxNode {
+            name: name.clone(),
+            op_type: op_type.into(),
+            inputs,
+            outputs: vec![name.clone()],
+            attributes,
+        });
+        name
+    }
+
+    /// Returns the full name of the current module's tensor called `name` (as named by
+    /// its [TensorCollection]).
+    ///
+    /// **Panics** if the module has no such tensor.
+    pub fn tensor(&self, name: &str) -> String {
+        let name = self.full_name(name);
+        assert!(
+            self.initializers.iter().any(|i| i.name == name),
+            "no tensor named {name}"
+        );
+        name
+    }
+
+    /// Adds a constant `int64` tensor, such as the `shape` input of a `Reshape`, and
+    /// returns its full name.
+    pub fn add_i64s(&mut self, name: &str, data: &[i64]) -> String {
+        let name = self.full_name(name);
+        if !self.initializers.iter().any(|i| i.name == name) {
+            self.initializers.push(OnnxInitializer {
+                name: name.clone(),
+                dims: vec![data.len()],
+                data_type: 7,
+                raw_data: data.iter().flat_map(|v| v.to_le_bytes()).collect(),
+            });
+        }
+        name
+    }
+
+    /// Adds `module` to the graph as the field `name` of the current module.
+    pub fn add_module<D: DeviceStorage, M: ExportOnnx<E, D>>(
+        &mut self,
+        name: &str,
+        module: &M,
+        input: String,
+    ) -> Result<String, OnnxExportError<D>> {
+        self.path.push(name.into());
+        let output = module.add_to_onnx(self, input);
+        self.path.pop();
+        output
+    }
+
+    fn full_name(&self, name: &str) -> String {
+        let mut path = self.path.clone();
+        path.push(name.into());
+        path.join(".")
+    }
+}
+
+/// The `onnx.TensorProto.DataType` of a [Dtype].
+pub trait OnnxDtype: Dtype {
+    const ONNX_DATA_TYPE: i32;
+    fn write_le_bytes(&self, buf: &mut Vec<u8>);
+}
+
+impl OnnxDtype for f32 {
+    const ONNX_DATA_TYPE: i32 = 1;
+    fn write_le_bytes(&self, buf: &mut Vec<u8>) {
+        buf.extend_from_slice(&self.to_le_bytes());
+    }
+}
+
+impl OnnxDtype for f64 {
+    const ONNX_DATA_TYPE: i32 = 11;
+    fn write_le_bytes(&self, buf: &mut Vec<u8>) {
+        buf.extend_from_slice(&self.to_le_bytes());
+    }
+}
+
+#[cfg(feature = "nightly")]
+fn ints(vals: &[usize]) -> OnnxAttribute {
+    OnnxAttribute::Ints(vals.iter().map(|&v| v as i64).collect())
+}
+
+/// Adds `op_type`, which ONNX only defines for batched `(B, C, H, W)` images. Unbatched
+/// `(C, H, W)` images are unsqueezed into a batch of 1 first, and squeezed after. Any
+/// other rank is an [OnnxExportError::UnsupportedRank].
+#[cfg(feature = "nightly")]
+fn add_image_node<E: OnnxDtype, D: DeviceStorage>(
+    graph: &mut OnnxGraphBuilder<E>,
+    op_type: &'static str,
+    mut inputs: Vec<String>,
+    attributes: Vec<(String, OnnxAttribute)>,
+) -> Result<String, OnnxExportError<D>> {
+    match graph.rank() {
+        4 => Ok(graph.add_node(op_type, inputs, attributes)),
+        3 => {
+            let axes = graph.add_i64s("batch_axis", &[0]);
+            inputs[0] = graph.add_node("Unsqueeze", vec![inputs[0].clone(), axes.clone()], vec![]);
+            let x = graph.add_node(op_type, inputs, attributes);
+            Ok(graph.add_node("Squeeze", vec![x, axes], vec![]))
+        }
+        rank => Err(OnnxExportError::UnsupportedRank { op: op_type, rank }),
+    }
+}
+
+/// Adds a `MatMul` with the `(O, I)` weight of the current module. MatMul needs the
+/// weight as `(I, O)`, so a `Transpose` is added before it.
+fn add_matmul<E: OnnxDtype>(graph: &mut OnnxGraphBuilder<E>, input: String) -> String {
+    let weight = graph.tensor("weight");
+    let weight = graph.add_node("Transpose", vec![weight], Vec::new());
+    graph.add_node("MatMul", vec![input, weight], Vec::new())
+}
+
+impl<const I: usize, const O: usize, E: OnnxDtype, D: DeviceStorage> ExportOnnx<E, D>
+    for Linear<I, O, E, D>
+{
+    fn add_to_onnx(
+        &self,
+        graph: &mut OnnxGraphBuilder<E>,
+        input: String,
+    ) -> Result<String, OnnxExportError<D>> {
+        let x = add_matmul(graph, input);
+        let bias = graph.tensor("bias");
+        Ok(graph.add_node("Add", vec![x, bias], Vec::new()))
+    }
+}
+
+impl<const I: usize, const O: usize, E: OnnxDtype, D: DeviceStorage> ExportOnnx<E, D>
+    for UnbiasedLinear<I, O, E, D>
+{
+    fn add_to_onnx(
+        &self,
+        graph: &mut OnnxGraphBuilder<E>,
+        input: String,
+    ) -> Result<String, OnnxExportError<D>> {
+        Ok(add_matmul(graph, input))
+    }
+}
+
+impl<const C: usize, E: OnnxDtype, D: DeviceStorage> ExportOnnx<E, D> for Bias2D<C, E, D> {
+    fn add_to_onnx(
+        &self,
+        graph: &mut OnnxGraphBuilder<E>,
+        input: String,
+    ) -> Result<String, OnnxExportError<D>> {
+        // the (C,) bias is reshaped to (C, 1, 1) to broadcast over the image
+        let bias = graph.tensor("beta");
+        let shape = graph.add_i64s("beta_shape", &[C as i64, 1, 1]);
+        let bias = graph.add_node("Reshape", vec![bias, shape], Vec::new());
+        Ok(graph.add_node("Add", vec![input, bias], Vec::new()))
+    }
+}
+
+#[cfg(feature = "nightly")]
+impl<const I: usize, const O: usize, const K: usize, const S: usize, const P: usize, E, D>
+    ExportOnnx<E, D> for Conv2D<I, O, K, S, P, E, D>
+where
+    E: OnnxDtype,
+    D: DeviceStorage,
+{
+    fn add_to_onnx(
+        &self,
+        graph: &mut OnnxGraphBuilder<E>,
+        input: String,
+    ) -> Result<String, OnnxExportError<D>> {
+        let weight = graph.tensor("weight");
+        let attributes = vec![
+            ("kernel_shape".into(), ints(&[K, K])),
+            ("strides".into(), ints(&[S, S])),
+            ("pads".into(), ints(&[P, P, P, P])),
+        ];
+        add_image_node(graph, "Conv", vec![input, weight], attributes)
+    }
+}
+
+#[cfg(feature = "nightly")]
+impl<const K: usize, const S: usize, const P: usize, E: OnnxDtype, D: DeviceStorage>
+    ExportOnnx<E, D> for AvgPool2D<K, S, P>
+{
+    fn add_to_onnx(
+        &self,
+        graph: &mut OnnxGraphBuilder<E>,
+        input: String,
+    ) -> Result<String, OnnxExportError<D>> {
+        let attributes = vec![
+            ("kernel_shape".into(), ints(&[K, K])),
+            ("strides".into(), ints(&[S, S])),
+            ("pads".into(), ints(&[P, P, P, P])),
+            ("count_include_pad".into(), OnnxAttribute::Int(1)),
+        ];
+        add_image_node(graph, "AveragePool", vec![input], attributes)
+    }
+}
+
+#[cfg(feature = "nightly")]
+impl<const K: usize, const S: usize, const P: usize, E: OnnxDtype, D: DeviceStorage>
+    ExportOnnx<E, D> for MaxPool2D<K, S, P>
+{
+    fn add_to_onnx(
+        &self,
+        graph: &mut OnnxGraphBuilder<E>,
+        input: String,
+    ) -> Result<String, OnnxExportError<D>> {
+        let attributes = vec![
+            ("kernel_shape".into(), ints(&[K, K])),
+            ("strides".into(), ints(&[S, S])),
+            ("pads".into(), ints(&[P, P, P, P])),
+        ];
+        add_image_node(graph, "MaxPool", vec![input], attributes)
+    }
+}
+
+impl<E: OnnxDtype, D: DeviceStorage, F: ExportOnnx<E, D>> ExportOnnx<E, D> for Residual<F> {
+    fn add_to_onnx(
+        &self,
+        graph: &mut OnnxGraphBuilder<E>,
+        input: String,
+    ) -> Result<String, OnnxExportError<D>> {
+        let x = graph.add_module("0", &self.0, input.clone())?;
+        Ok(graph.add_node("Add", vec![x, input], Vec::new()))
+    }
+}
+
+impl<E: OnnxDtype, D: DeviceStorage> ExportOnnx<E, D> for Softmax {
+    fn add_to_onnx(
+        &self,
+        graph: &mut OnnxGraphBuilder<E>,
+        input: String,
+    ) -> Result<String, OnnxExportError<D>> {
+        let attributes = vec![("axis".into(), OnnxAttribute::Int(-1))];
+        Ok(graph.add_node("Softmax", vec![input], attributes))
+    }
+}
+
+macro_rules! activation_exports {
+    ($($Activation:ty => $OpType:literal),+) => {
+        $(
+            impl<E: OnnxDtype, D: DeviceStorage> ExportOnnx<E, D> for $Activation {
+                fn add_to_onnx(
+                    &self,
+                    graph: &mut OnnxGraphBuilder<E>,
+                    input: String,
+                ) -> Result<String, OnnxExportError<D>> {
+                    Ok(graph.add_node($OpType, vec![input], Vec::new()))
+                }
+            }
+        )+
+    };
+}
+
+activation_exports!(
+    ReLU => "Relu",
+    Sigmoid => "Sigmoid",
+    Tanh => "Tanh",
+    Sin => "Sin",
+    Cos => "Cos",
+    Exp => "Exp",
+    Ln => "Log",
+    Abs => "Abs",
+    Sqrt => "Sqrt"
+);
+
+macro_rules! tuple_exports {
+    ([$($name:ident),+] [$($idx:tt),+]) => {
+        impl<E: OnnxDtype, D: DeviceStorage, $($name: ExportOnnx<E, D>),+> ExportOnnx<E, D>
+            for ($($name,)+)
+        {
+            fn add_to_onnx(
+                &self,
+                graph: &mut OnnxGraphBuilder<E>,
+                input: String,
+            ) -> Result<String, OnnxExportError<D>> {
+                $(let input = graph.add_module(stringify!($idx), &self.$idx, input)?;)+
+                Ok(input)
+            }
+        }
+    };
+}
+
+tuple_exports!([M1][0]);
+tuple_exports!([M1, M2] [0, 1]);
+tuple_exports!([M1, M2, M3] [0, 1, 2]);
+tuple_exports!([M1, M2, M3, M4] [0, 1, 2, 3]);
+tuple_exports!([M1, M2, M3, M4, M5] [0, 1, 2, 3, 4]);
+tuple_exports!([M1, M2, M3, M4, M5, M6] [0, 1, 2, 3, 4, 5]);
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{nn::builders, nn::DeviceBuildExt, shapes::*, tensor::*, tests::*};
+
+    /// Splits a protobuf message into its `(field number, value)` pairs, where values of
+    /// length delimited fields are the bytes of the field.
+    fn fields(mut buf: &[u8]) -> Vec<(u64, Result<u64, &[u8]>)> {
+        fn varint(buf: &mut &[u8]) -> u64 {
+            let mut v = 0;
+            let mut shift = 0;
+            loop {
+                let b = buf[0];
+                *buf = &buf[1..];
+                v |= ((b & 0x7f) as u64) << shift;
+                shift += 7;
+                if b < 0x80 {
+                    return v;
+                }
+            }
+        }
+        let mut out = Vec::new();
+        while !buf.is_empty() {
+            let key = varint(&mut buf);
+            match key & 7 {
+                0 => out.push((key >> 3, Ok(varint(&mut buf)))),
+                2 => {
+                    let len = varint(&mut buf) as usize;
+                    out.push((key >> 3, Err(&buf[..len])));
+                    buf = &buf[len..];
+                }
+                _ => panic!("unexpected wire type"),
+            }
+        }
+        out
+    }
+
+    fn field(buf: &[u8], number: u64) -> Vec<Result<u64, &[u8]>> {
+        fields(buf)
+            .into_iter()
+            .filter(|(n, _)| *n == number)
+            .map(|(_, v)| v)
+            .collect()
+    }
+
+    #[test]
+    fn test_export_mlp() {
+        let dev: TestDevice = Default::default();
+        type Model = (
+            builders::Linear<3, 4>,
+            ReLU,
+            builders::Linear<4, 2>,
+            Softmax,
+        );
+        let model = dev.build_module::<Model, TestDtype>();
+        let x: Tensor<Rank2<5, 3>, TestDtype, _> = dev.zeros();
+        let onnx = model.to_onnx(x);
+
+        let graph = &onnx.graph;
+        let ops: Vec<&str> = graph.nodes.iter().map(|n| n.op_type.as_str()).collect();
+        assert_eq!(
+            ops,
+            [
+                "Transpose",
+                "MatMul",
+                "Add",
+                "Relu",
+                "Transpose",
+                "MatMul",
+                "Add",
+                "Softmax"
+            ]
+        );
+        let inits: Vec<(&str, &[usize])> = graph
+            .initializers
+            .iter()
+            .map(|i| (i.name.as_str(), i.dims.as_slice()))
+            .collect();
+        assert_eq!(
+            inits,
+            [
+                ("0.weight", [4, 3].as_slice()),
+                ("0.bias", &[4]),
+                ("2.weight", &[2, 4]),
+                ("2.bias", &[2])
+            ]
+        );
+        assert_eq!(graph.input.dims, [5, 3]);
+        assert_eq!(graph.output.dims, [5, 2]);
+        assert_eq!(graph.output.name, graph.nodes[7].outputs[0]);
+
+        // the weight is stored as is, and transposed by the graph
+        assert_eq!(graph.nodes[0].inputs, ["0.weight"]);
+        assert_eq!(graph.nodes[1].inputs, ["input", "Transpose_0"]);
+        let w = model.0.weight.array();
+        let mut raw = Vec::new();
+        w[0][1].write_le_bytes(&mut raw);
+        let size = std::mem::size_of::<TestDtype>();
+        assert_eq!(&graph.initializers[0].raw_data[size..2 * size], &raw);
+
+        let bytes = onnx.to_bytes();
+        assert_eq!(field(&bytes, 1), [Ok(8)]);
+        let graph_bytes = match field(&bytes, 7)[..] {
+            [Err(g)] => g,
+            _ => panic!("expected a single graph"),
+        };
+        assert_eq!(field(graph_bytes, 1).len(), 8);
+        let inits = field(graph_bytes, 5);
+        assert_eq!(inits.len(), 4);
+        for (init, expected) in inits.iter().zip([[4, 3].as_slice(), &[4], &[2, 4], &[2]]) {
+            let dims: Vec<_> = field(init.unwrap_err(), 1)
+                .into_iter()
+                .map(|d| d.unwrap() as usize)
+                .collect();
+            assert_eq!(dims, expected);
+            let raw = field(init.unwrap_err(), 9);
+            let numel: usize = expected.iter().product();
+            assert_eq!(raw[0].unwrap_err().len(), numel * size);
+        }
+    }
+
+    #[test]
+    fn test_export_residual() {
+        let dev: TestDevice = Default::default();
+        type Model = Residual<(builders::Linear<3, 3>, Tanh)>;
+        let model = dev.build_module::<Model, TestDtype>();
+        let x: Tensor<Rank1<3>, TestDtype, _> = dev.zeros();
+        let graph = model.to_onnx(x).graph;
+        let ops: Vec<&str> = graph.nodes.iter().map(|n| n.op_type.as_str()).collect();
+        assert_eq!(ops, ["Transpose", "MatMul", "Add", "Tanh", "Add"]);
+        assert_eq!(graph.nodes[4].inputs, ["Tanh_3", "input"]);
+        assert_eq!(graph.initializers[0].name, "0.0.weight");
+    }
+
+    #[cfg(feature = "nightly")]
+    #[test]
+    fn test_export_conv() {
+        let dev: TestDevice = Default::default();
+        type Model = (
+            builders::Conv2D<1, 2, 3>,
+            builders::Bias2D<2>,
+            ReLU,
+            MaxPool2D<2, 2>,
+        );
+        let model = dev.build_module::<Model, TestDtype>();
+        let x: Tensor<Rank4<4, 1, 8, 8>, TestDtype, _> = dev.zeros();
+        let graph = model.to_onnx(x).graph;
+        let ops: Vec<&str> = graph.nodes.iter().map(|n| n.op_type.as_str()).collect();
+        assert_eq!(ops, ["Conv", "Reshape", "Add", "Relu", "MaxPool"]);
+        assert_eq!(graph.initializers[0].dims, [2, 1, 3, 3]);
+        assert_eq!(graph.initializers[1].dims, [2]);
+        assert_eq!(graph.initializers[2].name, "1.beta_shape");
+        assert_eq!(graph.initializers[2].data_type, 7);
+        assert_eq!(graph.output.dims, [4, 2, 3, 3]);
+        assert_eq!(
+            graph.nodes[4].attributes[0],
+            ("kernel_shape".into(), OnnxAttribute::Ints(vec![2, 2]))
+        );
+    }
+
+    #[cfg(feature = "nightly")]
+    #[test]
+    fn test_export_unbatched_conv() {
+        let dev: TestDevice = Default::default();
+        type Model = (builders::Conv2D<1, 2, 3>, AvgPool2D<2, 2>);
+        let model = dev.build_module::<Model, TestDtype>();
+        let x: Tensor<Rank3<1, 8, 8>, TestDtype, _> = dev.zeros();
+        let graph = model.to_onnx(x).graph;
+        let ops: Vec<&str> = graph.nodes.iter().map(|n| n.op_type.as_str()).collect();
+        assert_eq!(
+            ops,
+            [
+                "Unsqueeze",
+                "Conv",
+                "Squeeze",
+                "Unsqueeze",
+                "AveragePool",
+                "Squeeze"
+            ]
+        );
+        assert_eq!(graph.nodes[0].inputs, ["input", "0.batch_axis"]);
+        assert_eq!(graph.nodes[3].inputs, ["Squeeze_2", "1.batch_axis"]);
+        assert_eq!(graph.output.dims, [2, 3, 3]);
+    }
+
+    #[cfg(feature = "nightly")]
+    #[test]
+    fn test_export_pool_unsupported_rank() {
+        let mut graph = OnnxGraphBuilder::<TestDtype> {
+            nodes: Vec::new(),
+            initializers: Vec::new(),
+            path: Vec::new(),
+            rank: 2,
+            marker: std::marker::PhantomData,
+        };
+        let result: Result<_, OnnxExportError<TestDevice>> =
+            graph.add_module("pool", &MaxPool2D::<2, 2>::default(), "input".into());
+        assert!(matches!(
+            result,
+            Err(OnnxExportError::UnsupportedRank {
+                op: "MaxPool",
+                rank: 2
+            })
+        ));
+        assert!(graph.nodes.is_empty());
+    }
+}
@@ -0,0 +1,137 @@
+//! Hand written encoding of the subset of the [onnx protobuf](https://github.com/onnx/onnx/blob/main/onnx/onnx.proto)
+//! messages used by [super::OnnxModel].
+
+use std::vec::Vec;
+
+use super::{OnnxAttribute, OnnxGraph, OnnxInitializer, OnnxModel, OnnxNode, OnnxValue};
+
+const IR_VERSION: u64 = 8;
+const OPSET_VERSION: u64 = 13;
+
+/// Appends protobuf fields to a buffer.
+#[derive(Default)]
+struct Writer {
+    buf: Vec<u8>,
+}
+
+impl Writer {
+    fn varint(&mut self, mut v: u64) {
+        while v >= 0x80 {
+            self.buf.push((v as u8) | 0x80);
+            v >>= 7;
+        }
+        self.buf.push(v as u8);
+    }
+
+    fn key(&mut self, field: u64, wire_type: u64) {
+        self.varint((field << 3) | wire_type);
+    }
+
+    fn int(&mut self, field: u64, v: i64) {
+        self.key(field, 0);
+        self.varint(v as u64);
+    }
+
+    fn bytes(&mut self, field: u64, v: &[u8]) {
+        self.key(field, 2);
+        self.varint(v.len() as u64);
+        self.buf.extend_from_slice(v);
+    }
+
+    fn string(&mut self, field: u64, v: &str) {
+        self.bytes(field, v.as_bytes());
+    }
+
+    fn message(&mut self, field: u64, f: impl FnOnce(&mut Writer)) {
+        let mut inner = Writer::default();
+        f(&mut inner);
+        self.bytes(field, &inner.buf);
+    }
+}
+
+pub(super) fn encode_model(model: &OnnxModel) -> Vec<u8> {
+    // ModelProto
+    let mut w = Writer::default();
+    w.int(1, IR_VERSION as i64);
+    w.string(2, "dfdx");
+    w.message(7, |w| encode_graph(w, &model.graph));
+    w.message(8, |w| {
+        // OperatorSetIdProto
+        w.string(1, "");
+        w.int(2, OPSET_VERSION as i64);
+    });
+    w.buf
+}
+
+fn encode_graph(w: &mut Writer, graph: &OnnxGraph) {
+    // GraphProto
+    for node in graph.nodes.iter() {
+        w.message(1, |w| encode_node(w, node));
+    }
+    w.string(2, "dfdx");
+    for init in graph.initializers.iter() {
+        w.message(5, |w| encode_initializer(w, init));
+    }
+    w.message(11, |w| encode_value(w, &graph.input, graph.data_type));
+    w.message(12, |w| encode_value(w, &graph.output, graph.data_type));
+}
+
+fn encode_node(w: &mut Writer, node: &OnnxNode) {
+    // NodeProto
+    for input in node.inputs.iter() {
+        w.string(1, input);
+    }
+    for output in node.outputs.iter() {
+        w.string(2, output);
+    }
+    w.string(3, &node.name);
+    w.string(4, &node.op_type);
+    for (name, attr) in node.attributes.iter() {
+        w.message(5, |w| encode_attribute(w, name, attr));
+    }
+}
+
+fn encode_attribute(w: &mut Writer, name: &str, attr: &OnnxAttribute) {
+    // AttributeProto
+    w.string(1, name);
+    match attr {
+        OnnxAttribute::Int(i) => {
+            w.int(3, *i);
+            w.int(20, 2);
+        }
+        OnnxAttribute::Ints(ints) => {
+            for i in ints.iter() {
+                w.int(8, *i);
+            }
+            w.int(20, 7);
+        }
+    }
+}
+
+fn encode_initializer(w: &mut Writer, init: &OnnxInitializer) {
+    // TensorProto
+    for &d in init.dims.iter() {
+        w.int(1, d as i64);
+    }
+    w.int(2, init.data_type as i64);
+    w.string(8, &init.name);
+    w.bytes(9, &init.raw_data);
+}
+
+fn encode_value(w: &mut Writer, value: &OnnxValue, data_type: i32) {
+    // ValueInfoProto
+    w.string(1, &value.name);
+    w.message(2, |w| {
+        // TypeProto
+        w.message(1, |w| {
+            // TypeProto.Tensor
+            w.int(1, data_type as i64);
+            w.message(2, |w| {
+                // TensorShapeProto
+                for &d in value.dims.iter() {
+                    w.message(1, |w| w.int(1, d as i64));
+                }
+            });
+        });
+    });
+}
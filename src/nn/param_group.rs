@@ -0,0 +1,52 @@
+use super::tensor_collection::{
+    RecursiveWalker, TensorCollection, TensorOptions, TensorVisitor, ViewTensorMut,
+};
+
+use crate::{shapes::*, tensor::*};
+
+use std::{convert::Infallible, string::String, vec::Vec};
+
+struct GroupSetter(Option<&'static str>);
+impl<E: Dtype, D: DeviceStorage> TensorVisitor<E, D> for GroupSetter {
+    type Viewer = ViewTensorMut;
+    type Err = Infallible;
+
+    fn visit<S: Shape>(
+        &mut self,
+        _: String,
+        _: TensorOptions<S, E, D>,
+        t: &mut Tensor<S, E, D>,
+    ) -> Result<(), Self::Err> {
+        t.param_opts.group = self.0;
+        Ok(())
+    }
+}
+
+/// Puts all the tensors of `module` in the [crate::optim::ParamGroup] called `group`.
+/// This is stored with the tensors, and overrides the [TensorOptions::group] set by
+/// the module. Passing `None` removes the tensors from the group set here.
+///
+/// ```rust
+/// # use dfdx::{prelude::*, optim::*};
+/// # let dev: Cpu = Default::default();
+/// type Model = (Linear<2, 3>, ReLU, Linear<3, 2>);
+/// let mut model = dev.build_module::<Model, f32>();
+/// set_param_group(&mut model.0, Some("backbone"));
+/// let mut opt: Sgd<_, f32, Cpu> = Sgd::new(&model, Default::default());
+/// // model.0 is updated with a learning rate of 1e-4, and model.2 with the default
+/// opt.param_groups_mut().push(ParamGroup::new("backbone", 1e-4, None));
+/// ```
+pub fn set_param_group<E: Dtype, D: DeviceStorage, M: TensorCollection<E, D>>(
+    module: &mut M,
+    group: Option<&'static str>,
+) {
+    let result = M::iter_tensors(&mut RecursiveWalker {
+        m: module,
+        f: &mut GroupSetter(group),
+        path: &mut Vec::new(),
+    });
+    match result {
+        Ok(()) => (),
+        Err(e) => match e {},
+    }
+}
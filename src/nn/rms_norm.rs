@@ -0,0 +1,193 @@
+use crate::{gradients::Tape, shapes::*, tensor::*, tensor_ops::*};
+
+use super::{tensor_collection::*, BuildModule, BuildOnDevice, Module, NonMutableModule, ToDevice};
+
+pub mod builder {
+    #[derive(Debug)]
+    pub struct RmsNorm<const M: usize>;
+}
+impl<const M: usize, E: Dtype, D: Device<E>> BuildOnDevice<D, E> for builder::RmsNorm<M>
+where
+    RmsNorm<M, E, D>: BuildModule<D, E>,
+{
+    type Built = RmsNorm<M, E, D>;
+    fn try_build_on_device(device: &D) -> Result<Self::Built, D::Err> {
+        Self::Built::try_build(device)
+    }
+}
+
+/// Implements root mean square layer normalization as described in
+/// [Root Mean Square Layer Normalization](https://arxiv.org/abs/1910.07467).
+///
+/// This divides the input by the root mean square of its last axis, `x / sqrt(mean(x^2) + epsilon)`,
+/// and then scales element-wise by the learnable [Self::gamma]. Unlike [super::modules::LayerNorm1D]
+/// the mean is not subtracted, and there is no bias.
+///
+/// [Self::epsilon] is added to the mean square to ensure big enough numbers. It defaults to `1e-5`.
+///
+/// # Generics
+/// - `M` The size of the scale tensor.
+///
+/// # Examples
+/// ```rust
+/// # use dfdx::prelude::*;
+/// # let dev: Cpu = Default::default();
+/// type Model = RmsNorm<5>;
+/// let model = dev.build_module::<Model, f32>();
+/// let _: Tensor<Rank1<5>, f32, _> = model.forward(dev.zeros::<Rank1<5>>());
+/// ```
+#[derive(Debug, Clone)]
+pub struct RmsNorm<const M: usize, E: Dtype, D: DeviceStorage> {
+    pub gamma: Tensor<Rank1<M>, E, D>,
+    pub epsilon: E,
+}
+
+impl<const M: usize, E: Dtype, D: DeviceStorage> NonMutableModule for RmsNorm<M, E, D> {}
+
+impl<const M: usize, E: Dtype, D: Device<E>> BuildModule<D, E> for RmsNorm<M, E, D> {
+    /// Fills [Self::gamma] with 1s and sets [Self::epsilon] to `1e-5`.
+    fn try_build(device: &D) -> Result<Self, D::Err> {
+        Ok(Self {
+            gamma: device.try_ones()?,
+            epsilon: E::from_f32(1e-5).unwrap(),
+        })
+    }
+}
+
+impl<const M: usize, E: Dtype, D: Device<E>> TensorCollection<E, D> for RmsNorm<M, E, D> {
+    fn iter_tensors<V: ModuleVisitor<Self, E, D>>(visitor: &mut V) -> Result<(), V::Err> {
+        visitor.visit_tensor(
+            "gamma",
+            |s| &s.gamma,
+            |s| &mut s.gamma,
+            TensorOptions::reset_to_ones(),
+        )
+    }
+}
+
+impl<const M: usize, E: Dtype, D1: Device<E>, D2: Device<E>> ToDevice<D2> for RmsNorm<M, E, D1> {
+    type Output = RmsNorm<M, E, D2>;
+
+    fn to_device(&self, device: &D2) -> Self::Output {
+        RmsNorm {
+            gamma: self.gamma.to_device(device),
+            epsilon: self.epsilon,
+        }
+    }
+}
+
+/// `x / sqrt(mean(x^2, Ax) + epsilon)`
+fn try_rms_normalize<
+    Ax: Axes,
+    S: Shape + ReduceShape<Ax>,
+    E: Dtype,
+    D: Device<E>,
+    T: Tape<E, D>,
+>(
+    x: Tensor<S, E, D, T>,
+    epsilon: E,
+) -> Result<Tensor<S, E, D, T>, D::Err> {
+    let inv_rms = x
+        .retaped::<T>()
+        .try_square()?
+        .try_mean::<_, Ax>()?
+        .try_add(epsilon)?
+        .try_rsqrt()?
+        .try_broadcast_like(x.shape())?;
+    x.try_mul(inv_rms)
+}
+
+impl<const M: usize, E: Dtype, D: Device<E>, T: Tape<E, D>> Module<Tensor<Rank1<M>, E, D, T>>
+    for RmsNorm<M, E, D>
+{
+    type Output = Tensor<Rank1<M>, E, D, T>;
+    type Error = D::Err;
+
+    fn try_forward(&self, x: Tensor<Rank1<M>, E, D, T>) -> Result<Self::Output, D::Err> {
+        try_rms_normalize::<Axis<0>, _, _, _, _>(x, self.epsilon)?.try_mul(self.gamma.clone())
+    }
+}
+
+impl<B: Dim, const M: usize, E: Dtype, D: Device<E>, T: Tape<E, D>>
+    Module<Tensor<(B, Const<M>), E, D, T>> for RmsNorm<M, E, D>
+{
+    type Output = Tensor<(B, Const<M>), E, D, T>;
+    type Error = D::Err;
+
+    fn try_forward(&self, x: Tensor<(B, Const<M>), E, D, T>) -> Result<Self::Output, D::Err> {
+        let shape = *x.shape();
+        try_rms_normalize::<Axis<1>, _, _, _, _>(x, self.epsilon)?
+            .try_mul(self.gamma.retaped::<T>().try_broadcast_like(&shape)?)
+    }
+}
+
+impl<B: Dim, S: Dim, const M: usize, E: Dtype, D: Device<E>, T: Tape<E, D>>
+    Module<Tensor<(B, S, Const<M>), E, D, T>> for RmsNorm<M, E, D>
+{
+    type Output = Tensor<(B, S, Const<M>), E, D, T>;
+    type Error = D::Err;
+
+    fn try_forward(&self, x: Tensor<(B, S, Const<M>), E, D, T>) -> Result<Self::Output, D::Err> {
+        let shape = *x.shape();
+        try_rms_normalize::<Axis<2>, _, _, _, _>(x, self.epsilon)?
+            .try_mul(self.gamma.retaped::<T>().try_broadcast_like(&shape)?)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::nn::{DeviceBuildExt, ResetParams};
+    use crate::tests::*;
+
+    #[test]
+    fn test_rms_norm_reset() {
+        let dev: TestDevice = Default::default();
+
+        let mut m = dev.build_module::<builder::RmsNorm<5>, TestDtype>();
+        assert_eq!(m.gamma.array(), [1.0; 5]);
+
+        m.gamma = dev.sample_normal();
+        assert_ne!(m.gamma.array(), [1.0; 5]);
+
+        m.reset_params();
+        assert_eq!(m.gamma.array(), [1.0; 5]);
+    }
+
+    #[test]
+    fn test_rms_norm_unit_rms() {
+        let dev: TestDevice = Default::default();
+        let m = dev.build_module::<builder::RmsNorm<8>, TestDtype>();
+        let x: Tensor<Rank2<3, 8>, TestDtype, _> = dev.sample_normal() * 3.0;
+        let r = m.forward(x);
+        let rms = r.square().mean::<_, Axis<1>>().sqrt();
+        assert_close_with_tolerance(&rms.array(), &[1.0; 3], 1e-4);
+    }
+
+    #[test]
+    fn test_rms_norm_forward() {
+        let dev: TestDevice = Default::default();
+        let mut m = dev.build_module::<builder::RmsNorm<3>, TestDtype>();
+        m.gamma = dev.tensor([1.0, 2.0, -1.0]);
+        m.epsilon = 0.0;
+
+        let x: Tensor<_, TestDtype, _> = dev.tensor([[1.0, 2.0, 2.0], [0.0, -3.0, 4.0]]);
+        let r = m.forward(x.trace());
+        let a = 3.0f64.sqrt() as TestDtype;
+        let b = (3.0f64 / 25.0).sqrt() as TestDtype;
+        assert_close(
+            &r.array(),
+            &[
+                [a / 3.0, 4.0 * a / 3.0, -2.0 * a / 3.0],
+                [0.0, -6.0 * b, -4.0 * b],
+            ],
+        );
+
+        // the scale's gradient is the normalized input summed over the batch
+        let g = r.sum().backward();
+        assert_close(
+            &g.get(&m.gamma).array(),
+            &[a / 3.0, 2.0 * a / 3.0 - 3.0 * b, 2.0 * a / 3.0 + 4.0 * b],
+        );
+    }
+}
@@ -0,0 +1,187 @@
+use crate::{
+    shapes::{Dtype, Shape},
+    tensor::{
+        safetensors::{SafeDtype, SafeTensorError, SafeTensors},
+        CopySlice, Tensor,
+    },
+};
+
+use super::tensor_collection::*;
+
+use std::{path::Path, string::String};
+
+/// Something that can be loaded from a `.safetensors` file, such as one exported from pytorch
+/// with `safetensors.torch.save_file(model.state_dict(), path)`.
+///
+/// All [super::Module]s in nn implement LoadFromSafetensors. Each tensor is looked up by its
+/// path from [RecursiveWalker], e.g. `0.weight` for the weight of the first module in a tuple.
+/// These line up with the names of a `torch.nn.Sequential`, and since [super::modules::Linear]
+/// stores its weight as `(out, in)` just like pytorch, weights can be loaded without transposing.
+pub trait LoadFromSafetensors<E: Dtype + SafeDtype, D: CopySlice<E>>:
+    TensorCollection<E, D>
+{
+    /// Loads data from the `.safetensors` file at the specified `path`.
+    ///
+    /// Example:
+    /// ```ignore
+    /// # use dfdx::prelude::*;
+    /// let mut model = dev.build_module::<(Linear<5, 10>, ReLU, Linear<10, 5>), f32>();
+    /// model.load_safetensors("model.safetensors")?;
+    /// ```
+    fn load_safetensors<P: AsRef<Path>>(&mut self, path: P) -> Result<(), SafeTensorError> {
+        self.load_safetensors_with(path, |name| name.into())
+    }
+
+    /// Loads data from the `.safetensors` file at the specified `path`, where `name_map`
+    /// maps the path of each tensor in this module to its name in the file.
+    ///
+    /// Example:
+    /// ```ignore
+    /// # use dfdx::prelude::*;
+    /// let mut model = dev.build_module::<(Linear<5, 10>, ReLU, Linear<10, 5>), f32>();
+    /// model.load_safetensors_with("model.safetensors", |name| {
+    ///     name.replace("0.", "fc1.").replace("2.", "fc2.")
+    /// })?;
+    /// ```
+    fn load_safetensors_with<P: AsRef<Path>, F: FnMut(&str) -> String>(
+        &mut self,
+        path: P,
+        name_map: F,
+    ) -> Result<(), SafeTensorError> {
+        let tensors = SafeTensors::load(path)?;
+        self.read_safetensors(&tensors, name_map)
+    }
+
+    /// Reads this object from already loaded [SafeTensors], where `name_map` maps the
+    /// path of each tensor in this module to its name in `tensors`.
+    fn read_safetensors<F: FnMut(&str) -> String>(
+        &mut self,
+        tensors: &SafeTensors,
+        name_map: F,
+    ) -> Result<(), SafeTensorError> {
+        Self::iter_tensors(&mut RecursiveWalker {
+            m: self,
+            f: &mut SafeTensorsReader { tensors, name_map },
+            path: &mut std::vec::Vec::new(),
+        })
+    }
+}
+impl<E: Dtype + SafeDtype, D: CopySlice<E>, T: TensorCollection<E, D>> LoadFromSafetensors<E, D>
+    for T
+{
+}
+
+struct SafeTensorsReader<'a, F> {
+    tensors: &'a SafeTensors,
+    name_map: F,
+}
+
+impl<'a, F: FnMut(&str) -> String, E: Dtype + SafeDtype, D: CopySlice<E>> TensorVisitor<E, D>
+    for SafeTensorsReader<'a, F>
+{
+    type Viewer = ViewTensorMut;
+    type Err = SafeTensorError;
+
+    fn visit<S: Shape>(
+        &mut self,
+        full_path: String,
+        _: TensorOptions<S, E, D>,
+        t: &mut Tensor<S, E, D>,
+    ) -> Result<(), Self::Err> {
+        t.load_safetensor(self.tensors, &(self.name_map)(&full_path))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        nn::{builders::*, DeviceBuildExt, Module},
+        tensor::{safetensors::SafeTensors, AsArray, TensorFrom},
+        tests::*,
+    };
+    use safetensors::tensor::TensorView;
+    use std::{format, string::ToString, vec::Vec};
+
+    const W1: [[TestDtype; 5]; 2] = [
+        [-0.3458893, -0.30371523, -0.3712057, 0.14303583, -0.0268966],
+        [0.11733949, 0.14059687, -0.10670426, -0.09373143, 0.18974298],
+    ];
+    const B1: [TestDtype; 2] = [0.3765365, -0.290717];
+    const W2: [[TestDtype; 2]; 3] = [[1.0, 2.0], [-1.0, 0.5], [0.25, -3.0]];
+    const B2: [TestDtype; 3] = [0.1, 0.2, -0.3];
+
+    /// Writes the tensors the same way as `safetensors.torch.save_file`.
+    fn serialize(tensors: &[(&str, Vec<usize>, Vec<TestDtype>)]) -> Vec<u8> {
+        let bytes: Vec<Vec<u8>> = tensors
+            .iter()
+            .map(|(_, _, values)| values.iter().flat_map(|v| v.to_le_bytes()).collect())
+            .collect();
+        let views = tensors
+            .iter()
+            .zip(bytes.iter())
+            .map(|((name, shape, _), data)| {
+                let view = TensorView::new(TestDtype::SAFE_DTYPE, shape.clone(), data).unwrap();
+                (name.to_string(), view)
+            });
+        let metadata = [("format".to_string(), "pt".to_string())].into();
+        safetensors::serialize(views, &Some(metadata)).unwrap()
+    }
+
+    fn mlp_tensors(prefix: [&str; 2]) -> SafeTensors {
+        let bytes = serialize(&[
+            (&format!("{}.weight", prefix[0]), [2, 5].into(), W1.concat()),
+            (&format!("{}.bias", prefix[0]), [2].into(), B1.into()),
+            (&format!("{}.weight", prefix[1]), [3, 2].into(), W2.concat()),
+            (&format!("{}.bias", prefix[1]), [3].into(), B2.into()),
+        ]);
+        SafeTensors::deserialize(bytes).expect("")
+    }
+
+    #[test]
+    fn test_load_mlp() {
+        let dev: TestDevice = Default::default();
+        type Model = (Linear<5, 2>, ReLU, Linear<2, 3>);
+        let mut model = dev.build_module::<Model, TestDtype>();
+        model
+            .read_safetensors(&mlp_tensors(["0", "2"]), |name| name.into())
+            .expect("");
+        assert_eq!(model.0.weight.array(), W1);
+        assert_eq!(model.0.bias.array(), B1);
+        assert_eq!(model.2.weight.array(), W2);
+        assert_eq!(model.2.bias.array(), B2);
+
+        // the first layer outputs [-0.93430865, 0.08624211] before the relu
+        let x = dev.tensor([-0.8808001, 2.4185333, 2.2478335, 0.0565211, 2.031299]);
+        let y = model.forward(x);
+        assert_close(&y.array(), &[0.2724842, 0.24312106, -0.5587263]);
+    }
+
+    #[test]
+    fn test_load_with_name_map() {
+        let dev: TestDevice = Default::default();
+        type Model = (Linear<5, 2>, ReLU, Linear<2, 3>);
+        let mut model = dev.build_module::<Model, TestDtype>();
+        let tensors = mlp_tensors(["fc1", "fc2"]);
+
+        let err = model.read_safetensors(&tensors, |name| name.into());
+        assert!(matches!(err, Err(SafeTensorError::MissingTensor(name)) if name == "0.weight"));
+
+        model
+            .read_safetensors(&tensors, |name| {
+                name.replace("0.", "fc1.").replace("2.", "fc2.")
+            })
+            .expect("");
+        assert_eq!(model.0.weight.array(), W1);
+        assert_eq!(model.2.bias.array(), B2);
+    }
+
+    #[test]
+    fn test_load_shape_mismatch() {
+        let dev: TestDevice = Default::default();
+        let mut model = dev.build_module::<Linear<2, 5>, TestDtype>();
+        let tensors = mlp_tensors(["0", "2"]);
+        let err = model.read_safetensors(&tensors, |name| format!("0.{name}"));
+        assert!(matches!(err, Err(SafeTensorError::ShapeMismatch { .. })));
+    }
+}
@@ -127,6 +127,27 @@ mod tests {
         assert_ne!(g.get(&r).array(), [0.0; 1]);
     }
 
+    #[test]
+    fn test_split_into_grads_accumulate() {
+        let dev: TestDevice = Default::default();
+        type Model = SplitInto<(Linear<3, 2>, Linear<3, 4>)>;
+        let m = dev.build_module::<Model, TestDtype>();
+        let x: Tensor<Rank1<3>, TestDtype, _> = dev.sample_normal();
+
+        let (left, right) = m.forward(x.trace());
+        assert_close(&left.array(), &m.0 .0.forward(x.clone()).array());
+        assert_close(&right.array(), &m.0 .1.forward(x.clone()).array());
+
+        let (right, tape) = right.split_tape();
+        let (left, tape) = left.put_tape(tape).sum().split_tape();
+        let g = (right.put_tape(tape).sum() + left).backward();
+
+        // d/dx sum(W x + b) is the column sums of W, one set for each branch
+        let expected =
+            m.0 .0.weight.clone().sum::<_, Axis<0>>() + m.0 .1.weight.clone().sum::<_, Axis<0>>();
+        assert_close(&g.get(&x).array(), &expected.array());
+    }
+
     #[test]
     fn test_split_into_2() {
         let dev: TestDevice = Default::default();
@@ -49,6 +49,7 @@ impl<S: Shape, E: Dtype, D: DeviceStorage> TensorCollection<E, D> for Tensor<S,
             |s| s,
             TensorOptions {
                 do_gradient_update: true,
+                group: None,
                 reset: |_| Ok(()),
             },
         )
@@ -61,6 +62,10 @@ pub struct TensorOptions<S: Shape, E: Dtype, D: DeviceStorage> {
     /// Whether the tensor should be updated with gradients
     pub do_gradient_update: bool,
 
+    /// The name of the [crate::optim::ParamGroup] whose hyperparameters the optimizers
+    /// should use for the tensor. `None` means the optimizer's own config is used.
+    pub group: Option<&'static str>,
+
     /// How to reset the tensor in the future.
     pub reset: fn(&'_ mut Tensor<S, E, D>) -> Result<(), D::Err>,
 }
@@ -73,6 +78,7 @@ impl<S: Shape, E: Dtype, D: DeviceStorage> TensorOptions<S, E, D> {
     {
         TensorOptions {
             do_gradient_update: true,
+            group: None,
             reset: |t| t.try_fill_with_zeros(),
         }
     }
@@ -84,6 +90,7 @@ impl<S: Shape, E: Dtype, D: DeviceStorage> TensorOptions<S, E, D> {
     {
         TensorOptions {
             do_gradient_update: true,
+            group: None,
             reset: |t| t.try_fill_with_ones(),
         }
     }
@@ -92,6 +99,7 @@ impl<S: Shape, E: Dtype, D: DeviceStorage> TensorOptions<S, E, D> {
     pub fn reset_with(reset: fn(&mut Tensor<S, E, D>) -> Result<(), D::Err>) -> Self {
         TensorOptions {
             do_gradient_update: true,
+            group: None,
             reset,
         }
     }
@@ -100,7 +108,14 @@ impl<S: Shape, E: Dtype, D: DeviceStorage> TensorOptions<S, E, D> {
     pub fn detached(reset: fn(&mut Tensor<S, E, D>) -> Result<(), D::Err>) -> Self {
         TensorOptions {
             do_gradient_update: false,
+            group: None,
             reset,
         }
     }
+
+    /// Puts the tensor in the [crate::optim::ParamGroup] called `name`
+    pub fn in_group(mut self, name: &'static str) -> Self {
+        self.group = Some(name);
+        self
+    }
 }
@@ -46,6 +46,10 @@ pub trait TensorViewer: 'static {
     where
         GetRef: FnMut(&Mod) -> &Field,
         GetMut: FnMut(&mut Mod) -> &mut Field;
+
+    /// Returns a reference to the viewed module, e.g. the first element of a tuple of views.
+    /// Returns `None` if there is nothing to view, e.g. for an empty `Vec` of views.
+    fn view_ref<'a, Mod>(view: &'a Self::View<'_, Mod>) -> Option<&'a Mod>;
 }
 
 /// A [TensorViewer] that represents a `&Tensor`
@@ -96,11 +100,13 @@ impl<'a, M, E: Dtype, D: DeviceStorage, F: TensorVisitor<E, D>> ModuleVisitor<M,
         GetMut: FnMut(&mut M) -> &mut Tensor<S, E, D>,
     {
         self.path.push(name.into());
-        self.f.visit(
-            self.path.join("."),
-            opts,
-            F::Viewer::view_field(&mut self.m, &mut get_refs, &mut get_muts),
-        )?;
+        let t = F::Viewer::view_field(&mut self.m, &mut get_refs, &mut get_muts);
+        let mut opts = opts;
+        if let Some(t) = F::Viewer::view_ref(&t) {
+            opts.do_gradient_update &= !t.param_opts.frozen;
+            opts.group = t.param_opts.group.or(opts.group);
+        }
+        self.f.visit(self.path.join("."), opts, t)?;
         self.path.pop();
         Ok(())
     }
@@ -120,6 +126,10 @@ impl TensorViewer for ViewTensorRef {
     {
         get_ref(module)
     }
+
+    fn view_ref<'a, Mod>(view: &'a Self::View<'_, Mod>) -> Option<&'a Mod> {
+        Some(view)
+    }
 }
 
 impl TensorViewer for ViewTensorMut {
@@ -136,6 +146,10 @@ impl TensorViewer for ViewTensorMut {
     {
         get_mut(module)
     }
+
+    fn view_ref<'a, Mod>(view: &'a Self::View<'_, Mod>) -> Option<&'a Mod> {
+        Some(view)
+    }
 }
 
 macro_rules! tuple_impls {
@@ -154,6 +168,10 @@ macro_rules! tuple_impls {
             {
                 ($($name::view_field(&mut module.$idx, get_ref, get_mut),)+)
             }
+
+            fn view_ref<'a, Mod>(view: &'a Self::View<'_, Mod>) -> Option<&'a Mod> {
+                M1::view_ref(&view.0)
+            }
         }
     }
 }
@@ -182,6 +200,10 @@ impl<T: TensorViewer> TensorViewer for std::vec::Vec<T> {
             .map(|x| T::view_field(x, get_ref, get_mut))
             .collect()
     }
+
+    fn view_ref<'a, Mod>(view: &'a Self::View<'_, Mod>) -> Option<&'a Mod> {
+        view.first().and_then(T::view_ref)
+    }
 }
 
 impl<T: TensorViewer> TensorViewer for Option<T> {
@@ -198,4 +220,8 @@ impl<T: TensorViewer> TensorViewer for Option<T> {
     {
         module.as_mut().map(|x| T::view_field(x, get_ref, get_mut))
     }
+
+    fn view_ref<'a, Mod>(view: &'a Self::View<'_, Mod>) -> Option<&'a Mod> {
+        view.as_ref().and_then(T::view_ref)
+    }
 }
@@ -0,0 +1,229 @@
+use num_traits::Float;
+use rand_distr::uniform::SampleUniform;
+
+use crate::{
+    nn::{modules::*, tensor_collection::*, *},
+    shapes::Dtype,
+    tensor::{PutTape, SplitTape},
+    tensor_ops::Device,
+};
+
+use super::mha::MultiHeadAttention;
+
+pub mod builder {
+    #[derive(Debug)]
+    pub struct TransformerEncoderLayer<
+        const EMBED_DIM: usize,
+        const NUM_HEADS: usize,
+        const FF_DIM: usize,
+        const PRE_NORM: bool = false,
+    >;
+}
+
+impl<const M: usize, const H: usize, const F: usize, const P: bool, E: Dtype, D: Device<E>>
+    BuildOnDevice<D, E> for builder::TransformerEncoderLayer<M, H, F, P>
+where
+    TransformerEncoderLayer<M, H, F, E, D, P>: BuildModule<D, E>,
+{
+    type Built = TransformerEncoderLayer<M, H, F, E, D, P>;
+    fn try_build_on_device(device: &D) -> Result<Self::Built, <D>::Err> {
+        Self::Built::try_build(device)
+    }
+}
+
+/// **Requires Nightly** A transformer encoder layer with a [GeLU] feedforward network.
+///
+/// Generics
+/// - `EMBED_DIM`: The size of query/key/value tensors. Given to [MultiHeadAttention].
+/// - `NUM_HEADS`: The number of heads in [MultiHeadAttention].
+/// - `FF_DIM`: The size of the hidden layer in the feedforward network.
+/// - `PRE_NORM`: Whether to apply layer norm before attention & feedforward (pre-norm),
+///   instead of after each residual connection (post-norm). Defaults to `false`.
+///
+/// Post-norm computes:
+/// 1. `x = norm1(x + self_attn(x))`
+/// 2. `x = norm2(x + ff(x))`
+///
+/// Pre-norm computes:
+/// 1. `x = x + self_attn(norm1(x))`
+/// 2. `x = x + ff(norm2(x))`
+///
+/// **Pytorch equivalent**:
+/// ```python
+/// encoder = torch.nn.TransformerEncoderLayer(
+///    EMBED_DIM, NUM_HEADS, dim_feedforward=FF_DIM, activation="gelu",
+///    batch_first=True, dropout=0.0, norm_first=PRE_NORM
+/// )
+/// ```
+#[derive(Clone, Debug)]
+pub struct TransformerEncoderLayer<
+    const EMBED_DIM: usize,
+    const NUM_HEADS: usize,
+    const FF_DIM: usize,
+    E: Dtype,
+    D: DeviceStorage,
+    const PRE_NORM: bool = false,
+> {
+    pub self_attn: MultiHeadAttention<EMBED_DIM, NUM_HEADS, EMBED_DIM, EMBED_DIM, E, D>,
+    pub norm1: LayerNorm1D<EMBED_DIM, E, D>,
+    pub ff: Residual<FF<EMBED_DIM, FF_DIM, E, D>>,
+    pub norm2: LayerNorm1D<EMBED_DIM, E, D>,
+}
+
+type FF<const M: usize, const F: usize, E, D> = (Linear<M, F, E, D>, GeLU, Linear<F, M, E, D>);
+
+impl<const M: usize, const H: usize, const F: usize, E, D: Device<E>, const P: bool>
+    BuildModule<D, E> for TransformerEncoderLayer<M, H, F, E, D, P>
+where
+    E: Dtype + Float + SampleUniform,
+{
+    fn try_build(device: &D) -> Result<Self, <D>::Err> {
+        Ok(Self {
+            self_attn: BuildModule::try_build(device)?,
+            norm1: BuildModule::try_build(device)?,
+            ff: BuildModule::try_build(device)?,
+            norm2: BuildModule::try_build(device)?,
+        })
+    }
+}
+
+impl<const M: usize, const H: usize, const F: usize, E, D: Device<E>, const P: bool>
+    TensorCollection<E, D> for TransformerEncoderLayer<M, H, F, E, D, P>
+where
+    E: Dtype + Float + SampleUniform,
+{
+    fn iter_tensors<V: ModuleVisitor<Self, E, D>>(visitor: &mut V) -> Result<(), V::Err> {
+        visitor.visit_module("self_attn", |s| &s.self_attn, |s| &mut s.self_attn)?;
+        visitor.visit_module("norm1", |s| &s.norm1, |s| &mut s.norm1)?;
+        visitor.visit_module("ff", |s| &s.ff, |s| &mut s.ff)?;
+        visitor.visit_module("norm2", |s| &s.norm2, |s| &mut s.norm2)
+    }
+}
+
+impl<const M: usize, const H: usize, const F: usize, E, D1, D2, const P: bool> ToDevice<D2>
+    for TransformerEncoderLayer<M, H, F, E, D1, P>
+where
+    E: Dtype,
+    D1: Device<E>,
+    D2: Device<E>,
+{
+    type Output = TransformerEncoderLayer<M, H, F, E, D2, P>;
+    fn to_device(&self, device: &D2) -> Self::Output {
+        TransformerEncoderLayer {
+            self_attn: self.self_attn.to_device(device),
+            norm1: self.norm1.to_device(device),
+            ff: self.ff.to_device(device),
+            norm2: self.norm2.to_device(device),
+        }
+    }
+}
+
+impl<
+        const M: usize,
+        const H: usize,
+        const F: usize,
+        E: Dtype,
+        D: Device<E>,
+        const P: bool,
+        Src,
+    > Module<Src> for TransformerEncoderLayer<M, H, F, E, D, P>
+where
+    Src: SplitTape + std::ops::Add<Src::NoTape, Output = Src>,
+    MultiHeadAttention<M, H, M, M, E, D>: Module<Src, Output = Src, Error = D::Err>,
+    LayerNorm1D<M, E, D>: Module<Src, Output = Src, Error = D::Err>,
+    Residual<FF<M, F, E, D>>: Module<Src, Output = Src, Error = D::Err>,
+    FF<M, F, E, D>: Module<Src, Output = Src, Error = D::Err>,
+{
+    type Output = Src;
+    type Error = D::Err;
+
+    fn try_forward(&self, src: Src) -> Result<Self::Output, D::Err> {
+        if P {
+            let (src, tape) = src.split_tape();
+            let x = self.norm1.try_forward(src.clone().put_tape(tape))?;
+            let x = self.self_attn.try_forward(x)? + src;
+            let (x, tape) = x.split_tape();
+            let y = self.norm2.try_forward(x.clone().put_tape(tape))?;
+            Ok(self.ff.0.try_forward(y)? + x)
+        } else {
+            let (src, tape) = src.split_tape();
+            let x = self.self_attn.try_forward(src.clone().put_tape(tape))?;
+            let x = self.norm1.try_forward(x + src)?;
+            let x = self.ff.try_forward(x)?;
+            self.norm2.try_forward(x)
+        }
+    }
+}
+
+impl<const M: usize, const H: usize, const F: usize, E: Dtype, D: Device<E>, const P: bool, T>
+    ModuleMut<T> for TransformerEncoderLayer<M, H, F, E, D, P>
+where
+    Self: Module<T, Error = D::Err>,
+{
+    type Output = <Self as Module<T>>::Output;
+    type Error = D::Err;
+
+    fn try_forward_mut(&mut self, t: T) -> Result<Self::Output, D::Err> {
+        self.try_forward(t)
+    }
+}
+
+#[cfg(feature = "nightly")]
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{optim::*, shapes::*, tensor::*, tensor_ops::*, tests::*};
+
+    #[test]
+    fn test_encoder_layer_forward() {
+        let dev = TestDevice::seed_from_u64(0);
+
+        type PostNorm = builder::TransformerEncoderLayer<12, 4, 16>;
+        let layer = dev.build_module::<PostNorm, TestDtype>();
+        let x: Tensor<Rank2<5, 12>, TestDtype, _> = dev.sample_normal();
+        let _: Tensor<Rank2<5, 12>, _, _> = layer.forward(x);
+        let x: Tensor<Rank3<3, 5, 12>, TestDtype, _> = dev.sample_normal();
+        let _: Tensor<Rank3<3, 5, 12>, _, _> = layer.forward(x);
+
+        type PreNorm = builder::TransformerEncoderLayer<12, 4, 16, true>;
+        let layer = dev.build_module::<PreNorm, TestDtype>();
+        let x: Tensor<Rank2<5, 12>, TestDtype, _> = dev.sample_normal();
+        let _: Tensor<Rank2<5, 12>, _, _> = layer.forward(x);
+        let x: Tensor<Rank3<3, 5, 12>, TestDtype, _> = dev.sample_normal();
+        let _: Tensor<Rank3<3, 5, 12>, _, _> = layer.forward(x);
+    }
+
+    #[test]
+    fn test_encoder_layer_pre_norm_differs() {
+        let dev = TestDevice::seed_from_u64(0);
+        let post = dev.build_module::<builder::TransformerEncoderLayer<8, 2, 8>, TestDtype>();
+        let pre = TransformerEncoderLayer::<8, 2, 8, TestDtype, TestDevice, true> {
+            self_attn: post.self_attn.clone(),
+            norm1: post.norm1.clone(),
+            ff: post.ff.clone(),
+            norm2: post.norm2.clone(),
+        };
+        let x: Tensor<Rank2<3, 8>, TestDtype, _> = dev.sample_normal();
+        let y_post = post.forward(x.clone());
+        let y_pre = pre.forward(x);
+        assert_ne!(y_post.array(), y_pre.array());
+    }
+
+    #[test]
+    fn test_encoder_layer_backward_updates_all() {
+        let dev = TestDevice::seed_from_u64(0);
+
+        let mut post = dev.build_module::<builder::TransformerEncoderLayer<12, 4, 16>, TestDtype>();
+        let x: Tensor<Rank3<2, 5, 12>, TestDtype, _> = dev.sample_normal();
+        let g = post.forward(x.trace()).square().mean().backward();
+        let mut opt = Sgd::new(&post, Default::default());
+        opt.update(&mut post, g).expect("");
+
+        let mut pre =
+            dev.build_module::<builder::TransformerEncoderLayer<12, 4, 16, true>, TestDtype>();
+        let x: Tensor<Rank3<2, 5, 12>, TestDtype, _> = dev.sample_normal();
+        let g = pre.forward(x.trace()).square().mean().backward();
+        let mut opt = Sgd::new(&pre, Default::default());
+        opt.update(&mut pre, g).expect("");
+    }
+}
@@ -0,0 +1,78 @@
+use num_traits::Float;
+
+use crate::{shapes::*, tensor::*};
+
+/// An additive attention mask that stops each position from attending to later positions.
+///
+/// The result has `0` on and below the diagonal, and `-inf` above it, so adding it to
+/// attention scores before softmax gives zero weight to all future positions.
+///
+/// Example:
+/// ```rust
+/// # use dfdx::prelude::*;
+/// # let dev: Cpu = Default::default();
+/// let mask: Tensor<Rank2<3, 3>, f32, _> = causal_mask(&dev);
+/// let inf = f32::INFINITY;
+/// assert_eq!(
+///     mask.array(),
+///     [[0.0, -inf, -inf], [0.0, 0.0, -inf], [0.0, 0.0, 0.0]]
+/// );
+/// ```
+pub fn causal_mask<const LEN: usize, E: Dtype + Float, D: TensorFromVec<E>>(
+    device: &D,
+) -> Tensor<Rank2<LEN, LEN>, E, D> {
+    try_causal_mask(device).unwrap()
+}
+
+/// Fallible version of [causal_mask]
+pub fn try_causal_mask<const LEN: usize, E: Dtype + Float, D: TensorFromVec<E>>(
+    device: &D,
+) -> Result<Tensor<Rank2<LEN, LEN>, E, D>, D::Err> {
+    let mut data = std::vec![E::default(); LEN * LEN];
+    for i in 0..LEN {
+        for j in i + 1..LEN {
+            data[i * LEN + j] = E::neg_infinity();
+        }
+    }
+    device.try_tensor_from_vec(data, Default::default())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::tests::*;
+
+    #[test]
+    fn test_causal_mask() {
+        let dev: TestDevice = Default::default();
+        let mask: Tensor<Rank2<4, 4>, TestDtype, _> = causal_mask(&dev);
+        let mask = mask.array();
+        for (i, row) in mask.iter().enumerate() {
+            for (j, v) in row.iter().enumerate() {
+                if j > i {
+                    assert_eq!(*v, TestDtype::NEG_INFINITY);
+                } else {
+                    assert_eq!(*v, 0.0);
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn test_causal_mask_softmax() {
+        let dev: TestDevice = Default::default();
+        let scores: Tensor<Rank2<3, 3>, TestDtype, _> =
+            dev.tensor([[0.5, 2.0, -1.0], [1.0, 1.0, 3.0], [0.0, -2.0, 1.0]]);
+        let weights = (scores + causal_mask(&dev)).softmax::<Axis<1>>();
+        let e = |x: TestDtype| x.exp();
+        let (a, b) = (e(1.0), e(0.0) + e(-2.0) + e(1.0));
+        assert_close(
+            &weights.array(),
+            &[
+                [1.0, 0.0, 0.0],
+                [0.5, 0.5, 0.0],
+                [e(0.0) / b, e(-2.0) / b, a / b],
+            ],
+        );
+    }
+}
@@ -1,9 +1,13 @@
 mod decoder;
 mod encoder;
+mod encoder_layer;
+mod mask;
 mod mha;
 
 pub use decoder::*;
 pub use encoder::*;
+pub use encoder_layer::*;
+pub use mask::{causal_mask, try_causal_mask};
 pub use mha::*;
 
 use num_traits::Float;
@@ -25,6 +29,7 @@ pub mod builder {
 
     pub use super::decoder::builder::*;
     pub use super::encoder::builder::*;
+    pub use super::encoder_layer::builder::*;
     pub use super::mha::builder::MultiHeadAttention;
 }
 
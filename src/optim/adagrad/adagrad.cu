@@ -0,0 +1,56 @@
+#include "cuda_utils.cuh"
+
+enum WeightDecayType {
+    WdNone,
+    L2,
+    Decoupled
+};
+
+template<typename T>
+struct AdagradConfig {
+    T eps;
+    T initial_accumulator_value;
+    WeightDecayType weight_decay_type;
+    T weight_decay;
+};
+
+template<typename T>
+__device__ void adagrad_update(
+    const AdagradConfig<T> cfg,
+    const size_t numel,
+    const T* param,
+    T* accum,
+    T* grad
+) {
+    unsigned int i = blockIdx.x * blockDim.x + threadIdx.x;
+
+    if (i >= numel) {
+        return;
+    }
+
+    T g = grad[i];
+    T a = accum[i];
+
+    if (cfg.weight_decay_type == L2) {
+        g += cfg.weight_decay * param[i];
+    }
+
+    a += g * g;
+
+    accum[i] = a;
+    grad[i] = g / (sqrtg(a + cfg.initial_accumulator_value) + cfg.eps);
+}
+
+#define ADAGRAD(TYPENAME, FN) \
+extern "C" __global__ void FN( \
+    const AdagradConfig<TYPENAME> cfg, \
+    const size_t numel, \
+    const TYPENAME* param, \
+    TYPENAME* accum, \
+    TYPENAME* grad \
+) { \
+    adagrad_update(cfg, numel, param, accum, grad); \
+}
+
+ADAGRAD(float, adagrad_update_f32);
+ADAGRAD(double, adagrad_update_f64);
@@ -0,0 +1,23 @@
+use crate::{optim::WeightDecay, shapes::Dtype, tensor::cpu::Cpu};
+
+use super::{AdagradConfig, AdagradKernel};
+
+impl<E: num_traits::Float + Dtype> AdagradKernel<E> for Cpu {
+    fn update(
+        &self,
+        cfg: &AdagradConfig<E>,
+        param: &Self::Vec<E>,
+        accum: &mut Self::Vec<E>,
+        grad: &mut Self::Vec<E>,
+    ) -> Result<(), Self::Err> {
+        for ((p, a), g) in param.iter().zip(accum.iter_mut()).zip(grad.iter_mut()) {
+            if let Some(WeightDecay::L2(wd)) = cfg.weight_decay {
+                *g += wd * *p;
+            }
+
+            *a += *g * *g;
+            *g /= (*a + cfg.initial_accumulator_value).sqrt() + cfg.eps;
+        }
+        Ok(())
+    }
+}
@@ -0,0 +1,66 @@
+use super::AdagradConfig;
+use crate::{optim::optimizer::*, shapes::*, tensor::Cuda};
+use cudarc::driver::{DeviceRepr, DeviceSlice, LaunchAsync, LaunchConfig};
+
+#[repr(C)]
+struct CudaAdagradConfig<E> {
+    eps: E,
+    initial_accumulator_value: E,
+    weight_decay_type: WeightDecayType,
+    weight_decay: E,
+}
+
+unsafe impl<E: DeviceRepr> DeviceRepr for CudaAdagradConfig<E> {}
+
+fn adagrad_config_to_cuda<E: Default + Copy>(config: &AdagradConfig<E>) -> CudaAdagradConfig<E> {
+    let (weight_decay_type, weight_decay) = weight_decay_to_cuda(config.weight_decay);
+
+    CudaAdagradConfig {
+        eps: config.eps,
+        initial_accumulator_value: config.initial_accumulator_value,
+        weight_decay_type,
+        weight_decay,
+    }
+}
+
+const PTX_SRC: &str = include_str!(concat!(env!("OUT_DIR"), "/adagrad.ptx"));
+
+trait HasCudaKernel<E> {
+    const MOD: &'static str;
+    const FWD: &'static str;
+}
+
+impl HasCudaKernel<f32> for Cuda {
+    const MOD: &'static str = "adagrad_f32";
+    const FWD: &'static str = "adagrad_update_f32";
+}
+
+impl HasCudaKernel<f64> for Cuda {
+    const MOD: &'static str = "adagrad_f64";
+    const FWD: &'static str = "adagrad_update_f64";
+}
+
+impl<E: Dtype> super::AdagradKernel<E> for Cuda
+where
+    Self: HasCudaKernel<E>,
+{
+    fn update(
+        &self,
+        cfg: &AdagradConfig<E>,
+        param: &Self::Vec<E>,
+        accum: &mut Self::Vec<E>,
+        grad: &mut Self::Vec<E>,
+    ) -> Result<(), Self::Err> {
+        if !self.dev.has_func(Self::MOD, Self::FWD) {
+            self.dev.load_ptx(PTX_SRC.into(), Self::MOD, &[Self::FWD])?;
+        }
+
+        let opt_cfg = adagrad_config_to_cuda(cfg);
+        let numel = param.len();
+        let func = self.dev.get_func(Self::MOD, Self::FWD).unwrap();
+        let cfg = LaunchConfig::for_num_elems(numel as u32);
+        let params = (opt_cfg, numel, param, accum, grad);
+        unsafe { func.launch(cfg, params) }?;
+        Ok(())
+    }
+}
@@ -0,0 +1,337 @@
+mod cpu_kernel;
+
+#[cfg(feature = "cuda")]
+mod cuda_kernel;
+
+use std::{marker::PhantomData, sync::Arc};
+
+use crate::{
+    gradients::Gradients,
+    nn::tensor_collection::*,
+    shapes::{Dtype, Shape},
+    tensor::*,
+    tensor_ops::axpy::AxpyKernel,
+};
+
+use super::{Optimizer, OptimizerUpdateError, ParamGroup, ParamGroups, UnusedTensors, WeightDecay};
+
+/// Configuration of hyperparameters for [Adagrad].
+///
+/// Changing all default parameters:
+/// ```rust
+/// # use dfdx::{prelude::*, optim::*};
+/// AdagradConfig {
+///     lr: 1e-1,
+///     eps: 1e-8,
+///     initial_accumulator_value: 0.1,
+///     weight_decay: Some(WeightDecay::L2(1e-2)),
+/// };
+/// ```
+#[derive(Debug, Clone, Copy)]
+pub struct AdagradConfig<E> {
+    /// Learning rate. Defaults to `1e-2`.
+    pub lr: E,
+
+    /// Epsilon for numerical stability. Defaults to `1e-10`.
+    pub eps: E,
+
+    /// The value the sum of squared gradients starts at. Defaults to `0.0`.
+    pub initial_accumulator_value: E,
+
+    /// Optional weight decay. Defaults to `None`.
+    pub weight_decay: Option<WeightDecay<E>>,
+}
+
+impl<E: Dtype> Default for AdagradConfig<E> {
+    fn default() -> Self {
+        Self {
+            lr: E::from_f32(1e-2).unwrap(),
+            eps: E::from_f32(1e-10).unwrap(),
+            initial_accumulator_value: E::default(),
+            weight_decay: None,
+        }
+    }
+}
+
+/// An implementation of the Adagrad optimizer from
+/// [Adaptive Subgradient Methods for Online Learning and Stochastic Optimization](https://jmlr.org/papers/v12/duchi11a.html).
+///
+/// Each parameter accumulates the sum of its squared gradients, and is updated with
+/// `param -= lr * grad / (sqrt(accum) + eps)`, so the effective step size of each parameter
+/// shrinks as training goes on.
+///
+/// # Example Usage
+///
+/// Constructing using default:
+/// ```rust
+/// # use dfdx::{prelude::*, optim::*};
+/// # type Model = Tensor<Rank0, f32, Cpu>;
+/// # let dev: Cpu = Default::default();
+/// # let model: Model = dev.zeros();
+/// let mut opt: Adagrad<Model, f32, Cpu> = Adagrad::new(&model, Default::default());
+/// ```
+///
+/// Changing using new
+/// ```rust
+/// # use dfdx::{prelude::*, optim::*};
+/// # type Model = Tensor<Rank0, f32, Cpu>;
+/// # let dev: Cpu = Default::default();
+/// # let model: Model = dev.zeros();
+/// let mut opt: Adagrad<Model, f32, Cpu> = Adagrad::new(&model, AdagradConfig {
+///     lr: 1e-1,
+///     initial_accumulator_value: 0.1,
+///     weight_decay: Some(WeightDecay::Decoupled(1e-2)),
+///     ..Default::default()
+/// });
+/// ```
+///
+/// See module level documentation at [crate::optim] for examples of how to actually use an optimizer.
+#[derive(Debug)]
+pub struct Adagrad<M, E: Dtype, D: DeviceStorage> {
+    /// Hyperparameter configuration
+    pub cfg: AdagradConfig<E>,
+
+    param_groups: std::vec::Vec<ParamGroup<E>>,
+
+    accum: Gradients<E, D>,
+    gradients: Gradients<E, D>,
+
+    unused: UnusedTensors,
+
+    marker: PhantomData<*const M>,
+}
+
+impl<M, E: Dtype, D: DeviceStorage> Adagrad<M, E, D> {
+    /// Constructs using hyperparameters from `cfg`.
+    pub fn new(_model: &M, cfg: AdagradConfig<E>) -> Self {
+        Self {
+            cfg,
+            param_groups: Default::default(),
+            accum: Default::default(),
+            gradients: Default::default(),
+            unused: Default::default(),
+            marker: PhantomData,
+        }
+    }
+}
+
+pub(super) trait AdagradKernel<E: Dtype>: DeviceStorage {
+    /// Adds the squared gradients to `accum`, and replaces `grad` with the
+    /// direction to step in (before scaling by the learning rate).
+    ///
+    /// `accum` starts at zero, so [AdagradConfig::initial_accumulator_value]
+    /// is added to it whenever it is read.
+    fn update(
+        &self,
+        cfg: &AdagradConfig<E>,
+        param: &Self::Vec<E>,
+        accum: &mut Self::Vec<E>,
+        grad: &mut Self::Vec<E>,
+    ) -> Result<(), Self::Err>;
+}
+
+impl<M: TensorCollection<E, D>, E: Dtype, D: AdagradKernel<E> + AxpyKernel<E>> TensorVisitor<E, D>
+    for Adagrad<M, E, D>
+{
+    type Viewer = ViewTensorMut;
+    type Err = D::Err;
+
+    fn visit<S: Shape>(
+        &mut self,
+        _: alloc::string::String,
+        opts: TensorOptions<S, E, D>,
+        p: &mut Tensor<S, E, D>,
+    ) -> Result<(), D::Err> {
+        if !opts.do_gradient_update {
+            return Ok(());
+        }
+        let cfg = match opts.group.and_then(|name| self.param_group(name)) {
+            Some(group) => AdagradConfig {
+                lr: group.lr,
+                weight_decay: group.weight_decay,
+                ..self.cfg
+            },
+            None => self.cfg,
+        };
+        let g = self.gradients.remove(p);
+        match g {
+            None => self.unused.add(p),
+            Some(mut g) => {
+                let acc = self.accum.get_or_alloc_mut(p)?;
+                p.device.update(&cfg, p.data.as_ref(), acc, &mut g)?;
+                // decoupled weight decay scales the parameter directly
+                let scale = match cfg.weight_decay {
+                    Some(WeightDecay::Decoupled(wd)) => E::ONE - cfg.lr * wd,
+                    _ => E::ONE,
+                };
+                p.device
+                    .forward(Arc::make_mut(&mut p.data), scale, &g, E::default() - cfg.lr)?;
+            }
+        }
+        Ok(())
+    }
+}
+
+impl<M: TensorCollection<E, D>, D: AdagradKernel<E> + AxpyKernel<E>, E: Dtype> Optimizer<M, D, E>
+    for Adagrad<M, E, D>
+{
+    fn update(
+        &mut self,
+        module: &mut M,
+        gradients: Gradients<E, D>,
+    ) -> Result<(), OptimizerUpdateError<D>> {
+        self.gradients = gradients;
+        let result = M::iter_tensors(&mut RecursiveWalker {
+            m: module,
+            f: self,
+            path: &mut std::vec::Vec::new(),
+        });
+        let unused = std::mem::take(&mut self.unused);
+        match result {
+            Ok(_) => unused.into(),
+            Err(e) => Err(OptimizerUpdateError::DeviceError(e)),
+        }
+    }
+}
+
+impl<M, E: Dtype, D: DeviceStorage> ParamGroups<E> for Adagrad<M, E, D> {
+    fn param_groups(&self) -> &[ParamGroup<E>] {
+        &self.param_groups
+    }
+
+    fn param_groups_mut(&mut self) -> &mut std::vec::Vec<ParamGroup<E>> {
+        &mut self.param_groups
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        nn::{builders::*, DeviceBuildExt, Module},
+        shapes::*,
+        tensor_ops::*,
+        tests::*,
+    };
+
+    fn test_matches_expected(cfg: AdagradConfig<TestDtype>, expected: [[TestDtype; 5]; 5]) {
+        let dev: TestDevice = Default::default();
+        let rate: Tensor<_, TestDtype, _> = dev.tensor([0.1, 1.0, 2.0, 10.0, 100.0]);
+        let mut t: Tensor<Rank1<5>, TestDtype, _> = dev.ones();
+        let mut opt = Adagrad::new(&t, cfg);
+        for e in expected.iter() {
+            let gradients = (t.trace() * rate.clone()).square().sum().backward();
+            opt.update(&mut t, gradients).expect("");
+            assert_close(&t.array(), e);
+        }
+    }
+
+    #[test]
+    fn test_adagrad_default() {
+        const EXPECTED: [[TestDtype; 5]; 5] = [
+            [0.99; 5],
+            [0.9829646; 5],
+            [0.97723794; 5],
+            [0.97229034; 5],
+            [0.9678739; 5],
+        ];
+        test_matches_expected(Default::default(), EXPECTED);
+    }
+
+    #[test]
+    fn test_adagrad_initial_accumulator() {
+        let cfg = AdagradConfig {
+            lr: 1e-1,
+            initial_accumulator_value: 1.0,
+            ..Default::default()
+        };
+        const EXPECTED: [[TestDtype; 5]; 5] = [
+            [0.9980004, 0.91055727, 0.9007722, 0.9000012, 0.9],
+            [0.9960052, 0.84740806, 0.8341308, 0.8331052, 0.83310354],
+            [0.9940144, 0.79674053, 0.7816146, 0.78045803, 0.7804562],
+            [0.99202794, 0.7537332, 0.7374613, 0.7362251, 0.7362231],
+            [0.99004585, 0.7160471, 0.6990057, 0.69771695, 0.69771487],
+        ];
+        test_matches_expected(cfg, EXPECTED);
+    }
+
+    #[test]
+    fn test_adagrad_l2_weight_decay() {
+        let cfg = AdagradConfig {
+            lr: 1e-1,
+            initial_accumulator_value: 1.0,
+            weight_decay: Some(WeightDecay::L2(0.5)),
+            ..Default::default()
+        };
+        const EXPECTED: [[TestDtype; 5]; 5] = [
+            [0.95386475, 0.90715235, 0.90068495, 0.9000012, 0.9],
+            [0.91358566, 0.8427314, 0.8340146, 0.8331052, 0.83310354],
+            [0.8775929, 0.7913789, 0.7814834, 0.78045803, 0.7804562],
+            [0.8449162, 0.74794245, 0.7373211, 0.7362251, 0.7362231],
+            [0.81490624, 0.70996565, 0.69885945, 0.69771695, 0.69771487],
+        ];
+        test_matches_expected(cfg, EXPECTED);
+    }
+
+    #[test]
+    fn test_adagrad_decoupled_weight_decay() {
+        let cfg = AdagradConfig {
+            lr: 1e-1,
+            initial_accumulator_value: 1.0,
+            weight_decay: Some(WeightDecay::Decoupled(0.5)),
+            ..Default::default()
+        };
+        const EXPECTED: [[TestDtype; 5]; 5] = [
+            [0.9480004, 0.86055726, 0.8507722, 0.8500013, 0.85],
+            [0.8987051, 0.7565347, 0.7437263, 0.74273676, 0.74273515],
+            [0.8519734, 0.67145133, 0.6574207, 0.6563481, 0.6563464],
+            [0.80767196, 0.599201, 0.5847226, 0.5836234, 0.5836216],
+            [0.76567435, 0.53661394, 0.52209646, 0.5209999, 0.5209981],
+        ];
+        test_matches_expected(cfg, EXPECTED);
+    }
+
+    #[test]
+    fn test_adagrad_step_size_shrinks() {
+        let dev: TestDevice = Default::default();
+        let mut model = dev.build_module::<Linear<5, 2>, TestDtype>();
+        let x: Tensor<Rank2<8, 5>, TestDtype, _> = dev.sample_normal();
+        let y: Tensor<Rank2<8, 2>, TestDtype, _> = dev.sample_normal();
+        let cfg = AdagradConfig {
+            lr: 1e-1,
+            ..Default::default()
+        };
+        let mut opt = Adagrad::new(&model, cfg);
+
+        let first_loss = crate::losses::mse_loss(model.forward(x.clone()), y.clone()).array();
+        let mut last_step_size: Option<[[TestDtype; 5]; 2]> = None;
+        for _ in 0..20 {
+            let loss = crate::losses::mse_loss(model.forward(x.trace()), y.clone());
+            let gradients = loss.backward();
+            opt.update(&mut model, gradients).expect("");
+
+            // lr / (sqrt(accum) + eps)
+            let accum = opt.accum.get(&model.weight);
+            let step_size = ((accum + cfg.initial_accumulator_value).sqrt() + cfg.eps)
+                .array()
+                .map(|row| row.map(|a| cfg.lr / a));
+            if let Some(last) = last_step_size {
+                for (s, l) in step_size.iter().flatten().zip(last.iter().flatten()) {
+                    assert!(s < l, "{step_size:?} >= {last:?}");
+                }
+            }
+            last_step_size = Some(step_size);
+        }
+
+        let last_loss = crate::losses::mse_loss(model.forward(x), y).array();
+        assert!(last_loss < first_loss, "{last_loss} >= {first_loss}");
+    }
+
+    #[test]
+    fn test_unused_tensors() {
+        let dev: TestDevice = Default::default();
+        let mut t: Tensor<Rank1<5>, TestDtype, _> = dev.sample_normal();
+        let mut opt = Adagrad::new(&t, Default::default());
+        opt.update(&mut t, Default::default()).expect_err("");
+    }
+}
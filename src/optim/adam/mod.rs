@@ -12,7 +12,7 @@ use crate::{
     tensor::DeviceStorage,
 };
 
-use super::{Optimizer, OptimizerUpdateError, UnusedTensors, WeightDecay};
+use super::{Optimizer, OptimizerUpdateError, ParamGroup, ParamGroups, UnusedTensors, WeightDecay};
 
 /// Configuration of hyperparameters for [Adam].
 ///
@@ -52,6 +52,55 @@ impl<E: Dtype> Default for AdamConfig<E> {
     }
 }
 
+/// Configuration of hyperparameters for [AdamW].
+///
+/// Changing all default parameters:
+/// ```rust
+/// # use dfdx::{prelude::*, optim::*};
+/// AdamWConfig {
+///     lr: 1e-2,
+///     betas: [0.1, 0.2],
+///     eps: 1e-6,
+///     weight_decay: 1e-1,
+/// };
+/// ```
+#[derive(Debug, Clone, Copy)]
+pub struct AdamWConfig<E> {
+    /// Learning rate. Defaults to `1e-3`.
+    pub lr: E,
+
+    /// Betas from Adam paper. Defaults to `[0.9, 0.999]`.
+    pub betas: [E; 2],
+
+    /// Epsilon for numerical stability. Defaults to `1e-8`.
+    pub eps: E,
+
+    /// Decoupled weight decay, see [WeightDecay::Decoupled]. Defaults to `1e-2`.
+    pub weight_decay: E,
+}
+
+impl<E: Dtype> Default for AdamWConfig<E> {
+    fn default() -> Self {
+        Self {
+            lr: E::from_f32(1e-3).unwrap(),
+            betas: [E::from_f32(0.9).unwrap(), E::from_f32(0.999).unwrap()],
+            eps: E::from_f32(1e-8).unwrap(),
+            weight_decay: E::from_f32(1e-2).unwrap(),
+        }
+    }
+}
+
+impl<E> From<AdamWConfig<E>> for AdamConfig<E> {
+    fn from(cfg: AdamWConfig<E>) -> Self {
+        Self {
+            lr: cfg.lr,
+            betas: cfg.betas,
+            eps: cfg.eps,
+            weight_decay: Some(WeightDecay::Decoupled(cfg.weight_decay)),
+        }
+    }
+}
+
 /// An implementation of the Adam optimizer from
 /// [Adam: A Method for Stochastic Optimization](https://arxiv.org/abs/1412.6980)
 ///
@@ -70,11 +119,15 @@ impl<E: Dtype> Default for AdamConfig<E> {
 /// ```
 ///
 /// See module level documentation at [crate::optim] for examples of how to actually use an optimizer.
+///
+/// Using [WeightDecay::Decoupled] is the same as [AdamW].
 #[derive(Debug)]
 pub struct Adam<M, E: Dtype, D: DeviceStorage> {
     /// Hyperparameter configuration
     pub cfg: AdamConfig<E>,
 
+    param_groups: std::vec::Vec<ParamGroup<E>>,
+
     t: i32,
     gradients: Gradients<E, D>,
     moment1: Gradients<E, D>,
@@ -85,11 +138,72 @@ pub struct Adam<M, E: Dtype, D: DeviceStorage> {
     marker: PhantomData<*const M>,
 }
 
+/// AdamW from [Decoupled Weight Decay Regularization](https://arxiv.org/abs/1711.05101).
+///
+/// This is [Adam] that always uses [WeightDecay::Decoupled]: the decay `lr * wd * param` is
+/// subtracted from the parameters directly, instead of being added to the gradients before
+/// the moment updates like [WeightDecay::L2] does.
+///
+/// # Example Usage
+/// ```rust
+/// # use dfdx::{prelude::*, optim::*};
+/// # type Model = Tensor<Rank0, f32, Cpu>;
+/// # let dev: Cpu = Default::default();
+/// # let model: Model = dev.zeros();
+/// let mut opt: AdamW<Model, f32, Cpu> = AdamW::new(&model, AdamWConfig {
+///     lr: 1e-2,
+///     betas: [0.5, 0.25],
+///     eps: 1e-6,
+///     weight_decay: 1e-2,
+/// });
+/// ```
+///
+/// See module level documentation at [crate::optim] for examples of how to actually use an optimizer.
+#[derive(Debug)]
+pub struct AdamW<M, E: Dtype, D: DeviceStorage> {
+    /// Hyperparameter configuration
+    pub cfg: AdamWConfig<E>,
+
+    adam: Adam<M, E, D>,
+}
+
+impl<M, E: Dtype, D: DeviceStorage> AdamW<M, E, D> {
+    /// Constructs using hyperparameters from `cfg`.
+    pub fn new(model: &M, cfg: AdamWConfig<E>) -> Self {
+        Self {
+            cfg,
+            adam: Adam::new(model, cfg.into()),
+        }
+    }
+}
+
+impl<M: TensorCollection<E, D>, D: AdamKernel<E>, E: Dtype> Optimizer<M, D, E> for AdamW<M, E, D> {
+    fn update(
+        &mut self,
+        module: &mut M,
+        gradients: Gradients<E, D>,
+    ) -> Result<(), OptimizerUpdateError<D>> {
+        self.adam.cfg = self.cfg.into();
+        self.adam.update(module, gradients)
+    }
+}
+
+impl<M, E: Dtype, D: DeviceStorage> ParamGroups<E> for AdamW<M, E, D> {
+    fn param_groups(&self) -> &[ParamGroup<E>] {
+        self.adam.param_groups()
+    }
+
+    fn param_groups_mut(&mut self) -> &mut std::vec::Vec<ParamGroup<E>> {
+        self.adam.param_groups_mut()
+    }
+}
+
 impl<M, E: Dtype, D: DeviceStorage> Adam<M, E, D> {
     /// Constructs using hyperparameters from `cfg`.
     pub fn new(_model: &M, cfg: AdamConfig<E>) -> Self {
         Self {
             cfg,
+            param_groups: Default::default(),
             t: 0,
             gradients: Default::default(),
             moment1: Default::default(),
@@ -112,7 +226,7 @@ pub(super) trait AdamKernel<E: Dtype>: DeviceStorage {
     ) -> Result<(), Self::Err>;
 }
 
-impl<M, D: AdamKernel<E>, E: Dtype> TensorVisitor<E, D> for Adam<M, E, D> {
+impl<M: TensorCollection<E, D>, D: AdamKernel<E>, E: Dtype> TensorVisitor<E, D> for Adam<M, E, D> {
     type Viewer = ViewTensorMut;
     type Err = D::Err;
 
@@ -125,6 +239,14 @@ impl<M, D: AdamKernel<E>, E: Dtype> TensorVisitor<E, D> for Adam<M, E, D> {
         if !opts.do_gradient_update {
             return Ok(());
         }
+        let cfg = match opts.group.and_then(|name| self.param_group(name)) {
+            Some(group) => AdamConfig {
+                lr: group.lr,
+                weight_decay: group.weight_decay,
+                ..self.cfg
+            },
+            None => self.cfg,
+        };
         let g = self.gradients.remove(p);
         match g {
             None => self.unused.add(p),
@@ -132,7 +254,7 @@ impl<M, D: AdamKernel<E>, E: Dtype> TensorVisitor<E, D> for Adam<M, E, D> {
                 let m_t = self.moment1.get_or_alloc_mut(p)?;
                 let v_t = self.moment2.get_or_alloc_mut(p)?;
                 p.device
-                    .update(self.t, &self.cfg, Arc::make_mut(&mut p.data), m_t, v_t, g)?;
+                    .update(self.t, &cfg, Arc::make_mut(&mut p.data), m_t, v_t, g)?;
             }
         }
         Ok(())
@@ -160,10 +282,20 @@ impl<M: TensorCollection<E, D>, D: AdamKernel<E>, E: Dtype> Optimizer<M, D, E> f
     }
 }
 
+impl<M, E: Dtype, D: DeviceStorage> ParamGroups<E> for Adam<M, E, D> {
+    fn param_groups(&self) -> &[ParamGroup<E>] {
+        &self.param_groups
+    }
+
+    fn param_groups_mut(&mut self) -> &mut std::vec::Vec<ParamGroup<E>> {
+        &mut self.param_groups
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
-    use crate::{shapes::*, tensor::*, tensor_ops::*, tests::*};
+    use crate::{nn::set_param_group, shapes::*, tensor::*, tensor_ops::*, tests::*};
 
     #[test]
     fn test_default_adam_params() {
@@ -291,6 +423,117 @@ mod tests {
         }
     }
 
+    #[test]
+    fn test_adamw_differs_from_l2() {
+        let dev: TestDevice = Default::default();
+        let t0: Tensor<Rank1<5>, TestDtype, _> = dev.tensor([-0.5, -0.25, 0.1, 0.6, 1.0]);
+        let mut t_w = t0.clone();
+        let mut t_l2 = t0.clone();
+        let mut adamw: AdamW<_, TestDtype, _> = AdamW::new(
+            &t_w,
+            AdamWConfig {
+                weight_decay: 0.5,
+                ..Default::default()
+            },
+        );
+        let mut adam_l2 = Adam::new(
+            &t_l2,
+            AdamConfig {
+                weight_decay: Some(WeightDecay::L2(0.5)),
+                ..Default::default()
+            },
+        );
+        for _ in 0..5 {
+            let g = t_w.trace().exp().square().mean().backward();
+            adamw.update(&mut t_w, g).expect("");
+            let g = t_l2.trace().exp().square().mean().backward();
+            adam_l2.update(&mut t_l2, g).expect("");
+        }
+        let (w, l2) = (t_w.array(), t_l2.array());
+        for i in 0..5 {
+            assert!((w[i] - l2[i]).abs() > 1e-4, "{w:?} {l2:?}");
+        }
+    }
+
+    #[test]
+    fn test_adamw_matches_decoupled_adam() {
+        let dev: TestDevice = Default::default();
+        let mut t_w: Tensor<Rank1<5>, TestDtype, _> = dev.tensor([-0.5, -0.25, 0.1, 0.6, 1.0]);
+        let mut t_a = t_w.clone();
+        let mut adamw = AdamW::new(
+            &t_w,
+            AdamWConfig {
+                betas: [0.5, 0.25],
+                weight_decay: 1.0,
+                ..Default::default()
+            },
+        );
+        let mut adam = Adam::new(
+            &t_a,
+            AdamConfig {
+                betas: [0.5, 0.25],
+                weight_decay: Some(WeightDecay::Decoupled(1.0)),
+                ..Default::default()
+            },
+        );
+        for i in 0..10 {
+            if i == 5 {
+                adamw.cfg.lr = 1e-2;
+                adam.cfg.lr = 1e-2;
+            }
+            let g = t_w.trace().exp().square().mean().backward();
+            adamw.update(&mut t_w, g).expect("");
+            let g = t_a.trace().exp().square().mean().backward();
+            adam.update(&mut t_a, g).expect("");
+            assert_eq!(t_w.array(), t_a.array());
+        }
+    }
+
+    #[test]
+    fn test_adamw_converges() {
+        let dev: TestDevice = Default::default();
+        let mut t: Tensor<Rank1<5>, TestDtype, _> = dev.zeros();
+        let target: Tensor<Rank1<5>, TestDtype, _> = dev.tensor([-1.0, -0.5, 0.0, 0.5, 1.0]);
+        let mut opt = AdamW::new(
+            &t,
+            AdamWConfig {
+                lr: 1e-1,
+                ..Default::default()
+            },
+        );
+        for _ in 0..200 {
+            let g = (t.trace() - target.clone()).square().mean().backward();
+            opt.update(&mut t, g).expect("");
+        }
+        // decoupled weight decay shrinks the solution towards zero by a small amount
+        assert_close_with_tolerance(&t.array(), &target.array(), 2e-2);
+    }
+
+    #[test]
+    fn test_adam_param_groups() {
+        let dev: TestDevice = Default::default();
+        let mut m: (
+            Tensor<Rank1<3>, TestDtype, _>,
+            Tensor<Rank1<3>, TestDtype, _>,
+        ) = (dev.tensor([1.0, 2.0, 3.0]), dev.tensor([1.0, 2.0, 3.0]));
+        set_param_group(&mut m.0, Some("frozen"));
+        let mut opt = Adam::new(&m, Default::default());
+        opt.param_groups_mut()
+            .push(ParamGroup::new("frozen", 0.0, None));
+
+        let mut t: Tensor<Rank1<3>, TestDtype, _> = dev.tensor([1.0, 2.0, 3.0]);
+        let mut expected_opt = Adam::new(&t, Default::default());
+
+        for _ in 0..3 {
+            let loss = m.0.trace().square().sum() + m.1.trace().square().sum();
+            opt.update(&mut m, loss.backward()).expect("");
+            let loss = t.trace().square().sum();
+            expected_opt.update(&mut t, loss.backward()).expect("");
+        }
+        assert_eq!(m.0.array(), [1.0, 2.0, 3.0]);
+        assert_close(&m.1.array(), &t.array());
+    }
+
     #[test]
     fn test_unused_tensors() {
         let dev: TestDevice = Default::default();
@@ -1,4 +1,4 @@
-//! Optimizers such as [Sgd], [Adam], and [RMSprop] that can optimize neural networks.
+//! Optimizers such as [Sgd], [Adam], [AdamW], [RMSprop], and [Adagrad] that can optimize neural networks.
 //!
 //! # Initializing
 //!
@@ -6,7 +6,9 @@
 //! all the relevant parameters through the corresponding config object:
 //! - [Sgd::new()] with [SgdConfig]
 //! - [Adam::new()] with [AdamConfig]
+//! - [AdamW::new()] with [AdamWConfig]
 //! - [RMSprop::new()] with [RMSpropConfig]
+//! - [Adagrad::new()] with [AdagradConfig]
 //!
 //! # Updating network parameters
 //!
@@ -28,13 +30,15 @@
 //! opt.update(&mut model, gradients);
 //! ```
 
+mod adagrad;
 mod adam;
 mod optimizer;
 mod rmsprop;
 mod sgd;
 
-pub use adam::{Adam, AdamConfig};
-pub use optimizer::{Momentum, WeightDecay};
+pub use adagrad::{Adagrad, AdagradConfig};
+pub use adam::{Adam, AdamConfig, AdamW, AdamWConfig};
+pub use optimizer::{Momentum, ParamGroup, ParamGroups, WeightDecay};
 pub use optimizer::{Optimizer, OptimizerUpdateError, UnusedTensors};
 pub use rmsprop::{RMSprop, RMSpropConfig};
 pub use sgd::{Sgd, SgdConfig};
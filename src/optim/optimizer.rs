@@ -5,6 +5,8 @@ use crate::{
     unique_id::{HasUniqueId, UniqueId},
 };
 
+use std::vec::Vec;
+
 /// L2 and decoupled regularization methods
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
 pub enum WeightDecay<E> {
@@ -64,6 +66,61 @@ pub(super) fn momentum_to_cuda<E: Default>(wd: Option<Momentum<E>>) -> (Momentum
     }
 }
 
+/// Hyperparameters for the tensors in a named group, which override the ones in the
+/// optimizer's config during update. A tensor is in the group called `name` if its
+/// [crate::nn::tensor_collection::TensorOptions::group] is `Some(name)`. Modules can set
+/// this in their [crate::nn::tensor_collection::TensorCollection] impl, and
+/// [crate::nn::set_param_group] sets it for all the tensors of a module at runtime.
+///
+/// Tensors in a group that the optimizer has no [ParamGroup] for use the optimizer's config.
+///
+/// ```rust
+/// # use dfdx::{prelude::*, optim::*};
+/// # let dev: Cpu = Default::default();
+/// type Model = (Linear<2, 3>, Linear<3, 2>);
+/// let mut model = dev.build_module::<Model, f32>();
+/// set_param_group(&mut model.0, Some("backbone"));
+/// let mut opt: Sgd<_, f32, Cpu> = Sgd::new(&model, Default::default());
+/// opt.param_groups_mut().push(ParamGroup::new("backbone", 1e-4, None));
+/// ```
+#[derive(Debug, Clone, PartialEq)]
+pub struct ParamGroup<E> {
+    /// Name of the group
+    pub name: &'static str,
+
+    /// Learning rate of tensors in the group
+    pub lr: E,
+
+    /// Weight decay of tensors in the group
+    pub weight_decay: Option<WeightDecay<E>>,
+}
+
+impl<E> ParamGroup<E> {
+    /// Creates a group with the given hyperparameters
+    pub fn new(name: &'static str, lr: E, weight_decay: Option<WeightDecay<E>>) -> Self {
+        Self {
+            name,
+            lr,
+            weight_decay,
+        }
+    }
+}
+
+/// An optimizer with [ParamGroup]s, whose hyperparameters override the ones in the
+/// optimizer's config for the tensors in each group.
+pub trait ParamGroups<E> {
+    /// The [ParamGroup]s of the optimizer.
+    fn param_groups(&self) -> &[ParamGroup<E>];
+
+    /// Mutable access to the [ParamGroup]s, e.g. to add a group.
+    fn param_groups_mut(&mut self) -> &mut Vec<ParamGroup<E>>;
+
+    /// The first [ParamGroup] called `name`, if there is one.
+    fn param_group(&self, name: &str) -> Option<&ParamGroup<E>> {
+        self.param_groups().iter().find(|g| g.name == name)
+    }
+}
+
 /// All optimizers must implement the update function, which takes a `M`
 /// and updates all of its parameters.
 ///
@@ -12,7 +12,7 @@ use crate::{
     tensor::*,
 };
 
-use super::{Optimizer, OptimizerUpdateError, UnusedTensors, WeightDecay};
+use super::{Optimizer, OptimizerUpdateError, ParamGroup, ParamGroups, UnusedTensors, WeightDecay};
 
 /// Configuration of hyperparameters for [RMSprop].
 #[derive(Debug, Clone, Copy)]
@@ -82,6 +82,8 @@ pub struct RMSprop<M, E: Dtype, D: DeviceStorage> {
     /// Hyperparameter configuration
     pub cfg: RMSpropConfig<E>,
 
+    param_groups: std::vec::Vec<ParamGroup<E>>,
+
     step: usize,
     momentums: Gradients<E, D>,
     square_avg: Gradients<E, D>,
@@ -98,6 +100,7 @@ impl<M, E: Dtype, D: DeviceStorage> RMSprop<M, E, D> {
     pub fn new(_model: &M, cfg: RMSpropConfig<E>) -> Self {
         Self {
             cfg,
+            param_groups: Default::default(),
             step: 0,
             momentums: Default::default(),
             square_avg: Default::default(),
@@ -121,8 +124,8 @@ pub(super) trait RMSpropKernel<E: Dtype>: DeviceStorage {
     ) -> Result<(), Self::Err>;
 }
 
-impl<M, E: Dtype, D: RMSpropKernel<E> + OneFillStorage<E>> TensorVisitor<E, D>
-    for RMSprop<M, E, D>
+impl<M: TensorCollection<E, D>, E: Dtype, D: RMSpropKernel<E> + OneFillStorage<E>>
+    TensorVisitor<E, D> for RMSprop<M, E, D>
 {
     type Viewer = ViewTensorMut;
     type Err = D::Err;
@@ -136,6 +139,14 @@ impl<M, E: Dtype, D: RMSpropKernel<E> + OneFillStorage<E>> TensorVisitor<E, D>
         if !opts.do_gradient_update {
             return Ok(());
         }
+        let cfg = match opts.group.and_then(|name| self.param_group(name)) {
+            Some(group) => RMSpropConfig {
+                lr: group.lr,
+                weight_decay: group.weight_decay,
+                ..self.cfg
+            },
+            None => self.cfg,
+        };
         let g = self.gradients.remove(p);
         match g {
             None => self.unused.add(p),
@@ -149,7 +160,7 @@ impl<M, E: Dtype, D: RMSpropKernel<E> + OneFillStorage<E>> TensorVisitor<E, D>
                 }
 
                 p.device
-                    .update(&self.cfg, Arc::make_mut(&mut p.data), m, sa, ga, g)?;
+                    .update(&cfg, Arc::make_mut(&mut p.data), m, sa, ga, g)?;
             }
         }
         Ok(())
@@ -180,10 +191,25 @@ impl<M: TensorCollection<E, D>, D: RMSpropKernel<E> + OneFillStorage<E>, E: Dtyp
     }
 }
 
+impl<M, E: Dtype, D: DeviceStorage> ParamGroups<E> for RMSprop<M, E, D> {
+    fn param_groups(&self) -> &[ParamGroup<E>] {
+        &self.param_groups
+    }
+
+    fn param_groups_mut(&mut self) -> &mut std::vec::Vec<ParamGroup<E>> {
+        &mut self.param_groups
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
-    use crate::{shapes::*, tensor_ops::*, tests::*};
+    use crate::{
+        nn::{builders::*, DeviceBuildExt, Module},
+        shapes::*,
+        tensor_ops::*,
+        tests::*,
+    };
 
     fn test_matches_expected(cfg: RMSpropConfig<TestDtype>, expected: [[TestDtype; 5]; 5]) {
         let dev: TestDevice = Default::default();
@@ -330,6 +356,33 @@ mod tests {
         test_matches_expected(cfg, EXPECTED);
     }
 
+    #[test]
+    fn test_rmsprop_fits_linear() {
+        let dev: TestDevice = Default::default();
+        let mut model = dev.build_module::<Linear<5, 2>, TestDtype>();
+        let x: Tensor<Rank2<8, 5>, TestDtype, _> = dev.sample_normal();
+        let y: Tensor<Rank2<8, 2>, TestDtype, _> = dev.sample_normal();
+        let mut opt = RMSprop::new(
+            &model,
+            RMSpropConfig {
+                lr: 1e-3,
+                momentum: Some(0.5),
+                weight_decay: Some(WeightDecay::L2(1e-4)),
+                ..Default::default()
+            },
+        );
+
+        let mut last_loss = TestDtype::INFINITY;
+        for _ in 0..20 {
+            let loss = crate::losses::mse_loss(model.forward(x.trace()), y.clone());
+            let loss_value = loss.array();
+            assert!(loss_value < last_loss, "{loss_value} >= {last_loss}");
+            last_loss = loss_value;
+            let gradients = loss.backward();
+            opt.update(&mut model, gradients).expect("");
+        }
+    }
+
     #[test]
     fn test_unused_tensors() {
         let dev: TestDevice = Default::default();
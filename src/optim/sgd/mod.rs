@@ -116,6 +116,8 @@ pub struct Sgd<M, E: Dtype, D: DeviceStorage> {
     /// Hyperparameter configuration
     pub cfg: SgdConfig<E>,
 
+    param_groups: std::vec::Vec<ParamGroup<E>>,
+
     velocity: Gradients<E, D>,
     gradients: Gradients<E, D>,
 
@@ -129,6 +131,7 @@ impl<M, E: Dtype, D: DeviceStorage> Sgd<M, E, D> {
     pub fn new(_model: &M, cfg: SgdConfig<E>) -> Self {
         Self {
             cfg,
+            param_groups: Default::default(),
             velocity: Default::default(),
             gradients: Default::default(),
             unused: Default::default(),
@@ -147,7 +150,7 @@ pub(super) trait SgdKernel<E: Dtype>: DeviceStorage {
     ) -> Result<(), Self::Err>;
 }
 
-impl<E: Dtype, D: SgdKernel<E>, M> TensorVisitor<E, D> for Sgd<M, E, D> {
+impl<E: Dtype, D: SgdKernel<E>, M: TensorCollection<E, D>> TensorVisitor<E, D> for Sgd<M, E, D> {
     type Viewer = ViewTensorMut;
     type Err = D::Err;
 
@@ -160,13 +163,21 @@ impl<E: Dtype, D: SgdKernel<E>, M> TensorVisitor<E, D> for Sgd<M, E, D> {
         if !opts.do_gradient_update {
             return Ok(());
         }
+        let cfg = match opts.group.and_then(|name| self.param_group(name)) {
+            Some(group) => SgdConfig {
+                lr: group.lr,
+                weight_decay: group.weight_decay,
+                ..self.cfg
+            },
+            None => self.cfg,
+        };
         let g = self.gradients.remove(p);
         match g {
             None => self.unused.add(p),
             Some(g) => {
                 let v = self.velocity.get_or_alloc_mut(p)?;
                 p.device
-                    .update(&self.cfg, std::sync::Arc::make_mut(&mut p.data), v, g)?;
+                    .update(&cfg, std::sync::Arc::make_mut(&mut p.data), v, g)?;
             }
         }
         Ok(())
@@ -193,10 +204,26 @@ impl<M: TensorCollection<E, D>, D: SgdKernel<E>, E: Dtype> Optimizer<M, D, E> fo
     }
 }
 
+impl<M, E: Dtype, D: DeviceStorage> ParamGroups<E> for Sgd<M, E, D> {
+    fn param_groups(&self) -> &[ParamGroup<E>] {
+        &self.param_groups
+    }
+
+    fn param_groups_mut(&mut self) -> &mut std::vec::Vec<ParamGroup<E>> {
+        &mut self.param_groups
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
-    use crate::{shapes::*, tensor::*, tensor_ops::*, tests::*};
+    use crate::{
+        nn::{builders::*, *},
+        shapes::*,
+        tensor::*,
+        tensor_ops::*,
+        tests::*,
+    };
 
     #[test]
     fn test_perfect_sgd() {
@@ -434,4 +461,67 @@ mod tests {
         let mut opt = Sgd::new(&t, Default::default());
         opt.update(&mut t, Default::default()).expect_err("");
     }
+
+    #[test]
+    fn test_sgd_param_groups() {
+        let dev: TestDevice = Default::default();
+        let mut m: (
+            Tensor<Rank1<3>, TestDtype, _>,
+            Tensor<Rank1<3>, TestDtype, _>,
+        ) = (dev.ones(), dev.ones());
+        let mut sgd = Sgd::new(
+            &m,
+            SgdConfig {
+                lr: 0.1,
+                momentum: None,
+                weight_decay: None,
+            },
+        );
+        set_param_group(&mut m.0, Some("slow"));
+        set_param_group(&mut m.1, Some("fast"));
+        sgd.param_groups_mut()
+            .push(ParamGroup::new("slow", 0.01, None));
+        sgd.param_groups_mut()
+            .push(ParamGroup::new("fast", 1.0, Some(WeightDecay::L2(0.5))));
+
+        let loss = m.0.trace().sum() + m.1.trace().sum();
+        sgd.update(&mut m, loss.backward()).expect("");
+        assert_close(&m.0.array(), &[0.99; 3]);
+        assert_close(&m.1.array(), &[-0.5; 3]);
+
+        // tensors that aren't in a group fall back to the config
+        set_param_group(&mut m.1, None);
+        let loss = m.0.trace().sum() + m.1.trace().sum();
+        sgd.update(&mut m, loss.backward()).expect("");
+        assert_close(&m.0.array(), &[0.98; 3]);
+        assert_close(&m.1.array(), &[-0.6; 3]);
+    }
+
+    #[test]
+    fn test_sgd_frozen() {
+        let dev: TestDevice = Default::default();
+        type Model = (Linear<2, 3>, Linear<3, 2>);
+        let mut model = dev.build_module::<Model, TestDtype>();
+        freeze(&mut model.0);
+        assert_eq!(model.num_trainable_params(), 8);
+
+        // the freeze is stored in the model, not the optimizer
+        let mut sgd = Sgd::new(&model, Default::default());
+        let m0 = model.clone();
+        let x: Tensor<Rank1<2>, TestDtype, _> = dev.sample_normal();
+        let g = model.forward(x.trace()).square().mean().backward();
+        assert_ne!(g.get(&model.0.weight).array(), [[0.0; 2]; 3]);
+        sgd.update(&mut model, g).expect("");
+        assert_eq!(model.0.weight.array(), m0.0.weight.array());
+        assert_eq!(model.0.bias.array(), m0.0.bias.array());
+        assert_ne!(model.1.weight.array(), m0.1.weight.array());
+        assert_ne!(model.1.bias.array(), m0.1.bias.array());
+
+        unfreeze(&mut model.0);
+        assert_eq!(model.num_trainable_params(), 17);
+        let g = model.forward(x.trace()).square().mean().backward();
+        sgd.update(&mut model, g).expect("");
+        assert_ne!(model.0.weight.array(), m0.0.weight.array());
+        assert_ne!(model.0.bias.array(), m0.0.bias.array());
+    }
 }
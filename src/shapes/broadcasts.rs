@@ -74,6 +74,136 @@ broadcast_to!(3, (M, N, P), 4, (M, N, O, P), Axis<2>);
 broadcast_to!(3, (M, O, P), 4, (M, N, O, P), Axis<1>);
 broadcast_to!(3, (N, O, P), 4, (M, N, O, P), Axis<0>);
 
+/// Marker for shapes that `Small` can be broadcasted to the same way numpy does it:
+/// `Small` is lined up with the trailing dimensions of `Self`, and is expanded along
+/// the leading axes it is missing and along its dimensions that are `Const<1>`.
+/// `Ax` are the expanded axes of `Self`, and [ReduceShape::Reduced] is `Small` without
+/// its `Const<1>` dimensions.
+pub trait BroadcastShapeTrailing<Small: Shape, Ax: Axes>: ReduceShape<Ax> {}
+
+macro_rules! broadcast_trailing {
+    (($($SrcDims:tt),*), ($($DstDims:tt),*), $Axes:ty) => {
+        impl<$($DstDims: Dim, )*> BroadcastShapeTrailing<($($SrcDims, )*), $Axes> for ($($DstDims, )*) {}
+    };
+}
+broadcast_trailing!((), (M), Axis<0>);
+broadcast_trailing!((C1), (M), Axis<0>);
+broadcast_trailing!((), (M, N), Axes2<0, 1>);
+broadcast_trailing!((N), (M, N), Axis<0>);
+broadcast_trailing!((C1), (M, N), Axes2<0, 1>);
+broadcast_trailing!((M, C1), (M, N), Axis<1>);
+broadcast_trailing!((C1, N), (M, N), Axis<0>);
+broadcast_trailing!((C1, C1), (M, N), Axes2<0, 1>);
+broadcast_trailing!((), (M, N, O), Axes3<0, 1, 2>);
+broadcast_trailing!((O), (M, N, O), Axes2<0, 1>);
+broadcast_trailing!((C1), (M, N, O), Axes3<0, 1, 2>);
+broadcast_trailing!((N, O), (M, N, O), Axis<0>);
+broadcast_trailing!((N, C1), (M, N, O), Axes2<0, 2>);
+broadcast_trailing!((C1, O), (M, N, O), Axes2<0, 1>);
+broadcast_trailing!((C1, C1), (M, N, O), Axes3<0, 1, 2>);
+broadcast_trailing!((M, N, C1), (M, N, O), Axis<2>);
+broadcast_trailing!((M, C1, O), (M, N, O), Axis<1>);
+broadcast_trailing!((M, C1, C1), (M, N, O), Axes2<1, 2>);
+broadcast_trailing!((C1, N, O), (M, N, O), Axis<0>);
+broadcast_trailing!((C1, N, C1), (M, N, O), Axes2<0, 2>);
+broadcast_trailing!((C1, C1, O), (M, N, O), Axes2<0, 1>);
+broadcast_trailing!((C1, C1, C1), (M, N, O), Axes3<0, 1, 2>);
+broadcast_trailing!((), (M, N, O, P), Axes4<0, 1, 2, 3>);
+broadcast_trailing!((P), (M, N, O, P), Axes3<0, 1, 2>);
+broadcast_trailing!((C1), (M, N, O, P), Axes4<0, 1, 2, 3>);
+broadcast_trailing!((O, P), (M, N, O, P), Axes2<0, 1>);
+broadcast_trailing!((O, C1), (M, N, O, P), Axes3<0, 1, 3>);
+broadcast_trailing!((C1, P), (M, N, O, P), Axes3<0, 1, 2>);
+broadcast_trailing!((C1, C1), (M, N, O, P), Axes4<0, 1, 2, 3>);
+broadcast_trailing!((N, O, P), (M, N, O, P), Axis<0>);
+broadcast_trailing!((N, O, C1), (M, N, O, P), Axes2<0, 3>);
+broadcast_trailing!((N, C1, P), (M, N, O, P), Axes2<0, 2>);
+broadcast_trailing!((N, C1, C1), (M, N, O, P), Axes3<0, 2, 3>);
+broadcast_trailing!((C1, O, P), (M, N, O, P), Axes2<0, 1>);
+broadcast_trailing!((C1, O, C1), (M, N, O, P), Axes3<0, 1, 3>);
+broadcast_trailing!((C1, C1, P), (M, N, O, P), Axes3<0, 1, 2>);
+broadcast_trailing!((C1, C1, C1), (M, N, O, P), Axes4<0, 1, 2, 3>);
+broadcast_trailing!((M, N, O, C1), (M, N, O, P), Axis<3>);
+broadcast_trailing!((M, N, C1, P), (M, N, O, P), Axis<2>);
+broadcast_trailing!((M, N, C1, C1), (M, N, O, P), Axes2<2, 3>);
+broadcast_trailing!((M, C1, O, P), (M, N, O, P), Axis<1>);
+broadcast_trailing!((M, C1, O, C1), (M, N, O, P), Axes2<1, 3>);
+broadcast_trailing!((M, C1, C1, P), (M, N, O, P), Axes2<1, 2>);
+broadcast_trailing!((M, C1, C1, C1), (M, N, O, P), Axes3<1, 2, 3>);
+broadcast_trailing!((C1, N, O, P), (M, N, O, P), Axis<0>);
+broadcast_trailing!((C1, N, O, C1), (M, N, O, P), Axes2<0, 3>);
+broadcast_trailing!((C1, N, C1, P), (M, N, O, P), Axes2<0, 2>);
+broadcast_trailing!((C1, N, C1, C1), (M, N, O, P), Axes3<0, 2, 3>);
+broadcast_trailing!((C1, C1, O, P), (M, N, O, P), Axes2<0, 1>);
+broadcast_trailing!((C1, C1, O, C1), (M, N, O, P), Axes3<0, 1, 3>);
+broadcast_trailing!((C1, C1, C1, P), (M, N, O, P), Axes3<0, 1, 2>);
+broadcast_trailing!((C1, C1, C1, C1), (M, N, O, P), Axes4<0, 1, 2, 3>);
+
+/// Marker for shapes that can have their [Axes] `Ax` reduced to size 1 instead of
+/// removed. See Self::KeptDim for the resulting type.
+pub trait ReduceShapeKeepDim<Ax: Axes>: ReduceShape<Ax> {
+    /// `Self` with the dimensions of `Ax` replaced with `Const<1>`.
+    type KeptDim: Shape + ReduceShapeTo<Self::Reduced, Ax>;
+
+    #[inline]
+    fn keep_dim(&self) -> Self::KeptDim {
+        let src_dims = self.concrete();
+        let mut dst_dims: <Self::KeptDim as Shape>::Concrete = Default::default();
+        for i in 0..Self::NUM_DIMS {
+            dst_dims[i] = if Ax::as_array().into_iter().any(|x| x == i as isize) {
+                1
+            } else {
+                src_dims[i]
+            };
+        }
+        Self::KeptDim::from_concrete(&dst_dims).unwrap()
+    }
+}
+
+impl ReduceShapeKeepDim<Axis<0>> for () {
+    type KeptDim = ();
+}
+
+macro_rules! keep_dim {
+    ($DstNum:literal, ($($DstDims:tt),*), $Axes:ty, ($($KeptDims:ty),*)) => {
+        impl<$($DstDims: Dim, )*> ReduceShapeKeepDim<$Axes> for ($($DstDims, )*) {
+            type KeptDim = ($($KeptDims, )*);
+        }
+        impl ReduceShapeKeepDim<$Axes> for [usize; $DstNum] {
+            type KeptDim = [usize; $DstNum];
+        }
+    };
+}
+type C1 = Const<1>;
+keep_dim!(1, (M), Axis<0>, (C1));
+keep_dim!(2, (M, N), Axes2<0, 1>, (C1, C1));
+keep_dim!(3, (M, N, O), Axes3<0, 1, 2>, (C1, C1, C1));
+keep_dim!(4, (M, N, O, P), Axes4<0, 1, 2, 3>, (C1, C1, C1, C1));
+keep_dim!(5, (M, N, O, P, Q), Axes5<0, 1, 2, 3, 4>, (C1, C1, C1, C1, C1));
+keep_dim!(6, (M, N, O, P, Q, R), Axes6<0, 1, 2, 3, 4, 5>, (C1, C1, C1, C1, C1, C1));
+keep_dim!(2, (M, N), Axis<1>, (M, C1));
+keep_dim!(2, (M, N), Axis<0>, (C1, N));
+keep_dim!(3, (M, N, O), Axes2<1, 2>, (M, C1, C1));
+keep_dim!(3, (M, N, O), Axes2<0, 2>, (C1, N, C1));
+keep_dim!(3, (M, N, O), Axes2<0, 1>, (C1, C1, O));
+keep_dim!(4, (M, N, O, P), Axes3<1, 2, 3>, (M, C1, C1, C1));
+keep_dim!(4, (M, N, O, P), Axes3<0, 2, 3>, (C1, N, C1, C1));
+keep_dim!(4, (M, N, O, P), Axes3<0, 1, 3>, (C1, C1, O, C1));
+keep_dim!(4, (M, N, O, P), Axes3<0, 1, 2>, (C1, C1, C1, P));
+keep_dim!(3, (M, N, O), Axis<2>, (M, N, C1));
+keep_dim!(3, (M, N, O), Axis<1>, (M, C1, O));
+keep_dim!(3, (M, N, O), Axis<0>, (C1, N, O));
+keep_dim!(4, (M, N, O, P), Axes2<2, 3>, (M, N, C1, C1));
+keep_dim!(4, (M, N, O, P), Axes2<1, 3>, (M, C1, O, C1));
+keep_dim!(4, (M, N, O, P), Axes2<0, 3>, (C1, N, O, C1));
+keep_dim!(4, (M, N, O, P), Axes2<1, 2>, (M, C1, C1, P));
+keep_dim!(4, (M, N, O, P), Axes2<0, 2>, (C1, N, C1, P));
+keep_dim!(4, (M, N, O, P), Axes2<0, 1>, (C1, C1, O, P));
+keep_dim!(4, (M, N, O, P), Axis<3>, (M, N, O, C1));
+keep_dim!(4, (M, N, O, P), Axis<2>, (M, N, C1, P));
+keep_dim!(4, (M, N, O, P), Axis<1>, (M, C1, O, P));
+keep_dim!(4, (M, N, O, P), Axis<0>, (C1, N, O, P));
+
 /// Internal implementation for broadcasting strides
 pub trait BroadcastStridesTo<S: Shape, Ax>: Shape + BroadcastShapeTo<S, Ax> {
     fn check(&self, dst: &S);
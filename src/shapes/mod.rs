@@ -8,10 +8,11 @@ mod shape;
 
 pub(crate) use axes::Axes;
 pub(crate) use broadcasts::{
-    BroadcastShapeTo, BroadcastStridesTo, ReduceShape, ReduceShapeTo, ReduceStridesTo,
+    BroadcastShapeTo, BroadcastShapeTrailing, BroadcastStridesTo, ReduceShape, ReduceShapeKeepDim,
+    ReduceShapeTo, ReduceStridesTo,
 };
-pub(crate) use permutes::{PermuteShapeTo, PermuteStridesTo};
-pub(crate) use replace_dim::{RemoveDimTo, ReplaceDimTo};
+pub(crate) use permutes::{MoveAxisShape, PermuteShapeTo, PermuteStridesTo, SwapAxesShape};
+pub(crate) use replace_dim::{RemoveAxis, RemoveDimTo, ReplaceAxisWith, ReplaceDimTo};
 
 #[allow(unused_imports)]
 pub(crate) use same_numel::HasSameNumelAs;
@@ -170,6 +170,187 @@ permutations!([0, 1, 2, 3]);
 permutations!([0, 1, 2, 3, 4]);
 permutations!([0, 1, 2, 3, 4, 5]);
 
+/// Marker for shapes that can have axes `A` and `B` swapped, where
+/// [SwapAxesShape::Perm] is the permutation that does so.
+pub trait SwapAxesShape<A, B>: Shape {
+    type Swapped: Shape;
+    type Perm: Axes;
+}
+
+/// Marker for shapes that can move axis `Src` to position `Dst`, shifting the axes
+/// in between over by one. [MoveAxisShape::Perm] is the permutation that does so.
+pub trait MoveAxisShape<Src, Dst>: Shape {
+    type Moved: Shape;
+    type Perm: Axes;
+}
+
+macro_rules! impl_swap {
+    ([$($Vars:tt),*], $A:tt, $B:tt, $Axes:ident, [$($P:tt),*]) => {
+        impl<$($Vars: Dim, )*> SwapAxesShape<Axis<$A>, Axis<$B>> for ($($Vars, )*) {
+            type Swapped = ($(d!($P), )*);
+            type Perm = $Axes<$($P),*>;
+        }
+    };
+}
+
+macro_rules! impl_move {
+    ([$($Vars:tt),*], $Src:tt, $Dst:tt, $Axes:ident, [$($P:tt),*]) => {
+        impl<$($Vars: Dim, )*> MoveAxisShape<Axis<$Src>, Axis<$Dst>> for ($($Vars, )*) {
+            type Moved = ($(d!($P), )*);
+            type Perm = $Axes<$($P),*>;
+        }
+    };
+}
+
+impl_swap!([D1, D2], 0, 1, Axes2, [1, 0]);
+impl_swap!([D1, D2], 1, 0, Axes2, [1, 0]);
+
+impl_swap!([D1, D2, D3], 0, 1, Axes3, [1, 0, 2]);
+impl_swap!([D1, D2, D3], 1, 0, Axes3, [1, 0, 2]);
+impl_swap!([D1, D2, D3], 0, 2, Axes3, [2, 1, 0]);
+impl_swap!([D1, D2, D3], 2, 0, Axes3, [2, 1, 0]);
+impl_swap!([D1, D2, D3], 1, 2, Axes3, [0, 2, 1]);
+impl_swap!([D1, D2, D3], 2, 1, Axes3, [0, 2, 1]);
+
+impl_swap!([D1, D2, D3, D4], 0, 1, Axes4, [1, 0, 2, 3]);
+impl_swap!([D1, D2, D3, D4], 1, 0, Axes4, [1, 0, 2, 3]);
+impl_swap!([D1, D2, D3, D4], 0, 2, Axes4, [2, 1, 0, 3]);
+impl_swap!([D1, D2, D3, D4], 2, 0, Axes4, [2, 1, 0, 3]);
+impl_swap!([D1, D2, D3, D4], 0, 3, Axes4, [3, 1, 2, 0]);
+impl_swap!([D1, D2, D3, D4], 3, 0, Axes4, [3, 1, 2, 0]);
+impl_swap!([D1, D2, D3, D4], 1, 2, Axes4, [0, 2, 1, 3]);
+impl_swap!([D1, D2, D3, D4], 2, 1, Axes4, [0, 2, 1, 3]);
+impl_swap!([D1, D2, D3, D4], 1, 3, Axes4, [0, 3, 2, 1]);
+impl_swap!([D1, D2, D3, D4], 3, 1, Axes4, [0, 3, 2, 1]);
+impl_swap!([D1, D2, D3, D4], 2, 3, Axes4, [0, 1, 3, 2]);
+impl_swap!([D1, D2, D3, D4], 3, 2, Axes4, [0, 1, 3, 2]);
+
+impl_swap!([D1, D2, D3, D4, D5], 0, 1, Axes5, [1, 0, 2, 3, 4]);
+impl_swap!([D1, D2, D3, D4, D5], 1, 0, Axes5, [1, 0, 2, 3, 4]);
+impl_swap!([D1, D2, D3, D4, D5], 0, 2, Axes5, [2, 1, 0, 3, 4]);
+impl_swap!([D1, D2, D3, D4, D5], 2, 0, Axes5, [2, 1, 0, 3, 4]);
+impl_swap!([D1, D2, D3, D4, D5], 0, 3, Axes5, [3, 1, 2, 0, 4]);
+impl_swap!([D1, D2, D3, D4, D5], 3, 0, Axes5, [3, 1, 2, 0, 4]);
+impl_swap!([D1, D2, D3, D4, D5], 0, 4, Axes5, [4, 1, 2, 3, 0]);
+impl_swap!([D1, D2, D3, D4, D5], 4, 0, Axes5, [4, 1, 2, 3, 0]);
+impl_swap!([D1, D2, D3, D4, D5], 1, 2, Axes5, [0, 2, 1, 3, 4]);
+impl_swap!([D1, D2, D3, D4, D5], 2, 1, Axes5, [0, 2, 1, 3, 4]);
+impl_swap!([D1, D2, D3, D4, D5], 1, 3, Axes5, [0, 3, 2, 1, 4]);
+impl_swap!([D1, D2, D3, D4, D5], 3, 1, Axes5, [0, 3, 2, 1, 4]);
+impl_swap!([D1, D2, D3, D4, D5], 1, 4, Axes5, [0, 4, 2, 3, 1]);
+impl_swap!([D1, D2, D3, D4, D5], 4, 1, Axes5, [0, 4, 2, 3, 1]);
+impl_swap!([D1, D2, D3, D4, D5], 2, 3, Axes5, [0, 1, 3, 2, 4]);
+impl_swap!([D1, D2, D3, D4, D5], 3, 2, Axes5, [0, 1, 3, 2, 4]);
+impl_swap!([D1, D2, D3, D4, D5], 2, 4, Axes5, [0, 1, 4, 3, 2]);
+impl_swap!([D1, D2, D3, D4, D5], 4, 2, Axes5, [0, 1, 4, 3, 2]);
+impl_swap!([D1, D2, D3, D4, D5], 3, 4, Axes5, [0, 1, 2, 4, 3]);
+impl_swap!([D1, D2, D3, D4, D5], 4, 3, Axes5, [0, 1, 2, 4, 3]);
+
+impl_swap!([D1, D2, D3, D4, D5, D6], 0, 1, Axes6, [1, 0, 2, 3, 4, 5]);
+impl_swap!([D1, D2, D3, D4, D5, D6], 1, 0, Axes6, [1, 0, 2, 3, 4, 5]);
+impl_swap!([D1, D2, D3, D4, D5, D6], 0, 2, Axes6, [2, 1, 0, 3, 4, 5]);
+impl_swap!([D1, D2, D3, D4, D5, D6], 2, 0, Axes6, [2, 1, 0, 3, 4, 5]);
+impl_swap!([D1, D2, D3, D4, D5, D6], 0, 3, Axes6, [3, 1, 2, 0, 4, 5]);
+impl_swap!([D1, D2, D3, D4, D5, D6], 3, 0, Axes6, [3, 1, 2, 0, 4, 5]);
+impl_swap!([D1, D2, D3, D4, D5, D6], 0, 4, Axes6, [4, 1, 2, 3, 0, 5]);
+impl_swap!([D1, D2, D3, D4, D5, D6], 4, 0, Axes6, [4, 1, 2, 3, 0, 5]);
+impl_swap!([D1, D2, D3, D4, D5, D6], 0, 5, Axes6, [5, 1, 2, 3, 4, 0]);
+impl_swap!([D1, D2, D3, D4, D5, D6], 5, 0, Axes6, [5, 1, 2, 3, 4, 0]);
+impl_swap!([D1, D2, D3, D4, D5, D6], 1, 2, Axes6, [0, 2, 1, 3, 4, 5]);
+impl_swap!([D1, D2, D3, D4, D5, D6], 2, 1, Axes6, [0, 2, 1, 3, 4, 5]);
+impl_swap!([D1, D2, D3, D4, D5, D6], 1, 3, Axes6, [0, 3, 2, 1, 4, 5]);
+impl_swap!([D1, D2, D3, D4, D5, D6], 3, 1, Axes6, [0, 3, 2, 1, 4, 5]);
+impl_swap!([D1, D2, D3, D4, D5, D6], 1, 4, Axes6, [0, 4, 2, 3, 1, 5]);
+impl_swap!([D1, D2, D3, D4, D5, D6], 4, 1, Axes6, [0, 4, 2, 3, 1, 5]);
+impl_swap!([D1, D2, D3, D4, D5, D6], 1, 5, Axes6, [0, 5, 2, 3, 4, 1]);
+impl_swap!([D1, D2, D3, D4, D5, D6], 5, 1, Axes6, [0, 5, 2, 3, 4, 1]);
+impl_swap!([D1, D2, D3, D4, D5, D6], 2, 3, Axes6, [0, 1, 3, 2, 4, 5]);
+impl_swap!([D1, D2, D3, D4, D5, D6], 3, 2, Axes6, [0, 1, 3, 2, 4, 5]);
+impl_swap!([D1, D2, D3, D4, D5, D6], 2, 4, Axes6, [0, 1, 4, 3, 2, 5]);
+impl_swap!([D1, D2, D3, D4, D5, D6], 4, 2, Axes6, [0, 1, 4, 3, 2, 5]);
+impl_swap!([D1, D2, D3, D4, D5, D6], 2, 5, Axes6, [0, 1, 5, 3, 4, 2]);
+impl_swap!([D1, D2, D3, D4, D5, D6], 5, 2, Axes6, [0, 1, 5, 3, 4, 2]);
+impl_swap!([D1, D2, D3, D4, D5, D6], 3, 4, Axes6, [0, 1, 2, 4, 3, 5]);
+impl_swap!([D1, D2, D3, D4, D5, D6], 4, 3, Axes6, [0, 1, 2, 4, 3, 5]);
+impl_swap!([D1, D2, D3, D4, D5, D6], 3, 5, Axes6, [0, 1, 2, 5, 4, 3]);
+impl_swap!([D1, D2, D3, D4, D5, D6], 5, 3, Axes6, [0, 1, 2, 5, 4, 3]);
+impl_swap!([D1, D2, D3, D4, D5, D6], 4, 5, Axes6, [0, 1, 2, 3, 5, 4]);
+impl_swap!([D1, D2, D3, D4, D5, D6], 5, 4, Axes6, [0, 1, 2, 3, 5, 4]);
+impl_move!([D1, D2], 0, 1, Axes2, [1, 0]);
+impl_move!([D1, D2], 1, 0, Axes2, [1, 0]);
+
+impl_move!([D1, D2, D3], 0, 1, Axes3, [1, 0, 2]);
+impl_move!([D1, D2, D3], 0, 2, Axes3, [1, 2, 0]);
+impl_move!([D1, D2, D3], 1, 0, Axes3, [1, 0, 2]);
+impl_move!([D1, D2, D3], 1, 2, Axes3, [0, 2, 1]);
+impl_move!([D1, D2, D3], 2, 0, Axes3, [2, 0, 1]);
+impl_move!([D1, D2, D3], 2, 1, Axes3, [0, 2, 1]);
+
+impl_move!([D1, D2, D3, D4], 0, 1, Axes4, [1, 0, 2, 3]);
+impl_move!([D1, D2, D3, D4], 0, 2, Axes4, [1, 2, 0, 3]);
+impl_move!([D1, D2, D3, D4], 0, 3, Axes4, [1, 2, 3, 0]);
+impl_move!([D1, D2, D3, D4], 1, 0, Axes4, [1, 0, 2, 3]);
+impl_move!([D1, D2, D3, D4], 1, 2, Axes4, [0, 2, 1, 3]);
+impl_move!([D1, D2, D3, D4], 1, 3, Axes4, [0, 2, 3, 1]);
+impl_move!([D1, D2, D3, D4], 2, 0, Axes4, [2, 0, 1, 3]);
+impl_move!([D1, D2, D3, D4], 2, 1, Axes4, [0, 2, 1, 3]);
+impl_move!([D1, D2, D3, D4], 2, 3, Axes4, [0, 1, 3, 2]);
+impl_move!([D1, D2, D3, D4], 3, 0, Axes4, [3, 0, 1, 2]);
+impl_move!([D1, D2, D3, D4], 3, 1, Axes4, [0, 3, 1, 2]);
+impl_move!([D1, D2, D3, D4], 3, 2, Axes4, [0, 1, 3, 2]);
+
+impl_move!([D1, D2, D3, D4, D5], 0, 1, Axes5, [1, 0, 2, 3, 4]);
+impl_move!([D1, D2, D3, D4, D5], 0, 2, Axes5, [1, 2, 0, 3, 4]);
+impl_move!([D1, D2, D3, D4, D5], 0, 3, Axes5, [1, 2, 3, 0, 4]);
+impl_move!([D1, D2, D3, D4, D5], 0, 4, Axes5, [1, 2, 3, 4, 0]);
+impl_move!([D1, D2, D3, D4, D5], 1, 0, Axes5, [1, 0, 2, 3, 4]);
+impl_move!([D1, D2, D3, D4, D5], 1, 2, Axes5, [0, 2, 1, 3, 4]);
+impl_move!([D1, D2, D3, D4, D5], 1, 3, Axes5, [0, 2, 3, 1, 4]);
+impl_move!([D1, D2, D3, D4, D5], 1, 4, Axes5, [0, 2, 3, 4, 1]);
+impl_move!([D1, D2, D3, D4, D5], 2, 0, Axes5, [2, 0, 1, 3, 4]);
+impl_move!([D1, D2, D3, D4, D5], 2, 1, Axes5, [0, 2, 1, 3, 4]);
+impl_move!([D1, D2, D3, D4, D5], 2, 3, Axes5, [0, 1, 3, 2, 4]);
+impl_move!([D1, D2, D3, D4, D5], 2, 4, Axes5, [0, 1, 3, 4, 2]);
+impl_move!([D1, D2, D3, D4, D5], 3, 0, Axes5, [3, 0, 1, 2, 4]);
+impl_move!([D1, D2, D3, D4, D5], 3, 1, Axes5, [0, 3, 1, 2, 4]);
+impl_move!([D1, D2, D3, D4, D5], 3, 2, Axes5, [0, 1, 3, 2, 4]);
+impl_move!([D1, D2, D3, D4, D5], 3, 4, Axes5, [0, 1, 2, 4, 3]);
+impl_move!([D1, D2, D3, D4, D5], 4, 0, Axes5, [4, 0, 1, 2, 3]);
+impl_move!([D1, D2, D3, D4, D5], 4, 1, Axes5, [0, 4, 1, 2, 3]);
+impl_move!([D1, D2, D3, D4, D5], 4, 2, Axes5, [0, 1, 4, 2, 3]);
+impl_move!([D1, D2, D3, D4, D5], 4, 3, Axes5, [0, 1, 2, 4, 3]);
+
+impl_move!([D1, D2, D3, D4, D5, D6], 0, 1, Axes6, [1, 0, 2, 3, 4, 5]);
+impl_move!([D1, D2, D3, D4, D5, D6], 0, 2, Axes6, [1, 2, 0, 3, 4, 5]);
+impl_move!([D1, D2, D3, D4, D5, D6], 0, 3, Axes6, [1, 2, 3, 0, 4, 5]);
+impl_move!([D1, D2, D3, D4, D5, D6], 0, 4, Axes6, [1, 2, 3, 4, 0, 5]);
+impl_move!([D1, D2, D3, D4, D5, D6], 0, 5, Axes6, [1, 2, 3, 4, 5, 0]);
+impl_move!([D1, D2, D3, D4, D5, D6], 1, 0, Axes6, [1, 0, 2, 3, 4, 5]);
+impl_move!([D1, D2, D3, D4, D5, D6], 1, 2, Axes6, [0, 2, 1, 3, 4, 5]);
+impl_move!([D1, D2, D3, D4, D5, D6], 1, 3, Axes6, [0, 2, 3, 1, 4, 5]);
+impl_move!([D1, D2, D3, D4, D5, D6], 1, 4, Axes6, [0, 2, 3, 4, 1, 5]);
+impl_move!([D1, D2, D3, D4, D5, D6], 1, 5, Axes6, [0, 2, 3, 4, 5, 1]);
+impl_move!([D1, D2, D3, D4, D5, D6], 2, 0, Axes6, [2, 0, 1, 3, 4, 5]);
+impl_move!([D1, D2, D3, D4, D5, D6], 2, 1, Axes6, [0, 2, 1, 3, 4, 5]);
+impl_move!([D1, D2, D3, D4, D5, D6], 2, 3, Axes6, [0, 1, 3, 2, 4, 5]);
+impl_move!([D1, D2, D3, D4, D5, D6], 2, 4, Axes6, [0, 1, 3, 4, 2, 5]);
+impl_move!([D1, D2, D3, D4, D5, D6], 2, 5, Axes6, [0, 1, 3, 4, 5, 2]);
+impl_move!([D1, D2, D3, D4, D5, D6], 3, 0, Axes6, [3, 0, 1, 2, 4, 5]);
+impl_move!([D1, D2, D3, D4, D5, D6], 3, 1, Axes6, [0, 3, 1, 2, 4, 5]);
+impl_move!([D1, D2, D3, D4, D5, D6], 3, 2, Axes6, [0, 1, 3, 2, 4, 5]);
+impl_move!([D1, D2, D3, D4, D5, D6], 3, 4, Axes6, [0, 1, 2, 4, 3, 5]);
+impl_move!([D1, D2, D3, D4, D5, D6], 3, 5, Axes6, [0, 1, 2, 4, 5, 3]);
+impl_move!([D1, D2, D3, D4, D5, D6], 4, 0, Axes6, [4, 0, 1, 2, 3, 5]);
+impl_move!([D1, D2, D3, D4, D5, D6], 4, 1, Axes6, [0, 4, 1, 2, 3, 5]);
+impl_move!([D1, D2, D3, D4, D5, D6], 4, 2, Axes6, [0, 1, 4, 2, 3, 5]);
+impl_move!([D1, D2, D3, D4, D5, D6], 4, 3, Axes6, [0, 1, 2, 4, 3, 5]);
+impl_move!([D1, D2, D3, D4, D5, D6], 4, 5, Axes6, [0, 1, 2, 3, 5, 4]);
+impl_move!([D1, D2, D3, D4, D5, D6], 5, 0, Axes6, [5, 0, 1, 2, 3, 4]);
+impl_move!([D1, D2, D3, D4, D5, D6], 5, 1, Axes6, [0, 5, 1, 2, 3, 4]);
+impl_move!([D1, D2, D3, D4, D5, D6], 5, 2, Axes6, [0, 1, 5, 2, 3, 4]);
+impl_move!([D1, D2, D3, D4, D5, D6], 5, 3, Axes6, [0, 1, 2, 5, 3, 4]);
+impl_move!([D1, D2, D3, D4, D5, D6], 5, 4, Axes6, [0, 1, 2, 3, 5, 4]);
+
 #[cfg(test)]
 mod tests {
     use super::*;
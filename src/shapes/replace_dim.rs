@@ -140,3 +140,80 @@ impl<Batch: Dim, Seq: Dim, S1: Dim, S2: Dim> ReplaceDimTo<(Batch, Seq, S2), (Bat
 {
     type Ax = Axis<0>;
 }
+
+/// Marker for shapes that can have the dimension along `Ax` replaced with
+/// a dimension of a different size, keeping all other dimensions the same.
+pub trait ReplaceAxisWith<Ax: Axes<Array = [isize; 1]>, New: Dim>: Shape {
+    type Replaced: Shape<Concrete = Self::Concrete>;
+
+    #[inline]
+    fn replace_axis(&self, new: New) -> Self::Replaced {
+        let ax = Ax::as_array()[0] as usize;
+        let mut dims = self.concrete();
+        dims[ax] = new.size();
+        Self::Replaced::from_concrete(&dims).unwrap()
+    }
+}
+
+macro_rules! replace_axis {
+    (($($DimVars:tt),*), $Ax:ty, $Dst:ty) => {
+impl<$($DimVars: Dim, )* New: Dim> ReplaceAxisWith<$Ax, New> for ($($DimVars, )*) {
+    type Replaced = $Dst;
+}
+    };
+}
+
+replace_axis!((D1), Axis<0>, (New,));
+
+replace_axis!((D1, D2), Axis<0>, (New, D2));
+replace_axis!((D1, D2), Axis<1>, (D1, New));
+
+replace_axis!((D1, D2, D3), Axis<0>, (New, D2, D3));
+replace_axis!((D1, D2, D3), Axis<1>, (D1, New, D3));
+replace_axis!((D1, D2, D3), Axis<2>, (D1, D2, New));
+
+replace_axis!((D1, D2, D3, D4), Axis<0>, (New, D2, D3, D4));
+replace_axis!((D1, D2, D3, D4), Axis<1>, (D1, New, D3, D4));
+replace_axis!((D1, D2, D3, D4), Axis<2>, (D1, D2, New, D4));
+replace_axis!((D1, D2, D3, D4), Axis<3>, (D1, D2, D3, New));
+
+/// Marker for shapes that can have the dimension along `Ax` removed. This is the
+/// [RemoveDimTo] that removes `Ax`, along with the shape of the index it needs.
+pub trait RemoveAxis<Ax: Axes<Array = [isize; 1]>>: Shape {
+    type Removed: Shape;
+    type Idx: Shape;
+
+    /// The dimensions of `self` before `Ax`.
+    #[inline]
+    fn idx_shape(&self) -> Self::Idx {
+        let src_dims = self.concrete();
+        let mut idx_dims: <Self::Idx as Shape>::Concrete = Default::default();
+        for i in 0..Self::Idx::NUM_DIMS {
+            idx_dims[i] = src_dims[i];
+        }
+        Self::Idx::from_concrete(&idx_dims).unwrap()
+    }
+}
+
+macro_rules! remove_axis {
+    (($($DimVars:tt),*), $Ax:ty, $Dst:ty, $Idx:ty) => {
+impl<$($DimVars: Dim, )*> RemoveAxis<$Ax> for ($($DimVars, )*) {
+    type Removed = $Dst;
+    type Idx = $Idx;
+}
+    };
+}
+
+remove_axis!((D1), Axis<0>, (), ());
+
+remove_axis!((D1, D2), Axis<0>, (D2,), ());
+remove_axis!((D1, D2), Axis<1>, (D1,), (D1,));
+
+remove_axis!((D1, D2, D3), Axis<0>, (D2, D3), ());
+remove_axis!((D1, D2, D3), Axis<1>, (D1, D3), (D1,));
+remove_axis!((D1, D2, D3), Axis<2>, (D1, D2), (D1, D2));
+
+remove_axis!((D1, D2, D3, D4), Axis<0>, (D2, D3, D4), ());
+remove_axis!((D1, D2, D3, D4), Axis<1>, (D1, D3, D4), (D1,));
+remove_axis!((D1, D2, D3, D4), Axis<2>, (D1, D2, D4), (D1, D2));
+remove_axis!((D1, D2, D3, D4), Axis<3>, (D1, D2, D3), (D1, D2, D3));
@@ -50,6 +50,7 @@ impl<E: Unit> ZerosTensor<E> for Cpu {
             shape,
             strides,
             device: self.clone(),
+            param_opts: Default::default(),
             tape: Default::default(),
         })
     }
@@ -62,6 +63,18 @@ impl<E: Unit> ZeroFillStorage<E> for Cpu {
     }
 }
 
+impl<E: Dtype + num_traits::Float> SquaredNormStorage<E> for Cpu {
+    fn try_squared_norm(&self, storage: &Self::Vec<E>) -> Result<f64, Self::Err> {
+        Ok(storage
+            .iter()
+            .map(|x| {
+                let x = x.to_f64().unwrap();
+                x * x
+            })
+            .sum())
+    }
+}
+
 impl<E: Unit> OnesTensor<E> for Cpu {
     fn try_ones_like<S: HasShape>(&self, src: &S) -> Result<Tensor<S::Shape, E, Self>, Self::Err> {
         let shape = *src.shape();
@@ -74,6 +87,7 @@ impl<E: Unit> OnesTensor<E> for Cpu {
             shape,
             strides,
             device: self.clone(),
+            param_opts: Default::default(),
             tape: Default::default(),
         })
     }
@@ -86,6 +100,38 @@ impl<E: Unit> OneFillStorage<E> for Cpu {
     }
 }
 
+impl<E: Unit + num_traits::Float> PositionalEncodingTensor<E> for Cpu {
+    fn try_sinusoidal_positional_encoding<const LEN: usize, const DIM: usize>(
+        &self,
+    ) -> Result<Tensor<Rank2<LEN, DIM>, E, Self>, Self::Err> {
+        let mut data = self.try_alloc_zeros::<E>(LEN * DIM)?;
+        for pos in 0..LEN {
+            for i in 0..DIM {
+                let freq = 10000f64.powf((i - i % 2) as f64 / DIM as f64);
+                let angle = pos as f64 / freq;
+                let v = if i % 2 == 0 { angle.sin() } else { angle.cos() };
+                data[pos * DIM + i] = E::from(v).unwrap();
+            }
+        }
+        self.try_tensor_from_vec(data, Default::default())
+    }
+}
+
+impl<E: Unit> BuildWithTensor<E> for Cpu {
+    fn try_build_with<S: Shape, F: FnMut(S::Concrete) -> E>(
+        &self,
+        shape: S,
+        mut f: F,
+    ) -> Result<Tensor<S, E, Self>, Self::Err> {
+        let mut tensor = self.try_zeros_like(&shape)?;
+        let mut iter = tensor.iter_mut_with_index();
+        while let Some((v, idx)) = iter.next() {
+            *v = f(idx);
+        }
+        Ok(tensor)
+    }
+}
+
 impl<E: Unit> SampleTensor<E> for Cpu {
     fn try_sample_like<S: HasShape, D: Distribution<E>>(
         &self,
@@ -142,6 +188,7 @@ impl<E: Unit> TensorFromVec<E> for Cpu {
                 shape,
                 strides: shape.strides(),
                 device: self.clone(),
+                param_opts: Default::default(),
                 tape: Default::default(),
             })
         }
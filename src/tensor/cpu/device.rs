@@ -1,5 +1,6 @@
 use crate::shapes::{Shape, Unit};
 use crate::tensor::{cpu::LendingIterator, storage_traits::*, Tensor};
+use crate::tensor_ops::StackError;
 use rand::{rngs::StdRng, Rng, SeedableRng};
 use std::{
     sync::{Arc, Mutex},
@@ -33,12 +34,28 @@ impl Cpu {
     }
 }
 
+impl SeedableDevice for Cpu {
+    fn try_with_seed(seed: u64) -> Result<Self, Self::Err> {
+        Ok(Self::seed_from_u64(seed))
+    }
+}
+
 #[derive(Debug, Clone, Copy)]
 pub enum CpuError {
     /// Device is out of memory
     OutOfMemory,
     /// Not enough elements were provided when creating a tensor
     WrongNumElements,
+    /// Invalid inputs to [crate::tensor_ops::TryStack]
+    Stack(StackError),
+    /// The input to [crate::tensor_ops::cholesky] was not positive definite
+    NotPositiveDefinite,
+}
+
+impl From<StackError> for CpuError {
+    fn from(value: StackError) -> Self {
+        Self::Stack(value)
+    }
 }
 
 impl std::fmt::Display for CpuError {
@@ -46,6 +63,8 @@ impl std::fmt::Display for CpuError {
         match self {
             Self::OutOfMemory => f.write_str("CpuError::OutOfMemory"),
             Self::WrongNumElements => f.write_str("CpuError::WrongNumElements"),
+            Self::Stack(e) => write!(f, "CpuError::Stack({e})"),
+            Self::NotPositiveDefinite => f.write_str("CpuError::NotPositiveDefinite"),
         }
     }
 }
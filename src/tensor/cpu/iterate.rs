@@ -79,6 +79,25 @@ impl<S: Shape> NdIndex<S> {
             }
         }
     }
+
+    /// Moves the index so that the next call to [NdIndex::next()] returns
+    /// the `n`th element in iteration order.
+    #[inline]
+    pub(crate) fn seek(&mut self, n: usize) {
+        if n == 0 {
+            self.indices = Default::default();
+            self.next = Some(0);
+            return;
+        }
+        let mut rem = n;
+        let mut i = 0;
+        for dim in (0..S::NUM_DIMS).rev() {
+            self.indices[dim] = rem % self.shape[dim];
+            rem /= self.shape[dim];
+            i += self.indices[dim] * self.strides[dim];
+        }
+        self.next = (rem == 0).then_some(i);
+    }
 }
 
 pub(crate) struct StridedRefIter<'a, S: Shape, E> {
@@ -275,4 +294,18 @@ mod tests {
         assert_eq!(i.next(), Some(5));
         assert!(i.next().is_none());
     }
+
+    #[test]
+    fn test_seek() {
+        let shape: Rank2<2, 3> = Default::default();
+        let mut i = NdIndex::new(shape, [1, 2]);
+        i.seek(4);
+        assert_eq!(i.next(), Some(3));
+        assert_eq!(i.next(), Some(5));
+        assert!(i.next().is_none());
+        i.seek(0);
+        assert_eq!(i.next(), Some(0));
+        i.seek(6);
+        assert!(i.next().is_none());
+    }
 }
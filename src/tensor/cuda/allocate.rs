@@ -12,7 +12,10 @@ use crate::{
 
 use super::{Cuda, CudaError};
 
-use cudarc::driver::{CudaSlice, DeviceSlice};
+use cudarc::{
+    cublas::{sys::cublasOperation_t, CudaBlas, Gemm, GemmConfig},
+    driver::{sys, CudaSlice, DevicePtr, DeviceSlice},
+};
 use rand::Rng;
 use std::{sync::Arc, vec::Vec};
 
@@ -37,6 +40,7 @@ impl Cuda {
             shape,
             strides,
             device: self.clone(),
+            param_opts: Default::default(),
             tape: Default::default(),
         }
     }
@@ -58,6 +62,34 @@ impl<E: Unit> ZeroFillStorage<E> for Cuda {
     }
 }
 
+impl<E: Dtype + num_traits::Float> SquaredNormStorage<E> for Cuda
+where
+    CudaBlas: Gemm<E>,
+{
+    fn try_squared_norm(&self, storage: &Self::Vec<E>) -> Result<f64, Self::Err> {
+        // the dot product of the buffer with itself as a (1, n) x (n, 1) matmul, so the
+        // buffer is only borrowed and just the final sum is copied back to the host
+        let n = storage.len();
+        let cfg = GemmConfig {
+            transa: cublasOperation_t::CUBLAS_OP_N,
+            transb: cublasOperation_t::CUBLAS_OP_N,
+            m: 1,
+            n: 1,
+            k: n as i32,
+            alpha: E::ONE,
+            lda: 1,
+            ldb: n.max(1) as i32,
+            beta: E::default(),
+            ldc: 1,
+        };
+        let mut out = self.dev.alloc_zeros::<E>(1)?;
+        unsafe { self.blas.gemm(cfg, storage, storage, &mut out) }?;
+        let mut sum_sq = [E::default()];
+        self.dev.dtoh_sync_copy_into(&out, &mut sum_sq)?;
+        Ok(sum_sq[0].to_f64().unwrap())
+    }
+}
+
 impl<E: Unit> OnesTensor<E> for Cuda
 where
     Cpu: OnesTensor<E>,
@@ -69,6 +101,29 @@ where
     }
 }
 
+impl<E: Unit> PositionalEncodingTensor<E> for Cuda
+where
+    Cpu: PositionalEncodingTensor<E>,
+{
+    fn try_sinusoidal_positional_encoding<const LEN: usize, const DIM: usize>(
+        &self,
+    ) -> Result<Tensor<Rank2<LEN, DIM>, E, Self>, Self::Err> {
+        let host = self.cpu.try_sinusoidal_positional_encoding::<LEN, DIM>()?;
+        self.tensor_from_host_buf(host.shape, host.data.as_ref().clone())
+    }
+}
+
+impl<E: Unit> BuildWithTensor<E> for Cuda {
+    fn try_build_with<S: Shape, F: FnMut(S::Concrete) -> E>(
+        &self,
+        shape: S,
+        f: F,
+    ) -> Result<Tensor<S, E, Self>, Self::Err> {
+        let host = self.cpu.try_build_with(shape, f)?;
+        self.tensor_from_host_buf(host.shape, host.data.as_ref().clone())
+    }
+}
+
 impl<E: Unit> OneFillStorage<E> for Cuda {
     fn try_fill_with_ones(&self, storage: &mut Self::Vec<E>) -> Result<(), Self::Err> {
         self.dev
@@ -116,10 +171,22 @@ impl<E: Unit> CopySlice<E> for Cuda {
             src.len(),
             "Slices must have same number of elements as *physical* storage of tensors."
         );
-        dst.device
-            .dev
-            .htod_sync_copy_into(src, Arc::make_mut(&mut dst.data))
-            .unwrap();
+        let dev = dst.device.dev.clone();
+        if let Some(pinned) = dst.device.pinned.clone() {
+            let mut pinned = pinned.lock().unwrap();
+            let ptr = pinned.try_reserve(std::mem::size_of_val(src)).unwrap() as *mut E;
+            // Safety: the buffer has room for `src.len()` elements, which are all
+            // initialized by the copy before the slice is created.
+            let staged = unsafe {
+                std::ptr::copy_nonoverlapping(src.as_ptr(), ptr, src.len());
+                std::slice::from_raw_parts(ptr, src.len())
+            };
+            dev.htod_sync_copy_into(staged, Arc::make_mut(&mut dst.data))
+                .unwrap();
+        } else {
+            dev.htod_sync_copy_into(src, Arc::make_mut(&mut dst.data))
+                .unwrap();
+        }
     }
     fn copy_into<S: Shape, T>(src: &Tensor<S, E, Self, T>, dst: &mut [E]) {
         assert_eq!(
@@ -127,10 +194,24 @@ impl<E: Unit> CopySlice<E> for Cuda {
             dst.len(),
             "Slices must have same number of elements as *physical* storage of tensors."
         );
-        src.device
-            .dev
-            .dtoh_sync_copy_into(src.data.as_ref(), dst)
-            .unwrap();
+        let dev = &src.device.dev;
+        if let Some(pinned) = &src.device.pinned {
+            let mut pinned = pinned.lock().unwrap();
+            let num_bytes = std::mem::size_of_val(dst);
+            let ptr = pinned.try_reserve(num_bytes).unwrap();
+            // wait for the kernels writing to `src`, since the raw copy doesn't use their stream
+            dev.synchronize().unwrap();
+            // Safety: the device copy writes all `dst.len()` elements into the buffer
+            // before they are read.
+            unsafe {
+                sys::cuMemcpyDtoH_v2(ptr as *mut _, *src.data.device_ptr(), num_bytes)
+                    .result()
+                    .unwrap();
+                std::ptr::copy_nonoverlapping(ptr as *const E, dst.as_mut_ptr(), dst.len());
+            }
+        } else {
+            dev.dtoh_sync_copy_into(src.data.as_ref(), dst).unwrap();
+        }
     }
 }
 
@@ -164,6 +245,7 @@ where
                 shape: tensor.shape,
                 strides: tensor.strides,
                 device: self.cpu.clone(),
+                param_opts: tensor.param_opts,
                 tape: Default::default(),
             })
     }
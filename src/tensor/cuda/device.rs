@@ -1,18 +1,83 @@
 use crate::shapes::{Shape, Unit};
 use crate::tensor::cpu::{Cpu, CpuError, NdIndex};
-use crate::tensor::{DeviceStorage, HasErr, Tensor};
+use crate::tensor::{DeviceStorage, HasErr, SeedableDevice, Tensor};
+use crate::tensor_ops::StackError;
 
 use cudarc::{
     cublas::{result::CublasError, CudaBlas},
-    driver::{CudaDevice, CudaSlice, DeviceSlice, DriverError},
+    driver::{sys, CudaDevice, CudaSlice, DeviceSlice, DriverError},
+};
+use std::{
+    sync::{Arc, Mutex},
+    vec::Vec,
 };
-use std::{sync::Arc, vec::Vec};
 
+/// A device that stores tensors in nvidia gpu memory & runs kernels on the gpu.
+///
+/// Buffers are allocated from the device's default memory pool, which is set to keep
+/// freed buffers around instead of releasing them back to the driver. So in a training
+/// loop, allocations of the same size reuse the buffers freed by the previous iteration.
 #[derive(Clone, Debug)]
 pub struct Cuda {
     pub(crate) cpu: Cpu,
     pub(crate) dev: Arc<CudaDevice>,
     pub(crate) blas: Arc<CudaBlas>,
+    /// Page-locked host buffer that [crate::tensor::CopySlice] stages through, if enabled.
+    pub(crate) pinned: Option<Arc<Mutex<PinnedBuf>>>,
+}
+
+/// A page-locked host buffer that is reused between transfers, and only
+/// reallocated when a transfer needs more space than it has.
+#[derive(Debug)]
+pub(crate) struct PinnedBuf {
+    /// Keeps the context alive until the buffer is freed, even if the [Cuda] that
+    /// owns this buffer drops its own handle to the device first.
+    #[allow(dead_code)]
+    dev: Arc<CudaDevice>,
+    ptr: *mut u8,
+    num_bytes: usize,
+}
+
+// Safety: the buffer is only accessed while holding the Mutex around it.
+unsafe impl Send for PinnedBuf {}
+
+impl PinnedBuf {
+    fn empty(dev: Arc<CudaDevice>) -> Self {
+        Self {
+            dev,
+            ptr: std::ptr::null_mut(),
+            num_bytes: 0,
+        }
+    }
+
+    /// Returns a pointer to at least `num_bytes` of page-locked memory. The contents
+    /// are uninitialized, so they must only be accessed through the raw pointer.
+    pub(crate) fn try_reserve(&mut self, num_bytes: usize) -> Result<*mut u8, CudaError> {
+        if num_bytes > self.num_bytes {
+            self.free()?;
+            let mut ptr = std::ptr::null_mut();
+            unsafe { sys::cuMemAllocHost_v2(&mut ptr, num_bytes) }.result()?;
+            self.ptr = ptr as *mut u8;
+            self.num_bytes = num_bytes;
+        }
+        Ok(self.ptr)
+    }
+
+    fn free(&mut self) -> Result<(), CudaError> {
+        if !self.ptr.is_null() {
+            unsafe { sys::cuMemFreeHost(self.ptr as *mut _) }.result()?;
+            self.ptr = std::ptr::null_mut();
+            self.num_bytes = 0;
+        }
+        Ok(())
+    }
+}
+
+impl Drop for PinnedBuf {
+    fn drop(&mut self) {
+        // errors can't be reported from drop, and at worst the buffer is leaked
+        let _ = self.free();
+    }
 }
 
 #[derive(Debug)]
@@ -28,6 +93,12 @@ impl From<CpuError> for CudaError {
     }
 }
 
+impl From<StackError> for CudaError {
+    fn from(value: StackError) -> Self {
+        Self::Cpu(value.into())
+    }
+}
+
 impl From<CublasError> for CudaError {
     fn from(value: CublasError) -> Self {
         Self::Blas(value)
@@ -60,8 +131,43 @@ impl Cuda {
     pub fn try_build(ordinal: usize, seed: u64) -> Result<Self, CudaError> {
         let cpu = Cpu::seed_from_u64(seed);
         let dev = CudaDevice::new(ordinal)?;
+        // cudarc allocates asynchronously from the default memory pool, which by default
+        // releases all of its free memory at every synchronization. Keep it all instead.
+        let mut threshold: u64 = u64::MAX;
+        unsafe {
+            sys::cuMemPoolSetAttribute(
+                default_mem_pool(ordinal)?,
+                sys::CUmemPool_attribute::CU_MEMPOOL_ATTR_RELEASE_THRESHOLD,
+                &mut threshold as *mut u64 as *mut _,
+            )
+        }
+        .result()?;
         let blas = Arc::new(CudaBlas::new(dev.clone())?);
-        Ok(Self { cpu, dev, blas })
+        Ok(Self {
+            cpu,
+            dev,
+            blas,
+            pinned: None,
+        })
+    }
+
+    /// Enables or disables staging [crate::tensor::CopySlice] transfers through a pinned
+    /// (page-locked) host buffer. Pinned memory makes host/device copies faster for large
+    /// tensors. The buffer is shared by clones of the returned device, and kept around
+    /// between transfers, growing to the size of the largest transfer so far.
+    ///
+    /// Only affects tensors created by the returned device. Disabled by default.
+    ///
+    /// Examples:
+    /// ```rust
+    /// # use dfdx::prelude::*;
+    /// let dev: Cuda = Cuda::default().with_pinned_transfers(true);
+    /// let mut a: Tensor<Rank1<3>, f32, _> = dev.zeros();
+    /// a.copy_from(&[1.0, 2.0, 3.0]);
+    /// ```
+    pub fn with_pinned_transfers(mut self, enabled: bool) -> Self {
+        self.pinned = enabled.then(|| Arc::new(Mutex::new(PinnedBuf::empty(self.dev.clone()))));
+        self
     }
 
     /// Block until kernels finish processing. Useful for benchmarking.
@@ -79,12 +185,27 @@ impl Cuda {
     }
 }
 
+/// The memory pool that [CudaDevice] allocates from.
+fn default_mem_pool(ordinal: usize) -> Result<sys::CUmemoryPool, CudaError> {
+    let mut cu_device = 0;
+    unsafe { sys::cuDeviceGet(&mut cu_device, ordinal as _) }.result()?;
+    let mut pool = std::ptr::null_mut();
+    unsafe { sys::cuDeviceGetDefaultMemPool(&mut pool, cu_device) }.result()?;
+    Ok(pool)
+}
+
 impl std::fmt::Display for CudaError {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         write!(f, "{self:?}")
     }
 }
 
+impl SeedableDevice for Cuda {
+    fn try_with_seed(seed: u64) -> Result<Self, Self::Err> {
+        Self::try_build(0, seed)
+    }
+}
+
 impl HasErr for Cuda {
     type Err = CudaError;
 }
@@ -112,3 +233,78 @@ impl DeviceStorage for Cuda {
         contiguous
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{shapes::*, tensor::*, tensor_ops::*};
+
+    #[test]
+    fn test_seeded_samples_match_cpu() {
+        for seed in [0, 1, 1234] {
+            let cpu = Cpu::with_seed(seed);
+            let gpu = Cuda::with_seed(seed);
+
+            let a: Tensor<Rank2<3, 7>, f32, _> = cpu.sample_normal();
+            let b: Tensor<Rank2<3, 7>, f32, _> = gpu.sample_normal();
+            assert_eq!(a.array(), b.array());
+
+            let a: Tensor<Rank1<11>, f64, _> = cpu.sample_uniform();
+            let b: Tensor<Rank1<11>, f64, _> = gpu.sample_uniform();
+            assert_eq!(a.array(), b.array());
+
+            let a: Tensor<(usize, Const<2>), f32, _> = cpu.sample_normal_like(&(5, Const));
+            let b: Tensor<(usize, Const<2>), f32, _> = gpu.sample_normal_like(&(5, Const));
+            assert_eq!(a.as_vec(), b.as_vec());
+        }
+    }
+
+    #[test]
+    fn test_freed_buffers_are_reused() {
+        let dev: Cuda = Default::default();
+        let reserved_bytes = || {
+            dev.synchronize().unwrap();
+            let mut num_bytes: u64 = 0;
+            unsafe {
+                sys::cuMemPoolGetAttribute(
+                    default_mem_pool(0).unwrap(),
+                    sys::CUmemPool_attribute::CU_MEMPOOL_ATTR_RESERVED_MEM_CURRENT,
+                    &mut num_bytes as *mut u64 as *mut _,
+                )
+            }
+            .result()
+            .unwrap();
+            num_bytes
+        };
+
+        let a: Tensor<Rank2<256, 1024>, f32, _> = dev.zeros();
+        drop(a);
+        let reserved = reserved_bytes();
+        assert!(reserved >= 256 * 1024 * 4);
+
+        // the freed buffer is kept by the pool, and handed back to the next allocations
+        for _ in 0..100 {
+            let a: Tensor<Rank2<256, 1024>, f32, _> = dev.zeros();
+            let _b = a.square();
+        }
+        assert!(reserved_bytes() <= 2 * reserved);
+    }
+
+    #[test]
+    fn test_pinned_copy_roundtrip() {
+        let dev = Cuda::default().with_pinned_transfers(true);
+        let src: Vec<f32> = (0..100_000).map(|i| i as f32 * 0.5).collect();
+        let mut a: Tensor<Rank1<100_000>, f32, _> = dev.zeros();
+        a.copy_from(&src);
+
+        let mut dst = std::vec![0.0; 100_000];
+        a.copy_into(&mut dst);
+        assert_eq!(src, dst);
+
+        let b = a.clone() * 2.0;
+        b.copy_into(&mut dst);
+        for (s, d) in src.iter().zip(dst.iter()) {
+            assert_eq!(s * 2.0, *d);
+        }
+    }
+}
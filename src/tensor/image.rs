@@ -0,0 +1,117 @@
+use crate::shapes::{Const, HasShape};
+
+use super::{DeviceStorage, Tensor, TensorFromVec};
+
+use std::{path::Path, string::ToString, vec::Vec};
+
+pub use image::ImageError;
+
+/// Create tensors from image files.
+pub trait TensorFromImage: TensorFromVec<f32> {
+    /// Decodes the image file at `path` (e.g. a PNG or JPEG) into a `(channel, height, width)`
+    /// tensor of RGB values in `[0, 1]`. The format is determined by the contents of the file.
+    ///
+    /// ```ignore
+    /// # use dfdx::prelude::*;
+    /// # let dev: Cpu = Default::default();
+    /// let img: Tensor<(Const<3>, usize, usize), f32, _> = dev.load_image("cat.png")?;
+    /// let (_, height, width) = *img.shape();
+    /// ```
+    #[allow(clippy::type_complexity)]
+    fn load_image<P: AsRef<Path>>(
+        &self,
+        path: P,
+    ) -> Result<Tensor<(Const<3>, usize, usize), f32, Self>, ImageError> {
+        let img = image::io::Reader::open(path)?
+            .with_guessed_format()?
+            .decode()?
+            .to_rgb8();
+        let (width, height) = img.dimensions();
+        let (width, height) = (width as usize, height as usize);
+
+        // images are stored as (height, width, channel)
+        let hwc = img.into_raw();
+        let mut chw = Vec::with_capacity(hwc.len());
+        for c in 0..3 {
+            for i in 0..height * width {
+                chw.push(hwc[i * 3 + c] as f32 / 255.0);
+            }
+        }
+        self.try_tensor_from_vec(chw, (Const, height, width))
+            .map_err(|err| ImageError::from(std::io::Error::other(err.to_string())))
+    }
+}
+impl<D: TensorFromVec<f32>> TensorFromImage for D {}
+
+impl<D: DeviceStorage, T> Tensor<(Const<3>, usize, usize), f32, D, T> {
+    /// Saves this `(channel, height, width)` tensor of RGB values as an image file at `path`.
+    /// Values are clamped to `[0, 1]`, and the format is determined by the extension of `path`.
+    ///
+    /// This is the inverse of [TensorFromImage::load_image].
+    pub fn save_image<P: AsRef<Path>>(&self, path: P) -> Result<(), ImageError> {
+        let (_, height, width) = *self.shape();
+        let chw = self.as_vec();
+        let mut hwc = Vec::with_capacity(chw.len());
+        for i in 0..height * width {
+            for c in 0..3 {
+                let v = chw[c * height * width + i].clamp(0.0, 1.0);
+                hwc.push((v * 255.0).round() as u8);
+            }
+        }
+        let img = image::RgbImage::from_raw(width as u32, height as u32, hwc).unwrap();
+        img.save(path)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::tests::TestDevice;
+
+    fn tempfile(suffix: &str) -> tempfile::NamedTempFile {
+        tempfile::Builder::new()
+            .suffix(suffix)
+            .tempfile()
+            .expect("failed to create tempfile")
+    }
+
+    #[test]
+    fn test_load_png() {
+        let dev: TestDevice = Default::default();
+
+        // 2 rows by 3 columns, where red is the column, green is the row, and blue is 255
+        let img = image::RgbImage::from_fn(3, 2, |x, y| image::Rgb([x as u8 * 100, y as u8, 255]));
+        let file = tempfile(".png");
+        img.save(file.path()).expect("");
+
+        let t = dev.load_image(file.path()).expect("");
+        assert_eq!(t.shape(), &(Const, 2, 3));
+        let v = t.as_vec();
+        assert!(v.iter().all(|x| (0.0..=1.0).contains(x)));
+        assert_eq!(
+            v,
+            [
+                [0.0, 100.0, 200.0, 0.0, 100.0, 200.0],
+                [0.0, 0.0, 0.0, 1.0, 1.0, 1.0],
+                [255.0; 6]
+            ]
+            .concat()
+            .into_iter()
+            .map(|x: f32| x / 255.0)
+            .collect::<Vec<f32>>()
+        );
+    }
+
+    #[test]
+    fn test_save_load_png() {
+        let dev: TestDevice = Default::default();
+        let data: Vec<f32> = (0..3 * 4 * 5).map(|i| i as f32 * 4.0 / 255.0).collect();
+        let t = dev.tensor_from_vec(data.clone(), (Const::<3>, 4, 5));
+        let file = tempfile(".png");
+        t.save_image(file.path()).expect("");
+
+        let loaded = dev.load_image(file.path()).expect("");
+        assert_eq!(loaded.shape(), &(Const, 4, 5));
+        assert_eq!(loaded.as_vec(), data);
+    }
+}
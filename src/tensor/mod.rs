@@ -56,6 +56,8 @@
 //! let _: Tensor<Rank2<4, 3>, f32, _> = dev.sample(rand_distr::StandardNormal);
 //! ```
 //!
+//! Devices constructed with the same seed (see [SeedableDevice]) produce the same samples.
+//!
 //! ### Copy data from slices
 //!
 //! You can use [Tensor::copy_from] and [Tensor::copy_into] to copy data into a tensor:
@@ -105,16 +107,29 @@
 //!
 //! # Serialization using numpy
 //!
-//! See [Tensor::save_to_npy] and [Tensor::load_from_npy].
+//! See [Tensor::save_to_npy] and [Tensor::load_from_npy]. To create a new tensor
+//! from a `.npy` file, see [TensorFromNpy::load_npy].
 //!
 //! You can also use [Tensor::write_to_npz] and [Tensor::read_from_npz] when working with
 //! zip archives.
+//!
+//! # Images
+//!
+//! With the "image" feature, see [TensorFromImage::load_image] and [Tensor::save_image].
+//!
+//! # Loading from safetensors
+//!
+//! With the "safetensors" feature, see [Tensor::load_safetensor].
 
 pub(crate) mod cpu;
 #[cfg(feature = "cuda")]
 pub(crate) mod cuda;
+#[cfg(feature = "image")]
+pub(crate) mod image;
 #[cfg(feature = "numpy")]
 pub(crate) mod numpy;
+#[cfg(feature = "safetensors")]
+pub(crate) mod safetensors;
 pub(crate) mod storage_traits;
 mod tensor_impls;
 
@@ -125,9 +140,18 @@ pub use cpu::{Cpu, CpuError};
 #[cfg(feature = "cuda")]
 pub use cuda::{Cuda, CudaError};
 
+#[cfg(feature = "image")]
+pub use self::image::{ImageError, TensorFromImage};
+
+#[cfg(feature = "numpy")]
+pub use numpy::{NpyError, TensorFromNpy};
+
+#[cfg(feature = "safetensors")]
+pub use safetensors::{SafeDtype, SafeTensorError, SafeTensors};
+
 pub use storage_traits::{AsArray, CopySlice, TensorFrom, TensorFromVec};
-pub use storage_traits::{DeviceStorage, HasErr};
-pub use storage_traits::{OnesTensor, SampleTensor, ZerosTensor};
+pub use storage_traits::{BuildWithTensor, DeviceStorage, HasErr, SeedableDevice};
+pub use storage_traits::{OnesTensor, PositionalEncodingTensor, SampleTensor, ZerosTensor};
 
 #[cfg(feature = "cuda")]
 pub use tensor_impls::OnCuda;
@@ -138,7 +162,7 @@ pub use tensor_impls::{Tensor0D, Tensor1D, Tensor2D, Tensor3D, Tensor4D, Tensor5
 mod tests {
     use super::*;
     use crate::shapes::*;
-    use crate::tests::TestDevice;
+    use crate::tests::{assert_close, TestDevice, TestDtype};
     use crate::unique_id::{unique_id, UniqueId};
     use std::collections::HashSet;
 
@@ -189,6 +213,61 @@ mod tests {
         assert_eq!(t3.id, t1_id);
     }
 
+    #[test]
+    fn test_sinusoidal_positional_encoding() {
+        let dev: TestDevice = Default::default();
+        let pe: Tensor<Rank2<3, 4>, TestDtype, _> = dev.sinusoidal_positional_encoding();
+        let f = 0.01f64;
+        assert_close(
+            &pe.array(),
+            &[
+                [0.0, 1.0, 0.0, 1.0],
+                [
+                    1f64.sin() as TestDtype,
+                    1f64.cos() as TestDtype,
+                    f.sin() as TestDtype,
+                    f.cos() as TestDtype,
+                ],
+                [
+                    2f64.sin() as TestDtype,
+                    2f64.cos() as TestDtype,
+                    (2.0 * f).sin() as TestDtype,
+                    (2.0 * f).cos() as TestDtype,
+                ],
+            ],
+        );
+
+        let pe: Tensor<Rank2<2, 3>, TestDtype, _> = dev.sinusoidal_positional_encoding();
+        let f = 10000f64.powf(-2.0 / 3.0);
+        assert_close(
+            &pe.array(),
+            &[
+                [0.0, 1.0, 0.0],
+                [
+                    1f64.sin() as TestDtype,
+                    1f64.cos() as TestDtype,
+                    f.sin() as TestDtype,
+                ],
+            ],
+        );
+    }
+
+    #[test]
+    fn test_build_with() {
+        let dev: TestDevice = Default::default();
+        let t: Tensor<Rank2<2, 3>, TestDtype, _> =
+            dev.build_with(Default::default(), |[i, j]| (i + j) as TestDtype);
+        assert_eq!(t.array(), [[0.0, 1.0, 2.0], [1.0, 2.0, 3.0]]);
+
+        let t = dev.build_with((2, Const::<2>, 2), |[i, j, k]| {
+            (i * 100 + j * 10 + k) as TestDtype
+        });
+        assert_eq!(
+            t.as_vec(),
+            [0.0, 1.0, 10.0, 11.0, 100.0, 101.0, 110.0, 111.0]
+        );
+    }
+
     #[test]
     fn test_zeros() {
         let dev: TestDevice = Default::default();
@@ -234,4 +313,13 @@ mod tests {
         let dev: TestDevice = Default::default();
         let _: Tensor<Rank1<1000>, f32, _> = dev.sample_normal();
     }
+
+    #[test]
+    fn test_seeded_samples_repeat() {
+        let a: Tensor<Rank2<4, 5>, f32, _> = TestDevice::with_seed(3).sample_normal();
+        let b: Tensor<Rank2<4, 5>, f32, _> = TestDevice::with_seed(3).sample_normal();
+        let c: Tensor<Rank2<4, 5>, f32, _> = TestDevice::with_seed(4).sample_normal();
+        assert_eq!(a.array(), b.array());
+        assert_ne!(a.array(), c.array());
+    }
 }
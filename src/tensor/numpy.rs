@@ -1,6 +1,6 @@
 use crate::shapes::{Dtype, HasShape, Shape};
 
-use super::{CopySlice, DeviceStorage, Tensor};
+use super::{CopySlice, DeviceStorage, Tensor, TensorFromVec};
 
 use std::{
     fs::File,
@@ -38,7 +38,10 @@ impl<S: Shape, E: Dtype + NumpyDtype, D: DeviceStorage + CopySlice<E>, T> Tensor
         Ok(())
     }
 
-    /// Attemps to load the data from a `.npy` file at `path`
+    /// Attemps to load the data from a `.npy` file at `path`. The file must have
+    /// the same dtype & shape as this tensor.
+    ///
+    /// See [TensorFromNpy::load_npy] to create a new tensor from a `.npy` file instead.
     pub fn load_from_npy<P: AsRef<Path>>(&mut self, path: P) -> Result<(), NpyError> {
         let mut f = BufReader::new(File::open(path)?);
         self.read_from(&mut f)
@@ -50,13 +53,18 @@ impl<S: Shape, E: Dtype + NumpyDtype, D: DeviceStorage + CopySlice<E>, T> Tensor
         self.write_to(&mut f)
     }
 
+    /// Same as [Tensor::save_to_npy], named to match [TensorFromNpy::load_npy].
+    pub fn save_npy<P: AsRef<Path>>(&self, path: P) -> io::Result<()> {
+        self.save_to_npy(path)
+    }
+
     pub(crate) fn read_from<R: Read>(&mut self, r: &mut R) -> Result<(), NpyError> {
-        let endian = read_header::<R, E>(r, self.shape().concrete().into_iter().collect())?;
-        let numel = self.shape().num_elements();
-        let mut buf = Vec::with_capacity(numel);
-        for _ in 0..numel {
-            buf.push(E::read_endian(r, endian)?);
+        let header = read_header::<R, E>(r)?;
+        let shape: Vec<usize> = self.shape().concrete().into();
+        if header.shape != shape {
+            return Err(NpyError::shape_mismatch(&shape, &header.shape));
         }
+        let buf = read_data(r, &header)?;
         D::copy_from(self, &buf);
         Ok(())
     }
@@ -74,6 +82,93 @@ impl<S: Shape, E: Dtype + NumpyDtype, D: DeviceStorage + CopySlice<E>, T> Tensor
     }
 }
 
+/// Create tensors from `.npy` files.
+pub trait TensorFromNpy<E: Dtype + NumpyDtype>: TensorFromVec<E> {
+    /// Loads the array in the `.npy` file at `path` into a new tensor. The dtype of the
+    /// file must be `E`, and its shape must fit `S`. Both C & Fortran ordered files are supported.
+    ///
+    /// ```ignore
+    /// # use dfdx::prelude::*;
+    /// # let dev: Cpu = Default::default();
+    /// let a: Tensor<Rank2<2, 3>, f32, _> = dev.load_npy("a.npy")?;
+    /// let b: Tensor<(usize, usize), f32, _> = dev.load_npy("b.npy")?;
+    /// ```
+    fn load_npy<S: Shape, P: AsRef<Path>>(&self, path: P) -> Result<Tensor<S, E, Self>, NpyError> {
+        let mut f = BufReader::new(File::open(path)?);
+        self.read_npy(&mut f)
+    }
+
+    /// Reads a `.npy` file from `r` into a new tensor. See [TensorFromNpy::load_npy].
+    fn read_npy<S: Shape, R: Read>(&self, r: &mut R) -> Result<Tensor<S, E, Self>, NpyError> {
+        let header = read_header::<R, E>(r)?;
+        let mut concrete: S::Concrete = Default::default();
+        if header.shape.len() != S::NUM_DIMS {
+            return Err(NpyError::InvalidShape(to_shape_str(header.shape)));
+        }
+        for (i, &dim) in header.shape.iter().enumerate() {
+            concrete[i] = dim;
+        }
+        let shape = S::from_concrete(&concrete)
+            .ok_or_else(|| NpyError::InvalidShape(to_shape_str(header.shape.clone())))?;
+        let buf = read_data(r, &header)?;
+        self.try_tensor_from_vec(buf, shape)
+            .map_err(|err| NpyError::DeviceError(err.to_string()))
+    }
+}
+impl<E: Dtype + NumpyDtype, D: TensorFromVec<E>> TensorFromNpy<E> for D {}
+
+/// The information stored in the header of a `.npy` file.
+struct NpyHeader {
+    endian: Endian,
+    fortran_order: bool,
+    shape: Vec<usize>,
+}
+
+/// The most elements that are reserved up front when reading the data of a `.npy` file.
+const MAX_PREALLOC_ELEMS: usize = 1 << 20;
+
+/// Reads the data following `header`, and puts it in C (row major) order.
+fn read_data<R: Read, E: NumpyDtype + Copy>(
+    r: &mut R,
+    header: &NpyHeader,
+) -> Result<Vec<E>, NpyError> {
+    let numel = header
+        .shape
+        .iter()
+        .try_fold(1usize, |n, &d| n.checked_mul(d))
+        .ok_or_else(|| NpyError::InvalidShape(to_shape_str(header.shape.clone())))?;
+    // the shape comes from the file, so only trust it up to a point before the data is
+    // actually read. a file that is too short fails with [io::ErrorKind::UnexpectedEof].
+    let mut buf = Vec::with_capacity(numel.min(MAX_PREALLOC_ELEMS));
+    for _ in 0..numel {
+        buf.push(E::read_endian(r, header.endian)?);
+    }
+    if !header.fortran_order || numel == 0 {
+        return Ok(buf);
+    }
+
+    // in fortran order the first index changes fastest
+    let num_dims = header.shape.len();
+    let mut strides = std::vec![1; num_dims];
+    for i in 1..num_dims {
+        strides[i] = strides[i - 1] * header.shape[i - 1];
+    }
+    let mut index = std::vec![0; num_dims];
+    let mut c_order = Vec::with_capacity(numel);
+    for _ in 0..numel {
+        let i: usize = index.iter().zip(strides.iter()).map(|(i, s)| i * s).sum();
+        c_order.push(buf[i]);
+        for d in (0..num_dims).rev() {
+            index[d] += 1;
+            if index[d] < header.shape[d] {
+                break;
+            }
+            index[d] = 0;
+        }
+    }
+    Ok(c_order)
+}
+
 fn write_header<W: Write, E: NumpyDtype>(
     w: &mut W,
     endian: Endian,
@@ -113,7 +208,7 @@ fn write_header<W: Write, E: NumpyDtype>(
     Ok(())
 }
 
-fn read_header<R: Read, E: NumpyDtype>(r: &mut R, shape: Vec<usize>) -> Result<Endian, NpyError> {
+fn read_header<R: Read, E: NumpyDtype>(r: &mut R) -> Result<NpyHeader, NpyError> {
     let mut magic = [0; 6];
     r.read_exact(&mut magic)?;
     if magic != MAGIC_NUMBER {
@@ -148,15 +243,36 @@ fn read_header<R: Read, E: NumpyDtype>(r: &mut R, shape: Vec<usize>) -> Result<E
     i = expect(&header, i, b"', ")?;
 
     // fortran order
-    i = expect(&header, i, b"'fortran_order': False, ")?;
+    i = expect(&header, i, b"'fortran_order': ")?;
+    let fortran_order = header[i..].starts_with(b"True");
+    i = if fortran_order {
+        i + 4
+    } else {
+        expect(&header, i, b"False")?
+    };
+    i = expect(&header, i, b", ")?;
 
     // shape
     i = expect(&header, i, b"'shape': (")?;
-    let shape_str = to_shape_str(shape);
-    i = expect(&header, i, shape_str.as_bytes())?;
-    expect(&header, i, b"), }")?;
-
-    Ok(endian)
+    let len = header[i..]
+        .iter()
+        .position(|&c| c == b')')
+        .ok_or(NpyError::InvalidAlignment)?;
+    let shape_str = String::from_utf8(header[i..i + len].to_vec())?;
+    let shape = shape_str
+        .split(',')
+        .map(|dim| dim.trim())
+        .filter(|dim| !dim.is_empty())
+        .map(|dim| dim.parse())
+        .collect::<Result<Vec<usize>, _>>()
+        .map_err(|_| NpyError::InvalidShape(shape_str.clone()))?;
+    expect(&header, i + len, b"), }")?;
+
+    Ok(NpyHeader {
+        endian,
+        fortran_order,
+        shape,
+    })
 }
 
 fn expect(buf: &[u8], i: usize, chars: &[u8]) -> Result<usize, NpyError> {
@@ -259,6 +375,25 @@ pub enum NpyError {
 
     /// Unexpected alignment for [Endian].
     InvalidAlignment,
+
+    /// The shape in the header can't be parsed, or doesn't fit the tensor's [Shape].
+    InvalidShape(String),
+
+    /// The device failed to create the tensor, e.g. because it ran out of memory.
+    DeviceError(String),
+}
+
+impl NpyError {
+    fn shape_mismatch(expected: &[usize], found: &[usize]) -> Self {
+        let expected_str = to_shape_str(expected.to_vec());
+        let found_str = to_shape_str(found.to_vec());
+        Self::ParsingMismatch {
+            expected: expected_str.clone().into_bytes(),
+            found: found_str.clone().into_bytes(),
+            expected_str,
+            found_str,
+        }
+    }
 }
 
 impl std::fmt::Display for NpyError {
@@ -277,6 +412,8 @@ impl std::fmt::Display for NpyError {
                 "error while parsing: expected {expected_str} found {found_str}"
             ),
             NpyError::InvalidAlignment => write!(fmt, "invalid alignment"),
+            NpyError::InvalidShape(shape) => write!(fmt, "invalid shape: ({shape})"),
+            NpyError::DeviceError(err) => write!(fmt, "{err}"),
         }
     }
 }
@@ -361,6 +498,7 @@ impl From<std::io::Error> for NpzError {
 #[cfg(test)]
 mod tests {
     use crate::{
+        shapes::*,
         tensor::{AsArray, TensorFrom},
         tests::TestDevice,
     };
@@ -514,4 +652,89 @@ mod tests {
             .load_from_npy(file.path())
             .expect_err("");
     }
+
+    #[test]
+    fn test_load_npy_round_trip() {
+        let dev: TestDevice = Default::default();
+        let x = dev.tensor([[0.0f32, 1.0, 2.0], [3.0, 4.0, 5.0]]);
+
+        let file = NamedTempFile::new().expect("failed to create tempfile");
+        x.save_npy(file.path()).expect("Saving failed");
+
+        let y: Tensor<Rank2<2, 3>, f32, _> = dev.load_npy(file.path()).expect("");
+        assert_eq!(y.array(), x.array());
+
+        let y: Tensor<(usize, Const<3>), f32, _> = dev.load_npy(file.path()).expect("");
+        assert_eq!(y.shape(), &(2, Const));
+        assert_eq!(y.as_vec(), x.as_vec());
+
+        let r: Result<Tensor<Rank2<3, 2>, f32, _>, _> = dev.load_npy(file.path());
+        assert!(matches!(r, Err(NpyError::InvalidShape(_))));
+        let r: Result<Tensor<Rank1<6>, f32, _>, _> = dev.load_npy(file.path());
+        assert!(matches!(r, Err(NpyError::InvalidShape(_))));
+        let r: Result<Tensor<Rank2<2, 3>, f64, _>, _> = dev.load_npy(file.path());
+        assert!(matches!(r, Err(NpyError::ParsingMismatch { .. })));
+    }
+
+    /// The bytes written by `np.save(f, np.array([[1, 2, 3], [4, 5, 6]], dtype="<f4", order=order))`
+    fn numpy_2d_f32(fortran_order: bool) -> Vec<u8> {
+        let mut bytes = b"\x93NUMPY\x01\x00\x76\x00".to_vec();
+        if fortran_order {
+            bytes.extend_from_slice(b"{'descr': '<f4', 'fortran_order': True, 'shape': (2, 3), }");
+        } else {
+            bytes.extend_from_slice(b"{'descr': '<f4', 'fortran_order': False, 'shape': (2, 3), }");
+        }
+        bytes.resize(10 + 0x75, b' ');
+        bytes.push(b'\n');
+        let data: [f32; 6] = if fortran_order {
+            [1.0, 4.0, 2.0, 5.0, 3.0, 6.0]
+        } else {
+            [1.0, 2.0, 3.0, 4.0, 5.0, 6.0]
+        };
+        for v in data {
+            bytes.extend_from_slice(&v.to_le_bytes());
+        }
+        bytes
+    }
+
+    #[test]
+    fn test_load_npy_from_numpy() {
+        let dev: TestDevice = Default::default();
+        let expected = [[1.0, 2.0, 3.0], [4.0, 5.0, 6.0]];
+
+        let c: Tensor<Rank2<2, 3>, f32, _> = dev.read_npy(&mut &numpy_2d_f32(false)[..]).expect("");
+        assert_eq!(c.array(), expected);
+
+        let f: Tensor<Rank2<2, 3>, f32, _> = dev.read_npy(&mut &numpy_2d_f32(true)[..]).expect("");
+        assert_eq!(f.array(), expected);
+
+        let mut t = dev.tensor([[0.0f32; 3]; 2]);
+        t.read_from(&mut &numpy_2d_f32(true)[..]).expect("");
+        assert_eq!(t.array(), expected);
+    }
+
+    #[test]
+    fn test_load_npy_untrusted_shape() {
+        let dev: TestDevice = Default::default();
+        let read = |shape: &str| {
+            let mut bytes = b"\x93NUMPY\x01\x00\x76\x00".to_vec();
+            bytes.extend_from_slice(b"{'descr': '<f4', 'fortran_order': False, 'shape': (");
+            bytes.extend_from_slice(shape.as_bytes());
+            bytes.extend_from_slice(b"), }");
+            bytes.resize(10 + 0x75, b' ');
+            bytes.push(b'\n');
+            bytes.extend_from_slice(&[0; 16]);
+            let r: Result<Tensor<(usize, usize), f32, _>, _> = dev.read_npy(&mut &bytes[..]);
+            r
+        };
+
+        let r = read("18446744073709551615, 2");
+        assert!(matches!(r, Err(NpyError::InvalidShape(_))));
+
+        // the data is much shorter than the shape says
+        let r = read("1099511627776, 1024");
+        assert!(matches!(r, Err(NpyError::IoError(e)) if e.kind() == io::ErrorKind::UnexpectedEof));
+
+        assert_eq!(read("2, 2").expect("").as_vec(), [0.0; 4]);
+    }
 }
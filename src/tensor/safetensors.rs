@@ -0,0 +1,237 @@
+use crate::shapes::{Dtype, HasShape, Shape};
+
+use super::{CopySlice, DeviceStorage, Tensor};
+
+use safetensors::tensor::{Dtype as SafeDtypeTag, TensorInfo};
+use std::{
+    collections::BTreeMap,
+    fs::File,
+    io::{self, BufReader, Read},
+    path::Path,
+    string::String,
+    vec::Vec,
+};
+
+/// The tensors stored in a `.safetensors` file, as described by the
+/// [safetensors format](https://github.com/huggingface/safetensors).
+///
+/// This is what `safetensors.torch.save_file(model.state_dict(), path)` writes from pytorch.
+/// The header is parsed and validated by the [safetensors] crate.
+#[derive(Debug, Clone)]
+pub struct SafeTensors {
+    infos: BTreeMap<String, TensorInfo>,
+    data: Vec<u8>,
+}
+
+impl SafeTensors {
+    /// Reads all the tensors in the `.safetensors` file at `path`.
+    pub fn load<P: AsRef<Path>>(path: P) -> Result<Self, SafeTensorError> {
+        let mut f = BufReader::new(File::open(path)?);
+        let mut bytes = Vec::new();
+        f.read_to_end(&mut bytes)?;
+        Self::deserialize(bytes)
+    }
+
+    /// Parses the contents of a `.safetensors` file.
+    pub fn deserialize(mut bytes: Vec<u8>) -> Result<Self, SafeTensorError> {
+        let (header_len, metadata) = safetensors::SafeTensors::read_metadata(&bytes)?;
+        let infos = metadata
+            .tensors()
+            .into_iter()
+            .map(|(name, info)| (name, info.clone()))
+            .collect();
+        let data = bytes.split_off(header_len + 8);
+        Ok(Self { infos, data })
+    }
+
+    /// The names of all the tensors in the file.
+    pub fn names(&self) -> impl Iterator<Item = &String> {
+        self.infos.keys()
+    }
+}
+
+impl<S: Shape, E: Dtype + SafeDtype, D: DeviceStorage + CopySlice<E>, T> Tensor<S, E, D, T> {
+    /// Copies the data of the tensor named `name` in `tensors` into this tensor.
+    ///
+    /// The stored tensor must have the same dtype and shape as this tensor.
+    pub fn load_safetensor(
+        &mut self,
+        tensors: &SafeTensors,
+        name: &str,
+    ) -> Result<(), SafeTensorError> {
+        let info = tensors
+            .infos
+            .get(name)
+            .ok_or_else(|| SafeTensorError::MissingTensor(name.into()))?;
+        if info.dtype != E::SAFE_DTYPE {
+            return Err(SafeTensorError::DtypeMismatch {
+                name: name.into(),
+                expected: E::SAFE_DTYPE,
+                found: info.dtype,
+            });
+        }
+        let shape: Vec<usize> = self.shape().concrete().into_iter().collect();
+        if info.shape != shape {
+            return Err(SafeTensorError::ShapeMismatch {
+                name: name.into(),
+                expected: shape,
+                found: info.shape.clone(),
+            });
+        }
+        // read_metadata already checked that the offsets fit the shape and dtype
+        let bytes = &tensors.data[info.data_offsets.0..info.data_offsets.1];
+        let buf: Vec<E> = bytes
+            .chunks_exact(std::mem::size_of::<E>())
+            .map(E::from_le_bytes)
+            .collect();
+        D::copy_from(self, &buf);
+        Ok(())
+    }
+}
+
+/// Maps dtypes to the [safetensors::Dtype] used in safetensors headers.
+///
+/// For example an f32's dtype is `Dtype::F32`, written as "F32" in the header.
+pub trait SafeDtype: Sized {
+    const SAFE_DTYPE: SafeDtypeTag;
+    fn from_le_bytes(bytes: &[u8]) -> Self;
+}
+
+impl SafeDtype for f32 {
+    const SAFE_DTYPE: SafeDtypeTag = SafeDtypeTag::F32;
+    fn from_le_bytes(bytes: &[u8]) -> Self {
+        let mut buf = [0; 4];
+        buf.copy_from_slice(bytes);
+        Self::from_le_bytes(buf)
+    }
+}
+
+impl SafeDtype for f64 {
+    const SAFE_DTYPE: SafeDtypeTag = SafeDtypeTag::F64;
+    fn from_le_bytes(bytes: &[u8]) -> Self {
+        let mut buf = [0; 8];
+        buf.copy_from_slice(bytes);
+        Self::from_le_bytes(buf)
+    }
+}
+
+/// Error that can happen while loading tensors from a `.safetensors` file.
+#[derive(Debug)]
+pub enum SafeTensorError {
+    /// Error from opening or reading the file.
+    IoError(std::io::Error),
+
+    /// The header is invalid, see [safetensors::SafeTensorError].
+    InvalidHeader(safetensors::SafeTensorError),
+
+    /// There is no tensor with this name in the file.
+    MissingTensor(String),
+
+    /// The stored tensor has a different dtype.
+    DtypeMismatch {
+        name: String,
+        expected: SafeDtypeTag,
+        found: SafeDtypeTag,
+    },
+
+    /// The stored tensor has a different shape.
+    ShapeMismatch {
+        name: String,
+        expected: Vec<usize>,
+        found: Vec<usize>,
+    },
+}
+
+impl std::fmt::Display for SafeTensorError {
+    fn fmt(&self, fmt: &mut std::fmt::Formatter) -> std::fmt::Result {
+        match self {
+            SafeTensorError::IoError(err) => write!(fmt, "{err}"),
+            SafeTensorError::InvalidHeader(err) => write!(fmt, "invalid header: {err}"),
+            SafeTensorError::MissingTensor(name) => write!(fmt, "missing tensor {name}"),
+            SafeTensorError::DtypeMismatch {
+                name,
+                expected,
+                found,
+            } => write!(
+                fmt,
+                "dtype mismatch for {name}: expected {expected:?} found {found:?}"
+            ),
+            SafeTensorError::ShapeMismatch {
+                name,
+                expected,
+                found,
+            } => write!(
+                fmt,
+                "shape mismatch for {name}: expected {expected:?} found {found:?}"
+            ),
+        }
+    }
+}
+
+impl std::error::Error for SafeTensorError {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        match self {
+            SafeTensorError::IoError(err) => Some(err),
+            SafeTensorError::InvalidHeader(err) => Some(err),
+            _ => None,
+        }
+    }
+}
+
+impl From<io::Error> for SafeTensorError {
+    fn from(e: io::Error) -> Self {
+        Self::IoError(e)
+    }
+}
+
+impl From<safetensors::SafeTensorError> for SafeTensorError {
+    fn from(e: safetensors::SafeTensorError) -> Self {
+        Self::InvalidHeader(e)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::format;
+
+    fn with_header(header: &str) -> Vec<u8> {
+        let mut bytes = (header.len() as u64).to_le_bytes().to_vec();
+        bytes.extend_from_slice(header.as_bytes());
+        bytes.extend_from_slice(&[0; 8]);
+        bytes
+    }
+
+    #[test]
+    fn test_unicode_names() {
+        let header =
+            r#"{"caf\u00e9.\ud83d\ude00":{"dtype":"F32","shape":[2],"data_offsets":[0,8]}}"#;
+        let tensors = SafeTensors::deserialize(with_header(header)).unwrap();
+        assert_eq!(tensors.names().collect::<Vec<_>>(), ["café.😀"]);
+    }
+
+    #[test]
+    fn test_invalid_headers() {
+        let mut bytes = u64::MAX.to_le_bytes().to_vec();
+        bytes.extend_from_slice(b"{}");
+        let r = SafeTensors::deserialize(bytes);
+        assert!(matches!(r, Err(SafeTensorError::InvalidHeader(_))));
+
+        let nested = format!(
+            r#"{{"__metadata__":{}{}}}"#,
+            "[".repeat(10_000),
+            "]".repeat(10_000)
+        );
+        for header in [
+            nested.as_str(),
+            r#"{"__metadata__":{"flag":true}}"#,
+            r#"{"a":{"dtype":"F32","shape":[2],"data_offsets":[0,4]}}"#,
+            r#"{"a":{"dtype":"F32","shape":[1],"data_offsets":[4,8]}}"#,
+            r#"{"a":{"dtype":"F32","shape":[2],"data_offsets":[0,8]}} {}"#,
+            r#"{"\ud83d":{"dtype":"F32","shape":[2],"data_offsets":[0,8]}}"#,
+        ] {
+            let r = SafeTensors::deserialize(with_header(header));
+            assert!(matches!(r, Err(SafeTensorError::InvalidHeader(_))));
+        }
+    }
+}
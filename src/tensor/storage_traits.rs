@@ -30,6 +30,33 @@ pub trait DeviceStorage: 'static + std::fmt::Debug + Default + Clone + HasErr {
     fn tensor_to_vec<S: Shape, E: Unit, T>(&self, tensor: &Tensor<S, E, Self, T>) -> Vec<E>;
 }
 
+/// Construct a device whose random number generator starts from a given seed.
+///
+/// For the same seed, every device produces the same sequence of random values for
+/// the same sequence of calls. In particular [SampleTensor] methods like
+/// [SampleTensor::sample_normal] and [SampleTensor::sample_uniform] return element-equal
+/// tensors for the same seed, shape and dtype whether the device is a [crate::tensor::Cpu]
+/// or a `Cuda`, because `Cuda` draws its samples on the host with the same rng
+/// and then copies them over.
+///
+/// ```rust
+/// # use dfdx::prelude::*;
+/// let a: Tensor<Rank1<5>, f32, Cpu> = Cpu::with_seed(7).sample_normal();
+/// let b: Tensor<Rank1<5>, f32, Cpu> = Cpu::with_seed(7).sample_normal();
+/// assert_eq!(a.array(), b.array());
+/// ```
+pub trait SeedableDevice: DeviceStorage {
+    /// Constructs the device with its rng seeded from `seed`.
+    ///
+    /// Named differently from the inherent `seed_from_u64` constructors on the devices,
+    /// so that calls don't resolve to one or the other depending on what's in scope.
+    fn with_seed(seed: u64) -> Self {
+        Self::try_with_seed(seed).unwrap()
+    }
+    /// Fallible version of [SeedableDevice::with_seed]
+    fn try_with_seed(seed: u64) -> Result<Self, Self::Err>;
+}
+
 /// Internal trait - Represents something that can allocate its own gradient.
 pub trait AllocGrad: HasErr {
     type Gradient: 'static;
@@ -124,6 +151,11 @@ pub trait ZeroFillStorage<E: Unit>: DeviceStorage {
     fn try_fill_with_zeros(&self, storage: &mut Self::Vec<E>) -> Result<(), Self::Err>;
 }
 
+/// Computes the sum of the squares of every element in a buffer.
+pub trait SquaredNormStorage<E: Unit>: DeviceStorage {
+    fn try_squared_norm(&self, storage: &Self::Vec<E>) -> Result<f64, Self::Err>;
+}
+
 /// Construct tensors filled with ones.
 pub trait OnesTensor<E: Unit>: DeviceStorage {
     /// Creates a tensor filled with ones.
@@ -169,6 +201,62 @@ pub trait OneFillStorage<E: Unit>: DeviceStorage {
     fn try_fill_with_ones(&self, storage: &mut Self::Vec<E>) -> Result<(), Self::Err>;
 }
 
+/// Construct the sinusoidal positional encoding from
+/// [Attention Is All You Need](https://arxiv.org/abs/1706.03762).
+pub trait PositionalEncodingTensor<E: Unit>: DeviceStorage {
+    /// Creates a `(LEN, DIM)` tensor where row `pos` is the encoding of position `pos`:
+    /// - `PE[pos, 2i] = sin(pos / 10000^(2i / DIM))`
+    /// - `PE[pos, 2i + 1] = cos(pos / 10000^(2i / DIM))`
+    ///
+    /// The result is not tracked by any tape, so it is usually just added to embeddings.
+    /// ```rust
+    /// # use dfdx::prelude::*;
+    /// # let dev: Cpu = Default::default();
+    /// let pe: Tensor<Rank2<16, 8>, f32, _> = dev.sinusoidal_positional_encoding();
+    /// assert_eq!(pe.array()[0], [0.0, 1.0, 0.0, 1.0, 0.0, 1.0, 0.0, 1.0]);
+    /// ```
+    fn sinusoidal_positional_encoding<const LEN: usize, const DIM: usize>(
+        &self,
+    ) -> Tensor<Rank2<LEN, DIM>, E, Self> {
+        self.try_sinusoidal_positional_encoding().unwrap()
+    }
+
+    /// Fallible version of [PositionalEncodingTensor::sinusoidal_positional_encoding]
+    fn try_sinusoidal_positional_encoding<const LEN: usize, const DIM: usize>(
+        &self,
+    ) -> Result<Tensor<Rank2<LEN, DIM>, E, Self>, Self::Err>;
+}
+
+/// Construct tensors where each element is computed from its index.
+pub trait BuildWithTensor<E: Unit>: DeviceStorage {
+    /// Creates a tensor with `shape`, where the element at index `idx` is `f(idx)`.
+    /// This is handy for ranges, identity matrices, and test fixtures.
+    /// ```rust
+    /// # use dfdx::prelude::*;
+    /// # let dev: Cpu = Default::default();
+    /// let t: Tensor<Rank2<2, 3>, f32, _> =
+    ///     dev.build_with((Const, Const), |[i, j]| (i * 3 + j) as f32);
+    /// assert_eq!(t.array(), [[0.0, 1.0, 2.0], [3.0, 4.0, 5.0]]);
+    ///
+    /// let eye = dev.build_with((3, 3), |[i, j]| if i == j { 1.0f32 } else { 0.0 });
+    /// assert_eq!(eye.as_vec(), [1.0, 0.0, 0.0, 0.0, 1.0, 0.0, 0.0, 0.0, 1.0]);
+    /// ```
+    fn build_with<S: Shape, F: FnMut(S::Concrete) -> E>(
+        &self,
+        shape: S,
+        f: F,
+    ) -> Tensor<S, E, Self> {
+        self.try_build_with(shape, f).unwrap()
+    }
+
+    /// Fallible version of [BuildWithTensor::build_with]
+    fn try_build_with<S: Shape, F: FnMut(S::Concrete) -> E>(
+        &self,
+        shape: S,
+        f: F,
+    ) -> Result<Tensor<S, E, Self>, Self::Err>;
+}
+
 /// Constructs tensors filled with random values from a given distribution.
 pub trait SampleTensor<E: Unit>: DeviceStorage {
     /// Samples a const tensor from a uniform distribution
@@ -261,6 +349,69 @@ impl<S: Shape, E: Unit, D: DeviceStorage, T> Tensor<S, E, D, T> {
     }
 }
 
+impl<S: Shape, E: Dtype + num_traits::Float, D: DeviceStorage, T> Tensor<S, E, D, T> {
+    /// Whether every element `a` of `self` is close to the element `b` of `other` at the
+    /// same position, i.e. `|a - b| <= atol + rtol * |b|`. NaNs are never close to anything,
+    /// see [Tensor::allclose_equal_nan] to treat them as equal.
+    ///
+    /// Both tensors are copied to the host for the comparison.
+    ///
+    /// **Pytorch equivalent**: `torch.allclose(self, other, rtol, atol)`
+    ///
+    /// Examples:
+    /// ```rust
+    /// # use dfdx::prelude::*;
+    /// # let dev: Cpu = Default::default();
+    /// let a = dev.tensor([1.0, 2.0, 3.0]);
+    /// assert!(a.allclose(&dev.tensor([1.0, 2.001, 3.0]), 0.0, 1e-2));
+    /// assert!(!a.allclose(&dev.tensor([1.0, 2.1, 3.0]), 0.0, 1e-2));
+    /// ```
+    pub fn allclose<T2>(&self, other: &Tensor<S, E, D, T2>, rtol: E, atol: E) -> bool {
+        self.allclose_with(other, rtol, atol, false)
+    }
+
+    /// Same as [Tensor::allclose], but two NaNs at the same position count as close.
+    pub fn allclose_equal_nan<T2>(&self, other: &Tensor<S, E, D, T2>, rtol: E, atol: E) -> bool {
+        self.allclose_with(other, rtol, atol, true)
+    }
+
+    /// [Tensor::allclose] with `rtol = 1e-5` and `atol = 1e-8`, the same defaults as
+    /// pytorch and numpy.
+    ///
+    /// Examples:
+    /// ```rust
+    /// # use dfdx::prelude::*;
+    /// # let dev: Cpu = Default::default();
+    /// let a = dev.tensor([0.1f32, 0.2]);
+    /// let b = dev.tensor([0.3f32, 0.6]);
+    /// assert!((a.clone() + a.clone() + a).approx_eq(&b));
+    /// ```
+    pub fn approx_eq<T2>(&self, other: &Tensor<S, E, D, T2>) -> bool {
+        let rtol = E::from_f64(1e-5).unwrap();
+        let atol = E::from_f64(1e-8).unwrap();
+        self.allclose(other, rtol, atol)
+    }
+
+    fn allclose_with<T2>(
+        &self,
+        other: &Tensor<S, E, D, T2>,
+        rtol: E,
+        atol: E,
+        equal_nan: bool,
+    ) -> bool {
+        assert_eq!(self.shape, other.shape);
+        let lhs = self.as_vec();
+        let rhs = other.as_vec();
+        lhs.iter().zip(rhs.iter()).all(|(&a, &b)| {
+            if a.is_nan() || b.is_nan() {
+                equal_nan && a.is_nan() && b.is_nan()
+            } else {
+                a == b || (a - b).abs() <= atol + rtol * b.abs()
+            }
+        })
+    }
+}
+
 /// Construct tensors from rust vectors. This trait is only used to implement TensorFrom.
 pub trait TensorFromVec<E: Unit>: DeviceStorage {
     fn tensor_from_vec<S: Shape>(&self, src: Vec<E>, shape: S) -> Tensor<S, E, Self> {
@@ -376,3 +527,35 @@ impl<E: Unit, S: Shape, D: DeviceStorage + TensorFromVec<E>> TensorFrom<(Vec<E>,
         self.try_tensor_from_vec(src, shape)
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use crate::{shapes::*, tensor::*, tests::*};
+
+    #[test]
+    fn test_allclose() {
+        let dev: TestDevice = Default::default();
+        let a: Tensor<Rank2<2, 2>, TestDtype, _> = dev.tensor([[1.0, -2.0], [100.0, 0.0]]);
+        let b: Tensor<Rank2<2, 2>, TestDtype, _> = dev.tensor([[1.0, -2.0], [100.05, 1e-9]]);
+        assert!(a.allclose(&b, 1e-3, 1e-8));
+        assert!(!a.allclose(&b, 1e-4, 1e-8));
+        assert!(!a.approx_eq(&b));
+        assert!(a.approx_eq(&a.clone()));
+        assert!(a.trace::<TestDtype>().approx_eq(&a));
+
+        let c: Tensor<Rank2<2, 2>, TestDtype, _> = dev.tensor([[1.0, 2.0], [100.0, 0.0]]);
+        assert!(!a.allclose(&c, 0.1, 0.1));
+    }
+
+    #[test]
+    fn test_allclose_nan() {
+        let dev: TestDevice = Default::default();
+        let a: Tensor<Rank1<3>, TestDtype, _> = dev.tensor([1.0, TestDtype::NAN, 3.0]);
+        assert!(!a.approx_eq(&a));
+        assert!(a.allclose_equal_nan(&a, 1e-5, 1e-8));
+
+        let b: Tensor<Rank1<3>, TestDtype, _> = dev.tensor([1.0, 2.0, 3.0]);
+        assert!(!a.allclose_equal_nan(&b, 1e-5, 1e-8));
+        assert!(!b.allclose_equal_nan(&a, 1e-5, 1e-8));
+    }
+}
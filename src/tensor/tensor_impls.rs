@@ -3,7 +3,7 @@ use rand::distributions::Distribution;
 use super::storage_traits::{DeviceStorage, HasErr, TensorFromVec};
 use super::{Cpu, OneFillStorage, SampleTensor, ZeroFillStorage};
 use crate::{
-    gradients::{NoneTape, OwnedTape, Tape},
+    gradients::{DuplicateTape, NoneTape, OwnedTape, Tape},
     shapes::*,
     unique_id::{HasUniqueId, UniqueId},
 };
@@ -40,9 +40,22 @@ pub struct Tensor<S: Shape, E: Unit, D: DeviceStorage, T = NoneTape> {
     pub(crate) shape: S,
     pub(crate) strides: S::Concrete,
     pub(crate) device: D,
+    pub(crate) param_opts: ParamOptions,
     pub(crate) tape: T,
 }
 
+/// Options of a tensor that is a parameter of a module, which are set at runtime
+/// and stored with the tensor. They are read by [crate::nn::tensor_collection::RecursiveWalker]
+/// into the [crate::nn::tensor_collection::TensorOptions] of the tensor.
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
+pub(crate) struct ParamOptions {
+    /// Whether the tensor is frozen, see [crate::nn::freeze]
+    pub(crate) frozen: bool,
+
+    /// The name of the parameter group, see [crate::optim::ParamGroup]
+    pub(crate) group: Option<&'static str>,
+}
+
 impl<S: Shape, E: Unit, D: DeviceStorage, T> HasShape for Tensor<S, E, D, T> {
     type WithShape<New: Shape> = Tensor<New, E, D, T>;
     type Shape = S;
@@ -89,11 +102,50 @@ impl<S: Shape, E: Unit, D: DeviceStorage, T> Tensor<S, E, D, T> {
             shape: self.shape,
             strides: self.strides,
             device: self.device.clone(),
+            param_opts: self.param_opts,
             tape: Default::default(),
         }
     }
 }
 
+impl<S: Shape, E: Unit, D: DeviceStorage, F: Unit> Tensor<S, E, D, OwnedTape<F, D>> {
+    /// Clones the tensor *and* its tape, so the backward operations recorded so far can be
+    /// used by more than one call to `backward()`. This is useful for computing the gradients
+    /// of several outputs that share part of the forward pass, without recomputing it.
+    ///
+    /// The clones share the recorded operations, and each one gets its own zeroed gradient
+    /// buffers. If the tapes are merged back together (e.g. by adding two outputs), the
+    /// shared operations are only run once. Operations recorded with [Tape::add_backward_op]
+    /// can't be run by the clone, only ones recorded with [Tape::add_reusable_backward_op].
+    ///
+    /// ```rust
+    /// # use dfdx::prelude::*;
+    /// # let dev: Cpu = Default::default();
+    /// let x = dev.tensor([1.0f32, 2.0]);
+    /// let h = x.trace().square();
+    /// let g1 = h.retain_graph().sum().backward();
+    /// let g2 = h.exp().sum().backward();
+    /// assert_eq!(g1.get(&x).array(), [2.0, 4.0]);
+    /// assert_eq!(g2.get(&x).array(), [2.0 * 1.0f32.exp(), 4.0 * 4.0f32.exp()]);
+    /// ```
+    pub fn retain_graph(&self) -> Self {
+        self.try_retain_graph().unwrap()
+    }
+
+    /// Fallible version of [Tensor::retain_graph]
+    pub fn try_retain_graph(&self) -> Result<Self, D::Err> {
+        Ok(Tensor {
+            id: self.id,
+            data: self.data.clone(),
+            shape: self.shape,
+            strides: self.strides,
+            device: self.device.clone(),
+            param_opts: self.param_opts,
+            tape: self.tape.try_duplicate(&self.device)?,
+        })
+    }
+}
+
 /// Put a tape of type `T` into the tensor
 pub trait PutTape<T> {
     type Output;
@@ -115,6 +167,7 @@ impl<S: Shape, E: Unit, D: DeviceStorage, T> PutTape<T> for Tensor<S, E, D> {
             shape: self.shape,
             strides: self.strides,
             device: self.device,
+            param_opts: self.param_opts,
             tape,
         }
     }
@@ -149,6 +202,7 @@ impl<S: Shape, E: Dtype, D: DeviceStorage, T: Default> SplitTape for Tensor<S, E
                 shape: self.shape,
                 strides: self.strides,
                 device: self.device,
+                param_opts: self.param_opts,
                 tape: NoneTape,
             },
             self.tape,
@@ -162,6 +216,7 @@ impl<S: Shape, E: Dtype, D: DeviceStorage, T: Default> SplitTape for Tensor<S, E
             shape: self.shape,
             strides: self.strides,
             device: self.device.clone(),
+            param_opts: self.param_opts,
             tape: Default::default(),
         }
     }
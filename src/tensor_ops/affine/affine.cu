@@ -0,0 +1,57 @@
+#include "cuda_utils.cuh"
+
+#define AFFINE(TYPENAME, FORWARD, BACKWARD) \
+extern "C" __global__ void FORWARD( \
+    const size_t numel, \
+    const size_t num_dims, \
+    const size_t *dims, \
+    const TYPENAME *x, \
+    const size_t *x_strides, \
+    const TYPENAME *scale, \
+    const size_t *scale_strides, \
+    const TYPENAME *shift, \
+    const size_t *shift_strides, \
+    TYPENAME *out \
+) { \
+    unsigned int i = blockIdx.x * blockDim.x + threadIdx.x; \
+    if (i >= numel) { \
+        return; \
+    } \
+\
+    unsigned int x_i = get_strided_index(i, num_dims, dims, x_strides); \
+    unsigned int scale_i = get_strided_index(i, num_dims, dims, scale_strides); \
+    unsigned int shift_i = get_strided_index(i, num_dims, dims, shift_strides); \
+    out[i] = x[x_i] * scale[scale_i] + shift[shift_i]; \
+} \
+\
+extern "C" __global__ void BACKWARD( \
+    const size_t numel, \
+    const size_t num_dims, \
+    const size_t *dims, \
+    const TYPENAME *x, \
+    TYPENAME *grad_x, \
+    const size_t *x_strides, \
+    const TYPENAME *scale, \
+    TYPENAME *grad_scale, \
+    const size_t *scale_strides, \
+    TYPENAME *grad_shift, \
+    const size_t *shift_strides, \
+    const TYPENAME *grad_out \
+) { \
+    unsigned int i = blockIdx.x * blockDim.x + threadIdx.x; \
+    if (i >= numel) { \
+        return; \
+    } \
+\
+    unsigned int x_i = get_strided_index(i, num_dims, dims, x_strides); \
+    unsigned int scale_i = get_strided_index(i, num_dims, dims, scale_strides); \
+    unsigned int shift_i = get_strided_index(i, num_dims, dims, shift_strides); \
+    TYPENAME go = grad_out[i]; \
+\
+    atomicAdd(grad_x + x_i, scale[scale_i] * go); \
+    atomicAdd(grad_scale + scale_i, x[x_i] * go); \
+    atomicAdd(grad_shift + shift_i, go); \
+}
+
+AFFINE(float, affine_fwd_f32, affine_bwd_f32);
+AFFINE(double, affine_fwd_f64, affine_bwd_f64);
@@ -0,0 +1,56 @@
+use crate::{
+    shapes::{Dtype, Shape},
+    tensor::{
+        cpu::{LendingIterator, NdIndex},
+        Cpu, Tensor, ZerosTensor,
+    },
+};
+
+impl<E: Dtype> super::AffineKernel<E> for Cpu {
+    fn forward<S: Shape>(
+        &self,
+        x: &Tensor<S, E, Self>,
+        scale: &Tensor<S, E, Self>,
+        shift: &Tensor<S, E, Self>,
+    ) -> Result<Tensor<S, E, Self>, Self::Err> {
+        let mut out = self.try_zeros_like(&x.shape)?;
+        let mut x_iter = x.iter();
+        let mut scale_iter = scale.iter();
+        let mut shift_iter = shift.iter();
+        // NOTE: we can use buf_iter_mut() here because try_zeros_like makes a contiguous array
+        for o in out.buf_iter_mut() {
+            let x = *x_iter.next().unwrap();
+            let scale = *scale_iter.next().unwrap();
+            let shift = *shift_iter.next().unwrap();
+            *o = x * scale + shift;
+        }
+        Ok(out)
+    }
+
+    fn backward<S: Shape>(
+        &self,
+        x: &Tensor<S, E, Self>,
+        grad_x: &mut Self::Vec<E>,
+        scale: &Tensor<S, E, Self>,
+        grad_scale: &mut Self::Vec<E>,
+        shift: &Tensor<S, E, Self>,
+        grad_shift: &mut Self::Vec<E>,
+        grad_out: &Self::Vec<E>,
+    ) -> Result<(), Self::Err> {
+        let mut x_idx = NdIndex::new(x.shape, x.strides);
+        let mut scale_idx = NdIndex::new(scale.shape, scale.strides);
+        let mut shift_idx = NdIndex::new(shift.shape, shift.strides);
+        let x_buf = x.data.as_ref();
+        let scale_buf = scale.data.as_ref();
+        // NOTE: we can use .iter() here because the output of forward is contiguous
+        for &go in grad_out.iter() {
+            let x_i = x_idx.next().unwrap();
+            let scale_i = scale_idx.next().unwrap();
+            let shift_i = shift_idx.next().unwrap();
+            grad_x[x_i] += scale_buf[scale_i] * go;
+            grad_scale[scale_i] += x_buf[x_i] * go;
+            grad_shift[shift_i] += go;
+        }
+        Ok(())
+    }
+}
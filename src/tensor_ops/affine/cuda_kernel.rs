@@ -0,0 +1,113 @@
+use crate::{
+    shapes::*,
+    tensor::{Cuda, Tensor},
+    unique_id::unique_id,
+};
+
+use cudarc::driver::{CudaSlice, LaunchAsync, LaunchConfig};
+use std::sync::Arc;
+
+const PTX_SRC: &str = include_str!(concat!(env!("OUT_DIR"), "/affine.ptx"));
+
+trait HasCudaKernel<E> {
+    const MOD: &'static str;
+    const FNS: &'static [&'static str];
+}
+
+impl HasCudaKernel<f32> for Cuda {
+    const MOD: &'static str = "affine_f32";
+    const FNS: &'static [&'static str] = &["affine_fwd_f32", "affine_bwd_f32"];
+}
+
+impl HasCudaKernel<f64> for Cuda {
+    const MOD: &'static str = "affine_f64";
+    const FNS: &'static [&'static str] = &["affine_fwd_f64", "affine_bwd_f64"];
+}
+
+impl<E: Dtype> super::AffineKernel<E> for Cuda
+where
+    Self: HasCudaKernel<E>,
+{
+    fn forward<S: Shape>(
+        &self,
+        x: &Tensor<S, E, Self>,
+        scale: &Tensor<S, E, Self>,
+        shift: &Tensor<S, E, Self>,
+    ) -> Result<Tensor<S, E, Self>, Self::Err> {
+        if !self.dev.has_func(Self::MOD, Self::FNS[0]) {
+            self.dev.load_ptx(PTX_SRC.into(), Self::MOD, Self::FNS)?;
+        }
+
+        let shape = x.shape;
+        let strides = shape.strides();
+        let numel = shape.num_elements();
+        let mut storage = unsafe { self.dev.alloc::<E>(numel) }?;
+
+        let dims: CudaSlice<usize> = self.dev.htod_copy(shape.concrete().into())?;
+        let x_strides: CudaSlice<usize> = self.dev.htod_copy(x.strides.into())?;
+        let scale_strides: CudaSlice<usize> = self.dev.htod_copy(scale.strides.into())?;
+        let shift_strides: CudaSlice<usize> = self.dev.htod_copy(shift.strides.into())?;
+
+        let fwd_fn = self.dev.get_func(Self::MOD, Self::FNS[0]).unwrap();
+        let cfg = LaunchConfig::for_num_elems(numel as u32);
+        let params = (
+            numel,               // const size_t numel,
+            S::NUM_DIMS,         // const size_t num_dims,
+            &dims,               // const size_t *dims,
+            x.data.as_ref(),     // const float *x,
+            &x_strides,          // const size_t *x_strides,
+            scale.data.as_ref(), // const float *scale,
+            &scale_strides,      // const size_t *scale_strides,
+            shift.data.as_ref(), // const float *shift,
+            &shift_strides,      // const size_t *shift_strides,
+            &mut storage,        // float *out
+        );
+        unsafe { fwd_fn.launch(cfg, params) }?;
+        Ok(Tensor {
+            id: unique_id(),
+            data: Arc::new(storage),
+            shape,
+            strides,
+            device: self.clone(),
+            param_opts: Default::default(),
+            tape: Default::default(),
+        })
+    }
+
+    fn backward<S: Shape>(
+        &self,
+        x: &Tensor<S, E, Self>,
+        grad_x: &mut Self::Vec<E>,
+        scale: &Tensor<S, E, Self>,
+        grad_scale: &mut Self::Vec<E>,
+        shift: &Tensor<S, E, Self>,
+        grad_shift: &mut Self::Vec<E>,
+        grad_out: &Self::Vec<E>,
+    ) -> Result<(), Self::Err> {
+        let bwd_fn = self.dev.get_func(Self::MOD, Self::FNS[1]).unwrap();
+        let numel = x.shape.num_elements();
+
+        let dims: CudaSlice<usize> = self.dev.htod_copy(x.shape.concrete().into())?;
+        let x_strides: CudaSlice<usize> = self.dev.htod_copy(x.strides.into())?;
+        let scale_strides: CudaSlice<usize> = self.dev.htod_copy(scale.strides.into())?;
+        let shift_strides: CudaSlice<usize> = self.dev.htod_copy(shift.strides.into())?;
+
+        let cfg = LaunchConfig::for_num_elems(numel as u32);
+        let params = (
+            numel,               // const size_t numel,
+            S::NUM_DIMS,         // const size_t num_dims,
+            &dims,               // const size_t *dims,
+            x.data.as_ref(),     // const float *x,
+            grad_x,              // float *grad_x,
+            &x_strides,          // const size_t *x_strides,
+            scale.data.as_ref(), // const float *scale,
+            grad_scale,          // float *grad_scale,
+            &scale_strides,      // const size_t *scale_strides,
+            grad_shift,          // float *grad_shift,
+            &shift_strides,      // const size_t *shift_strides,
+            grad_out,            // const float *grad_out
+        );
+        unsafe { bwd_fn.launch(cfg, params) }?;
+        Ok(())
+    }
+}
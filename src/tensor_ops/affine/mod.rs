@@ -0,0 +1,149 @@
+mod cpu_kernel;
+
+#[cfg(feature = "cuda")]
+mod cuda_kernel;
+
+use crate::{
+    gradients::{Merge, Tape},
+    shapes::{Dtype, HasShape, Shape},
+    tensor::{DeviceStorage, PutTape, SplitTape, Tensor},
+};
+
+use std::vec;
+
+pub trait AffineKernel<E: Dtype>: DeviceStorage {
+    fn forward<S: Shape>(
+        &self,
+        x: &Tensor<S, E, Self>,
+        scale: &Tensor<S, E, Self>,
+        shift: &Tensor<S, E, Self>,
+    ) -> Result<Tensor<S, E, Self>, Self::Err>;
+
+    #[allow(clippy::too_many_arguments)]
+    fn backward<S: Shape>(
+        &self,
+        x: &Tensor<S, E, Self>,
+        grad_x: &mut Self::Vec<E>,
+        scale: &Tensor<S, E, Self>,
+        grad_scale: &mut Self::Vec<E>,
+        shift: &Tensor<S, E, Self>,
+        grad_shift: &mut Self::Vec<E>,
+        grad_out: &Self::Vec<E>,
+    ) -> Result<(), Self::Err>;
+}
+
+/// Fused `x * scale + shift`, computed in a single pass over the data.
+///
+/// Unlike composing a mul and an add, this doesn't allocate the intermediate product.
+/// `scale` and `shift` are usually broadcasted from a smaller shape, and their
+/// gradients are summed over the broadcasted axes.
+///
+/// Example:
+/// ```rust
+/// # use dfdx::prelude::*;
+/// # let dev: Cpu = Default::default();
+/// let x = dev.tensor([[1.0, 2.0, 3.0], [4.0, 5.0, 6.0]]);
+/// let scale = dev.tensor([2.0, 0.5, -1.0]).broadcast();
+/// let shift = dev.tensor([0.0, 1.0, 1.0]).broadcast();
+/// let r = affine(x, scale, shift);
+/// assert_eq!(r.array(), [[2.0, 2.0, -2.0], [8.0, 3.5, -5.0]]);
+/// ```
+pub fn affine<S: Shape, E: Dtype, D: AffineKernel<E>, T, R1: Default, R2: Default>(
+    x: Tensor<S, E, D, T>,
+    scale: Tensor<S, E, D, R1>,
+    shift: Tensor<S, E, D, R2>,
+) -> Tensor<S, E, D, T>
+where
+    T: Tape<E, D> + Merge<R1> + Merge<R2>,
+{
+    x.affine(scale, shift)
+}
+
+impl<S: Shape, E: Dtype, D: AffineKernel<E>, T: Tape<E, D>> Tensor<S, E, D, T> {
+    /// See [affine]
+    pub fn affine<R1: Default, R2: Default>(
+        self,
+        scale: Tensor<S, E, D, R1>,
+        shift: Tensor<S, E, D, R2>,
+    ) -> Self
+    where
+        T: Merge<R1> + Merge<R2>,
+    {
+        self.try_affine(scale, shift).unwrap()
+    }
+
+    /// See [affine]
+    pub fn try_affine<R1: Default, R2: Default>(
+        self,
+        scale: Tensor<S, E, D, R1>,
+        shift: Tensor<S, E, D, R2>,
+    ) -> Result<Self, D::Err>
+    where
+        T: Merge<R1> + Merge<R2>,
+    {
+        assert_eq!(self.shape(), scale.shape());
+        assert_eq!(self.shape(), shift.shape());
+        let (x, tape) = self.split_tape();
+        let (scale, scale_tape) = scale.split_tape();
+        let (shift, shift_tape) = shift.split_tape();
+        let mut tape = tape.merge(scale_tape).merge(shift_tape);
+        let out = x.device.forward(&x, &scale, &shift)?;
+        let phantom_out = out.clone();
+        tape.try_alloc_grad(&x)?;
+        tape.try_alloc_grad(&scale)?;
+        tape.try_alloc_grad(&shift)?;
+        tape.try_alloc_grad(&out)?;
+        let inps = vec![x, scale, shift];
+        tape.add_reusable_backward_op(move |grads| {
+            let (mut grad_inps, grad_out) = grads.many_and_ref(&inps, &phantom_out);
+            let grad_shift = grad_inps.pop().unwrap();
+            let grad_scale = grad_inps.pop().unwrap();
+            let grad_x = grad_inps.pop().unwrap();
+            let [x, scale, shift] = [&inps[0], &inps[1], &inps[2]];
+            x.device
+                .backward(x, grad_x, scale, grad_scale, shift, grad_shift, grad_out)
+        });
+        Ok(out.put_tape(tape))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{shapes::*, tensor::*, tensor_ops::*, tests::*};
+
+    #[test]
+    fn test_affine_matches_mul_add() {
+        let dev: TestDevice = Default::default();
+        let x: Tensor<Rank3<2, 3, 4>, TestDtype, _> = dev.sample_normal();
+        let scale: Tensor<Rank1<3>, TestDtype, _> = dev.sample_normal();
+        let shift: Tensor<Rank1<3>, TestDtype, _> = dev.sample_normal();
+
+        let r = x.trace().affine(
+            scale.trace().broadcast::<_, Axes2<0, 2>>(),
+            shift.trace().broadcast::<_, Axes2<0, 2>>(),
+        );
+        let r2 = x.trace() * scale.trace().broadcast::<_, Axes2<0, 2>>()
+            + shift.trace().broadcast::<_, Axes2<0, 2>>();
+        assert_close(&r.array(), &r2.array());
+
+        // route the gradients through the tapes of `scale` and `shift` to check
+        // that all three tapes are merged
+        let g = r.exp().mean().backward();
+        let g2 = r2.exp().mean().backward();
+        assert_close(&g.get(&x).array(), &g2.get(&x).array());
+        assert_close(&g.get(&scale).array(), &g2.get(&scale).array());
+        assert_close(&g.get(&shift).array(), &g2.get(&shift).array());
+    }
+
+    #[test]
+    fn test_affine_tape_on_scale_only() {
+        let dev: TestDevice = Default::default();
+        let x: Tensor<Rank1<3>, TestDtype, _> = dev.tensor([1.0, -2.0, 3.0]);
+        let scale: Tensor<Rank1<3>, TestDtype, _> = dev.tensor([0.5, 0.5, 2.0]);
+        let shift: Tensor<Rank1<3>, TestDtype, _> = dev.tensor([1.0, 0.0, -1.0]);
+        let r = scale.trace().affine(x.clone(), shift);
+        assert_eq!(r.array(), [1.5, -1.0, 5.0]);
+        let g = r.sum().backward();
+        assert_eq!(g.get(&scale).array(), x.array());
+    }
+}
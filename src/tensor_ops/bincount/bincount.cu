@@ -0,0 +1,80 @@
+#include "cuda_utils.cuh"
+
+template<typename T>
+__device__ void bincount_fwd(
+    const size_t numel,
+    const size_t num_bins,
+    const size_t num_dims,
+    const size_t *dims,
+    const size_t *idx,
+    const size_t *idx_strides,
+    const T *weights,
+    const size_t *weights_strides,
+    T *out
+) {
+    unsigned int i = blockIdx.x * blockDim.x + threadIdx.x;
+    if (i >= numel) {
+        return;
+    }
+
+    unsigned int idx_i = get_strided_index(i, num_dims, dims, idx_strides);
+    unsigned int weights_i = get_strided_index(i, num_dims, dims, weights_strides);
+
+    size_t bin = idx[idx_i];
+    assert(bin < num_bins);
+    atomicAdd(out + bin, weights[weights_i]);
+}
+
+template<typename T>
+__device__ void bincount_bwd(
+    const size_t numel,
+    const size_t num_bins,
+    const size_t num_dims,
+    const size_t *dims,
+    const size_t *idx,
+    const size_t *idx_strides,
+    T *grad_weights,
+    const size_t *weights_strides,
+    const T *grad_out
+) {
+    unsigned int i = blockIdx.x * blockDim.x + threadIdx.x;
+    if (i >= numel) {
+        return;
+    }
+
+    unsigned int idx_i = get_strided_index(i, num_dims, dims, idx_strides);
+    unsigned int weights_i = get_strided_index(i, num_dims, dims, weights_strides);
+
+    atomicAdd(grad_weights + weights_i, grad_out[idx[idx_i]]);
+}
+
+#define BINCOUNT(TYPENAME, FWD, BWD) \
+extern "C" __global__ void FWD( \
+    const size_t numel, \
+    const size_t num_bins, \
+    const size_t num_dims, \
+    const size_t *dims, \
+    const size_t *idx, \
+    const size_t *idx_strides, \
+    const TYPENAME *weights, \
+    const size_t *weights_strides, \
+    TYPENAME *out \
+) { \
+    bincount_fwd(numel, num_bins, num_dims, dims, idx, idx_strides, weights, weights_strides, out); \
+} \
+extern "C" __global__ void BWD( \
+    const size_t numel, \
+    const size_t num_bins, \
+    const size_t num_dims, \
+    const size_t *dims, \
+    const size_t *idx, \
+    const size_t *idx_strides, \
+    TYPENAME *grad_weights, \
+    const size_t *weights_strides, \
+    const TYPENAME *grad_out \
+) { \
+    bincount_bwd(numel, num_bins, num_dims, dims, idx, idx_strides, grad_weights, weights_strides, grad_out); \
+}
+
+BINCOUNT(float, bincount_fwd_f32, bincount_bwd_f32);
+BINCOUNT(double, bincount_fwd_f64, bincount_bwd_f64);
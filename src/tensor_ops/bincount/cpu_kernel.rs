@@ -0,0 +1,36 @@
+use crate::shapes::{Dtype, Rank1, Shape};
+use crate::tensor::{
+    cpu::{index_to_i, LendingIterator},
+    Cpu, Tensor, ZerosTensor,
+};
+
+impl<E: Dtype> super::BincountKernel<E> for Cpu {
+    fn forward<S: Shape, const N: usize>(
+        &self,
+        idx: &Tensor<S, usize, Self>,
+        weights: &Tensor<S, E, Self>,
+    ) -> Result<Tensor<Rank1<N>, E, Self>, Self::Err> {
+        let mut out: Tensor<Rank1<N>, E, Self> = self.try_zeros()?;
+        let buf = std::sync::Arc::make_mut(&mut out.data);
+        let mut idx_iter = idx.iter_with_index();
+        while let Some((bin, i)) = idx_iter.next() {
+            assert!(*bin < N, "bin {bin} out of range for {N} bins");
+            buf[*bin] += weights[i];
+        }
+        Ok(out)
+    }
+
+    fn backward<S: Shape, const N: usize>(
+        &self,
+        idx: &Tensor<S, usize, Self>,
+        weights: &Tensor<S, E, Self>,
+        grad_weights: &mut Self::Vec<E>,
+        grad_out: &Self::Vec<E>,
+    ) -> Result<(), Self::Err> {
+        let mut idx_iter = idx.iter_with_index();
+        while let Some((bin, i)) = idx_iter.next() {
+            grad_weights[index_to_i(&weights.shape, &weights.strides, i)] += grad_out[*bin];
+        }
+        Ok(())
+    }
+}
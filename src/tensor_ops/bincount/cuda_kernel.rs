@@ -0,0 +1,91 @@
+use crate::{
+    shapes::*,
+    tensor::{Cuda, Tensor},
+};
+use cudarc::driver::{CudaSlice, LaunchAsync, LaunchConfig};
+
+const PTX_SRC: &str = include_str!(concat!(env!("OUT_DIR"), "/bincount.ptx"));
+
+pub(crate) trait HasCudaKernel<E> {
+    const MOD: &'static str;
+    const FNS: &'static [&'static str];
+}
+
+impl HasCudaKernel<f32> for Cuda {
+    const MOD: &'static str = "bincount_f32";
+    const FNS: &'static [&'static str] = &["bincount_fwd_f32", "bincount_bwd_f32"];
+}
+
+impl HasCudaKernel<f64> for Cuda {
+    const MOD: &'static str = "bincount_f64";
+    const FNS: &'static [&'static str] = &["bincount_fwd_f64", "bincount_bwd_f64"];
+}
+
+impl<E: Dtype> super::BincountKernel<E> for Cuda
+where
+    Self: HasCudaKernel<E>,
+{
+    fn forward<S: Shape, const N: usize>(
+        &self,
+        idx: &Tensor<S, usize, Self>,
+        weights: &Tensor<S, E, Self>,
+    ) -> Result<Tensor<Rank1<N>, E, Self>, Self::Err> {
+        if !self.dev.has_func(Self::MOD, Self::FNS[0]) {
+            self.dev.load_ptx(PTX_SRC.into(), Self::MOD, Self::FNS)?;
+        }
+
+        let shape: Rank1<N> = Default::default();
+        let mut storage = self.dev.alloc_zeros::<E>(N)?;
+
+        let numel = idx.shape.num_elements();
+        let dims: CudaSlice<usize> = self.dev.htod_copy(idx.shape.concrete().into())?;
+        let idx_strides: CudaSlice<usize> = self.dev.htod_copy(idx.strides.into())?;
+        let weights_strides: CudaSlice<usize> = self.dev.htod_copy(weights.strides.into())?;
+
+        let fwd_fn = self.dev.get_func(Self::MOD, Self::FNS[0]).unwrap();
+        let cfg = LaunchConfig::for_num_elems(numel as u32);
+        let params = (
+            numel,                 // const size_t numel,
+            N,                     // const size_t num_bins,
+            S::NUM_DIMS,           // const size_t num_dims,
+            &dims,                 // const size_t *dims,
+            idx.data.as_ref(),     // const size_t *idx,
+            &idx_strides,          // const size_t *idx_strides,
+            weights.data.as_ref(), // const float *weights,
+            &weights_strides,      // const size_t *weights_strides,
+            &mut storage,          // float *out,
+        );
+        unsafe { fwd_fn.launch(cfg, params) }?;
+        Ok(self.build_tensor(shape, shape.strides(), storage))
+    }
+
+    fn backward<S: Shape, const N: usize>(
+        &self,
+        idx: &Tensor<S, usize, Self>,
+        weights: &Tensor<S, E, Self>,
+        grad_weights: &mut Self::Vec<E>,
+        grad_out: &Self::Vec<E>,
+    ) -> Result<(), Self::Err> {
+        let bwd_fn = self.dev.get_func(Self::MOD, Self::FNS[1]).unwrap();
+        let numel = idx.shape.num_elements();
+
+        let dims: CudaSlice<usize> = self.dev.htod_copy(idx.shape.concrete().into())?;
+        let idx_strides: CudaSlice<usize> = self.dev.htod_copy(idx.strides.into())?;
+        let weights_strides: CudaSlice<usize> = self.dev.htod_copy(weights.strides.into())?;
+
+        let cfg = LaunchConfig::for_num_elems(numel as u32);
+        let params = (
+            numel,             // const size_t numel,
+            N,                 // const size_t num_bins,
+            S::NUM_DIMS,       // const size_t num_dims,
+            &dims,             // const size_t *dims,
+            idx.data.as_ref(), // const size_t *idx,
+            &idx_strides,      // const size_t *idx_strides,
+            grad_weights,      // float *grad_weights,
+            &weights_strides,  // const size_t *weights_strides,
+            grad_out,          // const float *grad_out,
+        );
+        unsafe { bwd_fn.launch(cfg, params) }?;
+        Ok(())
+    }
+}
@@ -0,0 +1,146 @@
+mod cpu_kernel;
+
+#[cfg(feature = "cuda")]
+mod cuda_kernel;
+
+use crate::{gradients::Tape, shapes::*, tensor::*};
+
+pub trait BincountKernel<E: Dtype>: DeviceStorage {
+    fn forward<S: Shape, const N: usize>(
+        &self,
+        idx: &Tensor<S, usize, Self>,
+        weights: &Tensor<S, E, Self>,
+    ) -> Result<Tensor<Rank1<N>, E, Self>, Self::Err>;
+    fn backward<S: Shape, const N: usize>(
+        &self,
+        idx: &Tensor<S, usize, Self>,
+        weights: &Tensor<S, E, Self>,
+        grad_weights: &mut Self::Vec<E>,
+        grad_out: &Self::Vec<E>,
+    ) -> Result<(), Self::Err>;
+}
+
+/// Sums `weights` into `N` bins, where the bin of each element is given by the
+/// integer tensor `self`. Every value in `self` must be less than `N`.
+///
+/// The gradient of each weight is the gradient of the bin it was counted in.
+///
+/// **Pytorch equivalent**: `torch.bincount(idx, weights, minlength=N)`
+pub trait Bincount<E: Dtype, D: DeviceStorage, T>: HasShape + HasErr {
+    /// Weighted count of each value:
+    /// ```rust
+    /// # use dfdx::prelude::*;
+    /// # let dev: Cpu = Default::default();
+    /// let idx: Tensor<Rank1<5>, usize, _> = dev.tensor([0, 2, 2, 3, 2]);
+    /// let w: Tensor<Rank1<5>, f32, _> = dev.tensor([1.0, 0.5, 0.5, 2.0, 1.0]);
+    /// let r: Tensor<Rank1<4>, f32, _> = idx.bincount::<4>(w);
+    /// assert_eq!(r.array(), [1.0, 0.0, 2.0, 2.0]);
+    /// ```
+    fn bincount<const N: usize>(
+        self,
+        weights: Tensor<Self::Shape, E, D, T>,
+    ) -> Tensor<Rank1<N>, E, D, T> {
+        self.try_bincount(weights).unwrap()
+    }
+
+    /// Fallible version of [Bincount::bincount]
+    fn try_bincount<const N: usize>(
+        self,
+        weights: Tensor<Self::Shape, E, D, T>,
+    ) -> Result<Tensor<Rank1<N>, E, D, T>, Self::Err>;
+}
+
+impl<S: Shape, E: Dtype, D: BincountKernel<E>, T: Tape<E, D>> Bincount<E, D, T>
+    for Tensor<S, usize, D>
+{
+    fn try_bincount<const N: usize>(
+        self,
+        weights: Tensor<S, E, D, T>,
+    ) -> Result<Tensor<Rank1<N>, E, D, T>, Self::Err> {
+        assert_eq!(self.shape(), weights.shape());
+        let (weights, mut tape) = weights.split_tape();
+        let out = weights.device.forward::<S, N>(&self, &weights)?;
+        let phantom_out = out.clone();
+        tape.try_alloc_grad(&weights)?;
+        tape.try_alloc_grad(&out)?;
+        tape.add_reusable_backward_op(move |grads| {
+            let (grad_weights, grad_out) = grads.mut_and_ref(&weights, &phantom_out);
+            weights
+                .device
+                .backward::<S, N>(&self, &weights, grad_weights, grad_out)
+        });
+        Ok(out.put_tape(tape))
+    }
+}
+
+impl<S: Shape, D: DeviceStorage> Tensor<S, usize, D> {
+    /// Counts the occurrences of each value in `self`, which must all be less than `N`.
+    /// This is [Bincount::bincount] with all weights equal to one.
+    ///
+    /// **Pytorch equivalent**: `torch.bincount(idx, minlength=N)`
+    ///
+    /// Example:
+    /// ```rust
+    /// # use dfdx::prelude::*;
+    /// # let dev: Cpu = Default::default();
+    /// let idx: Tensor<Rank2<2, 3>, usize, _> = dev.tensor([[1, 1, 0], [3, 1, 0]]);
+    /// let r: Tensor<Rank1<5>, f32, _> = idx.bincount_unweighted::<5, f32>();
+    /// assert_eq!(r.array(), [2.0, 3.0, 0.0, 1.0, 0.0]);
+    /// ```
+    pub fn bincount_unweighted<const N: usize, E: Dtype>(self) -> Tensor<Rank1<N>, E, D>
+    where
+        D: BincountKernel<E> + OnesTensor<E>,
+    {
+        self.try_bincount_unweighted().unwrap()
+    }
+
+    /// Fallible version of [Tensor::bincount_unweighted]
+    pub fn try_bincount_unweighted<const N: usize, E: Dtype>(
+        self,
+    ) -> Result<Tensor<Rank1<N>, E, D>, D::Err>
+    where
+        D: BincountKernel<E> + OnesTensor<E>,
+    {
+        let weights = self.device.try_ones_like(self.shape())?;
+        self.try_bincount(weights)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::tensor_ops::*;
+    use crate::tests::*;
+
+    #[test]
+    fn test_bincount_unweighted() {
+        let dev: TestDevice = Default::default();
+        let idx = dev.tensor([[0, 4, 4], [1, 4, 0]]);
+        let r = idx.clone().bincount_unweighted::<6, TestDtype>();
+        assert_eq!(r.array(), [2.0, 1.0, 0.0, 0.0, 3.0, 0.0]);
+
+        let r = idx
+            .permute::<_, Axes2<1, 0>>()
+            .bincount_unweighted::<5, TestDtype>();
+        assert_eq!(r.array(), [2.0, 1.0, 0.0, 0.0, 3.0]);
+    }
+
+    #[test]
+    fn test_bincount_weighted_backward() {
+        let dev: TestDevice = Default::default();
+        let idx = dev.tensor([2, 0, 2, 1, 2]);
+        let w: Tensor<_, TestDtype, _> = dev.tensor([0.5, 1.0, -1.0, 2.0, 3.0]);
+        let r = idx.bincount::<3>(w.trace());
+        assert_eq!(r.array(), [1.0, 2.0, 2.5]);
+        let g = (r * dev.tensor([1.0, 2.0, 3.0])).sum().backward();
+        assert_eq!(g.get(&w).array(), [3.0, 1.0, 3.0, 2.0, 3.0]);
+    }
+
+    #[test]
+    #[should_panic]
+    fn test_bincount_out_of_range() {
+        let dev: TestDevice = Default::default();
+        let idx = dev.tensor([0, 3]);
+        let _ = idx.bincount_unweighted::<3, TestDtype>();
+    }
+}
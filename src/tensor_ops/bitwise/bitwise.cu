@@ -0,0 +1,73 @@
+#include "cuda_utils.cuh"
+
+// shifts by a negative amount or by at least the bit width are out of range. Left shifts
+// give 0, and right shifts fill with the sign bit
+template<typename T>
+__device__ bool shift_out_of_range(T y) {
+    return y < 0 || y >= (T)(sizeof(T) * 8);
+}
+
+template<typename T>
+__device__ T shl_op(T x, T y) {
+    return shift_out_of_range(y) ? (T)0 : (T)(x << y);
+}
+
+template<typename T>
+__device__ T shr_op(T x, T y) {
+    if (shift_out_of_range(y)) {
+        return x < 0 ? (T)-1 : (T)0;
+    }
+    return x >> y;
+}
+
+#define BITWISE_OP(TYPENAME, FWD, EXPR) \
+extern "C" __global__ void FWD( \
+    const size_t numel, \
+    const size_t num_dims, \
+    const size_t *dims, \
+    const TYPENAME *lhs, \
+    const size_t *lhs_strides, \
+    const TYPENAME *rhs, \
+    const size_t *rhs_strides, \
+    TYPENAME *out \
+) { \
+    unsigned int i = blockIdx.x * blockDim.x + threadIdx.x; \
+    if (i >= numel) { \
+        return; \
+    } \
+    unsigned int lhs_i = get_strided_index(i, num_dims, dims, lhs_strides); \
+    unsigned int rhs_i = get_strided_index(i, num_dims, dims, rhs_strides); \
+    TYPENAME x = lhs[lhs_i]; \
+    TYPENAME y = rhs[rhs_i]; \
+    out[i] = EXPR; \
+}
+
+BITWISE_OP(int, bitand_fwd_i32, x & y)
+BITWISE_OP(int, bitor_fwd_i32, x | y)
+BITWISE_OP(int, bitxor_fwd_i32, x ^ y)
+BITWISE_OP(int, shl_fwd_i32, shl_op(x, y))
+BITWISE_OP(int, shr_fwd_i32, shr_op(x, y))
+
+BITWISE_OP(long long, bitand_fwd_i64, x & y)
+BITWISE_OP(long long, bitor_fwd_i64, x | y)
+BITWISE_OP(long long, bitxor_fwd_i64, x ^ y)
+BITWISE_OP(long long, shl_fwd_i64, shl_op(x, y))
+BITWISE_OP(long long, shr_fwd_i64, shr_op(x, y))
+
+BITWISE_OP(unsigned char, bitand_fwd_u8, x & y)
+BITWISE_OP(unsigned char, bitor_fwd_u8, x | y)
+BITWISE_OP(unsigned char, bitxor_fwd_u8, x ^ y)
+BITWISE_OP(unsigned char, shl_fwd_u8, shl_op(x, y))
+BITWISE_OP(unsigned char, shr_fwd_u8, shr_op(x, y))
+
+BITWISE_OP(unsigned int, bitand_fwd_u32, x & y)
+BITWISE_OP(unsigned int, bitor_fwd_u32, x | y)
+BITWISE_OP(unsigned int, bitxor_fwd_u32, x ^ y)
+BITWISE_OP(unsigned int, shl_fwd_u32, shl_op(x, y))
+BITWISE_OP(unsigned int, shr_fwd_u32, shr_op(x, y))
+
+BITWISE_OP(unsigned long long, bitand_fwd_u64, x & y)
+BITWISE_OP(unsigned long long, bitor_fwd_u64, x | y)
+BITWISE_OP(unsigned long long, bitxor_fwd_u64, x ^ y)
+BITWISE_OP(unsigned long long, shl_fwd_u64, shl_op(x, y))
+BITWISE_OP(unsigned long long, shr_fwd_u64, shr_op(x, y))
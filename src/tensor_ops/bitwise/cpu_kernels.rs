@@ -0,0 +1,77 @@
+use crate::{
+    shapes::{Shape, Unit},
+    tensor::{
+        cpu::{Cpu, LendingIterator},
+        Tensor, ZerosTensor,
+    },
+};
+
+use num_traits::PrimInt;
+
+use super::{
+    BitAndKernelOp, BitOrKernelOp, BitXorKernelOp, BitwiseKernel, ShlKernelOp, ShrKernelOp,
+};
+
+trait BitwiseOpCpuKernel<E: Unit> {
+    fn func(lhs: E, rhs: E) -> E;
+}
+
+impl<Op: BitwiseOpCpuKernel<E>, E: Unit> BitwiseKernel<Op, E> for Cpu {
+    fn forward<S: Shape, T>(
+        &self,
+        lhs: &Tensor<S, E, Self, T>,
+        rhs: &Tensor<S, E, Self, T>,
+    ) -> Result<Tensor<S, E, Self>, Self::Err> {
+        let mut out: Tensor<S, E, Self> = self.try_zeros_like(&lhs.shape)?;
+        let mut lhs_iter = lhs.iter();
+        let mut rhs_iter = rhs.iter();
+        let mut out_iter = out.iter_mut();
+        while let Some((o, (l, r))) = out_iter.next().zip(lhs_iter.next().zip(rhs_iter.next())) {
+            *o = Op::func(*l, *r);
+        }
+        Ok(out)
+    }
+}
+
+impl<E: Unit + std::ops::BitAnd<Output = E>> BitwiseOpCpuKernel<E> for BitAndKernelOp {
+    fn func(lhs: E, rhs: E) -> E {
+        lhs & rhs
+    }
+}
+
+impl<E: Unit + std::ops::BitOr<Output = E>> BitwiseOpCpuKernel<E> for BitOrKernelOp {
+    fn func(lhs: E, rhs: E) -> E {
+        lhs | rhs
+    }
+}
+
+impl<E: Unit + std::ops::BitXor<Output = E>> BitwiseOpCpuKernel<E> for BitXorKernelOp {
+    fn func(lhs: E, rhs: E) -> E {
+        lhs ^ rhs
+    }
+}
+
+/// Returns the shift amount if it is in `0..bits`, where `bits` is the bit width of `E`.
+fn shift_amount<E: PrimInt>(rhs: E) -> Option<usize> {
+    rhs.to_usize().filter(|&n| n < 8 * std::mem::size_of::<E>())
+}
+
+impl<E: Unit + PrimInt> BitwiseOpCpuKernel<E> for ShlKernelOp {
+    fn func(lhs: E, rhs: E) -> E {
+        match shift_amount(rhs) {
+            Some(n) => lhs << n,
+            None => E::zero(),
+        }
+    }
+}
+
+impl<E: Unit + PrimInt> BitwiseOpCpuKernel<E> for ShrKernelOp {
+    fn func(lhs: E, rhs: E) -> E {
+        match shift_amount(rhs) {
+            Some(n) => lhs >> n,
+            // all the bits are shifted out, leaving only the sign bit
+            None if lhs < E::zero() => !E::zero(),
+            None => E::zero(),
+        }
+    }
+}
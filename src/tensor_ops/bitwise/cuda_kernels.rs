@@ -0,0 +1,100 @@
+use crate::{
+    shapes::{Shape, Unit},
+    tensor::{Cuda, Tensor},
+};
+use cudarc::driver::{CudaSlice, LaunchAsync, LaunchConfig};
+
+use super::{
+    BitAndKernelOp, BitOrKernelOp, BitXorKernelOp, BitwiseKernel, ShlKernelOp, ShrKernelOp,
+};
+
+const PTX_SRC: &str = include_str!(concat!(env!("OUT_DIR"), "/bitwise.ptx"));
+
+trait BitwiseOpCudaKernel<E: Unit> {
+    /// Compiled by build.rs
+    const PTX_SRC: &'static str;
+
+    /// Unique name for the kernel
+    const MODULE_NAME: &'static str;
+
+    /// Name of function in the .cu file
+    const FWD_FN_NAME: &'static str;
+}
+
+impl<E: Unit, Op: BitwiseOpCudaKernel<E>> BitwiseKernel<Op, E> for Cuda {
+    fn forward<S: Shape, T>(
+        &self,
+        lhs: &Tensor<S, E, Self, T>,
+        rhs: &Tensor<S, E, Self, T>,
+    ) -> Result<Tensor<S, E, Self>, Self::Err> {
+        if !self.dev.has_func(Op::MODULE_NAME, Op::FWD_FN_NAME) {
+            self.dev
+                .load_ptx(Op::PTX_SRC.into(), Op::MODULE_NAME, &[Op::FWD_FN_NAME])?;
+        }
+
+        let shape = lhs.shape;
+        let strides = lhs.shape.strides();
+        let numel = shape.num_elements();
+
+        let mut storage = self.dev.alloc_zeros::<E>(numel)?;
+
+        let dims: CudaSlice<usize> = self.dev.htod_copy(shape.concrete().into())?;
+        let lhs_strides: CudaSlice<usize> = self.dev.htod_copy(lhs.strides.into())?;
+        let rhs_strides: CudaSlice<usize> = self.dev.htod_copy(rhs.strides.into())?;
+
+        let fwd_fn = self.dev.get_func(Op::MODULE_NAME, Op::FWD_FN_NAME).unwrap();
+        let cfg = LaunchConfig::for_num_elems(numel as u32);
+        let params = (
+            numel,             // const size_t numel,
+            S::NUM_DIMS,       // const size_t num_dims,
+            &dims,             // const size_t *dims,
+            lhs.data.as_ref(), // const T *lhs,
+            &lhs_strides,      // const size_t *lhs_strides,
+            rhs.data.as_ref(), // const T *rhs,
+            &rhs_strides,      // const size_t *rhs_strides,
+            &mut storage,      // T *out,
+        );
+        unsafe { fwd_fn.launch(cfg, params) }?;
+        Ok(self.build_tensor(shape, strides, storage))
+    }
+}
+
+macro_rules! bitwise {
+    ($Op:ty, $TypeName:ty, $Fwd:tt) => {
+        impl BitwiseOpCudaKernel<$TypeName> for $Op {
+            const PTX_SRC: &'static str = PTX_SRC;
+            const MODULE_NAME: &'static str = $Fwd;
+            const FWD_FN_NAME: &'static str = $Fwd;
+        }
+    };
+}
+
+bitwise!(BitAndKernelOp, i32, "bitand_fwd_i32");
+bitwise!(BitOrKernelOp, i32, "bitor_fwd_i32");
+bitwise!(BitXorKernelOp, i32, "bitxor_fwd_i32");
+bitwise!(ShlKernelOp, i32, "shl_fwd_i32");
+bitwise!(ShrKernelOp, i32, "shr_fwd_i32");
+
+bitwise!(BitAndKernelOp, i64, "bitand_fwd_i64");
+bitwise!(BitOrKernelOp, i64, "bitor_fwd_i64");
+bitwise!(BitXorKernelOp, i64, "bitxor_fwd_i64");
+bitwise!(ShlKernelOp, i64, "shl_fwd_i64");
+bitwise!(ShrKernelOp, i64, "shr_fwd_i64");
+
+bitwise!(BitAndKernelOp, u8, "bitand_fwd_u8");
+bitwise!(BitOrKernelOp, u8, "bitor_fwd_u8");
+bitwise!(BitXorKernelOp, u8, "bitxor_fwd_u8");
+bitwise!(ShlKernelOp, u8, "shl_fwd_u8");
+bitwise!(ShrKernelOp, u8, "shr_fwd_u8");
+
+bitwise!(BitAndKernelOp, u32, "bitand_fwd_u32");
+bitwise!(BitOrKernelOp, u32, "bitor_fwd_u32");
+bitwise!(BitXorKernelOp, u32, "bitxor_fwd_u32");
+bitwise!(ShlKernelOp, u32, "shl_fwd_u32");
+bitwise!(ShrKernelOp, u32, "shr_fwd_u32");
+
+bitwise!(BitAndKernelOp, u64, "bitand_fwd_u64");
+bitwise!(BitOrKernelOp, u64, "bitor_fwd_u64");
+bitwise!(BitXorKernelOp, u64, "bitxor_fwd_u64");
+bitwise!(ShlKernelOp, u64, "shl_fwd_u64");
+bitwise!(ShrKernelOp, u64, "shr_fwd_u64");
@@ -0,0 +1,215 @@
+use crate::{
+    gradients::{NoneTape, Tape},
+    shapes::{HasShape, Shape, Unit},
+    tensor::{DeviceStorage, Tensor},
+};
+
+mod cpu_kernels;
+#[cfg(feature = "cuda")]
+mod cuda_kernels;
+
+pub trait BitwiseKernel<Op, E: Unit>: DeviceStorage {
+    fn forward<S: Shape, T>(
+        &self,
+        lhs: &Tensor<S, E, Self, T>,
+        rhs: &Tensor<S, E, Self, T>,
+    ) -> Result<Tensor<S, E, Self>, Self::Err>;
+}
+
+fn try_bitwise_op<Op, S: Shape, E: Unit, D: BitwiseKernel<Op, E>, T: Tape<E, D>>(
+    lhs: &Tensor<S, E, D, T>,
+    rhs: &Tensor<S, E, D, T>,
+) -> Result<Tensor<S, E, D, NoneTape>, D::Err> {
+    assert_eq!(lhs.shape(), rhs.shape());
+    lhs.device.forward(lhs, rhs)
+}
+
+pub enum BitAndKernelOp {}
+pub enum BitOrKernelOp {}
+pub enum BitXorKernelOp {}
+pub enum ShlKernelOp {}
+pub enum ShrKernelOp {}
+
+/// Element-wise bitwise and of integer tensors. `&`
+///
+/// Examples:
+/// ```
+/// # use dfdx::prelude::*;
+/// # let dev: Cpu = Default::default();
+/// let a = dev.tensor([0b1100, 0b1010, -1]);
+/// let b = dev.tensor([0b1010, 0b0110, 7]);
+/// let r = a.bitwise_and(&b);
+/// assert_eq!(r.array(), [0b1000, 0b0010, 7]);
+/// ```
+pub fn bitand<S: Shape, E: Unit, D: BitwiseKernel<BitAndKernelOp, E>, T: Tape<E, D>>(
+    lhs: &Tensor<S, E, D, T>,
+    rhs: &Tensor<S, E, D, T>,
+) -> Tensor<S, E, D, NoneTape> {
+    lhs.bitwise_and(rhs)
+}
+
+/// Element-wise bitwise or of integer tensors. `|`
+///
+/// Examples:
+/// ```
+/// # use dfdx::prelude::*;
+/// # let dev: Cpu = Default::default();
+/// let a = dev.tensor([0b1100, 0b1010, -8]);
+/// let b = dev.tensor([0b1010, 0b0110, 7]);
+/// let r = a.bitwise_or(&b);
+/// assert_eq!(r.array(), [0b1110, 0b1110, -1]);
+/// ```
+pub fn bitor<S: Shape, E: Unit, D: BitwiseKernel<BitOrKernelOp, E>, T: Tape<E, D>>(
+    lhs: &Tensor<S, E, D, T>,
+    rhs: &Tensor<S, E, D, T>,
+) -> Tensor<S, E, D, NoneTape> {
+    lhs.bitwise_or(rhs)
+}
+
+/// Element-wise bitwise xor of integer tensors. `^`
+///
+/// Examples:
+/// ```
+/// # use dfdx::prelude::*;
+/// # let dev: Cpu = Default::default();
+/// let a = dev.tensor([0b1100, 0b1010, -1]);
+/// let b = dev.tensor([0b1010, 0b0110, 7]);
+/// let r = a.bitwise_xor(&b);
+/// assert_eq!(r.array(), [0b0110, 0b1100, -8]);
+/// ```
+pub fn bitxor<S: Shape, E: Unit, D: BitwiseKernel<BitXorKernelOp, E>, T: Tape<E, D>>(
+    lhs: &Tensor<S, E, D, T>,
+    rhs: &Tensor<S, E, D, T>,
+) -> Tensor<S, E, D, NoneTape> {
+    lhs.bitwise_xor(rhs)
+}
+
+/// Element-wise left shift of integer tensors. `<<`
+///
+/// Shifting by a negative amount, or by at least the number of bits in `E`, gives 0.
+///
+/// Examples:
+/// ```
+/// # use dfdx::prelude::*;
+/// # let dev: Cpu = Default::default();
+/// let a = dev.tensor([1, 3, -2]);
+/// let b = dev.tensor([4, 1, 2]);
+/// let r = a.bitwise_left_shift(&b);
+/// assert_eq!(r.array(), [16, 6, -8]);
+/// ```
+pub fn shl<S: Shape, E: Unit, D: BitwiseKernel<ShlKernelOp, E>, T: Tape<E, D>>(
+    lhs: &Tensor<S, E, D, T>,
+    rhs: &Tensor<S, E, D, T>,
+) -> Tensor<S, E, D, NoneTape> {
+    lhs.bitwise_left_shift(rhs)
+}
+
+/// Element-wise right shift of integer tensors. `>>`
+///
+/// The shift is arithmetic for signed integers, so the sign bit is preserved.
+///
+/// Shifting by a negative amount, or by at least the number of bits in `E`, shifts out
+/// every bit, so it gives 0, or -1 for negative signed integers.
+///
+/// Examples:
+/// ```
+/// # use dfdx::prelude::*;
+/// # let dev: Cpu = Default::default();
+/// let a = dev.tensor([16, 7, -8]);
+/// let b = dev.tensor([4, 1, 2]);
+/// let r = a.bitwise_right_shift(&b);
+/// assert_eq!(r.array(), [1, 3, -2]);
+/// ```
+pub fn shr<S: Shape, E: Unit, D: BitwiseKernel<ShrKernelOp, E>, T: Tape<E, D>>(
+    lhs: &Tensor<S, E, D, T>,
+    rhs: &Tensor<S, E, D, T>,
+) -> Tensor<S, E, D, NoneTape> {
+    lhs.bitwise_right_shift(rhs)
+}
+
+// Macro to reduce boilerplate of implementing bitwise methods on Tensor.
+macro_rules! impl_bitwise_method {
+    ($TraitName:tt, $FnName:tt, $TryFnName:tt, $DocFn:tt) => {
+        impl<S: Shape, E: Unit, D: BitwiseKernel<$TraitName, E>, T: Tape<E, D>> Tensor<S, E, D, T> {
+            #[doc = concat!("See [", stringify!($DocFn), "]")]
+            pub fn $FnName(&self, other: &Self) -> Tensor<S, E, D, NoneTape> {
+                self.$TryFnName(other).unwrap()
+            }
+
+            #[doc = concat!("See [", stringify!($DocFn), "]")]
+            pub fn $TryFnName(&self, other: &Self) -> Result<Tensor<S, E, D, NoneTape>, D::Err> {
+                try_bitwise_op(self, other)
+            }
+        }
+    };
+}
+
+impl_bitwise_method!(BitAndKernelOp, bitwise_and, try_bitwise_and, bitand);
+impl_bitwise_method!(BitOrKernelOp, bitwise_or, try_bitwise_or, bitor);
+impl_bitwise_method!(BitXorKernelOp, bitwise_xor, try_bitwise_xor, bitxor);
+impl_bitwise_method!(ShlKernelOp, bitwise_left_shift, try_bitwise_left_shift, shl);
+impl_bitwise_method!(
+    ShrKernelOp,
+    bitwise_right_shift,
+    try_bitwise_right_shift,
+    shr
+);
+
+#[cfg(test)]
+mod tests {
+    use crate::{tensor::*, tensor_ops::*, tests::*};
+
+    const LHS: [i32; 6] = [0, 1, -1, 0x5a5a, -12345, i32::MAX];
+    const RHS: [i32; 6] = [3, 0x0f0f, 6, -0x3c3c, 31, 1];
+    const SHIFTS: [i32; 6] = [0, 1, 5, 7, 3, 30];
+
+    fn scalar(lhs: [i32; 6], rhs: [i32; 6], f: impl Fn(i32, i32) -> i32) -> [i32; 6] {
+        std::array::from_fn(|i| f(lhs[i], rhs[i]))
+    }
+
+    #[test]
+    fn test_bitwise_and_or_xor() {
+        let dev: TestDevice = Default::default();
+        let a = dev.tensor(LHS);
+        let b = dev.tensor(RHS);
+
+        assert_eq!(bitand(&a, &b).array(), scalar(LHS, RHS, |l, r| l & r));
+        assert_eq!(bitor(&a, &b).array(), scalar(LHS, RHS, |l, r| l | r));
+        assert_eq!(bitxor(&a, &b).array(), scalar(LHS, RHS, |l, r| l ^ r));
+    }
+
+    #[test]
+    fn test_bitwise_shifts() {
+        let dev: TestDevice = Default::default();
+        let a = dev.tensor(LHS);
+        let s = dev.tensor(SHIFTS);
+
+        assert_eq!(shl(&a, &s).array(), scalar(LHS, SHIFTS, |l, r| l << r));
+        assert_eq!(shr(&a, &s).array(), scalar(LHS, SHIFTS, |l, r| l >> r));
+    }
+
+    #[test]
+    fn test_bitwise_shifts_out_of_range() {
+        let dev: TestDevice = Default::default();
+        let a = dev.tensor([1, 1, 8, -8, 8]);
+        let s = dev.tensor([1, 32, -1, 40, i32::MIN]);
+        assert_eq!(shl(&a, &s).array(), [2, 0, 0, 0, 0]);
+        assert_eq!(shr(&a, &s).array(), [0, 0, 0, -1, 0]);
+
+        let a = dev.tensor([0xffu8, 0x80]);
+        let s = dev.tensor([8u8, 255]);
+        assert_eq!(shl(&a, &s).array(), [0; 2]);
+        assert_eq!(shr(&a, &s).array(), [0; 2]);
+    }
+
+    #[test]
+    fn test_bitwise_2d() {
+        let dev: TestDevice = Default::default();
+        let a = dev.tensor([[0b1100u8, 0b1010], [0xff, 0x0f]]);
+        let b = dev.tensor([[0b1010u8, 0b0110], [0x0f, 0xf0]]);
+
+        assert_eq!(a.bitwise_and(&b).array(), [[0b1000, 0b0010], [0x0f, 0x00]]);
+        assert_eq!(a.bitwise_or(&b).array(), [[0b1110, 0b1110], [0xff, 0xff]]);
+        assert_eq!(a.bitwise_xor(&b).array(), [[0b0110, 0b1100], [0xf0, 0xff]]);
+    }
+}
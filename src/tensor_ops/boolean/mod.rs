@@ -11,8 +11,6 @@ use crate::{
 
 use std::ops::{BitAnd, BitOr, BitXor, Not};
 
-use super::Device;
-
 pub trait BooleanKernel: DeviceStorage + OnesTensor<bool> + ZerosTensor<bool> {
     fn not<S: Shape>(
         &self,
@@ -143,7 +141,7 @@ boolean_op_impl!(BitXor, bitxor, xor, scalar_xor);
 /// assert_eq!(r1.array(), [true, false, true]);
 /// assert_eq!(r2.array(), [true, false, true]);
 /// ```
-pub fn bool_not<S: Shape, E: Dtype, D: Device<E>>(inp: &Tensor<S, bool, D>) -> Tensor<S, bool, D> {
+pub fn bool_not<S: Shape, D: BooleanKernel>(inp: &Tensor<S, bool, D>) -> Tensor<S, bool, D> {
     !inp
 }
 
@@ -175,7 +173,7 @@ pub fn bool_not<S: Shape, E: Dtype, D: Device<E>>(inp: &Tensor<S, bool, D>) -> T
 /// assert_eq!(r1.array(), a.array());
 /// assert_eq!(r2.array(), [false; 3]);
 /// ```
-pub fn bool_and<S: Shape, E: Dtype, D: Device<E>>(
+pub fn bool_and<S: Shape, D: BooleanKernel>(
     lhs: &Tensor<S, bool, D>,
     rhs: &Tensor<S, bool, D>,
 ) -> Tensor<S, bool, D> {
@@ -210,7 +208,7 @@ pub fn bool_and<S: Shape, E: Dtype, D: Device<E>>(
 /// assert_eq!(r1.array(), [true; 3]);
 /// assert_eq!(r2.array(), a.array());
 /// ```
-pub fn bool_or<S: Shape, E: Dtype, D: Device<E>>(
+pub fn bool_or<S: Shape, D: BooleanKernel>(
     lhs: &Tensor<S, bool, D>,
     rhs: &Tensor<S, bool, D>,
 ) -> Tensor<S, bool, D> {
@@ -245,16 +243,54 @@ pub fn bool_or<S: Shape, E: Dtype, D: Device<E>>(
 /// assert_eq!(r1.array(), (!&a).array());
 /// assert_eq!(r2.array(), a.array());
 /// ```
-pub fn bool_xor<S: Shape, E: Dtype, D: Device<E>>(
+pub fn bool_xor<S: Shape, D: BooleanKernel>(
     lhs: &Tensor<S, bool, D>,
     rhs: &Tensor<S, bool, D>,
 ) -> Tensor<S, bool, D> {
     lhs ^ rhs
 }
 
+impl<S: Shape, D: BooleanKernel> Tensor<S, bool, D> {
+    /// See [bool_not]
+    pub fn logical_not(&self) -> Self {
+        self.try_logical_not().unwrap()
+    }
+    /// See [bool_not]
+    pub fn try_logical_not(&self) -> Result<Self, D::Err> {
+        self.device.not(self)
+    }
+    /// See [bool_and]
+    pub fn logical_and(&self, rhs: &Self) -> Self {
+        self.try_logical_and(rhs).unwrap()
+    }
+    /// See [bool_and]
+    pub fn try_logical_and(&self, rhs: &Self) -> Result<Self, D::Err> {
+        assert_eq!(self.shape(), rhs.shape());
+        self.device.and(self, rhs)
+    }
+    /// See [bool_or]
+    pub fn logical_or(&self, rhs: &Self) -> Self {
+        self.try_logical_or(rhs).unwrap()
+    }
+    /// See [bool_or]
+    pub fn try_logical_or(&self, rhs: &Self) -> Result<Self, D::Err> {
+        assert_eq!(self.shape(), rhs.shape());
+        self.device.or(self, rhs)
+    }
+    /// See [bool_xor]
+    pub fn logical_xor(&self, rhs: &Self) -> Self {
+        self.try_logical_xor(rhs).unwrap()
+    }
+    /// See [bool_xor]
+    pub fn try_logical_xor(&self, rhs: &Self) -> Result<Self, D::Err> {
+        assert_eq!(self.shape(), rhs.shape());
+        self.device.xor(self, rhs)
+    }
+}
+
 #[cfg(test)]
 mod tests {
-    use crate::{tensor::*, tests::*};
+    use crate::{tensor::*, tensor_ops::*, tests::*};
 
     const TRUTH_TABLE_1: [bool; 4] = [false, false, true, true];
     const TRUTH_TABLE_2: [bool; 4] = [false, true, false, true];
@@ -282,7 +318,7 @@ mod tests {
         let r3 = &a & false;
         assert_eq!(r1.array(), [[false, false, false, true]; 2]);
         assert_eq!(r2.array(), a.array());
-        assert_eq!(r3.array(), dev.zeros_like(&a).array());
+        assert_eq!(r3.array(), [[false; 4]; 2]);
     }
 
     #[test]
@@ -295,7 +331,7 @@ mod tests {
         let r2 = &a | true;
         let r3 = &a | false;
         assert_eq!(r1.array(), [[false, true, true, true]; 2]);
-        assert_eq!(r2.array(), dev.ones_like(&a).array());
+        assert_eq!(r2.array(), [[true; 4]; 2]);
         assert_eq!(r3.array(), a.array());
     }
 
@@ -312,4 +348,45 @@ mod tests {
         assert_eq!(r2.array(), (!&a).array());
         assert_eq!(r3.array(), a.array());
     }
+
+    #[test]
+    fn test_logical_ops() {
+        let dev: TestDevice = Default::default();
+        let a = dev.tensor(TRUTH_TABLE_1);
+        let b = dev.tensor(TRUTH_TABLE_2);
+
+        assert_eq!(bool_not(&a).array(), [true, true, false, false]);
+        assert_eq!(bool_and(&a, &b).array(), [false, false, false, true]);
+        assert_eq!(bool_or(&a, &b).array(), [false, true, true, true]);
+        assert_eq!(bool_xor(&a, &b).array(), [false, true, true, false]);
+        assert_eq!(a.logical_not().array(), (!&a).array());
+        assert_eq!(a.logical_and(&b).array(), (&a & &b).array());
+        assert_eq!(a.logical_or(&b).array(), (&a | &b).array());
+        assert_eq!(a.logical_xor(&b).array(), (&a ^ &b).array());
+    }
+
+    #[test]
+    fn test_logical_ops_combine_masks() {
+        let dev: TestDevice = Default::default();
+        let t: Tensor<_, TestDtype, _> = dev.tensor([-2.0, -1.0, 0.0, 1.0, 2.0]);
+        let pos = t.scalar_gt(0.0);
+        let small = t.abs().scalar_le(1.0);
+
+        assert_eq!(
+            pos.logical_and(&small).array(),
+            [false, false, false, true, false]
+        );
+        assert_eq!(
+            pos.logical_or(&small).array(),
+            [false, true, true, true, true]
+        );
+        assert_eq!(
+            pos.logical_xor(&small).array(),
+            [false, true, true, false, true]
+        );
+        assert_eq!(
+            pos.logical_not().logical_and(&small.logical_not()).array(),
+            [true, false, false, false, false]
+        );
+    }
 }
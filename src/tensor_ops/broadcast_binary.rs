@@ -0,0 +1,276 @@
+use super::{
+    add::BinaryAddKernelOp,
+    broadcast_to::BroadcastKernel,
+    div::BinaryDivKernelOp,
+    maximum::MaximumKernelOp,
+    minimum::MinimumKernelOp,
+    mul::BinaryMulKernelOp,
+    ops::{try_broadcast_binary_op, BinaryKernel},
+    pow::BinaryPowKernelOp,
+    reshape_to::ReshapeKernel,
+    sub::BinarySubKernelOp,
+};
+use crate::{gradients::*, shapes::*, tensor::Tensor};
+
+macro_rules! broadcast_binary {
+    ($Op:ty, $Fn:ident, $TryFn:ident, $Desc:literal) => {
+        impl<S: Shape, E: Dtype, D, LhsTape: Tape<E, D>> Tensor<S, E, D, LhsTape>
+        where
+            D: BinaryKernel<$Op, E> + BroadcastKernel<E> + ReshapeKernel<E>,
+        {
+            #[doc = concat!("Element wise ", $Desc, " of `self` and a smaller `rhs`, that is")]
+            /// broadcasted to the shape of `self` like numpy does. See
+            /// [Tensor::broadcast_add] for details.
+            pub fn $Fn<Small: Shape, Ax: Axes, R: Tape<E, D>>(
+                self,
+                rhs: Tensor<Small, E, D, R>,
+            ) -> Self
+            where
+                S: BroadcastShapeTrailing<Small, Ax>,
+                LhsTape: Merge<R>,
+            {
+                self.$TryFn(rhs).unwrap()
+            }
+
+            #[doc = concat!("Fallible version of [Tensor::", stringify!($Fn), "]")]
+            pub fn $TryFn<Small: Shape, Ax: Axes, R: Tape<E, D>>(
+                self,
+                rhs: Tensor<Small, E, D, R>,
+            ) -> Result<Self, D::Err>
+            where
+                S: BroadcastShapeTrailing<Small, Ax>,
+                LhsTape: Merge<R>,
+            {
+                try_broadcast_binary_op(<$Op>::default(), self, rhs)
+            }
+        }
+    };
+}
+
+impl<S: Shape, E: Dtype, D, LhsTape: Tape<E, D>> Tensor<S, E, D, LhsTape>
+where
+    D: BinaryKernel<BinaryAddKernelOp, E> + BroadcastKernel<E> + ReshapeKernel<E>,
+{
+    /// Element wise addition of `self` and a smaller `rhs`, which is broadcasted
+    /// to the shape of `self` like numpy does: `rhs` is lined up with the trailing
+    /// dimensions of `self`, and is expanded along the leading axes it is missing and
+    /// along its `Const<1>` dimensions. The gradient of `rhs` is summed over the
+    /// expanded axes.
+    ///
+    /// Only `Const<1>` dimensions are expanded, since a `usize` dimension can't be
+    /// known to be 1 at compile time. If the expanded axes are ambiguous (e.g. a `Rank1<1>`
+    /// added to a `Rank2<2, 1>`) they have to be given explicitly as `Ax`.
+    ///
+    /// There is intentionally no `+` overload for this, because a second tensor `Rhs`
+    /// for [std::ops::Add] would make the type of e.g. `a + dev.ones()` ambiguous.
+    ///
+    /// Example:
+    /// ```rust
+    /// # use dfdx::prelude::*;
+    /// # let dev: Cpu = Default::default();
+    /// let a = dev.tensor([[1.0, 2.0, 3.0], [4.0, 5.0, 6.0]]);
+    /// let b = dev.tensor([10.0, 20.0, 30.0]);
+    /// let r = a.broadcast_add(b);
+    /// assert_eq!(r.array(), [[11.0, 22.0, 33.0], [14.0, 25.0, 36.0]]);
+    ///
+    /// let c = dev.tensor([[100.0], [200.0]]);
+    /// let r = r.broadcast_add(c);
+    /// assert_eq!(r.array(), [[111.0, 122.0, 133.0], [214.0, 225.0, 236.0]]);
+    /// ```
+    pub fn broadcast_add<Small: Shape, Ax: Axes, R: Tape<E, D>>(
+        self,
+        rhs: Tensor<Small, E, D, R>,
+    ) -> Self
+    where
+        S: BroadcastShapeTrailing<Small, Ax>,
+        LhsTape: Merge<R>,
+    {
+        self.try_broadcast_add(rhs).unwrap()
+    }
+
+    /// Fallible version of [Tensor::broadcast_add]
+    pub fn try_broadcast_add<Small: Shape, Ax: Axes, R: Tape<E, D>>(
+        self,
+        rhs: Tensor<Small, E, D, R>,
+    ) -> Result<Self, D::Err>
+    where
+        S: BroadcastShapeTrailing<Small, Ax>,
+        LhsTape: Merge<R>,
+    {
+        try_broadcast_binary_op(BinaryAddKernelOp, self, rhs)
+    }
+}
+
+broadcast_binary!(
+    BinarySubKernelOp,
+    broadcast_sub,
+    try_broadcast_sub,
+    "subtraction"
+);
+broadcast_binary!(
+    BinaryMulKernelOp,
+    broadcast_mul,
+    try_broadcast_mul,
+    "multiplication"
+);
+broadcast_binary!(
+    BinaryDivKernelOp,
+    broadcast_div,
+    try_broadcast_div,
+    "division"
+);
+broadcast_binary!(
+    MaximumKernelOp,
+    broadcast_maximum,
+    try_broadcast_maximum,
+    "maximum"
+);
+broadcast_binary!(
+    MinimumKernelOp,
+    broadcast_minimum,
+    try_broadcast_minimum,
+    "minimum"
+);
+broadcast_binary!(
+    BinaryPowKernelOp,
+    broadcast_pow_tensor,
+    try_broadcast_pow_tensor,
+    "power"
+);
+
+#[cfg(test)]
+mod tests {
+    use crate::{shapes::*, tensor::*, tensor_ops::*, tests::*};
+
+    #[test]
+    fn test_broadcast_add_1d_to_2d() {
+        let dev: TestDevice = Default::default();
+        let a: Tensor<Rank2<2, 3>, TestDtype, _> = dev.sample_normal();
+        let b: Tensor<Rank1<3>, TestDtype, _> = dev.sample_normal();
+
+        let r = a.trace().broadcast_add(b.trace());
+        let r2 = a.trace() + b.trace().broadcast();
+        assert_close(&r.array(), &r2.array());
+
+        let g = r.exp().mean().backward();
+        let g2 = r2.exp().mean().backward();
+        assert_close(&g.get(&a).array(), &g2.get(&a).array());
+        assert_close(&g.get(&b).array(), &g2.get(&b).array());
+    }
+
+    #[test]
+    fn test_broadcast_mul_1d_to_3d() {
+        let dev: TestDevice = Default::default();
+        let a: Tensor<Rank3<2, 3, 4>, TestDtype, _> = dev.sample_normal();
+        let b: Tensor<Rank1<4>, TestDtype, _> = dev.sample_normal();
+
+        let r = a.trace().broadcast_mul(b.trace());
+        let r2 = a.trace() * b.trace().broadcast::<_, Axes2<0, 1>>();
+        assert_close(&r.array(), &r2.array());
+
+        let g = r.exp().mean().backward();
+        let g2 = r2.exp().mean().backward();
+        assert_close(&g.get(&a).array(), &g2.get(&a).array());
+        assert_close(&g.get(&b).array(), &g2.get(&b).array());
+    }
+
+    #[test]
+    fn test_broadcast_sub_div_2d_to_3d() {
+        let dev: TestDevice = Default::default();
+        let a: Tensor<Rank3<2, 3, 4>, TestDtype, _> = dev.sample_normal();
+        let b: Tensor<Rank2<3, 4>, TestDtype, _> = dev.sample_uniform();
+        let b = b + 0.5;
+
+        let r = a.trace().broadcast_sub(b.trace());
+        let r2 = a.trace() - b.trace().broadcast::<_, Axis<0>>();
+        assert_close(&r.array(), &r2.array());
+        let g = r.square().mean().backward();
+        let g2 = r2.square().mean().backward();
+        assert_close(&g.get(&a).array(), &g2.get(&a).array());
+        assert_close(&g.get(&b).array(), &g2.get(&b).array());
+
+        let r = a.trace().broadcast_div(b.trace());
+        let r2 = a.trace() / b.trace().broadcast::<_, Axis<0>>();
+        assert_close(&r.array(), &r2.array());
+        let g = r.square().mean().backward();
+        let g2 = r2.square().mean().backward();
+        assert_close(&g.get(&a).array(), &g2.get(&a).array());
+        assert_close(&g.get(&b).array(), &g2.get(&b).array());
+    }
+
+    #[test]
+    fn test_broadcast_maximum_minimum() {
+        let dev: TestDevice = Default::default();
+        let a: Tensor<_, TestDtype, _> = dev.tensor([[-1.0, 0.0, 1.0], [3.0, -4.0, 0.5]]);
+        let b: Tensor<_, TestDtype, _> = dev.tensor([0.0, 0.0, 0.5]);
+
+        let r = a.trace().broadcast_maximum(b.trace());
+        assert_eq!(r.array(), [[0.0, 0.0, 1.0], [3.0, 0.0, 0.5]]);
+        let g = r.sum().backward();
+        assert_eq!(g.get(&a).array(), [[0.0, 0.5, 1.0], [1.0, 0.0, 0.5]]);
+        assert_eq!(g.get(&b).array(), [1.0, 1.5, 0.5]);
+
+        let r = a.trace().broadcast_minimum(b.trace());
+        assert_eq!(r.array(), [[-1.0, 0.0, 0.5], [0.0, -4.0, 0.5]]);
+        let g = r.sum().backward();
+        assert_eq!(g.get(&a).array(), [[1.0, 0.5, 0.0], [0.0, 1.0, 0.5]]);
+        assert_eq!(g.get(&b).array(), [1.0, 0.5, 1.5]);
+    }
+
+    #[test]
+    fn test_broadcast_scalar_tensor() {
+        let dev: TestDevice = Default::default();
+        let a: Tensor<Rank2<2, 2>, TestDtype, _> = dev.tensor([[1.0, 2.0], [3.0, 4.0]]);
+        let b: Tensor<Rank0, TestDtype, _> = dev.tensor(2.0);
+        let r = a.trace().broadcast_mul(b.trace());
+        assert_eq!(r.array(), [[2.0, 4.0], [6.0, 8.0]]);
+        let g = r.sum().backward();
+        assert_eq!(g.get(&a).array(), [[2.0; 2]; 2]);
+        assert_eq!(g.get(&b).array(), 10.0);
+    }
+
+    #[test]
+    fn test_broadcast_add_size_1_dims() {
+        let dev: TestDevice = Default::default();
+        let a: Tensor<Rank3<2, 3, 4>, TestDtype, _> = dev.sample_normal();
+        let b: Tensor<Rank2<3, 1>, TestDtype, _> = dev.sample_normal();
+
+        let r = a.trace().broadcast_add(b.trace());
+        let b2: Tensor<Rank1<3>, TestDtype, _> = dev.tensor(b.array().map(|[x]| x));
+        let r2 = a.trace() + b2.trace().broadcast::<_, Axes2<0, 2>>();
+        assert_close(&r.array(), &r2.array());
+
+        let g = r.exp().mean().backward();
+        let g2 = r2.exp().mean().backward();
+        assert_close(&g.get(&a).array(), &g2.get(&a).array());
+        assert_close(
+            &g.get(&b).array(),
+            &[
+                [g2.get(&b2).array()[0]],
+                [g2.get(&b2).array()[1]],
+                [g2.get(&b2).array()[2]],
+            ],
+        );
+    }
+
+    #[test]
+    fn test_broadcast_mul_size_1_leading_dim() {
+        let dev: TestDevice = Default::default();
+        let a: Tensor<_, TestDtype, _> = dev.tensor([[1.0, 2.0], [3.0, 4.0]]);
+        let b: Tensor<_, TestDtype, _> = dev.tensor([[2.0, -1.0]]);
+        let r = a.trace().broadcast_mul(b.trace());
+        assert_eq!(r.array(), [[2.0, -2.0], [6.0, -4.0]]);
+        let g = r.sum().backward();
+        assert_eq!(g.get(&a).array(), [[2.0, -1.0], [2.0, -1.0]]);
+        assert_eq!(g.get(&b).array(), [[4.0, 6.0]]);
+    }
+
+    #[test]
+    #[should_panic]
+    fn test_broadcast_wrong_usize_dims() {
+        let dev: TestDevice = Default::default();
+        let a: Tensor<(usize, usize), TestDtype, _> = dev.zeros_like(&(2, 3));
+        let b: Tensor<(usize,), TestDtype, _> = dev.zeros_like(&(2,));
+        let _ = a.broadcast_add(b);
+    }
+}
@@ -19,6 +19,7 @@ impl<E: Dtype> super::BroadcastKernel<E> for Cpu {
             shape: dst,
             strides: inp.shape.broadcast_strides(inp.strides),
             device: self.clone(),
+            param_opts: Default::default(),
             tape: Default::default(),
         })
     }
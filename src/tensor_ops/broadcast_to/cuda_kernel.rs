@@ -39,6 +39,7 @@ where
             shape: dst,
             strides: inp.shape.broadcast_strides(inp.strides),
             device: self.clone(),
+            param_opts: Default::default(),
             tape: Default::default(),
         })
     }
@@ -77,7 +77,7 @@ impl<S: Shape, E: Dtype, D: BroadcastKernel<E>, T: Tape<E, D>> BroadcastTo for T
         let phantom_out = out.clone();
         tape.try_alloc_grad(&inp)?;
         tape.try_alloc_grad(&out)?;
-        tape.add_backward_op(move |grads| {
+        tape.add_reusable_backward_op(move |grads| {
             let (grad_inp, grad_out) = grads.mut_and_ref(&inp, &phantom_out);
             inp.device.backward(grad_inp, grad_out)
         });
@@ -0,0 +1,75 @@
+use crate::{
+    shapes::{Dim, Dtype, Shape},
+    tensor::{Cpu, CpuError, Tensor, ZerosTensor},
+    tensor_ops::triangular_solve::cpu_kernel::substitute,
+};
+
+impl<E: num_traits::Float + Dtype> super::CholeskyKernel<E> for Cpu {
+    fn forward<N: Dim>(
+        &self,
+        inp: &Tensor<(N, N), E, Self>,
+    ) -> Result<Tensor<(N, N), E, Self>, Self::Err> {
+        let n = inp.shape.0.size();
+        let [s0, s1] = inp.strides;
+        let mut out = self.try_zeros_like(&inp.shape)?;
+        let l = std::sync::Arc::make_mut(&mut out.data);
+        for i in 0..n {
+            for j in 0..=i {
+                let mut sum = inp.data[i * s0 + j * s1];
+                for k in 0..j {
+                    sum -= l[i * n + k] * l[j * n + k];
+                }
+                if i == j {
+                    if sum <= E::zero() || sum.is_nan() {
+                        return Err(CpuError::NotPositiveDefinite);
+                    }
+                    l[i * n + i] = sum.sqrt();
+                } else {
+                    l[i * n + j] = sum / l[j * n + j];
+                }
+            }
+        }
+        Ok(out)
+    }
+
+    fn backward<N: Dim>(
+        &self,
+        inp: &Tensor<(N, N), E, Self>,
+        grad_inp: &mut Self::Vec<E>,
+        out: &Tensor<(N, N), E, Self>,
+        grad_out: &Self::Vec<E>,
+    ) -> Result<(), Self::Err> {
+        let n = inp.shape.0.size();
+        let [s0, s1] = inp.strides;
+        debug_assert_eq!(out.shape.strides(), out.strides);
+        let l = out.data.as_ref();
+
+        // phi = l^T * tril(grad_l), lower triangle with the diagonal halved
+        let mut phi = std::vec![E::default(); n * n];
+        for i in 0..n {
+            for j in 0..=i {
+                let mut sum = E::default();
+                for k in i..n {
+                    sum += l[k * n + i] * grad_out[k * n + j];
+                }
+                phi[i * n + j] = if i == j {
+                    sum / (E::one() + E::one())
+                } else {
+                    sum
+                };
+            }
+        }
+
+        // y = l^-T * phi, then g = y * l^-1, i.e. g^T = l^-T * y^T
+        let y = substitute(n, n, |i, j| l[j * n + i], |i, c| phi[i * n + c], true);
+        let g_t = substitute(n, n, |i, j| l[j * n + i], |i, c| y[c * n + i], true);
+
+        let half = E::one() / (E::one() + E::one());
+        for i in 0..n {
+            for j in 0..n {
+                grad_inp[i * s0 + j * s1] += (g_t[j * n + i] + g_t[i * n + j]) * half;
+            }
+        }
+        Ok(())
+    }
+}
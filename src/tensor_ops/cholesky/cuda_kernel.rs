@@ -0,0 +1,40 @@
+use crate::{
+    shapes::{Dim, Dtype},
+    tensor::{Cpu, Cuda, Tensor, TensorFromVec},
+    tensor_ops::triangular_solve::cuda_kernel::to_host,
+};
+
+use super::CholeskyKernel;
+
+/// The factorization is sequential, so this runs on the host with the
+/// [Cpu] implementation and copies the results back to the device.
+impl<E: Dtype> CholeskyKernel<E> for Cuda
+where
+    Cpu: CholeskyKernel<E>,
+{
+    fn forward<N: Dim>(
+        &self,
+        inp: &Tensor<(N, N), E, Self>,
+    ) -> Result<Tensor<(N, N), E, Self>, Self::Err> {
+        let inp = to_host(self, inp)?;
+        let out = self.cpu.forward(&inp)?;
+        self.try_tensor_from_vec(out.data.as_ref().clone(), out.shape)
+    }
+
+    fn backward<N: Dim>(
+        &self,
+        inp: &Tensor<(N, N), E, Self>,
+        grad_inp: &mut Self::Vec<E>,
+        out: &Tensor<(N, N), E, Self>,
+        grad_out: &Self::Vec<E>,
+    ) -> Result<(), Self::Err> {
+        let inp_host = to_host(self, inp)?;
+        let out_host = to_host(self, out)?;
+        let mut grad_inp_host = self.dev.dtoh_sync_copy(grad_inp)?;
+        let grad_out_host = self.dev.dtoh_sync_copy(grad_out)?;
+        self.cpu
+            .backward(&inp_host, &mut grad_inp_host, &out_host, &grad_out_host)?;
+        self.dev.htod_sync_copy_into(&grad_inp_host, grad_inp)?;
+        Ok(())
+    }
+}
@@ -0,0 +1,147 @@
+mod cpu_kernel;
+
+#[cfg(feature = "cuda")]
+mod cuda_kernel;
+
+use crate::{
+    gradients::Tape,
+    shapes::{Dim, Dtype},
+    tensor::{DeviceStorage, PutTape, SplitTape, Tensor},
+};
+
+pub trait CholeskyKernel<E: Dtype>: DeviceStorage {
+    fn forward<N: Dim>(
+        &self,
+        inp: &Tensor<(N, N), E, Self>,
+    ) -> Result<Tensor<(N, N), E, Self>, Self::Err>;
+
+    fn backward<N: Dim>(
+        &self,
+        inp: &Tensor<(N, N), E, Self>,
+        grad_inp: &mut Self::Vec<E>,
+        out: &Tensor<(N, N), E, Self>,
+        grad_out: &Self::Vec<E>,
+    ) -> Result<(), Self::Err>;
+}
+
+/// Cholesky decomposition of a symmetric positive-definite matrix `a`: the lower-triangular
+/// matrix `l` with a positive diagonal such that `a = l * l^T`.
+///
+/// Only the lower triangle of `a` is read. [Tensor::try_cholesky] returns an error
+/// (e.g. [crate::tensor::CpuError::NotPositiveDefinite]) if `a` is not positive definite.
+///
+/// Since `a` is symmetric, its gradient is symmetric as well:
+/// `grad_a = sym(l^-T * phi(l^T * grad_l) * l^-1)`, where `phi` takes the lower triangle
+/// and halves the diagonal, and `sym(x) = (x + x^T) / 2`.
+///
+/// **Pytorch equivalent**: `torch.linalg.cholesky(a)`
+///
+/// Example:
+/// ```rust
+/// # use dfdx::prelude::*;
+/// # let dev: Cpu = Default::default();
+/// let a = dev.tensor([[4.0, 2.0], [2.0, 5.0]]);
+/// let l = cholesky(a);
+/// assert_eq!(l.array(), [[2.0, 0.0], [1.0, 2.0]]);
+/// ```
+pub fn cholesky<N: Dim, E: Dtype, D: CholeskyKernel<E>, T: Tape<E, D>>(
+    a: Tensor<(N, N), E, D, T>,
+) -> Tensor<(N, N), E, D, T> {
+    a.cholesky()
+}
+
+impl<N: Dim, E: Dtype, D: CholeskyKernel<E>, T: Tape<E, D>> Tensor<(N, N), E, D, T> {
+    /// See [cholesky]
+    pub fn cholesky(self) -> Self {
+        self.try_cholesky().unwrap()
+    }
+
+    /// See [cholesky]
+    pub fn try_cholesky(self) -> Result<Self, D::Err> {
+        assert_eq!(self.shape.0, self.shape.1);
+        let (inp, mut tape) = self.split_tape();
+        let out = inp.device.forward(&inp)?;
+        let phantom_out = out.clone();
+        tape.try_alloc_grad(&inp)?;
+        tape.try_alloc_grad(&out)?;
+        tape.add_reusable_backward_op(move |grads| {
+            let (grad_inp, grad_out) = grads.mut_and_ref(&inp, &phantom_out);
+            inp.device.backward(&inp, grad_inp, &phantom_out, grad_out)
+        });
+        Ok(out.put_tape(tape))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{shapes::*, tensor::*, tensor_ops::*, tests::*};
+
+    #[test]
+    fn test_cholesky() {
+        let dev: TestDevice = Default::default();
+        let a: Tensor<_, TestDtype, _> = dev.tensor([
+            [4.0, 12.0, -16.0],
+            [12.0, 37.0, -43.0],
+            [-16.0, -43.0, 98.0],
+        ]);
+        let l = cholesky(a.clone());
+        assert_close(
+            &l.array(),
+            &[[2.0, 0.0, 0.0], [6.0, 1.0, 0.0], [-8.0, 5.0, 3.0]],
+        );
+        let l_t = l.clone().permute::<Rank2<3, 3>, _>();
+        assert_close(&l.matmul(l_t).array(), &a.array());
+    }
+
+    #[test]
+    fn test_cholesky_not_positive_definite() {
+        let dev: TestDevice = Default::default();
+        let a: Tensor<_, TestDtype, _> = dev.tensor([[1.0, 2.0], [2.0, 1.0]]);
+        assert!(a.try_cholesky().is_err());
+    }
+
+    #[test]
+    fn test_cholesky_grads() {
+        let dev: TestDevice = Default::default();
+        let a: Tensor<Rank2<3, 3>, TestDtype, _> =
+            dev.tensor([[4.0, 2.0, -1.0], [2.0, 5.0, 1.5], [-1.0, 1.5, 3.0]]);
+        let w: Tensor<Rank2<3, 3>, TestDtype, _> =
+            dev.tensor([[0.3, -0.7, 0.5], [1.1, 0.2, -0.6], [-0.4, 0.9, 0.8]]);
+        let g = (a.trace().cholesky() * w.clone())
+            .sum::<Rank0, _>()
+            .backward();
+        let g_a = g.get(&a).array();
+
+        // gradients are symmetric
+        assert_close(&g_a, &g.get(&a).permute::<Rank2<3, 3>, _>().array());
+
+        // central finite differences, perturbing a symmetrically
+        let loss = |a: std::vec::Vec<TestDtype>| {
+            (dev.tensor_from_vec(a, (Const::<3>, Const::<3>)).cholesky() * w.clone())
+                .sum::<Rank0, _>()
+                .array()
+        };
+        let eps: TestDtype = 1e-2;
+        let a_vec = a.as_vec();
+        for i in 0..3 {
+            for j in 0..=i {
+                let mut plus = a_vec.clone();
+                let mut minus = a_vec.clone();
+                plus[i * 3 + j] += eps;
+                minus[i * 3 + j] -= eps;
+                if i != j {
+                    plus[j * 3 + i] += eps;
+                    minus[j * 3 + i] -= eps;
+                }
+                let expected = (loss(plus) - loss(minus)) / (2.0 * eps);
+                let actual = if i == j {
+                    g_a[i][j]
+                } else {
+                    g_a[i][j] + g_a[j][i]
+                };
+                assert_close_with_tolerance(&actual, &expected, 1e-3);
+            }
+        }
+    }
+}
@@ -70,7 +70,7 @@ impl<
         tape.try_alloc_grad(&lhs)?;
         tape.try_alloc_grad(&rhs)?;
         tape.try_alloc_grad(&out)?;
-        tape.add_backward_op(move |grads| {
+        tape.add_reusable_backward_op(move |grads| {
             let (grad_lhs, grad_rhs, grad_out) = grads.muts_and_ref(&lhs, &rhs, &phantom_out);
             lhs.device
                 .backward(&self, &lhs, grad_lhs, &rhs, grad_rhs, grad_out)
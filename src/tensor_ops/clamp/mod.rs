@@ -3,7 +3,7 @@ mod cpu_kernel;
 #[cfg(feature = "cuda")]
 mod cuda_kernel;
 
-use super::ops::{try_unary_op, UnaryKernel};
+use super::ops::{try_unary_op, try_unary_op_inplace, UnaryKernel};
 use crate::{gradients::Tape, shapes::*, tensor::Tensor};
 
 #[repr(C)]
@@ -31,6 +31,40 @@ pub fn clamp<S: Shape, E: Dtype, D: UnaryKernel<ClampKernelOp<E>, E>, T: Tape<E,
     t.clamp(min, max)
 }
 
+/// Clamp all elements to be at least `min`. Same as [clamp] with an upper bound of `inf`.
+///
+/// Example:
+/// ```rust
+/// # use dfdx::prelude::*;
+/// # let dev: Cpu = Default::default();
+/// let t = dev.tensor([-1.0, -0.5, 0.0, 0.5, 1.0]);
+/// let r = t.clamp_min(-0.5);
+/// assert_eq!(r.array(), [-0.5, -0.5, 0.0, 0.5, 1.0]);
+/// ```
+pub fn clamp_min<S: Shape, E: Dtype, D: UnaryKernel<ClampKernelOp<E>, E>, T: Tape<E, D>>(
+    t: Tensor<S, E, D, T>,
+    min: E,
+) -> Tensor<S, E, D, T> {
+    t.clamp_min(min)
+}
+
+/// Clamp all elements to be at most `max`. Same as [clamp] with a lower bound of `-inf`.
+///
+/// Example:
+/// ```rust
+/// # use dfdx::prelude::*;
+/// # let dev: Cpu = Default::default();
+/// let t = dev.tensor([-1.0, -0.5, 0.0, 0.5, 1.0]);
+/// let r = t.clamp_max(0.5);
+/// assert_eq!(r.array(), [-1.0, -0.5, 0.0, 0.5, 0.5]);
+/// ```
+pub fn clamp_max<S: Shape, E: Dtype, D: UnaryKernel<ClampKernelOp<E>, E>, T: Tape<E, D>>(
+    t: Tensor<S, E, D, T>,
+    max: E,
+) -> Tensor<S, E, D, T> {
+    t.clamp_max(max)
+}
+
 impl<S: Shape, E: Dtype, D: UnaryKernel<ClampKernelOp<E>, E>, T: Tape<E, D>> Tensor<S, E, D, T> {
     /// See [clamp]
     pub fn clamp(self, min: E, max: E) -> Self {
@@ -40,6 +74,36 @@ impl<S: Shape, E: Dtype, D: UnaryKernel<ClampKernelOp<E>, E>, T: Tape<E, D>> Ten
     pub fn try_clamp(self, min: E, max: E) -> Result<Self, D::Err> {
         try_unary_op(ClampKernelOp { min, max }, self)
     }
+
+    /// See [clamp_min]
+    pub fn clamp_min(self, min: E) -> Self {
+        self.try_clamp_min(min).unwrap()
+    }
+    /// See [clamp_min]
+    pub fn try_clamp_min(self, min: E) -> Result<Self, D::Err> {
+        let max = E::from_f64(f64::INFINITY).unwrap();
+        try_unary_op(ClampKernelOp { min, max }, self)
+    }
+
+    /// See [clamp_max]
+    pub fn clamp_max(self, max: E) -> Self {
+        self.try_clamp_max(max).unwrap()
+    }
+    /// See [clamp_max]
+    pub fn try_clamp_max(self, max: E) -> Result<Self, D::Err> {
+        let min = E::from_f64(f64::NEG_INFINITY).unwrap();
+        try_unary_op(ClampKernelOp { min, max }, self)
+    }
+
+    /// In place version of [clamp]. Overwrites the storage of `self` if it has no tape and
+    /// isn't shared with another tensor, otherwise this is the same as [clamp].
+    pub fn clamp_(self, min: E, max: E) -> Self {
+        self.try_clamp_(min, max).unwrap()
+    }
+    /// See [Tensor::clamp_]
+    pub fn try_clamp_(self, min: E, max: E) -> Result<Self, D::Err> {
+        try_unary_op_inplace(ClampKernelOp { min, max }, self)
+    }
 }
 
 #[cfg(test)]
@@ -58,4 +122,47 @@ mod tests {
             &[[0.06131324, 0.16666667, 0.45304698], [0.0; 3]],
         );
     }
+
+    #[test]
+    fn test_clamp_min() {
+        let dev: TestDevice = Default::default();
+        let t: Tensor<_, TestDtype, _> = dev.tensor([-2.0, -1.0, 0.0, 1.0, 1e30]);
+        let r = t.trace().clamp_min(-1.0);
+        assert_eq!(r.array(), [-1.0, -1.0, 0.0, 1.0, 1e30]);
+        let g = r.sum().backward();
+        assert_eq!(g.get(&t).array(), [0.0, 1.0, 1.0, 1.0, 1.0]);
+
+        // the gradient at the boundary matches the two sided clamp
+        let g2 = t.trace().clamp(-1.0, 1e30).sum().backward();
+        assert_eq!(g.get(&t).array(), g2.get(&t).array());
+    }
+
+    #[test]
+    fn test_clamp_max() {
+        let dev: TestDevice = Default::default();
+        let t: Tensor<_, TestDtype, _> = dev.tensor([-1e30, -1.0, 0.0, 1.0, 2.0]);
+        let r = t.trace().clamp_max(1.0);
+        assert_eq!(r.array(), [-1e30, -1.0, 0.0, 1.0, 1.0]);
+        let g = r.sum().backward();
+        assert_eq!(g.get(&t).array(), [1.0, 1.0, 1.0, 1.0, 0.0]);
+
+        let g2 = t.trace().clamp(-1e30, 1.0).sum().backward();
+        assert_eq!(g.get(&t).array(), g2.get(&t).array());
+    }
+
+    #[test]
+    fn test_clamp_inplace() {
+        let dev: TestDevice = Default::default();
+        let t: Tensor<_, TestDtype, _> = dev.tensor([[-1.0, 0.0, 1.0], [-2.0, 2.0, 1.1]]);
+        let expected = [[-1.0, 0.0, 1.0], [-1.0, 1.0, 1.0]];
+
+        let r = t.clone().clamp_(-1.0, 1.0);
+        assert_close(&r.array(), &expected);
+        assert_close(&t.array(), &[[-1.0, 0.0, 1.0], [-2.0, 2.0, 1.1]]);
+
+        let ptr = std::sync::Arc::as_ptr(&t.data);
+        let r = t.clamp_(-1.0, 1.0);
+        assert_close(&r.array(), &expected);
+        assert_eq!(std::sync::Arc::as_ptr(&r.data), ptr);
+    }
 }
@@ -1,7 +1,10 @@
 struct Conv2DOp {
-    size_t stride;
-    size_t padding;
-    size_t kernel;
+    size_t stride_h;
+    size_t stride_w;
+    size_t padding_h;
+    size_t padding_w;
+    size_t kernel_h;
+    size_t kernel_w;
     size_t batch;
     size_t chan_in;
     size_t chan_out;
@@ -16,43 +19,43 @@ __device__ void unfold_input_into_patches(
     const Conv2DOp op,
     const T *image, // 4d (Batch, Channels, Height, Width)
     const size_t *strides, // 4d image strides
-    T *patches // 6d (Batch, Channels, KernelSize, KernelSize, HeightOut, WidthOut)
+    T *patches // 6d (Batch, Channels, KernelHeight, KernelWidth, HeightOut, WidthOut)
 ) {
     unsigned int i = blockIdx.x * blockDim.x + threadIdx.x;
-    const auto patches_numel = op.batch * op.chan_in * op.kernel * op.kernel * op.h_out * op.w_out;
+    const auto patches_numel = op.batch * op.chan_in * op.kernel_h * op.kernel_w * op.h_out * op.w_out;
     if (i >= patches_numel) {
         return;
     }
 
-    // patches shape is (B, C, K, K, h_out, w_out)
+    // patches shape is (B, C, KH, KW, h_out, w_out)
     unsigned int idx = i;
     const size_t ow = idx % op.w_out;
     idx /= op.w_out;
     const size_t oh = idx % op.h_out;
     idx /= op.h_out;
-    const size_t k2 = idx % op.kernel;
-    idx /= op.kernel;
-    const size_t k1 = idx % op.kernel;
-    idx /= op.kernel;
+    const size_t k2 = idx % op.kernel_w;
+    idx /= op.kernel_w;
+    const size_t k1 = idx % op.kernel_h;
+    idx /= op.kernel_h;
     const size_t c = idx % op.chan_in;
     idx /= op.chan_in;
     const size_t b = idx % op.batch;
     idx /= op.batch;
 
-    const size_t y_plus_p = oh * op.stride + k1;
-    if (y_plus_p < op.padding) {
+    const size_t y_plus_p = oh * op.stride_h + k1;
+    if (y_plus_p < op.padding_h) {
         return;
     }
-    const size_t y = y_plus_p - op.padding;
+    const size_t y = y_plus_p - op.padding_h;
     if (y >= op.h_in) {
         return;
     }
 
-    const size_t x_plus_p = ow * op.stride + k2;
-    if (x_plus_p < op.padding) {
+    const size_t x_plus_p = ow * op.stride_w + k2;
+    if (x_plus_p < op.padding_w) {
         return;
     }
-    const size_t x = x_plus_p - op.padding;
+    const size_t x = x_plus_p - op.padding_w;
     if (x >= op.w_in) {
         return;
     }
@@ -65,10 +68,10 @@ template<typename T>
 __device__ void unfold_output_into_patches(
     const Conv2DOp op,
     const T *image_out, // 4d (Batch, ChanOut, HeightOut, WidthOut)
-    T *patches // 6d (Batch, ChanOut, KernelSize, KernelSize, HeightIn, WidthIn)
+    T *patches // 6d (Batch, ChanOut, KernelHeight, KernelWidth, HeightIn, WidthIn)
 ) {
     unsigned int i = blockIdx.x * blockDim.x + threadIdx.x;
-    const auto patches_numel = op.batch * op.chan_out * op.kernel * op.kernel * op.h_in * op.w_in;
+    const auto patches_numel = op.batch * op.chan_out * op.kernel_h * op.kernel_w * op.h_in * op.w_in;
     if (i >= patches_numel) {
         return;
     }
@@ -78,37 +81,37 @@ __device__ void unfold_output_into_patches(
     idx /= op.w_in;
     const size_t y = idx % op.h_in;
     idx /= op.h_in;
-    const size_t k2 = idx % op.kernel;
-    idx /= op.kernel;
-    const size_t k1 = idx % op.kernel;
-    idx /= op.kernel;
+    const size_t k2 = idx % op.kernel_w;
+    idx /= op.kernel_w;
+    const size_t k1 = idx % op.kernel_h;
+    idx /= op.kernel_h;
     const size_t o = idx % op.chan_out;
     idx /= op.chan_out;
     const size_t b = idx % op.batch;
     idx /= op.batch;
 
-    size_t oh = y + op.padding;
+    size_t oh = y + op.padding_h;
     if (oh < k1) {
         return;
     }
     oh -= k1;
-    if (oh % op.stride != 0) {
+    if (oh % op.stride_h != 0) {
         return;
     }
-    oh /= op.stride;
+    oh /= op.stride_h;
     if (oh >= op.h_out) {
         return;
     }
     
-    size_t ow = x + op.padding;
+    size_t ow = x + op.padding_w;
     if (ow < k2) {
         return;
     }
     ow -= k2;
-    if (ow % op.stride != 0) {
+    if (ow % op.stride_w != 0) {
         return;
     }
-    ow /= op.stride;
+    ow /= op.stride_w;
     if (ow >= op.w_out) {
         return;
     }
@@ -120,27 +123,27 @@ __device__ void unfold_output_into_patches(
 template<typename T>
 __device__ void transpose_and_broadcast_filters(
     const Conv2DOp op,
-    const T *filters, // 4d (ChanOut, ChanIn, KernelSize, KernelSize)
+    const T *filters, // 4d (ChanOut, ChanIn, KernelHeight, KernelWidth)
     const size_t *strides, // 4d filters strides
-    T *filters_tr // 5d (Batch, ChanIn, ChanOut, KernelSize, KernelSize)
+    T *filters_tr // 5d (Batch, ChanIn, ChanOut, KernelHeight, KernelWidth)
 ) {
     unsigned int i = blockIdx.x * blockDim.x + threadIdx.x;
-    auto numel = op.chan_in * op.chan_out * op.kernel * op.kernel;
+    auto numel = op.chan_in * op.chan_out * op.kernel_h * op.kernel_w;
     if (i >= numel) {
         return;
     }
 
     unsigned int idx = i;
-    const size_t k2 = idx % op.kernel;
-    idx /= op.kernel;
-    const size_t k1 = idx % op.kernel;
-    idx /= op.kernel;
+    const size_t k2 = idx % op.kernel_w;
+    idx /= op.kernel_w;
+    const size_t k1 = idx % op.kernel_h;
+    idx /= op.kernel_h;
     const size_t c = idx % op.chan_in;
     idx /= op.chan_in;
     const size_t o = idx % op.chan_out;
     idx /= op.chan_out;
 
-    auto i_tr = c * (op.chan_out * op.kernel * op.kernel) + o * (op.kernel * op.kernel) + k1 * (op.kernel) + k2;
+    auto i_tr = c * (op.chan_out * op.kernel_h * op.kernel_w) + o * (op.kernel_h * op.kernel_w) + k1 * (op.kernel_w) + k2;
     auto i_no = o * strides[0] + c * strides[1] + k1 * strides[2] + k2 * strides[3];
 
     const T f = filters[i_no];
@@ -152,27 +155,27 @@ __device__ void transpose_and_broadcast_filters(
 template<typename T>
 __device__ void sum_transposed_filters(
     const Conv2DOp op,
-    const T *filters_tr, // 5d (Batch, ChanIn, ChanOut, KernelSize, KernelSize)
-    T *filters, // 4d (ChanOut, ChanIn, KernelSize, KernelSize)
+    const T *filters_tr, // 5d (Batch, ChanIn, ChanOut, KernelHeight, KernelWidth)
+    T *filters, // 4d (ChanOut, ChanIn, KernelHeight, KernelWidth)
     const size_t *strides // 4d filter strides
 ) {
     unsigned int i = blockIdx.x * blockDim.x + threadIdx.x;
-    auto numel = op.chan_out * op.chan_in * op.kernel * op.kernel;
+    auto numel = op.chan_out * op.chan_in * op.kernel_h * op.kernel_w;
     if (i >= numel) {
         return;
     }
 
     unsigned int idx = i;
-    const size_t k2 = idx % op.kernel;
-    idx /= op.kernel;
-    const size_t k1 = idx % op.kernel;
-    idx /= op.kernel;
+    const size_t k2 = idx % op.kernel_w;
+    idx /= op.kernel_w;
+    const size_t k1 = idx % op.kernel_h;
+    idx /= op.kernel_h;
     const size_t c = idx % op.chan_in;
     idx /= op.chan_in;
     const size_t o = idx % op.chan_out;
     idx /= op.chan_out;
 
-    auto i_tr = c * (op.chan_out * op.kernel * op.kernel) + o * (op.kernel * op.kernel) + k1 * (op.kernel) + k2;
+    auto i_tr = c * (op.chan_out * op.kernel_h * op.kernel_w) + o * (op.kernel_h * op.kernel_w) + k1 * (op.kernel_w) + k2;
     auto i_no = o * strides[0] + c * strides[1] + k1 * strides[2] + k2 * strides[3];
 
     T tmp = 0.0;
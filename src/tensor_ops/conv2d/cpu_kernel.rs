@@ -9,28 +9,28 @@ use std::sync::Arc;
 impl Conv2DOp {
     #[inline(always)]
     fn unfold_idx(&self, [k1, k2, y, x]: [usize; 4]) -> Option<[usize; 2]> {
-        let mut oh = y + self.padding;
+        let mut oh = y + self.padding_h;
         if oh < k1 {
             return None;
         }
         oh -= k1;
-        if oh % self.stride != 0 {
+        if !oh.is_multiple_of(self.stride_h) {
             return None;
         }
-        oh /= self.stride;
+        oh /= self.stride_h;
         if oh >= self.h_out {
             return None;
         }
 
-        let mut ow = x + self.padding;
+        let mut ow = x + self.padding_w;
         if ow < k2 {
             return None;
         }
         ow -= k2;
-        if ow % self.stride != 0 {
+        if !ow.is_multiple_of(self.stride_w) {
             return None;
         }
-        ow /= self.stride;
+        ow /= self.stride_w;
         if ow >= self.w_out {
             return None;
         }
@@ -55,12 +55,12 @@ impl Cpu {
         {
             let mut i = 0;
             for c in 0..op.chan_in {
-                for k1 in 0..op.kernel {
-                    for k2 in 0..op.kernel {
+                for k1 in 0..op.kernel_h {
+                    for k2 in 0..op.kernel_w {
                         for oh in 0..op.h_out {
                             for ow in 0..op.w_out {
-                                let y = (oh * op.stride + k1).wrapping_sub(op.padding);
-                                let x = (ow * op.stride + k2).wrapping_sub(op.padding);
+                                let y = (oh * op.stride_h + k1).wrapping_sub(op.padding_h);
+                                let x = (ow * op.stride_w + k2).wrapping_sub(op.padding_w);
                                 if y < op.h_in && x < op.w_in {
                                     buf[i] = img[c * (op.w_in * op.h_in) + y * op.w_in + x];
                                 }
@@ -72,9 +72,9 @@ impl Cpu {
             }
         }
 
-        // (O, C * K * K) * (C * K * K, OH * OW) = (O, OH * OW)
+        // (O, C * KH * KW) * (C * KH * KW, OH * OW) = (O, OH * OW)
         let m = op.chan_out;
-        let k = op.chan_in * op.kernel * op.kernel;
+        let k = op.chan_in * op.kernel_h * op.kernel_w;
         let n = op.w_out * op.h_out;
         Self::matmul(
             (m, k, n),
@@ -106,8 +106,8 @@ impl Cpu {
         {
             let mut i = 0;
             for o in 0..op.chan_out {
-                for k1 in 0..op.kernel {
-                    for k2 in 0..op.kernel {
+                for k1 in 0..op.kernel_h {
+                    for k2 in 0..op.kernel_w {
                         for y in 0..op.h_in {
                             for x in 0..op.w_in {
                                 if let Some([oh, ow]) = op.unfold_idx([k1, k2, y, x]) {
@@ -124,9 +124,9 @@ impl Cpu {
 
         {
             // img_g += filters^T * unfold(grad_out)
-            // (C, H * W) += (C, O * K * K) * (O * K * K, H * W)
+            // (C, H * W) += (C, O * KH * KW) * (O * KH * KW, H * W)
             let m = op.chan_in;
-            let k = op.chan_out * op.kernel * op.kernel;
+            let k = op.chan_out * op.kernel_h * op.kernel_w;
             let n = op.h_in * op.w_in;
             Self::matmul(
                 (m, k, n),
@@ -141,10 +141,10 @@ impl Cpu {
 
         {
             // weight_g^T += img * patches^T
-            // (C, O * K * K) += (C, H * W) * (H * W, O * K * K)
+            // (C, O * KH * KW) += (C, H * W) * (H * W, O * KH * KW)
             let m = op.chan_in;
             let k = op.h_in * op.w_in;
-            let n = op.chan_out * op.kernel * op.kernel;
+            let n = op.chan_out * op.kernel_h * op.kernel_w;
             Self::matmul(
                 (m, k, n),
                 img.as_ptr(),
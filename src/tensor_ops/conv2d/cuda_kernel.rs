@@ -62,7 +62,7 @@ where
             self.dev.load_ptx(PTX_SRC.into(), Self::MOD, Self::FNS)?;
         }
 
-        let patches_numel = op.batch * op.chan_in * op.kernel * op.kernel * op.h_out * op.w_out;
+        let patches_numel = op.batch * op.chan_in * op.kernel_h * op.kernel_w * op.h_out * op.w_out;
         let mut patches = self.dev.alloc_zeros::<E>(patches_numel)?;
         let img_strides = self.dev.htod_copy(make_4d::<L>(lhs.strides).into())?;
         let unfold_fn = self.dev.get_func(Self::MOD, Self::FNS[0]).unwrap();
@@ -70,9 +70,9 @@ where
         let params = (op, lhs.data.as_ref(), &img_strides, &mut patches);
         unsafe { unfold_fn.launch(cfg, params) }?;
 
-        // (O, C * K * K) * (B, C * K * K, OH * OW) = (B, O, OH * OW)
+        // (O, C * KH * KW) * (B, C * KH * KW, OH * OW) = (B, O, OH * OW)
         let m = op.chan_out;
-        let k = op.chan_in * op.kernel * op.kernel;
+        let k = op.chan_in * op.kernel_h * op.kernel_w;
         let n = op.h_out * op.w_out;
         unsafe {
             sgemm_batch(
@@ -102,7 +102,7 @@ where
         _: &Tensor<O, E, Self>,
         grad_out: &Self::Vec<E>,
     ) -> Result<(), Self::Err> {
-        let patches_numel = op.batch * op.chan_out * op.kernel * op.kernel * op.h_in * op.w_in;
+        let patches_numel = op.batch * op.chan_out * op.kernel_h * op.kernel_w * op.h_in * op.w_in;
         let mut patches = self.dev.alloc_zeros::<E>(patches_numel)?;
 
         {
@@ -112,7 +112,7 @@ where
             unsafe { unfold_fn.launch(cfg, (op, grad_out, &mut patches)) }?;
         }
 
-        let filters_numel = op.batch * op.chan_in * op.chan_out * op.kernel * op.kernel;
+        let filters_numel = op.batch * op.chan_in * op.chan_out * op.kernel_h * op.kernel_w;
         let mut f_b1023 = self.dev.alloc_zeros::<E>(filters_numel)?;
         let mut grad_f_b1023 = self.dev.alloc_zeros::<E>(filters_numel)?;
         let f_strides = self.dev.htod_copy(rhs.strides.into())?;
@@ -127,9 +127,9 @@ where
 
         {
             // img_g += filters * patches
-            // (B, C, H * W) += (B, C, O * K * K) * (B, O * K * K, H * W)
+            // (B, C, H * W) += (B, C, O * KH * KW) * (B, O * KH * KW, H * W)
             let m = op.chan_in;
-            let k = op.chan_out * op.kernel * op.kernel;
+            let k = op.chan_out * op.kernel_h * op.kernel_w;
             let n = op.h_in * op.w_in;
             unsafe {
                 sgemm_batch(
@@ -149,10 +149,10 @@ where
 
         {
             // weight_g += img * patches^T
-            // (B, C, O * K * K) += (B, C, H * W) * (B, H * W, O * K * K)
+            // (B, C, O * KH * KW) += (B, C, H * W) * (B, H * W, O * KH * KW)
             let m = op.chan_in;
             let k = op.h_in * op.w_in;
-            let n = op.chan_out * op.kernel * op.kernel;
+            let n = op.chan_out * op.kernel_h * op.kernel_w;
             unsafe {
                 sgemm_batch(
                     self.blas.as_ref(),
@@ -12,9 +12,12 @@ use crate::{
 #[repr(C)]
 #[derive(Debug, Copy, Clone)]
 pub(super) struct Conv2DOp {
-    pub stride: usize,
-    pub padding: usize,
-    pub kernel: usize,
+    pub stride_h: usize,
+    pub stride_w: usize,
+    pub padding_h: usize,
+    pub padding_w: usize,
+    pub kernel_h: usize,
+    pub kernel_w: usize,
     pub batch: usize,
     pub chan_in: usize,
     pub chan_out: usize,
@@ -25,33 +28,42 @@ pub(super) struct Conv2DOp {
 }
 
 impl Conv2DOp {
-    fn new(s: usize, p: usize, k: usize, [b, c, h_in, w_in]: [usize; 4], o: usize) -> Self {
+    fn new(
+        [sh, sw]: [usize; 2],
+        [ph, pw]: [usize; 2],
+        [kh, kw]: [usize; 2],
+        [b, c, h_in, w_in]: [usize; 4],
+        o: usize,
+    ) -> Self {
         Self {
-            stride: s,
-            padding: p,
-            kernel: k,
+            stride_h: sh,
+            stride_w: sw,
+            padding_h: ph,
+            padding_w: pw,
+            kernel_h: kh,
+            kernel_w: kw,
             batch: b,
             chan_in: c,
             chan_out: o,
             h_in,
-            h_out: (h_in + 2 * p - k) / s + 1,
+            h_out: (h_in + 2 * ph - kh) / sh + 1,
             w_in,
-            w_out: (w_in + 2 * p - k) / s + 1,
+            w_out: (w_in + 2 * pw - kw) / sw + 1,
         }
     }
 
     #[rustfmt::skip]
     pub(super) fn inp_patches_shape(&self) -> (usize, usize, usize, usize, usize) {
-        (self.chan_in, self.kernel, self.kernel, self.h_out, self.w_out)
+        (self.chan_in, self.kernel_h, self.kernel_w, self.h_out, self.w_out)
     }
 
     #[rustfmt::skip]
     pub(super) fn out_patches_shape(&self) -> (usize, usize, usize, usize, usize) {
-        (self.chan_out, self.kernel, self.kernel, self.h_in, self.w_in)
+        (self.chan_out, self.kernel_h, self.kernel_w, self.h_in, self.w_in)
     }
 
     pub(super) fn filters_tr_shape(&self) -> (usize, usize, usize, usize) {
-        (self.chan_in, self.chan_out, self.kernel, self.kernel)
+        (self.chan_in, self.chan_out, self.kernel_h, self.kernel_w)
     }
 }
 
@@ -96,6 +108,25 @@ pub trait TryConv2DTo<F, const S: usize, const P: usize>: HasErr {
     fn try_conv2d_to(self, filters: F) -> Result<Self::Output, Self::Err>;
 }
 
+/// Convolution with a different stride and padding along the height and width.
+/// The kernel size `(KH, KW)` comes from the shape of the filters.
+pub trait TryConv2DRectTo<F, const SH: usize, const SW: usize, const PH: usize, const PW: usize>:
+    HasErr
+{
+    type Output;
+    fn try_conv2d_rect_to(self, filters: F) -> Result<Self::Output, Self::Err>;
+}
+
+impl<T, F, const S: usize, const P: usize> TryConv2DTo<F, S, P> for T
+where
+    T: TryConv2DRectTo<F, S, S, P, P>,
+{
+    type Output = T::Output;
+    fn try_conv2d_to(self, filters: F) -> Result<Self::Output, Self::Err> {
+        self.try_conv2d_rect_to(filters)
+    }
+}
+
 pub trait TryConv2D<F> {
     fn conv2d<const S: usize, const P: usize>(self, filters: F) -> Self::Output
     where
@@ -112,6 +143,26 @@ pub trait TryConv2D<F> {
     {
         self.try_conv2d_to(filters)
     }
+    /// Convolves with stride `(SH, SW)` and padding `(PH, PW)` along the height and
+    /// width. Filters may have a non-square `(KH, KW)` kernel.
+    fn conv2d_rect<const SH: usize, const SW: usize, const PH: usize, const PW: usize>(
+        self,
+        filters: F,
+    ) -> Self::Output
+    where
+        Self: TryConv2DRectTo<F, SH, SW, PH, PW>,
+    {
+        self.try_conv2d_rect_to(filters).unwrap()
+    }
+    fn try_conv2d_rect<const SH: usize, const SW: usize, const PH: usize, const PW: usize>(
+        self,
+        filters: F,
+    ) -> Result<Self::Output, Self::Err>
+    where
+        Self: TryConv2DRectTo<F, SH, SW, PH, PW>,
+    {
+        self.try_conv2d_rect_to(filters)
+    }
 }
 
 impl<T, F> TryConv2D<F> for T {}
@@ -121,33 +172,37 @@ impl<
         const H: usize,
         const W: usize,
         const O: usize,
-        const K: usize,
-        const S: usize,
-        const P: usize,
+        const KH: usize,
+        const KW: usize,
+        const SH: usize,
+        const SW: usize,
+        const PH: usize,
+        const PW: usize,
         E: Dtype,
         D: Conv2DKernel<E> + ZerosTensor<E>,
         T: 'static + Tape<E, D>,
-    > TryConv2DTo<Tensor<Rank4<O, C, K, K>, E, D>, S, P> for Tensor<Rank3<C, H, W>, E, D, T>
+    > TryConv2DRectTo<Tensor<Rank4<O, C, KH, KW>, E, D>, SH, SW, PH, PW>
+    for Tensor<Rank3<C, H, W>, E, D, T>
 where
-    Const<H>: ConvAlgebra<K, S, P>,
-    Const<W>: ConvAlgebra<K, S, P>,
+    Const<H>: ConvAlgebra<KH, SH, PH>,
+    Const<W>: ConvAlgebra<KW, SW, PW>,
 {
     type Output = Tensor<
         (
             Const<O>,
-            <Const<H> as ConvAlgebra<K, S, P>>::Convolved,
-            <Const<W> as ConvAlgebra<K, S, P>>::Convolved,
+            <Const<H> as ConvAlgebra<KH, SH, PH>>::Convolved,
+            <Const<W> as ConvAlgebra<KW, SW, PW>>::Convolved,
         ),
         E,
         D,
         T,
     >;
 
-    fn try_conv2d_to(
+    fn try_conv2d_rect_to(
         self,
-        filters: Tensor<Rank4<O, C, K, K>, E, D>,
+        filters: Tensor<Rank4<O, C, KH, KW>, E, D>,
     ) -> Result<Self::Output, Self::Err> {
-        let op = Conv2DOp::new(S, P, K, [1, C, H, W], O);
+        let op = Conv2DOp::new([SH, SW], [PH, PW], [KH, KW], [1, C, H, W], O);
         let (lhs, ltape) = self.split_tape();
         let (rhs, rtape) = filters.split_tape();
         let mut tape = ltape.merge(rtape);
@@ -157,7 +212,7 @@ where
         tape.try_alloc_grad(&lhs)?;
         tape.try_alloc_grad(&rhs)?;
         tape.try_alloc_grad(&out)?;
-        tape.add_backward_op(move |grads| {
+        tape.add_reusable_backward_op(move |grads| {
             let (grad_lhs, grad_rhs, grad_out) = grads.muts_and_ref(&lhs, &rhs, &phantom_out);
             lhs.device
                 .backward(op, &lhs, grad_lhs, &rhs, grad_rhs, &phantom_out, grad_out)
@@ -172,35 +227,38 @@ impl<
         const H: usize,
         const W: usize,
         const O: usize,
-        const K: usize,
-        const S: usize,
-        const P: usize,
+        const KH: usize,
+        const KW: usize,
+        const SH: usize,
+        const SW: usize,
+        const PH: usize,
+        const PW: usize,
         E: Dtype,
         D: Conv2DKernel<E> + ZerosTensor<E>,
         T: 'static + Tape<E, D>,
-    > TryConv2DTo<Tensor<Rank4<O, C, K, K>, E, D>, S, P>
+    > TryConv2DRectTo<Tensor<Rank4<O, C, KH, KW>, E, D>, SH, SW, PH, PW>
     for Tensor<(B, Const<C>, Const<H>, Const<W>), E, D, T>
 where
-    Const<H>: ConvAlgebra<K, S, P>,
-    Const<W>: ConvAlgebra<K, S, P>,
+    Const<H>: ConvAlgebra<KH, SH, PH>,
+    Const<W>: ConvAlgebra<KW, SW, PW>,
 {
     type Output = Tensor<
         (
             B,
             Const<O>,
-            <Const<H> as ConvAlgebra<K, S, P>>::Convolved,
-            <Const<W> as ConvAlgebra<K, S, P>>::Convolved,
+            <Const<H> as ConvAlgebra<KH, SH, PH>>::Convolved,
+            <Const<W> as ConvAlgebra<KW, SW, PW>>::Convolved,
         ),
         E,
         D,
         T,
     >;
-    fn try_conv2d_to(
+    fn try_conv2d_rect_to(
         self,
-        filters: Tensor<Rank4<O, C, K, K>, E, D>,
+        filters: Tensor<Rank4<O, C, KH, KW>, E, D>,
     ) -> Result<Self::Output, Self::Err> {
         let batch = self.shape().0;
-        let op = Conv2DOp::new(S, P, K, [batch.size(), C, H, W], O);
+        let op = Conv2DOp::new([SH, SW], [PH, PW], [KH, KW], [batch.size(), C, H, W], O);
         let (lhs, ltape) = self.split_tape();
         let (rhs, rtape) = filters.split_tape();
         let mut out =
@@ -212,7 +270,7 @@ where
         tape.try_alloc_grad(&lhs)?;
         tape.try_alloc_grad(&rhs)?;
         tape.try_alloc_grad(&out)?;
-        tape.add_backward_op(move |grads| {
+        tape.add_reusable_backward_op(move |grads| {
             let (grad_lhs, grad_rhs, grad_out) = grads.muts_and_ref(&lhs, &rhs, &phantom_out);
             lhs.device
                 .backward(op, &lhs, grad_lhs, &rhs, grad_rhs, &phantom_out, grad_out)?;
@@ -222,6 +280,99 @@ where
     }
 }
 
+impl<
+        const C: usize,
+        const O: usize,
+        const KH: usize,
+        const KW: usize,
+        const SH: usize,
+        const SW: usize,
+        const PH: usize,
+        const PW: usize,
+        E: Dtype,
+        D: Conv2DKernel<E> + ZerosTensor<E>,
+        T: 'static + Tape<E, D>,
+    > TryConv2DRectTo<Tensor<Rank4<O, C, KH, KW>, E, D>, SH, SW, PH, PW>
+    for Tensor<(Const<C>, usize, usize), E, D, T>
+{
+    type Output = Tensor<(Const<O>, usize, usize), E, D, T>;
+
+    fn try_conv2d_rect_to(
+        self,
+        filters: Tensor<Rank4<O, C, KH, KW>, E, D>,
+    ) -> Result<Self::Output, Self::Err> {
+        let &(_, h, w) = self.shape();
+        assert!(
+            h + 2 * PH >= KH && w + 2 * PW >= KW,
+            "Image is smaller than the kernel"
+        );
+        let op = Conv2DOp::new([SH, SW], [PH, PW], [KH, KW], [1, C, h, w], O);
+        let (lhs, ltape) = self.split_tape();
+        let (rhs, rtape) = filters.split_tape();
+        let mut tape = ltape.merge(rtape);
+        let mut out = lhs.device.try_zeros_like(&(Const, op.h_out, op.w_out))?;
+        lhs.device.forward(op, &lhs, &rhs, &mut out)?;
+        let phantom_out = out.clone();
+        tape.try_alloc_grad(&lhs)?;
+        tape.try_alloc_grad(&rhs)?;
+        tape.try_alloc_grad(&out)?;
+        tape.add_reusable_backward_op(move |grads| {
+            let (grad_lhs, grad_rhs, grad_out) = grads.muts_and_ref(&lhs, &rhs, &phantom_out);
+            lhs.device
+                .backward(op, &lhs, grad_lhs, &rhs, grad_rhs, &phantom_out, grad_out)
+        });
+        Ok(out.put_tape(tape))
+    }
+}
+
+impl<
+        B: Dim,
+        const C: usize,
+        const O: usize,
+        const KH: usize,
+        const KW: usize,
+        const SH: usize,
+        const SW: usize,
+        const PH: usize,
+        const PW: usize,
+        E: Dtype,
+        D: Conv2DKernel<E> + ZerosTensor<E>,
+        T: 'static + Tape<E, D>,
+    > TryConv2DRectTo<Tensor<Rank4<O, C, KH, KW>, E, D>, SH, SW, PH, PW>
+    for Tensor<(B, Const<C>, usize, usize), E, D, T>
+{
+    type Output = Tensor<(B, Const<O>, usize, usize), E, D, T>;
+
+    fn try_conv2d_rect_to(
+        self,
+        filters: Tensor<Rank4<O, C, KH, KW>, E, D>,
+    ) -> Result<Self::Output, Self::Err> {
+        let &(batch, _, h, w) = self.shape();
+        assert!(
+            h + 2 * PH >= KH && w + 2 * PW >= KW,
+            "Image is smaller than the kernel"
+        );
+        let op = Conv2DOp::new([SH, SW], [PH, PW], [KH, KW], [batch.size(), C, h, w], O);
+        let (lhs, ltape) = self.split_tape();
+        let (rhs, rtape) = filters.split_tape();
+        let mut out = lhs
+            .device
+            .try_zeros_like(&(batch, Const, op.h_out, op.w_out))?;
+        let mut tape = ltape.merge(rtape);
+        lhs.device.forward(op, &lhs, &rhs, &mut out)?;
+        let phantom_out = out.clone();
+        tape.try_alloc_grad(&lhs)?;
+        tape.try_alloc_grad(&rhs)?;
+        tape.try_alloc_grad(&out)?;
+        tape.add_reusable_backward_op(move |grads| {
+            let (grad_lhs, grad_rhs, grad_out) = grads.muts_and_ref(&lhs, &rhs, &phantom_out);
+            lhs.device
+                .backward(op, &lhs, grad_lhs, &rhs, grad_rhs, &phantom_out, grad_out)
+        });
+        Ok(out.put_tape(tape))
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -448,6 +599,78 @@ mod tests {
         assert_close(&g.get(&bias).array(), &[0.55381978, 0.55677116, 0.30686682]);
     }
 
+    #[test]
+    fn test_conv2d_rect_kernel_3x1() {
+        let dev: TestDevice = Default::default();
+        let weight: Tensor<_, TestDtype, _> =
+            dev.tensor([[[[0.2], [-0.3], [0.5]]], [[[-0.6], [0.1], [0.4]]]]);
+        let x: Tensor<_, TestDtype, _> = dev.tensor([[
+            [0.1, -0.4, 0.7],
+            [0.3, 0.9, -0.2],
+            [-0.5, 0.2, 0.6],
+            [0.8, -0.1, 0.4],
+        ]]);
+        let result = x.trace().conv2d_rect::<1, 1, 0, 0>(weight.clone());
+        // out[o, i, j] = sum_k weight[o, 0, k, 0] * x[0, i + k, j]
+        assert_close(
+            &result.array(),
+            &[
+                [[-0.32, -0.25, 0.5], [0.61, 0.07, -0.02]],
+                [[-0.23, 0.41, -0.2], [0.09, -0.56, 0.34]],
+            ],
+        );
+        let g = result.exp().mean().backward();
+        assert_close(
+            &g.get(&x).array(),
+            &[[
+                [-0.0276242, -0.06236088, -0.01345785],
+                [-0.03556747, -0.01759852, -0.08830601],
+                [0.019848, 0.06062466, 0.08319067],
+                [0.11315712, 0.06372814, 0.0876732],
+            ]],
+        );
+        assert_close(
+            &g.get(&weight).array(),
+            &[
+                [[[0.18637888], [0.03928553], [0.211591]]],
+                [[[0.05093239], [0.1534061], [0.14796106]]],
+            ],
+        );
+    }
+
+    #[test]
+    fn test_conv2d_rect_strides_and_padding() {
+        let dev: TestDevice = Default::default();
+        let weight: Tensor<Rank4<3, 2, 3, 1>, TestDtype, _> = dev.sample_normal();
+        let x: Tensor<Rank4<2, 2, 7, 5>, TestDtype, _> = dev.sample_normal();
+        let _: Tensor<Rank4<2, 3, 3, 7>, TestDtype, _> =
+            x.clone().conv2d_rect::<2, 1, 0, 1>(weight.clone());
+
+        // the square version is the same as equal height and width parameters
+        let weight: Tensor<Rank4<3, 2, 2, 2>, TestDtype, _> = dev.sample_normal();
+        let a = x.clone().conv2d::<2, 1>(weight.clone());
+        let b = x.conv2d_rect::<2, 2, 1, 1>(weight);
+        assert_close(&a.array(), &b.array());
+    }
+
+    #[test]
+    fn test_conv2d_runtime_spatial_dims() {
+        let dev: TestDevice = Default::default();
+        let weight: Tensor<Rank4<3, 2, 2, 3>, TestDtype, _> = dev.sample_normal();
+        let y: Tensor<Rank4<2, 2, 6, 5>, TestDtype, _> = dev.sample_normal();
+        let x: Tensor<(Const<2>, Const<2>, usize, usize), TestDtype, _> =
+            dev.tensor_from_vec(y.as_vec(), (Const, Const, 6, 5));
+
+        let r = x.trace().conv2d::<2, 1>(weight.clone());
+        assert_eq!(r.shape(), &(Const::<2>, Const::<3>, 4, 3));
+        let r2 = y.trace().conv2d::<2, 1>(weight.clone());
+        assert_eq!(r.as_vec(), r2.as_vec());
+        let g = r.exp().mean().backward();
+        let g2 = r2.exp().mean().backward();
+        assert_eq!(g.get(&x).as_vec(), g2.get(&y).as_vec());
+        assert_close(&g.get(&weight).array(), &g2.get(&weight).array());
+    }
+
     #[test]
     fn test_conv2d_s4p3k2() {
         let dev = TestDevice::seed_from_u64(432);
@@ -0,0 +1,124 @@
+use crate::{
+    gradients::{Merge, Tape},
+    shapes::{Axes, Dtype, ReduceShape, Shape},
+    tensor::{HasErr, Tensor},
+};
+
+use super::{Device, SumTo, TryDiv, TryMul};
+
+/// Cosine similarity of `lhs` and `rhs` along `Ax`: `sum(lhs * rhs) / max(|lhs| * |rhs|, epsilon)`.
+///
+/// The gradient is finite even if one of the slices is all zeros.
+///
+/// **Pytorch equivalent**: `torch.nn.functional.cosine_similarity(lhs, rhs, dim, eps)`
+///
+/// Example:
+/// ```rust
+/// # use dfdx::prelude::*;
+/// # let dev: Cpu = Default::default();
+/// let a = dev.tensor([[1.0, 0.0], [3.0, 4.0]]);
+/// let b = dev.tensor([[0.0, 1.0], [6.0, 8.0]]);
+/// let r = a.cosine_similarity::<Axis<1>, _>(b, 1e-8);
+/// assert_eq!(r.array(), [0.0, 1.0]);
+/// ```
+pub fn cosine_similarity<
+    Ax: Axes,
+    S: Shape + ReduceShape<Ax>,
+    E: Dtype,
+    D: Device<E>,
+    T: Tape<E, D> + Merge<R>,
+    R: Tape<E, D>,
+>(
+    lhs: Tensor<S, E, D, T>,
+    rhs: Tensor<S, E, D, R>,
+    epsilon: E,
+) -> Tensor<S::Reduced, E, D, T> {
+    lhs.cosine_similarity::<Ax, R>(rhs, epsilon)
+}
+
+impl<S: Shape, E: Dtype, D: Device<E>, T: Tape<E, D>> Tensor<S, E, D, T> {
+    /// See [cosine_similarity]
+    pub fn cosine_similarity<Ax: Axes, R: Tape<E, D>>(
+        self,
+        rhs: Tensor<S, E, D, R>,
+        epsilon: E,
+    ) -> Tensor<S::Reduced, E, D, T>
+    where
+        S: ReduceShape<Ax>,
+        T: Merge<R>,
+    {
+        self.try_cosine_similarity::<Ax, R>(rhs, epsilon).unwrap()
+    }
+
+    /// See [cosine_similarity]
+    pub fn try_cosine_similarity<Ax: Axes, R: Tape<E, D>>(
+        self,
+        rhs: Tensor<S, E, D, R>,
+        epsilon: E,
+    ) -> Result<Tensor<S::Reduced, E, D, T>, <Self as HasErr>::Err>
+    where
+        S: ReduceShape<Ax>,
+        T: Merge<R>,
+    {
+        let dot = self
+            .retaped::<T>()
+            .try_mul(rhs.retaped::<R>())?
+            .try_sum::<_, Ax>()?;
+        // the gradient of sqrt is infinite at 0, so the norms are computed with
+        // [Tensor::norm], which gives all-zero slices a zero gradient
+        let lhs_norm = self.try_norm::<Ax>(E::from_f32(2.0).unwrap())?;
+        let rhs_norm = rhs.try_norm::<Ax>(E::from_f32(2.0).unwrap())?;
+        let norm = lhs_norm.try_mul(rhs_norm)?.try_maximum_scalar(epsilon)?;
+        dot.try_div(norm)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::tests::*;
+    use crate::{shapes::*, tensor::*, tensor_ops::*};
+
+    #[test]
+    fn test_cosine_similarity() {
+        let dev: TestDevice = Default::default();
+        let a: Tensor<_, TestDtype, _> = dev.tensor([[1.0, 2.0, 3.0], [1.0, 0.0, 0.0]]);
+        let b: Tensor<_, TestDtype, _> = dev.tensor([[-1.0, 0.5, 2.0], [3.0, 4.0, 0.0]]);
+        let r = a.trace().cosine_similarity::<Axis<1>, _>(b.trace(), 1e-8);
+        assert_close(&r.array(), &[0.6998542, 0.6]);
+
+        let g = r.sum().backward();
+        assert_close(
+            &g.get(&a).array(),
+            &[[-0.16663196, -0.04165799, 0.08331598], [0.0, 0.8, 0.0]],
+        );
+        assert_close(
+            &g.get(&b).array(),
+            &[[0.24994793, 0.16663195, 0.08331598], [0.128, -0.096, 0.0]],
+        );
+    }
+
+    #[test]
+    fn test_cosine_similarity_matches_dot() {
+        let dev: TestDevice = Default::default();
+        let a: Tensor<Rank1<4>, TestDtype, _> = dev.sample_normal();
+        let b: Tensor<Rank1<4>, TestDtype, _> = dev.sample_normal();
+        let r = a.clone().cosine_similarity::<Axis<0>, _>(b.clone(), 1e-8);
+        let a_norm = a.clone().square().sum().sqrt().array();
+        let b_norm = b.clone().square().sum().sqrt().array();
+        assert_close(&r.array(), &(a.dot(b).array() / (a_norm * b_norm)));
+    }
+
+    #[test]
+    fn test_cosine_similarity_zero_vector() {
+        let dev: TestDevice = Default::default();
+        let a: Tensor<_, TestDtype, _> = dev.tensor([[0.0, 0.0, 0.0], [1.0, 0.0, 0.0]]);
+        let b: Tensor<_, TestDtype, _> = dev.tensor([[1.0, 2.0, 3.0], [3.0, 4.0, 0.0]]);
+        let r = a.trace().cosine_similarity::<Axis<1>, _>(b.trace(), 0.5);
+        assert_close(&r.array(), &[0.0, 0.6]);
+
+        // the norm of the first row is clamped to epsilon, so its gradient is b / 0.5
+        let g = r.sum().backward();
+        assert_close(&g.get(&a).array(), &[[2.0, 4.0, 6.0], [0.0, 0.8, 0.0]]);
+        assert_close(&g.get(&b).array(), &[[0.0; 3], [0.128, -0.096, 0.0]]);
+    }
+}
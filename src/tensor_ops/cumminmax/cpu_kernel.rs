@@ -0,0 +1,60 @@
+use crate::{
+    shapes::{Dtype, Shape},
+    tensor::{
+        cpu::{index_to_i, Cpu, LendingIterator},
+        Tensor, ZerosTensor,
+    },
+};
+use num_traits::Float;
+
+impl<E: Dtype + Float> super::CumMinMaxKernel<E> for Cpu {
+    fn forward<S: Shape>(
+        &self,
+        ax: usize,
+        is_max: bool,
+        inp: &Tensor<S, E, Self>,
+    ) -> Result<(Tensor<S, E, Self>, Tensor<S, usize, Self>), Self::Err> {
+        let mut out: Tensor<S, E, Self> = self.try_zeros_like(&inp.shape)?;
+        let mut idx: Tensor<S, usize, Self> = self.try_zeros_like(&inp.shape)?;
+        // NOTE: out & idx are contiguous, so the previous element along `ax`
+        // is always `stride` elements before the current one.
+        let stride = inp.shape.strides()[ax];
+        let out_buf = std::sync::Arc::make_mut(&mut out.data);
+        let idx_buf = std::sync::Arc::make_mut(&mut idx.data);
+        let mut inp_iter = inp.iter_with_index();
+        let mut i = 0;
+        while let Some((&x, i_inp)) = inp_iter.next() {
+            let replace = i_inp[ax] == 0 || {
+                let prev = out_buf[i - stride];
+                !prev.is_nan() && (x.is_nan() || if is_max { x >= prev } else { x <= prev })
+            };
+            if replace {
+                out_buf[i] = x;
+                idx_buf[i] = i_inp[ax];
+            } else {
+                out_buf[i] = out_buf[i - stride];
+                idx_buf[i] = idx_buf[i - stride];
+            }
+            i += 1;
+        }
+        Ok((out, idx))
+    }
+
+    fn backward<S: Shape>(
+        &self,
+        ax: usize,
+        inp: &Tensor<S, E, Self>,
+        grad_inp: &mut Self::Vec<E>,
+        idx: &Tensor<S, usize, Self>,
+        grad_out: &Self::Vec<E>,
+    ) -> Result<(), Self::Err> {
+        let mut idx_iter = idx.iter_with_index();
+        let mut i = 0;
+        while let Some((&j, mut i_inp)) = idx_iter.next() {
+            i_inp[ax] = j;
+            grad_inp[index_to_i(&inp.shape, &inp.strides, i_inp)] += grad_out[i];
+            i += 1;
+        }
+        Ok(())
+    }
+}
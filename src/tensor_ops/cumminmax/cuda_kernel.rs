@@ -0,0 +1,99 @@
+use crate::{
+    shapes::*,
+    tensor::{Cuda, Tensor},
+};
+use cudarc::driver::{CudaSlice, DeviceSlice, LaunchAsync, LaunchConfig};
+
+const PTX_SRC: &str = include_str!(concat!(env!("OUT_DIR"), "/cumminmax.ptx"));
+
+pub(crate) trait HasCudaKernel<E> {
+    const MOD: &'static str;
+    const FNS: &'static [&'static str];
+}
+
+impl HasCudaKernel<f32> for Cuda {
+    const MOD: &'static str = "cumminmax_f32";
+    const FNS: &'static [&'static str] = &["cumminmax_fwd_f32", "cumminmax_bwd_f32"];
+}
+
+impl HasCudaKernel<f64> for Cuda {
+    const MOD: &'static str = "cumminmax_f64";
+    const FNS: &'static [&'static str] = &["cumminmax_fwd_f64", "cumminmax_bwd_f64"];
+}
+
+impl<E: Dtype> super::CumMinMaxKernel<E> for Cuda
+where
+    Self: HasCudaKernel<E>,
+{
+    fn forward<S: Shape>(
+        &self,
+        ax: usize,
+        is_max: bool,
+        inp: &Tensor<S, E, Self>,
+    ) -> Result<(Tensor<S, E, Self>, Tensor<S, usize, Self>), Self::Err> {
+        if !self.dev.has_func(Self::MOD, Self::FNS[0]) {
+            self.dev.load_ptx(PTX_SRC.into(), Self::MOD, Self::FNS)?;
+        }
+
+        let shape = inp.shape;
+        let strides = shape.strides();
+        let numel = shape.num_elements();
+        let mut out = self.dev.alloc_zeros::<E>(numel)?;
+        let mut idx = self.dev.alloc_zeros::<usize>(numel)?;
+
+        // each thread scans one line along `ax`
+        let num_lines = numel / shape.concrete()[ax].max(1);
+        let dims: CudaSlice<usize> = self.dev.htod_copy(shape.concrete().into())?;
+        let inp_strides: CudaSlice<usize> = self.dev.htod_copy(inp.strides.into())?;
+        let out_strides: CudaSlice<usize> = self.dev.htod_copy(strides.into())?;
+
+        let fwd_fn = self.dev.get_func(Self::MOD, Self::FNS[0]).unwrap();
+        let cfg = LaunchConfig::for_num_elems(num_lines as u32);
+        let params = (
+            num_lines,         // const size_t num_lines,
+            S::NUM_DIMS,       // const size_t num_dims,
+            ax,                // const size_t ax,
+            &dims,             // const size_t *dims,
+            inp.data.as_ref(), // const float *inp,
+            &inp_strides,      // const size_t *inp_strides,
+            &out_strides,      // const size_t *out_strides,
+            is_max,            // const bool is_max,
+            &mut out,          // float *out,
+            &mut idx,          // size_t *idx,
+        );
+        unsafe { fwd_fn.launch(cfg, params) }?;
+        Ok((
+            self.build_tensor(shape, strides, out),
+            self.build_tensor(shape, strides, idx),
+        ))
+    }
+
+    fn backward<S: Shape>(
+        &self,
+        ax: usize,
+        inp: &Tensor<S, E, Self>,
+        grad_inp: &mut Self::Vec<E>,
+        idx: &Tensor<S, usize, Self>,
+        grad_out: &Self::Vec<E>,
+    ) -> Result<(), Self::Err> {
+        let bwd_fn = self.dev.get_func(Self::MOD, Self::FNS[1]).unwrap();
+        let numel = grad_out.len();
+
+        let dims: CudaSlice<usize> = self.dev.htod_copy(inp.shape.concrete().into())?;
+        let inp_strides: CudaSlice<usize> = self.dev.htod_copy(inp.strides.into())?;
+
+        let cfg = LaunchConfig::for_num_elems(numel as u32);
+        let params = (
+            numel,             // const size_t numel,
+            S::NUM_DIMS,       // const size_t num_dims,
+            ax,                // const size_t ax,
+            &dims,             // const size_t *dims,
+            &inp_strides,      // const size_t *inp_strides,
+            idx.data.as_ref(), // const size_t *idx,
+            grad_inp,          // float *grad_inp,
+            grad_out,          // const float *grad_out,
+        );
+        unsafe { bwd_fn.launch(cfg, params) }?;
+        Ok(())
+    }
+}
@@ -0,0 +1,107 @@
+#include "cuda_utils.cuh"
+
+// Each thread scans a single line of `inp` along `ax`, writing the running
+// extremum and the index along `ax` that it came from.
+template<typename T>
+__device__ void cumminmax_fwd(
+    const size_t num_lines,
+    const size_t num_dims,
+    const size_t ax,
+    const size_t *dims,
+    const T *inp,
+    const size_t *inp_strides,
+    const size_t *out_strides,
+    const bool is_max,
+    T *out,
+    size_t *idx
+) {
+    unsigned int line = blockIdx.x * blockDim.x + threadIdx.x;
+    if (line >= num_lines) {
+        return;
+    }
+
+    // position of the first element of this line, skipping over `ax`
+    size_t inp_i = 0;
+    size_t out_i = 0;
+    unsigned int tmp = line;
+    for (int d = num_dims - 1; d >= 0; d--) {
+        if (d == ax) {
+            continue;
+        }
+        size_t j = tmp % dims[d];
+        inp_i += j * inp_strides[d];
+        out_i += j * out_strides[d];
+        tmp /= dims[d];
+    }
+
+    T best = inp[inp_i];
+    size_t best_j = 0;
+    for (size_t j = 0; j < dims[ax]; j++) {
+        T x = inp[inp_i + j * inp_strides[ax]];
+        if (j > 0 && !isnan(best) && (isnan(x) || (is_max ? x >= best : x <= best))) {
+            best = x;
+            best_j = j;
+        }
+        out[out_i + j * out_strides[ax]] = best;
+        idx[out_i + j * out_strides[ax]] = best_j;
+    }
+}
+
+template<typename T>
+__device__ void cumminmax_bwd(
+    const size_t numel,
+    const size_t num_dims,
+    const size_t ax,
+    const size_t *dims,
+    const size_t *inp_strides,
+    const size_t *idx,
+    T *grad_inp,
+    const T *grad_out
+) {
+    unsigned int i = blockIdx.x * blockDim.x + threadIdx.x;
+    if (i >= numel) {
+        return;
+    }
+
+    // out is contiguous, so `i` is also the position in `idx` & `grad_out`
+    size_t inp_i = 0;
+    unsigned int tmp = i;
+    for (int d = num_dims - 1; d >= 0; d--) {
+        size_t j = d == ax ? idx[i] : tmp % dims[d];
+        inp_i += j * inp_strides[d];
+        tmp /= dims[d];
+    }
+
+    atomicAdd(grad_inp + inp_i, grad_out[i]);
+}
+
+#define CUMMINMAX(TYPENAME, FWD, BWD) \
+extern "C" __global__ void FWD( \
+    const size_t num_lines, \
+    const size_t num_dims, \
+    const size_t ax, \
+    const size_t *dims, \
+    const TYPENAME *inp, \
+    const size_t *inp_strides, \
+    const size_t *out_strides, \
+    const bool is_max, \
+    TYPENAME *out, \
+    size_t *idx \
+) { \
+    cumminmax_fwd(num_lines, num_dims, ax, dims, inp, inp_strides, out_strides, is_max, out, idx); \
+} \
+extern "C" __global__ void BWD( \
+    const size_t numel, \
+    const size_t num_dims, \
+    const size_t ax, \
+    const size_t *dims, \
+    const size_t *inp_strides, \
+    const size_t *idx, \
+    TYPENAME *grad_inp, \
+    const TYPENAME *grad_out \
+) { \
+    cumminmax_bwd(numel, num_dims, ax, dims, inp_strides, idx, grad_inp, grad_out); \
+}
+
+CUMMINMAX(float, cumminmax_fwd_f32, cumminmax_bwd_f32);
+CUMMINMAX(double, cumminmax_fwd_f64, cumminmax_bwd_f64);
@@ -0,0 +1,208 @@
+#![allow(clippy::type_complexity)]
+
+mod cpu_kernel;
+
+#[cfg(feature = "cuda")]
+mod cuda_kernel;
+
+use crate::{gradients::Tape, shapes::*, tensor::*};
+
+pub trait CumMinMaxKernel<E: Dtype>: DeviceStorage {
+    /// Returns the running extremum of `inp` along `ax`, and the index along `ax`
+    /// where each value came from.
+    fn forward<S: Shape>(
+        &self,
+        ax: usize,
+        is_max: bool,
+        inp: &Tensor<S, E, Self>,
+    ) -> Result<(Tensor<S, E, Self>, Tensor<S, usize, Self>), Self::Err>;
+    fn backward<S: Shape>(
+        &self,
+        ax: usize,
+        inp: &Tensor<S, E, Self>,
+        grad_inp: &mut Self::Vec<E>,
+        idx: &Tensor<S, usize, Self>,
+        grad_out: &Self::Vec<E>,
+    ) -> Result<(), Self::Err>;
+}
+
+fn try_cum_min_max<
+    Ax: Axes<Array = [isize; 1]>,
+    S: Shape + HasAxes<Ax>,
+    E: Dtype,
+    D: CumMinMaxKernel<E>,
+    T: Tape<E, D>,
+>(
+    t: Tensor<S, E, D, T>,
+    is_max: bool,
+) -> Result<(Tensor<S, E, D, T>, Tensor<S, usize, D>), D::Err> {
+    let ax = Ax::as_array()[0] as usize;
+    let (inp, mut tape) = t.split_tape();
+    let (out, idx) = inp.device.forward(ax, is_max, &inp)?;
+    let phantom_out = out.clone();
+    let phantom_idx = idx.clone();
+    tape.try_alloc_grad(&inp)?;
+    tape.try_alloc_grad(&out)?;
+    tape.add_reusable_backward_op(move |grads| {
+        let (grad_inp, grad_out) = grads.mut_and_ref(&inp, &phantom_out);
+        inp.device
+            .backward(ax, &inp, grad_inp, &phantom_idx, grad_out)
+    });
+    Ok((out.put_tape(tape), idx))
+}
+
+impl<S: Shape, E: Dtype, D: CumMinMaxKernel<E>, T: Tape<E, D>> Tensor<S, E, D, T> {
+    /// The running maximum along axis `Ax`, i.e. each element is the maximum of all
+    /// elements up to and including it along that axis. NaNs are propagated.
+    ///
+    /// The gradient of each output element goes to the input element that was the
+    /// running maximum at that position. On ties the later element is used.
+    ///
+    /// **Pytorch equivalent**: `torch.cummax(t, dim=Ax).values`
+    ///
+    /// Example:
+    /// ```rust
+    /// # use dfdx::prelude::*;
+    /// # let dev: Cpu = Default::default();
+    /// let t = dev.tensor([[1.0, 3.0, 2.0, 5.0], [0.0, -1.0, 4.0, 4.0]]);
+    /// let r = t.cummax::<Axis<1>>();
+    /// assert_eq!(r.array(), [[1.0, 3.0, 3.0, 5.0], [0.0, 0.0, 4.0, 4.0]]);
+    /// ```
+    pub fn cummax<Ax: Axes<Array = [isize; 1]>>(self) -> Self
+    where
+        S: HasAxes<Ax>,
+    {
+        self.try_cummax::<Ax>().unwrap()
+    }
+    /// Fallible version of [Tensor::cummax]
+    pub fn try_cummax<Ax: Axes<Array = [isize; 1]>>(self) -> Result<Self, D::Err>
+    where
+        S: HasAxes<Ax>,
+    {
+        Ok(try_cum_min_max::<Ax, S, E, D, T>(self, true)?.0)
+    }
+
+    /// [Tensor::cummax], but also returns the index along `Ax` of each running maximum.
+    ///
+    /// **Pytorch equivalent**: `torch.cummax(t, dim=Ax)`
+    ///
+    /// Example:
+    /// ```rust
+    /// # use dfdx::prelude::*;
+    /// # let dev: Cpu = Default::default();
+    /// let t = dev.tensor([1.0, 3.0, 2.0, 3.0]);
+    /// let (r, idx) = t.cummax_with_indices::<Axis<0>>();
+    /// assert_eq!(r.array(), [1.0, 3.0, 3.0, 3.0]);
+    /// assert_eq!(idx.array(), [0, 1, 1, 3]);
+    /// ```
+    pub fn cummax_with_indices<Ax: Axes<Array = [isize; 1]>>(self) -> (Self, Tensor<S, usize, D>)
+    where
+        S: HasAxes<Ax>,
+    {
+        self.try_cummax_with_indices::<Ax>().unwrap()
+    }
+    /// Fallible version of [Tensor::cummax_with_indices]
+    pub fn try_cummax_with_indices<Ax: Axes<Array = [isize; 1]>>(
+        self,
+    ) -> Result<(Self, Tensor<S, usize, D>), D::Err>
+    where
+        S: HasAxes<Ax>,
+    {
+        try_cum_min_max::<Ax, S, E, D, T>(self, true)
+    }
+
+    /// The running minimum along axis `Ax`. See [Tensor::cummax].
+    ///
+    /// **Pytorch equivalent**: `torch.cummin(t, dim=Ax).values`
+    pub fn cummin<Ax: Axes<Array = [isize; 1]>>(self) -> Self
+    where
+        S: HasAxes<Ax>,
+    {
+        self.try_cummin::<Ax>().unwrap()
+    }
+    /// Fallible version of [Tensor::cummin]
+    pub fn try_cummin<Ax: Axes<Array = [isize; 1]>>(self) -> Result<Self, D::Err>
+    where
+        S: HasAxes<Ax>,
+    {
+        Ok(try_cum_min_max::<Ax, S, E, D, T>(self, false)?.0)
+    }
+
+    /// [Tensor::cummin], but also returns the index along `Ax` of each running minimum.
+    ///
+    /// **Pytorch equivalent**: `torch.cummin(t, dim=Ax)`
+    pub fn cummin_with_indices<Ax: Axes<Array = [isize; 1]>>(self) -> (Self, Tensor<S, usize, D>)
+    where
+        S: HasAxes<Ax>,
+    {
+        self.try_cummin_with_indices::<Ax>().unwrap()
+    }
+    /// Fallible version of [Tensor::cummin_with_indices]
+    pub fn try_cummin_with_indices<Ax: Axes<Array = [isize; 1]>>(
+        self,
+    ) -> Result<(Self, Tensor<S, usize, D>), D::Err>
+    where
+        S: HasAxes<Ax>,
+    {
+        try_cum_min_max::<Ax, S, E, D, T>(self, false)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{tensor_ops::*, tests::*};
+
+    #[test]
+    fn test_cummax_1d() {
+        let dev: TestDevice = Default::default();
+        let t: Tensor<_, TestDtype, _> = dev.tensor([1.0, -2.0, 3.0, 3.0, 0.5, 4.0]);
+        let (r, idx) = t.trace().cummax_with_indices::<Axis<0>>();
+        assert_eq!(r.array(), [1.0, 1.0, 3.0, 3.0, 3.0, 4.0]);
+        assert_eq!(idx.array(), [0, 0, 2, 3, 3, 5]);
+
+        let w: Tensor<_, TestDtype, _> = dev.tensor([1.0, 2.0, 3.0, 4.0, 5.0, 6.0]);
+        let g = (r * w).sum().backward();
+        assert_eq!(g.get(&t).array(), [3.0, 0.0, 3.0, 9.0, 0.0, 6.0]);
+    }
+
+    #[test]
+    fn test_cummin_1d() {
+        let dev: TestDevice = Default::default();
+        let t: Tensor<_, TestDtype, _> = dev.tensor([2.0, 3.0, -1.0, 0.0, -1.0]);
+        let (r, idx) = t.trace().cummin_with_indices::<Axis<0>>();
+        assert_eq!(r.array(), [2.0, 2.0, -1.0, -1.0, -1.0]);
+        assert_eq!(idx.array(), [0, 0, 2, 2, 4]);
+
+        let g = r.exp().sum().backward();
+        let (e2, e_1) = ((2.0 as TestDtype).exp(), (-1.0 as TestDtype).exp());
+        assert_close(&g.get(&t).array(), &[2.0 * e2, 0.0, 2.0 * e_1, 0.0, e_1]);
+    }
+
+    #[test]
+    fn test_cummax_2d_axes() {
+        let dev: TestDevice = Default::default();
+        let t: Tensor<_, TestDtype, _> = dev.tensor([[1.0, 5.0, 2.0], [3.0, 0.0, 6.0]]);
+
+        let r = t.trace().cummax::<Axis<0>>();
+        assert_eq!(r.array(), [[1.0, 5.0, 2.0], [3.0, 5.0, 6.0]]);
+        let g = r.sum().backward();
+        assert_eq!(g.get(&t).array(), [[1.0, 2.0, 1.0], [1.0, 0.0, 1.0]]);
+
+        let r = t.trace().cummin::<Axis<1>>();
+        assert_eq!(r.array(), [[1.0, 1.0, 1.0], [3.0, 0.0, 0.0]]);
+        let g = r.sum().backward();
+        assert_eq!(g.get(&t).array(), [[3.0, 0.0, 0.0], [1.0, 2.0, 0.0]]);
+    }
+
+    #[test]
+    fn test_cummax_nan() {
+        let dev: TestDevice = Default::default();
+        let t: Tensor<_, TestDtype, _> = dev.tensor([1.0, TestDtype::NAN, 3.0]);
+        let (r, idx) = t.cummax_with_indices::<Axis<0>>();
+        let r = r.array();
+        assert_eq!(r[0], 1.0);
+        assert!(r[1].is_nan() && r[2].is_nan());
+        assert_eq!(idx.array(), [0, 1, 1]);
+    }
+}
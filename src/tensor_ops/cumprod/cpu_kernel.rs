@@ -0,0 +1,74 @@
+use crate::{
+    shapes::{Dtype, Shape},
+    tensor::{
+        cpu::{Cpu, LendingIterator, NdIndex},
+        Tensor, ZerosTensor,
+    },
+};
+use std::vec::Vec;
+
+impl<E: Dtype> super::CumProdKernel<E> for Cpu {
+    fn forward<S: Shape>(
+        &self,
+        ax: usize,
+        inp: &Tensor<S, E, Self>,
+    ) -> Result<Tensor<S, E, Self>, Self::Err> {
+        let mut out: Tensor<S, E, Self> = self.try_zeros_like(&inp.shape)?;
+        // NOTE: out is contiguous, so the previous element along `ax`
+        // is always `stride` elements before the current one.
+        let stride = inp.shape.strides()[ax];
+        let out_buf = std::sync::Arc::make_mut(&mut out.data);
+        let mut inp_iter = inp.iter_with_index();
+        let mut i = 0;
+        while let Some((&x, i_inp)) = inp_iter.next() {
+            out_buf[i] = if i_inp[ax] == 0 {
+                x
+            } else {
+                out_buf[i - stride] * x
+            };
+            i += 1;
+        }
+        Ok(out)
+    }
+
+    fn backward<S: Shape>(
+        &self,
+        ax: usize,
+        inp: &Tensor<S, E, Self>,
+        grad_inp: &mut Self::Vec<E>,
+        grad_out: &Self::Vec<E>,
+    ) -> Result<(), Self::Err> {
+        let n = inp.shape.concrete()[ax];
+        let stride = inp.shape.strides()[ax];
+        let x: Vec<E> = inp.as_vec();
+        let numel = x.len();
+
+        // the gradient of x_i is `prod_{j<i} x_j * s_i`, where
+        // `s_i = sum_{k>=i} g_k * prod_{i<j<=k} x_j`. neither factor divides by x_i,
+        // so zeros in the input don't need special handling.
+        let mut suffix: Vec<E> = grad_out.clone();
+        for i in (0..numel).rev() {
+            let pos = (i / stride) % n;
+            if pos + 1 < n {
+                let next = x[i + stride] * suffix[i + stride];
+                suffix[i] += next;
+            }
+        }
+
+        let mut prefix: Vec<E> = std::vec![E::ONE; numel];
+        for i in 0..numel {
+            let pos = (i / stride) % n;
+            if pos > 0 {
+                prefix[i] = prefix[i - stride] * x[i - stride];
+            }
+        }
+
+        let mut inp_idx = NdIndex::new(inp.shape, inp.strides);
+        let mut i = 0;
+        while let Some(i_inp) = inp_idx.next() {
+            grad_inp[i_inp] += prefix[i] * suffix[i];
+            i += 1;
+        }
+        Ok(())
+    }
+}
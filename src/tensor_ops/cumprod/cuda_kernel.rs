@@ -0,0 +1,97 @@
+use crate::{
+    shapes::*,
+    tensor::{Cuda, Tensor},
+};
+use cudarc::driver::{CudaSlice, DeviceSlice, LaunchAsync, LaunchConfig};
+
+const PTX_SRC: &str = include_str!(concat!(env!("OUT_DIR"), "/cumprod.ptx"));
+
+pub(crate) trait HasCudaKernel<E> {
+    const MOD: &'static str;
+    const FNS: &'static [&'static str];
+}
+
+impl HasCudaKernel<f32> for Cuda {
+    const MOD: &'static str = "cumprod_f32";
+    const FNS: &'static [&'static str] = &["cumprod_fwd_f32", "cumprod_bwd_f32"];
+}
+
+impl HasCudaKernel<f64> for Cuda {
+    const MOD: &'static str = "cumprod_f64";
+    const FNS: &'static [&'static str] = &["cumprod_fwd_f64", "cumprod_bwd_f64"];
+}
+
+impl<E: Dtype> super::CumProdKernel<E> for Cuda
+where
+    Self: HasCudaKernel<E>,
+{
+    fn forward<S: Shape>(
+        &self,
+        ax: usize,
+        inp: &Tensor<S, E, Self>,
+    ) -> Result<Tensor<S, E, Self>, Self::Err> {
+        if !self.dev.has_func(Self::MOD, Self::FNS[0]) {
+            self.dev.load_ptx(PTX_SRC.into(), Self::MOD, Self::FNS)?;
+        }
+
+        let shape = inp.shape;
+        let strides = shape.strides();
+        let numel = shape.num_elements();
+        let mut out = self.dev.alloc_zeros::<E>(numel)?;
+
+        // each thread scans one line along `ax`
+        let num_lines = numel / shape.concrete()[ax].max(1);
+        let dims: CudaSlice<usize> = self.dev.htod_copy(shape.concrete().into())?;
+        let inp_strides: CudaSlice<usize> = self.dev.htod_copy(inp.strides.into())?;
+        let out_strides: CudaSlice<usize> = self.dev.htod_copy(strides.into())?;
+
+        let fwd_fn = self.dev.get_func(Self::MOD, Self::FNS[0]).unwrap();
+        let cfg = LaunchConfig::for_num_elems(num_lines as u32);
+        let params = (
+            num_lines,         // const size_t num_lines,
+            S::NUM_DIMS,       // const size_t num_dims,
+            ax,                // const size_t ax,
+            &dims,             // const size_t *dims,
+            inp.data.as_ref(), // const float *inp,
+            &inp_strides,      // const size_t *inp_strides,
+            &out_strides,      // const size_t *out_strides,
+            &mut out,          // float *out,
+        );
+        unsafe { fwd_fn.launch(cfg, params) }?;
+        Ok(self.build_tensor(shape, strides, out))
+    }
+
+    fn backward<S: Shape>(
+        &self,
+        ax: usize,
+        inp: &Tensor<S, E, Self>,
+        grad_inp: &mut Self::Vec<E>,
+        grad_out: &Self::Vec<E>,
+    ) -> Result<(), Self::Err> {
+        let bwd_fn = self.dev.get_func(Self::MOD, Self::FNS[1]).unwrap();
+        let shape = inp.shape;
+        let numel = grad_out.len();
+        let mut suffix = self.dev.alloc_zeros::<E>(numel)?;
+
+        let num_lines = numel / shape.concrete()[ax].max(1);
+        let dims: CudaSlice<usize> = self.dev.htod_copy(shape.concrete().into())?;
+        let inp_strides: CudaSlice<usize> = self.dev.htod_copy(inp.strides.into())?;
+        let out_strides: CudaSlice<usize> = self.dev.htod_copy(shape.strides().into())?;
+
+        let cfg = LaunchConfig::for_num_elems(num_lines as u32);
+        let params = (
+            num_lines,         // const size_t num_lines,
+            S::NUM_DIMS,       // const size_t num_dims,
+            ax,                // const size_t ax,
+            &dims,             // const size_t *dims,
+            inp.data.as_ref(), // const float *inp,
+            &inp_strides,      // const size_t *inp_strides,
+            &out_strides,      // const size_t *out_strides,
+            &mut suffix,       // float *suffix,
+            grad_inp,          // float *grad_inp,
+            grad_out,          // const float *grad_out,
+        );
+        unsafe { bwd_fn.launch(cfg, params) }?;
+        Ok(())
+    }
+}
@@ -0,0 +1,128 @@
+#include "cuda_utils.cuh"
+
+// computes the position of the first element of `line` in `inp` and `out`,
+// where `line` indexes all the dimensions except `ax`.
+__device__ void get_line_start(
+    unsigned int line,
+    const size_t num_dims,
+    const size_t ax,
+    const size_t *dims,
+    const size_t *inp_strides,
+    const size_t *out_strides,
+    size_t *inp_i,
+    size_t *out_i
+) {
+    *inp_i = 0;
+    *out_i = 0;
+    for (int d = num_dims - 1; d >= 0; d--) {
+        if (d == ax) {
+            continue;
+        }
+        size_t j = line % dims[d];
+        *inp_i += j * inp_strides[d];
+        *out_i += j * out_strides[d];
+        line /= dims[d];
+    }
+}
+
+// Each thread scans a single line of `inp` along `ax`.
+template<typename T>
+__device__ void cumprod_fwd(
+    const size_t num_lines,
+    const size_t num_dims,
+    const size_t ax,
+    const size_t *dims,
+    const T *inp,
+    const size_t *inp_strides,
+    const size_t *out_strides,
+    T *out
+) {
+    unsigned int line = blockIdx.x * blockDim.x + threadIdx.x;
+    if (line >= num_lines) {
+        return;
+    }
+
+    size_t inp_i, out_i;
+    get_line_start(line, num_dims, ax, dims, inp_strides, out_strides, &inp_i, &out_i);
+
+    T acc = 1.0;
+    for (size_t j = 0; j < dims[ax]; j++) {
+        acc *= inp[inp_i + j * inp_strides[ax]];
+        out[out_i + j * out_strides[ax]] = acc;
+    }
+}
+
+// The gradient of x_i is `prod_{j<i} x_j * s_i` where `s_i = sum_{k>=i} g_k * prod_{i<j<=k} x_j`.
+// Neither factor divides by x_i, so zeros in the input don't need special handling.
+template<typename T>
+__device__ void cumprod_bwd(
+    const size_t num_lines,
+    const size_t num_dims,
+    const size_t ax,
+    const size_t *dims,
+    const T *inp,
+    const size_t *inp_strides,
+    const size_t *out_strides,
+    T *suffix,
+    T *grad_inp,
+    const T *grad_out
+) {
+    unsigned int line = blockIdx.x * blockDim.x + threadIdx.x;
+    if (line >= num_lines) {
+        return;
+    }
+
+    size_t inp_i, out_i;
+    get_line_start(line, num_dims, ax, dims, inp_strides, out_strides, &inp_i, &out_i);
+
+    const size_t n = dims[ax];
+    const size_t inp_s = inp_strides[ax];
+    const size_t out_s = out_strides[ax];
+
+    T s = 0.0;
+    for (size_t j = n; j > 0; j--) {
+        size_t k = j - 1;
+        if (k + 1 < n) {
+            s *= inp[inp_i + (k + 1) * inp_s];
+        }
+        s += grad_out[out_i + k * out_s];
+        suffix[out_i + k * out_s] = s;
+    }
+
+    T prefix = 1.0;
+    for (size_t k = 0; k < n; k++) {
+        atomicAdd(grad_inp + inp_i + k * inp_s, prefix * suffix[out_i + k * out_s]);
+        prefix *= inp[inp_i + k * inp_s];
+    }
+}
+
+#define CUMPROD(TYPENAME, FWD, BWD) \
+extern "C" __global__ void FWD( \
+    const size_t num_lines, \
+    const size_t num_dims, \
+    const size_t ax, \
+    const size_t *dims, \
+    const TYPENAME *inp, \
+    const size_t *inp_strides, \
+    const size_t *out_strides, \
+    TYPENAME *out \
+) { \
+    cumprod_fwd(num_lines, num_dims, ax, dims, inp, inp_strides, out_strides, out); \
+} \
+extern "C" __global__ void BWD( \
+    const size_t num_lines, \
+    const size_t num_dims, \
+    const size_t ax, \
+    const size_t *dims, \
+    const TYPENAME *inp, \
+    const size_t *inp_strides, \
+    const size_t *out_strides, \
+    TYPENAME *suffix, \
+    TYPENAME *grad_inp, \
+    const TYPENAME *grad_out \
+) { \
+    cumprod_bwd(num_lines, num_dims, ax, dims, inp, inp_strides, out_strides, suffix, grad_inp, grad_out); \
+}
+
+CUMPROD(float, cumprod_fwd_f32, cumprod_bwd_f32);
+CUMPROD(double, cumprod_fwd_f64, cumprod_bwd_f64);
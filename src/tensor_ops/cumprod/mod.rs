@@ -0,0 +1,132 @@
+mod cpu_kernel;
+
+#[cfg(feature = "cuda")]
+mod cuda_kernel;
+
+use crate::{gradients::Tape, shapes::*, tensor::*};
+
+pub trait CumProdKernel<E: Dtype>: DeviceStorage {
+    fn forward<S: Shape>(
+        &self,
+        ax: usize,
+        inp: &Tensor<S, E, Self>,
+    ) -> Result<Tensor<S, E, Self>, Self::Err>;
+    fn backward<S: Shape>(
+        &self,
+        ax: usize,
+        inp: &Tensor<S, E, Self>,
+        grad_inp: &mut Self::Vec<E>,
+        grad_out: &Self::Vec<E>,
+    ) -> Result<(), Self::Err>;
+}
+
+impl<S: Shape, E: Dtype, D: CumProdKernel<E>, T: Tape<E, D>> Tensor<S, E, D, T> {
+    /// The running product along axis `Ax`, i.e. each element is the product of all
+    /// elements up to and including it along that axis.
+    ///
+    /// The gradient is computed without dividing by the input, so it is correct
+    /// even when the input contains zeros.
+    ///
+    /// **Pytorch equivalent**: `torch.cumprod(t, dim=Ax)`
+    ///
+    /// Example:
+    /// ```rust
+    /// # use dfdx::prelude::*;
+    /// # let dev: Cpu = Default::default();
+    /// let t = dev.tensor([[1.0, 2.0, 3.0], [4.0, 0.0, 2.0]]);
+    /// let r = t.clone().cumprod::<Axis<1>>();
+    /// assert_eq!(r.array(), [[1.0, 2.0, 6.0], [4.0, 0.0, 0.0]]);
+    /// let r = t.cumprod::<Axis<0>>();
+    /// assert_eq!(r.array(), [[1.0, 2.0, 3.0], [4.0, 0.0, 6.0]]);
+    /// ```
+    pub fn cumprod<Ax: Axes<Array = [isize; 1]>>(self) -> Self
+    where
+        S: HasAxes<Ax>,
+    {
+        self.try_cumprod::<Ax>().unwrap()
+    }
+
+    /// Fallible version of [Tensor::cumprod]
+    pub fn try_cumprod<Ax: Axes<Array = [isize; 1]>>(self) -> Result<Self, D::Err>
+    where
+        S: HasAxes<Ax>,
+    {
+        let ax = Ax::as_array()[0] as usize;
+        let (inp, mut tape) = self.split_tape();
+        let out = inp.device.forward(ax, &inp)?;
+        let phantom_out = out.clone();
+        tape.try_alloc_grad(&inp)?;
+        tape.try_alloc_grad(&out)?;
+        tape.add_reusable_backward_op(move |grads| {
+            let (grad_inp, grad_out) = grads.mut_and_ref(&inp, &phantom_out);
+            inp.device.backward(ax, &inp, grad_inp, grad_out)
+        });
+        Ok(out.put_tape(tape))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{tensor_ops::*, tests::*};
+
+    #[test]
+    fn test_cumprod_1d() {
+        let dev: TestDevice = Default::default();
+        let t: Tensor<_, TestDtype, _> = dev.tensor([2.0, -1.0, 3.0, 0.5]);
+        let r = t.trace().cumprod::<Axis<0>>();
+        assert_eq!(r.array(), [2.0, -2.0, -6.0, -3.0]);
+
+        // d/dx_i sum_k w_k * prod_{j<=k} x_j = sum_{k>=i} w_k * prod_{j<=k, j!=i} x_j
+        let w: Tensor<_, TestDtype, _> = dev.tensor([1.0, 2.0, 3.0, 4.0]);
+        let g = (r * w).sum().backward();
+        assert_close(
+            &g.get(&t).array(),
+            &[1.0 - 2.0 - 9.0 - 6.0, 4.0 + 18.0 + 12.0, -6.0 - 4.0, -24.0],
+        );
+    }
+
+    #[test]
+    fn test_cumprod_1d_with_zero() {
+        let dev: TestDevice = Default::default();
+        let t: Tensor<_, TestDtype, _> = dev.tensor([2.0, 0.0, 3.0, 4.0]);
+        let r = t.trace().cumprod::<Axis<0>>();
+        assert_eq!(r.array(), [2.0, 0.0, 0.0, 0.0]);
+
+        let w: Tensor<_, TestDtype, _> = dev.tensor([1.0, 2.0, 3.0, 4.0]);
+        let g = (r * w).sum().backward();
+        // only terms that skip the zero survive, i.e. the ones with x_1 as the variable
+        assert_eq!(
+            g.get(&t).array(),
+            [1.0, 2.0 * 2.0 + 3.0 * 6.0 + 4.0 * 24.0, 0.0, 0.0]
+        );
+    }
+
+    #[test]
+    fn test_cumprod_2d() {
+        let dev: TestDevice = Default::default();
+        let t: Tensor<Rank2<3, 2>, TestDtype, _> = dev.sample_normal();
+        let r = t.trace().cumprod::<Axis<0>>();
+        let a = t.array();
+        assert_close(
+            &r.array(),
+            &[
+                a[0],
+                [a[0][0] * a[1][0], a[0][1] * a[1][1]],
+                [a[0][0] * a[1][0] * a[2][0], a[0][1] * a[1][1] * a[2][1]],
+            ],
+        );
+        // each column is `[a, ab, abc]`, so the gradients of the sum are
+        // `[1 + b + bc, a + ac, ab]`
+        let g = r.sum().backward();
+        let col = |c: usize| {
+            let (a, b, c) = (a[0][c], a[1][c], a[2][c]);
+            [1.0 + b + b * c, a + a * c, a * b]
+        };
+        let (c0, c1) = (col(0), col(1));
+        assert_close(
+            &g.get(&t).array(),
+            &[[c0[0], c1[0]], [c0[1], c1[1]], [c0[2], c1[2]]],
+        );
+    }
+}
@@ -0,0 +1,42 @@
+use crate::shapes::{Dtype, Shape};
+use crate::tensor::{
+    cpu::{index_to_i, LendingIterator, NdIndex},
+    Cpu, Tensor, ZerosTensor,
+};
+
+impl<E: Dtype> super::DiffKernel<E> for Cpu {
+    fn forward<Src: Shape, Dst: Shape<Concrete = Src::Concrete>>(
+        &self,
+        ax: usize,
+        inp: &Tensor<Src, E, Self>,
+        dst: Dst,
+    ) -> Result<Tensor<Dst, E, Self>, Self::Err> {
+        let mut out = self.try_zeros_like(&dst)?;
+        let mut out_iter = out.iter_mut_with_index();
+        while let Some((x, i_out)) = out_iter.next() {
+            let mut i_next = i_out;
+            i_next[ax] += 1;
+            *x = inp[i_next] - inp[i_out];
+        }
+        Ok(out)
+    }
+
+    fn backward<Src: Shape, Dst: Shape<Concrete = Src::Concrete>>(
+        &self,
+        ax: usize,
+        inp: &Tensor<Src, E, Self>,
+        grad_inp: &mut Self::Vec<E>,
+        out: &Tensor<Dst, E, Self>,
+        grad_out: &Self::Vec<E>,
+    ) -> Result<(), Self::Err> {
+        let mut out_idx = NdIndex::new(out.shape, out.strides);
+        while let Some((i_out, i_dst)) = out_idx.next_with_idx() {
+            let mut i_next = i_dst;
+            i_next[ax] += 1;
+            let g = grad_out[i_out];
+            grad_inp[index_to_i(&inp.shape, &inp.strides, i_next)] += g;
+            grad_inp[index_to_i(&inp.shape, &inp.strides, i_dst)] -= g;
+        }
+        Ok(())
+    }
+}
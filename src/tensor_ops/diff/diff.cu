@@ -0,0 +1,87 @@
+#include "cuda_utils.cuh"
+
+// converts the contiguous index `i` into `out` into the strided position in `inp`
+// of the first of the two elements that are subtracted.
+__device__ unsigned int get_diff_index(
+    unsigned int i,
+    const size_t num_dims,
+    const size_t *inp_strides,
+    const size_t *out_dims
+) {
+    unsigned int inp_i = 0;
+    for (unsigned int d = 0; d < num_dims; d++) {
+        unsigned int dim_idx = num_dims - 1 - d;
+        inp_i += (i % out_dims[dim_idx]) * inp_strides[dim_idx];
+        i /= out_dims[dim_idx];
+    }
+    return inp_i;
+}
+
+template<typename T>
+__device__ void diff_fwd(
+    const size_t numel,
+    const T *inp,
+    const size_t num_dims,
+    const size_t ax,
+    const size_t *inp_strides,
+    const size_t *out_dims,
+    T *out
+) {
+    unsigned int i = blockIdx.x * blockDim.x + threadIdx.x;
+    if (i >= numel) {
+        return;
+    }
+
+    unsigned int inp_i = get_diff_index(i, num_dims, inp_strides, out_dims);
+
+    out[i] = inp[inp_i + inp_strides[ax]] - inp[inp_i];
+}
+
+template<typename T>
+__device__ void diff_bwd(
+    const size_t numel,
+    T *grad_inp,
+    const size_t num_dims,
+    const size_t ax,
+    const size_t *inp_strides,
+    const size_t *out_dims,
+    const T *grad_out
+) {
+    unsigned int i = blockIdx.x * blockDim.x + threadIdx.x;
+    if (i >= numel) {
+        return;
+    }
+
+    unsigned int inp_i = get_diff_index(i, num_dims, inp_strides, out_dims);
+
+    // neighbouring outputs touch the same input element, so both updates are atomic
+    atomicAdd(grad_inp + inp_i + inp_strides[ax], grad_out[i]);
+    atomicAdd(grad_inp + inp_i, -grad_out[i]);
+}
+
+#define DIFF(TYPENAME, FWD, BWD) \
+extern "C" __global__ void FWD( \
+    const size_t numel, \
+    const TYPENAME *inp, \
+    const size_t num_dims, \
+    const size_t ax, \
+    const size_t *inp_strides, \
+    const size_t *out_dims, \
+    TYPENAME *out \
+) { \
+    diff_fwd(numel, inp, num_dims, ax, inp_strides, out_dims, out); \
+} \
+extern "C" __global__ void BWD( \
+    const size_t numel, \
+    TYPENAME *grad_inp, \
+    const size_t num_dims, \
+    const size_t ax, \
+    const size_t *inp_strides, \
+    const size_t *out_dims, \
+    const TYPENAME *grad_out \
+) { \
+    diff_bwd(numel, grad_inp, num_dims, ax, inp_strides, out_dims, grad_out); \
+}
+
+DIFF(float, diff_fwd_f32, diff_bwd_f32);
+DIFF(double, diff_fwd_f64, diff_bwd_f64);
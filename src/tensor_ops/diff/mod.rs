@@ -0,0 +1,130 @@
+#![allow(clippy::type_complexity)]
+
+mod cpu_kernel;
+
+#[cfg(feature = "cuda")]
+mod cuda_kernel;
+
+use crate::{gradients::Tape, shapes::*, tensor::*};
+
+pub trait DiffKernel<E: Dtype>: DeviceStorage {
+    fn forward<Src: Shape, Dst: Shape<Concrete = Src::Concrete>>(
+        &self,
+        ax: usize,
+        inp: &Tensor<Src, E, Self>,
+        dst: Dst,
+    ) -> Result<Tensor<Dst, E, Self>, Self::Err>;
+    fn backward<Src: Shape, Dst: Shape<Concrete = Src::Concrete>>(
+        &self,
+        ax: usize,
+        inp: &Tensor<Src, E, Self>,
+        grad_inp: &mut Self::Vec<E>,
+        out: &Tensor<Dst, E, Self>,
+        grad_out: &Self::Vec<E>,
+    ) -> Result<(), Self::Err>;
+}
+
+/// Discrete differences `x[i + 1] - x[i]` between neighbouring elements along an axis.
+/// The output is one element shorter than the input along that axis.
+///
+/// **Pytorch equivalent**: `torch.diff(t, dim=Ax)`
+pub trait Diff: HasErr + HasShape {
+    /// Differences along axis `Ax`, which becomes a runtime dimension of size `size - 1`:
+    /// ```rust
+    /// # use dfdx::prelude::*;
+    /// # let dev: Cpu = Default::default();
+    /// let a: Tensor<Rank2<2, 3>, f32, _> = dev.tensor([[1.0, 2.0, 4.0], [0.0, -1.0, 5.0]]);
+    /// let r: Tensor<(Const<2>, usize), f32, _> = a.diff::<Axis<1>>();
+    /// assert_eq!(r.shape(), &(Const, 2));
+    /// assert_eq!(r.as_vec(), [1.0, 2.0, -1.0, 6.0]);
+    /// ```
+    fn diff<Ax: Axes<Array = [isize; 1]>>(
+        self,
+    ) -> Self::WithShape<<Self::Shape as ReplaceAxisWith<Ax, usize>>::Replaced>
+    where
+        Self::Shape: ReplaceAxisWith<Ax, usize>,
+    {
+        self.try_diff::<Ax>().unwrap()
+    }
+
+    /// Fallible version of [Diff::diff]
+    fn try_diff<Ax: Axes<Array = [isize; 1]>>(
+        self,
+    ) -> Result<Self::WithShape<<Self::Shape as ReplaceAxisWith<Ax, usize>>::Replaced>, Self::Err>
+    where
+        Self::Shape: ReplaceAxisWith<Ax, usize>;
+}
+
+impl<S: Shape, E: Dtype, D: DiffKernel<E>, T: Tape<E, D>> Diff for Tensor<S, E, D, T> {
+    fn try_diff<Ax: Axes<Array = [isize; 1]>>(
+        self,
+    ) -> Result<Self::WithShape<S::Replaced>, Self::Err>
+    where
+        S: ReplaceAxisWith<Ax, usize>,
+    {
+        let ax = Ax::as_array()[0] as usize;
+        let size = self.shape().concrete()[ax];
+        let dst = self.shape().replace_axis(size.saturating_sub(1));
+        let (inp, mut tape) = self.split_tape();
+        let out = inp.device.forward(ax, &inp, dst)?;
+        let phantom_out = out.clone();
+        tape.try_alloc_grad(&inp)?;
+        tape.try_alloc_grad(&out)?;
+        tape.add_reusable_backward_op(move |grads| {
+            let (grad_inp, grad_out) = grads.mut_and_ref(&inp, &phantom_out);
+            inp.device
+                .backward(ax, &inp, grad_inp, &phantom_out, grad_out)
+        });
+        Ok(out.put_tape(tape))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::tensor_ops::*;
+    use crate::tests::*;
+
+    #[test]
+    fn test_diff_1d() {
+        let dev: TestDevice = Default::default();
+        let t: Tensor<Rank1<5>, TestDtype, _> = dev.tensor([1.0, 4.0, 2.0, -3.0, 0.5]);
+        let r = t.trace().diff::<Axis<0>>();
+        assert_eq!(r.shape(), &(4,));
+        let a = t.array();
+        assert_eq!(
+            r.as_vec(),
+            [a[1] - a[0], a[2] - a[1], a[3] - a[2], a[4] - a[3]]
+        );
+
+        // d/dx_i sum_j w_j (x_{j+1} - x_j) = w_{i-1} - w_i
+        let w = dev.tensor_from_vec(std::vec![1.0, 2.0, 3.0, 4.0], (4,));
+        let g = (r * w).sum().backward();
+        assert_eq!(g.get(&t).array(), [-1.0, -1.0, -1.0, -1.0, 4.0]);
+    }
+
+    #[test]
+    fn test_diff_2d_axes() {
+        let dev: TestDevice = Default::default();
+        let t: Tensor<_, TestDtype, _> = dev.tensor([[1.0, 3.0, 6.0], [2.0, 0.0, 5.0]]);
+
+        let r = t.trace().diff::<Axis<0>>();
+        assert_eq!(r.shape(), &(1, Const::<3>));
+        assert_eq!(r.as_vec(), [1.0, -3.0, -1.0]);
+        let g = r.exp().sum().backward();
+        let e = [1.0 as TestDtype, -3.0, -1.0].map(TestDtype::exp);
+        assert_close(
+            &g.get(&t).array(),
+            &[[-e[0], -e[1], -e[2]], [e[0], e[1], e[2]]],
+        );
+
+        let r = t.trace().diff::<Axis<1>>();
+        assert_eq!(r.shape(), &(Const::<2>, 2));
+        assert_eq!(r.as_vec(), [2.0, 3.0, -2.0, 5.0]);
+        let g = r.square().sum().backward();
+        assert_eq!(
+            g.get(&t).array(),
+            [[-4.0, 4.0 - 6.0, 6.0], [4.0, -4.0 - 10.0, 10.0]]
+        );
+    }
+}
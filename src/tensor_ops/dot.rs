@@ -0,0 +1,69 @@
+use crate::{
+    gradients::{Merge, Tape},
+    shapes::{Dim, Dtype, Rank0},
+    tensor::Tensor,
+};
+
+use super::{Device, SumTo, TryMul};
+
+/// Dot product of two vectors: `sum(lhs * rhs)`.
+///
+/// **Pytorch equivalent**: `torch.dot(lhs, rhs)`
+///
+/// Example:
+/// ```rust
+/// # use dfdx::prelude::*;
+/// # let dev: Cpu = Default::default();
+/// let a = dev.tensor([1.0, 2.0, 3.0]);
+/// let b = dev.tensor([4.0, -5.0, 6.0]);
+/// let r = dot(a, b);
+/// assert_eq!(r.array(), 12.0);
+/// ```
+pub fn dot<N: Dim, E: Dtype, D: Device<E>, T: Tape<E, D> + Merge<R>, R: Default>(
+    lhs: Tensor<(N,), E, D, T>,
+    rhs: Tensor<(N,), E, D, R>,
+) -> Tensor<Rank0, E, D, T> {
+    lhs.dot(rhs)
+}
+
+impl<N: Dim, E: Dtype, D: Device<E>, T: Tape<E, D>> Tensor<(N,), E, D, T> {
+    /// See [dot]
+    pub fn dot<R: Default>(self, rhs: Tensor<(N,), E, D, R>) -> Tensor<Rank0, E, D, T>
+    where
+        T: Merge<R>,
+    {
+        self.try_dot(rhs).unwrap()
+    }
+
+    /// See [dot]
+    pub fn try_dot<R: Default>(
+        self,
+        rhs: Tensor<(N,), E, D, R>,
+    ) -> Result<Tensor<Rank0, E, D, T>, D::Err>
+    where
+        T: Merge<R>,
+    {
+        self.try_mul(rhs)?.try_sum()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{tensor::*, tensor_ops::*, tests::*};
+
+    #[test]
+    fn test_dot() {
+        let dev: TestDevice = Default::default();
+        let a: Tensor<_, TestDtype, _> = dev.tensor([1.0, -2.0, 0.5, 3.0]);
+        let b: Tensor<_, TestDtype, _> = dev.tensor([0.25, 4.0, -1.0, 2.0]);
+
+        let r = a.trace().dot(b.trace());
+        let manual = (a.clone() * b.clone()).sum();
+        assert_eq!(r.array(), manual.array());
+        assert_eq!(r.array(), -2.25);
+
+        let g = r.backward();
+        assert_eq!(g.get(&a).array(), b.array());
+        assert_eq!(g.get(&b).array(), a.array());
+    }
+}
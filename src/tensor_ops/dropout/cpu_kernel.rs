@@ -24,6 +24,7 @@ where
             shape: inp.shape,
             strides: inp.strides,
             device: self.clone(),
+            param_opts: Default::default(),
             tape: Default::default(),
         };
         for x in out.buf_iter_mut() {
@@ -65,13 +65,32 @@ impl<S: Shape, E: Dtype, D: DropoutKernel<E>, T: Tape<E, D>> Tensor<S, E, D, T>
     /// See [dropout]
     pub fn try_dropout(self, prob: E) -> Result<Self, D::Err> {
         let seed = self.device.random_u64();
+        self.try_dropout_with_seed(prob, seed)
+    }
+
+    /// Same as [dropout], but the mask is generated from `seed` instead of a seed drawn
+    /// from the device's rng, so the same `prob` and `seed` always zero the same elements.
+    ///
+    /// ```rust
+    /// # use dfdx::prelude::*;
+    /// # let dev: Cpu = Default::default();
+    /// let t: Tensor<Rank1<8>, f32, _> = dev.ones();
+    /// let a = t.clone().dropout_with_seed(0.5, 42);
+    /// let b = t.dropout_with_seed(0.5, 42);
+    /// assert_eq!(a.array(), b.array());
+    /// ```
+    pub fn dropout_with_seed(self, prob: E, seed: u64) -> Self {
+        self.try_dropout_with_seed(prob, seed).unwrap()
+    }
+    /// See [Tensor::dropout_with_seed]
+    pub fn try_dropout_with_seed(self, prob: E, seed: u64) -> Result<Self, D::Err> {
         let op = DropoutKernelOp { seed, prob };
         let (inp, mut tape) = self.split_tape();
         let out = inp.device.forward(op, &inp)?;
         let phantom_out = out.clone();
         tape.try_alloc_grad(&inp)?;
         tape.try_alloc_grad(&out)?;
-        tape.add_backward_op(move |grads| {
+        tape.add_reusable_backward_op(move |grads| {
             let (grad_inp, grad_out) = grads.mut_and_ref(&inp, &phantom_out);
             inp.device.backward(op, &inp, grad_inp, grad_out)?;
             Ok(())
@@ -82,7 +101,7 @@ impl<S: Shape, E: Dtype, D: DropoutKernel<E>, T: Tape<E, D>> Tensor<S, E, D, T>
 
 #[cfg(test)]
 mod tests {
-    use crate::{tensor::*, tensor_ops::*, tests::*};
+    use crate::{shapes::*, tensor::*, tensor_ops::*, tests::*};
 
     #[test]
     fn test_dropout_all_0d() {
@@ -127,4 +146,22 @@ mod tests {
             &[[0.47214523, 0.5350107, 0.2527211], [0.0, 0.0, 1.4543099]],
         );
     }
+
+    #[test]
+    fn test_dropout_with_seed() {
+        let dev: TestDevice = Default::default();
+        let t: Tensor<Rank2<4, 25>, TestDtype, _> = dev.ones();
+        let a = t.clone().dropout_with_seed(0.5, 7);
+        let b = t.clone().dropout_with_seed(0.5, 7);
+        let c = t.clone().dropout_with_seed(0.5, 8);
+        assert_eq!(a.array(), b.array());
+        assert_ne!(a.array(), c.array());
+
+        // the device rng does not affect the mask
+        let _: Tensor<Rank1<10>, TestDtype, _> = dev.sample_normal();
+        let e = t.trace().dropout_with_seed(0.5, 7);
+        assert_eq!(a.array(), e.array());
+        let g = e.sum().backward();
+        assert_eq!(g.get(&t).array(), a.array());
+    }
 }
@@ -1,7 +1,6 @@
-use crate::tensor_ops::cpu_kernels::UnaryDerivative;
-use num_traits::Float;
+use crate::tensor_ops::{cpu_kernels::UnaryDerivative, utilities::cpu_simd::FloatSlice};
 
-impl<F: Float> UnaryDerivative<F> for super::ExpKernelOp {
+impl<F: FloatSlice> UnaryDerivative<F> for super::ExpKernelOp {
     #[inline(always)]
     fn f(&self, x: &F) -> F {
         x.exp()
@@ -10,4 +9,8 @@ impl<F: Float> UnaryDerivative<F> for super::ExpKernelOp {
     fn df(&self, x: &F) -> F {
         x.exp()
     }
+    #[inline(always)]
+    fn f_slice(&self, buf: &mut [F]) {
+        F::exp_slice(buf)
+    }
 }
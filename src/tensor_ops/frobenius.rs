@@ -0,0 +1,112 @@
+use crate::{
+    gradients::{Merge, Tape},
+    shapes::{Dtype, Rank0, Shape},
+    tensor::Tensor,
+};
+
+use super::{Device, SumTo, TryMul};
+
+/// Frobenius inner product of two tensors: the sum of their elementwise product, `sum(lhs * rhs)`.
+///
+/// **Pytorch equivalent**: `(lhs * rhs).sum()`
+///
+/// Example:
+/// ```rust
+/// # use dfdx::prelude::*;
+/// # let dev: Cpu = Default::default();
+/// let a = dev.tensor([[1.0, 2.0], [3.0, 4.0]]);
+/// let b = dev.tensor([[0.5, -1.0], [2.0, 0.0]]);
+/// assert_eq!(frobenius_inner(a, b).array(), 4.5);
+/// ```
+pub fn frobenius_inner<S: Shape, E: Dtype, D: Device<E>, T: Tape<E, D> + Merge<R>, R: Default>(
+    lhs: Tensor<S, E, D, T>,
+    rhs: Tensor<S, E, D, R>,
+) -> Tensor<Rank0, E, D, T> {
+    lhs.frobenius_inner(rhs)
+}
+
+/// Frobenius norm of a tensor: `sqrt(frobenius_inner(t, t))`.
+///
+/// **Pytorch equivalent**: `torch.linalg.norm(t)` for matrices.
+///
+/// Example:
+/// ```rust
+/// # use dfdx::prelude::*;
+/// # let dev: Cpu = Default::default();
+/// let a = dev.tensor([[3.0, 0.0], [0.0, -4.0]]);
+/// assert_eq!(frobenius_norm(a).array(), 5.0);
+/// ```
+pub fn frobenius_norm<S: Shape, E: Dtype, D: Device<E>, T: Tape<E, D>>(
+    t: Tensor<S, E, D, T>,
+) -> Tensor<Rank0, E, D, T> {
+    t.frobenius_norm()
+}
+
+impl<S: Shape, E: Dtype, D: Device<E>, T: Tape<E, D>> Tensor<S, E, D, T> {
+    /// See [frobenius_inner]
+    pub fn frobenius_inner<R: Default>(self, rhs: Tensor<S, E, D, R>) -> Tensor<Rank0, E, D, T>
+    where
+        T: Merge<R>,
+    {
+        self.try_frobenius_inner(rhs).unwrap()
+    }
+
+    /// See [frobenius_inner]
+    pub fn try_frobenius_inner<R: Default>(
+        self,
+        rhs: Tensor<S, E, D, R>,
+    ) -> Result<Tensor<Rank0, E, D, T>, D::Err>
+    where
+        T: Merge<R>,
+    {
+        self.try_mul(rhs)?.try_sum()
+    }
+
+    /// See [frobenius_norm]
+    pub fn frobenius_norm(self) -> Tensor<Rank0, E, D, T> {
+        self.try_frobenius_norm().unwrap()
+    }
+
+    /// See [frobenius_norm]
+    pub fn try_frobenius_norm(self) -> Result<Tensor<Rank0, E, D, T>, D::Err> {
+        // same as the inner product of self with itself, without splitting the tape
+        self.try_square()?.try_sum()?.try_sqrt()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{shapes::*, tensor::*, tensor_ops::*, tests::*};
+
+    #[test]
+    fn test_frobenius_inner() {
+        let dev: TestDevice = Default::default();
+        let a: Tensor<_, TestDtype, _> = dev.tensor([[1.0, -2.0, 0.5], [3.0, 0.0, 4.0]]);
+        let b: Tensor<_, TestDtype, _> = dev.tensor([[2.0, 1.0, 4.0], [-1.0, 5.0, 0.25]]);
+
+        let r = a.trace().frobenius_inner(b.trace());
+        assert_eq!(r.array(), 0.0);
+        assert_eq!(frobenius_inner(a.clone(), a.clone()).array(), 30.25);
+
+        let g = r.backward();
+        assert_eq!(g.get(&a).array(), b.array());
+        assert_eq!(g.get(&b).array(), a.array());
+    }
+
+    #[test]
+    fn test_frobenius_norm() {
+        let dev: TestDevice = Default::default();
+        let a: Tensor<Rank2<2, 3>, TestDtype, _> = dev.tensor([[1.0, -2.0, 2.0], [0.0, 4.0, 0.0]]);
+
+        let r = a.trace().frobenius_norm();
+        assert_eq!(r.array(), 5.0);
+        assert_close(
+            &r.array(),
+            &frobenius_inner(a.clone(), a.clone()).sqrt().array(),
+        );
+
+        // d||a|| / da = a / ||a||
+        let g = r.backward();
+        assert_close(&g.get(&a).array(), &(a.clone() / 5.0).array());
+    }
+}
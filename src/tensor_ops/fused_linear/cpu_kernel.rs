@@ -0,0 +1,74 @@
+use crate::{
+    shapes::{Axes2, Dim, Dtype},
+    tensor::{Cpu, CpuError, Tensor},
+    tensor_ops::{cpu_kernels::UnaryDerivative, matmul::MatMatKernel, permute_to::PermuteKernel},
+};
+
+use std::{sync::Arc, vec::Vec};
+
+/// `x * w^T + b`, composed from the matmul kernel and a bias add.
+fn linear<M: Dim, K: Dim, N: Dim, E: Dtype>(
+    dev: &Cpu,
+    x: &Tensor<(M, K), E, Cpu>,
+    w: &Tensor<(N, K), E, Cpu>,
+    b: &Tensor<(N,), E, Cpu>,
+) -> Result<Tensor<(M, N), E, Cpu>, CpuError>
+where
+    Cpu: MatMatKernel<E>,
+{
+    let w_t = PermuteKernel::forward::<_, _, Axes2<1, 0>>(dev, w)?;
+    let mut z = MatMatKernel::forward(dev, x, &w_t)?;
+    let n = b.shape.0.size();
+    let b_buf = b.data.as_ref();
+    // NOTE: the output of the matmul kernel is contiguous
+    for (i, z_i) in Arc::make_mut(&mut z.data).iter_mut().enumerate() {
+        *z_i += b_buf[(i % n) * b.strides[0]];
+    }
+    Ok(z)
+}
+
+impl<Act: UnaryDerivative<E>, E: Dtype> super::FusedLinearKernel<Act, E> for Cpu
+where
+    Self: MatMatKernel<E>,
+{
+    fn forward<M: Dim, K: Dim, N: Dim>(
+        &self,
+        act: Act,
+        x: &Tensor<(M, K), E, Self>,
+        w: &Tensor<(N, K), E, Self>,
+        b: &Tensor<(N,), E, Self>,
+    ) -> Result<Tensor<(M, N), E, Self>, Self::Err> {
+        let mut out = linear(self, x, w, b)?;
+        act.f_slice(Arc::make_mut(&mut out.data).as_mut_slice());
+        Ok(out)
+    }
+
+    fn backward<M: Dim, K: Dim, N: Dim>(
+        &self,
+        act: Act,
+        x: &Tensor<(M, K), E, Self>,
+        grad_x: &mut Self::Vec<E>,
+        w: &Tensor<(N, K), E, Self>,
+        grad_w: &mut Self::Vec<E>,
+        b: &Tensor<(N,), E, Self>,
+        grad_b: &mut Self::Vec<E>,
+        grad_out: &Self::Vec<E>,
+    ) -> Result<(), Self::Err> {
+        // the pre-activation isn't kept around by forward, so recompute it here
+        let z = linear(self, x, w, b)?;
+        let n = b.shape.0.size();
+        let grad_z: Vec<E> = z
+            .data
+            .iter()
+            .zip(grad_out.iter())
+            .enumerate()
+            .map(|(i, (z_i, &go))| {
+                let g = act.df(z_i) * go;
+                grad_b[(i % n) * b.strides[0]] += g;
+                g
+            })
+            .collect();
+        let w_t = PermuteKernel::forward::<_, _, Axes2<1, 0>>(self, w)?;
+        MatMatKernel::backward(self, x, grad_x, &w_t, grad_w, &grad_z)
+    }
+}
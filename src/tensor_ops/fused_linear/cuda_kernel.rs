@@ -0,0 +1,137 @@
+use crate::{
+    shapes::*,
+    tensor::{Cuda, Tensor},
+    tensor_ops::{gelu::GeLUKernelOp, matmul::MatMatKernel, relu::ReLUKernelOp},
+    unique_id::unique_id,
+};
+
+use cudarc::driver::{DeviceRepr, LaunchAsync, LaunchConfig};
+use std::sync::Arc;
+
+const PTX_SRC: &str = include_str!(concat!(env!("OUT_DIR"), "/fused_linear.ptx"));
+
+#[repr(C)]
+#[derive(Debug, Clone, Copy)]
+struct FusedLinearOp {
+    m: usize,
+    n: usize,
+    b_stride: usize,
+}
+
+unsafe impl DeviceRepr for FusedLinearOp {}
+
+trait HasCudaKernel<Act, E> {
+    const MOD: &'static str;
+    const FNS: &'static [&'static str];
+}
+
+impl HasCudaKernel<ReLUKernelOp, f32> for Cuda {
+    const MOD: &'static str = "linear_relu_f32";
+    const FNS: &'static [&'static str] = &["linear_relu_fwd_f32", "linear_relu_bwd_f32"];
+}
+
+impl HasCudaKernel<ReLUKernelOp, f64> for Cuda {
+    const MOD: &'static str = "linear_relu_f64";
+    const FNS: &'static [&'static str] = &["linear_relu_fwd_f64", "linear_relu_bwd_f64"];
+}
+
+impl HasCudaKernel<GeLUKernelOp, f32> for Cuda {
+    const MOD: &'static str = "linear_gelu_f32";
+    const FNS: &'static [&'static str] = &["linear_gelu_fwd_f32", "linear_gelu_bwd_f32"];
+}
+
+impl HasCudaKernel<GeLUKernelOp, f64> for Cuda {
+    const MOD: &'static str = "linear_gelu_f64";
+    const FNS: &'static [&'static str] = &["linear_gelu_fwd_f64", "linear_gelu_bwd_f64"];
+}
+
+fn make_op<M: Dim, N: Dim, E: Dtype>(
+    z: &Tensor<(M, N), E, Cuda>,
+    b: &Tensor<(N,), E, Cuda>,
+) -> FusedLinearOp {
+    FusedLinearOp {
+        m: z.shape.0.size(),
+        n: z.shape.1.size(),
+        b_stride: b.strides[0],
+    }
+}
+
+/// `w^T` as a strided view of `w`, so `x * w^T` can go through the cublas matmul.
+fn transposed<N: Dim, K: Dim, E: Dtype>(w: &Tensor<(N, K), E, Cuda>) -> Tensor<(K, N), E, Cuda> {
+    Tensor {
+        id: unique_id(),
+        data: w.data.clone(),
+        shape: (w.shape.1, w.shape.0),
+        strides: [w.strides[1], w.strides[0]],
+        device: w.device.clone(),
+        param_opts: Default::default(),
+        tape: Default::default(),
+    }
+}
+
+impl<Act, E: Dtype> super::FusedLinearKernel<Act, E> for Cuda
+where
+    Self: HasCudaKernel<Act, E> + MatMatKernel<E>,
+{
+    fn forward<M: Dim, K: Dim, N: Dim>(
+        &self,
+        _act: Act,
+        x: &Tensor<(M, K), E, Self>,
+        w: &Tensor<(N, K), E, Self>,
+        b: &Tensor<(N,), E, Self>,
+    ) -> Result<Tensor<(M, N), E, Self>, Self::Err> {
+        if !self.dev.has_func(Self::MOD, Self::FNS[0]) {
+            self.dev.load_ptx(PTX_SRC.into(), Self::MOD, Self::FNS)?;
+        }
+
+        let mut z = MatMatKernel::forward(self, x, &transposed(w))?;
+        let op = make_op(&z, b);
+        let numel = op.m * op.n;
+
+        // the bias add and activation happen in a single launch on the matmul output
+        let fwd_fn = self.dev.get_func(Self::MOD, Self::FNS[0]).unwrap();
+        let cfg = LaunchConfig::for_num_elems(numel as u32);
+        let params = (
+            op,                         // const FusedLinearOp op,
+            b.data.as_ref(),            // const float *b,
+            Arc::make_mut(&mut z.data), // float *z
+        );
+        unsafe { fwd_fn.launch(cfg, params) }?;
+        Ok(z)
+    }
+
+    fn backward<M: Dim, K: Dim, N: Dim>(
+        &self,
+        _act: Act,
+        x: &Tensor<(M, K), E, Self>,
+        grad_x: &mut Self::Vec<E>,
+        w: &Tensor<(N, K), E, Self>,
+        grad_w: &mut Self::Vec<E>,
+        b: &Tensor<(N,), E, Self>,
+        grad_b: &mut Self::Vec<E>,
+        grad_out: &Self::Vec<E>,
+    ) -> Result<(), Self::Err> {
+        // recompute the matmul, which with the bias gives the pre-activation
+        let w_t = transposed(w);
+        let z = MatMatKernel::forward(self, x, &w_t)?;
+        let op = make_op(&z, b);
+        let numel = op.m * op.n;
+        let mut grad_z = self.dev.alloc_zeros::<E>(numel)?;
+
+        // gradient wrt the pre-activation, and the bias gradient
+        let bwd_fn = self.dev.get_func(Self::MOD, Self::FNS[1]).unwrap();
+        let cfg = LaunchConfig::for_num_elems(numel as u32);
+        let params = (
+            op,              // const FusedLinearOp op,
+            b.data.as_ref(), // const float *b,
+            z.data.as_ref(), // const float *z,
+            grad_b,          // float *grad_b,
+            grad_out,        // const float *grad_out,
+            &mut grad_z,     // float *grad_z
+        );
+        unsafe { bwd_fn.launch(cfg, params) }?;
+
+        // the rest is the backward pass of `x * w^T`
+        MatMatKernel::backward(self, x, grad_x, &w_t, grad_w, &grad_z)
+    }
+}
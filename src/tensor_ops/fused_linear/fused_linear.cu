@@ -0,0 +1,76 @@
+#include "cuda_utils.cuh"
+#define _USE_MATH_DEFINES
+#include <math.h>
+
+struct FusedLinearOp {
+    size_t m;
+    size_t n;
+    size_t b_stride;
+};
+
+template<typename T>
+__device__ T relu_fwd(T x) {
+    return x > 0.0 ? x : 0.0;
+}
+
+template<typename T>
+__device__ T relu_bwd(T x) {
+    return x > 0.0 ? 1.0 : 0.0;
+}
+
+template<typename T>
+__device__ T gelu_fwd(T x) {
+    constexpr T fastCoeff = 0.044715;
+    T alpha = x + fastCoeff * x * x * x;
+    return 0.5 * x * (1.0 + tanhg(M_2_SQRTPI * M_SQRT1_2 * alpha));
+}
+
+template<typename T>
+__device__ T gelu_bwd(T x) {
+    constexpr T kBeta = M_2_SQRTPI * M_SQRT2 * 0.5;
+    constexpr T fastCoeff = 0.044715;
+    T x_sq = x * x;
+    T tanh_inner = tanhg(kBeta * (x + fastCoeff * x_sq * x));
+    T left_derivative = 0.5 * (1.0 + tanh_inner);
+    T tanh_derivative = 1.0 - tanh_inner * tanh_inner;
+    T inner_derivative = kBeta * (1.0 + 3.0 * fastCoeff * x_sq);
+    return left_derivative + 0.5 * x * tanh_derivative * inner_derivative;
+}
+
+// `z` is the contiguous `(m, n)` output of the `x * w^T` matmul, which is done by cublas.
+// these kernels are the epilogue that adds the bias and applies the activation.
+#define FUSED_LINEAR(TYPENAME, ACT, FORWARD, BACKWARD) \
+extern "C" __global__ void FORWARD( \
+    const FusedLinearOp op, \
+    const TYPENAME *b, \
+    TYPENAME *z \
+) { \
+    unsigned int i = blockIdx.x * blockDim.x + threadIdx.x; \
+    if (i >= op.m * op.n) { \
+        return; \
+    } \
+    z[i] = ACT##_fwd(z[i] + b[(i % op.n) * op.b_stride]); \
+} \
+\
+extern "C" __global__ void BACKWARD( \
+    const FusedLinearOp op, \
+    const TYPENAME *b, \
+    const TYPENAME *z, \
+    TYPENAME *grad_b, \
+    const TYPENAME *grad_out, \
+    TYPENAME *grad_z \
+) { \
+    unsigned int i = blockIdx.x * blockDim.x + threadIdx.x; \
+    if (i >= op.m * op.n) { \
+        return; \
+    } \
+    const size_t j = i % op.n; \
+    TYPENAME g = grad_out[i] * ACT##_bwd(z[i] + b[j * op.b_stride]); \
+    grad_z[i] = g; \
+    atomicAdd(grad_b + j * op.b_stride, g); \
+}
+
+FUSED_LINEAR(float, relu, linear_relu_fwd_f32, linear_relu_bwd_f32);
+FUSED_LINEAR(double, relu, linear_relu_fwd_f64, linear_relu_bwd_f64);
+FUSED_LINEAR(float, gelu, linear_gelu_fwd_f32, linear_gelu_bwd_f32);
+FUSED_LINEAR(double, gelu, linear_gelu_fwd_f64, linear_gelu_bwd_f64);
@@ -0,0 +1,230 @@
+#![allow(clippy::type_complexity)]
+
+mod cpu_kernel;
+
+#[cfg(feature = "cuda")]
+mod cuda_kernel;
+
+use super::{gelu::GeLUKernelOp, relu::ReLUKernelOp};
+use crate::{
+    gradients::{Merge, Tape},
+    shapes::{Dim, Dtype},
+    tensor::{DeviceStorage, PutTape, SplitTape, Tensor},
+};
+
+/// `act(x * w^T + b)` for a `(M, K)` input, a `(N, K)` weight and a `(N,)` bias.
+///
+/// `Act` is the kernel op of the activation, e.g. [ReLUKernelOp].
+pub trait FusedLinearKernel<Act, E: Dtype>: DeviceStorage {
+    fn forward<M: Dim, K: Dim, N: Dim>(
+        &self,
+        act: Act,
+        x: &Tensor<(M, K), E, Self>,
+        w: &Tensor<(N, K), E, Self>,
+        b: &Tensor<(N,), E, Self>,
+    ) -> Result<Tensor<(M, N), E, Self>, Self::Err>;
+
+    #[allow(clippy::too_many_arguments)]
+    fn backward<M: Dim, K: Dim, N: Dim>(
+        &self,
+        act: Act,
+        x: &Tensor<(M, K), E, Self>,
+        grad_x: &mut Self::Vec<E>,
+        w: &Tensor<(N, K), E, Self>,
+        grad_w: &mut Self::Vec<E>,
+        b: &Tensor<(N,), E, Self>,
+        grad_b: &mut Self::Vec<E>,
+        grad_out: &Self::Vec<E>,
+    ) -> Result<(), Self::Err>;
+}
+
+/// Fused `relu(x * w^T + b)`, the forward pass of a [crate::nn::Linear] followed
+/// by a [crate::nn::ReLU].
+///
+/// On [crate::tensor::Cuda] the matmul goes through cuBLAS, and the bias add and the
+/// activation are fused into a single kernel launch on its output. On
+/// [crate::tensor::Cpu] it is composed from the existing kernels.
+///
+/// Example:
+/// ```rust
+/// # use dfdx::prelude::*;
+/// # let dev: Cpu = Default::default();
+/// let x = dev.tensor([[1.0, 2.0], [-1.0, 0.5]]);
+/// let w = dev.tensor([[1.0, 1.0], [0.5, -1.0], [0.0, 1.0]]);
+/// let b = dev.tensor([0.0, 1.0, -1.0]);
+/// let r = linear_relu(x, w, b);
+/// assert_eq!(r.array(), [[3.0, 0.0, 1.0], [0.0, 0.0, 0.0]]);
+/// ```
+pub fn linear_relu<M: Dim, K: Dim, N: Dim, E: Dtype, D, T, R1: Default, R2: Default>(
+    x: Tensor<(M, K), E, D, T>,
+    w: Tensor<(N, K), E, D, R1>,
+    b: Tensor<(N,), E, D, R2>,
+) -> Tensor<(M, N), E, D, T>
+where
+    D: FusedLinearKernel<ReLUKernelOp, E>,
+    T: Tape<E, D> + Merge<R1> + Merge<R2>,
+{
+    x.linear_relu(w, b)
+}
+
+/// Fused `gelu(x * w^T + b)`, the forward pass of a [crate::nn::Linear] followed
+/// by a [crate::nn::GeLU]. See [linear_relu] for how this is dispatched.
+///
+/// Example:
+/// ```rust
+/// # use dfdx::prelude::*;
+/// # let dev: Cpu = Default::default();
+/// let x = dev.tensor([[1.0, 2.0], [-1.0, 0.5]]);
+/// let w = dev.tensor([[1.0, 1.0], [0.5, -1.0], [0.0, 1.0]]);
+/// let b = dev.tensor([0.0, 1.0, -1.0]);
+/// let r = linear_gelu(x, w, b);
+/// assert_eq!(r.shape(), &(Const::<2>, Const::<3>));
+/// ```
+pub fn linear_gelu<M: Dim, K: Dim, N: Dim, E: Dtype, D, T, R1: Default, R2: Default>(
+    x: Tensor<(M, K), E, D, T>,
+    w: Tensor<(N, K), E, D, R1>,
+    b: Tensor<(N,), E, D, R2>,
+) -> Tensor<(M, N), E, D, T>
+where
+    D: FusedLinearKernel<GeLUKernelOp, E>,
+    T: Tape<E, D> + Merge<R1> + Merge<R2>,
+{
+    x.linear_gelu(w, b)
+}
+
+impl<M: Dim, K: Dim, E: Dtype, D: DeviceStorage, T: Tape<E, D>> Tensor<(M, K), E, D, T> {
+    /// See [linear_relu]
+    pub fn linear_relu<N: Dim, R1: Default, R2: Default>(
+        self,
+        w: Tensor<(N, K), E, D, R1>,
+        b: Tensor<(N,), E, D, R2>,
+    ) -> Tensor<(M, N), E, D, T>
+    where
+        D: FusedLinearKernel<ReLUKernelOp, E>,
+        T: Merge<R1> + Merge<R2>,
+    {
+        self.try_linear_relu(w, b).unwrap()
+    }
+
+    /// See [linear_relu]
+    pub fn try_linear_relu<N: Dim, R1: Default, R2: Default>(
+        self,
+        w: Tensor<(N, K), E, D, R1>,
+        b: Tensor<(N,), E, D, R2>,
+    ) -> Result<Tensor<(M, N), E, D, T>, D::Err>
+    where
+        D: FusedLinearKernel<ReLUKernelOp, E>,
+        T: Merge<R1> + Merge<R2>,
+    {
+        try_fused_linear(ReLUKernelOp, self, w, b)
+    }
+
+    /// See [linear_gelu]
+    pub fn linear_gelu<N: Dim, R1: Default, R2: Default>(
+        self,
+        w: Tensor<(N, K), E, D, R1>,
+        b: Tensor<(N,), E, D, R2>,
+    ) -> Tensor<(M, N), E, D, T>
+    where
+        D: FusedLinearKernel<GeLUKernelOp, E>,
+        T: Merge<R1> + Merge<R2>,
+    {
+        self.try_linear_gelu(w, b).unwrap()
+    }
+
+    /// See [linear_gelu]
+    pub fn try_linear_gelu<N: Dim, R1: Default, R2: Default>(
+        self,
+        w: Tensor<(N, K), E, D, R1>,
+        b: Tensor<(N,), E, D, R2>,
+    ) -> Result<Tensor<(M, N), E, D, T>, D::Err>
+    where
+        D: FusedLinearKernel<GeLUKernelOp, E>,
+        T: Merge<R1> + Merge<R2>,
+    {
+        try_fused_linear(GeLUKernelOp, self, w, b)
+    }
+}
+
+fn try_fused_linear<Act, M: Dim, K: Dim, N: Dim, E: Dtype, D, T, R1: Default, R2: Default>(
+    act: Act,
+    x: Tensor<(M, K), E, D, T>,
+    w: Tensor<(N, K), E, D, R1>,
+    b: Tensor<(N,), E, D, R2>,
+) -> Result<Tensor<(M, N), E, D, T>, D::Err>
+where
+    Act: 'static + Copy,
+    D: FusedLinearKernel<Act, E>,
+    T: Tape<E, D> + Merge<R1> + Merge<R2>,
+{
+    assert_eq!(x.shape.1, w.shape.1);
+    assert_eq!(w.shape.0, b.shape.0);
+    let (x, tape) = x.split_tape();
+    let (w, w_tape) = w.split_tape();
+    let (b, b_tape) = b.split_tape();
+    let mut tape = tape.merge(w_tape).merge(b_tape);
+    let out = x.device.forward(act, &x, &w, &b)?;
+    let phantom_out = out.clone();
+    tape.try_alloc_grad(&x)?;
+    tape.try_alloc_grad(&w)?;
+    tape.try_alloc_grad(&b)?;
+    tape.try_alloc_grad(&out)?;
+    tape.add_reusable_backward_op(move |grads| {
+        let (grad_x, grad_w, grad_b, grad_out) = grads.three_muts_and_ref(&x, &w, &b, &phantom_out);
+        x.device
+            .backward(act, &x, grad_x, &w, grad_w, &b, grad_b, grad_out)
+    });
+    Ok(out.put_tape(tape))
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{shapes::*, tensor::*, tensor_ops::*, tests::*};
+
+    #[test]
+    fn test_linear_relu_matches_composed() {
+        let dev: TestDevice = Default::default();
+        let x: Tensor<Rank2<4, 5>, TestDtype, _> = dev.sample_normal();
+        let w: Tensor<Rank2<3, 5>, TestDtype, _> = dev.sample_normal();
+        let b: Tensor<Rank1<3>, TestDtype, _> = dev.sample_normal();
+
+        let r = x.trace().linear_relu(w.trace(), b.trace());
+        let r2 = (x.trace().matmul(w.trace().permute()) + b.trace().broadcast()).relu();
+        assert_close(&r.array(), &r2.array());
+
+        let g = r.exp().mean().backward();
+        let g2 = r2.exp().mean().backward();
+        assert_close(&g.get(&x).array(), &g2.get(&x).array());
+        assert_close(&g.get(&w).array(), &g2.get(&w).array());
+        assert_close(&g.get(&b).array(), &g2.get(&b).array());
+    }
+
+    #[test]
+    fn test_linear_gelu_matches_composed() {
+        let dev: TestDevice = Default::default();
+        let x: Tensor<Rank2<4, 5>, TestDtype, _> = dev.sample_normal();
+        let w: Tensor<Rank2<3, 5>, TestDtype, _> = dev.sample_normal();
+        let b: Tensor<Rank1<3>, TestDtype, _> = dev.sample_normal();
+
+        let r = x.trace().linear_gelu(w.trace(), b.trace());
+        let r2 = (x.trace().matmul(w.trace().permute()) + b.trace().broadcast()).gelu();
+        assert_close(&r.array(), &r2.array());
+
+        let g = r.exp().mean().backward();
+        let g2 = r2.exp().mean().backward();
+        assert_close(&g.get(&x).array(), &g2.get(&x).array());
+        assert_close(&g.get(&w).array(), &g2.get(&w).array());
+        assert_close(&g.get(&b).array(), &g2.get(&b).array());
+    }
+
+    #[test]
+    fn test_linear_relu_permuted_input() {
+        let dev: TestDevice = Default::default();
+        let x: Tensor<Rank2<5, 2>, TestDtype, _> = dev.sample_normal();
+        let w: Tensor<Rank2<3, 5>, TestDtype, _> = dev.sample_normal();
+        let b: Tensor<Rank1<3>, TestDtype, _> = dev.sample_normal();
+        let r = x.clone().permute().linear_relu(w.clone(), b.clone());
+        let r2 = (x.permute().matmul(w.permute()) + b.broadcast()).relu();
+        assert_close(&r.array(), &r2.array());
+    }
+}
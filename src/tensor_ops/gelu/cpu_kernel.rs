@@ -30,3 +30,53 @@ impl<F: Float + FloatConst> UnaryDerivative<F> for super::GeLUKernelOp {
         left_derivative + right_derivative
     }
 }
+
+impl<F: Float + FloatConst> UnaryDerivative<F> for super::GeLUExactKernelOp {
+    #[inline(always)]
+    fn f(&self, &x: &F) -> F {
+        let half = F::from(0.5).unwrap();
+        half * x * (F::one() + erf(x * F::FRAC_1_SQRT_2()))
+    }
+
+    #[inline(always)]
+    fn df(&self, &x: &F) -> F {
+        let half = F::from(0.5).unwrap();
+        let cdf = half * (F::one() + erf(x * F::FRAC_1_SQRT_2()));
+        let pdf = (-half * x * x).exp() * F::FRAC_2_SQRT_PI() * F::FRAC_1_SQRT_2() * half;
+        cdf + x * pdf
+    }
+}
+
+#[inline(always)]
+fn erf<F: Float>(x: F) -> F {
+    F::from(erf_f64(x.to_f64().unwrap())).unwrap()
+}
+
+/// The error function, accurate to about 1e-13.
+///
+/// Below 3 this sums the maclaurin series, and above that it evaluates the continued
+/// fraction of `erfc`, which converges quickly for large inputs.
+fn erf_f64(x: f64) -> f64 {
+    let a = x.abs();
+    let r = if a < 3.0 {
+        // term is (-1)^n * a^(2n + 1) / n!
+        let a_sq = a * a;
+        let mut term = a;
+        let mut sum = a;
+        let mut n = 0.0;
+        while term.abs() > 1e-17 * sum {
+            n += 1.0;
+            term *= -a_sq / n;
+            sum += term / (2.0 * n + 1.0);
+        }
+        sum * core::f64::consts::FRAC_2_SQRT_PI
+    } else {
+        // erfc(a) = exp(-a^2) / sqrt(pi) / (a + (1/2) / (a + (2/2) / (a + (3/2) / ...)))
+        let mut frac = 0.0;
+        for k in (1..=60).rev() {
+            frac = (k as f64 * 0.5) / (a + frac);
+        }
+        1.0 - (-a * a).exp() / (a + frac) * core::f64::consts::FRAC_2_SQRT_PI * 0.5
+    };
+    r.copysign(x)
+}
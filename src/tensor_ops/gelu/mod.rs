@@ -10,8 +10,15 @@ use crate::{gradients::Tape, shapes::*, tensor::Tensor};
 #[derive(Debug, Default, Copy, Clone)]
 pub struct GeLUKernelOp;
 
+#[repr(C)]
+#[derive(Debug, Default, Copy, Clone)]
+pub struct GeLUExactKernelOp;
+
 /// [Gaussian Linear Unit (GeLU)](https://paperswithcode.com/method/gelu). `0.5 * x * (1 + tanh(sqrt(2 / pi) * (x + 0.044715 * x^3)))`
 ///
+/// This is the tanh approximation, and is the same as [gelu_fast()]. See [gelu_exact()]
+/// for the formulation using `erf`.
+///
 /// Examples:
 /// ```rust
 /// # use dfdx::prelude::*;
@@ -25,6 +32,38 @@ pub fn gelu<S: Shape, E: Dtype, D: UnaryKernel<GeLUKernelOp, E>, T: Tape<E, D>>(
     t.gelu()
 }
 
+/// [Gaussian Linear Unit (GeLU)](https://paperswithcode.com/method/gelu) using the tanh
+/// approximation. `0.5 * x * (1 + tanh(sqrt(2 / pi) * (x + 0.044715 * x^3)))`
+///
+/// Examples:
+/// ```rust
+/// # use dfdx::prelude::*;
+/// # let dev: Cpu = Default::default();
+/// let t = dev.tensor([-1.0, 0.0, 1.0, 2.0]);
+/// let r = t.gelu_fast();
+/// ```
+pub fn gelu_fast<S: Shape, E: Dtype, D: UnaryKernel<GeLUKernelOp, E>, T: Tape<E, D>>(
+    t: Tensor<S, E, D, T>,
+) -> Tensor<S, E, D, T> {
+    t.gelu_fast()
+}
+
+/// [Gaussian Linear Unit (GeLU)](https://paperswithcode.com/method/gelu) computed exactly
+/// with the gaussian cdf. `0.5 * x * (1 + erf(x / sqrt(2)))`
+///
+/// Examples:
+/// ```rust
+/// # use dfdx::prelude::*;
+/// # let dev: Cpu = Default::default();
+/// let t = dev.tensor([-1.0, 0.0, 1.0, 2.0]);
+/// let r = t.gelu_exact();
+/// ```
+pub fn gelu_exact<S: Shape, E: Dtype, D: UnaryKernel<GeLUExactKernelOp, E>, T: Tape<E, D>>(
+    t: Tensor<S, E, D, T>,
+) -> Tensor<S, E, D, T> {
+    t.gelu_exact()
+}
+
 impl<S: Shape, E: Dtype, D: UnaryKernel<GeLUKernelOp, E>, T: Tape<E, D>> Tensor<S, E, D, T> {
     /// See [gelu]
     pub fn gelu(self) -> Self {
@@ -34,11 +73,30 @@ impl<S: Shape, E: Dtype, D: UnaryKernel<GeLUKernelOp, E>, T: Tape<E, D>> Tensor<
     pub fn try_gelu(self) -> Result<Self, D::Err> {
         try_unary_op(GeLUKernelOp, self)
     }
+    /// See [gelu_fast]
+    pub fn gelu_fast(self) -> Self {
+        self.try_gelu_fast().unwrap()
+    }
+    /// See [gelu_fast]
+    pub fn try_gelu_fast(self) -> Result<Self, D::Err> {
+        try_unary_op(GeLUKernelOp, self)
+    }
+}
+
+impl<S: Shape, E: Dtype, D: UnaryKernel<GeLUExactKernelOp, E>, T: Tape<E, D>> Tensor<S, E, D, T> {
+    /// See [gelu_exact]
+    pub fn gelu_exact(self) -> Self {
+        self.try_gelu_exact().unwrap()
+    }
+    /// See [gelu_exact]
+    pub fn try_gelu_exact(self) -> Result<Self, D::Err> {
+        try_unary_op(GeLUExactKernelOp, self)
+    }
 }
 
 #[cfg(test)]
 mod tests {
-    use crate::{tensor::*, tensor_ops::*, tests::*};
+    use crate::{shapes::*, tensor::*, tensor_ops::*, tests::*};
 
     #[test]
     fn test_gelu() {
@@ -57,4 +115,39 @@ mod tests {
             &[-0.016455507, -0.014156329, 0.1, 0.5023068, 1.5338063],
         );
     }
+
+    #[test]
+    fn test_gelu_exact() {
+        let dev: TestDevice = Default::default();
+        let x: Tensor<_, TestDtype, _> = dev.tensor([-2.0, -1.0, 0.0, 1.0, 2.0]);
+        let r = x.trace().gelu_exact();
+        assert_close(
+            &r.array(),
+            &[-0.04550026, -0.15865526, 0.0, 0.8413447, 1.9544997],
+        );
+        let g = r.exp().mean().backward();
+        assert_close(
+            &g.get(&x).array(),
+            &[-0.016288126, -0.01421846, 0.1, 0.5025466, 1.5324311],
+        );
+    }
+
+    #[test]
+    fn test_gelu_exact_vs_fast() {
+        let dev: TestDevice = Default::default();
+        let x: Tensor<Rank1<32>, TestDtype, _> = dev.sample_normal();
+        let exact = x.clone().gelu_exact().array();
+        let fast = x.clone().gelu_fast().array();
+        assert_eq!(fast, x.gelu().array());
+
+        let mut max_diff = 0.0;
+        for (e, f) in exact.iter().zip(fast.iter()) {
+            let diff = (e - f).abs();
+            assert!(diff < 1e-3, "{e} vs {f}");
+            if diff > max_diff {
+                max_diff = diff;
+            }
+        }
+        assert!(max_diff > 0.0);
+    }
 }
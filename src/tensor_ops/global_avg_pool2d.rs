@@ -0,0 +1,94 @@
+use super::{Device, MeanTo};
+use crate::{gradients::Tape, shapes::*, tensor::*};
+
+impl<C: Dim, H: Dim, W: Dim, E: Dtype, D: Device<E>, T: Tape<E, D>> Tensor<(C, H, W), E, D, T> {
+    /// Averages over the full height and width of an image, reducing `(C, H, W)` to `(C,)`.
+    /// The same as [MeanTo::mean] over `Axes2<1, 2>`.
+    ///
+    /// **Pytorch equivalent**: `torch.nn.functional.adaptive_avg_pool2d(t, 1).flatten(-3)`
+    ///
+    /// Example:
+    /// ```rust
+    /// # use dfdx::prelude::*;
+    /// # let dev: Cpu = Default::default();
+    /// let t: Tensor<Rank3<2, 2, 2>, f32, _> = dev.tensor([[[1.0, 2.0], [3.0, 4.0]], [[0.0; 2]; 2]]);
+    /// let r: Tensor<Rank1<2>, f32, _> = t.global_avg_pool2d();
+    /// assert_eq!(r.array(), [2.5, 0.0]);
+    /// ```
+    pub fn global_avg_pool2d(self) -> Tensor<(C,), E, D, T> {
+        self.try_global_avg_pool2d().unwrap()
+    }
+    /// Fallible version of [Tensor::global_avg_pool2d]
+    pub fn try_global_avg_pool2d(self) -> Result<Tensor<(C,), E, D, T>, D::Err> {
+        self.try_mean::<_, Axes2<1, 2>>()
+    }
+}
+
+#[allow(clippy::type_complexity)]
+impl<B: Dim, C: Dim, H: Dim, W: Dim, E: Dtype, D: Device<E>, T: Tape<E, D>>
+    Tensor<(B, C, H, W), E, D, T>
+{
+    /// Averages over the full height and width of a batch of images, reducing
+    /// `(B, C, H, W)` to `(B, C)`. The same as [MeanTo::mean] over `Axes2<2, 3>`.
+    ///
+    /// **Pytorch equivalent**: `torch.nn.functional.adaptive_avg_pool2d(t, 1).flatten(-3)`
+    ///
+    /// Example:
+    /// ```rust
+    /// # use dfdx::prelude::*;
+    /// # let dev: Cpu = Default::default();
+    /// let t: Tensor<Rank4<8, 3, 16, 16>, f32, _> = dev.ones();
+    /// let r: Tensor<Rank2<8, 3>, f32, _> = t.global_avg_pool2d();
+    /// assert_eq!(r.array(), [[1.0; 3]; 8]);
+    /// ```
+    pub fn global_avg_pool2d(self) -> Tensor<(B, C), E, D, T> {
+        self.try_global_avg_pool2d().unwrap()
+    }
+    /// Fallible version of [Tensor::global_avg_pool2d]
+    pub fn try_global_avg_pool2d(self) -> Result<Tensor<(B, C), E, D, T>, D::Err> {
+        self.try_mean::<_, Axes2<2, 3>>()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{shapes::*, tensor::*, tensor_ops::*, tests::*};
+
+    #[test]
+    fn test_global_avg_pool2d_3d() {
+        let dev: TestDevice = Default::default();
+        let t: Tensor<_, TestDtype, _> = dev.tensor([
+            [[1.0, 2.0, 3.0], [4.0, 5.0, 6.0]],
+            [[-1.0, 0.0, 1.0], [2.0, 3.0, -5.0]],
+        ]);
+        let r = t.trace().global_avg_pool2d();
+        assert_close(&r.array(), &[3.5, 0.0]);
+        let g = (r * dev.tensor([6.0, -12.0])).sum().backward();
+        assert_close(&g.get(&t).array(), &[[[1.0; 3]; 2], [[-2.0; 3]; 2]]);
+    }
+
+    #[test]
+    fn test_global_avg_pool2d_4d_grad() {
+        let dev: TestDevice = Default::default();
+        let t: Tensor<Rank4<2, 3, 4, 5>, TestDtype, _> = dev.sample_normal();
+        let r = t.trace().global_avg_pool2d();
+        let r2 = t.trace().mean::<Rank2<2, 3>, _>();
+        assert_close(&r.array(), &r2.array());
+        let g = r.exp().sum().backward();
+        let g2 = r2.exp().sum().backward();
+        assert_close(&g.get(&t).array(), &g2.get(&t).array());
+    }
+
+    #[cfg(feature = "nightly")]
+    #[test]
+    fn test_global_avg_pool2d_matches_full_kernel() {
+        let dev: TestDevice = Default::default();
+        let t: Tensor<Rank4<2, 3, 4, 4>, TestDtype, _> = dev.sample_normal();
+        let r = t.trace().global_avg_pool2d();
+        let r2 = t.trace().avg_pool2d::<4, 1, 0>().reshape::<Rank2<2, 3>>();
+        assert_close(&r.array(), &r2.array());
+        let g = r.square().sum().backward();
+        let g2 = r2.square().sum().backward();
+        assert_close(&g.get(&t).array(), &g2.get(&t).array());
+    }
+}
@@ -0,0 +1,178 @@
+use crate::{
+    shapes::{Dtype, Shape},
+    tensor::{Cpu, Tensor},
+};
+
+use super::GridSampleOp;
+
+use num_traits::Float;
+use std::sync::Arc;
+
+/// Converts a normalized location `g` into a pixel coordinate, along with the
+/// derivative of the coordinate with respect to `g`.
+fn unnormalize<E: Dtype + Float>(g: E, size: usize, border: bool) -> (E, E) {
+    let size_e = E::from_usize(size).unwrap();
+    let two = E::from_f64(2.0).unwrap();
+    let coord = ((g + E::ONE) * size_e - E::ONE) / two;
+    let scale = size_e / two;
+    if border {
+        let max = size_e - E::ONE;
+        if coord <= E::zero() {
+            return (E::zero(), E::zero());
+        } else if coord >= max {
+            return (max, E::zero());
+        }
+    }
+    (coord, scale)
+}
+
+/// The 4 pixels around a sampling location, with their bilinear weights.
+struct Neighbors<E> {
+    x0: isize,
+    y0: isize,
+    lx: E,
+    ly: E,
+    dx: E,
+    dy: E,
+}
+
+impl<E: Dtype + Float> Neighbors<E> {
+    fn new(op: &GridSampleOp, gx: E, gy: E) -> Self {
+        let (ix, dx) = unnormalize(gx, op.w_in, op.border);
+        let (iy, dy) = unnormalize(gy, op.h_in, op.border);
+        let x0 = ix.floor();
+        let y0 = iy.floor();
+        match (x0.to_isize(), y0.to_isize()) {
+            (Some(x0_i), Some(y0_i)) => Self {
+                x0: x0_i,
+                y0: y0_i,
+                lx: ix - x0,
+                ly: iy - y0,
+                dx,
+                dy,
+            },
+            // NaN, infinite, or too far away to index. All 4 neighbors are out of
+            // bounds, so the location contributes nothing and gets no gradient.
+            _ => Self {
+                x0: -2,
+                y0: -2,
+                lx: E::zero(),
+                ly: E::zero(),
+                dx: E::zero(),
+                dy: E::zero(),
+            },
+        }
+    }
+
+    /// The flat index of the pixel at offset `(oy, ox)` from `(y0, x0)`, or `None` if
+    /// the pixel is outside of the input.
+    fn index(&self, op: &GridSampleOp, istr: &[usize], oy: isize, ox: isize) -> Option<usize> {
+        let (y, x) = (self.y0 + oy, self.x0 + ox);
+        if y < 0 || x < 0 || y as usize >= op.h_in || x as usize >= op.w_in {
+            None
+        } else {
+            Some(y as usize * istr[2] + x as usize * istr[3])
+        }
+    }
+
+    /// The 4 neighbors in the order `(0, 0), (0, 1), (1, 0), (1, 1)`
+    fn indices(&self, op: &GridSampleOp, istr: &[usize]) -> [Option<usize>; 4] {
+        [
+            self.index(op, istr, 0, 0),
+            self.index(op, istr, 0, 1),
+            self.index(op, istr, 1, 0),
+            self.index(op, istr, 1, 1),
+        ]
+    }
+
+    fn weights(&self) -> [E; 4] {
+        let (hx, hy) = (E::ONE - self.lx, E::ONE - self.ly);
+        [hy * hx, hy * self.lx, self.ly * hx, self.ly * self.lx]
+    }
+}
+
+impl<E: Dtype + Float> super::GridSampleKernel<E> for Cpu {
+    fn forward<I: Shape, G: Shape, O: Shape>(
+        &self,
+        op: GridSampleOp,
+        inp: &Tensor<I, E, Self>,
+        grid: &Tensor<G, E, Self>,
+        out: &mut Tensor<O, E, Self>,
+    ) -> Result<(), Self::Err> {
+        let istr: [usize; 4] = std::array::from_fn(|i| inp.strides[i]);
+        let gstr: [usize; 4] = std::array::from_fn(|i| grid.strides[i]);
+        let ostr: [usize; 4] = std::array::from_fn(|i| out.strides[i]);
+
+        let buf = inp.data.as_ref();
+        let grid_buf = grid.data.as_ref();
+        let out_buf = Arc::make_mut(&mut out.data);
+        for b in 0..op.batch {
+            for oh in 0..op.h_out {
+                for ow in 0..op.w_out {
+                    let g = b * gstr[0] + oh * gstr[1] + ow * gstr[2];
+                    let n = Neighbors::new(&op, grid_buf[g], grid_buf[g + gstr[3]]);
+                    let indices = n.indices(&op, &istr);
+                    let weights = n.weights();
+                    for c in 0..op.chan {
+                        let base = b * istr[0] + c * istr[1];
+                        let mut v = E::zero();
+                        for (i, w) in indices.iter().zip(weights) {
+                            if let Some(i) = i {
+                                v += w * buf[base + i];
+                            }
+                        }
+                        out_buf[b * ostr[0] + c * ostr[1] + oh * ostr[2] + ow * ostr[3]] = v;
+                    }
+                }
+            }
+        }
+        Ok(())
+    }
+
+    fn backward<I: Shape, G: Shape, O: Shape>(
+        &self,
+        op: GridSampleOp,
+        inp: &Tensor<I, E, Self>,
+        grad_inp: &mut Self::Vec<E>,
+        grid: &Tensor<G, E, Self>,
+        grad_grid: &mut Self::Vec<E>,
+        out: &Tensor<O, E, Self>,
+        grad_out: &Self::Vec<E>,
+    ) -> Result<(), Self::Err> {
+        let istr: [usize; 4] = std::array::from_fn(|i| inp.strides[i]);
+        let gstr: [usize; 4] = std::array::from_fn(|i| grid.strides[i]);
+        let ostr: [usize; 4] = std::array::from_fn(|i| out.strides[i]);
+
+        let buf = inp.data.as_ref();
+        let grid_buf = grid.data.as_ref();
+        for b in 0..op.batch {
+            for oh in 0..op.h_out {
+                for ow in 0..op.w_out {
+                    let g = b * gstr[0] + oh * gstr[1] + ow * gstr[2];
+                    let n = Neighbors::new(&op, grid_buf[g], grid_buf[g + gstr[3]]);
+                    let indices = n.indices(&op, &istr);
+                    let weights = n.weights();
+                    let (hx, hy) = (E::ONE - n.lx, E::ONE - n.ly);
+                    let mut gx = E::zero();
+                    let mut gy = E::zero();
+                    for c in 0..op.chan {
+                        let base = b * istr[0] + c * istr[1];
+                        let go = grad_out[b * ostr[0] + c * ostr[1] + oh * ostr[2] + ow * ostr[3]];
+                        let mut v = [E::zero(); 4];
+                        for k in 0..4 {
+                            if let Some(i) = indices[k] {
+                                v[k] = buf[base + i];
+                                grad_inp[base + i] += weights[k] * go;
+                            }
+                        }
+                        gx += go * ((v[1] - v[0]) * hy + (v[3] - v[2]) * n.ly);
+                        gy += go * ((v[2] - v[0]) * hx + (v[3] - v[1]) * n.lx);
+                    }
+                    grad_grid[g] += gx * n.dx;
+                    grad_grid[g + gstr[3]] += gy * n.dy;
+                }
+            }
+        }
+        Ok(())
+    }
+}
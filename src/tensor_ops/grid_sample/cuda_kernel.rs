@@ -0,0 +1,100 @@
+use crate::{
+    shapes::*,
+    tensor::{Cuda, Tensor},
+};
+
+use super::GridSampleOp;
+
+use std::{sync::Arc, vec::Vec};
+
+use cudarc::driver::{DeviceRepr, LaunchAsync, LaunchConfig};
+
+const PTX_SRC: &str = include_str!(concat!(env!("OUT_DIR"), "/grid_sample.ptx"));
+
+unsafe impl DeviceRepr for GridSampleOp {}
+
+trait HasCudaKernel<E> {
+    const MOD: &'static str;
+    const FNS: &'static [&'static str];
+}
+
+impl HasCudaKernel<f32> for Cuda {
+    const MOD: &'static str = "grid_sample_f32";
+    const FNS: &'static [&'static str] = &["grid_sample_fwd_f32", "grid_sample_bwd_f32"];
+}
+
+impl HasCudaKernel<f64> for Cuda {
+    const MOD: &'static str = "grid_sample_f64";
+    const FNS: &'static [&'static str] = &["grid_sample_fwd_f64", "grid_sample_bwd_f64"];
+}
+
+impl<E: Dtype> super::GridSampleKernel<E> for Cuda
+where
+    Self: HasCudaKernel<E>,
+{
+    fn forward<I: Shape, G: Shape, O: Shape>(
+        &self,
+        op: GridSampleOp,
+        inp: &Tensor<I, E, Self>,
+        grid: &Tensor<G, E, Self>,
+        out: &mut Tensor<O, E, Self>,
+    ) -> Result<(), Self::Err> {
+        if !self.dev.has_func(Self::MOD, Self::FNS[0]) {
+            self.dev.load_ptx(PTX_SRC.into(), Self::MOD, Self::FNS)?;
+        }
+
+        let inp_strides: Vec<usize> = inp.strides.into();
+        let grid_strides: Vec<usize> = grid.strides.into();
+        let out_strides: Vec<usize> = out.strides.into();
+        let inp_strides = self.dev.htod_copy(inp_strides)?;
+        let grid_strides = self.dev.htod_copy(grid_strides)?;
+        let out_strides = self.dev.htod_copy(out_strides)?;
+        let fwd_fn = self.dev.get_func(Self::MOD, Self::FNS[0]).unwrap();
+        let cfg = LaunchConfig::for_num_elems(out.shape().num_elements() as u32);
+        let params = (
+            op,                           // const GridSampleOp op,
+            &inp_strides,                 // const size_t *inp_strides,
+            &grid_strides,                // const size_t *grid_strides,
+            &out_strides,                 // const size_t *out_strides,
+            inp.data.as_ref(),            // const float *inp,
+            grid.data.as_ref(),           // const float *grid,
+            Arc::make_mut(&mut out.data), // float *out
+        );
+        unsafe { fwd_fn.launch(cfg, params) }?;
+        Ok(())
+    }
+
+    fn backward<I: Shape, G: Shape, O: Shape>(
+        &self,
+        op: GridSampleOp,
+        inp: &Tensor<I, E, Self>,
+        grad_inp: &mut Self::Vec<E>,
+        grid: &Tensor<G, E, Self>,
+        grad_grid: &mut Self::Vec<E>,
+        out: &Tensor<O, E, Self>,
+        grad_out: &Self::Vec<E>,
+    ) -> Result<(), Self::Err> {
+        let inp_strides: Vec<usize> = inp.strides.into();
+        let grid_strides: Vec<usize> = grid.strides.into();
+        let out_strides: Vec<usize> = out.strides.into();
+        let inp_strides = self.dev.htod_copy(inp_strides)?;
+        let grid_strides = self.dev.htod_copy(grid_strides)?;
+        let out_strides = self.dev.htod_copy(out_strides)?;
+        let bwd_fn = self.dev.get_func(Self::MOD, Self::FNS[1]).unwrap();
+        // one thread per sampling location, which loops over the channels
+        let cfg = LaunchConfig::for_num_elems((op.batch * op.h_out * op.w_out) as u32);
+        let params = (
+            op,                 // const GridSampleOp op,
+            &inp_strides,       // const size_t *inp_strides,
+            &grid_strides,      // const size_t *grid_strides,
+            &out_strides,       // const size_t *out_strides,
+            inp.data.as_ref(),  // const float *inp,
+            grad_inp,           // float *grad_inp,
+            grid.data.as_ref(), // const float *grid,
+            grad_grid,          // float *grad_grid,
+            grad_out,           // const float *grad_out
+        );
+        unsafe { bwd_fn.launch(cfg, params) }?;
+        Ok(())
+    }
+}
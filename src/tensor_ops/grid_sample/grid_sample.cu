@@ -0,0 +1,202 @@
+#include "cuda_utils.cuh"
+
+struct GridSampleOp {
+    size_t batch;
+    size_t chan;
+    size_t h_in;
+    size_t w_in;
+    size_t h_out;
+    size_t w_out;
+    bool border;
+};
+
+// converts a normalized location `g` into a pixel coordinate, and the derivative
+// of the coordinate with respect to `g`
+template<typename T>
+__device__ void unnormalize(T g, size_t size, bool border, T *coord, T *scale) {
+    *coord = ((g + 1.0) * static_cast<T>(size) - 1.0) / 2.0;
+    *scale = static_cast<T>(size) / 2.0;
+    if (border) {
+        const T max = static_cast<T>(size) - 1.0;
+        if (*coord <= 0.0) {
+            *coord = 0.0;
+            *scale = 0.0;
+        } else if (*coord >= max) {
+            *coord = max;
+            *scale = 0.0;
+        }
+    }
+}
+
+// whether any of the 4 pixels around (fy, fx) is inside the input. false for NaN, and
+// checked before casting to long, since casting NaN or huge values is undefined
+template<typename T>
+__device__ bool any_neighbor_inside(const GridSampleOp op, T fy, T fx) {
+    return fx >= -1.0 && fx < static_cast<T>(op.w_in) && fy >= -1.0 && fy < static_cast<T>(op.h_in);
+}
+
+// the flat index of pixel (y, x), or -1 if it is outside of the input
+__device__ long neighbor_index(const GridSampleOp op, const size_t *inp_strides, long y, long x) {
+    if (y < 0 || x < 0 || y >= op.h_in || x >= op.w_in) {
+        return -1;
+    }
+    return y * inp_strides[2] + x * inp_strides[3];
+}
+
+template<typename T>
+__device__ void grid_sample_fwd(
+    const GridSampleOp op,
+    const size_t *inp_strides,
+    const size_t *grid_strides,
+    const size_t *out_strides,
+    const T *inp, // 4d (Batch, Channels, Height, Width)
+    const T *grid, // 4d (Batch, HeightOut, WidthOut, 2)
+    T *out // 4d (Batch, Channels, HeightOut, WidthOut)
+) {
+    unsigned int i = blockIdx.x * blockDim.x + threadIdx.x;
+    const size_t numel = op.batch * op.chan * op.h_out * op.w_out;
+    if (i >= numel) {
+        return;
+    }
+
+    unsigned int idx = i;
+    const size_t ow = idx % op.w_out;
+    idx /= op.w_out;
+    const size_t oh = idx % op.h_out;
+    idx /= op.h_out;
+    const size_t c = idx % op.chan;
+    idx /= op.chan;
+    const size_t b = idx % op.batch;
+
+    const T *g = grid + b * grid_strides[0] + oh * grid_strides[1] + ow * grid_strides[2];
+    T ix, iy, dx, dy;
+    unnormalize(g[0], op.w_in, op.border, &ix, &dx);
+    unnormalize(g[grid_strides[3]], op.h_in, op.border, &iy, &dy);
+    const T fx = floor(ix);
+    const T fy = floor(iy);
+    T *o = out + b * out_strides[0] + c * out_strides[1] + oh * out_strides[2] + ow * out_strides[3];
+    if (!any_neighbor_inside(op, fy, fx)) {
+        *o = 0.0;
+        return;
+    }
+    const long x0 = static_cast<long>(fx);
+    const long y0 = static_cast<long>(fy);
+    const T lx = ix - fx;
+    const T ly = iy - fy;
+    const T hx = 1.0 - lx;
+    const T hy = 1.0 - ly;
+
+    const long n[4] = {
+        neighbor_index(op, inp_strides, y0, x0),
+        neighbor_index(op, inp_strides, y0, x0 + 1),
+        neighbor_index(op, inp_strides, y0 + 1, x0),
+        neighbor_index(op, inp_strides, y0 + 1, x0 + 1),
+    };
+    const T w[4] = {hy * hx, hy * lx, ly * hx, ly * lx};
+
+    const T *base = inp + b * inp_strides[0] + c * inp_strides[1];
+    T v = 0.0;
+    for (int k = 0; k < 4; k++) {
+        if (n[k] >= 0) {
+            v += w[k] * base[n[k]];
+        }
+    }
+    *o = v;
+}
+
+template<typename T>
+__device__ void grid_sample_bwd(
+    const GridSampleOp op,
+    const size_t *inp_strides,
+    const size_t *grid_strides,
+    const size_t *out_strides,
+    const T *inp, // 4d (Batch, Channels, Height, Width)
+    T *grad_inp, // 4d (Batch, Channels, Height, Width)
+    const T *grid, // 4d (Batch, HeightOut, WidthOut, 2)
+    T *grad_grid, // 4d (Batch, HeightOut, WidthOut, 2)
+    const T *grad_out // 4d (Batch, Channels, HeightOut, WidthOut)
+) {
+    unsigned int i = blockIdx.x * blockDim.x + threadIdx.x;
+    const size_t numel = op.batch * op.h_out * op.w_out;
+    if (i >= numel) {
+        return;
+    }
+
+    unsigned int idx = i;
+    const size_t ow = idx % op.w_out;
+    idx /= op.w_out;
+    const size_t oh = idx % op.h_out;
+    idx /= op.h_out;
+    const size_t b = idx % op.batch;
+
+    const size_t g_i = b * grid_strides[0] + oh * grid_strides[1] + ow * grid_strides[2];
+    T ix, iy, dx, dy;
+    unnormalize(grid[g_i], op.w_in, op.border, &ix, &dx);
+    unnormalize(grid[g_i + grid_strides[3]], op.h_in, op.border, &iy, &dy);
+    const T fx = floor(ix);
+    const T fy = floor(iy);
+    if (!any_neighbor_inside(op, fy, fx)) {
+        return;
+    }
+    const long x0 = static_cast<long>(fx);
+    const long y0 = static_cast<long>(fy);
+    const T lx = ix - fx;
+    const T ly = iy - fy;
+    const T hx = 1.0 - lx;
+    const T hy = 1.0 - ly;
+
+    const long n[4] = {
+        neighbor_index(op, inp_strides, y0, x0),
+        neighbor_index(op, inp_strides, y0, x0 + 1),
+        neighbor_index(op, inp_strides, y0 + 1, x0),
+        neighbor_index(op, inp_strides, y0 + 1, x0 + 1),
+    };
+    const T w[4] = {hy * hx, hy * lx, ly * hx, ly * lx};
+
+    T gx = 0.0;
+    T gy = 0.0;
+    for (size_t c = 0; c < op.chan; c++) {
+        const size_t base = b * inp_strides[0] + c * inp_strides[1];
+        const T go = grad_out[b * out_strides[0] + c * out_strides[1] + oh * out_strides[2] + ow * out_strides[3]];
+        T v[4] = {0.0, 0.0, 0.0, 0.0};
+        for (int k = 0; k < 4; k++) {
+            if (n[k] >= 0) {
+                v[k] = inp[base + n[k]];
+                atomicAdd(grad_inp + base + n[k], w[k] * go);
+            }
+        }
+        gx += go * ((v[1] - v[0]) * hy + (v[3] - v[2]) * ly);
+        gy += go * ((v[2] - v[0]) * hx + (v[3] - v[1]) * lx);
+    }
+    atomicAdd(grad_grid + g_i, gx * dx);
+    atomicAdd(grad_grid + g_i + grid_strides[3], gy * dy);
+}
+
+#define GRID_SAMPLE(TYPENAME, FWD, BWD) \
+extern "C" __global__ void FWD( \
+    const GridSampleOp op, \
+    const size_t *inp_strides, \
+    const size_t *grid_strides, \
+    const size_t *out_strides, \
+    const TYPENAME *inp, \
+    const TYPENAME *grid, \
+    TYPENAME *out \
+) { \
+    grid_sample_fwd(op, inp_strides, grid_strides, out_strides, inp, grid, out); \
+} \
+extern "C" __global__ void BWD( \
+    const GridSampleOp op, \
+    const size_t *inp_strides, \
+    const size_t *grid_strides, \
+    const size_t *out_strides, \
+    const TYPENAME *inp, \
+    TYPENAME *grad_inp, \
+    const TYPENAME *grid, \
+    TYPENAME *grad_grid, \
+    const TYPENAME *grad_out \
+) { \
+    grid_sample_bwd(op, inp_strides, grid_strides, out_strides, inp, grad_inp, grid, grad_grid, grad_out); \
+}
+
+GRID_SAMPLE(float, grid_sample_fwd_f32, grid_sample_bwd_f32);
+GRID_SAMPLE(double, grid_sample_fwd_f64, grid_sample_bwd_f64);
@@ -0,0 +1,330 @@
+#![allow(clippy::type_complexity)]
+
+mod cpu_kernel;
+
+#[cfg(feature = "cuda")]
+mod cuda_kernel;
+
+use crate::{
+    gradients::{Merge, Tape},
+    shapes::*,
+    tensor::{DeviceStorage, PutTape, SplitTape, Tensor, ZerosTensor},
+};
+
+use super::{Device, PermuteTo, ReshapeTo, TryMatMul};
+
+/// How [grid_sample] treats sampling locations outside of the input.
+#[derive(Debug, Default, Copy, Clone, PartialEq, Eq)]
+pub enum PaddingMode {
+    /// Pixels outside of the input are `0`.
+    #[default]
+    Zeros,
+    /// Locations outside of the input are clamped to the closest border pixel.
+    Border,
+}
+
+#[repr(C)]
+#[derive(Debug, Copy, Clone)]
+pub struct GridSampleOp {
+    pub batch: usize,
+    pub chan: usize,
+    pub h_in: usize,
+    pub w_in: usize,
+    pub h_out: usize,
+    pub w_out: usize,
+    pub border: bool,
+}
+
+pub trait GridSampleKernel<E: Dtype>: DeviceStorage {
+    fn forward<I: Shape, G: Shape, O: Shape>(
+        &self,
+        op: GridSampleOp,
+        inp: &Tensor<I, E, Self>,
+        grid: &Tensor<G, E, Self>,
+        out: &mut Tensor<O, E, Self>,
+    ) -> Result<(), Self::Err>;
+
+    #[allow(clippy::too_many_arguments)]
+    fn backward<I: Shape, G: Shape, O: Shape>(
+        &self,
+        op: GridSampleOp,
+        inp: &Tensor<I, E, Self>,
+        grad_inp: &mut Self::Vec<E>,
+        grid: &Tensor<G, E, Self>,
+        grad_grid: &mut Self::Vec<E>,
+        out: &Tensor<O, E, Self>,
+        grad_out: &Self::Vec<E>,
+    ) -> Result<(), Self::Err>;
+}
+
+/// Generates the `(x, y)` sampling locations for [grid_sample] that apply the
+/// affine transforms `theta` to an output of size `(H, W)`.
+///
+/// Locations are normalized to `[-1, 1]`, where `-1` is the left/top edge of the image
+/// and `1` is the right/bottom edge. The identity transform
+/// `[[1, 0, 0], [0, 1, 0]]` samples the center of each pixel.
+///
+/// **Pytorch equivalent**: `F.affine_grid(theta, (B, C, H, W), align_corners=False)`
+///
+/// Example:
+/// ```rust
+/// # use dfdx::prelude::*;
+/// # let dev: Cpu = Default::default();
+/// let theta = dev.tensor([[[1.0, 0.0, 0.0], [0.0, 1.0, 0.0]]]);
+/// let grid = affine_grid(theta, (Const::<2>, Const::<2>));
+/// assert_eq!(
+///     grid.array(),
+///     [[[[-0.5, -0.5], [0.5, -0.5]], [[-0.5, 0.5], [0.5, 0.5]]]]
+/// );
+/// ```
+pub fn affine_grid<B: Dim, H: Dim, W: Dim, E: Dtype, D: Device<E>, T: Tape<E, D>>(
+    theta: Tensor<(B, Const<2>, Const<3>), E, D, T>,
+    size: (H, W),
+) -> Tensor<(B, H, W, Const<2>), E, D, T> {
+    theta.affine_grid(size)
+}
+
+impl<B: Dim, E: Dtype, D: Device<E>, T: Tape<E, D>> Tensor<(B, Const<2>, Const<3>), E, D, T> {
+    /// See [affine_grid]
+    pub fn affine_grid<H: Dim, W: Dim>(self, size: (H, W)) -> Tensor<(B, H, W, Const<2>), E, D, T> {
+        self.try_affine_grid(size).unwrap()
+    }
+
+    /// See [affine_grid]
+    pub fn try_affine_grid<H: Dim, W: Dim>(
+        self,
+        (h, w): (H, W),
+    ) -> Result<Tensor<(B, H, W, Const<2>), E, D, T>, D::Err> {
+        let (height, width) = (h.size(), w.size());
+
+        // the homogeneous coordinates `(x, y, 1)` of each output pixel, as columns
+        let mut base = std::vec::Vec::with_capacity(3 * height * width);
+        for k in 0..3 {
+            for y in 0..height {
+                for x in 0..width {
+                    base.push(match k {
+                        0 => (2 * x + 1) as f64 / width as f64 - 1.0,
+                        1 => (2 * y + 1) as f64 / height as f64 - 1.0,
+                        _ => 1.0,
+                    });
+                }
+            }
+        }
+        let base = base.into_iter().map(|v| E::from_f64(v).unwrap()).collect();
+        let base = self
+            .device
+            .try_tensor_from_vec(base, (Const::<3>, height * width))?;
+
+        let batch = self.shape.0;
+        self.try_matmul(base)?
+            .try_permute::<_, Axes3<0, 2, 1>>()?
+            .try_reshape_like(&(batch, h, w, Const))
+    }
+}
+
+/// Samples `input` at the `(x, y)` locations in `grid` with bilinear interpolation.
+///
+/// `input` has shape `(B, C, H, W)`, and `grid` has shape `(B, H_out, W_out, 2)`, where
+/// locations are normalized to `[-1, 1]` like in [affine_grid]. The result has shape
+/// `(B, C, H_out, W_out)`. Locations outside of the input are handled according to `mode`.
+///
+/// Gradients flow to both `input` and `grid`.
+///
+/// **Pytorch equivalent**:
+/// `F.grid_sample(input, grid, mode="bilinear", padding_mode=mode, align_corners=False)`
+///
+/// Example:
+/// ```rust
+/// # use dfdx::prelude::*;
+/// # let dev: Cpu = Default::default();
+/// let img: Tensor<Rank4<1, 1, 2, 2>, f32, _> = dev.tensor([[[[1.0, 2.0], [3.0, 4.0]]]]);
+/// // sample the center of the image
+/// let grid: Tensor<Rank4<1, 1, 1, 2>, f32, _> = dev.tensor([[[[0.0, 0.0]]]]);
+/// let r = grid_sample(img, grid, PaddingMode::Zeros);
+/// assert_eq!(r.array(), [[[[2.5]]]]);
+/// ```
+pub fn grid_sample<B: Dim, C: Dim, H: Dim, W: Dim, HO: Dim, WO: Dim, E: Dtype, D, T, R>(
+    input: Tensor<(B, C, H, W), E, D, T>,
+    grid: Tensor<(B, HO, WO, Const<2>), E, D, R>,
+    mode: PaddingMode,
+) -> Tensor<(B, C, HO, WO), E, D, T>
+where
+    D: GridSampleKernel<E> + ZerosTensor<E>,
+    T: Tape<E, D> + Merge<R>,
+    R: Default,
+{
+    input.grid_sample(grid, mode)
+}
+
+impl<B: Dim, C: Dim, H: Dim, W: Dim, E: Dtype, D, T> Tensor<(B, C, H, W), E, D, T>
+where
+    D: GridSampleKernel<E> + ZerosTensor<E>,
+    T: Tape<E, D>,
+{
+    /// See [grid_sample]
+    pub fn grid_sample<HO: Dim, WO: Dim, R: Default>(
+        self,
+        grid: Tensor<(B, HO, WO, Const<2>), E, D, R>,
+        mode: PaddingMode,
+    ) -> Tensor<(B, C, HO, WO), E, D, T>
+    where
+        T: Merge<R>,
+    {
+        self.try_grid_sample(grid, mode).unwrap()
+    }
+
+    /// See [grid_sample]
+    pub fn try_grid_sample<HO: Dim, WO: Dim, R: Default>(
+        self,
+        grid: Tensor<(B, HO, WO, Const<2>), E, D, R>,
+        mode: PaddingMode,
+    ) -> Result<Tensor<(B, C, HO, WO), E, D, T>, D::Err>
+    where
+        T: Merge<R>,
+    {
+        let (batch, chan, h_in, w_in) = self.shape;
+        let (_, h_out, w_out, _) = grid.shape;
+        assert_eq!(batch.size(), grid.shape.0.size());
+        let op = GridSampleOp {
+            batch: batch.size(),
+            chan: chan.size(),
+            h_in: h_in.size(),
+            w_in: w_in.size(),
+            h_out: h_out.size(),
+            w_out: w_out.size(),
+            border: mode == PaddingMode::Border,
+        };
+
+        let (inp, inp_tape) = self.split_tape();
+        let (grid, grid_tape) = grid.split_tape();
+        let mut tape = inp_tape.merge(grid_tape);
+        let mut out = inp.device.try_zeros_like(&(batch, chan, h_out, w_out))?;
+        inp.device.forward(op, &inp, &grid, &mut out)?;
+        let phantom_out = out.clone();
+        tape.try_alloc_grad(&inp)?;
+        tape.try_alloc_grad(&grid)?;
+        tape.try_alloc_grad(&out)?;
+        tape.add_reusable_backward_op(move |grads| {
+            let (grad_inp, grad_grid, grad_out) = grads.muts_and_ref(&inp, &grid, &phantom_out);
+            inp.device
+                .backward(op, &inp, grad_inp, &grid, grad_grid, &phantom_out, grad_out)
+        });
+        Ok(out.put_tape(tape))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{tensor::*, tensor_ops::*, tests::*};
+
+    #[test]
+    fn test_affine_grid_identity_sample() {
+        let dev: TestDevice = Default::default();
+        let img: Tensor<Rank4<1, 2, 3, 4>, TestDtype, _> = dev.sample_normal();
+        let theta: Tensor<_, TestDtype, _> = dev.tensor([[[1.0, 0.0, 0.0], [0.0, 1.0, 0.0]]]);
+        let grid = affine_grid(theta, (Const::<3>, Const::<4>));
+        let r = grid_sample(img.clone(), grid, PaddingMode::Zeros);
+        assert_close(&r.array(), &img.array());
+    }
+
+    #[test]
+    fn test_affine_grid_translate() {
+        let dev: TestDevice = Default::default();
+        let img: Tensor<Rank4<1, 1, 2, 3>, TestDtype, _> =
+            dev.tensor([[[[1.0, 2.0, 3.0], [4.0, 5.0, 6.0]]]]);
+
+        // a pixel is 2/3 wide, so this samples one pixel to the right
+        let theta: Tensor<_, TestDtype, _> = dev.tensor([[[1.0, 0.0, 2.0 / 3.0], [0.0, 1.0, 0.0]]]);
+        let grid = affine_grid(theta.clone(), (Const::<2>, Const::<3>));
+        let r = grid_sample(img.clone(), grid.clone(), PaddingMode::Zeros);
+        assert_close(&r.array(), &[[[[2.0, 3.0, 0.0], [5.0, 6.0, 0.0]]]]);
+        let r = grid_sample(img, grid, PaddingMode::Border);
+        assert_close(&r.array(), &[[[[2.0, 3.0, 3.0], [5.0, 6.0, 6.0]]]]);
+    }
+
+    #[test]
+    fn test_grid_sample_bilinear() {
+        let dev: TestDevice = Default::default();
+        let img: Tensor<Rank4<1, 1, 2, 2>, TestDtype, _> = dev.tensor([[[[1.0, 2.0], [3.0, 4.0]]]]);
+        let grid: Tensor<Rank4<1, 1, 3, 2>, TestDtype, _> =
+            dev.tensor([[[[0.0, 0.0], [-0.5, 0.25], [1.0, -1.0]]]]);
+        let r = grid_sample(img.trace(), grid.trace(), PaddingMode::Zeros);
+        // pixel coordinates are (0.5, 0.5), (0.0, 0.75) and (1.5, -0.5)
+        assert_close(&r.array(), &[[[[2.5, 2.5, 0.5]]]]);
+
+        let g = r.sum().backward();
+        assert_close(
+            &g.get(&img).array(),
+            &[[[[0.25 + 0.25, 0.25 + 0.25], [0.25 + 0.75, 0.25]]]],
+        );
+        // d/dx = (v01 - v00) * (1 - ly) + (v11 - v10) * ly scaled by W / 2,
+        // and d/dy = (v10 - v00) * (1 - lx) + (v11 - v01) * lx scaled by H / 2
+        assert_close(
+            &g.get(&grid).array(),
+            &[[[[1.0, 2.0], [1.0, 2.0], [-1.0, 1.0]]]],
+        );
+    }
+
+    #[test]
+    fn test_grid_sample_non_finite_is_out_of_bounds() {
+        let dev: TestDevice = Default::default();
+        let img: Tensor<Rank4<1, 1, 2, 2>, TestDtype, _> = dev.tensor([[[[1.0, 2.0], [3.0, 4.0]]]]);
+        let grid: Tensor<Rank4<1, 1, 4, 2>, TestDtype, _> = dev.tensor([[[
+            [TestDtype::NAN, 0.0],
+            [0.0, TestDtype::INFINITY],
+            [-1e30, 0.0],
+            [0.0, 0.0],
+        ]]]);
+        let r = grid_sample(img.trace(), grid.trace(), PaddingMode::Zeros);
+        assert_eq!(r.array(), [[[[0.0, 0.0, 0.0, 2.5]]]]);
+        let g = r.sum().backward();
+        assert_eq!(g.get(&img).array(), [[[[0.25; 2]; 2]]]);
+        assert_eq!(
+            g.get(&grid).array(),
+            [[[[0.0, 0.0], [0.0, 0.0], [0.0, 0.0], [1.0, 2.0]]]]
+        );
+    }
+
+    #[test]
+    fn test_affine_grid_theta_gradient() {
+        let dev: TestDevice = Default::default();
+        let img: Tensor<Rank4<1, 1, 3, 3>, TestDtype, _> =
+            dev.tensor([[[[0.0, 1.0, 0.0], [1.0, 4.0, 2.0], [0.0, 3.0, 1.0]]]]);
+        let theta: Tensor<_, TestDtype, _> = dev.tensor([[[0.9, 0.1, 0.05], [-0.1, 0.8, -0.1]]]);
+
+        let loss = |theta: Tensor<Rank3<1, 2, 3>, TestDtype, _>| -> TestDtype {
+            let grid = affine_grid(theta, (Const::<2>, Const::<2>));
+            grid_sample(img.clone(), grid, PaddingMode::Zeros)
+                .square()
+                .sum::<Rank0, _>()
+                .array()
+        };
+
+        let grid = affine_grid(theta.trace(), (Const::<2>, Const::<2>));
+        // the tape has to be on the input for the gradients to flow back through grid
+        let (grid, tape) = grid.split_tape();
+        let r = grid_sample(img.clone().put_tape(tape), grid, PaddingMode::Zeros);
+        let g = r.square().sum().backward();
+        let grad = g.get(&theta).array();
+
+        // compare against central differences. none of the sampled locations
+        // are close to a pixel boundary, so the loss is smooth around theta
+        let eps: TestDtype = 1e-2;
+        let t = theta.array();
+        for i in 0..2 {
+            for j in 0..3 {
+                let mut hi = t;
+                hi[0][i][j] += eps;
+                let mut lo = t;
+                lo[0][i][j] -= eps;
+                let fd = (loss(dev.tensor(hi)) - loss(dev.tensor(lo))) / (2.0 * eps);
+                assert!(
+                    (grad[0][i][j] - fd).abs() < 1e-2 * fd.abs().max(1.0),
+                    "{i} {j}: {} vs {fd}",
+                    grad[0][i][j]
+                );
+            }
+        }
+    }
+}
@@ -0,0 +1,42 @@
+use crate::shapes::{Dim, Dtype, Shape};
+use crate::tensor::{
+    cpu::{index_to_i, LendingIterator, NdIndex},
+    Cpu, Tensor, ZerosTensor,
+};
+
+impl<E: Dtype> super::IndexSelectKernel<E> for Cpu {
+    fn forward<Src: Shape, Dst: Shape<Concrete = Src::Concrete>, New: Dim>(
+        &self,
+        ax: usize,
+        inp: &Tensor<Src, E, Self>,
+        idx: &Tensor<(New,), usize, Self>,
+        dst: Dst,
+    ) -> Result<Tensor<Dst, E, Self>, Self::Err> {
+        let mut out = self.try_zeros_like(&dst)?;
+        let mut out_iter = out.iter_mut_with_index();
+        while let Some((x, i_out)) = out_iter.next() {
+            let mut i_inp = i_out;
+            i_inp[ax] = idx[[i_out[ax]]];
+            *x = inp[i_inp];
+        }
+        Ok(out)
+    }
+
+    fn backward<Src: Shape, Dst: Shape<Concrete = Src::Concrete>, New: Dim>(
+        &self,
+        ax: usize,
+        inp: &Tensor<Src, E, Self>,
+        grad_inp: &mut Self::Vec<E>,
+        idx: &Tensor<(New,), usize, Self>,
+        out: &Tensor<Dst, E, Self>,
+        grad_out: &Self::Vec<E>,
+    ) -> Result<(), Self::Err> {
+        let mut out_idx = NdIndex::new(out.shape, out.strides);
+        while let Some((i_out, i_dst)) = out_idx.next_with_idx() {
+            let mut i_inp = i_dst;
+            i_inp[ax] = idx[[i_dst[ax]]];
+            grad_inp[index_to_i(&inp.shape, &inp.strides, i_inp)] += grad_out[i_out];
+        }
+        Ok(())
+    }
+}
@@ -0,0 +1,96 @@
+use crate::{
+    shapes::{Dim, Shape},
+    tensor::{Cuda, Tensor},
+};
+use cudarc::driver::{DeviceSlice, LaunchAsync, LaunchConfig};
+
+const PTX_SRC: &str = include_str!(concat!(env!("OUT_DIR"), "/index_select.ptx"));
+
+macro_rules! impl_cuda_kernels {
+    ($TypeName:ty, $Mod:tt, $Fwd:tt, $Bwd:tt) => {
+        impl super::IndexSelectKernel<$TypeName> for Cuda {
+            fn forward<Src: Shape, Dst: Shape<Concrete = Src::Concrete>, New: Dim>(
+                &self,
+                ax: usize,
+                inp: &Tensor<Src, $TypeName, Self>,
+                idx: &Tensor<(New,), usize, Self>,
+                dst: Dst,
+            ) -> Result<Tensor<Dst, $TypeName, Self>, Self::Err> {
+                if !self.dev.has_func($Mod, $Fwd) {
+                    self.dev.load_ptx(PTX_SRC.into(), $Mod, &[$Fwd, $Bwd])?;
+                }
+
+                let numel = dst.num_elements();
+                let mut storage = self.dev.alloc_zeros::<$TypeName>(numel)?;
+
+                let inp_dims = self.dev.htod_copy(inp.shape.concrete().into())?;
+                let inp_strides = self.dev.htod_copy(inp.strides.into())?;
+                let out_dims = self.dev.htod_copy(dst.concrete().into())?;
+
+                let fwd_fn = self.dev.get_func($Mod, $Fwd).unwrap();
+                let cfg = LaunchConfig::for_num_elems(numel as u32);
+                let params = (
+                    numel,             // const size_t numel,
+                    inp.data.as_ref(), // const float *inp,
+                    Src::NUM_DIMS,     // const size_t num_dims,
+                    ax,                // const size_t ax,
+                    &inp_dims,         // const size_t *inp_dims,
+                    &inp_strides,      // const size_t *inp_strides,
+                    idx.data.as_ref(), // const size_t *idx,
+                    idx.strides[0],    // const size_t idx_stride,
+                    &out_dims,         // const size_t *out_dims,
+                    &mut storage,      // float *out,
+                );
+                unsafe { fwd_fn.launch(cfg, params) }?;
+
+                Ok(self.build_tensor(dst, dst.strides(), storage))
+            }
+
+            fn backward<Src: Shape, Dst: Shape<Concrete = Src::Concrete>, New: Dim>(
+                &self,
+                ax: usize,
+                inp: &Tensor<Src, $TypeName, Self>,
+                grad_inp: &mut Self::Vec<$TypeName>,
+                idx: &Tensor<(New,), usize, Self>,
+                out: &Tensor<Dst, $TypeName, Self>,
+                grad_out: &Self::Vec<$TypeName>,
+            ) -> Result<(), Self::Err> {
+                let bwd_fn = self.dev.get_func($Mod, $Bwd).unwrap();
+                let numel = grad_out.len();
+
+                let inp_dims = self.dev.htod_copy(inp.shape.concrete().into())?;
+                let inp_strides = self.dev.htod_copy(inp.strides.into())?;
+                let out_dims = self.dev.htod_copy(out.shape.concrete().into())?;
+
+                let cfg = LaunchConfig::for_num_elems(numel as u32);
+                let params = (
+                    numel,             // const size_t numel,
+                    grad_inp,          // float *grad_inp,
+                    Src::NUM_DIMS,     // const size_t num_dims,
+                    ax,                // const size_t ax,
+                    &inp_dims,         // const size_t *inp_dims,
+                    &inp_strides,      // const size_t *inp_strides,
+                    idx.data.as_ref(), // const size_t *idx,
+                    idx.strides[0],    // const size_t idx_stride,
+                    &out_dims,         // const size_t *out_dims,
+                    grad_out,          // const float *grad_out,
+                );
+                unsafe { bwd_fn.launch(cfg, params) }?;
+                Ok(())
+            }
+        }
+    };
+}
+
+impl_cuda_kernels!(
+    f32,
+    "index_select_f32",
+    "index_select_fwd_f32",
+    "index_select_bwd_f32"
+);
+impl_cuda_kernels!(
+    f64,
+    "index_select_f64",
+    "index_select_fwd_f64",
+    "index_select_bwd_f64"
+);
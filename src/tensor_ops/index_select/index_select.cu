@@ -0,0 +1,108 @@
+#include "cuda_utils.cuh"
+
+// converts the contiguous index `i` into `out` into the strided position in `inp`,
+// where the index along `ax` is looked up in `idx`.
+__device__ unsigned int get_selected_index(
+    unsigned int i,
+    const size_t num_dims,
+    const size_t ax,
+    const size_t *inp_dims,
+    const size_t *inp_strides,
+    const size_t *idx,
+    const size_t idx_stride,
+    const size_t *out_dims
+) {
+    unsigned int inp_i = 0;
+    for (unsigned int d = 0; d < num_dims; d++) {
+        unsigned int dim_idx = num_dims - 1 - d;
+        unsigned int j = i % out_dims[dim_idx];
+        if (dim_idx == ax) {
+            j = idx[j * idx_stride];
+            assert(j < inp_dims[ax]);
+        }
+        inp_i += j * inp_strides[dim_idx];
+        i /= out_dims[dim_idx];
+    }
+    return inp_i;
+}
+
+template<typename T>
+__device__ void index_select_fwd(
+    const size_t numel,
+    const T *inp,
+    const size_t num_dims,
+    const size_t ax,
+    const size_t *inp_dims,
+    const size_t *inp_strides,
+    const size_t *idx,
+    const size_t idx_stride,
+    const size_t *out_dims,
+    T *out
+) {
+    unsigned int i = blockIdx.x * blockDim.x + threadIdx.x;
+    if (i >= numel) {
+        return;
+    }
+
+    unsigned int inp_i =
+        get_selected_index(i, num_dims, ax, inp_dims, inp_strides, idx, idx_stride, out_dims);
+
+    out[i] = inp[inp_i];
+}
+
+template<typename T>
+__device__ void index_select_bwd(
+    const size_t numel,
+    T *grad_inp,
+    const size_t num_dims,
+    const size_t ax,
+    const size_t *inp_dims,
+    const size_t *inp_strides,
+    const size_t *idx,
+    const size_t idx_stride,
+    const size_t *out_dims,
+    const T *grad_out
+) {
+    unsigned int i = blockIdx.x * blockDim.x + threadIdx.x;
+    if (i >= numel) {
+        return;
+    }
+
+    unsigned int inp_i =
+        get_selected_index(i, num_dims, ax, inp_dims, inp_strides, idx, idx_stride, out_dims);
+
+    atomicAdd(grad_inp + inp_i, grad_out[i]);
+}
+
+#define INDEX_SELECT(TYPENAME, FWD, BWD) \
+extern "C" __global__ void FWD( \
+    const size_t numel, \
+    const TYPENAME *inp, \
+    const size_t num_dims, \
+    const size_t ax, \
+    const size_t *inp_dims, \
+    const size_t *inp_strides, \
+    const size_t *idx, \
+    const size_t idx_stride, \
+    const size_t *out_dims, \
+    TYPENAME *out \
+) { \
+    index_select_fwd(numel, inp, num_dims, ax, inp_dims, inp_strides, idx, idx_stride, out_dims, out); \
+} \
+extern "C" __global__ void BWD( \
+    const size_t numel, \
+    TYPENAME *grad_inp, \
+    const size_t num_dims, \
+    const size_t ax, \
+    const size_t *inp_dims, \
+    const size_t *inp_strides, \
+    const size_t *idx, \
+    const size_t idx_stride, \
+    const size_t *out_dims, \
+    const TYPENAME *grad_out \
+) { \
+    index_select_bwd(numel, grad_inp, num_dims, ax, inp_dims, inp_strides, idx, idx_stride, out_dims, grad_out); \
+}
+
+INDEX_SELECT(float, index_select_fwd_f32, index_select_bwd_f32);
+INDEX_SELECT(double, index_select_fwd_f64, index_select_bwd_f64);
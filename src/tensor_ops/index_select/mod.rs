@@ -0,0 +1,153 @@
+#![allow(clippy::type_complexity)]
+
+mod cpu_kernel;
+
+#[cfg(feature = "cuda")]
+mod cuda_kernel;
+
+use crate::{gradients::Tape, shapes::*, tensor::*};
+
+pub trait IndexSelectKernel<E: Dtype>: DeviceStorage {
+    fn forward<Src: Shape, Dst: Shape<Concrete = Src::Concrete>, New: Dim>(
+        &self,
+        ax: usize,
+        inp: &Tensor<Src, E, Self>,
+        idx: &Tensor<(New,), usize, Self>,
+        dst: Dst,
+    ) -> Result<Tensor<Dst, E, Self>, Self::Err>;
+    fn backward<Src: Shape, Dst: Shape<Concrete = Src::Concrete>, New: Dim>(
+        &self,
+        ax: usize,
+        inp: &Tensor<Src, E, Self>,
+        grad_inp: &mut Self::Vec<E>,
+        idx: &Tensor<(New,), usize, Self>,
+        out: &Tensor<Dst, E, Self>,
+        grad_out: &Self::Vec<E>,
+    ) -> Result<(), Self::Err>;
+}
+
+/// Select slices along a single axis given a 1d tensor of indices. The size
+/// of that axis in the output is the number of indices.
+///
+/// Indices can be in any order and can be repeated.
+///
+/// **Pytorch equivalent**: `torch.index_select(inp, dim, idx)`
+pub trait IndexSelect<D: DeviceStorage>: HasErr + HasShape {
+    /// Select along axis `Ax`:
+    /// ```rust
+    /// # use dfdx::prelude::*;
+    /// # let dev: Cpu = Default::default();
+    /// let a: Tensor<Rank2<2, 3>, f32, _> = dev.tensor([[1.0, 2.0, 3.0], [4.0, 5.0, 6.0]]);
+    ///
+    /// let idx: Tensor<Rank1<4>, usize, _> = dev.tensor([2, 0, 0, 1]);
+    /// let r: Tensor<Rank2<2, 4>, f32, _> = a.clone().index_select::<Axis<1>, _>(idx);
+    /// assert_eq!(r.array(), [[3.0, 1.0, 1.0, 2.0], [6.0, 4.0, 4.0, 5.0]]);
+    ///
+    /// let idx: Tensor<Rank1<1>, usize, _> = dev.tensor([1]);
+    /// let r: Tensor<Rank2<1, 3>, f32, _> = a.index_select::<Axis<0>, _>(idx);
+    /// assert_eq!(r.array(), [[4.0, 5.0, 6.0]]);
+    /// ```
+    fn index_select<Ax: Axes<Array = [isize; 1]>, New: Dim>(
+        self,
+        idx: Tensor<(New,), usize, D>,
+    ) -> Self::WithShape<<Self::Shape as ReplaceAxisWith<Ax, New>>::Replaced>
+    where
+        Self::Shape: ReplaceAxisWith<Ax, New>,
+    {
+        self.try_index_select::<Ax, New>(idx).unwrap()
+    }
+
+    /// Fallible version of [IndexSelect::index_select]
+    fn try_index_select<Ax: Axes<Array = [isize; 1]>, New: Dim>(
+        self,
+        idx: Tensor<(New,), usize, D>,
+    ) -> Result<Self::WithShape<<Self::Shape as ReplaceAxisWith<Ax, New>>::Replaced>, Self::Err>
+    where
+        Self::Shape: ReplaceAxisWith<Ax, New>;
+}
+
+impl<Src: Shape, E: Dtype, D: IndexSelectKernel<E>, T: Tape<E, D>> IndexSelect<D>
+    for Tensor<Src, E, D, T>
+{
+    fn try_index_select<Ax: Axes<Array = [isize; 1]>, New: Dim>(
+        self,
+        idx: Tensor<(New,), usize, D>,
+    ) -> Result<Self::WithShape<Src::Replaced>, Self::Err>
+    where
+        Src: ReplaceAxisWith<Ax, New>,
+    {
+        let ax = Ax::as_array()[0] as usize;
+        let dst = self.shape().replace_axis(idx.shape().0);
+        let (inp, mut tape) = self.split_tape();
+        let out = inp.device.forward(ax, &inp, &idx, dst)?;
+        let phantom_out = out.clone();
+        tape.try_alloc_grad(&inp)?;
+        tape.try_alloc_grad(&out)?;
+        tape.add_reusable_backward_op(move |grads| {
+            let (grad_inp, grad_out) = grads.mut_and_ref(&inp, &phantom_out);
+            inp.device
+                .backward(ax, &inp, grad_inp, &idx, &phantom_out, grad_out)
+        });
+        Ok(out.put_tape(tape))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::tensor_ops::*;
+    use crate::tests::*;
+
+    #[test]
+    fn test_index_select_repeated_rows() {
+        let dev: TestDevice = Default::default();
+        let t: Tensor<_, TestDtype, _> = dev.tensor([[1.0, 2.0], [3.0, 4.0], [5.0, 6.0]]);
+        let idx = dev.tensor([2, 0, 2, 2]);
+        let r = t.trace().index_select::<Axis<0>, _>(idx);
+        assert_eq!(r.array(), [[5.0, 6.0], [1.0, 2.0], [5.0, 6.0], [5.0, 6.0]]);
+        let g = (r * dev.tensor([[1.0, 2.0], [3.0, 4.0], [5.0, 6.0], [7.0, 8.0]]))
+            .sum()
+            .backward();
+        assert_eq!(g.get(&t).array(), [[3.0, 4.0], [0.0, 0.0], [13.0, 16.0]]);
+    }
+
+    #[test]
+    fn test_index_select_reorder_columns() {
+        let dev: TestDevice = Default::default();
+        let t: Tensor<_, TestDtype, _> = dev.tensor([[1.0, 2.0, 3.0], [4.0, 5.0, 6.0]]);
+        let idx = dev.tensor([2, 1, 0, 1]);
+        let r = t.trace().index_select::<Axis<1>, _>(idx);
+        assert_eq!(r.array(), [[3.0, 2.0, 1.0, 2.0], [6.0, 5.0, 4.0, 5.0]]);
+        let g = r.exp().sum().backward();
+        let e = t.array().map(|row| row.map(TestDtype::exp));
+        assert_close(
+            &g.get(&t).array(),
+            &[
+                [e[0][0], 2.0 * e[0][1], e[0][2]],
+                [e[1][0], 2.0 * e[1][1], e[1][2]],
+            ],
+        );
+    }
+
+    #[test]
+    fn test_index_select_3d_runtime_len() {
+        let dev: TestDevice = Default::default();
+        let t: Tensor<Rank3<2, 3, 2>, TestDtype, _> = dev.sample_normal();
+        let idx = dev.tensor_from_vec(std::vec![1, 1], (2,));
+        let r = t.trace().index_select::<Axis<1>, _>(idx);
+        assert_eq!(r.shape(), &(Const::<2>, 2, Const::<2>));
+        let t_arr = t.array();
+        assert_eq!(
+            r.as_vec(),
+            [t_arr[0][1], t_arr[0][1], t_arr[1][1], t_arr[1][1]].concat()
+        );
+        let g = r.sum().backward();
+        assert_eq!(
+            g.get(&t).array(),
+            [
+                [[0.0; 2], [2.0; 2], [0.0; 2]],
+                [[0.0; 2], [2.0; 2], [0.0; 2]]
+            ]
+        );
+    }
+}
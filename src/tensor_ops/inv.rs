@@ -0,0 +1,74 @@
+use crate::{
+    gradients::Tape,
+    shapes::{Axes2, Dim, Dtype},
+    tensor::Tensor,
+};
+
+use super::{qr::QrKernel, triangular_solve::TriangularSolveKernel, Device, PermuteTo};
+
+/// Inverse of a square matrix `a`, which must be invertible.
+///
+/// This is computed from the QR decomposition `a = q * r` as `r^-1 * q^T`, using [super::qr] and
+/// [super::triangular_solve], so gradients are propagated through both.
+///
+/// **Pytorch equivalent**: `torch.linalg.inv(a)`
+///
+/// Example:
+/// ```rust
+/// # use dfdx::prelude::*;
+/// # let dev: Cpu = Default::default();
+/// let a = dev.tensor([[2.0, 0.0], [0.0, 4.0]]);
+/// let r = inv(a);
+/// assert_eq!(r.array(), [[0.5, 0.0], [0.0, 0.25]]);
+/// ```
+pub fn inv<N: Dim, E: Dtype, D, T: Tape<E, D>>(
+    a: Tensor<(N, N), E, D, T>,
+) -> Tensor<(N, N), E, D, T>
+where
+    D: Device<E> + QrKernel<E> + TriangularSolveKernel<E>,
+{
+    a.inv()
+}
+
+impl<N: Dim, E: Dtype, D, T: Tape<E, D>> Tensor<(N, N), E, D, T>
+where
+    D: Device<E> + QrKernel<E> + TriangularSolveKernel<E>,
+{
+    /// See [inv]
+    pub fn inv(self) -> Self {
+        self.try_inv().unwrap()
+    }
+
+    /// See [inv]
+    pub fn try_inv(self) -> Result<Self, D::Err> {
+        let (q, r) = self.try_qr()?;
+        // NOTE: the backward op of qr is on the tape of q, which is merged in here
+        let q_t = q.try_permute::<_, Axes2<1, 0>>()?;
+        r.try_triangular_solve(q_t, true)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{shapes::*, tensor::*, tensor_ops::*, tests::*};
+
+    #[test]
+    fn test_inv() {
+        let dev: TestDevice = Default::default();
+        let a: Tensor<Rank2<3, 3>, TestDtype, _> =
+            dev.tensor([[2.0, -1.0, 0.0], [1.0, 3.0, 1.0], [0.5, 0.0, 4.0]]);
+        let a_inv = a.clone().inv();
+        assert_close_with_tolerance(
+            &a.clone().matmul(a_inv.clone()).array(),
+            &[[1.0, 0.0, 0.0], [0.0, 1.0, 0.0], [0.0, 0.0, 1.0]],
+            1e-5,
+        );
+
+        // d(sum(a^-1)) / da = -a^-T * ones * a^-T
+        let g = a.trace().inv().sum::<Rank0, _>().backward();
+        let a_inv_t = a_inv.clone().permute::<Rank2<3, 3>, _>();
+        let ones: Tensor<Rank2<3, 3>, TestDtype, _> = dev.ones();
+        let expected = a_inv_t.clone().matmul(ones).matmul(a_inv_t).negate();
+        assert_close_with_tolerance(&g.get(&a).array(), &expected.array(), 1e-4);
+    }
+}
@@ -0,0 +1,238 @@
+use super::broadcast_to::BroadcastKernel;
+use super::*;
+use crate::{gradients::Tape, shapes::*, tensor::*, unique_id::unique_id};
+
+/// The shape of `S` after reducing `Ax` with e.g. [Tensor::sum_keepdim].
+type Kept<S, Ax> = <S as ReduceShapeKeepDim<Ax>>::KeptDim;
+
+#[allow(clippy::type_complexity)]
+impl<S: Shape, E: Dtype, D: Device<E>, T: Tape<E, D>> Tensor<S, E, D, T> {
+    /// [SumTo::sum], but keeping the reduced axes as `Const<1>` instead of removing them.
+    /// The result can be expanded back to the shape of the input with [ExpandTo::expand_like].
+    ///
+    /// **Pytorch equivalent**: `t.sum(Axes, keepdim=True)`
+    ///
+    /// Example:
+    /// ```rust
+    /// # use dfdx::prelude::*;
+    /// # let dev: Cpu = Default::default();
+    /// let t: Tensor<Rank2<2, 3>, f32, _> = dev.tensor([[1.0, 2.0, 3.0], [-1.0, -2.0, -3.0]]);
+    /// let r: Tensor<Rank2<2, 1>, f32, _> = t.sum_keepdim::<Axis<1>>();
+    /// assert_eq!(r.array(), [[6.0], [-6.0]]);
+    /// ```
+    pub fn sum_keepdim<Ax: Axes>(self) -> Tensor<Kept<S, Ax>, E, D, T>
+    where
+        S: ReduceShapeKeepDim<Ax>,
+    {
+        self.try_sum_keepdim().unwrap()
+    }
+    /// Fallible version of [Tensor::sum_keepdim]
+    pub fn try_sum_keepdim<Ax: Axes>(self) -> Result<Tensor<Kept<S, Ax>, E, D, T>, D::Err>
+    where
+        S: ReduceShapeKeepDim<Ax>,
+    {
+        let dst = self.shape().keep_dim();
+        self.try_sum::<S::Reduced, Ax>()?
+            .try_broadcast_like::<Kept<S, Ax>, Ax>(&dst)
+    }
+
+    /// [MeanTo::mean], but keeping the reduced axes as `Const<1>`. See [Tensor::sum_keepdim].
+    pub fn mean_keepdim<Ax: Axes>(self) -> Tensor<Kept<S, Ax>, E, D, T>
+    where
+        S: ReduceShapeKeepDim<Ax>,
+    {
+        self.try_mean_keepdim().unwrap()
+    }
+    /// Fallible version of [Tensor::mean_keepdim]
+    pub fn try_mean_keepdim<Ax: Axes>(self) -> Result<Tensor<Kept<S, Ax>, E, D, T>, D::Err>
+    where
+        S: ReduceShapeKeepDim<Ax>,
+    {
+        let dst = self.shape().keep_dim();
+        self.try_mean::<S::Reduced, Ax>()?
+            .try_broadcast_like::<Kept<S, Ax>, Ax>(&dst)
+    }
+
+    /// [MaxTo::max], but keeping the reduced axes as `Const<1>`. See [Tensor::sum_keepdim].
+    ///
+    /// Example:
+    /// ```rust
+    /// # use dfdx::prelude::*;
+    /// # let dev: Cpu = Default::default();
+    /// let t: Tensor<Rank2<2, 3>, f32, _> = dev.tensor([[1.0, 2.0, 3.0], [-1.0, -2.0, -3.0]]);
+    /// let r: Tensor<Rank2<1, 3>, f32, _> = t.max_keepdim::<Axis<0>>();
+    /// assert_eq!(r.array(), [[1.0, 2.0, 3.0]]);
+    /// ```
+    pub fn max_keepdim<Ax: Axes>(self) -> Tensor<Kept<S, Ax>, E, D, T>
+    where
+        S: ReduceShapeKeepDim<Ax>,
+    {
+        self.try_max_keepdim().unwrap()
+    }
+    /// Fallible version of [Tensor::max_keepdim]
+    pub fn try_max_keepdim<Ax: Axes>(self) -> Result<Tensor<Kept<S, Ax>, E, D, T>, D::Err>
+    where
+        S: ReduceShapeKeepDim<Ax>,
+    {
+        let dst = self.shape().keep_dim();
+        self.try_max::<S::Reduced, Ax>()?
+            .try_broadcast_like::<Kept<S, Ax>, Ax>(&dst)
+    }
+
+    /// [MinTo::min], but keeping the reduced axes as `Const<1>`. See [Tensor::sum_keepdim].
+    pub fn min_keepdim<Ax: Axes>(self) -> Tensor<Kept<S, Ax>, E, D, T>
+    where
+        S: ReduceShapeKeepDim<Ax>,
+    {
+        self.try_min_keepdim().unwrap()
+    }
+    /// Fallible version of [Tensor::min_keepdim]
+    pub fn try_min_keepdim<Ax: Axes>(self) -> Result<Tensor<Kept<S, Ax>, E, D, T>, D::Err>
+    where
+        S: ReduceShapeKeepDim<Ax>,
+    {
+        let dst = self.shape().keep_dim();
+        self.try_min::<S::Reduced, Ax>()?
+            .try_broadcast_like::<Kept<S, Ax>, Ax>(&dst)
+    }
+}
+
+/// Expand dimensions of size 1 (e.g. produced by [Tensor::sum_keepdim]) into a bigger shape.
+///
+/// Like [BroadcastTo], this does not copy any data.
+///
+/// **Pytorch equivalent**: `t.expand_as(other)`
+pub trait ExpandTo: HasErr + HasShape {
+    /// Expand the size 1 axes `Ax` into shape `Dst`:
+    /// ```rust
+    /// # use dfdx::prelude::*;
+    /// # let dev: Cpu = Default::default();
+    /// let a: Tensor<Rank2<3, 1>, f32, _> = dev.tensor([[1.0], [2.0], [3.0]]);
+    /// let b: Tensor<Rank2<3, 5>, f32, _> = a.expand::<_, Axis<1>>();
+    /// assert_eq!(b.array(), [[1.0; 5], [2.0; 5], [3.0; 5]]);
+    /// ```
+    fn expand<Dst, Ax: Axes>(self) -> Self::WithShape<Dst>
+    where
+        Dst: ConstShape + ReduceShapeKeepDim<Ax, KeptDim = Self::Shape>,
+    {
+        self.try_expand_like(&Default::default()).unwrap()
+    }
+    /// Fallible version of [ExpandTo::expand]
+    fn try_expand<Dst, Ax: Axes>(self) -> Result<Self::WithShape<Dst>, Self::Err>
+    where
+        Dst: ConstShape + ReduceShapeKeepDim<Ax, KeptDim = Self::Shape>,
+    {
+        self.try_expand_like(&Default::default())
+    }
+    /// Same as [ExpandTo::expand], but the target shape is given
+    fn expand_like<Dst, Ax: Axes>(self, dst: &Dst) -> Self::WithShape<Dst>
+    where
+        Dst: Shape + ReduceShapeKeepDim<Ax, KeptDim = Self::Shape>,
+    {
+        self.try_expand_like(dst).unwrap()
+    }
+    /// Fallible version of [ExpandTo::expand_like]
+    fn try_expand_like<Dst, Ax: Axes>(self, dst: &Dst) -> Result<Self::WithShape<Dst>, Self::Err>
+    where
+        Dst: Shape + ReduceShapeKeepDim<Ax, KeptDim = Self::Shape>;
+}
+
+impl<S: Shape, E: Dtype, D: BroadcastKernel<E>, T: Tape<E, D>> ExpandTo for Tensor<S, E, D, T> {
+    fn try_expand_like<Dst, Ax: Axes>(self, dst: &Dst) -> Result<Self::WithShape<Dst>, Self::Err>
+    where
+        Dst: Shape + ReduceShapeKeepDim<Ax, KeptDim = S>,
+    {
+        assert_eq!(&dst.keep_dim(), self.shape());
+        let (inp, mut tape) = self.split_tape();
+        let mut strides: Dst::Concrete = Default::default();
+        for i in 0..Dst::NUM_DIMS {
+            if !Ax::as_array().into_iter().any(|x| x == i as isize) {
+                strides[i] = inp.strides[i];
+            }
+        }
+        let out = Tensor {
+            id: unique_id(),
+            data: inp.data.clone(),
+            shape: *dst,
+            strides,
+            device: inp.device.clone(),
+            param_opts: Default::default(),
+            tape: Default::default(),
+        };
+        let phantom_out = out.clone();
+        tape.try_alloc_grad(&inp)?;
+        tape.try_alloc_grad(&out)?;
+        tape.add_reusable_backward_op(move |grads| {
+            let (grad_inp, grad_out) = grads.mut_and_ref(&inp, &phantom_out);
+            inp.device.backward(grad_inp, grad_out)
+        });
+        Ok(out.put_tape(tape))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::tests::*;
+
+    #[test]
+    fn test_keepdim_shapes() {
+        let dev: TestDevice = Default::default();
+        let t: Tensor<Rank3<2, 3, 4>, TestDtype, _> = dev.zeros();
+        let _: Tensor<Rank3<2, 1, 4>, TestDtype, _> = t.clone().sum_keepdim::<Axis<1>>();
+        let _: Tensor<Rank3<1, 3, 1>, TestDtype, _> = t.clone().max_keepdim::<Axes2<0, 2>>();
+        let _: Tensor<Rank3<1, 1, 1>, TestDtype, _> = t.clone().mean_keepdim::<Axes3<0, 1, 2>>();
+
+        let t: Tensor<(usize, Const<3>), TestDtype, _> = dev.zeros_like(&(5, Const));
+        let r = t.min_keepdim::<Axis<0>>();
+        assert_eq!(r.shape(), &(Const::<1>, Const::<3>));
+
+        let t: Tensor<[usize; 2], TestDtype, _> = dev.zeros_like(&[5, 3]);
+        let r = t.sum_keepdim::<Axis<1>>();
+        assert_eq!(r.shape(), &[5, 1]);
+    }
+
+    #[test]
+    fn test_sum_keepdim_broadcasts_back() {
+        let dev: TestDevice = Default::default();
+        let t: Tensor<_, TestDtype, _> = dev.tensor([[1.0, 2.0, 3.0], [4.0, 5.0, 6.0]]);
+        let s = t.trace().sum_keepdim::<Axis<1>>();
+        assert_eq!(s.array(), [[6.0], [15.0]]);
+        let r = t.trace() / s.expand_like::<_, Axis<1>>(t.shape());
+        assert_close(
+            &r.array(),
+            &[[1. / 6., 2. / 6., 3. / 6.], [4. / 15., 5. / 15., 6. / 15.]],
+        );
+        let g = r.exp().sum().backward();
+        let g2 = {
+            let s = t.trace().sum::<Rank1<2>, _>();
+            let r = t.trace() / s.broadcast();
+            r.exp().sum().backward()
+        };
+        assert_close(&g.get(&t).array(), &g2.get(&t).array());
+    }
+
+    #[test]
+    fn test_max_keepdim() {
+        let dev: TestDevice = Default::default();
+        let t: Tensor<_, TestDtype, _> = dev.tensor([[1.0, 5.0, 3.0], [4.0, -5.0, 6.0]]);
+        let m = t.trace().max_keepdim::<Axis<1>>();
+        assert_eq!(m.array(), [[5.0], [6.0]]);
+        let r = t.trace() - m.expand_like::<_, Axis<1>>(t.shape());
+        assert_eq!(r.array(), [[-4.0, 0.0, -2.0], [-2.0, -11.0, 0.0]]);
+        let g = r.sum().backward();
+        assert_eq!(g.get(&t).array(), [[1.0, -2.0, 1.0], [1.0, 1.0, -2.0]]);
+    }
+
+    #[test]
+    fn test_expand() {
+        let dev: TestDevice = Default::default();
+        let t: Tensor<_, TestDtype, _> = dev.tensor([[[1.0], [2.0]]]);
+        let r = t.trace().expand::<Rank3<2, 2, 3>, Axes2<0, 2>>();
+        assert_eq!(r.array(), [[[1.0; 3], [2.0; 3]], [[1.0; 3], [2.0; 3]]]);
+        let g = (r * dev.tensor([1.0, 2.0, 3.0]).broadcast())
+            .sum()
+            .backward();
+        assert_eq!(g.get(&t).array(), [[[12.0], [12.0]]]);
+    }
+}
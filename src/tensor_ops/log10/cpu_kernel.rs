@@ -0,0 +1,13 @@
+use crate::tensor_ops::cpu_kernels::UnaryDerivative;
+use num_traits::{Float, FloatConst};
+
+impl<F: Float + FloatConst> UnaryDerivative<F> for super::Log10KernelOp {
+    #[inline(always)]
+    fn f(&self, x: &F) -> F {
+        x.log10()
+    }
+    #[inline(always)]
+    fn df(&self, x: &F) -> F {
+        (*x * F::LN_10()).recip()
+    }
+}
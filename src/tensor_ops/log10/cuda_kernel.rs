@@ -0,0 +1,9 @@
+use super::Log10KernelOp;
+use crate::tensor_ops::cuda_kernels::cuda_unary;
+
+unsafe impl cudarc::driver::DeviceRepr for Log10KernelOp {}
+
+const PTX: &str = include_str!(concat!(env!("OUT_DIR"), "/log10.ptx"));
+
+cuda_unary!(Log10KernelOp, f32, PTX, "log10_fwd_f32", "log10_bwd_f32");
+cuda_unary!(Log10KernelOp, f64, PTX, "log10_fwd_f64", "log10_bwd_f64");
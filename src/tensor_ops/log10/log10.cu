@@ -0,0 +1,13 @@
+#include "unary_op_macros.cuh"
+
+#define LN_10 2.30258509299404568402
+
+struct Log10KernelOp {};
+
+UNARY_OP(float, log10_fwd_f32, log10_bwd_f32, Log10KernelOp,
+        log10f(x),
+        1.0 / (x * LN_10))
+
+UNARY_OP(double, log10_fwd_f64, log10_bwd_f64, Log10KernelOp,
+        log10(x),
+        1.0 / (x * LN_10))
@@ -0,0 +1,57 @@
+mod cpu_kernel;
+
+#[cfg(feature = "cuda")]
+mod cuda_kernel;
+
+use super::ops::{try_unary_op, UnaryKernel};
+use crate::{gradients::Tape, shapes::*, tensor::Tensor};
+
+#[repr(C)]
+#[derive(Debug, Default, Copy, Clone)]
+pub struct Log10KernelOp;
+
+/// [Common Logarithm](https://en.wikipedia.org/wiki/Common_logarithm). `log_10(t)`.
+///
+/// The derivative is `1 / (t * ln(10))`.
+///
+/// Examples:
+/// ```rust
+/// # use dfdx::prelude::*;
+/// # let dev: Cpu = Default::default();
+/// let t = dev.tensor([1.0, 10.0, 1000.0, 0.1]);
+/// let r = t.log10();
+/// assert_eq!(r.array(), [0.0, 1.0, 3.0, -1.0]);
+/// ```
+pub fn log10<S: Shape, E: Dtype, D: UnaryKernel<Log10KernelOp, E>, T: Tape<E, D>>(
+    t: Tensor<S, E, D, T>,
+) -> Tensor<S, E, D, T> {
+    t.log10()
+}
+
+impl<S: Shape, E: Dtype, D: UnaryKernel<Log10KernelOp, E>, T: Tape<E, D>> Tensor<S, E, D, T> {
+    /// See [log10]
+    pub fn log10(self) -> Self {
+        self.try_log10().unwrap()
+    }
+    /// See [log10]
+    pub fn try_log10(self) -> Result<Self, D::Err> {
+        try_unary_op(Log10KernelOp, self)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{tensor::*, tensor_ops::*, tests::*};
+
+    #[test]
+    fn test_log10_matches_ln() {
+        let dev: TestDevice = Default::default();
+        let x: Tensor<_, TestDtype, _> = dev.tensor([0.25, 0.5, 1.0, 3.0, 20.0]);
+        let r = x.trace().log10();
+        let r2 = x.trace().ln() / core::f64::consts::LN_10 as TestDtype;
+        assert_close(&r.array(), &r2.array());
+        let g = r.exp().mean().backward();
+        let g2 = r2.exp().mean().backward();
+        assert_close(&g.get(&x).array(), &g2.get(&x).array());
+    }
+}
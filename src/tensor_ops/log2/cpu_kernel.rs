@@ -0,0 +1,13 @@
+use crate::tensor_ops::cpu_kernels::UnaryDerivative;
+use num_traits::{Float, FloatConst};
+
+impl<F: Float + FloatConst> UnaryDerivative<F> for super::Log2KernelOp {
+    #[inline(always)]
+    fn f(&self, x: &F) -> F {
+        x.log2()
+    }
+    #[inline(always)]
+    fn df(&self, x: &F) -> F {
+        (*x * F::LN_2()).recip()
+    }
+}
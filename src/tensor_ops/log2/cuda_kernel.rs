@@ -0,0 +1,9 @@
+use super::Log2KernelOp;
+use crate::tensor_ops::cuda_kernels::cuda_unary;
+
+unsafe impl cudarc::driver::DeviceRepr for Log2KernelOp {}
+
+const PTX: &str = include_str!(concat!(env!("OUT_DIR"), "/log2.ptx"));
+
+cuda_unary!(Log2KernelOp, f32, PTX, "log2_fwd_f32", "log2_bwd_f32");
+cuda_unary!(Log2KernelOp, f64, PTX, "log2_fwd_f64", "log2_bwd_f64");
@@ -0,0 +1,13 @@
+#include "unary_op_macros.cuh"
+
+#define LN_2 0.69314718055994530942
+
+struct Log2KernelOp {};
+
+UNARY_OP(float, log2_fwd_f32, log2_bwd_f32, Log2KernelOp,
+        log2f(x),
+        1.0 / (x * LN_2))
+
+UNARY_OP(double, log2_fwd_f64, log2_bwd_f64, Log2KernelOp,
+        log2(x),
+        1.0 / (x * LN_2))
@@ -0,0 +1,57 @@
+mod cpu_kernel;
+
+#[cfg(feature = "cuda")]
+mod cuda_kernel;
+
+use super::ops::{try_unary_op, UnaryKernel};
+use crate::{gradients::Tape, shapes::*, tensor::Tensor};
+
+#[repr(C)]
+#[derive(Debug, Default, Copy, Clone)]
+pub struct Log2KernelOp;
+
+/// [Binary Logarithm](https://en.wikipedia.org/wiki/Binary_logarithm). `log_2(t)`.
+///
+/// The derivative is `1 / (t * ln(2))`.
+///
+/// Examples:
+/// ```rust
+/// # use dfdx::prelude::*;
+/// # let dev: Cpu = Default::default();
+/// let t = dev.tensor([1.0, 2.0, 8.0, 0.5]);
+/// let r = t.log2();
+/// assert_eq!(r.array(), [0.0, 1.0, 3.0, -1.0]);
+/// ```
+pub fn log2<S: Shape, E: Dtype, D: UnaryKernel<Log2KernelOp, E>, T: Tape<E, D>>(
+    t: Tensor<S, E, D, T>,
+) -> Tensor<S, E, D, T> {
+    t.log2()
+}
+
+impl<S: Shape, E: Dtype, D: UnaryKernel<Log2KernelOp, E>, T: Tape<E, D>> Tensor<S, E, D, T> {
+    /// See [log2]
+    pub fn log2(self) -> Self {
+        self.try_log2().unwrap()
+    }
+    /// See [log2]
+    pub fn try_log2(self) -> Result<Self, D::Err> {
+        try_unary_op(Log2KernelOp, self)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{tensor::*, tensor_ops::*, tests::*};
+
+    #[test]
+    fn test_log2_matches_ln() {
+        let dev: TestDevice = Default::default();
+        let x: Tensor<_, TestDtype, _> = dev.tensor([0.25, 0.5, 1.0, 3.0, 20.0]);
+        let r = x.trace().log2();
+        let r2 = x.trace().ln() / core::f64::consts::LN_2 as TestDtype;
+        assert_close(&r.array(), &r2.array());
+        let g = r.exp().mean().backward();
+        let g2 = r2.exp().mean().backward();
+        assert_close(&g.get(&x).array(), &g2.get(&x).array());
+    }
+}
@@ -3,6 +3,9 @@ use crate::{gradients::Tape, shapes::*, tensor::Tensor};
 
 /// `log(softmax(t))` in numerically stable way across `Ax`. Does `t - logsumexp(t)` under the hood.
 ///
+/// Like [super::LogSumExpTo::logsumexp], a NaN anywhere along `Ax` makes every value along
+/// `Ax` NaN.
+///
 /// **Pytorch equivalent**: `t.log_softmax(Ax)`
 ///
 /// Example:
@@ -7,6 +7,9 @@ pub trait LogSumExpTo: HasErr + HasShape {
     ///
     /// **Pytorch equivalent**: `t.exp().sum(Axes).log()`
     ///
+    /// For numerical stability the max of each group is subtracted first (see [MaxTo::max]).
+    /// NaNs are propagated: if any of the reduced values is NaN, the result is NaN.
+    ///
     /// **Related functions**: [ln()], [exp()], [log_softmax()], [softmax()]
     ///
     /// Example:
@@ -0,0 +1,285 @@
+use super::{
+    cpu_kernels::{BinaryDerivative, UnaryDerivative},
+    ops::{try_binary_op, try_broadcast_binary_op, try_unary_op},
+};
+use crate::{
+    gradients::{Merge, Tape},
+    shapes::*,
+    tensor::{Cpu, CpuError, Tensor},
+};
+
+#[derive(Debug, Clone, Copy)]
+pub struct MapKernelOp<F, DF> {
+    f: F,
+    df: DF,
+}
+
+impl<E: Copy, F: Fn(E) -> E, DF: Fn(E) -> E> UnaryDerivative<E> for MapKernelOp<F, DF> {
+    #[inline(always)]
+    fn f(&self, x: &E) -> E {
+        (self.f)(*x)
+    }
+    #[inline(always)]
+    fn df(&self, x: &E) -> E {
+        (self.df)(*x)
+    }
+}
+
+#[derive(Debug, Clone, Copy)]
+pub struct ZipMapKernelOp<F, DA, DB> {
+    f: F,
+    df_da: DA,
+    df_db: DB,
+}
+
+impl<E: Copy, F, DA, DB> BinaryDerivative<E> for ZipMapKernelOp<F, DA, DB>
+where
+    F: Fn(E, E) -> E,
+    DA: Fn(E, E) -> E,
+    DB: Fn(E, E) -> E,
+{
+    #[inline(always)]
+    fn f(&self, a: &E, b: &E) -> E {
+        (self.f)(*a, *b)
+    }
+    #[inline(always)]
+    fn dfdx(&self, a: &E, b: &E) -> E {
+        (self.df_da)(*a, *b)
+    }
+    #[inline(always)]
+    fn dfdy(&self, a: &E, b: &E) -> E {
+        (self.df_db)(*a, *b)
+    }
+}
+
+impl<S: Shape, E: Dtype, T: Tape<E, Cpu>> Tensor<S, E, Cpu, T> {
+    /// Applies `f` to every element, where `df` is the derivative of `f`. The gradient
+    /// of each element is `df(x) * grad`, where `x` is the element's value in `self`.
+    ///
+    /// This is an easy way to try out a new elementwise function without writing a kernel
+    /// for it. Only [Cpu] is supported, since the closures run on the host.
+    ///
+    /// Examples:
+    /// ```rust
+    /// # use dfdx::prelude::*;
+    /// # let dev: Cpu = Default::default();
+    /// let t = dev.tensor([-1.0f32, 0.0, 1.0, 2.0]);
+    /// let r = t.trace().map(|x| x * x * x, |x| 3.0 * x * x);
+    /// assert_eq!(r.array(), [-1.0, 0.0, 1.0, 8.0]);
+    /// ```
+    pub fn map<F, DF>(self, f: F, df: DF) -> Self
+    where
+        F: 'static + Clone + Fn(E) -> E,
+        DF: 'static + Clone + Fn(E) -> E,
+    {
+        self.try_map(f, df).unwrap()
+    }
+
+    /// Fallible version of [Tensor::map]
+    pub fn try_map<F, DF>(self, f: F, df: DF) -> Result<Self, CpuError>
+    where
+        F: 'static + Clone + Fn(E) -> E,
+        DF: 'static + Clone + Fn(E) -> E,
+    {
+        try_unary_op(MapKernelOp { f, df }, self)
+    }
+
+    /// Applies the binary function `f(a, b)` to every pair of elements of `self` and `rhs`,
+    /// where `df_da` and `df_db` are the partial derivatives of `f` with respect to `a`
+    /// and `b`. See [Tensor::map].
+    ///
+    /// Both tensors must have the same shape, see [Tensor::broadcast_zip_map] to combine
+    /// tensors of different shapes.
+    ///
+    /// Examples:
+    /// ```rust
+    /// # use dfdx::prelude::*;
+    /// # let dev: Cpu = Default::default();
+    /// let a = dev.tensor([1.0f32, 2.0, 3.0]);
+    /// let b = dev.tensor([-1.0f32, 0.5, 2.0]);
+    /// let r = a.trace().zip_map(b, |a, b| a * b, |_, b| b, |a, _| a);
+    /// assert_eq!(r.array(), [-1.0, 1.0, 6.0]);
+    /// ```
+    pub fn zip_map<R: Default, F, DA, DB>(
+        self,
+        rhs: Tensor<S, E, Cpu, R>,
+        f: F,
+        df_da: DA,
+        df_db: DB,
+    ) -> Self
+    where
+        T: Merge<R>,
+        F: 'static + Clone + Fn(E, E) -> E,
+        DA: 'static + Clone + Fn(E, E) -> E,
+        DB: 'static + Clone + Fn(E, E) -> E,
+    {
+        self.try_zip_map(rhs, f, df_da, df_db).unwrap()
+    }
+
+    /// Fallible version of [Tensor::zip_map]
+    pub fn try_zip_map<R: Default, F, DA, DB>(
+        self,
+        rhs: Tensor<S, E, Cpu, R>,
+        f: F,
+        df_da: DA,
+        df_db: DB,
+    ) -> Result<Self, CpuError>
+    where
+        T: Merge<R>,
+        F: 'static + Clone + Fn(E, E) -> E,
+        DA: 'static + Clone + Fn(E, E) -> E,
+        DB: 'static + Clone + Fn(E, E) -> E,
+    {
+        try_binary_op(ZipMapKernelOp { f, df_da, df_db }, self, rhs)
+    }
+
+    /// [Tensor::zip_map] with a smaller `rhs`, which is broadcasted to the shape of `self`
+    /// like [Tensor::broadcast_add]. The gradient of `rhs` is summed over the broadcasted
+    /// axes.
+    ///
+    /// Examples:
+    /// ```rust
+    /// # use dfdx::prelude::*;
+    /// # let dev: Cpu = Default::default();
+    /// let a = dev.tensor([[1.0f32, 2.0], [3.0, 4.0]]);
+    /// let b = dev.tensor([10.0f32, -1.0]);
+    /// let r = a.trace().broadcast_zip_map(b, |a, b| a * b, |_, b| b, |a, _| a);
+    /// assert_eq!(r.array(), [[10.0, -2.0], [30.0, -4.0]]);
+    /// ```
+    pub fn broadcast_zip_map<Small: Shape, Ax: Axes, R: Tape<E, Cpu>, F, DA, DB>(
+        self,
+        rhs: Tensor<Small, E, Cpu, R>,
+        f: F,
+        df_da: DA,
+        df_db: DB,
+    ) -> Self
+    where
+        S: BroadcastShapeTrailing<Small, Ax>,
+        T: Merge<R>,
+        F: 'static + Clone + Fn(E, E) -> E,
+        DA: 'static + Clone + Fn(E, E) -> E,
+        DB: 'static + Clone + Fn(E, E) -> E,
+    {
+        self.try_broadcast_zip_map(rhs, f, df_da, df_db).unwrap()
+    }
+
+    /// Fallible version of [Tensor::broadcast_zip_map]
+    pub fn try_broadcast_zip_map<Small: Shape, Ax: Axes, R: Tape<E, Cpu>, F, DA, DB>(
+        self,
+        rhs: Tensor<Small, E, Cpu, R>,
+        f: F,
+        df_da: DA,
+        df_db: DB,
+    ) -> Result<Self, CpuError>
+    where
+        S: BroadcastShapeTrailing<Small, Ax>,
+        T: Merge<R>,
+        F: 'static + Clone + Fn(E, E) -> E,
+        DA: 'static + Clone + Fn(E, E) -> E,
+        DB: 'static + Clone + Fn(E, E) -> E,
+    {
+        try_broadcast_binary_op(ZipMapKernelOp { f, df_da, df_db }, self, rhs)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{shapes::*, tensor::*, tensor_ops::*, tests::*};
+
+    #[test]
+    fn test_map_cube_matches_powi() {
+        let dev: Cpu = Default::default();
+        let x: Tensor<Rank2<2, 3>, TestDtype, _> = dev.tensor([[-2.0, -0.5, 0.0], [0.5, 1.5, 3.0]]);
+
+        let r = x.trace().map(|x| x * x * x, |x| 3.0 * x * x);
+        let r2 = x.trace().powi(3);
+        assert_close(&r.array(), &r2.array());
+
+        let g = r.exp().mean().backward();
+        let g2 = r2.exp().mean().backward();
+        assert_close(&g.get(&x).array(), &g2.get(&x).array());
+    }
+
+    #[test]
+    fn test_map_captures_state() {
+        let dev: Cpu = Default::default();
+        let x: Tensor<_, TestDtype, _> = dev.tensor([1.0, 2.0, 3.0]);
+        let scale: TestDtype = 2.5;
+        let r = x.trace().map(move |x| scale * x, move |_| scale);
+        assert_eq!(r.array(), [2.5, 5.0, 7.5]);
+        let g = r.sum().backward();
+        assert_eq!(g.get(&x).array(), [2.5; 3]);
+    }
+
+    #[test]
+    fn test_zip_map_captures_non_copy_state() {
+        let dev: Cpu = Default::default();
+        let a: Tensor<_, TestDtype, _> = dev.tensor([1.0, 2.0, 3.0]);
+        let b: Tensor<_, TestDtype, _> = dev.tensor([4.0, 5.0, 6.0]);
+        let scale: std::sync::Arc<TestDtype> = std::sync::Arc::new(2.0);
+        let (s1, s2, s3) = (scale.clone(), scale.clone(), scale);
+        let r = a.trace().zip_map(
+            b.trace(),
+            move |a, b| *s1 * a + b,
+            move |_, _| *s2,
+            move |_, _| *s3 - 1.0,
+        );
+        assert_eq!(r.array(), [6.0, 9.0, 12.0]);
+        let g = r.sum().backward();
+        assert_eq!(g.get(&a).array(), [2.0; 3]);
+        assert_eq!(g.get(&b).array(), [1.0; 3]);
+    }
+
+    #[test]
+    fn test_zip_map_matches_composed() {
+        let dev: Cpu = Default::default();
+        let a: Tensor<Rank2<2, 3>, TestDtype, _> = dev.sample_normal();
+        let b: Tensor<Rank2<2, 3>, TestDtype, _> = dev.sample_normal();
+
+        let r = a
+            .trace()
+            .zip_map(b.trace(), |a, b| a * b + a, |_, b| b + 1.0, |a, _| a);
+        let r2 = a.trace() * b.trace() + a.trace();
+        assert_close(&r.array(), &r2.array());
+
+        let g = r.square().mean().backward();
+        let g2 = r2.square().mean().backward();
+        assert_close(&g.get(&a).array(), &g2.get(&a).array());
+        assert_close(&g.get(&b).array(), &g2.get(&b).array());
+    }
+
+    #[test]
+    fn test_zip_map_broadcasted_rhs() {
+        let dev: Cpu = Default::default();
+        let a: Tensor<_, TestDtype, _> = dev.tensor([[1.0, 2.0, 3.0], [4.0, 5.0, 6.0]]);
+        let b: Tensor<_, TestDtype, _> = dev.tensor([10.0, 20.0, 30.0]);
+        let r = a.trace().zip_map(
+            b.trace().broadcast::<Rank2<2, 3>, _>(),
+            |a, b| a * b + a,
+            |_, b| b + 1.0,
+            |a, _| a,
+        );
+        assert_eq!(r.array(), [[11.0, 42.0, 93.0], [44.0, 105.0, 186.0]]);
+        let g = r.sum().backward();
+        assert_eq!(g.get(&a).array(), [[11.0, 21.0, 31.0]; 2]);
+        assert_eq!(g.get(&b).array(), [5.0, 7.0, 9.0]);
+    }
+
+    #[test]
+    fn test_broadcast_zip_map_mismatched_shapes() {
+        let dev: Cpu = Default::default();
+        let a: Tensor<Rank3<4, 2, 3>, TestDtype, _> = dev.sample_normal();
+        let b: Tensor<Rank1<3>, TestDtype, _> = dev.sample_normal();
+
+        let r = a
+            .trace()
+            .broadcast_zip_map(b.trace(), |a, b| a * b + a, |_, b| b + 1.0, |a, _| a);
+        let r2 = a.trace() * b.trace().broadcast() + a.trace();
+        assert_close(&r.array(), &r2.array());
+
+        let g = r.square().mean().backward();
+        let g2 = r2.square().mean().backward();
+        assert_close(&g.get(&a).array(), &g2.get(&a).array());
+        assert_close(&g.get(&b).array(), &g2.get(&b).array());
+    }
+}
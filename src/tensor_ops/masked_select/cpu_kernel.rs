@@ -0,0 +1,42 @@
+use crate::shapes::{Dtype, Shape};
+use crate::tensor::{
+    cpu::{index_to_i, LendingIterator},
+    Cpu, Tensor, TensorFromVec,
+};
+
+use std::vec::Vec;
+
+impl<E: Dtype> super::MaskedSelectKernel<E> for Cpu {
+    fn forward<S: Shape>(
+        &self,
+        inp: &Tensor<S, E, Self>,
+        mask: &Tensor<S, bool, Self>,
+    ) -> Result<Tensor<(usize,), E, Self>, Self::Err> {
+        let mut data = Vec::new();
+        let mut mask_iter = mask.iter_with_index();
+        while let Some((m, i)) = mask_iter.next() {
+            if *m {
+                data.push(inp[i]);
+            }
+        }
+        let n = data.len();
+        self.try_tensor_from_vec(data, (n,))
+    }
+
+    fn backward<S: Shape>(
+        &self,
+        inp: &Tensor<S, E, Self>,
+        grad_inp: &mut Self::Vec<E>,
+        mask: &Tensor<S, bool, Self>,
+        grad_out: &Self::Vec<E>,
+    ) -> Result<(), Self::Err> {
+        let mut grad_out = grad_out.iter();
+        let mut mask_iter = mask.iter_with_index();
+        while let Some((m, i)) = mask_iter.next() {
+            if *m {
+                grad_inp[index_to_i(&inp.shape, &inp.strides, i)] += *grad_out.next().unwrap();
+            }
+        }
+        Ok(())
+    }
+}
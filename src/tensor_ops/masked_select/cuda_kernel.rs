@@ -0,0 +1,104 @@
+use crate::{
+    shapes::*,
+    tensor::{cpu::NdIndex, Cuda, DeviceStorage, Tensor},
+};
+use cudarc::driver::{LaunchAsync, LaunchConfig};
+
+use std::vec::Vec;
+
+const PTX_SRC: &str = include_str!(concat!(env!("OUT_DIR"), "/masked_select.ptx"));
+
+pub(crate) trait HasCudaKernel<E> {
+    const MOD: &'static str;
+    const FNS: &'static [&'static str];
+}
+
+impl HasCudaKernel<f32> for Cuda {
+    const MOD: &'static str = "masked_select_f32";
+    const FNS: &'static [&'static str] = &["masked_select_fwd_f32", "masked_select_bwd_f32"];
+}
+
+impl HasCudaKernel<f64> for Cuda {
+    const MOD: &'static str = "masked_select_f64";
+    const FNS: &'static [&'static str] = &["masked_select_fwd_f64", "masked_select_bwd_f64"];
+}
+
+impl Cuda {
+    /// The physical positions in `inp` of every element where `mask` is true, in row-major order.
+    ///
+    /// The number of selected elements has to be known on the host to allocate the output,
+    /// so the mask is copied over and the positions are computed here.
+    fn masked_positions<S: Shape, E: Unit>(
+        &self,
+        inp: &Tensor<S, E, Self>,
+        mask: &Tensor<S, bool, Self>,
+    ) -> Vec<usize> {
+        let mask_buf = self.tensor_to_vec(mask);
+        let mut idx = NdIndex::new(inp.shape, inp.strides);
+        mask_buf
+            .into_iter()
+            .filter_map(|m| {
+                let i = idx.next().unwrap();
+                m.then_some(i)
+            })
+            .collect()
+    }
+}
+
+impl<E: Dtype> super::MaskedSelectKernel<E> for Cuda
+where
+    Self: HasCudaKernel<E>,
+{
+    fn forward<S: Shape>(
+        &self,
+        inp: &Tensor<S, E, Self>,
+        mask: &Tensor<S, bool, Self>,
+    ) -> Result<Tensor<(usize,), E, Self>, Self::Err> {
+        if !self.dev.has_func(Self::MOD, Self::FNS[0]) {
+            self.dev.load_ptx(PTX_SRC.into(), Self::MOD, Self::FNS)?;
+        }
+
+        let positions = self.masked_positions(inp, mask);
+        let numel = positions.len();
+        let shape = (numel,);
+        let mut storage = self.dev.alloc_zeros::<E>(numel)?;
+        if numel > 0 {
+            let positions = self.dev.htod_copy(positions)?;
+            let fwd_fn = self.dev.get_func(Self::MOD, Self::FNS[0]).unwrap();
+            let cfg = LaunchConfig::for_num_elems(numel as u32);
+            let params = (
+                numel,             // const size_t numel,
+                inp.data.as_ref(), // const float *inp,
+                &positions,        // const size_t *positions,
+                &mut storage,      // float *out,
+            );
+            unsafe { fwd_fn.launch(cfg, params) }?;
+        }
+        Ok(self.build_tensor(shape, shape.strides(), storage))
+    }
+
+    fn backward<S: Shape>(
+        &self,
+        inp: &Tensor<S, E, Self>,
+        grad_inp: &mut Self::Vec<E>,
+        mask: &Tensor<S, bool, Self>,
+        grad_out: &Self::Vec<E>,
+    ) -> Result<(), Self::Err> {
+        let positions = self.masked_positions(inp, mask);
+        let numel = positions.len();
+        if numel == 0 {
+            return Ok(());
+        }
+        let positions = self.dev.htod_copy(positions)?;
+        let bwd_fn = self.dev.get_func(Self::MOD, Self::FNS[1]).unwrap();
+        let cfg = LaunchConfig::for_num_elems(numel as u32);
+        let params = (
+            numel,      // const size_t numel,
+            grad_inp,   // float *grad_inp,
+            &positions, // const size_t *positions,
+            grad_out,   // const float *grad_out,
+        );
+        unsafe { bwd_fn.launch(cfg, params) }?;
+        Ok(())
+    }
+}
@@ -0,0 +1,52 @@
+#include "cuda_utils.cuh"
+
+template<typename T>
+__device__ void masked_select_fwd(
+    const size_t numel,
+    const T *inp,
+    const size_t *positions,
+    T *out
+) {
+    unsigned int i = blockIdx.x * blockDim.x + threadIdx.x;
+    if (i >= numel) {
+        return;
+    }
+
+    out[i] = inp[positions[i]];
+}
+
+template<typename T>
+__device__ void masked_select_bwd(
+    const size_t numel,
+    T *grad_inp,
+    const size_t *positions,
+    const T *grad_out
+) {
+    unsigned int i = blockIdx.x * blockDim.x + threadIdx.x;
+    if (i >= numel) {
+        return;
+    }
+
+    atomicAdd(grad_inp + positions[i], grad_out[i]);
+}
+
+#define MASKED_SELECT(TYPENAME, FWD, BWD) \
+extern "C" __global__ void FWD( \
+    const size_t numel, \
+    const TYPENAME *inp, \
+    const size_t *positions, \
+    TYPENAME *out \
+) { \
+    masked_select_fwd(numel, inp, positions, out); \
+} \
+extern "C" __global__ void BWD( \
+    const size_t numel, \
+    TYPENAME *grad_inp, \
+    const size_t *positions, \
+    const TYPENAME *grad_out \
+) { \
+    masked_select_bwd(numel, grad_inp, positions, grad_out); \
+}
+
+MASKED_SELECT(float, masked_select_fwd_f32, masked_select_bwd_f32);
+MASKED_SELECT(double, masked_select_fwd_f64, masked_select_bwd_f64);
@@ -0,0 +1,131 @@
+mod cpu_kernel;
+
+#[cfg(feature = "cuda")]
+mod cuda_kernel;
+
+use crate::{gradients::Tape, shapes::*, tensor::*};
+
+pub trait MaskedSelectKernel<E: Dtype>: DeviceStorage {
+    fn forward<S: Shape>(
+        &self,
+        inp: &Tensor<S, E, Self>,
+        mask: &Tensor<S, bool, Self>,
+    ) -> Result<Tensor<(usize,), E, Self>, Self::Err>;
+    fn backward<S: Shape>(
+        &self,
+        inp: &Tensor<S, E, Self>,
+        grad_inp: &mut Self::Vec<E>,
+        mask: &Tensor<S, bool, Self>,
+        grad_out: &Self::Vec<E>,
+    ) -> Result<(), Self::Err>;
+}
+
+/// Select the elements where a boolean mask is true, flattened into a 1d tensor
+/// in row-major order.
+///
+/// Since the number of selected elements depends on the mask, the output always
+/// has a runtime dimension.
+///
+/// **Pytorch equivalent**: `torch.masked_select(inp, mask)`
+pub trait MaskedSelect<D: DeviceStorage>: HasErr + HasShape {
+    /// Select using `mask`, which must be the same shape as `self`:
+    /// ```rust
+    /// # use dfdx::prelude::*;
+    /// # let dev: Cpu = Default::default();
+    /// let a: Tensor<Rank2<2, 3>, f32, _> = dev.tensor([[1.0, 2.0, 3.0], [4.0, 5.0, 6.0]]);
+    /// let mask = dev.tensor([[true, false, true], [false, true, false]]);
+    /// let r: Tensor<(usize,), f32, _> = a.masked_select(mask);
+    /// assert_eq!(r.shape(), &(3,));
+    /// assert_eq!(r.as_vec(), [1.0, 3.0, 5.0]);
+    /// ```
+    fn masked_select(self, mask: Tensor<Self::Shape, bool, D>) -> Self::WithShape<(usize,)> {
+        self.try_masked_select(mask).unwrap()
+    }
+
+    /// Fallible version of [MaskedSelect::masked_select]
+    fn try_masked_select(
+        self,
+        mask: Tensor<Self::Shape, bool, D>,
+    ) -> Result<Self::WithShape<(usize,)>, Self::Err>;
+}
+
+impl<S: Shape, E: Dtype, D: MaskedSelectKernel<E>, T: Tape<E, D>> MaskedSelect<D>
+    for Tensor<S, E, D, T>
+{
+    fn try_masked_select(
+        self,
+        mask: Tensor<S, bool, D>,
+    ) -> Result<Self::WithShape<(usize,)>, Self::Err> {
+        assert_eq!(self.shape(), mask.shape());
+        let (inp, mut tape) = self.split_tape();
+        let out = inp.device.forward(&inp, &mask)?;
+        let phantom_out = out.clone();
+        tape.try_alloc_grad(&inp)?;
+        tape.try_alloc_grad(&out)?;
+        tape.add_reusable_backward_op(move |grads| {
+            let (grad_inp, grad_out) = grads.mut_and_ref(&inp, &phantom_out);
+            inp.device.backward(&inp, grad_inp, &mask, grad_out)
+        });
+        Ok(out.put_tape(tape))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::tensor_ops::*;
+    use crate::tests::*;
+
+    #[test]
+    fn test_masked_select_threshold() {
+        let dev: TestDevice = Default::default();
+        let t: Tensor<_, TestDtype, _> =
+            dev.tensor([[[0.5, -1.0], [2.0, 0.1]], [[3.0, 0.0], [-2.0, 1.5]]]);
+        let mask = t.scalar_gt(0.4);
+        let r = t.trace().masked_select(mask);
+        assert_eq!(r.shape(), &(4,));
+        assert_eq!(r.as_vec(), [0.5, 2.0, 3.0, 1.5]);
+
+        let g = (r * dev.tensor_from_vec(std::vec![1.0, 2.0, 3.0, 4.0], (4,)))
+            .sum()
+            .backward();
+        assert_eq!(
+            g.get(&t).array(),
+            [[[1.0, 0.0], [2.0, 0.0]], [[3.0, 0.0], [0.0, 4.0]]]
+        );
+    }
+
+    #[test]
+    fn test_masked_select_permuted() {
+        let dev: TestDevice = Default::default();
+        let t: Tensor<_, TestDtype, _> = dev.tensor([[1.0, 2.0, 3.0], [4.0, 5.0, 6.0]]);
+        let mask = dev.tensor([[true, true], [false, true], [true, false]]);
+        let r = t.trace().permute::<Rank2<3, 2>, _>().masked_select(mask);
+        assert_eq!(r.as_vec(), [1.0, 4.0, 5.0, 3.0]);
+        let g = r.exp().sum().backward();
+        let e = t.array().map(|row| row.map(TestDtype::exp));
+        assert_close(
+            &g.get(&t).array(),
+            &[[e[0][0], 0.0, e[0][2]], [e[1][0], e[1][1], 0.0]],
+        );
+    }
+
+    #[test]
+    fn test_masked_select_broadcasted() {
+        let dev: TestDevice = Default::default();
+        let t: Tensor<_, TestDtype, _> = dev.tensor([1.0, 2.0, 3.0]);
+        let mask = dev.tensor([[true, false, true], [true, true, false]]);
+        let r = t.trace().broadcast::<Rank2<2, 3>, _>().masked_select(mask);
+        assert_eq!(r.as_vec(), [1.0, 3.0, 1.0, 2.0]);
+        let g = r.sum().backward();
+        assert_eq!(g.get(&t).array(), [2.0, 1.0, 1.0]);
+    }
+
+    #[test]
+    fn test_masked_select_none() {
+        let dev: TestDevice = Default::default();
+        let t: Tensor<Rank1<3>, TestDtype, _> = dev.ones();
+        let r = t.masked_select(dev.tensor([false; 3]));
+        assert_eq!(r.shape(), &(0,));
+    }
+}
@@ -85,12 +85,12 @@ fn try_binary_op<
     RhsTape: Tape<E, D>,
     LhsTape: Tape<E, D> + Merge<RhsTape>,
     Fwd: 'static + FnMut(&D, &Tensor<Lhs, E, D>, &Tensor<Rhs, E, D>) -> Result<Tensor<Out, E,D>, D::Err>,
-    Bwd: 'static + FnMut(&D, &Tensor<Lhs, E, D>, &mut D::Vec<E>, &Tensor<Rhs, E,D>, &mut D::Vec<E>, &D::Vec<E>) -> Result<(), D::Err>,
+    Bwd: 'static + Fn(&D, &Tensor<Lhs, E, D>, &mut D::Vec<E>, &Tensor<Rhs, E,D>, &mut D::Vec<E>, &D::Vec<E>) -> Result<(), D::Err>,
 >(
     lhs: Tensor<Lhs, E, D, LhsTape>,
     rhs: Tensor<Rhs, E, D, RhsTape>,
     mut fwd: Fwd,
-    mut bwd: Bwd,
+    bwd: Bwd,
 ) -> Result<Tensor<Out, E, D, LhsTape>, D::Err> {
     let (lhs, ltape) = lhs.split_tape();
     let (rhs, rtape) = rhs.split_tape();
@@ -100,7 +100,7 @@ fn try_binary_op<
     tape.try_alloc_grad(&lhs)?;
     tape.try_alloc_grad(&rhs)?;
     tape.try_alloc_grad(&out)?;
-    tape.add_backward_op(move |grads| {
+    tape.add_reusable_backward_op(move |grads| {
         let (grad_lhs, grad_rhs, grad_out) =grads.muts_and_ref(&lhs, &rhs, &phantom_out);
         bwd(&lhs.device, &lhs, grad_lhs, &rhs, grad_rhs, grad_out)
     });
@@ -133,6 +133,53 @@ impl<M: Dim, N: Dim, E: Dtype, D: VecVecKernel<E>, T: Tape<E, D> + Merge<R>, R:
     }
 }
 
+/// Outer product of two vectors: `out[i, j] = lhs[i] * rhs[j]`.
+///
+/// This is the same as [matmul] on two vectors, but with a name that says what it does.
+///
+/// **Pytorch equivalent**: `torch.outer(lhs, rhs)`
+///
+/// Example:
+/// ```rust
+/// # use dfdx::prelude::*;
+/// # let dev: Cpu = Default::default();
+/// let a = dev.tensor([1.0, 2.0]);
+/// let b = dev.tensor([1.0, -1.0, 3.0]);
+/// let r = outer(a, b);
+/// assert_eq!(r.array(), [[1.0, -1.0, 3.0], [2.0, -2.0, 6.0]]);
+/// ```
+pub fn outer<M: Dim, N: Dim, E: Dtype, D: VecVecKernel<E>, T, R>(
+    lhs: Tensor<(M,), E, D, T>,
+    rhs: Tensor<(N,), E, D, R>,
+) -> Tensor<(M, N), E, D, T>
+where
+    T: Tape<E, D> + Merge<R>,
+    R: Tape<E, D>,
+{
+    lhs.outer(rhs)
+}
+
+impl<M: Dim, E: Dtype, D: VecVecKernel<E>, T: Tape<E, D>> Tensor<(M,), E, D, T> {
+    /// See [outer]
+    pub fn outer<N: Dim, R: Tape<E, D>>(self, rhs: Tensor<(N,), E, D, R>) -> Tensor<(M, N), E, D, T>
+    where
+        T: Merge<R>,
+    {
+        self.try_outer(rhs).unwrap()
+    }
+
+    /// See [outer]
+    pub fn try_outer<N: Dim, R: Tape<E, D>>(
+        self,
+        rhs: Tensor<(N,), E, D, R>,
+    ) -> Result<Tensor<(M, N), E, D, T>, D::Err>
+    where
+        T: Merge<R>,
+    {
+        self.try_matmul(rhs)
+    }
+}
+
 pub trait VecMatKernel<E: Dtype>: DeviceStorage {
     fn forward<K: Dim, N: Dim>(
         &self,
@@ -606,6 +653,31 @@ mod tests {
         assert_close(&g.get(&b).array(), &[-0.13630435, -1.6781758, -0.75171506]);
     }
 
+    #[test]
+    fn test_outer() {
+        let dev: TestDevice = Default::default();
+        let a: Tensor<_, TestDtype, _> = dev.tensor([1.0, -2.0, 0.5]);
+        let b: Tensor<_, TestDtype, _> = dev.tensor([3.0, 0.25]);
+        let r = outer(a.trace(), b.trace());
+        let manual = a.clone().broadcast::<Rank2<3, 2>, _>() * b.clone().broadcast();
+        assert_eq!(r.array(), manual.array());
+        assert_eq!(r.array(), [[3.0, 0.25], [-6.0, -0.5], [1.5, 0.125]]);
+
+        // d/da sum(a_i * b_j) = sum(b), d/db sum(a_i * b_j) = sum(a)
+        let g = r.sum().backward();
+        assert_eq!(g.get(&a).array(), [3.25; 3]);
+        assert_eq!(g.get(&b).array(), [-0.5; 2]);
+
+        let r = a.trace().outer(b.trace());
+        let g = r.square().sum().backward();
+        // d/da_i sum_j (a_i b_j)^2 = 2 a_i sum_j b_j^2
+        let b2: TestDtype = 3.0 * 3.0 + 0.25 * 0.25;
+        assert_close(&g.get(&a).array(), &[2.0 * b2, -4.0 * b2, 1.0 * b2]);
+        // d/db_j sum_i (a_i b_j)^2 = 2 b_j sum_i a_i^2
+        let a2: TestDtype = 1.0 + 4.0 + 0.25;
+        assert_close(&g.get(&b).array(), &[6.0 * a2, 0.5 * a2]);
+    }
+
     #[test]
     fn test_small_matmul_vv() {
         let dev: TestDevice = Default::default();
@@ -713,6 +785,47 @@ mod tests {
         }
     }
 
+    #[cfg(feature = "test-cuda")]
+    #[test]
+    fn test_matmul_matches_cpu() {
+        let dev: Cuda = Default::default();
+        let cpu: Cpu = Default::default();
+
+        {
+            let a: Tensor<Rank2<64, 128>, TestDtype, _> = dev.sample_normal();
+            let b: Tensor<Rank2<128, 32>, TestDtype, _> = dev.sample_normal();
+            let (a_cpu, b_cpu) = (a.to_device(&cpu), b.to_device(&cpu));
+            let c = a.trace().matmul(b.clone());
+            let c_cpu = a_cpu.trace().matmul(b_cpu.clone());
+            c.array().assert_close(&c_cpu.array(), 1e-3);
+            let g = c.square().mean().backward();
+            let g_cpu = c_cpu.square().mean().backward();
+            g.get(&a)
+                .array()
+                .assert_close(&g_cpu.get(&a_cpu).array(), 1e-4);
+            g.get(&b)
+                .array()
+                .assert_close(&g_cpu.get(&b_cpu).array(), 1e-4);
+        }
+
+        {
+            let a: Tensor<Rank3<4, 64, 128>, TestDtype, _> = dev.sample_normal();
+            let b: Tensor<Rank3<4, 128, 32>, TestDtype, _> = dev.sample_normal();
+            let (a_cpu, b_cpu) = (a.to_device(&cpu), b.to_device(&cpu));
+            let c = a.trace().matmul(b.clone());
+            let c_cpu = a_cpu.trace().matmul(b_cpu.clone());
+            c.array().assert_close(&c_cpu.array(), 1e-3);
+            let g = c.square().mean().backward();
+            let g_cpu = c_cpu.square().mean().backward();
+            g.get(&a)
+                .array()
+                .assert_close(&g_cpu.get(&a_cpu).array(), 1e-4);
+            g.get(&b)
+                .array()
+                .assert_close(&g_cpu.get(&b_cpu).array(), 1e-4);
+        }
+    }
+
     #[test]
     #[should_panic = "left: `3`,\n right: `4`"]
     fn test_dynamic_matmul_matmat_fail() {
@@ -0,0 +1,160 @@
+use crate::{
+    gradients::Tape,
+    shapes::{Dim, Dtype},
+    tensor::{PutTape, SplitTape, Tensor},
+};
+
+use super::{qr::QrKernel, triangular_solve::TriangularSolveKernel, Device, ReshapeTo, TryMatMul};
+
+/// Raises the square matrix `a` to the integer power `n` by repeated squaring.
+///
+/// `n = 0` gives the identity matrix, and negative `n` raises [super::inv()] of `a` to `-n`.
+/// Since this is composed of [super::matmul()] (and [super::inv()]), gradients follow the
+/// product rule through each multiplication.
+///
+/// **Pytorch equivalent**: `torch.linalg.matrix_power(a, n)`
+///
+/// Example:
+/// ```rust
+/// # use dfdx::prelude::*;
+/// # let dev: Cpu = Default::default();
+/// let a = dev.tensor([[1.0, 1.0], [0.0, 2.0]]);
+/// assert_eq!(matrix_power(a.clone(), 3).array(), [[1.0, 7.0], [0.0, 8.0]]);
+/// assert_eq!(matrix_power(a.clone(), 0).array(), [[1.0, 0.0], [0.0, 1.0]]);
+/// assert_eq!(a.matrix_power(-1).array(), [[1.0, -0.5], [0.0, 0.5]]);
+/// ```
+pub fn matrix_power<N: Dim, E: Dtype, D, T: Tape<E, D>>(
+    a: Tensor<(N, N), E, D, T>,
+    n: i32,
+) -> Tensor<(N, N), E, D, T>
+where
+    D: Device<E> + QrKernel<E> + TriangularSolveKernel<E>,
+{
+    a.matrix_power(n)
+}
+
+impl<N: Dim, E: Dtype, D, T: Tape<E, D>> Tensor<(N, N), E, D, T>
+where
+    D: Device<E> + QrKernel<E> + TriangularSolveKernel<E>,
+{
+    /// See [matrix_power]
+    pub fn matrix_power(self, n: i32) -> Self {
+        self.try_matrix_power(n).unwrap()
+    }
+
+    /// See [matrix_power]
+    pub fn try_matrix_power(self, n: i32) -> Result<Self, D::Err> {
+        assert_eq!(self.shape.0, self.shape.1);
+        if n == 0 {
+            let size = self.shape.0.size();
+            let mut eye = std::vec![E::default(); size * size];
+            for i in 0..size {
+                eye[i * size + i] = E::ONE;
+            }
+            let (a, mut tape) = self.split_tape();
+            let eye = a.device.try_tensor_from_vec(eye, a.shape)?;
+            // the identity doesn't depend on `a`, so its gradient is all zeros
+            tape.try_alloc_grad(&a)?;
+            tape.add_reusable_backward_op(move |grads| grads.try_alloc_for(&a));
+            return Ok(eye.put_tape(tape));
+        }
+
+        let mut base = if n < 0 { self.try_inv()? } else { self };
+        let mut n = n.unsigned_abs();
+        let mut result: Option<Self> = None;
+        loop {
+            // the last factor takes the tape of `base`, which has the ops of all the squarings
+            if n == 1 {
+                return match result {
+                    None => Ok(base),
+                    Some(result) => result.try_matmul(base),
+                };
+            }
+            if n & 1 == 1 {
+                let factor = base.retaped::<T>();
+                result = Some(match result {
+                    None => factor,
+                    Some(result) => result.try_matmul(factor)?,
+                });
+            }
+            // gradients can't be accumulated into both sides of a matmul with the same id, so
+            // square against a copy of `base` that has its own id
+            let shape = base.shape;
+            let copy = base.retaped::<T>().try_reshape_like(&shape)?;
+            base = copy.try_matmul(base)?;
+            n >>= 1;
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{shapes::*, tensor::*, tensor_ops::*, tests::*};
+
+    #[test]
+    fn test_matrix_power_2() {
+        let dev: TestDevice = Default::default();
+        let a: Tensor<Rank2<3, 3>, TestDtype, _> =
+            dev.tensor([[1.0, 2.0, 0.0], [-1.0, 0.5, 1.0], [0.0, 3.0, 2.0]]);
+        let r = a.trace().matrix_power(2);
+        let r2 = a.clone().matmul(a.clone());
+        assert_close(&r.array(), &r2.array());
+
+        // d(sum(a * a)) / da = ones * a^T + a^T * ones
+        let g = r.sum::<Rank0, _>().backward();
+        let ones: Tensor<Rank2<3, 3>, TestDtype, _> = dev.ones();
+        let a_t = a.clone().permute::<Rank2<3, 3>, _>();
+        let expected = ones.clone().matmul(a_t.clone()) + a_t.matmul(ones);
+        assert_close(&g.get(&a).array(), &expected.array());
+    }
+
+    #[test]
+    fn test_matrix_power_5() {
+        let dev: TestDevice = Default::default();
+        let a: Tensor<Rank2<2, 2>, TestDtype, _> = dev.tensor([[0.5, 0.25], [-0.25, 0.75]]);
+        let r = a.trace().matrix_power(5);
+        assert_close(
+            &r.array(),
+            &[[-0.08496094, 0.1455078], [-0.1455078, 0.06054688]],
+        );
+
+        // d(sum(r * r)) / da = sum_k (a^T)^k * 2r * (a^T)^(4 - k)
+        let g = r.square().sum::<Rank0, _>().backward();
+        assert_close(
+            &g.get(&a).array(),
+            &[[0.2270279, 0.022247314], [-0.022247314, 0.54354095]],
+        );
+    }
+
+    #[test]
+    fn test_matrix_power_0() {
+        let dev: TestDevice = Default::default();
+        let a: Tensor<Rank2<3, 3>, TestDtype, _> = dev.sample_normal();
+        let r = a.trace().matrix_power(0);
+        assert_eq!(
+            r.array(),
+            [[1.0, 0.0, 0.0], [0.0, 1.0, 0.0], [0.0, 0.0, 1.0]]
+        );
+        let g = r.exp().sum().backward();
+        assert_eq!(g.get(&a).array(), [[0.0; 3]; 3]);
+    }
+
+    #[test]
+    fn test_matrix_power_negative() {
+        let dev: TestDevice = Default::default();
+        let a: Tensor<Rank2<2, 2>, TestDtype, _> = dev.tensor([[2.0, 1.0], [1.0, 3.0]]);
+        let r = a.clone().matrix_power(-1);
+        assert_close(&r.array(), &[[0.6, -0.2], [-0.2, 0.4]]);
+        assert_close(&r.array(), &a.clone().inv().array());
+
+        let r = a.clone().matrix_power(-2);
+        let a_inv = a.clone().inv();
+        assert_close(&r.array(), &a_inv.clone().matmul(a_inv).array());
+
+        // with b = inv(a), d(sum(b * b)) / da = -b^T * (ones * b^T + b^T * ones) * b^T
+        let r = a.trace().matrix_power(-2);
+        assert_close(&r.array(), &[[0.4, -0.2], [-0.2, 0.2]]);
+        let g = r.sum::<Rank0, _>().backward();
+        assert_close(&g.get(&a).array(), &[[-0.16, -0.04], [-0.04, 0.0]]);
+    }
+}
@@ -18,6 +18,11 @@ __device__ __forceinline__ double atomicMaxf(double * addr, double value) {
     }
 }
 
+// NaN with the sign bit cleared. atomicMaxf treats it as larger than every other
+// value, so once it is stored in out it is never replaced.
+__device__ __forceinline__ float max_nan(float) { return __int_as_float(0x7fc00000); }
+__device__ __forceinline__ double max_nan(double) { return __longlong_as_double(0x7ff8000000000000ll); }
+
 // Efficiently computes the max of each chunk in "data" of size chunk_len, and
 // stores the maximums in out[i / chunk_len]
 template<typename T>
@@ -70,6 +75,7 @@ __device__ void max_to_fwd(
     const size_t numel,
     const size_t num_dims,
     const size_t chunk_len,
+    const bool ignore_nan,
     const T *inp,
     const size_t *dims,
     const size_t *strides,
@@ -82,7 +88,15 @@ __device__ void max_to_fwd(
     }
 
     unsigned int inp_i = get_strided_index(i, num_dims, dims, strides);
-    chunk_max(numel, chunk_len, inp[inp_i], out);
+    T x = inp[inp_i];
+    if (isnan(x)) {
+        if (!ignore_nan) {
+            atomicMaxf(out + i / chunk_len, max_nan(x));
+        }
+        // every thread has to take part in chunk_max
+        x = -INFINITY;
+    }
+    chunk_max(numel, chunk_len, x, out);
 }
 
 // Accepts pre-broadcasted strides for both input & output.
@@ -109,7 +123,9 @@ __device__ void max_to_bwd(
     unsigned int i = get_unstrided_index(inp_i, num_dims, dims, inp_strides);
     unsigned int out_i = get_strided_index(i, num_dims, dims, out_strides);
 
-    auto tmp = inp[inp_i] == out[out_i] ? grad_out[out_i] : 0.0;
+    auto x = inp[inp_i];
+    auto o = out[out_i];
+    auto tmp = (x == o || (isnan(x) && isnan(o))) ? grad_out[out_i] : 0.0;
     grad_inp[inp_i] += tmp * elems_per_thread;
 }
 
@@ -118,12 +134,13 @@ extern "C" __global__ void FWD( \
     const size_t numel, \
     const size_t num_dims, \
     const size_t chunk_len, \
+    const bool ignore_nan, \
     const TYPENAME *inp, \
     const size_t *dims, \
     const size_t *strides, \
     TYPENAME *out \
 ) { \
-    max_to_fwd(numel, num_dims, chunk_len, inp, dims, strides, out); \
+    max_to_fwd(numel, num_dims, chunk_len, ignore_nan, inp, dims, strides, out); \
 } \
 extern "C" __global__ void BWD( \
     const size_t numel, \
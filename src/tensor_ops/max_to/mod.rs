@@ -6,10 +6,13 @@ mod cuda_kernel;
 use crate::{gradients::Tape, shapes::*, tensor::*};
 
 pub trait MaxReduceKernel<E: Dtype>: DeviceStorage {
+    /// If `ignore_nan` is false, any NaN in a reduced group makes its result NaN.
+    /// Otherwise NaNs are skipped.
     fn forward<Src: Shape, Dst: Shape, Ax: Axes>(
         &self,
         dst: Dst,
         inp: &Tensor<Src, E, Self>,
+        ignore_nan: bool,
     ) -> Result<Tensor<Dst, E, Self>, Self::Err>
     where
         Src: ReduceShapeTo<Dst, Ax>;
@@ -28,6 +31,9 @@ pub trait MaxReduceKernel<E: Dtype>: DeviceStorage {
 pub trait MaxTo: HasErr + HasShape {
     /// Max reduction. **Pytorch equivalent**: `t.amax(Ax)`
     ///
+    /// NaNs are propagated: if any of the reduced values is NaN, the result is NaN,
+    /// and its gradient goes to the NaN values. See [MaxTo::nanmax] to skip NaNs instead.
+    ///
     /// **NOTE** This evenly distributes gradients between all equal maximum values, instead
     /// of only exactly 1 value.
     ///
@@ -58,6 +64,32 @@ pub trait MaxTo: HasErr + HasShape {
     fn try_max<Dst: Shape, Ax: Axes>(self) -> Result<Self::WithShape<Dst>, Self::Err>
     where
         Self::Shape: ReduceShapeTo<Dst, Ax>;
+
+    /// Same as [MaxTo::max], but NaNs are ignored. **Numpy equivalent**: `np.nanmax(t, Ax)`
+    ///
+    /// Unlike numpy, if every reduced value is NaN the result is `-inf`.
+    /// Gradients are only given to values equal to the result, so never to NaNs.
+    ///
+    /// Example:
+    /// ```rust
+    /// # use dfdx::prelude::*;
+    /// # let dev: Cpu = Default::default();
+    /// let t: Tensor<Rank2<2, 3>, f32, _> = dev.tensor([[1.0, f32::NAN, 3.0], [-1.0, -2.0, -3.0]]);
+    /// let r = t.clone().max::<Rank1<2>, _>();
+    /// assert!(r.array()[0].is_nan());
+    /// let r = t.nanmax::<Rank1<2>, _>();
+    /// assert_eq!(r.array(), [3.0, -1.0]);
+    /// ```
+    fn nanmax<Dst: Shape, Ax: Axes>(self) -> Self::WithShape<Dst>
+    where
+        Self::Shape: ReduceShapeTo<Dst, Ax>,
+    {
+        self.try_nanmax().unwrap()
+    }
+    /// Fallible version of [MaxTo::nanmax]
+    fn try_nanmax<Dst: Shape, Ax: Axes>(self) -> Result<Self::WithShape<Dst>, Self::Err>
+    where
+        Self::Shape: ReduceShapeTo<Dst, Ax>;
 }
 
 impl<S: Shape, E: Dtype, D: MaxReduceKernel<E>, T: Tape<E, D>> MaxTo for Tensor<S, E, D, T> {
@@ -65,20 +97,37 @@ impl<S: Shape, E: Dtype, D: MaxReduceKernel<E>, T: Tape<E, D>> MaxTo for Tensor<
     where
         Self::Shape: ReduceShapeTo<Dst, Ax>,
     {
-        let dst: Dst = self.shape().reduced();
-        let (inp, mut tape) = self.split_tape();
-        let out = inp.device.forward(dst, &inp)?;
-        let phantom_out = out.clone();
-        tape.try_alloc_grad(&inp)?;
-        tape.try_alloc_grad(&out)?;
-        tape.add_backward_op(move |grads| {
-            let (grad_inp, grad_out) = grads.mut_and_ref(&inp, &phantom_out);
-            inp.device.backward(&inp, grad_inp, &phantom_out, grad_out)
-        });
-        Ok(out.put_tape(tape))
+        try_max_reduce(self, false)
+    }
+
+    fn try_nanmax<Dst: Shape, Ax: Axes>(self) -> Result<Self::WithShape<Dst>, Self::Err>
+    where
+        Self::Shape: ReduceShapeTo<Dst, Ax>,
+    {
+        try_max_reduce(self, true)
     }
 }
 
+fn try_max_reduce<S, Dst: Shape, Ax: Axes, E: Dtype, D: MaxReduceKernel<E>, T: Tape<E, D>>(
+    t: Tensor<S, E, D, T>,
+    ignore_nan: bool,
+) -> Result<Tensor<Dst, E, D, T>, D::Err>
+where
+    S: Shape + ReduceShapeTo<Dst, Ax>,
+{
+    let dst: Dst = t.shape().reduced();
+    let (inp, mut tape) = t.split_tape();
+    let out = inp.device.forward(dst, &inp, ignore_nan)?;
+    let phantom_out = out.clone();
+    tape.try_alloc_grad(&inp)?;
+    tape.try_alloc_grad(&out)?;
+    tape.add_reusable_backward_op(move |grads| {
+        let (grad_inp, grad_out) = grads.mut_and_ref(&inp, &phantom_out);
+        inp.device.backward(&inp, grad_inp, &phantom_out, grad_out)
+    });
+    Ok(out.put_tape(tape))
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -133,4 +182,52 @@ mod tests {
             [[1.0, 1.0], [1.0, 1.0], [0.0, 1.0], [0.0, 1.0]]
         );
     }
+
+    #[test]
+    fn test_max_large_matches_serial() {
+        let dev: TestDevice = Default::default();
+        let t: Tensor<Rank3<32, 300, 17>, TestDtype, _> = dev.sample_normal();
+        // `as_vec` keeps the input off the stack, which `array` would overflow for f64
+        let v = t.as_vec();
+
+        let r = t.clone().max::<Rank2<32, 17>, _>().array();
+        for i in 0..32 {
+            for k in 0..17 {
+                let expected = (0..300)
+                    .map(|j| v[(i * 300 + j) * 17 + k])
+                    .fold(TestDtype::NEG_INFINITY, TestDtype::max);
+                assert_eq!(r[i][k], expected);
+            }
+        }
+
+        let expected = t
+            .as_vec()
+            .into_iter()
+            .fold(TestDtype::NEG_INFINITY, TestDtype::max);
+        assert_eq!(t.max::<Rank0, _>().array(), expected);
+    }
+
+    #[test]
+    fn test_max_nan() {
+        let dev: TestDevice = Default::default();
+        let t: Tensor<_, TestDtype, _> = dev.tensor([[1.0, TestDtype::NAN, 3.0], [2.0, -1.0, 2.0]]);
+
+        let r = t.trace().max::<_, Axis<1>>();
+        let r_array = r.array();
+        assert!(r_array[0].is_nan());
+        assert_eq!(r_array[1], 2.0);
+        let g = r.sum().backward();
+        assert_eq!(g.get(&t).array(), [[0.0, 1.0, 0.0], [1.0, 0.0, 1.0]]);
+
+        let r = t.trace().nanmax::<_, Axis<1>>();
+        assert_eq!(r.array(), [3.0, 2.0]);
+        let g = r.sum().backward();
+        assert_eq!(g.get(&t).array(), [[0.0, 0.0, 1.0], [1.0, 0.0, 1.0]]);
+
+        assert!(t.clone().max::<Rank0, _>().array().is_nan());
+        assert_eq!(t.nanmax::<Rank0, _>().array(), 3.0);
+
+        let t: Tensor<Rank1<2>, TestDtype, _> = dev.tensor([TestDtype::NAN; 2]);
+        assert_eq!(t.nanmax::<Rank0, _>().array(), TestDtype::NEG_INFINITY);
+    }
 }
@@ -1,4 +1,4 @@
-use crate::tensor_ops::cpu_kernels::BinaryDerivative;
+use crate::tensor_ops::cpu_kernels::{BinaryDerivative, UnaryDerivative};
 
 impl<F: num_traits::Float> BinaryDerivative<F> for super::MaximumKernelOp {
     #[inline(always)]
@@ -26,3 +26,20 @@ impl<F: num_traits::Float> BinaryDerivative<F> for super::MaximumKernelOp {
         }
     }
 }
+
+impl<F: num_traits::Float> UnaryDerivative<F> for super::ScalarMaximumKernelOp<F> {
+    #[inline(always)]
+    fn f(&self, &x: &F) -> F {
+        x.max(self.scalar)
+    }
+    #[inline(always)]
+    fn df(&self, x: &F) -> F {
+        if x > &self.scalar {
+            F::one()
+        } else if x < &self.scalar {
+            F::zero()
+        } else {
+            F::from(0.5).unwrap()
+        }
+    }
+}
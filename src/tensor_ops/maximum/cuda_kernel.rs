@@ -1,9 +1,15 @@
-use super::MaximumKernelOp as Max;
-use crate::tensor_ops::cuda_kernels::cuda_binary;
+use super::{MaximumKernelOp as Max, ScalarMaximumKernelOp as Scalar};
+use crate::tensor_ops::cuda_kernels::{cuda_binary, cuda_unary};
 
 unsafe impl cudarc::driver::DeviceRepr for Max {}
 
+unsafe impl cudarc::driver::DeviceRepr for Scalar<f32> {}
+unsafe impl cudarc::driver::DeviceRepr for Scalar<f64> {}
+
+const SCALAR_PTX: &str = include_str!(concat!(env!("OUT_DIR"), "/scalar_maximum.ptx"));
 const PTX: &str = include_str!(concat!(env!("OUT_DIR"), "/maximum.ptx"));
 
 cuda_binary!(Max, f32, PTX, "maximum_fwd_f32", "maximum_bwd_f32");
 cuda_binary!(Max, f64, PTX, "maximum_fwd_f64", "maximum_bwd_f64");
+cuda_unary!(Scalar<f32>, f32, SCALAR_PTX, "smax_fwd_f32", "smax_bwd_f32");
+cuda_unary!(Scalar<f64>, f64, SCALAR_PTX, "smax_fwd_f64", "smax_bwd_f64");
@@ -0,0 +1,14 @@
+#include "unary_op_macros.cuh"
+
+template<typename F>
+struct ScalarMaximumKernelOp {
+    F scalar;
+};
+
+UNARY_OP(float, smax_fwd_f32, smax_bwd_f32, ScalarMaximumKernelOp<float>,
+    fmaxf(x, op.scalar),
+    (x > op.scalar) ? 1.0 : ((x < op.scalar) ? 0.0 : 0.5));
+
+UNARY_OP(double, smax_fwd_f64, smax_bwd_f64, ScalarMaximumKernelOp<double>,
+    fmax(x, op.scalar),
+    (x > op.scalar) ? 1.0 : ((x < op.scalar) ? 0.0 : 0.5));
@@ -1,7 +1,7 @@
 use crate::{
     shapes::{Axes, Dtype, HasAxes, ReduceShapeTo, Shape},
     tensor::{Cpu, Tensor, ZerosTensor},
-    tensor_ops::utilities::reduction_utils::index_for_reductions,
+    tensor_ops::utilities::reduction_utils::{index_for_reductions, reduce_buf, reduce_into},
 };
 
 use num_traits::Float;
@@ -11,29 +11,27 @@ impl<E: Dtype + Float> super::MinReduceKernel<E> for Cpu {
         &self,
         dst: Dst,
         inp: &Tensor<Src, E, Self>,
+        ignore_nan: bool,
     ) -> Result<Tensor<Dst, E, Self>, Self::Err>
     where
         Src: ReduceShapeTo<Dst, Ax>,
     {
+        // `min` skips NaNs, so they are only propagated if `ignore_nan` is false
+        let f = |a: E, b: E| {
+            if !ignore_nan && (a.is_nan() || b.is_nan()) {
+                E::nan()
+            } else {
+                a.min(b)
+            }
+        };
         let mut out = self.try_zeros_like(&dst)?;
         if Dst::NUM_DIMS == 0 {
             debug_assert_eq!(out.data.len(), 1);
-            let mut tmp: E = E::infinity();
-            for i in inp.buf_iter() {
-                tmp = i.min(tmp);
-            }
+            let tmp = reduce_buf(inp.data.as_ref(), E::infinity(), f);
             std::sync::Arc::get_mut(&mut out.data).unwrap()[0] = tmp;
         } else {
-            let num_elems_reduced = <Src as HasAxes<Ax>>::size(&inp.shape);
-            let inp_buf = inp.data.as_ref();
-            let mut idx = index_for_reductions::<Src, Ax>(inp.shape, inp.strides);
-            for o in out.buf_iter_mut() {
-                let mut tmp: E = E::infinity();
-                for _ in 0..num_elems_reduced {
-                    tmp = tmp.min(inp_buf[idx.next().unwrap()]);
-                }
-                *o = tmp;
-            }
+            let out_buf = std::sync::Arc::get_mut(&mut out.data).unwrap();
+            reduce_into::<Src, Ax, E, _>(out_buf, inp, E::infinity(), f);
         }
         Ok(out)
     }
@@ -56,7 +54,8 @@ impl<E: Dtype + Float> super::MinReduceKernel<E> for Cpu {
         for (&o, &go) in out.buf_iter().zip(grad_out.iter()) {
             for _ in 0..num_elems_reduced {
                 let inp_i = inp_idx.next().unwrap();
-                let d = if o == inp_buf[inp_i] {
+                let x = inp_buf[inp_i];
+                let d = if o == x || (o.is_nan() && x.is_nan()) {
                     E::one()
                 } else {
                     E::zero()
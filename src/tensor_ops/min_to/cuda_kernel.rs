@@ -34,6 +34,7 @@ where
         &self,
         dst: Dst,
         inp: &Tensor<Src, E, Self>,
+        ignore_nan: bool,
     ) -> Result<Tensor<Dst, E, Self>, Self::Err>
     where
         Src: ReduceShapeTo<Dst, Ax>,
@@ -68,6 +69,7 @@ where
             physical_numel,    // const size_t numel,
             dims.len(),        // const size_t num_dims,
             chunk_len,         // const size_t chunk_len,
+            ignore_nan,        // const bool ignore_nan,
             inp.data.as_ref(), // const float *inp,
             &dims,             // const size_t *dims,
             &strides,          // const size_t *strides,
@@ -18,6 +18,11 @@ __device__ __forceinline__ double atomicMinf(double * addr, double value) {
     }
 }
 
+// NaN with the sign bit set. atomicMinf treats it as smaller than every other
+// value, so once it is stored in out it is never replaced.
+__device__ __forceinline__ float min_nan(float) { return __uint_as_float(0xffc00000u); }
+__device__ __forceinline__ double min_nan(double) { return __longlong_as_double(0xfff8000000000000ull); }
+
 // Efficiently computes the min of each chunk in "data" of size chunk_len, and
 // stores the minimums in out[i / chunk_len]
 template<typename T>
@@ -70,6 +75,7 @@ __device__ void min_to_fwd(
     const size_t numel,
     const size_t num_dims,
     const size_t chunk_len,
+    const bool ignore_nan,
     const T *inp,
     const size_t *dims,
     const size_t *strides,
@@ -82,7 +88,15 @@ __device__ void min_to_fwd(
     }
 
     unsigned int inp_i = get_strided_index(i, num_dims, dims, strides);
-    chunk_min(numel, chunk_len, inp[inp_i], out);
+    T x = inp[inp_i];
+    if (isnan(x)) {
+        if (!ignore_nan) {
+            atomicMinf(out + i / chunk_len, min_nan(x));
+        }
+        // every thread has to take part in chunk_min
+        x = INFINITY;
+    }
+    chunk_min(numel, chunk_len, x, out);
 }
 
 // Accepts pre-broadcasted strides for both input & output.
@@ -109,7 +123,9 @@ __device__ void min_to_bwd(
     unsigned int i = get_unstrided_index(inp_i, num_dims, dims, inp_strides);
     unsigned int out_i = get_strided_index(i, num_dims, dims, out_strides);
 
-    auto tmp = inp[inp_i] == out[out_i] ? grad_out[out_i] : 0.0;
+    auto x = inp[inp_i];
+    auto o = out[out_i];
+    auto tmp = (x == o || (isnan(x) && isnan(o))) ? grad_out[out_i] : 0.0;
     grad_inp[inp_i] += tmp * elems_per_thread;
 }
 
@@ -118,12 +134,13 @@ extern "C" __global__ void FWD( \
     const size_t numel, \
     const size_t num_dims, \
     const size_t chunk_len, \
+    const bool ignore_nan, \
     const TYPENAME *inp, \
     const size_t *dims, \
     const size_t *strides, \
     TYPENAME *out \
 ) { \
-    min_to_fwd(numel, num_dims, chunk_len, inp, dims, strides, out); \
+    min_to_fwd(numel, num_dims, chunk_len, ignore_nan, inp, dims, strides, out); \
 } \
 extern "C" __global__ void BWD( \
     const size_t numel, \
@@ -1,4 +1,4 @@
-use crate::tensor_ops::cpu_kernels::BinaryDerivative;
+use crate::tensor_ops::cpu_kernels::{BinaryDerivative, UnaryDerivative};
 
 use num_traits::Float;
 
@@ -29,3 +29,20 @@ impl<F: Float> BinaryDerivative<F> for super::MinimumKernelOp {
         }
     }
 }
+
+impl<F: num_traits::Float> UnaryDerivative<F> for super::ScalarMinimumKernelOp<F> {
+    #[inline(always)]
+    fn f(&self, &x: &F) -> F {
+        x.min(self.scalar)
+    }
+    #[inline(always)]
+    fn df(&self, x: &F) -> F {
+        if x < &self.scalar {
+            F::one()
+        } else if x > &self.scalar {
+            F::zero()
+        } else {
+            F::from(0.5).unwrap()
+        }
+    }
+}
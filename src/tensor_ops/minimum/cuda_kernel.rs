@@ -1,9 +1,15 @@
-use super::MinimumKernelOp as Min;
-use crate::tensor_ops::cuda_kernels::cuda_binary;
+use super::{MinimumKernelOp as Min, ScalarMinimumKernelOp as Scalar};
+use crate::tensor_ops::cuda_kernels::{cuda_binary, cuda_unary};
 
 unsafe impl cudarc::driver::DeviceRepr for super::MinimumKernelOp {}
 
+unsafe impl cudarc::driver::DeviceRepr for Scalar<f32> {}
+unsafe impl cudarc::driver::DeviceRepr for Scalar<f64> {}
+
+const SCALAR_PTX: &str = include_str!(concat!(env!("OUT_DIR"), "/scalar_minimum.ptx"));
 const PTX: &str = include_str!(concat!(env!("OUT_DIR"), "/minimum.ptx"));
 
 cuda_binary!(Min, f32, PTX, "minimum_fwd_f32", "minimum_bwd_f32");
 cuda_binary!(Min, f64, PTX, "minimum_fwd_f64", "minimum_bwd_f64");
+cuda_unary!(Scalar<f32>, f32, SCALAR_PTX, "smin_fwd_f32", "smin_bwd_f32");
+cuda_unary!(Scalar<f64>, f64, SCALAR_PTX, "smin_fwd_f64", "smin_bwd_f64");
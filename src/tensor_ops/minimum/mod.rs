@@ -3,13 +3,22 @@ mod cpu_kernel;
 #[cfg(feature = "cuda")]
 mod cuda_kernel;
 
-use super::{ops::try_binary_op, Device};
+use super::{
+    ops::{try_binary_op, try_unary_op, UnaryKernel},
+    Device,
+};
 use crate::{gradients::*, shapes::*, tensor::Tensor};
 
 #[repr(C)]
 #[derive(Debug, Default, Clone, Copy)]
 pub struct MinimumKernelOp;
 
+#[repr(C)]
+#[derive(Debug, Clone, Copy)]
+pub struct ScalarMinimumKernelOp<E> {
+    scalar: E,
+}
+
 /// Element wise minimum.
 ///
 /// **Pytorch equivalent**: `torch.minimum(a, b)`
@@ -46,6 +55,33 @@ impl<S: Shape, E: Dtype, D: Device<E>, LTape: Tape<E, D>> Tensor<S, E, D, LTape>
         try_binary_op(MinimumKernelOp, self, rhs)
     }
 }
+
+impl<S: Shape, E: Dtype, D: UnaryKernel<ScalarMinimumKernelOp<E>, E>, T: Tape<E, D>>
+    Tensor<S, E, D, T>
+{
+    /// Element wise minimum with a scalar, without allocating a tensor for `scalar`.
+    ///
+    /// The gradient goes to the elements that are smaller than `scalar`, and is split in
+    /// half where they are equal, the same as [minimum] with a broadcasted `scalar`.
+    ///
+    /// Example:
+    /// ```rust
+    /// # use dfdx::prelude::*;
+    /// # let dev: Cpu = Default::default();
+    /// let a = dev.tensor([-1.0, 0.0, 1.0, 2.0]);
+    /// let r = a.minimum_scalar(0.5);
+    /// assert_eq!(r.array(), [-1.0, 0.0, 0.5, 0.5]);
+    /// ```
+    pub fn minimum_scalar(self, scalar: E) -> Self {
+        self.try_minimum_scalar(scalar).unwrap()
+    }
+
+    /// See [Tensor::minimum_scalar]
+    pub fn try_minimum_scalar(self, scalar: E) -> Result<Self, D::Err> {
+        try_unary_op(ScalarMinimumKernelOp { scalar }, self)
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use crate::{tensor::*, tensor_ops::*, tests::*};
@@ -63,4 +99,23 @@ mod tests {
         assert_eq!(g.get(&a).array(), [[1.0, 0.5, 0.0], [0.5, 0.0, 1.0]]);
         assert_eq!(g.get(&b).array(), [[0.0, 0.5, 1.0], [0.5, 1.0, 0.0]]);
     }
+
+    #[test]
+    fn test_minimum_scalar() {
+        let dev: TestDevice = Default::default();
+        let a: Tensor<_, TestDtype, _> = dev.tensor([[-1.0, 0.0, 1.0], [3.0, 0.25, -5.0]]);
+
+        let r = a.trace().minimum_scalar(0.5);
+        let b: Tensor<_, TestDtype, _> = dev.ones_like(&a.shape) * 0.5;
+        let r2 = a.trace().minimum(b);
+        assert_eq!(r.array(), r2.array());
+
+        let g = r.exp().sum().backward();
+        let g2 = r2.exp().sum().backward();
+        assert_close(&g.get(&a).array(), &g2.get(&a).array());
+
+        // ties split the gradient in half, like minimum does
+        let g = a.trace().minimum_scalar(1.0).sum().backward();
+        assert_eq!(g.get(&a).array(), [[1.0, 1.0, 0.5], [0.0, 1.0, 1.0]]);
+    }
 }
@@ -0,0 +1,14 @@
+#include "unary_op_macros.cuh"
+
+template<typename F>
+struct ScalarMinimumKernelOp {
+    F scalar;
+};
+
+UNARY_OP(float, smin_fwd_f32, smin_bwd_f32, ScalarMinimumKernelOp<float>,
+    fminf(x, op.scalar),
+    (x < op.scalar) ? 1.0 : ((x > op.scalar) ? 0.0 : 0.5));
+
+UNARY_OP(double, smin_fwd_f64, smin_bwd_f64, ScalarMinimumKernelOp<double>,
+    fmin(x, op.scalar),
+    (x < op.scalar) ? 1.0 : ((x > op.scalar) ? 0.0 : 0.5));
@@ -144,24 +144,46 @@ pub use utilities::*;
 
 mod abs;
 mod add;
+mod affine;
 mod attention_reshape;
 pub(crate) mod axpy;
 mod bce;
+mod bincount;
+mod bitwise;
 mod boolean;
+mod broadcast_binary;
 mod broadcast_to;
+mod cholesky;
 mod choose;
 mod clamp;
 mod cmp;
 mod cos;
+mod cosine_similarity;
+mod cumminmax;
+mod cumprod;
+mod diff;
 mod div;
+mod dot;
 mod dropout;
 mod exp;
+mod frobenius;
+mod fused_linear;
 mod gelu;
+mod global_avg_pool2d;
+mod grid_sample;
 mod huber_error;
+mod index_select;
+mod inv;
+mod keepdim;
 mod ln;
+mod log10;
+mod log2;
 mod log_softmax;
 mod logsumexp_to;
+mod map;
+mod masked_select;
 mod matmul;
+mod matrix_power;
 mod max_to;
 mod maximum;
 mod mean_to;
@@ -170,11 +192,22 @@ mod minimum;
 mod mul;
 mod nans_to;
 mod negate;
+mod nonzero;
+mod norm;
 mod normalize;
 mod permute_to;
+mod pixel_shuffle;
 mod pow;
+mod qr;
+mod quantize;
 mod relu;
+mod relu6;
+mod rem;
+mod repeat_interleave;
 mod reshape_to;
+mod rope;
+mod rsqrt;
+mod searchsorted;
 mod select_and_gather;
 mod sigmoid;
 mod sin;
@@ -185,29 +218,51 @@ mod stack;
 mod stddev_to;
 mod sub;
 mod sum_to;
+mod take_along_axis;
 mod tanh;
+mod tensordot;
+mod triangular_solve;
+mod unbind;
+mod vander;
 mod var_to;
 
 pub use abs::abs;
 pub use add::{add, TryAdd};
+pub use affine::affine;
 pub use attention_reshape::TryAttentionReshape;
 pub use axpy::axpy;
 pub use bce::bce_with_logits;
+pub use bincount::Bincount;
+pub use bitwise::{bitand, bitor, bitxor, shl, shr};
 pub use boolean::{bool_and, bool_not, bool_or, bool_xor};
 pub use broadcast_to::BroadcastTo;
+pub use cholesky::cholesky;
 pub use choose::ChooseFrom;
-pub use clamp::clamp;
+pub use clamp::{clamp, clamp_max, clamp_min};
 pub use cmp::{eq, ge, gt, le, lt, ne};
 pub use cos::cos;
+pub use cosine_similarity::cosine_similarity;
+pub use diff::Diff;
 pub use div::{div, TryDiv};
+pub use dot::dot;
 pub use dropout::dropout;
 pub use exp::exp;
-pub use gelu::gelu;
+pub use frobenius::{frobenius_inner, frobenius_norm};
+pub use fused_linear::{linear_gelu, linear_relu};
+pub use gelu::{gelu, gelu_exact, gelu_fast};
+pub use grid_sample::{affine_grid, grid_sample, PaddingMode};
 pub use huber_error::huber_error;
+pub use index_select::IndexSelect;
+pub use inv::inv;
+pub use keepdim::ExpandTo;
 pub use ln::ln;
+pub use log10::log10;
+pub use log2::log2;
 pub use log_softmax::log_softmax;
 pub use logsumexp_to::LogSumExpTo;
-pub use matmul::{matmul, TryMatMul};
+pub use masked_select::MaskedSelect;
+pub use matmul::{matmul, outer, TryMatMul};
+pub use matrix_power::matrix_power;
 pub use max_to::MaxTo;
 pub use maximum::maximum;
 pub use mean_to::MeanTo;
@@ -216,22 +271,36 @@ pub use minimum::minimum;
 pub use mul::{mul, TryMul};
 pub use nans_to::nans_to;
 pub use negate::negate;
+pub use nonzero::nonzero;
+pub use norm::norm;
 pub use normalize::normalize;
 pub use permute_to::PermuteTo;
-pub use pow::{powf, powi};
+pub use pow::{pow_tensor, powf, powi};
+pub use qr::qr;
+pub use quantize::{dequantize, quantize};
 pub use relu::relu;
+pub use relu6::relu6;
+pub use rem::{fmod, rem};
+pub use repeat_interleave::RepeatInterleave;
 pub use reshape_to::ReshapeTo;
+pub use rope::rotary_embedding;
+pub use rsqrt::rsqrt;
+pub use searchsorted::{searchsorted, SearchSide};
 pub use select_and_gather::{GatherTo, SelectTo};
 pub use sigmoid::sigmoid;
 pub use sin::sin;
 pub use softmax::softmax;
 pub use sqrt::sqrt;
 pub use square::square;
-pub use stack::TryStack;
+pub use stack::{StackError, TryStack};
 pub use stddev_to::StddevTo;
 pub use sub::{sub, TrySub};
 pub use sum_to::SumTo;
+pub use take_along_axis::TakeAlongAxis;
 pub use tanh::tanh;
+pub use tensordot::tensordot;
+pub use triangular_solve::triangular_solve;
+pub use vander::vander;
 pub use var_to::VarTo;
 
 #[cfg(feature = "nightly")]
@@ -246,4 +315,9 @@ mod pool2d;
 #[cfg(feature = "nightly")]
 pub(crate) use pool2d::{ConstAvgPool2D, ConstMaxPool2D, ConstMinPool2D};
 #[cfg(feature = "nightly")]
-pub use pool2d::{TryAvgPool2D, TryMaxPool2D, TryMinPool2D};
+pub use pool2d::{TryAvgPool2D, TryAvgPool2DExcludePad, TryMaxPool2D, TryMinPool2D};
+
+#[cfg(feature = "nightly")]
+mod upsample;
+#[cfg(feature = "nightly")]
+pub use upsample::TryUpsample2D;
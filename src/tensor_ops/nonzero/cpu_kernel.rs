@@ -0,0 +1,30 @@
+use crate::{
+    shapes::*,
+    tensor::{
+        cpu::{Cpu, LendingIterator},
+        Tensor, TensorFromVec,
+    },
+};
+
+use std::vec::Vec;
+
+impl<E: Unit> super::NonzeroKernel<E> for Cpu {
+    fn forward<S: Shape, T>(
+        &self,
+        inp: &Tensor<S, E, Self, T>,
+    ) -> Result<Tensor<(usize, usize), usize, Self>, Self::Err> {
+        let mut coords = Vec::new();
+        let mut iter = inp.iter();
+        let mut i = 0;
+        let mut k = 0;
+        while let Some(x) = iter.next() {
+            if *x != E::default() {
+                super::push_coords(&inp.shape, i, &mut coords);
+                k += 1;
+            }
+            i += 1;
+        }
+        let shape = (k, S::NUM_DIMS);
+        self.try_tensor_from_vec(coords, shape)
+    }
+}
@@ -0,0 +1,72 @@
+use crate::{
+    shapes::*,
+    tensor::{Cuda, Tensor},
+};
+use cudarc::driver::{CudaSlice, LaunchAsync, LaunchConfig};
+
+use std::vec::Vec;
+
+const PTX_SRC: &str = include_str!(concat!(env!("OUT_DIR"), "/nonzero.ptx"));
+
+trait HasCudaKernel<E> {
+    const MOD: &'static str;
+    const FNS: &'static [&'static str];
+}
+
+impl HasCudaKernel<f32> for Cuda {
+    const MOD: &'static str = "nonzero_f32";
+    const FNS: &'static [&'static str] = &["nonzero_mask_f32"];
+}
+
+impl HasCudaKernel<f64> for Cuda {
+    const MOD: &'static str = "nonzero_f64";
+    const FNS: &'static [&'static str] = &["nonzero_mask_f64"];
+}
+
+impl<E: Dtype> super::NonzeroKernel<E> for Cuda
+where
+    Self: HasCudaKernel<E>,
+{
+    fn forward<S: Shape, T>(
+        &self,
+        inp: &Tensor<S, E, Self, T>,
+    ) -> Result<Tensor<(usize, usize), usize, Self>, Self::Err> {
+        if !self.dev.has_func(Self::MOD, Self::FNS[0]) {
+            self.dev.load_ptx(PTX_SRC.into(), Self::MOD, Self::FNS)?;
+        }
+
+        let numel = inp.shape.num_elements();
+        let mut mask = self.dev.alloc_zeros::<bool>(numel)?;
+        if numel > 0 {
+            let dims: CudaSlice<usize> = self.dev.htod_copy(inp.shape.concrete().into())?;
+            let strides: CudaSlice<usize> = self.dev.htod_copy(inp.strides.into())?;
+            let fwd_fn = self.dev.get_func(Self::MOD, Self::FNS[0]).unwrap();
+            let cfg = LaunchConfig::for_num_elems(numel as u32);
+            let params = (
+                numel,             // const size_t numel,
+                S::NUM_DIMS,       // const size_t num_dims,
+                &dims,             // const size_t *dims,
+                inp.data.as_ref(), // const T *inp,
+                &strides,          // const size_t *strides,
+                &mut mask,         // bool *mask
+            );
+            unsafe { fwd_fn.launch(cfg, params) }?;
+        }
+
+        // the number of nonzero elements has to be known on the host to allocate the output
+        let mask: Vec<bool> = self.dev.dtoh_sync_copy(&mask)?;
+        let mut coords = Vec::new();
+        let mut k = 0;
+        for (i, _) in mask.into_iter().enumerate().filter(|(_, m)| *m) {
+            super::push_coords(&inp.shape, i, &mut coords);
+            k += 1;
+        }
+        let shape = (k, S::NUM_DIMS);
+        let storage = if coords.is_empty() {
+            self.dev.alloc_zeros::<usize>(0)?
+        } else {
+            self.dev.htod_copy(coords)?
+        };
+        Ok(self.build_tensor(shape, shape.strides(), storage))
+    }
+}
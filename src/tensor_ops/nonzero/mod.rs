@@ -0,0 +1,109 @@
+mod cpu_kernel;
+
+#[cfg(feature = "cuda")]
+mod cuda_kernel;
+
+use crate::{
+    gradients::{NoneTape, Tape},
+    shapes::*,
+    tensor::{DeviceStorage, Tensor},
+};
+
+pub trait NonzeroKernel<E: Unit>: DeviceStorage {
+    fn forward<S: Shape, T>(
+        &self,
+        inp: &Tensor<S, E, Self, T>,
+    ) -> Result<Tensor<(usize, usize), usize, Self>, Self::Err>;
+}
+
+/// Appends the coordinates of the `i`th element of `shape` in row-major order to `coords`.
+fn push_coords<S: Shape>(shape: &S, mut i: usize, coords: &mut std::vec::Vec<usize>) {
+    let dims = shape.concrete();
+    let start = coords.len();
+    coords.resize(start + S::NUM_DIMS, 0);
+    for d in (0..S::NUM_DIMS).rev() {
+        coords[start + d] = i % dims[d];
+        i /= dims[d];
+    }
+}
+
+/// The coordinates of every element that isn't zero, in row-major order.
+///
+/// The result has shape `(K, S::NUM_DIMS)`, where `K` is the number of nonzero
+/// elements and row `k` holds the index along each axis of the `k`th nonzero element.
+/// Since `K` depends on the values, both dimensions are runtime dimensions.
+///
+/// This is not recorded on the tape, so the result has no gradient.
+///
+/// **Pytorch equivalent**: `torch.nonzero(t)`
+///
+/// Examples:
+/// ```
+/// # use dfdx::prelude::*;
+/// # let dev: Cpu = Default::default();
+/// let a = dev.tensor([[0.0, 1.5, 0.0], [-2.0, 0.0, 3.0]]);
+/// let r = a.nonzero();
+/// assert_eq!(r.shape(), &(3, 2));
+/// assert_eq!(r.as_vec(), [0, 1, 1, 0, 1, 2]);
+/// ```
+pub fn nonzero<S: Shape, E: Unit, D: NonzeroKernel<E>, T: Tape<E, D>>(
+    t: &Tensor<S, E, D, T>,
+) -> Tensor<(usize, usize), usize, D, NoneTape> {
+    t.nonzero()
+}
+
+impl<S: Shape, E: Unit, D: NonzeroKernel<E>, T: Tape<E, D>> Tensor<S, E, D, T> {
+    /// See [nonzero]
+    pub fn nonzero(&self) -> Tensor<(usize, usize), usize, D, NoneTape> {
+        self.try_nonzero().unwrap()
+    }
+
+    /// See [nonzero]
+    #[allow(clippy::type_complexity)]
+    pub fn try_nonzero(&self) -> Result<Tensor<(usize, usize), usize, D, NoneTape>, D::Err> {
+        self.device.forward(self)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{shapes::*, tensor::*, tensor_ops::*, tests::*};
+
+    #[test]
+    fn test_nonzero_2d() {
+        let dev: TestDevice = Default::default();
+        let a: Tensor<_, TestDtype, _> = dev.tensor([
+            [0.0, 2.0, 0.0, 0.0],
+            [0.0, 0.0, 0.0, -1.0],
+            [3.0, 0.0, 0.5, 0.0],
+        ]);
+        let r = a.nonzero();
+        assert_eq!(r.shape(), &(4, 2));
+        assert_eq!(r.as_vec(), [0, 1, 1, 3, 2, 0, 2, 2]);
+    }
+
+    #[test]
+    fn test_nonzero_permuted() {
+        let dev: TestDevice = Default::default();
+        let a: Tensor<_, TestDtype, _> = dev.tensor([[0.0, 2.0, 0.0], [4.0, 0.0, 5.0]]);
+        let r = a.permute::<Rank2<3, 2>, _>().nonzero();
+        assert_eq!(r.shape(), &(3, 2));
+        assert_eq!(r.as_vec(), [0, 1, 1, 0, 2, 1]);
+    }
+
+    #[test]
+    fn test_nonzero_none() {
+        let dev: TestDevice = Default::default();
+        let a: Tensor<Rank3<2, 3, 4>, TestDtype, _> = dev.zeros();
+        let r = a.nonzero();
+        assert_eq!(r.shape(), &(0, 3));
+    }
+
+    #[test]
+    fn test_nonzero_0d() {
+        let dev: TestDevice = Default::default();
+        let a: Tensor<Rank0, TestDtype, _> = dev.ones();
+        assert_eq!(a.nonzero().shape(), &(1, 0));
+        assert_eq!((a * 0.0).nonzero().shape(), &(0, 0));
+    }
+}
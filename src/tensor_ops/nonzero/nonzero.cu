@@ -0,0 +1,41 @@
+#include "cuda_utils.cuh"
+
+template<typename T>
+__device__ void nonzero_mask(
+    const size_t numel,
+    const size_t num_dims,
+    const size_t *dims,
+    const T *inp,
+    const size_t *strides,
+    bool *mask
+) {
+    unsigned int i = blockIdx.x * blockDim.x + threadIdx.x;
+    if (i >= numel) {
+        return;
+    }
+
+    unsigned int inp_i = get_strided_index(i, num_dims, dims, strides);
+    mask[i] = inp[inp_i] != 0.0;
+}
+
+extern "C" __global__ void nonzero_mask_f32(
+    const size_t numel,
+    const size_t num_dims,
+    const size_t *dims,
+    const float *inp,
+    const size_t *strides,
+    bool *mask
+) {
+    nonzero_mask(numel, num_dims, dims, inp, strides, mask);
+}
+
+extern "C" __global__ void nonzero_mask_f64(
+    const size_t numel,
+    const size_t num_dims,
+    const size_t *dims,
+    const double *inp,
+    const size_t *strides,
+    bool *mask
+) {
+    nonzero_mask(numel, num_dims, dims, inp, strides, mask);
+}
@@ -0,0 +1,100 @@
+use crate::{
+    gradients::Tape,
+    shapes::{Axes, Dtype, ReduceShape, Shape},
+    tensor::{HasErr, Tensor},
+};
+
+use super::{ChooseFrom, Device, SumTo};
+
+/// The `p`-norm of `t` along `Ax`: `sum(|t|^p)^(1/p)`. The gradient of an all-zero
+/// slice is zero.
+///
+/// **Pytorch equivalent**: `torch.linalg.vector_norm(t, ord=p, dim)`
+///
+/// Example:
+/// ```rust
+/// # use dfdx::prelude::*;
+/// # let dev: Cpu = Default::default();
+/// let t = dev.tensor([[3.0, -4.0], [1.0, 1.0]]);
+/// assert_eq!(t.clone().norm::<Axis<1>>(1.0).array(), [7.0, 2.0]);
+/// assert_eq!(t.norm::<Axis<1>>(2.0).array()[0], 5.0);
+/// ```
+pub fn norm<Ax: Axes, S: Shape + ReduceShape<Ax>, E: Dtype, D: Device<E>, T: Tape<E, D>>(
+    t: Tensor<S, E, D, T>,
+    p: E,
+) -> Tensor<S::Reduced, E, D, T> {
+    t.norm::<Ax>(p)
+}
+
+impl<S: Shape, E: Dtype, D: Device<E>, T: Tape<E, D>> Tensor<S, E, D, T> {
+    /// See [norm]
+    pub fn norm<Ax: Axes>(self, p: E) -> Tensor<S::Reduced, E, D, T>
+    where
+        S: ReduceShape<Ax>,
+    {
+        self.try_norm::<Ax>(p).unwrap()
+    }
+
+    /// See [norm]
+    pub fn try_norm<Ax: Axes>(
+        self,
+        p: E,
+    ) -> Result<Tensor<S::Reduced, E, D, T>, <Self as HasErr>::Err>
+    where
+        S: ReduceShape<Ax>,
+    {
+        let sum = self.try_abs()?.try_powf(p)?.try_sum::<_, Ax>()?;
+        // the gradient of `sum^(1/p)` is infinite at 0, so all-zero slices are routed
+        // around it to give them a zero subgradient instead of NaN
+        let nonzero = sum.try_scalar_ne(E::default())?;
+        let ones = sum.device.try_ones_like(&sum.shape)?;
+        let zeros = sum.device.try_zeros_like(&sum.shape)?;
+        let norm = nonzero
+            .clone()
+            .try_choose(sum, ones)?
+            .try_powf(E::ONE / p)?;
+        nonzero.try_choose(norm, zeros)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::tests::*;
+    use crate::{shapes::*, tensor::*, tensor_ops::*};
+
+    #[test]
+    fn test_norm_p() {
+        let dev: TestDevice = Default::default();
+        let t: Tensor<_, TestDtype, _> = dev.tensor([[1.0, -2.0, 2.0], [0.5, 0.0, -0.5]]);
+        assert_close(&t.clone().norm::<Axis<1>>(1.0).array(), &[5.0, 1.0]);
+        assert_close(&t.clone().norm::<Axis<1>>(2.0).array(), &[3.0, 0.70710677]);
+        assert_close(
+            &t.norm::<Axis<0>>(3.0).array(),
+            &[1.0400419, 2.0, 2.0103629],
+        );
+    }
+
+    #[test]
+    fn test_norm_2_grad() {
+        let dev: TestDevice = Default::default();
+        let t: Tensor<_, TestDtype, _> = dev.tensor([[1.0, -2.0, 2.0], [3.0, 0.0, -4.0]]);
+        let g = t.trace().norm::<Axis<1>>(2.0).sum().backward();
+        assert_close(
+            &g.get(&t).array(),
+            &[[1.0 / 3.0, -2.0 / 3.0, 2.0 / 3.0], [0.6, 0.0, -0.8]],
+        );
+    }
+
+    #[test]
+    fn test_norm_zero_slice_grad() {
+        let dev: TestDevice = Default::default();
+        let t: Tensor<_, TestDtype, _> = dev.tensor([[0.0, 0.0, 0.0], [3.0, 0.0, -4.0]]);
+        let r = t.trace().norm::<Axis<1>>(2.0);
+        assert_eq!(r.array(), [0.0, 5.0]);
+        let g = r.sum().backward();
+        assert_close(&g.get(&t).array(), &[[0.0; 3], [0.6, 0.0, -0.8]]);
+
+        let g = t.trace().norm::<Axis<1>>(1.0).sum().backward();
+        assert_close(&g.get(&t).array(), &[[0.0; 3], [1.0, 0.0, -1.0]]);
+    }
+}
@@ -16,6 +16,7 @@ impl<E: Dtype> super::PermuteKernel<E> for Cpu {
             shape: inp.shape.permuted(),
             strides: inp.shape.permute_strides(inp.strides),
             device: self.clone(),
+            param_opts: Default::default(),
             tape: Default::default(),
         })
     }
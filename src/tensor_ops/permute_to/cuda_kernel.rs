@@ -38,6 +38,7 @@ where
             shape: inp.shape.permuted(),
             strides: inp.shape.permute_strides(inp.strides),
             device: self.clone(),
+            param_opts: Default::default(),
             tape: Default::default(),
         })
     }
@@ -39,6 +39,76 @@ pub trait PermuteTo: HasErr + HasShape {
     fn try_permute<Dst: Shape, Ax: Axes>(self) -> Result<Self::WithShape<Dst>, Self::Err>
     where
         Self::Shape: PermuteShapeTo<Dst, Ax>;
+
+    /// Swaps axes `A` and `B`, without having to write out the full permutation:
+    /// ```rust
+    /// # use dfdx::prelude::*;
+    /// # let dev: Cpu = Default::default();
+    /// let a: Tensor<Rank3<1, 2, 3>, f32, _> = dev.zeros();
+    /// let _: Tensor<Rank3<3, 2, 1>, f32, _> = a.swapaxes::<Axis<0>, Axis<2>>();
+    /// ```
+    ///
+    /// **Pytorch equivalent**: `t.transpose(A, B)`
+    fn swapaxes<A, B>(self) -> Self::WithShape<<Self::Shape as SwapAxesShape<A, B>>::Swapped>
+    where
+        Self::Shape: SwapAxesShape<A, B>,
+        Self::Shape: PermuteShapeTo<
+            <Self::Shape as SwapAxesShape<A, B>>::Swapped,
+            <Self::Shape as SwapAxesShape<A, B>>::Perm,
+        >,
+    {
+        self.try_swapaxes().unwrap()
+    }
+    /// Fallible version of [PermuteTo::swapaxes]
+    #[allow(clippy::type_complexity)]
+    fn try_swapaxes<A, B>(
+        self,
+    ) -> Result<Self::WithShape<<Self::Shape as SwapAxesShape<A, B>>::Swapped>, Self::Err>
+    where
+        Self::Shape: SwapAxesShape<A, B>,
+        Self::Shape: PermuteShapeTo<
+            <Self::Shape as SwapAxesShape<A, B>>::Swapped,
+            <Self::Shape as SwapAxesShape<A, B>>::Perm,
+        >,
+    {
+        self.try_permute::<_, <Self::Shape as SwapAxesShape<A, B>>::Perm>()
+    }
+
+    /// Moves axis `Src` so that it ends up at position `Dst`, keeping the
+    /// order of all the other axes:
+    /// ```rust
+    /// # use dfdx::prelude::*;
+    /// # let dev: Cpu = Default::default();
+    /// let a: Tensor<Rank4<1, 2, 3, 4>, f32, _> = dev.zeros();
+    /// let _: Tensor<Rank4<2, 3, 1, 4>, f32, _> = a.clone().movedim::<Axis<0>, Axis<2>>();
+    /// let _: Tensor<Rank4<4, 1, 2, 3>, f32, _> = a.movedim::<Axis<3>, Axis<0>>();
+    /// ```
+    ///
+    /// **Pytorch equivalent**: `t.movedim(Src, Dst)`
+    fn movedim<Src, Dst>(self) -> Self::WithShape<<Self::Shape as MoveAxisShape<Src, Dst>>::Moved>
+    where
+        Self::Shape: MoveAxisShape<Src, Dst>,
+        Self::Shape: PermuteShapeTo<
+            <Self::Shape as MoveAxisShape<Src, Dst>>::Moved,
+            <Self::Shape as MoveAxisShape<Src, Dst>>::Perm,
+        >,
+    {
+        self.try_movedim().unwrap()
+    }
+    /// Fallible version of [PermuteTo::movedim]
+    #[allow(clippy::type_complexity)]
+    fn try_movedim<Src, Dst>(
+        self,
+    ) -> Result<Self::WithShape<<Self::Shape as MoveAxisShape<Src, Dst>>::Moved>, Self::Err>
+    where
+        Self::Shape: MoveAxisShape<Src, Dst>,
+        Self::Shape: PermuteShapeTo<
+            <Self::Shape as MoveAxisShape<Src, Dst>>::Moved,
+            <Self::Shape as MoveAxisShape<Src, Dst>>::Perm,
+        >,
+    {
+        self.try_permute::<_, <Self::Shape as MoveAxisShape<Src, Dst>>::Perm>()
+    }
 }
 
 impl<S: Shape, E: Dtype, D: PermuteKernel<E>, T: Tape<E, D>> PermuteTo for Tensor<S, E, D, T> {
@@ -51,7 +121,7 @@ impl<S: Shape, E: Dtype, D: PermuteKernel<E>, T: Tape<E, D>> PermuteTo for Tenso
         let phantom_out = out.clone();
         tape.try_alloc_grad(&inp)?;
         tape.try_alloc_grad(&out)?;
-        tape.add_backward_op(move |grads| {
+        tape.add_reusable_backward_op(move |grads| {
             let (grad_inp, grad_out) = grads.mut_and_ref(&inp, &phantom_out);
             inp.device.backward(grad_inp, grad_out)
         });
@@ -191,4 +261,42 @@ mod tests {
         x.clone().permute::<_, Axes4<3, 2, 0, 1>>();
         x.permute::<_, Axes4<3, 2, 1, 0>>();
     }
+
+    #[test]
+    fn test_swapaxes_3d() {
+        let dev: TestDevice = Default::default();
+        let t: Tensor<Rank3<2, 3, 4>, TestDtype, _> = dev.sample_normal();
+        let r = t.trace().swapaxes::<Axis<0>, Axis<2>>();
+        let r2 = t.clone().permute::<Rank3<4, 3, 2>, Axes3<2, 1, 0>>();
+        assert_eq!(r.array(), r2.array());
+        assert_eq!(t.clone().swapaxes::<Axis<2>, Axis<0>>().array(), r2.array());
+
+        let w: Tensor<Rank3<4, 3, 2>, TestDtype, _> = dev.sample_normal();
+        let g = (r * w.clone()).sum().backward();
+        assert_eq!(g.get(&t).array(), w.permute::<_, Axes3<2, 1, 0>>().array());
+    }
+
+    #[test]
+    fn test_movedim() {
+        let dev: TestDevice = Default::default();
+        let t: Tensor<Rank4<2, 3, 4, 5>, TestDtype, _> = dev.sample_normal();
+        let r: Tensor<Rank4<3, 4, 2, 5>, TestDtype, _> = t.clone().movedim::<Axis<0>, Axis<2>>();
+        assert_eq!(
+            r.array(),
+            t.clone().permute::<_, Axes4<1, 2, 0, 3>>().array()
+        );
+        let r: Tensor<Rank4<5, 2, 3, 4>, TestDtype, _> = t.clone().movedim::<Axis<3>, Axis<0>>();
+        assert_eq!(
+            r.array(),
+            t.clone().permute::<_, Axes4<3, 0, 1, 2>>().array()
+        );
+
+        let g = t
+            .trace()
+            .movedim::<Axis<1>, Axis<3>>()
+            .exp()
+            .sum()
+            .backward();
+        assert_close(&g.get(&t).array(), &t.exp().array());
+    }
 }
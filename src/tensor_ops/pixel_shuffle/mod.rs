@@ -0,0 +1,140 @@
+#![allow(clippy::type_complexity)]
+
+use crate::{
+    gradients::Tape,
+    shapes::{Axes6, Dim, Dtype},
+    tensor::Tensor,
+};
+
+use super::{permute_to::PermuteKernel, reshape_to::ReshapeKernel, PermuteTo, ReshapeTo};
+
+impl<B: Dim, C: Dim, H: Dim, W: Dim, E: Dtype, D, T: Tape<E, D>> Tensor<(B, C, H, W), E, D, T>
+where
+    D: ReshapeKernel<E> + PermuteKernel<E>,
+{
+    /// Rearranges a `(B, C * R * R, H, W)` tensor into `(B, C, H * R, W * R)`, moving
+    /// each group of `R * R` channels into an `R x R` block of pixels.
+    ///
+    /// This is the upsampling step of sub-pixel convolutions. Panics if the number
+    /// of channels is not divisible by `R * R`.
+    ///
+    /// **Pytorch equivalent**: `torch.nn.functional.pixel_shuffle(t, R)`
+    ///
+    /// Example:
+    /// ```rust
+    /// # use dfdx::prelude::*;
+    /// # let dev: Cpu = Default::default();
+    /// let t: Tensor<Rank4<1, 4, 1, 1>, f32, _> = dev.tensor([[[[1.0]], [[2.0]], [[3.0]], [[4.0]]]]);
+    /// let r = t.pixel_shuffle::<2>();
+    /// assert_eq!(r.shape(), &(Const, 1, 2, 2));
+    /// assert_eq!(r.as_vec(), [1.0, 2.0, 3.0, 4.0]);
+    /// ```
+    pub fn pixel_shuffle<const R: usize>(self) -> Tensor<(B, usize, usize, usize), E, D, T> {
+        self.try_pixel_shuffle::<R>().unwrap()
+    }
+
+    /// Fallible version of [Tensor::pixel_shuffle]
+    pub fn try_pixel_shuffle<const R: usize>(
+        self,
+    ) -> Result<Tensor<(B, usize, usize, usize), E, D, T>, D::Err> {
+        assert!(R > 0);
+        let (b, c, h, w) = self.shape;
+        let c = c.size();
+        assert_eq!(c % (R * R), 0, "channels must be divisible by R * R");
+        let (c, h, w) = (c / (R * R), h.size(), w.size());
+        self.try_reshape_like(&(b, c, R, R, h, w))?
+            .try_permute::<_, Axes6<0, 1, 4, 2, 5, 3>>()?
+            .try_reshape_like(&(b, c, h * R, w * R))
+    }
+
+    /// The inverse of [Tensor::pixel_shuffle], which rearranges a `(B, C, H * R, W * R)`
+    /// tensor into `(B, C * R * R, H, W)`.
+    ///
+    /// Panics if the height or width is not divisible by `R`.
+    ///
+    /// **Pytorch equivalent**: `torch.nn.functional.pixel_unshuffle(t, R)`
+    ///
+    /// Example:
+    /// ```rust
+    /// # use dfdx::prelude::*;
+    /// # let dev: Cpu = Default::default();
+    /// let t: Tensor<Rank4<1, 1, 2, 2>, f32, _> = dev.tensor([[[[1.0, 2.0], [3.0, 4.0]]]]);
+    /// let r = t.pixel_unshuffle::<2>();
+    /// assert_eq!(r.shape(), &(Const, 4, 1, 1));
+    /// assert_eq!(r.as_vec(), [1.0, 2.0, 3.0, 4.0]);
+    /// ```
+    pub fn pixel_unshuffle<const R: usize>(self) -> Tensor<(B, usize, usize, usize), E, D, T> {
+        self.try_pixel_unshuffle::<R>().unwrap()
+    }
+
+    /// Fallible version of [Tensor::pixel_unshuffle]
+    pub fn try_pixel_unshuffle<const R: usize>(
+        self,
+    ) -> Result<Tensor<(B, usize, usize, usize), E, D, T>, D::Err> {
+        assert!(R > 0);
+        let (b, c, h, w) = self.shape;
+        let (c, h, w) = (c.size(), h.size(), w.size());
+        assert_eq!(h % R, 0, "height must be divisible by R");
+        assert_eq!(w % R, 0, "width must be divisible by R");
+        let (h, w) = (h / R, w / R);
+        self.try_reshape_like(&(b, c, h, R, w, R))?
+            .try_permute::<_, Axes6<0, 1, 3, 5, 2, 4>>()?
+            .try_reshape_like(&(b, c * R * R, h, w))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{shapes::*, tensor::*, tensor_ops::*, tests::*};
+
+    #[test]
+    fn test_pixel_shuffle() {
+        let dev: TestDevice = Default::default();
+        let t: Tensor<Rank4<1, 8, 1, 2>, TestDtype, _> = dev.tensor([[
+            [[0.0, 1.0]],
+            [[2.0, 3.0]],
+            [[4.0, 5.0]],
+            [[6.0, 7.0]],
+            [[8.0, 9.0]],
+            [[10.0, 11.0]],
+            [[12.0, 13.0]],
+            [[14.0, 15.0]],
+        ]]);
+        let r = t.pixel_shuffle::<2>();
+        assert_eq!(r.shape(), &(Const, 2, 2, 4));
+        assert_eq!(
+            r.as_vec(),
+            [
+                0.0, 2.0, 1.0, 3.0, 4.0, 6.0, 5.0, 7.0, 8.0, 10.0, 9.0, 11.0, 12.0, 14.0, 13.0,
+                15.0
+            ]
+        );
+    }
+
+    #[test]
+    fn test_pixel_shuffle_round_trip() {
+        let dev: TestDevice = Default::default();
+        let t: Tensor<Rank4<2, 18, 2, 3>, TestDtype, _> = dev.sample_normal();
+        let r = t.clone().pixel_shuffle::<3>();
+        assert_eq!(r.shape(), &(Const, 2, 6, 9));
+        let u = r.pixel_unshuffle::<3>();
+        assert_eq!(u.shape(), &(Const, 18, 2, 3));
+        assert_eq!(u.as_vec(), t.as_vec());
+    }
+
+    #[test]
+    fn test_pixel_shuffle_gradient() {
+        let dev: TestDevice = Default::default();
+        let t: Tensor<Rank4<1, 4, 2, 2>, TestDtype, _> = dev.sample_normal();
+        let w: Tensor<Rank4<1, 1, 4, 4>, TestDtype, _> = dev.sample_normal();
+        let r = t.trace().pixel_shuffle::<2>();
+        let g = (r * w.clone().reshape_like(&(Const::<1>, 1, 4, 4)))
+            .sum::<Rank0, _>()
+            .backward();
+        // every element is moved exactly once, so the gradient is `w` moved back
+        let expected = w
+            .reshape_like(&(Const::<1>, 1, 4, 4))
+            .pixel_unshuffle::<2>();
+        assert_eq!(g.get(&t).as_vec(), expected.as_vec());
+    }
+}
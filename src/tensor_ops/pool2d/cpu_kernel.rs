@@ -32,10 +32,10 @@ impl<E: Float + Unit + std::ops::AddAssign + std::ops::DivAssign> super::AvgPool
                 for oh in 0..op.h_out {
                     for ow in 0..op.w_out {
                         let mut tmp = E::zero();
-                        for k1 in 0..op.kernel {
-                            let y = (oh * op.stride + k1).checked_sub(op.padding);
-                            for k2 in 0..op.kernel {
-                                let x = (ow * op.stride + k2).checked_sub(op.padding);
+                        for k1 in 0..op.kernel_h {
+                            let y = (oh * op.stride_h + k1).checked_sub(op.padding_h);
+                            for k2 in 0..op.kernel_w {
+                                let x = (ow * op.stride_w + k2).checked_sub(op.padding_w);
                                 if let Some((y, x)) = y.zip(x) {
                                     if y < op.h_in && x < op.w_in {
                                         let inp_idx =
@@ -45,7 +45,7 @@ impl<E: Float + Unit + std::ops::AddAssign + std::ops::DivAssign> super::AvgPool
                                 }
                             }
                         }
-                        tmp /= E::from(op.kernel * op.kernel).unwrap();
+                        tmp /= E::from(op.avg_divisor(oh, ow)).unwrap();
                         out_buf[b * ostr[0] + c * ostr[1] + oh * ostr[2] + ow * ostr[3]] = tmp;
                     }
                 }
@@ -70,12 +70,12 @@ impl<E: Float + Unit + std::ops::AddAssign + std::ops::DivAssign> super::AvgPool
                 for oh in 0..op.h_out {
                     for ow in 0..op.w_out {
                         let g = grad_out[b * ostr[0] + c * ostr[1] + oh * ostr[2] + ow * ostr[3]]
-                            / E::from(op.kernel * op.kernel).unwrap();
+                            / E::from(op.avg_divisor(oh, ow)).unwrap();
 
-                        for k1 in 0..op.kernel {
-                            let y = (oh * op.stride + k1).checked_sub(op.padding);
-                            for k2 in 0..op.kernel {
-                                let x = (ow * op.stride + k2).checked_sub(op.padding);
+                        for k1 in 0..op.kernel_h {
+                            let y = (oh * op.stride_h + k1).checked_sub(op.padding_h);
+                            for k2 in 0..op.kernel_w {
+                                let x = (ow * op.stride_w + k2).checked_sub(op.padding_w);
                                 if let Some((y, x)) = y.zip(x) {
                                     if x < op.w_in && y < op.h_in {
                                         grad_inp[b * istr[0]
@@ -111,10 +111,10 @@ impl<E: Float + Unit + std::ops::AddAssign> super::MaxPool2DKernel<E> for Cpu {
                 for oh in 0..op.h_out {
                     for ow in 0..op.w_out {
                         let mut tmp = E::neg_infinity();
-                        for k1 in 0..op.kernel {
-                            let y = (oh * op.stride + k1).checked_sub(op.padding);
-                            for k2 in 0..op.kernel {
-                                let x = (ow * op.stride + k2).checked_sub(op.padding);
+                        for k1 in 0..op.kernel_h {
+                            let y = (oh * op.stride_h + k1).checked_sub(op.padding_h);
+                            for k2 in 0..op.kernel_w {
+                                let x = (ow * op.stride_w + k2).checked_sub(op.padding_w);
                                 if let Some((y, x)) = y.zip(x) {
                                     if y < op.h_in && x < op.w_in {
                                         tmp = tmp.max(
@@ -155,10 +155,10 @@ impl<E: Float + Unit + std::ops::AddAssign> super::MaxPool2DKernel<E> for Cpu {
                         let out_idx = b * ostr[0] + c * ostr[1] + oh * ostr[2] + ow * ostr[3];
                         let go = grad_out[out_idx];
                         let vo = out_buf[out_idx];
-                        for k1 in 0..op.kernel {
-                            let y = (oh * op.stride + k1).checked_sub(op.padding);
-                            for k2 in 0..op.kernel {
-                                let x = (ow * op.stride + k2).checked_sub(op.padding);
+                        for k1 in 0..op.kernel_h {
+                            let y = (oh * op.stride_h + k1).checked_sub(op.padding_h);
+                            for k2 in 0..op.kernel_w {
+                                let x = (ow * op.stride_w + k2).checked_sub(op.padding_w);
                                 if let Some((y, x)) = y.zip(x) {
                                     if x < op.w_in && y < op.h_in {
                                         let inp_idx =
@@ -195,10 +195,10 @@ impl<E: Float + Unit + std::ops::AddAssign> super::MinPool2DKernel<E> for Cpu {
                 for oh in 0..op.h_out {
                     for ow in 0..op.w_out {
                         let mut tmp = E::infinity();
-                        for k1 in 0..op.kernel {
-                            let y = (oh * op.stride + k1).checked_sub(op.padding);
-                            for k2 in 0..op.kernel {
-                                let x = (ow * op.stride + k2).checked_sub(op.padding);
+                        for k1 in 0..op.kernel_h {
+                            let y = (oh * op.stride_h + k1).checked_sub(op.padding_h);
+                            for k2 in 0..op.kernel_w {
+                                let x = (ow * op.stride_w + k2).checked_sub(op.padding_w);
                                 if let Some((y, x)) = y.zip(x) {
                                     if y < op.h_in && x < op.w_in {
                                         tmp = tmp.min(
@@ -239,10 +239,10 @@ impl<E: Float + Unit + std::ops::AddAssign> super::MinPool2DKernel<E> for Cpu {
                         let out_idx = b * ostr[0] + c * ostr[1] + oh * ostr[2] + ow * ostr[3];
                         let go = grad_out[out_idx];
                         let vo = out_buf[out_idx];
-                        for k1 in 0..op.kernel {
-                            let y = (oh * op.stride + k1).checked_sub(op.padding);
-                            for k2 in 0..op.kernel {
-                                let x = (ow * op.stride + k2).checked_sub(op.padding);
+                        for k1 in 0..op.kernel_h {
+                            let y = (oh * op.stride_h + k1).checked_sub(op.padding_h);
+                            for k2 in 0..op.kernel_w {
+                                let x = (ow * op.stride_w + k2).checked_sub(op.padding_w);
                                 if let Some((y, x)) = y.zip(x) {
                                     if x < op.w_in && y < op.h_in {
                                         let inp_idx =
@@ -9,40 +9,112 @@ use crate::{
     tensor::{DeviceStorage, HasErr, PutTape, SplitTape, Tensor, ZerosTensor},
 };
 
-use super::conv2d::ConvAlgebra;
-
 #[repr(C)]
 #[derive(Debug, Copy, Clone)]
 pub struct Pool2DOp {
-    pub kernel: usize,
-    pub stride: usize,
-    pub padding: usize,
+    pub kernel_h: usize,
+    pub kernel_w: usize,
+    pub stride_h: usize,
+    pub stride_w: usize,
+    pub padding_h: usize,
+    pub padding_w: usize,
     pub batch: usize,
     pub chan: usize,
     pub h_in: usize,
     pub h_out: usize,
     pub w_in: usize,
     pub w_out: usize,
+    pub count_include_pad: bool,
 }
 
 impl Pool2DOp {
-    fn new(k: usize, s: usize, p: usize, [b, c, h_in, w_in]: [usize; 4]) -> Self {
+    fn new(
+        [kh, kw]: [usize; 2],
+        [sh, sw]: [usize; 2],
+        [ph, pw]: [usize; 2],
+        ceil_mode: bool,
+        [b, c, h_in, w_in]: [usize; 4],
+    ) -> Self {
         Self {
-            kernel: k,
-            stride: s,
-            padding: p,
+            kernel_h: kh,
+            kernel_w: kw,
+            stride_h: sh,
+            stride_w: sw,
+            padding_h: ph,
+            padding_w: pw,
             batch: b,
             chan: c,
             h_in,
-            h_out: (h_in + 2 * p - k) / s + 1,
+            h_out: pool2d_out_dim(h_in, kh, sh, ph, ceil_mode),
             w_in,
-            w_out: (w_in + 2 * p - k) / s + 1,
+            w_out: pool2d_out_dim(w_in, kw, sw, pw, ceil_mode),
+            count_include_pad: true,
+        }
+    }
+
+    /// What to divide the sum of the window at `(oh, ow)` by for average pooling.
+    ///
+    /// With `count_include_pad` this is the number of elements of the padded input
+    /// covered by the window, which is `kernel_h * kernel_w` except for windows that
+    /// hang off the end of the padded input in ceil mode. Otherwise padding is not
+    /// counted, so it is the number of real elements in the window. A window that only
+    /// covers padding has nothing to average, so its divisor is clamped to 1 to avoid
+    /// dividing 0 by 0.
+    fn avg_divisor(&self, oh: usize, ow: usize) -> usize {
+        let (h0, w0) = (oh * self.stride_h, ow * self.stride_w);
+        let h1 = (h0 + self.kernel_h).min(self.h_in + 2 * self.padding_h);
+        let w1 = (w0 + self.kernel_w).min(self.w_in + 2 * self.padding_w);
+        if self.count_include_pad {
+            (h1 - h0) * (w1 - w0)
+        } else {
+            let h = h1
+                .min(self.h_in + self.padding_h)
+                .saturating_sub(h0.max(self.padding_h));
+            let w = w1
+                .min(self.w_in + self.padding_w)
+                .saturating_sub(w0.max(self.padding_w));
+            (h * w).max(1)
         }
     }
 }
 
+/// The output size of pooling a dimension of size `d`. With `ceil_mode` the last
+/// window is kept even if it only partially overlaps the (padded) input, as long
+/// as it starts inside the input or the left padding. This matches pytorch.
+///
+/// If the (padded) input is smaller than the kernel there are no windows, so this is 0.
+pub const fn pool2d_out_dim(d: usize, k: usize, s: usize, p: usize, ceil_mode: bool) -> usize {
+    if d + 2 * p < k {
+        return 0;
+    }
+    if !ceil_mode {
+        return (d + 2 * p - k) / s + 1;
+    }
+    let span = d + 2 * p - k;
+    let out = span.div_ceil(s) + 1;
+    if (out - 1) * s >= d + p {
+        out - 1
+    } else {
+        out
+    }
+}
+
+pub trait PoolAlgebra<const K: usize, const S: usize, const P: usize, const CEIL: bool>:
+    ConstDim
+{
+    type Pooled: ConstDim;
+}
+
+impl<const D: usize, const K: usize, const S: usize, const P: usize, const CEIL: bool>
+    PoolAlgebra<K, S, P, CEIL> for Const<D>
+where
+    Const<{ pool2d_out_dim(D, K, S, P, CEIL) }>: Sized,
+{
+    type Pooled = Const<{ pool2d_out_dim(D, K, S, P, CEIL) }>;
+}
+
 macro_rules! pool2d {
-    (Kernel=$Kernel:ident, ConstTrait=$ConstTrait:ident, TryTrait=$TryTrait:ident, Meth=$Meth:ident, TryMeth=$TryMeth:ident) => {
+    ($(#[$attr:meta])* Kernel=$Kernel:ident, ConstTrait=$ConstTrait:ident, RectTrait=$RectTrait:ident, TryTrait=$TryTrait:ident, Meth=$Meth:ident, TryMeth=$TryMeth:ident, CeilMeth=$CeilMeth:ident, TryCeilMeth=$TryCeilMeth:ident, RectMeth=$RectMeth:ident, TryRectMeth=$TryRectMeth:ident) => {
         pub trait $Kernel<E: Unit>: DeviceStorage {
             fn forward<I: Shape, O: Shape>(
                 &self,
@@ -61,11 +133,45 @@ macro_rules! pool2d {
             ) -> Result<(), Self::Err>;
         }
 
-        pub trait $ConstTrait<const K: usize, const S: usize, const P: usize>: HasErr {
+        pub trait $ConstTrait<
+            const K: usize,
+            const S: usize,
+            const P: usize,
+            const CEIL: bool = false,
+        >: HasErr
+        {
             type Output;
             fn try_pool2d(self) -> Result<Self::Output, Self::Err>;
         }
 
+        /// Pooling with a different kernel size, stride and padding along the height
+        /// and width. The square version is implemented in terms of this.
+        pub trait $RectTrait<
+            const KH: usize,
+            const KW: usize,
+            const SH: usize,
+            const SW: usize,
+            const PH: usize,
+            const PW: usize,
+            const CEIL: bool = false,
+        >: HasErr
+        {
+            type Output;
+            fn try_pool2d_rect(self) -> Result<Self::Output, Self::Err>;
+        }
+
+        impl<T, const K: usize, const S: usize, const P: usize, const CEIL: bool>
+            $ConstTrait<K, S, P, CEIL> for T
+        where
+            T: $RectTrait<K, K, S, S, P, P, CEIL>,
+        {
+            type Output = T::Output;
+            fn try_pool2d(self) -> Result<Self::Output, Self::Err> {
+                self.try_pool2d_rect()
+            }
+        }
+
+        $(#[$attr])*
         pub trait $TryTrait {
             fn $Meth<const K: usize, const S: usize, const P: usize>(self) -> Self::Output
             where
@@ -81,6 +187,55 @@ macro_rules! pool2d {
             {
                 self.try_pool2d()
             }
+            /// Same as the floor mode method, but the output size is computed with
+            /// ceiling division. Windows that hang off the edge of the input only
+            /// cover the elements that exist.
+            fn $CeilMeth<const K: usize, const S: usize, const P: usize>(self) -> Self::Output
+            where
+                Self: $ConstTrait<K, S, P, true>,
+            {
+                self.try_pool2d().unwrap()
+            }
+            fn $TryCeilMeth<const K: usize, const S: usize, const P: usize>(
+                self,
+            ) -> Result<Self::Output, Self::Err>
+            where
+                Self: $ConstTrait<K, S, P, true>,
+            {
+                self.try_pool2d()
+            }
+            /// Pools with kernel size `(KH, KW)`, stride `(SH, SW)` and padding `(PH, PW)`
+            /// along the height and width.
+            fn $RectMeth<
+                const KH: usize,
+                const KW: usize,
+                const SH: usize,
+                const SW: usize,
+                const PH: usize,
+                const PW: usize,
+            >(
+                self,
+            ) -> <Self as $RectTrait<KH, KW, SH, SW, PH, PW>>::Output
+            where
+                Self: $RectTrait<KH, KW, SH, SW, PH, PW>,
+            {
+                self.try_pool2d_rect().unwrap()
+            }
+            fn $TryRectMeth<
+                const KH: usize,
+                const KW: usize,
+                const SH: usize,
+                const SW: usize,
+                const PH: usize,
+                const PW: usize,
+            >(
+                self,
+            ) -> Result<<Self as $RectTrait<KH, KW, SH, SW, PH, PW>>::Output, Self::Err>
+            where
+                Self: $RectTrait<KH, KW, SH, SW, PH, PW>,
+            {
+                self.try_pool2d_rect()
+            }
         }
         impl<T> $TryTrait for T {}
 
@@ -91,28 +246,33 @@ macro_rules! pool2d {
                 E: Dtype,
                 D: $Kernel<E> + ZerosTensor<E>,
                 T: 'static + Tape<E, D>,
-                const K: usize,
-                const S: usize,
-                const P: usize,
-            > $ConstTrait<K, S, P> for Tensor<(C, Const<H>, Const<W>), E, D, T>
+                const KH: usize,
+                const KW: usize,
+                const SH: usize,
+                const SW: usize,
+                const PH: usize,
+                const PW: usize,
+                const CEIL: bool,
+            > $RectTrait<KH, KW, SH, SW, PH, PW, CEIL> for Tensor<(C, Const<H>, Const<W>), E, D, T>
         where
-            Const<H>: ConvAlgebra<K, S, P>,
-            Const<W>: ConvAlgebra<K, S, P>,
+            Const<H>: PoolAlgebra<KH, SH, PH, CEIL>,
+            Const<W>: PoolAlgebra<KW, SW, PW, CEIL>,
         {
             type Output = Tensor<
                 (
                     C,
-                    <Const<H> as ConvAlgebra<K, S, P>>::Convolved,
-                    <Const<W> as ConvAlgebra<K, S, P>>::Convolved,
+                    <Const<H> as PoolAlgebra<KH, SH, PH, CEIL>>::Pooled,
+                    <Const<W> as PoolAlgebra<KW, SW, PW, CEIL>>::Pooled,
                 ),
                 E,
                 D,
                 T,
             >;
 
-            fn try_pool2d(self) -> Result<Self::Output, Self::Err> {
+            fn try_pool2d_rect(self) -> Result<Self::Output, Self::Err> {
                 let &(chan, _, _) = self.shape();
-                let op = Pool2DOp::new(K, S, P, [1, chan.size(), H, W]);
+                let op =
+                    Pool2DOp::new([KH, KW], [SH, SW], [PH, PW], CEIL, [1, chan.size(), H, W]);
                 let (inp, mut tape) = self.split_tape();
                 let mut out =
                     inp.device
@@ -121,7 +281,7 @@ macro_rules! pool2d {
                 let phantom_out = out.clone();
                 tape.try_alloc_grad(&inp)?;
                 tape.try_alloc_grad(&out)?;
-                tape.add_backward_op(move |grads| {
+                tape.add_reusable_backward_op(move |grads| {
                     let (grad_inp, grad_out) = grads.mut_and_ref(&inp, &phantom_out);
                     inp.device
                         .backward(op, &inp, grad_inp, &phantom_out, grad_out)
@@ -138,29 +298,40 @@ macro_rules! pool2d {
                 E: Dtype,
                 D: $Kernel<E> + ZerosTensor<E>,
                 T: 'static + Tape<E, D>,
-                const K: usize,
-                const S: usize,
-                const P: usize,
-            > $ConstTrait<K, S, P> for Tensor<(B, C, Const<H>, Const<W>), E, D, T>
+                const KH: usize,
+                const KW: usize,
+                const SH: usize,
+                const SW: usize,
+                const PH: usize,
+                const PW: usize,
+                const CEIL: bool,
+            > $RectTrait<KH, KW, SH, SW, PH, PW, CEIL>
+            for Tensor<(B, C, Const<H>, Const<W>), E, D, T>
         where
-            Const<H>: ConvAlgebra<K, S, P>,
-            Const<W>: ConvAlgebra<K, S, P>,
+            Const<H>: PoolAlgebra<KH, SH, PH, CEIL>,
+            Const<W>: PoolAlgebra<KW, SW, PW, CEIL>,
         {
             type Output = Tensor<
                 (
                     B,
                     C,
-                    <Const<H> as ConvAlgebra<K, S, P>>::Convolved,
-                    <Const<W> as ConvAlgebra<K, S, P>>::Convolved,
+                    <Const<H> as PoolAlgebra<KH, SH, PH, CEIL>>::Pooled,
+                    <Const<W> as PoolAlgebra<KW, SW, PW, CEIL>>::Pooled,
                 ),
                 E,
                 D,
                 T,
             >;
 
-            fn try_pool2d(self) -> Result<Self::Output, Self::Err> {
+            fn try_pool2d_rect(self) -> Result<Self::Output, Self::Err> {
                 let &(batch, chan, _, _) = self.shape();
-                let op = Pool2DOp::new(K, S, P, [batch.size(), chan.size(), H, W]);
+                let op = Pool2DOp::new(
+                    [KH, KW],
+                    [SH, SW],
+                    [PH, PW],
+                    CEIL,
+                    [batch.size(), chan.size(), H, W],
+                );
                 let (inp, mut tape) = self.split_tape();
                 let mut out = inp.device.try_zeros_like(&(
                     batch,
@@ -172,7 +343,85 @@ macro_rules! pool2d {
                 let phantom_out = out.clone();
                 tape.try_alloc_grad(&inp)?;
                 tape.try_alloc_grad(&out)?;
-                tape.add_backward_op(move |grads| {
+                tape.add_reusable_backward_op(move |grads| {
+                    let (grad_inp, grad_out) = grads.mut_and_ref(&inp, &phantom_out);
+                    inp.device
+                        .backward(op, &inp, grad_inp, &phantom_out, grad_out)
+                });
+                Ok(out.put_tape(tape))
+            }
+        }
+
+        impl<
+                C: Dim,
+                E: Dtype,
+                D: $Kernel<E> + ZerosTensor<E>,
+                T: 'static + Tape<E, D>,
+                const KH: usize,
+                const KW: usize,
+                const SH: usize,
+                const SW: usize,
+                const PH: usize,
+                const PW: usize,
+                const CEIL: bool,
+            > $RectTrait<KH, KW, SH, SW, PH, PW, CEIL> for Tensor<(C, usize, usize), E, D, T>
+        {
+            type Output = Tensor<(C, usize, usize), E, D, T>;
+
+            fn try_pool2d_rect(self) -> Result<Self::Output, Self::Err> {
+                let &(chan, h, w) = self.shape();
+                let op =
+                    Pool2DOp::new([KH, KW], [SH, SW], [PH, PW], CEIL, [1, chan.size(), h, w]);
+                let (inp, mut tape) = self.split_tape();
+                let mut out = inp.device.try_zeros_like(&(chan, op.h_out, op.w_out))?;
+                inp.device.forward(op, &inp, &mut out)?;
+                let phantom_out = out.clone();
+                tape.try_alloc_grad(&inp)?;
+                tape.try_alloc_grad(&out)?;
+                tape.add_reusable_backward_op(move |grads| {
+                    let (grad_inp, grad_out) = grads.mut_and_ref(&inp, &phantom_out);
+                    inp.device
+                        .backward(op, &inp, grad_inp, &phantom_out, grad_out)
+                });
+                Ok(out.put_tape(tape))
+            }
+        }
+
+        impl<
+                B: Dim,
+                C: Dim,
+                E: Dtype,
+                D: $Kernel<E> + ZerosTensor<E>,
+                T: 'static + Tape<E, D>,
+                const KH: usize,
+                const KW: usize,
+                const SH: usize,
+                const SW: usize,
+                const PH: usize,
+                const PW: usize,
+                const CEIL: bool,
+            > $RectTrait<KH, KW, SH, SW, PH, PW, CEIL> for Tensor<(B, C, usize, usize), E, D, T>
+        {
+            type Output = Tensor<(B, C, usize, usize), E, D, T>;
+
+            fn try_pool2d_rect(self) -> Result<Self::Output, Self::Err> {
+                let &(batch, chan, h, w) = self.shape();
+                let op = Pool2DOp::new(
+                    [KH, KW],
+                    [SH, SW],
+                    [PH, PW],
+                    CEIL,
+                    [batch.size(), chan.size(), h, w],
+                );
+                let (inp, mut tape) = self.split_tape();
+                let mut out = inp
+                    .device
+                    .try_zeros_like(&(batch, chan, op.h_out, op.w_out))?;
+                inp.device.forward(op, &inp, &mut out)?;
+                let phantom_out = out.clone();
+                tape.try_alloc_grad(&inp)?;
+                tape.try_alloc_grad(&out)?;
+                tape.add_reusable_backward_op(move |grads| {
                     let (grad_inp, grad_out) = grads.mut_and_ref(&inp, &phantom_out);
                     inp.device
                         .backward(op, &inp, grad_inp, &phantom_out, grad_out)
@@ -186,27 +435,100 @@ macro_rules! pool2d {
 pool2d!(
     Kernel = AvgPool2DKernel,
     ConstTrait = ConstAvgPool2D,
+    RectTrait = ConstAvgPool2DRect,
     TryTrait = TryAvgPool2D,
     Meth = avg_pool2d,
-    TryMeth = try_avg_pool2d
+    TryMeth = try_avg_pool2d,
+    CeilMeth = avg_pool2d_ceil,
+    TryCeilMeth = try_avg_pool2d_ceil,
+    RectMeth = avg_pool2d_rect,
+    TryRectMeth = try_avg_pool2d_rect
 );
 
 pool2d!(
     Kernel = MaxPool2DKernel,
     ConstTrait = ConstMaxPool2D,
+    RectTrait = ConstMaxPool2DRect,
     TryTrait = TryMaxPool2D,
     Meth = max_pool2d,
-    TryMeth = try_max_pool2d
+    TryMeth = try_max_pool2d,
+    CeilMeth = max_pool2d_ceil,
+    TryCeilMeth = try_max_pool2d_ceil,
+    RectMeth = max_pool2d_rect,
+    TryRectMeth = try_max_pool2d_rect
 );
 
 pool2d!(
     Kernel = MinPool2DKernel,
     ConstTrait = ConstMinPool2D,
+    RectTrait = ConstMinPool2DRect,
     TryTrait = TryMinPool2D,
     Meth = min_pool2d,
-    TryMeth = try_min_pool2d
+    TryMeth = try_min_pool2d,
+    CeilMeth = min_pool2d_ceil,
+    TryCeilMeth = try_min_pool2d_ceil,
+    RectMeth = min_pool2d_rect,
+    TryRectMeth = try_min_pool2d_rect
+);
+
+pool2d!(
+    /// Average pooling where padding is not counted in the divisor of each window,
+    /// so every output is the average of only the real elements it covers.
+    ///
+    /// **Pytorch equivalent**: `torch.nn.functional.avg_pool2d(x, K, S, P, count_include_pad=False)`
+    ///
+    /// Example:
+    /// ```rust
+    /// # use dfdx::prelude::*;
+    /// # let dev: Cpu = Default::default();
+    /// let x: Tensor<Rank3<1, 2, 2>, f32, _> = dev.ones();
+    /// let r = x.clone().avg_pool2d::<2, 1, 1>();
+    /// assert_eq!(r.array(), [[[0.25, 0.5, 0.25], [0.5, 1.0, 0.5], [0.25, 0.5, 0.25]]]);
+    /// let r = x.avg_pool2d_exclude_pad::<2, 1, 1>();
+    /// assert_eq!(r.array(), [[[1.0; 3]; 3]]);
+    /// ```
+    Kernel = AvgPool2DExcludePadKernel,
+    ConstTrait = ConstAvgPool2DExcludePad,
+    RectTrait = ConstAvgPool2DExcludePadRect,
+    TryTrait = TryAvgPool2DExcludePad,
+    Meth = avg_pool2d_exclude_pad,
+    TryMeth = try_avg_pool2d_exclude_pad,
+    CeilMeth = avg_pool2d_exclude_pad_ceil,
+    TryCeilMeth = try_avg_pool2d_exclude_pad_ceil,
+    RectMeth = avg_pool2d_exclude_pad_rect,
+    TryRectMeth = try_avg_pool2d_exclude_pad_rect
 );
 
+impl<E: Unit, D: AvgPool2DKernel<E>> AvgPool2DExcludePadKernel<E> for D {
+    fn forward<I: Shape, O: Shape>(
+        &self,
+        op: Pool2DOp,
+        inp: &Tensor<I, E, Self>,
+        out: &mut Tensor<O, E, Self>,
+    ) -> Result<(), Self::Err> {
+        let op = Pool2DOp {
+            count_include_pad: false,
+            ..op
+        };
+        AvgPool2DKernel::forward(self, op, inp, out)
+    }
+
+    fn backward<I: Shape, O: Shape>(
+        &self,
+        op: Pool2DOp,
+        inp: &Tensor<I, E, Self>,
+        grad_inp: &mut Self::Vec<E>,
+        out: &Tensor<O, E, Self>,
+        grad_out: &Self::Vec<E>,
+    ) -> Result<(), Self::Err> {
+        let op = Pool2DOp {
+            count_include_pad: false,
+            ..op
+        };
+        AvgPool2DKernel::backward(self, op, inp, grad_inp, out, grad_out)
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -292,6 +614,202 @@ mod tests {
         );
     }
 
+    #[test]
+    fn test_pool2d_ceil_mode_sizes() {
+        let dev: TestDevice = Default::default();
+        let x: Tensor<Rank3<2, 5, 7>, TestDtype, _> = dev.zeros();
+        let _: Tensor<Rank3<2, 2, 3>, TestDtype, _> = x.clone().avg_pool2d::<2, 2, 0>();
+        let _: Tensor<Rank3<2, 3, 4>, TestDtype, _> = x.clone().avg_pool2d_ceil::<2, 2, 0>();
+        let _: Tensor<Rank3<2, 2, 3>, TestDtype, _> = x.clone().max_pool2d::<3, 2, 0>();
+        let _: Tensor<Rank3<2, 2, 3>, TestDtype, _> = x.clone().max_pool2d_ceil::<3, 2, 0>();
+        // the last window would start in the right padding, so it is dropped
+        let _: Tensor<Rank3<2, 3, 4>, TestDtype, _> = x.clone().min_pool2d::<2, 2, 1>();
+        let _: Tensor<Rank3<2, 3, 4>, TestDtype, _> = x.min_pool2d_ceil::<2, 2, 1>();
+    }
+
+    #[test]
+    fn test_pool2d_ceil_mode_edge_windows() {
+        let dev: TestDevice = Default::default();
+        let x: Tensor<_, TestDtype, _> =
+            dev.tensor([[[1.0, 2.0, 3.0], [4.0, 5.0, 6.0], [7.0, 8.0, 9.0]]]);
+
+        let r = x.trace().avg_pool2d::<2, 2, 0>();
+        assert_close(&r.array(), &[[[3.0]]]);
+
+        let r = x.trace().avg_pool2d_ceil::<2, 2, 0>();
+        assert_close(&r.array(), &[[[3.0, 4.5], [7.5, 9.0]]]);
+        let g = r.sum().backward();
+        assert_close(
+            &g.get(&x).array(),
+            &[[[0.25, 0.25, 0.5], [0.25, 0.25, 0.5], [0.5, 0.5, 1.0]]],
+        );
+
+        let r = x.trace().max_pool2d_ceil::<2, 2, 0>();
+        assert_close(&r.array(), &[[[5.0, 6.0], [8.0, 9.0]]]);
+        let g = r.sum().backward();
+        assert_close(
+            &g.get(&x).array(),
+            &[[[0.0, 0.0, 0.0], [0.0, 1.0, 1.0], [0.0, 1.0, 1.0]]],
+        );
+    }
+
+    #[test]
+    fn test_avg_pool2d_count_include_pad() {
+        let dev: TestDevice = Default::default();
+        let x: Tensor<_, TestDtype, _> = dev.tensor([[[1.0, 2.0, 3.0], [4.0, 5.0, 6.0]]]);
+
+        let r = x.trace().avg_pool2d::<3, 2, 1>();
+        assert_close(&r.array(), &[[[12.0 / 9.0, 16.0 / 9.0]]]);
+        let g = r.sum().backward();
+        assert_close(
+            &g.get(&x).array(),
+            &[[
+                [1.0 / 9.0, 2.0 / 9.0, 1.0 / 9.0],
+                [1.0 / 9.0, 2.0 / 9.0, 1.0 / 9.0],
+            ]],
+        );
+
+        let r = x.trace().avg_pool2d_exclude_pad::<3, 2, 1>();
+        assert_close(&r.array(), &[[[3.0, 4.0]]]);
+        let g = r.sum().backward();
+        assert_close(
+            &g.get(&x).array(),
+            &[[[0.25, 0.5, 0.25], [0.25, 0.5, 0.25]]],
+        );
+
+        // the extra ceil mode row only covers the last row of x and the bottom padding
+        let r = x.trace().avg_pool2d_exclude_pad_ceil::<3, 2, 1>();
+        assert_close(&r.array(), &[[[3.0, 4.0], [4.5, 5.5]]]);
+        let g = r.sum().backward();
+        assert_close(
+            &g.get(&x).array(),
+            &[[[0.25, 0.5, 0.25], [0.75, 1.5, 0.75]]],
+        );
+    }
+
+    #[test]
+    fn test_avg_pool2d_exclude_pad_only_padding() {
+        let dev: TestDevice = Default::default();
+        let x: Tensor<_, TestDtype, _> = dev.tensor([[[1.0, 2.0], [3.0, 4.0]]]);
+
+        // all windows except the center one only cover padding
+        let r = x.trace().avg_pool2d_exclude_pad::<2, 2, 2>();
+        assert_close(
+            &r.array(),
+            &[[[0.0, 0.0, 0.0], [0.0, 2.5, 0.0], [0.0, 0.0, 0.0]]],
+        );
+        let g = r.sum().backward();
+        assert_close(&g.get(&x).array(), &[[[0.25; 2]; 2]]);
+    }
+
+    #[test]
+    fn test_pool2d_runtime_spatial_dims() {
+        let dev: TestDevice = Default::default();
+        #[rustfmt::skip]
+        let data = std::vec![
+            1.0, 2.0, 3.0,
+            4.0, -5.0, 6.0,
+            7.0, 8.0, -9.0,
+            0.0, 1.0, 2.0,
+        ];
+        let x: Tensor<(Const<1>, usize, usize), TestDtype, _> =
+            dev.tensor_from_vec(data.clone(), (Const, 4, 3));
+        let r = x.trace().avg_pool2d::<2, 1, 0>();
+        assert_eq!(r.shape(), &(Const::<1>, 3, 2));
+        assert_eq!(r.as_vec(), [0.5, 1.5, 3.5, 0.0, 4.0, 0.5]);
+        let g = r.sum().backward();
+        #[rustfmt::skip]
+        assert_eq!(
+            g.get(&x).as_vec(),
+            [
+                0.25, 0.5, 0.25,
+                0.5, 1.0, 0.5,
+                0.5, 1.0, 0.5,
+                0.25, 0.5, 0.25,
+            ],
+        );
+
+        // batched runtime dims give the same result as the compile time version
+        let x: Tensor<(usize, Const<1>, usize, usize), TestDtype, _> =
+            dev.tensor_from_vec([data.clone(), data.clone()].concat(), (2, Const, 4, 3));
+        let y: Tensor<Rank4<2, 1, 4, 3>, TestDtype, _> = dev.tensor([data.clone(), data].concat());
+        let r = x.trace().max_pool2d_ceil::<2, 2, 0>();
+        assert_eq!(r.shape(), &(2, Const::<1>, 2, 2));
+        let r2 = y.trace().max_pool2d_ceil::<2, 2, 0>();
+        assert_eq!(r.as_vec(), r2.as_vec());
+        let g = r.exp().sum().backward();
+        let g2 = r2.exp().sum().backward();
+        assert_eq!(g.get(&x).as_vec(), g2.get(&y).as_vec());
+    }
+
+    #[test]
+    fn test_pool2d_runtime_dims_smaller_than_kernel() {
+        let dev: TestDevice = Default::default();
+        let x: Tensor<(Const<2>, usize, usize), TestDtype, _> =
+            dev.sample_normal_like(&(Const, 2, 5));
+        let r = x.trace().try_max_pool2d::<3, 1, 0>().unwrap();
+        assert_eq!(r.shape(), &(Const::<2>, 0, 3));
+        let g = r.sum().backward();
+        assert_eq!(g.get(&x).as_vec(), [0.0; 20]);
+
+        // padding makes the image big enough again
+        let r = x.trace().try_avg_pool2d::<3, 1, 1>().unwrap();
+        assert_eq!(r.shape(), &(Const::<2>, 2, 5));
+
+        let x: Tensor<(usize, Const<1>, usize, usize), TestDtype, _> =
+            dev.sample_normal_like(&(3, Const, 4, 1));
+        let r = x.trace().try_min_pool2d_ceil::<2, 2, 0>().unwrap();
+        assert_eq!(r.shape(), &(3, Const::<1>, 2, 0));
+    }
+
+    #[test]
+    fn test_pool2d_rect_kernel() {
+        let dev: TestDevice = Default::default();
+        let x: Tensor<_, TestDtype, _> = dev.tensor([[
+            [1.0, 2.0, 3.0],
+            [4.0, -5.0, 6.0],
+            [7.0, 8.0, -9.0],
+            [0.0, 1.0, 2.0],
+        ]]);
+
+        let r = x.trace().avg_pool2d_rect::<2, 1, 2, 1, 0, 0>();
+        assert_close(&r.array(), &[[[2.5, -1.5, 4.5], [3.5, 4.5, -3.5]]]);
+        let g = r.exp().sum().backward();
+        let e = |v: TestDtype| v.exp() / 2.0;
+        assert_close(
+            &g.get(&x).array(),
+            &[[
+                [e(2.5), e(-1.5), e(4.5)],
+                [e(2.5), e(-1.5), e(4.5)],
+                [e(3.5), e(4.5), e(-3.5)],
+                [e(3.5), e(4.5), e(-3.5)],
+            ]],
+        );
+
+        let r = x.trace().max_pool2d_rect::<2, 1, 2, 1, 0, 0>();
+        assert_close(&r.array(), &[[[4.0, 2.0, 6.0], [7.0, 8.0, 2.0]]]);
+        let g = r.sum().backward();
+        assert_close(
+            &g.get(&x).array(),
+            &[[
+                [0.0, 1.0, 0.0],
+                [1.0, 0.0, 1.0],
+                [1.0, 1.0, 0.0],
+                [0.0, 0.0, 1.0],
+            ]],
+        );
+
+        let x: Tensor<Rank4<2, 3, 5, 6>, TestDtype, _> = dev.sample_normal();
+        let _: Tensor<Rank4<2, 3, 3, 6>, TestDtype, _> =
+            x.clone().min_pool2d_rect::<3, 1, 2, 1, 1, 0>();
+        let _: Tensor<Rank4<2, 3, 2, 2>, TestDtype, _> =
+            x.clone().avg_pool2d_rect::<2, 3, 2, 3, 0, 0>();
+        // the square methods are the same as equal height and width parameters
+        let a = x.clone().avg_pool2d::<2, 2, 1>();
+        let b = x.avg_pool2d_rect::<2, 2, 2, 2, 1, 1>();
+        assert_close(&a.array(), &b.array());
+    }
+
     #[test]
     fn test_pool2d_4d_avg2d() {
         let dev = TestDevice::seed_from_u64(234);
@@ -1,17 +1,45 @@
 #include "cuda_utils.cuh"
 
 struct Pool2dOp {
-    size_t kernel;
-    size_t stride;
-    size_t padding;
+    size_t kernel_h;
+    size_t kernel_w;
+    size_t stride_h;
+    size_t stride_w;
+    size_t padding_h;
+    size_t padding_w;
     size_t batch;
     size_t chan;
     size_t h_in;
     size_t h_out;
     size_t w_in;
     size_t w_out;
+    bool count_include_pad;
 };
 
+// what to divide the sum of the window at (oh, ow) by for average pooling.
+// with count_include_pad this is the number of elements of the padded input covered
+// by the window, which is only less than kernel_h * kernel_w for windows that hang off
+// the end of the padded input in ceil mode. otherwise it's the number of real elements,
+// clamped to 1 for windows that only cover padding.
+__device__ size_t avg_divisor(const Pool2dOp op, const size_t oh, const size_t ow) {
+    const size_t h0 = oh * op.stride_h;
+    const size_t w0 = ow * op.stride_w;
+    size_t h1 = h0 + op.kernel_h;
+    if (h1 > op.h_in + 2 * op.padding_h) { h1 = op.h_in + 2 * op.padding_h; }
+    size_t w1 = w0 + op.kernel_w;
+    if (w1 > op.w_in + 2 * op.padding_w) { w1 = op.w_in + 2 * op.padding_w; }
+    if (op.count_include_pad) {
+        return (h1 - h0) * (w1 - w0);
+    }
+    const size_t h_start = h0 > op.padding_h ? h0 : op.padding_h;
+    const size_t h_end = h1 < op.h_in + op.padding_h ? h1 : op.h_in + op.padding_h;
+    const size_t w_start = w0 > op.padding_w ? w0 : op.padding_w;
+    const size_t w_end = w1 < op.w_in + op.padding_w ? w1 : op.w_in + op.padding_w;
+    const size_t h = h_end > h_start ? h_end - h_start : 0;
+    const size_t w = w_end > w_start ? w_end - w_start : 0;
+    return h * w > 0 ? h * w : 1;
+}
+
 template<typename T>
 __device__ void avg_pool2d_fwd(
     const Pool2dOp op,
@@ -37,15 +65,15 @@ __device__ void avg_pool2d_fwd(
     idx /= op.batch;
     
     T tmp = 0.0;
-    for(size_t k1 = 0; k1 < op.kernel; k1++) {
-        for (size_t k2 = 0; k2 < op.kernel; k2++) {
-            const size_t y_plus_p = oh * op.stride + k1;
-            if (y_plus_p < op.padding) { continue; }
-            const size_t y = y_plus_p - op.padding;
+    for(size_t k1 = 0; k1 < op.kernel_h; k1++) {
+        for (size_t k2 = 0; k2 < op.kernel_w; k2++) {
+            const size_t y_plus_p = oh * op.stride_h + k1;
+            if (y_plus_p < op.padding_h) { continue; }
+            const size_t y = y_plus_p - op.padding_h;
             if (y >= op.h_in) { continue; }
-            const size_t x_plus_p = ow * op.stride + k2;
-            if (x_plus_p < op.padding) { continue; }
-            const size_t x = x_plus_p - op.padding;
+            const size_t x_plus_p = ow * op.stride_w + k2;
+            if (x_plus_p < op.padding_w) { continue; }
+            const size_t x = x_plus_p - op.padding_w;
             if (x >= op.w_in) { continue; }
 
             auto inp_i = b * inp_strides[0] + c * inp_strides[1] + y * inp_strides[2] + x * inp_strides[3];
@@ -53,7 +81,7 @@ __device__ void avg_pool2d_fwd(
         }
     }
 
-    tmp /= static_cast<T>(op.kernel * op.kernel);
+    tmp /= static_cast<T>(avg_divisor(op, oh, ow));
     out[i] = tmp;
 }
 
@@ -84,28 +112,28 @@ __device__ void avg_pool2d_bwd(
     idx /= op.batch;
 
     T tmp = 0.0;
-    for(size_t k1 = 0; k1 < op.kernel; k1++) {
-        for (size_t k2 = 0; k2 < op.kernel; k2++) {
-            size_t oh = y + op.padding;
+    for(size_t k1 = 0; k1 < op.kernel_h; k1++) {
+        for (size_t k2 = 0; k2 < op.kernel_w; k2++) {
+            size_t oh = y + op.padding_h;
             if (oh < k1) { continue; }
             oh -= k1;
-            if (oh % op.stride != 0) { continue; }
-            oh /= op.stride;
+            if (oh % op.stride_h != 0) { continue; }
+            oh /= op.stride_h;
             if (oh >= op.h_out) { continue; }
 
-            size_t ow = x + op.padding;
+            size_t ow = x + op.padding_w;
             if (ow < k2) { continue; }
             ow -= k2;
-            if (ow % op.stride != 0) { continue; }
-            ow /= op.stride;
+            if (ow % op.stride_w != 0) { continue; }
+            ow /= op.stride_w;
             if (ow >= op.w_out) { continue; }
 
             auto out_i = b * out_strides[0] + c * out_strides[1] + oh * out_strides[2] + ow * out_strides[3];
-            tmp += grad_out[out_i];
+            tmp += grad_out[out_i] / static_cast<T>(avg_divisor(op, oh, ow));
         }
     }
 
-    grad_inp[i] += tmp / static_cast<T>(op.kernel * op.kernel);
+    grad_inp[i] += tmp;
 }
 
 template<typename T>
@@ -133,15 +161,15 @@ __device__ void max_pool2d_fwd(
     idx /= op.batch;
 
     T tmp = -INFINITY;
-    for(size_t k1 = 0; k1 < op.kernel; k1++) {
-        for (size_t k2 = 0; k2 < op.kernel; k2++) {
-            const size_t y_plus_p = oh * op.stride + k1;
-            if (y_plus_p < op.padding) { continue; }
-            const size_t y = y_plus_p - op.padding;
+    for(size_t k1 = 0; k1 < op.kernel_h; k1++) {
+        for (size_t k2 = 0; k2 < op.kernel_w; k2++) {
+            const size_t y_plus_p = oh * op.stride_h + k1;
+            if (y_plus_p < op.padding_h) { continue; }
+            const size_t y = y_plus_p - op.padding_h;
             if (y >= op.h_in) { continue; }
-            const size_t x_plus_p = ow * op.stride + k2;
-            if (x_plus_p < op.padding) { continue; }
-            const size_t x = x_plus_p - op.padding;
+            const size_t x_plus_p = ow * op.stride_w + k2;
+            if (x_plus_p < op.padding_w) { continue; }
+            const size_t x = x_plus_p - op.padding_w;
             if (x >= op.w_in) { continue; }
 
             auto inp_i = b * inp_strides[0] + c * inp_strides[1] + y * inp_strides[2] + x * inp_strides[3];
@@ -181,20 +209,20 @@ __device__ void max_pool2d_bwd(
     const T inp_v = inp[i];
 
     T tmp = 0.0;
-    for(size_t k1 = 0; k1 < op.kernel; k1++) {
-        for (size_t k2 = 0; k2 < op.kernel; k2++) {
-            size_t oh = y + op.padding;
+    for(size_t k1 = 0; k1 < op.kernel_h; k1++) {
+        for (size_t k2 = 0; k2 < op.kernel_w; k2++) {
+            size_t oh = y + op.padding_h;
             if (oh < k1) { continue; }
             oh -= k1;
-            if (oh % op.stride != 0) { continue; }
-            oh /= op.stride;
+            if (oh % op.stride_h != 0) { continue; }
+            oh /= op.stride_h;
             if (oh >= op.h_out) { continue; }
 
-            size_t ow = x + op.padding;
+            size_t ow = x + op.padding_w;
             if (ow < k2) { continue; }
             ow -= k2;
-            if (ow % op.stride != 0) { continue; }
-            ow /= op.stride;
+            if (ow % op.stride_w != 0) { continue; }
+            ow /= op.stride_w;
             if (ow >= op.w_out) { continue; }
 
             auto out_i = b * out_strides[0] + c * out_strides[1] + oh * out_strides[2] + ow * out_strides[3];
@@ -233,15 +261,15 @@ __device__ void min_pool2d_fwd(
     idx /= op.batch;
 
     T tmp = INFINITY;
-    for(size_t k1 = 0; k1 < op.kernel; k1++) {
-        for (size_t k2 = 0; k2 < op.kernel; k2++) {
-            const size_t y_plus_p = oh * op.stride + k1;
-            if (y_plus_p < op.padding) { continue; }
-            const size_t y = y_plus_p - op.padding;
+    for(size_t k1 = 0; k1 < op.kernel_h; k1++) {
+        for (size_t k2 = 0; k2 < op.kernel_w; k2++) {
+            const size_t y_plus_p = oh * op.stride_h + k1;
+            if (y_plus_p < op.padding_h) { continue; }
+            const size_t y = y_plus_p - op.padding_h;
             if (y >= op.h_in) { continue; }
-            const size_t x_plus_p = ow * op.stride + k2;
-            if (x_plus_p < op.padding) { continue; }
-            const size_t x = x_plus_p - op.padding;
+            const size_t x_plus_p = ow * op.stride_w + k2;
+            if (x_plus_p < op.padding_w) { continue; }
+            const size_t x = x_plus_p - op.padding_w;
             if (x >= op.w_in) { continue; }
 
             auto inp_i = b * inp_strides[0] + c * inp_strides[1] + y * inp_strides[2] + x * inp_strides[3];
@@ -281,20 +309,20 @@ __device__ void min_pool2d_bwd(
     const T inp_v = inp[i];
 
     T tmp = 0.0;
-    for(size_t k1 = 0; k1 < op.kernel; k1++) {
-        for (size_t k2 = 0; k2 < op.kernel; k2++) {
-            size_t oh = y + op.padding;
+    for(size_t k1 = 0; k1 < op.kernel_h; k1++) {
+        for (size_t k2 = 0; k2 < op.kernel_w; k2++) {
+            size_t oh = y + op.padding_h;
             if (oh < k1) { continue; }
             oh -= k1;
-            if (oh % op.stride != 0) { continue; }
-            oh /= op.stride;
+            if (oh % op.stride_h != 0) { continue; }
+            oh /= op.stride_h;
             if (oh >= op.h_out) { continue; }
 
-            size_t ow = x + op.padding;
+            size_t ow = x + op.padding_w;
             if (ow < k2) { continue; }
             ow -= k2;
-            if (ow % op.stride != 0) { continue; }
-            ow /= op.stride;
+            if (ow % op.stride_w != 0) { continue; }
+            ow /= op.stride_w;
             if (ow >= op.w_out) { continue; }
 
             auto out_i = b * out_strides[0] + c * out_strides[1] + oh * out_strides[2] + ow * out_strides[3];
@@ -0,0 +1,27 @@
+#include "binary_op_macros.cuh"
+
+struct BinaryPowKernelOp {};
+
+template<typename T>
+__device__ T op_dfdx(T x, T y) {
+    // x^y is constant when y is 0, even where x^(y - 1) is infinite
+    return (y == (T)0.0) ? (T)0.0 : y * powg(x, y - (T)1.0);
+}
+
+template<typename T>
+__device__ T op_dfdy(T x, T y) {
+    // ln(x) is not defined for x <= 0
+    return (x > (T)0.0) ? powg(x, y) * logg(x) : (T)0.0;
+}
+
+BINARY_OP(float, binary_pow_fwd_f32, binary_pow_bwd_f32, BinaryPowKernelOp,
+    powf(x, y),
+    op_dfdx(x, y),
+    op_dfdy(x, y)
+)
+
+BINARY_OP(double, binary_pow_fwd_f64, binary_pow_bwd_f64, BinaryPowKernelOp,
+    pow(x, y),
+    op_dfdx(x, y),
+    op_dfdy(x, y)
+)
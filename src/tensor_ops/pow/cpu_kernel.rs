@@ -1,4 +1,4 @@
-use crate::tensor_ops::cpu_kernels::UnaryDerivative;
+use crate::tensor_ops::cpu_kernels::{BinaryDerivative, UnaryDerivative};
 
 impl<F: num_traits::Float> UnaryDerivative<F> for super::PowiKernelOp {
     #[inline(always)]
@@ -21,3 +21,27 @@ impl<F: num_traits::Float> UnaryDerivative<F> for super::PowfKernelOp<F> {
         self.0 * x.powf(self.0 - F::one())
     }
 }
+
+impl<F: num_traits::Float> BinaryDerivative<F> for super::BinaryPowKernelOp {
+    #[inline(always)]
+    fn f(&self, x: &F, y: &F) -> F {
+        x.powf(*y)
+    }
+    #[inline(always)]
+    fn dfdx(&self, x: &F, y: &F) -> F {
+        // x^y is constant when y is 0, even where x^(y - 1) is infinite
+        if *y == F::zero() {
+            F::zero()
+        } else {
+            *y * x.powf(*y - F::one())
+        }
+    }
+    #[inline(always)]
+    fn dfdy(&self, x: &F, y: &F) -> F {
+        if *x > F::zero() {
+            x.powf(*y) * x.ln()
+        } else {
+            F::zero()
+        }
+    }
+}
@@ -1,17 +1,36 @@
-use super::PowfKernelOp;
+use super::{BinaryPowKernelOp, PowfKernelOp};
 use crate::{
     shapes::*,
     tensor::{Cuda, Tensor},
-    tensor_ops::{cuda_kernels::cuda_unary, ops::UnaryKernel},
+    tensor_ops::{
+        cuda_kernels::{cuda_binary, cuda_unary},
+        ops::UnaryKernel,
+    },
 };
 
 unsafe impl cudarc::driver::DeviceRepr for super::PowfKernelOp<f32> {}
 unsafe impl cudarc::driver::DeviceRepr for super::PowfKernelOp<f64> {}
+unsafe impl cudarc::driver::DeviceRepr for super::BinaryPowKernelOp {}
 
 const PTX: &str = include_str!(concat!(env!("OUT_DIR"), "/pow.ptx"));
+const BINARY_PTX: &str = include_str!(concat!(env!("OUT_DIR"), "/binary_pow.ptx"));
 
 cuda_unary!(PowfKernelOp<f32>, f32, PTX, "pow_fwd_f32", "pow_bwd_f32");
 cuda_unary!(PowfKernelOp<f64>, f64, PTX, "pow_fwd_f64", "pow_bwd_f64");
+cuda_binary!(
+    BinaryPowKernelOp,
+    f32,
+    BINARY_PTX,
+    "binary_pow_fwd_f32",
+    "binary_pow_bwd_f32"
+);
+cuda_binary!(
+    BinaryPowKernelOp,
+    f64,
+    BINARY_PTX,
+    "binary_pow_fwd_f64",
+    "binary_pow_bwd_f64"
+);
 
 impl<E: Dtype> UnaryKernel<super::PowiKernelOp, E> for Cuda
 where
@@ -3,8 +3,12 @@ mod cpu_kernel;
 #[cfg(feature = "cuda")]
 mod cuda_kernel;
 
-use super::ops::{try_unary_op, UnaryKernel};
-use crate::{gradients::Tape, shapes::*, tensor::Tensor};
+use super::ops::{try_binary_op, try_unary_op, BinaryKernel, UnaryKernel};
+use crate::{
+    gradients::{Merge, Tape},
+    shapes::*,
+    tensor::Tensor,
+};
 
 #[repr(C)]
 #[derive(Debug, Clone, Copy)]
@@ -14,6 +18,10 @@ pub struct PowiKernelOp(i32);
 #[derive(Debug, Clone, Copy)]
 pub struct PowfKernelOp<E>(E);
 
+#[repr(C)]
+#[derive(Debug, Default, Clone, Copy)]
+pub struct BinaryPowKernelOp;
+
 /// Raises to a float power; `t^i`.
 /// ```rust
 /// # use dfdx::prelude::*;
@@ -64,6 +72,53 @@ impl<S: Shape, E: Dtype, D: UnaryKernel<PowiKernelOp, E>, T: Tape<E, D>> Tensor<
     }
 }
 
+/// Raises each element of `lhs` to the power of the corresponding element of `exponent`;
+/// `lhs^exponent`.
+///
+/// The gradient of `lhs` is `exponent * lhs^(exponent - 1)`, and the gradient of `exponent`
+/// is `lhs^exponent * ln(lhs)`. Since `ln(lhs)` is not defined for `lhs <= 0`, the gradient
+/// of `exponent` is `0` there.
+///
+/// Use [Tensor::broadcast_pow_tensor] for an `exponent` with fewer dimensions than `lhs`.
+///
+/// **Pytorch equivalent**: `torch.pow(lhs, exponent)`
+///
+/// Example:
+/// ```rust
+/// # use dfdx::prelude::*;
+/// # let dev: Cpu = Default::default();
+/// let a = dev.tensor([2.0, 3.0, -2.0, 4.0]);
+/// let e = dev.tensor([3.0, 2.0, 2.0, 0.5]);
+/// let r = a.pow_tensor(e);
+/// assert_eq!(r.array(), [8.0, 9.0, 4.0, 2.0]);
+/// ```
+pub fn pow_tensor<S: Shape, E: Dtype, D, T: Tape<E, D> + Merge<R>, R: Default>(
+    lhs: Tensor<S, E, D, T>,
+    exponent: Tensor<S, E, D, R>,
+) -> Tensor<S, E, D, T>
+where
+    D: BinaryKernel<BinaryPowKernelOp, E>,
+{
+    lhs.pow_tensor(exponent)
+}
+
+impl<S: Shape, E: Dtype, D: BinaryKernel<BinaryPowKernelOp, E>, T: Tape<E, D>> Tensor<S, E, D, T> {
+    /// See [pow_tensor]
+    pub fn pow_tensor<R: Default>(self, exponent: Tensor<S, E, D, R>) -> Self
+    where
+        T: Merge<R>,
+    {
+        self.try_pow_tensor(exponent).unwrap()
+    }
+    /// See [pow_tensor]
+    pub fn try_pow_tensor<R: Default>(self, exponent: Tensor<S, E, D, R>) -> Result<Self, D::Err>
+    where
+        T: Merge<R>,
+    {
+        try_binary_op(BinaryPowKernelOp, self, exponent)
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use crate::{tensor::*, tensor_ops::*, tests::*};
@@ -132,4 +187,55 @@ mod tests {
             &[-0.1875, -3., TestDtype::NEG_INFINITY, -3., -0.1875],
         );
     }
+
+    #[test]
+    fn test_pow_tensor() {
+        let dev: TestDevice = Default::default();
+        let a: Tensor<_, TestDtype, _> = dev.tensor([2.0, 3.0, 4.0]);
+        let e: Tensor<_, TestDtype, _> = dev.tensor([3.0, 2.0, 0.5]);
+        let r = a.trace().pow_tensor(e.trace());
+        assert_close(&r.array(), &[8.0, 9.0, 2.0]);
+        let g = r.sum().backward();
+        assert_close(&g.get(&a).array(), &[12.0, 6.0, 0.25]);
+        assert_close(&g.get(&e).array(), &[5.5451775, 9.887511, 2.7725887]);
+    }
+
+    #[test]
+    fn test_pow_tensor_non_positive_base() {
+        let dev: TestDevice = Default::default();
+        let a: Tensor<_, TestDtype, _> = dev.tensor([-2.0, 0.0, -1.0]);
+        let e: Tensor<_, TestDtype, _> = dev.tensor([2.0, 2.0, 3.0]);
+        let r = a.trace().pow_tensor(e.trace());
+        assert_close(&r.array(), &[4.0, 0.0, -1.0]);
+        let g = r.sum().backward();
+        assert_close(&g.get(&a).array(), &[-4.0, 0.0, 3.0]);
+        assert_eq!(g.get(&e).array(), [0.0; 3]);
+    }
+
+    #[test]
+    fn test_pow_tensor_zero_exponent() {
+        let dev: TestDevice = Default::default();
+        let a: Tensor<_, TestDtype, _> = dev.tensor([0.0, 2.0, -3.0]);
+        let e: Tensor<_, TestDtype, _> = dev.tensor([0.0; 3]);
+        let r = a.trace().pow_tensor(e.trace());
+        assert_eq!(r.array(), [1.0; 3]);
+        let g = r.sum().backward();
+        assert_eq!(g.get(&a).array(), [0.0; 3]);
+        assert_close(&g.get(&e).array(), &[0.0, TestDtype::ln(2.0), 0.0]);
+    }
+
+    #[test]
+    fn test_broadcast_pow_tensor() {
+        let dev: TestDevice = Default::default();
+        let a: Tensor<_, TestDtype, _> = dev.tensor([[1.0, 2.0, 3.0], [2.0, 2.0, 4.0]]);
+        let e: Tensor<_, TestDtype, _> = dev.tensor([2.0, 3.0, 0.5]);
+        let r = a.trace().broadcast_pow_tensor(e.trace());
+        assert_close(&r.array(), &[[1.0, 8.0, 1.7320508], [4.0, 8.0, 2.0]]);
+        let g = r.sum().backward();
+        assert_close(
+            &g.get(&a).array(),
+            &[[2.0, 12.0, 0.28867513], [4.0, 12.0, 0.25]],
+        );
+        assert_close(&g.get(&e).array(), &[2.7725887, 11.090355, 4.675441]);
+    }
 }
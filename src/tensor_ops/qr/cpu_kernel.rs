@@ -0,0 +1,135 @@
+use crate::{
+    shapes::{Dim, Dtype, Shape},
+    tensor::{Cpu, Tensor, ZerosTensor},
+    tensor_ops::triangular_solve::cpu_kernel::substitute,
+};
+
+impl<E: num_traits::Float + Dtype> super::QrKernel<E> for Cpu {
+    fn forward<M: Dim, N: Dim>(
+        &self,
+        inp: &Tensor<(M, N), E, Self>,
+    ) -> Result<(Tensor<(M, N), E, Self>, Tensor<(N, N), E, Self>), Self::Err> {
+        let (m, n) = (inp.shape.0.size(), inp.shape.1.size());
+        let [s0, s1] = inp.strides;
+
+        // reduce a copy of the input to upper triangular with householder reflections
+        let mut a = std::vec![E::zero(); m * n];
+        for i in 0..m {
+            for j in 0..n {
+                a[i * n + j] = inp.data[i * s0 + j * s1];
+            }
+        }
+        let mut reflectors = std::vec::Vec::with_capacity(n);
+        for k in 0..n {
+            let mut v: std::vec::Vec<E> = (k..m).map(|i| a[i * n + k]).collect();
+            let norm = v.iter().fold(E::zero(), |s, &x| s + x * x).sqrt();
+            let alpha = if v[0] > E::zero() { -norm } else { norm };
+            v[0] -= alpha;
+            let v_norm = v.iter().fold(E::zero(), |s, &x| s + x * x).sqrt();
+            if v_norm > E::zero() {
+                v.iter_mut().for_each(|x| *x /= v_norm);
+                // a[k.., k..] -= 2 v (v^T a[k.., k..])
+                for j in k..n {
+                    let mut dot = E::zero();
+                    for (vi, i) in v.iter().zip(k..m) {
+                        dot += *vi * a[i * n + j];
+                    }
+                    for (vi, i) in v.iter().zip(k..m) {
+                        a[i * n + j] -= (dot + dot) * *vi;
+                    }
+                }
+            }
+            reflectors.push(v);
+        }
+
+        // q is the product of the reflections applied to the first n columns of the identity
+        let mut q = std::vec![E::zero(); m * n];
+        for k in 0..n {
+            q[k * n + k] = E::one();
+        }
+        for (k, v) in reflectors.iter().enumerate().rev() {
+            for j in 0..n {
+                let mut dot = E::zero();
+                for (vi, i) in v.iter().zip(k..m) {
+                    dot += *vi * q[i * n + j];
+                }
+                for (vi, i) in v.iter().zip(k..m) {
+                    q[i * n + j] -= (dot + dot) * *vi;
+                }
+            }
+        }
+
+        // flip signs so r has a non-negative diagonal
+        let mut q_out = self.try_zeros_like(&inp.shape)?;
+        let mut r_out = self.try_zeros_like(&(inp.shape.1, inp.shape.1))?;
+        let q_buf = std::sync::Arc::make_mut(&mut q_out.data);
+        let r_buf = std::sync::Arc::make_mut(&mut r_out.data);
+        for k in 0..n {
+            let sign = if a[k * n + k] < E::zero() {
+                -E::one()
+            } else {
+                E::one()
+            };
+            for j in k..n {
+                r_buf[k * n + j] = sign * a[k * n + j];
+            }
+            for i in 0..m {
+                q_buf[i * n + k] = sign * q[i * n + k];
+            }
+        }
+        Ok((q_out, r_out))
+    }
+
+    fn backward<M: Dim, N: Dim>(
+        &self,
+        inp: &Tensor<(M, N), E, Self>,
+        grad_inp: &mut Self::Vec<E>,
+        q: &Tensor<(M, N), E, Self>,
+        grad_q: &Self::Vec<E>,
+        r: &Tensor<(N, N), E, Self>,
+        grad_r: &Self::Vec<E>,
+    ) -> Result<(), Self::Err> {
+        let (m, n) = (inp.shape.0.size(), inp.shape.1.size());
+        let [s0, s1] = inp.strides;
+        debug_assert_eq!(q.shape.strides(), q.strides);
+        debug_assert_eq!(r.shape.strides(), r.strides);
+        let (q, r) = (q.data.as_ref(), r.data.as_ref());
+
+        // w = r * grad_r^T - grad_q^T * q
+        let mut w = std::vec![E::zero(); n * n];
+        for i in 0..n {
+            for j in 0..n {
+                let mut sum = E::zero();
+                for k in 0..n {
+                    sum += r[i * n + k] * grad_r[j * n + k];
+                }
+                for k in 0..m {
+                    sum -= grad_q[k * n + i] * q[k * n + j];
+                }
+                w[i * n + j] = sum;
+            }
+        }
+
+        // b = grad_q + q * copyltu(w), where copyltu mirrors the lower triangle onto the upper
+        let mut b = grad_q.clone();
+        for i in 0..m {
+            for j in 0..n {
+                let mut sum = E::zero();
+                for k in 0..n {
+                    let w_kj = if k >= j { w[k * n + j] } else { w[j * n + k] };
+                    sum += q[i * n + k] * w_kj;
+                }
+                b[i * n + j] += sum;
+            }
+        }
+
+        // grad_inp = b * r^-T, i.e. grad_inp^T = r^-1 * b^T
+        let g_t = substitute(n, m, |i, j| r[i * n + j], |i, c| b[c * n + i], true);
+        for i in 0..m {
+            for j in 0..n {
+                grad_inp[i * s0 + j * s1] += g_t[j * m + i];
+            }
+        }
+        Ok(())
+    }
+}
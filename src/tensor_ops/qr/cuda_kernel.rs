@@ -0,0 +1,52 @@
+use crate::{
+    shapes::{Dim, Dtype},
+    tensor::{Cpu, Cuda, Tensor, TensorFromVec},
+    tensor_ops::triangular_solve::cuda_kernel::to_host,
+};
+
+use super::QrKernel;
+
+/// The householder reflections are applied one after another, so this runs on the
+/// host with the [Cpu] implementation and copies the results back to the device.
+impl<E: Dtype> QrKernel<E> for Cuda
+where
+    Cpu: QrKernel<E>,
+{
+    fn forward<M: Dim, N: Dim>(
+        &self,
+        inp: &Tensor<(M, N), E, Self>,
+    ) -> Result<(Tensor<(M, N), E, Self>, Tensor<(N, N), E, Self>), Self::Err> {
+        let inp = to_host(self, inp)?;
+        let (q, r) = self.cpu.forward(&inp)?;
+        let q = self.try_tensor_from_vec(q.data.as_ref().clone(), q.shape)?;
+        let r = self.try_tensor_from_vec(r.data.as_ref().clone(), r.shape)?;
+        Ok((q, r))
+    }
+
+    fn backward<M: Dim, N: Dim>(
+        &self,
+        inp: &Tensor<(M, N), E, Self>,
+        grad_inp: &mut Self::Vec<E>,
+        q: &Tensor<(M, N), E, Self>,
+        grad_q: &Self::Vec<E>,
+        r: &Tensor<(N, N), E, Self>,
+        grad_r: &Self::Vec<E>,
+    ) -> Result<(), Self::Err> {
+        let inp_host = to_host(self, inp)?;
+        let q_host = to_host(self, q)?;
+        let r_host = to_host(self, r)?;
+        let mut grad_inp_host = self.dev.dtoh_sync_copy(grad_inp)?;
+        let grad_q_host = self.dev.dtoh_sync_copy(grad_q)?;
+        let grad_r_host = self.dev.dtoh_sync_copy(grad_r)?;
+        self.cpu.backward(
+            &inp_host,
+            &mut grad_inp_host,
+            &q_host,
+            &grad_q_host,
+            &r_host,
+            &grad_r_host,
+        )?;
+        self.dev.htod_sync_copy_into(&grad_inp_host, grad_inp)?;
+        Ok(())
+    }
+}
@@ -0,0 +1,183 @@
+#![allow(clippy::type_complexity)]
+
+mod cpu_kernel;
+
+#[cfg(feature = "cuda")]
+mod cuda_kernel;
+
+use crate::{
+    gradients::Tape,
+    shapes::{Dim, Dtype},
+    tensor::{DeviceStorage, PutTape, SplitTape, Tensor},
+};
+
+pub trait QrKernel<E: Dtype>: DeviceStorage {
+    fn forward<M: Dim, N: Dim>(
+        &self,
+        inp: &Tensor<(M, N), E, Self>,
+    ) -> Result<(Tensor<(M, N), E, Self>, Tensor<(N, N), E, Self>), Self::Err>;
+
+    #[allow(clippy::too_many_arguments)]
+    fn backward<M: Dim, N: Dim>(
+        &self,
+        inp: &Tensor<(M, N), E, Self>,
+        grad_inp: &mut Self::Vec<E>,
+        q: &Tensor<(M, N), E, Self>,
+        grad_q: &Self::Vec<E>,
+        r: &Tensor<(N, N), E, Self>,
+        grad_r: &Self::Vec<E>,
+    ) -> Result<(), Self::Err>;
+}
+
+/// Reduced QR decomposition of a matrix `a` with at least as many rows as columns:
+/// `a = q * r`, where `q` has orthonormal columns and `r` is upper triangular with a
+/// non-negative diagonal. Computed with Householder reflections.
+///
+/// `q` and `r` share the backward operation, so gradients reach `a` from either of them.
+///
+/// **Pytorch equivalent**: `torch.linalg.qr(a, mode="reduced")`
+///
+/// Example:
+/// ```rust
+/// # use dfdx::prelude::*;
+/// # let dev: Cpu = Default::default();
+/// let a = dev.tensor([[3.0, 1.0], [4.0, 2.0], [0.0, 2.0]]);
+/// let (q, r) = qr(a);
+/// assert_eq!(r.array()[1][0], 0.0);
+/// let _: Tensor<Rank2<3, 2>, f64, _> = q;
+/// ```
+pub fn qr<M: Dim, N: Dim, E: Dtype, D: QrKernel<E>, T: Tape<E, D>>(
+    a: Tensor<(M, N), E, D, T>,
+) -> (Tensor<(M, N), E, D, T>, Tensor<(N, N), E, D, T>) {
+    a.qr()
+}
+
+impl<M: Dim, N: Dim, E: Dtype, D: QrKernel<E>, T: Tape<E, D>> Tensor<(M, N), E, D, T> {
+    /// See [qr]
+    pub fn qr(self) -> (Tensor<(M, N), E, D, T>, Tensor<(N, N), E, D, T>) {
+        self.try_qr().unwrap()
+    }
+
+    /// See [qr]
+    pub fn try_qr(self) -> Result<(Tensor<(M, N), E, D, T>, Tensor<(N, N), E, D, T>), D::Err> {
+        let (m, n) = (self.shape.0.size(), self.shape.1.size());
+        assert!(m >= n, "qr requires at least as many rows as columns");
+        let (inp, mut tape) = self.split_tape();
+        let (q, r) = inp.device.forward(&inp)?;
+        let phantom_q = q.clone();
+        let phantom_r = r.clone();
+        tape.try_alloc_grad(&inp)?;
+        tape.try_alloc_grad(&q)?;
+        tape.try_alloc_grad(&r)?;
+        tape.add_reusable_backward_op(move |grads| {
+            let (grad_inp, grad_q, grad_r) = grads.mut_and_refs(&inp, &phantom_q, &phantom_r);
+            inp.device
+                .backward(&inp, grad_inp, &phantom_q, grad_q, &phantom_r, grad_r)
+        });
+        let r_tape = tape.try_duplicate(&r.device)?;
+        Ok((q.put_tape(tape), r.put_tape(r_tape)))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{shapes::*, tensor::*, tensor_ops::*, tests::*};
+
+    #[test]
+    fn test_qr() {
+        let dev: TestDevice = Default::default();
+        let a: Tensor<Rank2<4, 3>, TestDtype, _> = dev.tensor([
+            [2.0, -1.0, 0.5],
+            [1.0, 3.0, -2.0],
+            [0.0, 1.0, 4.0],
+            [-1.0, 2.0, 1.0],
+        ]);
+        let (q, r) = qr(a.clone());
+
+        for (i, row) in r.array().iter().enumerate() {
+            assert!(row[i] > 0.0);
+            assert!(row[..i].iter().all(|&x| x == 0.0));
+        }
+
+        assert_close_with_tolerance(&q.clone().matmul(r).array(), &a.array(), 1e-5);
+
+        let q_t = q.clone().permute::<Rank2<3, 4>, _>();
+        assert_close(
+            &q_t.matmul(q).array(),
+            &[[1.0, 0.0, 0.0], [0.0, 1.0, 0.0], [0.0, 0.0, 1.0]],
+        );
+    }
+
+    #[test]
+    fn test_qr_grads() {
+        let dev: TestDevice = Default::default();
+        let a: Tensor<Rank2<4, 3>, TestDtype, _> = dev.tensor([
+            [2.0, -1.0, 0.5],
+            [1.0, 3.0, -2.0],
+            [0.0, 1.0, 4.0],
+            [-1.0, 2.0, 1.0],
+        ]);
+        let w_q: Tensor<Rank2<4, 3>, TestDtype, _> = dev.tensor([
+            [0.3, -0.7, 0.5],
+            [1.1, 0.2, -0.6],
+            [-0.4, 0.9, 0.8],
+            [0.1, -0.2, 0.6],
+        ]);
+        let w_r: Tensor<Rank2<3, 3>, TestDtype, _> =
+            dev.tensor([[0.5, 1.2, -0.3], [0.7, -0.4, 0.2], [0.9, 0.1, -0.8]]);
+
+        let (q, r) = a.trace().qr();
+        let loss = (q * w_q.clone()).sum::<Rank0, _>() + (r * w_r.clone()).sum::<Rank0, _>();
+        let g = loss.backward();
+        let g_a = g.get(&a).array();
+
+        // central finite differences
+        let loss = |a: std::vec::Vec<TestDtype>| {
+            let (q, r) = dev.tensor_from_vec(a, (Const::<4>, Const::<3>)).qr();
+            (q * w_q.clone()).sum::<Rank0, _>().array()
+                + (r * w_r.clone()).sum::<Rank0, _>().array()
+        };
+        let eps: TestDtype = 1e-2;
+        let a_vec = a.as_vec();
+        for i in 0..4 {
+            for j in 0..3 {
+                let mut plus = a_vec.clone();
+                let mut minus = a_vec.clone();
+                plus[i * 3 + j] += eps;
+                minus[i * 3 + j] -= eps;
+                let expected = (loss(plus) - loss(minus)) / (2.0 * eps);
+                assert_close_with_tolerance(&g_a[i][j], &expected, 1e-3);
+            }
+        }
+    }
+
+    #[test]
+    fn test_qr_grads_through_r() {
+        let dev: TestDevice = Default::default();
+        let a: Tensor<Rank2<3, 2>, TestDtype, _> =
+            dev.tensor([[2.0, -1.0], [1.0, 3.0], [0.5, 1.0]]);
+        let w_r: Tensor<Rank2<2, 2>, TestDtype, _> = dev.tensor([[0.5, 1.2], [0.7, -0.4]]);
+
+        let (_, r) = a.trace().qr();
+        let g = (r * w_r.clone()).sum::<Rank0, _>().backward();
+        let g_a = g.get(&a).array();
+
+        let loss = |a: std::vec::Vec<TestDtype>| {
+            let (_, r) = dev.tensor_from_vec(a, (Const::<3>, Const::<2>)).qr();
+            (r * w_r.clone()).sum::<Rank0, _>().array()
+        };
+        let eps: TestDtype = 1e-2;
+        let a_vec = a.as_vec();
+        for i in 0..3 {
+            for j in 0..2 {
+                let mut plus = a_vec.clone();
+                let mut minus = a_vec.clone();
+                plus[i * 2 + j] += eps;
+                minus[i * 2 + j] -= eps;
+                let expected = (loss(plus) - loss(minus)) / (2.0 * eps);
+                assert_close_with_tolerance(&g_a[i][j], &expected, 1e-3);
+            }
+        }
+    }
+}
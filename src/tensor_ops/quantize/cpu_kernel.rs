@@ -0,0 +1,42 @@
+use crate::{
+    shapes::{Dtype, Shape},
+    tensor::{
+        cpu::{Cpu, LendingIterator},
+        Tensor, ZerosTensor,
+    },
+};
+
+impl<E: Dtype + num_traits::Float> super::QuantizeKernel<E> for Cpu {
+    fn quantize<S: Shape, T>(
+        &self,
+        inp: &Tensor<S, E, Self, T>,
+        scale: E,
+        zero_point: i8,
+    ) -> Result<Tensor<S, i8, Self>, Self::Err> {
+        let mut out: Tensor<S, i8, Self> = self.try_zeros_like(&inp.shape)?;
+        let (min, max) = (E::from(i8::MIN).unwrap(), E::from(i8::MAX).unwrap());
+        let zero_point = E::from(zero_point).unwrap();
+        let mut inp_iter = inp.iter();
+        let mut out_iter = out.iter_mut();
+        while let Some((o, &x)) = out_iter.next().zip(inp_iter.next()) {
+            let q = ((x / scale).round() + zero_point).max(min).min(max);
+            *o = q.to_i8().unwrap();
+        }
+        Ok(out)
+    }
+
+    fn dequantize<S: Shape, T>(
+        &self,
+        inp: &Tensor<S, i8, Self, T>,
+        scale: E,
+        zero_point: i8,
+    ) -> Result<Tensor<S, E, Self>, Self::Err> {
+        let mut out: Tensor<S, E, Self> = self.try_zeros_like(&inp.shape)?;
+        let mut inp_iter = inp.iter();
+        let mut out_iter = out.iter_mut();
+        while let Some((o, &q)) = out_iter.next().zip(inp_iter.next()) {
+            *o = E::from(q as i16 - zero_point as i16).unwrap() * scale;
+        }
+        Ok(out)
+    }
+}
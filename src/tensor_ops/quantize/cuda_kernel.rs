@@ -0,0 +1,97 @@
+use crate::{
+    shapes::{Dtype, Shape},
+    tensor::{Cuda, Tensor},
+};
+use cudarc::driver::{CudaSlice, DeviceRepr, LaunchAsync, LaunchConfig};
+
+const PTX_SRC: &str = include_str!(concat!(env!("OUT_DIR"), "/quantize.ptx"));
+
+trait HasCudaKernel<E> {
+    const MOD: &'static str;
+    const FNS: &'static [&'static str];
+}
+
+impl HasCudaKernel<f32> for Cuda {
+    const MOD: &'static str = "quantize_f32";
+    const FNS: &'static [&'static str] = &["quantize_f32", "dequantize_f32"];
+}
+
+impl HasCudaKernel<f64> for Cuda {
+    const MOD: &'static str = "quantize_f64";
+    const FNS: &'static [&'static str] = &["quantize_f64", "dequantize_f64"];
+}
+
+impl<E: Dtype + DeviceRepr> super::QuantizeKernel<E> for Cuda
+where
+    Self: HasCudaKernel<E>,
+{
+    fn quantize<S: Shape, T>(
+        &self,
+        inp: &Tensor<S, E, Self, T>,
+        scale: E,
+        zero_point: i8,
+    ) -> Result<Tensor<S, i8, Self>, Self::Err> {
+        if !self.dev.has_func(Self::MOD, Self::FNS[0]) {
+            self.dev.load_ptx(PTX_SRC.into(), Self::MOD, Self::FNS)?;
+        }
+
+        let shape = inp.shape;
+        let strides = inp.shape.strides();
+        let numel = shape.num_elements();
+
+        let mut storage = self.dev.alloc_zeros::<i8>(numel)?;
+
+        let dims: CudaSlice<usize> = self.dev.htod_copy(shape.concrete().into())?;
+        let inp_strides: CudaSlice<usize> = self.dev.htod_copy(inp.strides.into())?;
+
+        let fwd_fn = self.dev.get_func(Self::MOD, Self::FNS[0]).unwrap();
+        let cfg = LaunchConfig::for_num_elems(numel as u32);
+        let params = (
+            numel,             // const size_t numel,
+            S::NUM_DIMS,       // const size_t num_dims,
+            &dims,             // const size_t *dims,
+            inp.data.as_ref(), // const T *inp,
+            &inp_strides,      // const size_t *inp_strides,
+            scale,             // const T scale,
+            zero_point as i32, // const int zero_point,
+            &mut storage,      // signed char *out
+        );
+        unsafe { fwd_fn.launch(cfg, params) }?;
+        Ok(self.build_tensor(shape, strides, storage))
+    }
+
+    fn dequantize<S: Shape, T>(
+        &self,
+        inp: &Tensor<S, i8, Self, T>,
+        scale: E,
+        zero_point: i8,
+    ) -> Result<Tensor<S, E, Self>, Self::Err> {
+        if !self.dev.has_func(Self::MOD, Self::FNS[1]) {
+            self.dev.load_ptx(PTX_SRC.into(), Self::MOD, Self::FNS)?;
+        }
+
+        let shape = inp.shape;
+        let strides = inp.shape.strides();
+        let numel = shape.num_elements();
+
+        let mut storage = self.dev.alloc_zeros::<E>(numel)?;
+
+        let dims: CudaSlice<usize> = self.dev.htod_copy(shape.concrete().into())?;
+        let inp_strides: CudaSlice<usize> = self.dev.htod_copy(inp.strides.into())?;
+
+        let fwd_fn = self.dev.get_func(Self::MOD, Self::FNS[1]).unwrap();
+        let cfg = LaunchConfig::for_num_elems(numel as u32);
+        let params = (
+            numel,             // const size_t numel,
+            S::NUM_DIMS,       // const size_t num_dims,
+            &dims,             // const size_t *dims,
+            inp.data.as_ref(), // const signed char *inp,
+            &inp_strides,      // const size_t *inp_strides,
+            scale,             // const T scale,
+            zero_point as i32, // const int zero_point,
+            &mut storage,      // T *out
+        );
+        unsafe { fwd_fn.launch(cfg, params) }?;
+        Ok(self.build_tensor(shape, strides, storage))
+    }
+}
@@ -0,0 +1,146 @@
+use crate::{
+    gradients::{NoneTape, Tape},
+    shapes::{Dtype, Shape},
+    tensor::{DeviceStorage, Tensor},
+};
+
+mod cpu_kernel;
+#[cfg(feature = "cuda")]
+mod cuda_kernel;
+
+pub trait QuantizeKernel<E: Dtype>: DeviceStorage {
+    fn quantize<S: Shape, T>(
+        &self,
+        inp: &Tensor<S, E, Self, T>,
+        scale: E,
+        zero_point: i8,
+    ) -> Result<Tensor<S, i8, Self>, Self::Err>;
+
+    fn dequantize<S: Shape, T>(
+        &self,
+        inp: &Tensor<S, i8, Self, T>,
+        scale: E,
+        zero_point: i8,
+    ) -> Result<Tensor<S, E, Self>, Self::Err>;
+}
+
+/// Affine quantization of a float tensor to `i8`.
+/// `clamp(round(t / scale) + zero_point, -128, 127)`
+///
+/// Values are rounded half away from zero. This is not recorded on the tape, so
+/// the result has no gradient.
+///
+/// Use [dequantize] to convert back to floats.
+///
+/// **Pytorch equivalent**: `torch.quantize_per_tensor(t, scale, zero_point, torch.qint8)`
+///
+/// Examples:
+/// ```
+/// # use dfdx::prelude::*;
+/// # let dev: Cpu = Default::default();
+/// let a = dev.tensor([-1.0, 0.0, 0.26, 1.0, 100.0]);
+/// let q = a.quantize(0.1, 2);
+/// assert_eq!(q.array(), [-8, 2, 5, 12, 127]);
+/// ```
+pub fn quantize<S: Shape, E: Dtype, D: QuantizeKernel<E>, T: Tape<E, D>>(
+    t: &Tensor<S, E, D, T>,
+    scale: E,
+    zero_point: i8,
+) -> Tensor<S, i8, D, NoneTape> {
+    t.quantize(scale, zero_point)
+}
+
+/// Converts an `i8` tensor produced by [quantize] back to floats.
+/// `(t - zero_point) * scale`
+///
+/// For values that weren't clamped, `dequantize(quantize(x))` is within `scale / 2` of `x`.
+///
+/// **Pytorch equivalent**: `torch.dequantize(q)`
+///
+/// Examples:
+/// ```
+/// # use dfdx::prelude::*;
+/// # let dev: Cpu = Default::default();
+/// let q: Tensor<Rank1<3>, i8, _> = dev.tensor([-8, 2, 12]);
+/// let r: Tensor<Rank1<3>, f32, _> = dequantize(&q, 0.5, 2);
+/// assert_eq!(r.array(), [-5.0, 0.0, 5.0]);
+/// ```
+pub fn dequantize<S: Shape, E: Dtype, D: QuantizeKernel<E>>(
+    t: &Tensor<S, i8, D>,
+    scale: E,
+    zero_point: i8,
+) -> Tensor<S, E, D, NoneTape> {
+    t.dequantize(scale, zero_point)
+}
+
+impl<S: Shape, E: Dtype, D: QuantizeKernel<E>, T: Tape<E, D>> Tensor<S, E, D, T> {
+    /// See [quantize]
+    pub fn quantize(&self, scale: E, zero_point: i8) -> Tensor<S, i8, D, NoneTape> {
+        self.try_quantize(scale, zero_point).unwrap()
+    }
+
+    /// See [quantize]
+    pub fn try_quantize(
+        &self,
+        scale: E,
+        zero_point: i8,
+    ) -> Result<Tensor<S, i8, D, NoneTape>, D::Err> {
+        self.device.quantize(self, scale, zero_point)
+    }
+}
+
+impl<S: Shape, D: DeviceStorage> Tensor<S, i8, D> {
+    /// See [dequantize]
+    pub fn dequantize<E: Dtype>(&self, scale: E, zero_point: i8) -> Tensor<S, E, D, NoneTape>
+    where
+        D: QuantizeKernel<E>,
+    {
+        self.try_dequantize(scale, zero_point).unwrap()
+    }
+
+    /// See [dequantize]
+    pub fn try_dequantize<E: Dtype>(
+        &self,
+        scale: E,
+        zero_point: i8,
+    ) -> Result<Tensor<S, E, D, NoneTape>, D::Err>
+    where
+        D: QuantizeKernel<E>,
+    {
+        self.device.dequantize(self, scale, zero_point)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{shapes::*, tensor::*, tensor_ops::*, tests::*};
+
+    #[test]
+    fn test_quantize() {
+        let dev: TestDevice = Default::default();
+        let a: Tensor<_, TestDtype, _> = dev.tensor([-300.0, -2.5, -0.24, 0.25, 1.26, 300.0]);
+        let q = a.quantize(0.5, -3);
+        assert_eq!(q.array(), [-128, -8, -3, -2, 0, 127]);
+        let r = q.dequantize::<TestDtype>(0.5, -3);
+        assert_close(&r.array(), &[-62.5, -2.5, 0.0, 0.5, 1.5, 65.0]);
+    }
+
+    #[test]
+    fn test_quantize_round_trip() {
+        let dev: TestDevice = Default::default();
+        let a: Tensor<Rank2<8, 16>, TestDtype, _> = dev.sample_uniform();
+        let a = (a - 0.5) * 4.0;
+        let scale = 4.0 / 255.0;
+        let r = dequantize(&a.quantize(scale, 0), scale, 0);
+        let err = (r - a).abs().max::<Rank0, _>().array();
+        assert!(err <= scale / 2.0 + 1e-6, "{err}");
+    }
+
+    #[test]
+    fn test_quantize_strided() {
+        let dev: TestDevice = Default::default();
+        let a: Tensor<_, TestDtype, _> = dev.tensor([1.0, -1.0]);
+        let b: Tensor<Rank2<2, 2>, TestDtype, _> = a.broadcast::<_, Axis<0>>();
+        assert_eq!(b.quantize(0.25, 1).array(), [[5, -3], [5, -3]]);
+    }
+}
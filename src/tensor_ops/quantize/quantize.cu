@@ -0,0 +1,42 @@
+#include "cuda_utils.cuh"
+
+#define QUANTIZE(TYPENAME, QUANTIZE, DEQUANTIZE) \
+extern "C" __global__ void QUANTIZE( \
+    const size_t numel, \
+    const size_t num_dims, \
+    const size_t *dims, \
+    const TYPENAME *inp, \
+    const size_t *inp_strides, \
+    const TYPENAME scale, \
+    const int zero_point, \
+    signed char *out \
+) { \
+    unsigned int i = blockIdx.x * blockDim.x + threadIdx.x; \
+    if (i >= numel) { \
+        return; \
+    } \
+    unsigned int inp_i = get_strided_index(i, num_dims, dims, inp_strides); \
+    TYPENAME q = round(inp[inp_i] / scale) + zero_point; \
+    out[i] = (signed char) ming(maxg(q, (TYPENAME) -128.0), (TYPENAME) 127.0); \
+} \
+\
+extern "C" __global__ void DEQUANTIZE( \
+    const size_t numel, \
+    const size_t num_dims, \
+    const size_t *dims, \
+    const signed char *inp, \
+    const size_t *inp_strides, \
+    const TYPENAME scale, \
+    const int zero_point, \
+    TYPENAME *out \
+) { \
+    unsigned int i = blockIdx.x * blockDim.x + threadIdx.x; \
+    if (i >= numel) { \
+        return; \
+    } \
+    unsigned int inp_i = get_strided_index(i, num_dims, dims, inp_strides); \
+    out[i] = (TYPENAME) ((int) inp[inp_i] - zero_point) * scale; \
+}
+
+QUANTIZE(float, quantize_f32, dequantize_f32)
+QUANTIZE(double, quantize_f64, dequantize_f64)
@@ -1,6 +1,6 @@
-use crate::tensor_ops::cpu_kernels::UnaryDerivative;
+use crate::tensor_ops::{cpu_kernels::UnaryDerivative, utilities::cpu_simd::FloatSlice};
 
-impl<F: num_traits::Float> UnaryDerivative<F> for super::ReLUKernelOp {
+impl<F: FloatSlice> UnaryDerivative<F> for super::ReLUKernelOp {
     #[inline(always)]
     fn f(&self, x: &F) -> F {
         x.max(F::zero())
@@ -13,4 +13,8 @@ impl<F: num_traits::Float> UnaryDerivative<F> for super::ReLUKernelOp {
             F::zero()
         }
     }
+    #[inline(always)]
+    fn f_slice(&self, buf: &mut [F]) {
+        F::relu_slice(buf)
+    }
 }
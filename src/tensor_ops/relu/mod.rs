@@ -3,7 +3,7 @@ mod cpu_kernel;
 #[cfg(feature = "cuda")]
 mod cuda_kernel;
 
-use super::ops::{try_unary_op, UnaryKernel};
+use super::ops::{try_unary_op, try_unary_op_inplace, UnaryKernel};
 use crate::{gradients::Tape, shapes::*, tensor::Tensor};
 
 #[repr(C)]
@@ -37,6 +37,16 @@ impl<S: Shape, E: Dtype, D: UnaryKernel<ReLUKernelOp, E>, T: Tape<E, D>> Tensor<
     pub fn try_relu(self) -> Result<Self, D::Err> {
         try_unary_op(ReLUKernelOp, self)
     }
+
+    /// In place version of [relu]. Overwrites the storage of `self` if it has no tape and
+    /// isn't shared with another tensor, otherwise this is the same as [relu].
+    pub fn relu_(self) -> Self {
+        self.try_relu_().unwrap()
+    }
+    /// See [Tensor::relu_]
+    pub fn try_relu_(self) -> Result<Self, D::Err> {
+        try_unary_op_inplace(ReLUKernelOp, self)
+    }
 }
 
 #[cfg(test)]
@@ -53,4 +63,37 @@ mod tests {
         let g = r.exp().mean().backward();
         assert_close(&g.get(&x).array(), &[0.0, 0.0, 0.0, 0.54365635, 1.4778112]);
     }
+
+    #[test]
+    fn test_relu_inplace_unique() {
+        let dev: TestDevice = Default::default();
+        let x: Tensor<_, TestDtype, _> = dev.tensor([-2.0, -1.0, 0.0, 1.0, 2.0]);
+        let ptr = std::sync::Arc::as_ptr(&x.data);
+        let r = x.relu_();
+        assert_eq!(r.array(), [0.0, 0.0, 0.0, 1.0, 2.0]);
+        assert_eq!(std::sync::Arc::as_ptr(&r.data), ptr);
+    }
+
+    #[test]
+    fn test_relu_inplace_shared() {
+        let dev: TestDevice = Default::default();
+        let x: Tensor<_, TestDtype, _> = dev.tensor([-2.0, -1.0, 0.0, 1.0, 2.0]);
+        let r = x.clone().relu_();
+        assert_eq!(r.array(), [0.0, 0.0, 0.0, 1.0, 2.0]);
+        assert_eq!(x.array(), [-2.0, -1.0, 0.0, 1.0, 2.0]);
+        assert_ne!(
+            std::sync::Arc::as_ptr(&r.data),
+            std::sync::Arc::as_ptr(&x.data)
+        );
+    }
+
+    #[test]
+    fn test_relu_inplace_traced() {
+        let dev: TestDevice = Default::default();
+        let x: Tensor<_, TestDtype, _> = dev.tensor([-2.0, -1.0, 0.0, 1.0, 2.0]);
+        let r = x.trace().relu_();
+        assert_eq!(r.array(), [0.0, 0.0, 0.0, 1.0, 2.0]);
+        let g = r.exp().mean().backward();
+        assert_close(&g.get(&x).array(), &[0.0, 0.0, 0.0, 0.54365635, 1.4778112]);
+    }
 }
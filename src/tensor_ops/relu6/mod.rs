@@ -0,0 +1,57 @@
+mod cpu_kernel;
+
+#[cfg(feature = "cuda")]
+mod cuda_kernel;
+
+use super::ops::{try_unary_op, UnaryKernel};
+use crate::{gradients::Tape, shapes::*, tensor::Tensor};
+
+#[repr(C)]
+#[derive(Debug, Default, Copy, Clone)]
+pub struct ReLU6KernelOp;
+
+/// [relu](crate::tensor_ops::relu()) capped at 6. `min(max(0, t), 6)`
+///
+/// The derivative is 1 for `0 < t < 6`, and 0 everywhere else.
+///
+/// Examples:
+/// ```rust
+/// # use dfdx::prelude::*;
+/// # let dev: Cpu = Default::default();
+/// let t = dev.tensor([-1.0, 0.0, 1.0, 6.0, 7.0]);
+/// let r = t.relu6();
+/// assert_eq!(r.array(), [0.0, 0.0, 1.0, 6.0, 6.0]);
+/// ```
+pub fn relu6<S: Shape, E: Dtype, D: UnaryKernel<ReLU6KernelOp, E>, T: Tape<E, D>>(
+    t: Tensor<S, E, D, T>,
+) -> Tensor<S, E, D, T> {
+    t.relu6()
+}
+
+impl<S: Shape, E: Dtype, D: UnaryKernel<ReLU6KernelOp, E>, T: Tape<E, D>> Tensor<S, E, D, T> {
+    /// See [relu6]
+    pub fn relu6(self) -> Self {
+        self.try_relu6().unwrap()
+    }
+    /// See [relu6]
+    pub fn try_relu6(self) -> Result<Self, D::Err> {
+        try_unary_op(ReLU6KernelOp, self)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{tensor::*, tensor_ops::*, tests::*};
+
+    #[test]
+    fn test_relu6() {
+        let dev: TestDevice = Default::default();
+        let x: Tensor<_, TestDtype, _> = dev.tensor([-2.0, 0.0, 0.5, 3.0, 6.0, 8.0]);
+        let r = x.trace().relu6();
+        assert_eq!(r.array(), [0.0, 0.0, 0.5, 3.0, 6.0, 6.0]);
+        // NOTE: call .exp() to make sure we cover cases where .relu6() uses the result's gradient
+        let g = r.exp().sum().backward();
+        let e = [0.5, 3.0].map(TestDtype::exp);
+        assert_close(&g.get(&x).array(), &[0.0, 0.0, e[0], e[1], 0.0, 0.0]);
+    }
+}
@@ -0,0 +1,11 @@
+#include "unary_op_macros.cuh"
+
+struct ReLU6KernelOp {};
+
+UNARY_OP(float, relu6_fwd_f32, relu6_bwd_f32, ReLU6KernelOp,
+        fminf(fmaxf(x, 0.0), 6.0),
+        x > 0.0 && x < 6.0 ? 1.0 : 0.0)
+
+UNARY_OP(double, relu6_fwd_f64, relu6_bwd_f64, ReLU6KernelOp,
+        fmin(fmax(x, 0.0), 6.0),
+        x > 0.0 && x < 6.0 ? 1.0 : 0.0)
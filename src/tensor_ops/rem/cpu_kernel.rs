@@ -0,0 +1,36 @@
+use crate::tensor_ops::cpu_kernels::BinaryDerivative;
+
+impl<F: num_traits::Float> BinaryDerivative<F> for super::RemKernelOp {
+    #[inline(always)]
+    fn f(&self, &x: &F, &y: &F) -> F {
+        let r = x % y;
+        if r != F::zero() && ((r < F::zero()) != (y < F::zero())) {
+            r + y
+        } else {
+            r
+        }
+    }
+    #[inline(always)]
+    fn dfdx(&self, _: &F, _: &F) -> F {
+        F::one()
+    }
+    #[inline(always)]
+    fn dfdy(&self, _: &F, _: &F) -> F {
+        F::zero()
+    }
+}
+
+impl<F: num_traits::Float> BinaryDerivative<F> for super::FmodKernelOp {
+    #[inline(always)]
+    fn f(&self, &x: &F, &y: &F) -> F {
+        x % y
+    }
+    #[inline(always)]
+    fn dfdx(&self, _: &F, _: &F) -> F {
+        F::one()
+    }
+    #[inline(always)]
+    fn dfdy(&self, _: &F, _: &F) -> F {
+        F::zero()
+    }
+}
@@ -0,0 +1,12 @@
+use super::{FmodKernelOp as Fmod, RemKernelOp as Rem};
+use crate::tensor_ops::cuda_kernels::cuda_binary;
+
+unsafe impl cudarc::driver::DeviceRepr for Rem {}
+unsafe impl cudarc::driver::DeviceRepr for Fmod {}
+
+const PTX: &str = include_str!(concat!(env!("OUT_DIR"), "/rem.ptx"));
+
+cuda_binary!(Rem, f32, PTX, "rem_fwd_f32", "rem_bwd_f32");
+cuda_binary!(Rem, f64, PTX, "rem_fwd_f64", "rem_bwd_f64");
+cuda_binary!(Fmod, f32, PTX, "fmod_fwd_f32", "fmod_bwd_f32");
+cuda_binary!(Fmod, f64, PTX, "fmod_fwd_f64", "fmod_bwd_f64");
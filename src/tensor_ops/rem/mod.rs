@@ -0,0 +1,135 @@
+mod cpu_kernel;
+
+#[cfg(feature = "cuda")]
+mod cuda_kernel;
+
+use super::{ops::try_binary_op, Device};
+use crate::{gradients::*, shapes::*, tensor::Tensor};
+
+#[repr(C)]
+#[derive(Debug, Default, Clone, Copy)]
+pub struct RemKernelOp;
+
+#[repr(C)]
+#[derive(Debug, Default, Clone, Copy)]
+pub struct FmodKernelOp;
+
+/// Element wise remainder of `lhs / rhs`, rounding the quotient towards negative infinity.
+/// The result has the same sign as `rhs`.
+///
+/// The gradient of `lhs` is `1`, and `rhs` is treated as a constant, so its gradient is `0`.
+///
+/// **Pytorch equivalent**: `torch.remainder(a, b)`
+///
+/// Example:
+/// ```rust
+/// # use dfdx::prelude::*;
+/// # let dev: Cpu = Default::default();
+/// let a = dev.tensor([5.0, -5.0, 5.0, -5.0]);
+/// let b = dev.tensor([3.0, 3.0, -3.0, -3.0]);
+/// let r = a.clone().remainder(b.clone());
+/// assert_eq!(r.array(), [2.0, 1.0, -1.0, -2.0]);
+/// assert_eq!((a % b).array(), r.array());
+/// ```
+pub fn rem<S: Shape, E: Dtype, D: Device<E>, LTape: Tape<E, D> + Merge<R>, R: Default>(
+    lhs: Tensor<S, E, D, LTape>,
+    rhs: Tensor<S, E, D, R>,
+) -> Tensor<S, E, D, LTape> {
+    lhs.remainder(rhs)
+}
+
+/// Element wise remainder of `lhs / rhs`, rounding the quotient towards zero.
+/// The result has the same sign as `lhs`.
+///
+/// The gradient of `lhs` is `1`, and `rhs` is treated as a constant, so its gradient is `0`.
+///
+/// **Pytorch equivalent**: `torch.fmod(a, b)`
+///
+/// Example:
+/// ```rust
+/// # use dfdx::prelude::*;
+/// # let dev: Cpu = Default::default();
+/// let a = dev.tensor([5.0, -5.0, 5.0, -5.0]);
+/// let b = dev.tensor([3.0, 3.0, -3.0, -3.0]);
+/// let r = a.fmod(b);
+/// assert_eq!(r.array(), [2.0, -2.0, 2.0, -2.0]);
+/// ```
+pub fn fmod<S: Shape, E: Dtype, D: Device<E>, LTape: Tape<E, D> + Merge<R>, R: Default>(
+    lhs: Tensor<S, E, D, LTape>,
+    rhs: Tensor<S, E, D, R>,
+) -> Tensor<S, E, D, LTape> {
+    lhs.fmod(rhs)
+}
+
+impl<S: Shape, E: Dtype, D: Device<E>, LTape: Tape<E, D>> Tensor<S, E, D, LTape> {
+    /// See [rem]
+    pub fn remainder<R: Default>(self, rhs: Tensor<S, E, D, R>) -> Self
+    where
+        LTape: Merge<R>,
+    {
+        self.try_remainder(rhs).unwrap()
+    }
+
+    /// See [rem]
+    pub fn try_remainder<R: Default>(self, rhs: Tensor<S, E, D, R>) -> Result<Self, D::Err>
+    where
+        LTape: Merge<R>,
+    {
+        try_binary_op(RemKernelOp, self, rhs)
+    }
+
+    /// See [fmod]
+    pub fn fmod<R: Default>(self, rhs: Tensor<S, E, D, R>) -> Self
+    where
+        LTape: Merge<R>,
+    {
+        self.try_fmod(rhs).unwrap()
+    }
+
+    /// See [fmod]
+    pub fn try_fmod<R: Default>(self, rhs: Tensor<S, E, D, R>) -> Result<Self, D::Err>
+    where
+        LTape: Merge<R>,
+    {
+        try_binary_op(FmodKernelOp, self, rhs)
+    }
+}
+
+impl<S: Shape, E: Dtype, D: Device<E>, LTape: Tape<E, D> + Merge<R>, R: Default>
+    std::ops::Rem<Tensor<S, E, D, R>> for Tensor<S, E, D, LTape>
+{
+    type Output = Self;
+    /// See [rem]
+    fn rem(self, rhs: Tensor<S, E, D, R>) -> Self::Output {
+        self.remainder(rhs)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{tensor::*, tensor_ops::*, tests::*};
+
+    #[test]
+    fn test_rem() {
+        let dev: TestDevice = Default::default();
+        let a: Tensor<_, TestDtype, _> = dev.tensor([5.0, -5.0, 5.0, -5.0, 6.0, 1.5]);
+        let b: Tensor<_, TestDtype, _> = dev.tensor([3.0, 3.0, -3.0, -3.0, 3.0, -0.5]);
+        let r = a.trace() % b.trace();
+        assert_close(&r.array(), &[2.0, 1.0, -1.0, -2.0, 0.0, 0.0]);
+        let g = r.sum().backward();
+        assert_eq!(g.get(&a).array(), [1.0; 6]);
+        assert_eq!(g.get(&b).array(), [0.0; 6]);
+    }
+
+    #[test]
+    fn test_fmod() {
+        let dev: TestDevice = Default::default();
+        let a: Tensor<_, TestDtype, _> = dev.tensor([5.0, -5.0, 5.0, -5.0, 6.0, 1.25]);
+        let b: Tensor<_, TestDtype, _> = dev.tensor([3.0, 3.0, -3.0, -3.0, 3.0, -0.5]);
+        let r = a.trace().fmod(b.trace());
+        assert_close(&r.array(), &[2.0, -2.0, 2.0, -2.0, 0.0, 0.25]);
+        let g = r.sum().backward();
+        assert_eq!(g.get(&a).array(), [1.0; 6]);
+        assert_eq!(g.get(&b).array(), [0.0; 6]);
+    }
+}
@@ -0,0 +1,37 @@
+#include "binary_op_macros.cuh"
+
+struct RemKernelOp {};
+struct FmodKernelOp {};
+
+__device__ float fmodg(float a, float b) { return fmodf(a, b); }
+__device__ double fmodg(double a, double b) { return fmod(a, b); }
+
+template<typename T>
+__device__ T op_rem(T x, T y) {
+    T r = fmodg(x, y);
+    return (r != 0.0 && ((r < 0.0) != (y < 0.0))) ? r + y : r;
+}
+
+BINARY_OP(float, rem_fwd_f32, rem_bwd_f32, RemKernelOp,
+    op_rem(x, y),
+    1.0,
+    0.0
+)
+
+BINARY_OP(double, rem_fwd_f64, rem_bwd_f64, RemKernelOp,
+    op_rem(x, y),
+    1.0,
+    0.0
+)
+
+BINARY_OP(float, fmod_fwd_f32, fmod_bwd_f32, FmodKernelOp,
+    fmodf(x, y),
+    1.0,
+    0.0
+)
+
+BINARY_OP(double, fmod_fwd_f64, fmod_bwd_f64, FmodKernelOp,
+    fmod(x, y),
+    1.0,
+    0.0
+)
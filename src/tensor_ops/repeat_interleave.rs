@@ -0,0 +1,96 @@
+use super::{index_select::IndexSelectKernel, IndexSelect};
+use crate::{gradients::Tape, shapes::*, tensor::*};
+
+/// Repeats each element along an axis `repeats` times in a row,
+/// e.g. `[a, b]` becomes `[a, a, b, b]` with `repeats = 2`.
+///
+/// This is different from tiling, which would produce `[a, b, a, b]`.
+/// The gradient of each copy is summed back into the element it was copied from.
+///
+/// **Pytorch equivalent**: `t.repeat_interleave(repeats, dim=Ax)`
+pub trait RepeatInterleave: HasErr + HasShape {
+    /// Repeats along axis `Ax`, which becomes a runtime dimension of size `repeats * size`:
+    /// ```rust
+    /// # use dfdx::prelude::*;
+    /// # let dev: Cpu = Default::default();
+    /// let a: Tensor<Rank2<2, 2>, f32, _> = dev.tensor([[1.0, 2.0], [3.0, 4.0]]);
+    /// let r: Tensor<(Const<2>, usize), f32, _> = a.repeat_interleave::<Axis<1>>(3);
+    /// assert_eq!(r.shape(), &(Const, 6));
+    /// assert_eq!(
+    ///     r.as_vec(),
+    ///     [1.0, 1.0, 1.0, 2.0, 2.0, 2.0, 3.0, 3.0, 3.0, 4.0, 4.0, 4.0]
+    /// );
+    /// ```
+    fn repeat_interleave<Ax: Axes<Array = [isize; 1]>>(
+        self,
+        repeats: usize,
+    ) -> Self::WithShape<<Self::Shape as ReplaceAxisWith<Ax, usize>>::Replaced>
+    where
+        Self::Shape: ReplaceAxisWith<Ax, usize>,
+    {
+        self.try_repeat_interleave::<Ax>(repeats).unwrap()
+    }
+
+    /// Fallible version of [RepeatInterleave::repeat_interleave]
+    #[allow(clippy::type_complexity)]
+    fn try_repeat_interleave<Ax: Axes<Array = [isize; 1]>>(
+        self,
+        repeats: usize,
+    ) -> Result<Self::WithShape<<Self::Shape as ReplaceAxisWith<Ax, usize>>::Replaced>, Self::Err>
+    where
+        Self::Shape: ReplaceAxisWith<Ax, usize>;
+}
+
+impl<S: Shape, E: Dtype, D, T: Tape<E, D>> RepeatInterleave for Tensor<S, E, D, T>
+where
+    D: IndexSelectKernel<E> + TensorFromVec<usize>,
+{
+    fn try_repeat_interleave<Ax: Axes<Array = [isize; 1]>>(
+        self,
+        repeats: usize,
+    ) -> Result<Self::WithShape<S::Replaced>, Self::Err>
+    where
+        S: ReplaceAxisWith<Ax, usize>,
+    {
+        let size = self.shape().concrete()[Ax::as_array()[0] as usize];
+        let idx: std::vec::Vec<usize> = (0..size * repeats).map(|i| i / repeats).collect();
+        let n = idx.len();
+        let idx = self.device.try_tensor_from_vec(idx, (n,))?;
+        self.try_index_select::<Ax, usize>(idx)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{tensor_ops::*, tests::*};
+
+    #[test]
+    fn test_repeat_interleave_axis_0() {
+        let dev: TestDevice = Default::default();
+        let t: Tensor<_, TestDtype, _> = dev.tensor([[1.0, 2.0, 3.0], [4.0, 5.0, 6.0]]);
+        let r = t.trace().repeat_interleave::<Axis<0>>(2);
+        assert_eq!(r.shape(), &(4, Const::<3>));
+        assert_eq!(
+            r.as_vec(),
+            [1.0, 2.0, 3.0, 1.0, 2.0, 3.0, 4.0, 5.0, 6.0, 4.0, 5.0, 6.0]
+        );
+
+        let w = dev.tensor_from_vec(
+            std::vec![1.0, 2.0, 3.0, 4.0, 5.0, 6.0, 7.0, 8.0, 9.0, 10.0, 11.0, 12.0],
+            (4, Const::<3>),
+        );
+        let g = (r * w).sum().backward();
+        assert_eq!(g.get(&t).array(), [[5.0, 7.0, 9.0], [17.0, 19.0, 21.0]]);
+    }
+
+    #[test]
+    fn test_repeat_interleave_once() {
+        let dev: TestDevice = Default::default();
+        let t: Tensor<Rank3<2, 3, 4>, TestDtype, _> = dev.sample_normal();
+        let r = t.trace().repeat_interleave::<Axis<2>>(1);
+        assert_eq!(r.as_vec(), t.as_vec());
+        let g = r.exp().sum().backward();
+        assert_close(&g.get(&t).array(), &t.exp().array());
+    }
+}
@@ -0,0 +1,67 @@
+use crate::{
+    shapes::{Dtype, Shape},
+    tensor::{Cpu, Tensor},
+};
+
+use super::RotaryEmbeddingOp;
+
+use std::sync::Arc;
+
+impl<E: Dtype> super::RotaryEmbeddingKernel<E> for Cpu {
+    fn forward<S: Shape>(
+        &self,
+        op: RotaryEmbeddingOp,
+        inp: &Tensor<S, E, Self>,
+        sin: &Tensor<(usize, usize), E, Self>,
+        cos: &Tensor<(usize, usize), E, Self>,
+        out: &mut Tensor<S, E, Self>,
+    ) -> Result<(), Self::Err> {
+        let istr: [usize; 3] = std::array::from_fn(|i| inp.strides[i]);
+        let ostr: [usize; 3] = std::array::from_fn(|i| out.strides[i]);
+        let half = op.dim / 2;
+
+        let buf = inp.data.as_ref();
+        let out_buf = Arc::make_mut(&mut out.data);
+        for b in 0..op.batch {
+            for s in 0..op.seq {
+                for i in 0..half {
+                    let (sin, cos) = (sin.data[s * half + i], cos.data[s * half + i]);
+                    let x = b * istr[0] + s * istr[1] + 2 * i * istr[2];
+                    let (x0, x1) = (buf[x], buf[x + istr[2]]);
+                    let o = b * ostr[0] + s * ostr[1] + 2 * i * ostr[2];
+                    out_buf[o] = x0 * cos - x1 * sin;
+                    out_buf[o + ostr[2]] = x0 * sin + x1 * cos;
+                }
+            }
+        }
+        Ok(())
+    }
+
+    fn backward<S: Shape>(
+        &self,
+        op: RotaryEmbeddingOp,
+        inp: &Tensor<S, E, Self>,
+        grad_inp: &mut Self::Vec<E>,
+        sin: &Tensor<(usize, usize), E, Self>,
+        cos: &Tensor<(usize, usize), E, Self>,
+        grad_out: &Self::Vec<E>,
+    ) -> Result<(), Self::Err> {
+        let istr: [usize; 3] = std::array::from_fn(|i| inp.strides[i]);
+        let half = op.dim / 2;
+
+        // the rotation is orthogonal, so the gradient is rotated back by the same angle
+        for b in 0..op.batch {
+            for s in 0..op.seq {
+                for i in 0..half {
+                    let (sin, cos) = (sin.data[s * half + i], cos.data[s * half + i]);
+                    let o = (b * op.seq + s) * op.dim + 2 * i;
+                    let (g0, g1) = (grad_out[o], grad_out[o + 1]);
+                    let x = b * istr[0] + s * istr[1] + 2 * i * istr[2];
+                    grad_inp[x] += g0 * cos + g1 * sin;
+                    grad_inp[x + istr[2]] += g1 * cos - g0 * sin;
+                }
+            }
+        }
+        Ok(())
+    }
+}
@@ -0,0 +1,91 @@
+use crate::{
+    shapes::*,
+    tensor::{Cuda, Tensor},
+};
+
+use super::RotaryEmbeddingOp;
+
+use std::{sync::Arc, vec::Vec};
+
+use cudarc::driver::{DeviceRepr, LaunchAsync, LaunchConfig};
+
+const PTX_SRC: &str = include_str!(concat!(env!("OUT_DIR"), "/rope.ptx"));
+
+unsafe impl DeviceRepr for RotaryEmbeddingOp {}
+
+trait HasCudaKernel<E> {
+    const MOD: &'static str;
+    const FNS: &'static [&'static str];
+}
+
+impl HasCudaKernel<f32> for Cuda {
+    const MOD: &'static str = "rope_f32";
+    const FNS: &'static [&'static str] = &["rope_fwd_f32", "rope_bwd_f32"];
+}
+
+impl HasCudaKernel<f64> for Cuda {
+    const MOD: &'static str = "rope_f64";
+    const FNS: &'static [&'static str] = &["rope_fwd_f64", "rope_bwd_f64"];
+}
+
+impl<E: Dtype> super::RotaryEmbeddingKernel<E> for Cuda
+where
+    Self: HasCudaKernel<E>,
+{
+    fn forward<S: Shape>(
+        &self,
+        op: RotaryEmbeddingOp,
+        inp: &Tensor<S, E, Self>,
+        sin: &Tensor<(usize, usize), E, Self>,
+        cos: &Tensor<(usize, usize), E, Self>,
+        out: &mut Tensor<S, E, Self>,
+    ) -> Result<(), Self::Err> {
+        if !self.dev.has_func(Self::MOD, Self::FNS[0]) {
+            self.dev.load_ptx(PTX_SRC.into(), Self::MOD, Self::FNS)?;
+        }
+
+        let inp_strides: Vec<usize> = inp.strides.into();
+        let out_strides: Vec<usize> = out.strides.into();
+        let inp_strides = self.dev.htod_copy(inp_strides)?;
+        let out_strides = self.dev.htod_copy(out_strides)?;
+        let fwd_fn = self.dev.get_func(Self::MOD, Self::FNS[0]).unwrap();
+        // one thread per pair of features
+        let cfg = LaunchConfig::for_num_elems((op.batch * op.seq * op.dim / 2) as u32);
+        let params = (
+            op,                           // const RotaryEmbeddingOp op,
+            &inp_strides,                 // const size_t *inp_strides,
+            &out_strides,                 // const size_t *out_strides,
+            inp.data.as_ref(),            // const float *inp,
+            sin.data.as_ref(),            // const float *sin,
+            cos.data.as_ref(),            // const float *cos,
+            Arc::make_mut(&mut out.data), // float *out
+        );
+        unsafe { fwd_fn.launch(cfg, params) }?;
+        Ok(())
+    }
+
+    fn backward<S: Shape>(
+        &self,
+        op: RotaryEmbeddingOp,
+        inp: &Tensor<S, E, Self>,
+        grad_inp: &mut Self::Vec<E>,
+        sin: &Tensor<(usize, usize), E, Self>,
+        cos: &Tensor<(usize, usize), E, Self>,
+        grad_out: &Self::Vec<E>,
+    ) -> Result<(), Self::Err> {
+        let inp_strides: Vec<usize> = inp.strides.into();
+        let inp_strides = self.dev.htod_copy(inp_strides)?;
+        let bwd_fn = self.dev.get_func(Self::MOD, Self::FNS[1]).unwrap();
+        let cfg = LaunchConfig::for_num_elems((op.batch * op.seq * op.dim / 2) as u32);
+        let params = (
+            op,                // const RotaryEmbeddingOp op,
+            &inp_strides,      // const size_t *inp_strides,
+            grad_inp,          // float *grad_inp,
+            sin.data.as_ref(), // const float *sin,
+            cos.data.as_ref(), // const float *cos,
+            grad_out,          // const float *grad_out
+        );
+        unsafe { bwd_fn.launch(cfg, params) }?;
+        Ok(())
+    }
+}
@@ -0,0 +1,209 @@
+#![allow(clippy::type_complexity)]
+
+mod cpu_kernel;
+
+#[cfg(feature = "cuda")]
+mod cuda_kernel;
+
+use crate::{
+    gradients::Tape,
+    shapes::*,
+    tensor::{DeviceStorage, PutTape, SplitTape, Tensor, TensorFromVec, ZerosTensor},
+};
+
+/// The base of the geometric progression of rotation frequencies, as in the paper.
+const ROPE_BASE: f64 = 10000.0;
+
+#[repr(C)]
+#[derive(Debug, Copy, Clone)]
+pub struct RotaryEmbeddingOp {
+    pub batch: usize,
+    pub seq: usize,
+    pub dim: usize,
+}
+
+pub trait RotaryEmbeddingKernel<E: Dtype>: DeviceStorage {
+    fn forward<S: Shape>(
+        &self,
+        op: RotaryEmbeddingOp,
+        inp: &Tensor<S, E, Self>,
+        sin: &Tensor<(usize, usize), E, Self>,
+        cos: &Tensor<(usize, usize), E, Self>,
+        out: &mut Tensor<S, E, Self>,
+    ) -> Result<(), Self::Err>;
+
+    fn backward<S: Shape>(
+        &self,
+        op: RotaryEmbeddingOp,
+        inp: &Tensor<S, E, Self>,
+        grad_inp: &mut Self::Vec<E>,
+        sin: &Tensor<(usize, usize), E, Self>,
+        cos: &Tensor<(usize, usize), E, Self>,
+        grad_out: &Self::Vec<E>,
+    ) -> Result<(), Self::Err>;
+}
+
+/// Applies rotary positional embeddings to the queries or keys `t` of shape
+/// `(B, S, H)`, as described in [RoFormer](https://arxiv.org/abs/2104.09864).
+///
+/// Each pair of features `(t[.., 2i], t[.., 2i + 1])` of the token at sequence index `s`
+/// is rotated by the angle `positions[s] * 10000^(-2i / H)`. The dot product of a rotated
+/// query and a rotated key then only depends on the difference of their positions.
+///
+/// The sin/cos tables are computed from `positions` once per call. To use this with multi
+/// head attention, reshape `(B, NumHeads, S, H)` into `(B * NumHeads, S, H)`.
+///
+/// Panics if `H` is odd.
+///
+/// Example:
+/// ```rust
+/// # use dfdx::prelude::*;
+/// # let dev: Cpu = Default::default();
+/// let q: Tensor<Rank3<1, 2, 2>, f32, _> = dev.tensor([[[1.0, 0.0], [1.0, 0.0]]]);
+/// let r = q.rotary_embedding(dev.tensor([0, 1]));
+/// assert_eq!(r.array(), [[[1.0, 0.0], [1.0f64.cos() as f32, 1.0f64.sin() as f32]]]);
+/// ```
+pub fn rotary_embedding<B: Dim, S: Dim, H: Dim, E: Dtype, D, T: Tape<E, D>>(
+    t: Tensor<(B, S, H), E, D, T>,
+    positions: Tensor<(S,), usize, D>,
+) -> Tensor<(B, S, H), E, D, T>
+where
+    D: RotaryEmbeddingKernel<E> + TensorFromVec<E> + ZerosTensor<E>,
+{
+    t.rotary_embedding(positions)
+}
+
+impl<B: Dim, S: Dim, H: Dim, E: Dtype, D, T: Tape<E, D>> Tensor<(B, S, H), E, D, T>
+where
+    D: RotaryEmbeddingKernel<E> + TensorFromVec<E> + ZerosTensor<E>,
+{
+    /// See [rotary_embedding]
+    pub fn rotary_embedding(self, positions: Tensor<(S,), usize, D>) -> Self {
+        self.try_rotary_embedding(positions).unwrap()
+    }
+
+    /// See [rotary_embedding]
+    pub fn try_rotary_embedding(self, positions: Tensor<(S,), usize, D>) -> Result<Self, D::Err> {
+        let (batch, seq, dim) = self.shape;
+        assert_eq!(dim.size() % 2, 0, "the last dimension must be even");
+        assert_eq!(seq.size(), positions.shape.0.size());
+        let op = RotaryEmbeddingOp {
+            batch: batch.size(),
+            seq: seq.size(),
+            dim: dim.size(),
+        };
+
+        let half = op.dim / 2;
+        let mut sin = std::vec::Vec::with_capacity(op.seq * half);
+        let mut cos = std::vec::Vec::with_capacity(op.seq * half);
+        for pos in positions.as_vec() {
+            for i in 0..half {
+                let freq = ROPE_BASE.powf(-2.0 * i as f64 / op.dim as f64);
+                let angle = pos as f64 * freq;
+                sin.push(E::from_f64(angle.sin()).unwrap());
+                cos.push(E::from_f64(angle.cos()).unwrap());
+            }
+        }
+        let sin = self.device.try_tensor_from_vec(sin, (op.seq, half))?;
+        let cos = self.device.try_tensor_from_vec(cos, (op.seq, half))?;
+
+        let (inp, mut tape) = self.split_tape();
+        let mut out = inp.device.try_zeros_like(&inp.shape)?;
+        inp.device.forward(op, &inp, &sin, &cos, &mut out)?;
+        let phantom_out = out.clone();
+        tape.try_alloc_grad(&inp)?;
+        tape.try_alloc_grad(&out)?;
+        tape.add_reusable_backward_op(move |grads| {
+            let (grad_inp, grad_out) = grads.mut_and_ref(&inp, &phantom_out);
+            inp.device
+                .backward(op, &inp, grad_inp, &sin, &cos, grad_out)
+        });
+        Ok(out.put_tape(tape))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{tensor::*, tensor_ops::*, tests::*};
+
+    #[test]
+    fn test_rotary_embedding_values() {
+        let dev: TestDevice = Default::default();
+        let t: Tensor<Rank3<1, 2, 4>, TestDtype, _> =
+            dev.tensor([[[1.0, 2.0, 3.0, 4.0], [1.0, 2.0, 3.0, 4.0]]]);
+        let r = t.rotary_embedding(dev.tensor([0, 2]));
+        // the frequencies for `H = 4` are `1` and `1 / 100`
+        let (s0, c0) = (2.0f64.sin(), 2.0f64.cos());
+        let (s1, c1) = (0.02f64.sin(), 0.02f64.cos());
+        let expected = [
+            c0 - 2.0 * s0,
+            s0 + 2.0 * c0,
+            3.0 * c1 - 4.0 * s1,
+            3.0 * s1 + 4.0 * c1,
+        ]
+        .map(|v| v as TestDtype);
+        assert_close(&r.array(), &[[[1.0, 2.0, 3.0, 4.0], expected]]);
+    }
+
+    #[test]
+    fn test_rotary_embedding_relative_position() {
+        let dev: TestDevice = Default::default();
+        let q: Tensor<Rank1<6>, TestDtype, _> = dev.sample_normal();
+        let k: Tensor<Rank1<6>, TestDtype, _> = dev.sample_normal();
+        let q: Tensor<Rank3<1, 3, 6>, TestDtype, _> = q.broadcast();
+        let k: Tensor<Rank3<1, 3, 6>, TestDtype, _> = k.broadcast();
+
+        // every query is 3 positions after its key
+        let rq = q.clone().rotary_embedding(dev.tensor([3, 8, 104]));
+        let rk = k.clone().rotary_embedding(dev.tensor([0, 5, 101]));
+        let dots = (rq * rk).sum::<Rank2<1, 3>, _>().array();
+        assert_close_with_tolerance(&[dots[0][1], dots[0][2]], &[dots[0][0]; 2], 1e-4);
+
+        // with a different offset the dot product changes
+        let rq = q.rotary_embedding(dev.tensor([3, 8, 104]));
+        let rk = k.rotary_embedding(dev.tensor([3, 8, 104]));
+        let dots2 = (rq * rk).sum::<Rank2<1, 3>, _>().array();
+        assert_close_with_tolerance(&[dots2[0][1], dots2[0][2]], &[dots2[0][0]; 2], 1e-4);
+        assert!((dots[0][0] - dots2[0][0]).abs() > 1e-3);
+    }
+
+    #[test]
+    fn test_rotary_embedding_grad() {
+        let dev: TestDevice = Default::default();
+        let t: Tensor<Rank3<2, 3, 4>, TestDtype, _> = dev.sample_normal();
+        let w: Tensor<Rank3<2, 3, 4>, TestDtype, _> = dev.sample_normal();
+        let positions = [1, 4, 9];
+
+        let loss = |t: Tensor<Rank3<2, 3, 4>, TestDtype, _>| -> TestDtype {
+            (t.rotary_embedding(dev.tensor(positions)) * w.clone())
+                .sum::<Rank0, _>()
+                .square()
+                .array()
+        };
+
+        let r = t.trace().rotary_embedding(dev.tensor(positions));
+        let g = (r * w.clone()).sum().square().backward();
+        let grad = g.get(&t).array();
+
+        // the loss is quadratic, so central differences are exact up to rounding
+        let eps: TestDtype = 1e-2;
+        let a = t.array();
+        for b in 0..2 {
+            for s in 0..3 {
+                for h in 0..4 {
+                    let mut hi = a;
+                    hi[b][s][h] += eps;
+                    let mut lo = a;
+                    lo[b][s][h] -= eps;
+                    let fd = (loss(dev.tensor(hi)) - loss(dev.tensor(lo))) / (2.0 * eps);
+                    let v = grad[b][s][h];
+                    assert!(
+                        (v - fd).abs() < 1e-2 * fd.abs().max(1.0),
+                        "{b} {s} {h}: {v} vs {fd}"
+                    );
+                }
+            }
+        }
+    }
+}
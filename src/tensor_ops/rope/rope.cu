@@ -0,0 +1,98 @@
+#include "cuda_utils.cuh"
+
+struct RotaryEmbeddingOp {
+    size_t batch;
+    size_t seq;
+    size_t dim;
+};
+
+template<typename T>
+__device__ void rope_fwd(
+    const RotaryEmbeddingOp op,
+    const size_t *inp_strides,
+    const size_t *out_strides,
+    const T *inp, // 3d (Batch, Seq, Dim)
+    const T *sin, // 2d (Seq, Dim / 2)
+    const T *cos, // 2d (Seq, Dim / 2)
+    T *out // 3d (Batch, Seq, Dim)
+) {
+    unsigned int i = blockIdx.x * blockDim.x + threadIdx.x;
+    const size_t half = op.dim / 2;
+    if (i >= op.batch * op.seq * half) {
+        return;
+    }
+
+    unsigned int idx = i;
+    const size_t h = idx % half;
+    idx /= half;
+    const size_t s = idx % op.seq;
+    idx /= op.seq;
+    const size_t b = idx % op.batch;
+
+    const T sn = sin[s * half + h];
+    const T cs = cos[s * half + h];
+    const size_t x = b * inp_strides[0] + s * inp_strides[1] + 2 * h * inp_strides[2];
+    const T x0 = inp[x];
+    const T x1 = inp[x + inp_strides[2]];
+    const size_t o = b * out_strides[0] + s * out_strides[1] + 2 * h * out_strides[2];
+    out[o] = x0 * cs - x1 * sn;
+    out[o + out_strides[2]] = x0 * sn + x1 * cs;
+}
+
+template<typename T>
+__device__ void rope_bwd(
+    const RotaryEmbeddingOp op,
+    const size_t *inp_strides,
+    T *grad_inp, // 3d (Batch, Seq, Dim)
+    const T *sin, // 2d (Seq, Dim / 2)
+    const T *cos, // 2d (Seq, Dim / 2)
+    const T *grad_out // 3d (Batch, Seq, Dim), contiguous
+) {
+    unsigned int i = blockIdx.x * blockDim.x + threadIdx.x;
+    const size_t half = op.dim / 2;
+    if (i >= op.batch * op.seq * half) {
+        return;
+    }
+
+    unsigned int idx = i;
+    const size_t h = idx % half;
+    idx /= half;
+    const size_t s = idx % op.seq;
+    idx /= op.seq;
+    const size_t b = idx % op.batch;
+
+    const T sn = sin[s * half + h];
+    const T cs = cos[s * half + h];
+    const T g0 = grad_out[2 * i];
+    const T g1 = grad_out[2 * i + 1];
+    const size_t x = b * inp_strides[0] + s * inp_strides[1] + 2 * h * inp_strides[2];
+    // broadcasted inputs share elements, so the gradients need to be accumulated atomically
+    atomicAdd(grad_inp + x, g0 * cs + g1 * sn);
+    atomicAdd(grad_inp + x + inp_strides[2], g1 * cs - g0 * sn);
+}
+
+#define ROPE(TYPENAME, FWD, BWD) \
+extern "C" __global__ void FWD( \
+    const RotaryEmbeddingOp op, \
+    const size_t *inp_strides, \
+    const size_t *out_strides, \
+    const TYPENAME *inp, \
+    const TYPENAME *sin, \
+    const TYPENAME *cos, \
+    TYPENAME *out \
+) { \
+    rope_fwd(op, inp_strides, out_strides, inp, sin, cos, out); \
+} \
+extern "C" __global__ void BWD( \
+    const RotaryEmbeddingOp op, \
+    const size_t *inp_strides, \
+    TYPENAME *grad_inp, \
+    const TYPENAME *sin, \
+    const TYPENAME *cos, \
+    const TYPENAME *grad_out \
+) { \
+    rope_bwd(op, inp_strides, grad_inp, sin, cos, grad_out); \
+}
+
+ROPE(float, rope_fwd_f32, rope_bwd_f32);
+ROPE(double, rope_fwd_f64, rope_bwd_f64);
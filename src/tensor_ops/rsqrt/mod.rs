@@ -0,0 +1,67 @@
+mod cpu_kernel;
+
+#[cfg(feature = "cuda")]
+mod cuda_kernel;
+
+use super::ops::{try_unary_op, UnaryKernel};
+use crate::{gradients::Tape, shapes::*, tensor::Tensor};
+
+#[repr(C)]
+#[derive(Debug, Default, Copy, Clone)]
+pub struct RSqrtKernelOp;
+
+/// `1 / √t` or `t^-0.5`
+///
+/// The derivative is `-0.5 * t^-1.5`.
+///
+/// Examples:
+/// ```rust
+/// # use dfdx::prelude::*;
+/// # let dev: Cpu = Default::default();
+/// let t = dev.tensor([1.0, 4.0, 16.0]);
+/// let r = t.rsqrt();
+/// assert_eq!(r.array(), [1.0, 0.5, 0.25]);
+/// ```
+pub fn rsqrt<S: Shape, E: Dtype, D: UnaryKernel<RSqrtKernelOp, E>, T: Tape<E, D>>(
+    t: Tensor<S, E, D, T>,
+) -> Tensor<S, E, D, T> {
+    t.rsqrt()
+}
+
+impl<S: Shape, E: Dtype, D: UnaryKernel<RSqrtKernelOp, E>, T: Tape<E, D>> Tensor<S, E, D, T> {
+    /// See [rsqrt]
+    pub fn rsqrt(self) -> Self {
+        self.try_rsqrt().unwrap()
+    }
+    /// See [rsqrt]
+    pub fn try_rsqrt(self) -> Result<Self, D::Err> {
+        try_unary_op(RSqrtKernelOp, self)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{tensor::*, tensor_ops::*, tests::*};
+
+    #[test]
+    fn test_rsqrt() {
+        let dev: TestDevice = Default::default();
+        let x: Tensor<_, TestDtype, _> = dev.tensor([0.25, 1.0, 4.0]);
+        let r = x.trace().rsqrt();
+        assert_eq!(r.array(), [2.0, 1.0, 0.5]);
+        let g = r.sum().backward();
+        assert_eq!(g.get(&x).array(), [-4.0, -0.5, -0.0625]);
+    }
+
+    #[test]
+    fn test_rsqrt_matches_sqrt_recip() {
+        let dev: TestDevice = Default::default();
+        let x: Tensor<_, TestDtype, _> = dev.tensor([[0.5, 1.0, 2.0], [3.0, 4.0, 9.0]]);
+        let r = x.trace().rsqrt();
+        let r2 = x.trace().sqrt().powi(-1);
+        assert_close(&r.array(), &r2.array());
+        let g = r.exp().mean().backward();
+        let g2 = r2.exp().mean().backward();
+        assert_close(&g.get(&x).array(), &g2.get(&x).array());
+    }
+}
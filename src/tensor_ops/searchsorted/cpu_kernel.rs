@@ -0,0 +1,30 @@
+use crate::{
+    shapes::{Dim, Dtype, Shape},
+    tensor::{
+        cpu::{Cpu, LendingIterator},
+        DeviceStorage, Tensor, ZerosTensor,
+    },
+};
+
+use super::SearchSide;
+
+impl<E: Dtype> super::SearchSortedKernel<E> for Cpu {
+    fn forward<N: Dim, S: Shape, T1, T2>(
+        &self,
+        sorted: &Tensor<(N,), E, Self, T1>,
+        values: &Tensor<S, E, Self, T2>,
+        side: SearchSide,
+    ) -> Result<Tensor<S, usize, Self>, Self::Err> {
+        let sorted = self.tensor_to_vec(sorted);
+        let mut out: Tensor<S, usize, Self> = self.try_zeros_like(&values.shape)?;
+        let mut values_iter = values.iter();
+        let mut out_iter = out.iter_mut();
+        while let Some((o, v)) = out_iter.next().zip(values_iter.next()) {
+            *o = match side {
+                SearchSide::Left => sorted.partition_point(|s| s < v),
+                SearchSide::Right => sorted.partition_point(|s| s <= v),
+            };
+        }
+        Ok(out)
+    }
+}
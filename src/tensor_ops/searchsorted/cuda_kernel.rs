@@ -0,0 +1,65 @@
+use crate::{
+    shapes::*,
+    tensor::{Cuda, Tensor},
+};
+use cudarc::driver::{CudaSlice, LaunchAsync, LaunchConfig};
+
+use super::SearchSide;
+
+const PTX_SRC: &str = include_str!(concat!(env!("OUT_DIR"), "/searchsorted.ptx"));
+
+pub(crate) trait HasCudaKernel<E> {
+    const MOD: &'static str;
+    const FNS: &'static [&'static str];
+}
+
+impl HasCudaKernel<f32> for Cuda {
+    const MOD: &'static str = "searchsorted_f32";
+    const FNS: &'static [&'static str] = &["searchsorted_f32"];
+}
+
+impl HasCudaKernel<f64> for Cuda {
+    const MOD: &'static str = "searchsorted_f64";
+    const FNS: &'static [&'static str] = &["searchsorted_f64"];
+}
+
+impl<E: Dtype> super::SearchSortedKernel<E> for Cuda
+where
+    Self: HasCudaKernel<E>,
+{
+    fn forward<N: Dim, S: Shape, T1, T2>(
+        &self,
+        sorted: &Tensor<(N,), E, Self, T1>,
+        values: &Tensor<S, E, Self, T2>,
+        side: SearchSide,
+    ) -> Result<Tensor<S, usize, Self>, Self::Err> {
+        if !self.dev.has_func(Self::MOD, Self::FNS[0]) {
+            self.dev.load_ptx(PTX_SRC.into(), Self::MOD, Self::FNS)?;
+        }
+
+        let shape = values.shape;
+        let strides = shape.strides();
+        let numel = shape.num_elements();
+        let mut storage = self.dev.alloc_zeros::<usize>(numel)?;
+
+        let dims: CudaSlice<usize> = self.dev.htod_copy(shape.concrete().into())?;
+        let values_strides: CudaSlice<usize> = self.dev.htod_copy(values.strides.into())?;
+
+        let fwd_fn = self.dev.get_func(Self::MOD, Self::FNS[0]).unwrap();
+        let cfg = LaunchConfig::for_num_elems(numel as u32);
+        let params = (
+            numel,                     // const size_t numel,
+            sorted.shape.0.size(),     // const size_t num_sorted,
+            sorted.data.as_ref(),      // const float *sorted,
+            sorted.strides[0],         // const size_t sorted_stride,
+            S::NUM_DIMS,               // const size_t num_dims,
+            &dims,                     // const size_t *dims,
+            values.data.as_ref(),      // const float *values,
+            &values_strides,           // const size_t *values_strides,
+            side == SearchSide::Right, // const bool right,
+            &mut storage,              // size_t *out,
+        );
+        unsafe { fwd_fn.launch(cfg, params) }?;
+        Ok(self.build_tensor(shape, strides, storage))
+    }
+}
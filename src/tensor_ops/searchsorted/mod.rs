@@ -0,0 +1,108 @@
+mod cpu_kernel;
+
+#[cfg(feature = "cuda")]
+mod cuda_kernel;
+
+use crate::{
+    gradients::NoneTape,
+    shapes::{Dim, Dtype, Shape},
+    tensor::{DeviceStorage, Tensor},
+};
+
+/// Which index [searchsorted] returns when a value is equal to elements of the sorted tensor.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum SearchSide {
+    /// The index of the first equal element, i.e. the first `i` with `value <= sorted[i]`.
+    Left,
+    /// The index after the last equal element, i.e. the first `i` with `value < sorted[i]`.
+    Right,
+}
+
+pub trait SearchSortedKernel<E: Dtype>: DeviceStorage {
+    fn forward<N: Dim, S: Shape, T1, T2>(
+        &self,
+        sorted: &Tensor<(N,), E, Self, T1>,
+        values: &Tensor<S, E, Self, T2>,
+        side: SearchSide,
+    ) -> Result<Tensor<S, usize, Self>, Self::Err>;
+}
+
+/// Finds the indices into the 1d tensor `sorted` (sorted in ascending order) where each
+/// element of `values` would need to be inserted to keep `sorted` ordered. The result has
+/// the same shape as `values`, and each index is in the range `0..=sorted.len()`.
+///
+/// `side` decides where values that are equal to elements of `sorted` go, see [SearchSide].
+///
+/// This is not differentiable, so the result never has a tape.
+///
+/// **Pytorch equivalent**: `torch.searchsorted(sorted, values, side=side)`
+///
+/// Examples:
+/// ```rust
+/// # use dfdx::prelude::*;
+/// # let dev: Cpu = Default::default();
+/// let sorted = dev.tensor([1.0, 3.0, 5.0, 7.0]);
+/// let values = dev.tensor([[0.0, 3.0], [6.0, 9.0]]);
+/// let r = searchsorted(&sorted, &values, SearchSide::Left);
+/// assert_eq!(r.array(), [[0, 1], [3, 4]]);
+/// let r = sorted.searchsorted(&values, SearchSide::Right);
+/// assert_eq!(r.array(), [[0, 2], [3, 4]]);
+/// ```
+pub fn searchsorted<N: Dim, S: Shape, E: Dtype, D: SearchSortedKernel<E>, T1, T2>(
+    sorted: &Tensor<(N,), E, D, T1>,
+    values: &Tensor<S, E, D, T2>,
+    side: SearchSide,
+) -> Tensor<S, usize, D, NoneTape> {
+    sorted.searchsorted(values, side)
+}
+
+impl<N: Dim, E: Dtype, D: SearchSortedKernel<E>, T> Tensor<(N,), E, D, T> {
+    /// See [searchsorted]
+    pub fn searchsorted<S: Shape, T2>(
+        &self,
+        values: &Tensor<S, E, D, T2>,
+        side: SearchSide,
+    ) -> Tensor<S, usize, D, NoneTape> {
+        self.try_searchsorted(values, side).unwrap()
+    }
+
+    /// Fallible version of [Tensor::searchsorted]
+    pub fn try_searchsorted<S: Shape, T2>(
+        &self,
+        values: &Tensor<S, E, D, T2>,
+        side: SearchSide,
+    ) -> Result<Tensor<S, usize, D, NoneTape>, D::Err> {
+        self.device.forward(self, values, side)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{shapes::*, tensor::*, tests::*};
+
+    #[test]
+    fn test_searchsorted_sides() {
+        let dev: TestDevice = Default::default();
+        let sorted: Tensor<_, TestDtype, _> =
+            dev.tensor([-1.0, 0.0, 0.0, 2.5, 4.0, 4.0, 4.0, 10.0]);
+        let values: Tensor<_, TestDtype, _> =
+            dev.tensor([[-3.0, -1.0, 0.0, 1.0], [4.0, 5.0, 10.0, 11.0]]);
+
+        let r = searchsorted(&sorted, &values, SearchSide::Left);
+        assert_eq!(r.array(), [[0, 0, 1, 3], [4, 7, 7, 8]]);
+
+        let r = searchsorted(&sorted, &values, SearchSide::Right);
+        assert_eq!(r.array(), [[0, 1, 3, 3], [7, 7, 8, 8]]);
+    }
+
+    #[test]
+    fn test_searchsorted_runtime_len() {
+        let dev: TestDevice = Default::default();
+        let sorted: Tensor<(usize,), TestDtype, _> =
+            dev.tensor_from_vec(std::vec![0.0, 1.0, 2.0], (3,));
+        let values: Tensor<Rank1<4>, TestDtype, _> = dev.tensor([2.0, 0.5, -1.0, 3.0]);
+        let r = sorted.searchsorted(&values, SearchSide::Right);
+        assert_eq!(r.array(), [3, 1, 0, 3]);
+    }
+}
@@ -0,0 +1,57 @@
+#include "cuda_utils.cuh"
+
+// Each thread binary searches `sorted` for a single value.
+template<typename T>
+__device__ void searchsorted(
+    const size_t numel,
+    const size_t num_sorted,
+    const T *sorted,
+    const size_t sorted_stride,
+    const size_t num_dims,
+    const size_t *dims,
+    const T *values,
+    const size_t *values_strides,
+    const bool right,
+    size_t *out
+) {
+    unsigned int i = blockIdx.x * blockDim.x + threadIdx.x;
+    if (i >= numel) {
+        return;
+    }
+
+    T value = values[get_strided_index(i, num_dims, dims, values_strides)];
+
+    // first index `lo` where `sorted[lo] >= value` (left) or `sorted[lo] > value` (right)
+    size_t lo = 0;
+    size_t hi = num_sorted;
+    while (lo < hi) {
+        size_t mid = lo + (hi - lo) / 2;
+        T s = sorted[mid * sorted_stride];
+        bool go_right = right ? s <= value : s < value;
+        if (go_right) {
+            lo = mid + 1;
+        } else {
+            hi = mid;
+        }
+    }
+    out[i] = lo;
+}
+
+#define SEARCHSORTED(TYPENAME, FN) \
+extern "C" __global__ void FN( \
+    const size_t numel, \
+    const size_t num_sorted, \
+    const TYPENAME *sorted, \
+    const size_t sorted_stride, \
+    const size_t num_dims, \
+    const size_t *dims, \
+    const TYPENAME *values, \
+    const size_t *values_strides, \
+    const bool right, \
+    size_t *out \
+) { \
+    searchsorted(numel, num_sorted, sorted, sorted_stride, num_dims, dims, values, values_strides, right, out); \
+}
+
+SEARCHSORTED(float, searchsorted_f32);
+SEARCHSORTED(double, searchsorted_f64);
@@ -1,6 +1,6 @@
-use crate::tensor_ops::cpu_kernels::UnaryDerivative;
+use crate::tensor_ops::{cpu_kernels::UnaryDerivative, utilities::cpu_simd::FloatSlice};
 
-impl<F: num_traits::Float> UnaryDerivative<F> for super::SigmoidKernelOp {
+impl<F: FloatSlice> UnaryDerivative<F> for super::SigmoidKernelOp {
     #[inline(always)]
     fn f(&self, x: &F) -> F {
         F::one() / (F::one() + x.neg().exp())
@@ -10,4 +10,8 @@ impl<F: num_traits::Float> UnaryDerivative<F> for super::SigmoidKernelOp {
         let fx = self.f(x);
         fx * (F::one() - fx)
     }
+    #[inline(always)]
+    fn f_slice(&self, buf: &mut [F]) {
+        F::sigmoid_slice(buf)
+    }
 }
@@ -3,7 +3,7 @@ mod cpu_kernel;
 #[cfg(feature = "cuda")]
 mod cuda_kernel;
 
-use super::ops::{try_unary_op, UnaryKernel};
+use super::ops::{try_unary_op, try_unary_op_inplace, UnaryKernel};
 use crate::{gradients::Tape, shapes::*, tensor::Tensor};
 
 #[repr(C)]
@@ -36,6 +36,16 @@ impl<S: Shape, E: Dtype, D: UnaryKernel<SigmoidKernelOp, E>, T: Tape<E, D>> Tens
     pub fn try_sigmoid(self) -> Result<Self, D::Err> {
         try_unary_op(SigmoidKernelOp, self)
     }
+
+    /// In place version of [sigmoid]. Overwrites the storage of `self` if it has no tape and
+    /// isn't shared with another tensor, otherwise this is the same as [sigmoid].
+    pub fn sigmoid_(self) -> Self {
+        self.try_sigmoid_().unwrap()
+    }
+    /// See [Tensor::sigmoid_]
+    pub fn try_sigmoid_(self) -> Result<Self, D::Err> {
+        try_unary_op_inplace(SigmoidKernelOp, self)
+    }
 }
 
 #[cfg(test)]
@@ -57,4 +67,20 @@ mod tests {
             &[0.020998716, 0.039322387, 0.05, 0.039322387, 0.020998726],
         );
     }
+
+    #[test]
+    fn test_sigmoid_inplace() {
+        let dev: TestDevice = Default::default();
+        let x: Tensor<_, TestDtype, _> = dev.tensor([-2.0, -1.0, 0.0, 1.0, 2.0]);
+        let expected = [0.11920292, 0.26894143, 0.5, 0.7310586, 0.880797];
+
+        let r = x.clone().sigmoid_();
+        assert_close(&r.array(), &expected);
+        assert_eq!(x.array(), [-2.0, -1.0, 0.0, 1.0, 2.0]);
+
+        let ptr = std::sync::Arc::as_ptr(&x.data);
+        let r = x.sigmoid_();
+        assert_close(&r.array(), &expected);
+        assert_eq!(std::sync::Arc::as_ptr(&r.data), ptr);
+    }
 }
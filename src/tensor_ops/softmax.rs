@@ -6,6 +6,9 @@ use crate::{gradients::Tape, shapes::*, tensor::Tensor};
 ///
 /// Equivalent to `exp(log_softmax(t))`.
 ///
+/// Like [super::LogSumExpTo::logsumexp], a NaN anywhere along `Ax` makes every value along
+/// `Ax` NaN.
+///
 /// **Pytorch equivalent**: `t.softmax(Axes)`
 ///
 /// Example:
@@ -16,12 +16,9 @@ impl<E: Dtype> super::StackKernel<E> for Cpu {
         S: super::AddDim<Num>,
     {
         debug_assert_eq!(inp.len(), num.size());
+        super::check_stack_inputs(inp)?;
 
-        // check that all the strides are the same
         let item_strides = inp[0].strides;
-        for i in inp.iter() {
-            assert_eq!(i.strides, item_strides);
-        }
         let shape: S::Larger = inp[0].shape().add_dim(num);
 
         // build the new strides
@@ -44,6 +41,7 @@ impl<E: Dtype> super::StackKernel<E> for Cpu {
             shape,
             strides,
             device: self.clone(),
+            param_opts: Default::default(),
             tape: Default::default(),
         })
     }
@@ -52,11 +50,29 @@ impl<E: Dtype> super::StackKernel<E> for Cpu {
         mut grad_inp: Vec<&mut Self::Vec<E>>,
         grad_out: &Self::Vec<E>,
     ) -> Result<(), Self::Err> {
-        let mut offset = 0;
-        for item in grad_inp.drain(..) {
-            for gi in item.iter_mut() {
-                *gi += grad_out[offset];
-                offset += 1;
+        // all the inputs have the same number of elements, so the gradient of
+        // the i'th input is the i'th slice of that length in grad_out
+        let add_item = |i: usize, item: &mut Self::Vec<E>| {
+            let item_numel = item.len();
+            let src = &grad_out[i * item_numel..(i + 1) * item_numel];
+            for (gi, &go) in item.iter_mut().zip(src.iter()) {
+                *gi += go;
+            }
+        };
+
+        #[cfg(feature = "rayon")]
+        {
+            use rayon::prelude::*;
+            grad_inp
+                .par_iter_mut()
+                .enumerate()
+                .for_each(|(i, item)| add_item(i, item));
+        }
+
+        #[cfg(not(feature = "rayon"))]
+        {
+            for (i, item) in grad_inp.iter_mut().enumerate() {
+                add_item(i, item);
             }
         }
         Ok(())
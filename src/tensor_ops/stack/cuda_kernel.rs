@@ -35,12 +35,9 @@ where
         S: super::AddDim<Num>,
     {
         debug_assert_eq!(inps.len(), num.size());
+        super::check_stack_inputs(inps)?;
 
-        // check that all the strides are the same
         let item_strides = inps[0].strides;
-        for i in inps.iter() {
-            assert_eq!(i.strides, item_strides);
-        }
         let shape: S::Larger = inps[0].shape().add_dim(num);
 
         // build the new strides
@@ -36,6 +36,9 @@ pub trait TryStack<E: Dtype>: DeviceStorage {
     /// let b: Tensor<Rank2<3, 4>, f32, _> = dev.zeros();
     /// let _: Tensor<(usize, Const<3>, Const<4>), f32, _> = dev.stack(vec![a, b]);
     /// ```
+    ///
+    /// **Panics** if there are no tensors, or they don't all have the same shape and
+    /// strides. Use [TryStack::try_stack] to get a [StackError] instead.
     fn stack<S: Shape, T, Items>(&self, items: Items) -> Tensor<S::Larger, E, Self, T>
     where
         Items: Array<Tensor<S, E, Self, T>>,
@@ -45,7 +48,15 @@ pub trait TryStack<E: Dtype>: DeviceStorage {
         self.try_stack(items).unwrap()
     }
 
-    /// Fallible version of [TryStack::stack]
+    /// Fallible version of [TryStack::stack]. Invalid inputs are returned as a
+    /// [StackError] inside `Self::Err`.
+    ///
+    /// ```rust
+    /// # use dfdx::prelude::*;
+    /// # let dev: Cpu = Default::default();
+    /// let items: Vec<Tensor<Rank1<3>, f32, _>> = Vec::new();
+    /// assert!(dev.try_stack(items).is_err());
+    /// ```
     fn try_stack<S: Shape, T, Items>(
         &self,
         items: Items,
@@ -56,6 +67,47 @@ pub trait TryStack<E: Dtype>: DeviceStorage {
         T: Tape<E, Self> + Merge<T>;
 }
 
+/// Reasons [TryStack::try_stack] can fail.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum StackError {
+    /// No tensors were given
+    Empty,
+    /// The tensors don't all have the same shape
+    ShapeMismatch,
+    /// The tensors don't all have the same strides, e.g. only some are broadcasted
+    StridesMismatch,
+}
+
+impl std::fmt::Display for StackError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::Empty => f.write_str("StackError::Empty"),
+            Self::ShapeMismatch => f.write_str("StackError::ShapeMismatch"),
+            Self::StridesMismatch => f.write_str("StackError::StridesMismatch"),
+        }
+    }
+}
+
+#[cfg(feature = "std")]
+impl std::error::Error for StackError {}
+
+/// Checks that there is at least one tensor, and that they all have the same
+/// shape and strides. Used by the kernels before stacking.
+pub(super) fn check_stack_inputs<S: Shape, E: Unit, D: DeviceStorage>(
+    inps: &[Tensor<S, E, D>],
+) -> Result<(), StackError> {
+    let first = inps.first().ok_or(StackError::Empty)?;
+    for t in inps.iter() {
+        if t.shape() != first.shape() {
+            return Err(StackError::ShapeMismatch);
+        }
+        if t.strides != first.strides {
+            return Err(StackError::StridesMismatch);
+        }
+    }
+    Ok(())
+}
+
 pub trait AddDim<D: Dim>: Shape {
     type Larger: Shape;
     fn add_dim(&self, dim: D) -> Self::Larger;
@@ -118,7 +170,6 @@ impl<E: Dtype, D: StackKernel<E>> TryStack<E> for D {
         T: Tape<E, Self> + Merge<T>,
     {
         let new_dim = items.dim();
-        assert!(new_dim.size() > 0);
 
         // need to split tape and transform into Vec for ease of implementation
         let mut tensors = Vec::with_capacity(new_dim.size());
@@ -129,20 +180,17 @@ impl<E: Dtype, D: StackKernel<E>> TryStack<E> for D {
             tensors.push(item);
         }
 
-        // check that all the shapes are equal
-        let device = tensors[0].device.clone();
-        let shape = *tensors[0].shape();
+        // the kernel checks that there are tensors and their shapes are equal
+        let out = self.forward(new_dim, &tensors)?;
         for t in tensors.iter() {
-            assert_eq!(t.shape(), &shape);
             tape.try_alloc_grad(t)?;
         }
 
-        // we map to storage refs so kernels don't have to know about tensors
-        let out = device.forward(new_dim, &tensors)?;
+        let device = self.clone();
 
         let phantom_out = out.clone();
         tape.try_alloc_grad(&out)?;
-        tape.add_backward_op(move |grads| {
+        tape.add_reusable_backward_op(move |grads| {
             let (grad_inp, grad_out) = grads.many_and_ref(&tensors, &phantom_out);
             device.backward(grad_inp, grad_out)?;
             Ok(())
@@ -201,6 +249,43 @@ mod tests {
         let _ = dev.stack([x, y.broadcast()]);
     }
 
+    /// The [StackError] inside of a [TestDevice] error, if there is one.
+    fn stack_err<T>(r: Result<T, <TestDevice as HasErr>::Err>) -> Option<StackError> {
+        match r {
+            #[cfg(not(feature = "test-cuda"))]
+            Err(crate::tensor::CpuError::Stack(e)) => Some(e),
+            #[cfg(feature = "test-cuda")]
+            Err(crate::tensor::CudaError::Cpu(crate::tensor::CpuError::Stack(e))) => Some(e),
+            _ => None,
+        }
+    }
+
+    #[test]
+    fn test_try_stack_errors() {
+        let dev: TestDevice = Default::default();
+
+        let items: std::vec::Vec<Tensor<Rank1<3>, TestDtype, _>> = std::vec::Vec::new();
+        assert!(matches!(
+            stack_err(dev.try_stack(items)),
+            Some(StackError::Empty)
+        ));
+
+        let x: Tensor<_, TestDtype, _> = dev.sample_like(&(2, 3), rand_distr::StandardNormal);
+        let y: Tensor<_, TestDtype, _> = dev.sample_like(&(3, 4), rand_distr::StandardNormal);
+        assert!(matches!(
+            stack_err(dev.try_stack([x.trace(), y.trace()])),
+            Some(StackError::ShapeMismatch)
+        ));
+
+        let x: Tensor<Rank2<2, 3>, TestDtype, _> = dev.sample_normal();
+        let y: Tensor<Rank1<3>, TestDtype, _> = dev.sample_normal();
+        assert!(matches!(
+            stack_err(dev.try_stack([x.clone(), y.broadcast()])),
+            Some(StackError::StridesMismatch)
+        ));
+        assert!(dev.try_stack([x.clone(), x]).is_ok());
+    }
+
     #[test]
     fn test_stack_with_all_broadcasted() {
         let dev: TestDevice = Default::default();
@@ -234,4 +319,19 @@ mod tests {
         assert_eq!(r_grad[1], g.get(&y).array());
         assert_eq!(r_grad[2], g.get(&z).array());
     }
+
+    #[test]
+    fn test_stack_many_backwards() {
+        let dev: TestDevice = Default::default();
+        let xs: std::vec::Vec<Tensor<Rank2<8, 16>, TestDtype, _>> =
+            (0..64).map(|_| dev.sample_normal()).collect();
+        let r = dev.stack(xs.iter().map(|x| x.trace()).collect::<std::vec::Vec<_>>());
+        let r1 = r.retaped::<NoneTape>();
+        let g1 = r1.trace().exp().mean().backward();
+        let g = r.exp().mean().backward();
+        let r_grad = g1.get(&r1).as_vec();
+        for (i, x) in xs.iter().enumerate() {
+            assert_eq!(g.get(x).as_vec(), r_grad[i * 128..(i + 1) * 128]);
+        }
+    }
 }
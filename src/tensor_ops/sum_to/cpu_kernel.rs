@@ -1,7 +1,7 @@
 use crate::{
     shapes::{Axes, Dtype, HasAxes, ReduceShapeTo, Shape},
     tensor::{Cpu, Tensor, ZerosTensor},
-    tensor_ops::utilities::reduction_utils::index_for_reductions,
+    tensor_ops::utilities::reduction_utils::{index_for_reductions, reduce_buf, reduce_into},
 };
 
 impl<E: Dtype> super::SumKernel<E> for Cpu {
@@ -17,22 +17,11 @@ impl<E: Dtype> super::SumKernel<E> for Cpu {
         if Dst::NUM_DIMS == 0 {
             debug_assert_eq!(out.data.len(), 1);
             let scale = E::from_usize(inp.shape.num_elements() / inp.data.len()).unwrap();
-            let mut tmp: E = Default::default();
-            for v in inp.buf_iter() {
-                tmp += *v;
-            }
+            let tmp = reduce_buf(inp.data.as_ref(), Default::default(), |a, b| a + b);
             std::sync::Arc::get_mut(&mut out.data).unwrap()[0] = tmp * scale;
         } else {
-            let num_elems_reduced = <Src as HasAxes<Ax>>::size(&inp.shape);
-            let inp_buf = inp.data.as_ref();
-            let mut idx = index_for_reductions::<Src, Ax>(inp.shape, inp.strides);
-            for o in out.buf_iter_mut() {
-                let mut tmp: E = Default::default();
-                for _ in 0..num_elems_reduced {
-                    tmp += inp_buf[idx.next().unwrap()];
-                }
-                *o = tmp;
-            }
+            let out_buf = std::sync::Arc::get_mut(&mut out.data).unwrap();
+            reduce_into::<Src, Ax, E, _>(out_buf, inp, Default::default(), |a, b| a + b);
         }
         Ok(out)
     }
@@ -10,6 +10,21 @@ use cudarc::driver::{
 
 const PTX_SRC: &str = include_str!(concat!(env!("OUT_DIR"), "/sum_to.ptx"));
 
+/// Inputs with more physical elements than this are summed with the segmented
+/// kernels, which don't rely on a single launch covering every element and
+/// accumulate in f64.
+const SEGMENTED_THRESHOLD: usize = 1 << 22;
+
+/// Number of elements summed by each block of the segmented kernel.
+const SEGMENT_LEN: usize = 1 << 14;
+
+/// Must match `SEGMENT_BLOCK_SIZE` in sum_to.cu
+const SEGMENT_BLOCK_SIZE: u32 = 256;
+
+/// Upper bound on the number of blocks launched at once, blocks loop over
+/// segments if there are more.
+const MAX_GRID_DIM: usize = 65535;
+
 trait HasCudaKernel<E> {
     const MOD: &'static str;
     const FNS: &'static [&'static str];
@@ -17,12 +32,22 @@ trait HasCudaKernel<E> {
 
 impl HasCudaKernel<f32> for Cuda {
     const MOD: &'static str = "sum_f32";
-    const FNS: &'static [&'static str] = &["sum_to_fwd_f32", "sum_to_bwd_f32"];
+    const FNS: &'static [&'static str] = &[
+        "sum_to_fwd_f32",
+        "sum_to_bwd_f32",
+        "sum_segments_f32",
+        "sum_partials_f32",
+    ];
 }
 
 impl HasCudaKernel<f64> for Cuda {
     const MOD: &'static str = "sum_f64";
-    const FNS: &'static [&'static str] = &["sum_to_fwd_f64", "sum_to_bwd_f64"];
+    const FNS: &'static [&'static str] = &[
+        "sum_to_fwd_f64",
+        "sum_to_bwd_f64",
+        "sum_segments_f64",
+        "sum_partials_f64",
+    ];
 }
 
 impl<E: Dtype + ValidAsZeroBits + DeviceRepr> super::SumKernel<E> for Cuda
@@ -61,6 +86,46 @@ where
             reduction_output_strides::<Ax, Src, Dst>(inp.strides, dst);
         let chunk_len = physical_numel / dst_physical_numel;
 
+        if physical_numel > SEGMENTED_THRESHOLD {
+            // pass 1: sum segments of each chunk into f64 partials
+            let segs_per_chunk =
+                chunk_len / SEGMENT_LEN + usize::from(chunk_len % SEGMENT_LEN != 0);
+            let num_segments = dst_physical_numel * segs_per_chunk;
+            let mut partials = self.dev.alloc_zeros::<f64>(num_segments)?;
+            let seg_fn = self.dev.get_func(Self::MOD, Self::FNS[2]).unwrap();
+            let cfg = LaunchConfig {
+                grid_dim: (num_segments.min(MAX_GRID_DIM) as u32, 1, 1),
+                block_dim: (SEGMENT_BLOCK_SIZE, 1, 1),
+                shared_mem_bytes: 0,
+            };
+            let params = (
+                num_segments,      // const size_t num_segments,
+                segs_per_chunk,    // const size_t segs_per_chunk,
+                SEGMENT_LEN,       // const size_t seg_len,
+                chunk_len,         // const size_t chunk_len,
+                num_dims,          // const size_t num_dims,
+                inp.data.as_ref(), // const float *inp,
+                &dims,             // const size_t *dims,
+                &strides,          // const size_t *strides,
+                &mut partials,     // double *partials
+            );
+            unsafe { seg_fn.launch(cfg, params) }?;
+
+            // pass 2: combine the partials of each chunk
+            let partials_fn = self.dev.get_func(Self::MOD, Self::FNS[3]).unwrap();
+            let mut cfg = LaunchConfig::for_num_elems(dst_physical_numel as u32);
+            cfg.grid_dim.0 = cfg.grid_dim.0.min(MAX_GRID_DIM as u32);
+            let params = (
+                dst_physical_numel, // const size_t numel,
+                segs_per_chunk,     // const size_t segs_per_chunk,
+                elems_per_thread,   // const float elems_per_thread,
+                &partials,          // const double *partials,
+                &mut storage,       // float *out
+            );
+            unsafe { partials_fn.launch(cfg, params) }?;
+            return Ok(self.build_tensor(dst, dst_strides, storage));
+        }
+
         let cfg = LaunchConfig::for_num_elems(physical_numel as u32);
         let params = (
             physical_numel,    // const size_t numel,
@@ -68,7 +68,7 @@ impl<S: Shape, E: Dtype, D: SumKernel<E>, T: Tape<E, D>> SumTo for Tensor<S, E,
         let phantom_out = out.clone();
         tape.try_alloc_grad(&inp)?;
         tape.try_alloc_grad(&out)?;
-        tape.add_backward_op(move |grads| {
+        tape.add_reusable_backward_op(move |grads| {
             let (grad_inp, grad_out) = grads.mut_and_ref(&inp, &phantom_out);
             inp.device.backward(&inp, grad_inp, &phantom_out, grad_out)
         });
@@ -172,4 +172,45 @@ mod tests {
         let g = c.backward();
         assert_eq!(g.get(&a).array(), [8.0; 3]);
     }
+
+    #[test]
+    fn test_sum_large_matches_serial() {
+        let dev: TestDevice = Default::default();
+        let t: Tensor<Rank3<32, 300, 17>, TestDtype, _> = dev.sample_normal();
+        // `as_vec` keeps the input off the stack, which `array` would overflow for f64
+        let v = t.as_vec();
+
+        let r = t.clone().sum::<Rank2<32, 17>, _>().array();
+        for i in 0..32 {
+            for k in 0..17 {
+                let mut expected = 0.0;
+                for j in 0..300 {
+                    expected += v[(i * 300 + j) * 17 + k];
+                }
+                assert_eq!(r[i][k], expected);
+            }
+        }
+
+        let expected: TestDtype = t.as_vec().iter().sum();
+        assert!((t.sum::<Rank0, _>().array() - expected).abs() < 1e-2);
+    }
+
+    #[test]
+    fn test_sum_huge_matches_cpu() {
+        let dev: TestDevice = Default::default();
+        let cpu: crate::tensor::Cpu = Default::default();
+        let t: Tensor<(Const<2>, usize), TestDtype, _> =
+            dev.sample_uniform_like(&(Const, 2_500_000));
+        let t_cpu = t.to_device(&cpu);
+
+        let r = t.clone().sum::<Rank1<2>, _>().array();
+        let r_cpu = t_cpu.clone().sum::<Rank1<2>, _>().array();
+        for (a, b) in r.iter().zip(r_cpu.iter()) {
+            assert!((a - b).abs() <= 1e-5 * b, "{a} vs {b}");
+        }
+
+        let r = t.sum::<Rank0, _>().array();
+        let r_cpu = t_cpu.sum::<Rank0, _>().array();
+        assert!((r - r_cpu).abs() <= 1e-5 * r_cpu, "{r} vs {r_cpu}");
+    }
 }
@@ -97,7 +97,82 @@ __device__ void sum_to_bwd(
     grad_inp[inp_i] += tmp * elems_per_thread;
 }
 
-#define SUM(TYPENAME, FWD, BWD) \
+// Number of threads per block for sum_segments, which sizes its shared buffer.
+#define SEGMENT_BLOCK_SIZE 256
+
+// Used instead of sum_to_fwd for large inputs. Each chunk of chunk_len elements is
+// split into segments of seg_len elements, and each block sums whole segments,
+// accumulating in double precision. The partial sum of segment s is stored
+// in partials[s], and sum_partials combines them. All indexing is done with
+// size_t so this works for inputs with more than 2^32 elements.
+template<typename T>
+__device__ void sum_segments(
+    const size_t num_segments,
+    const size_t segs_per_chunk,
+    const size_t seg_len,
+    const size_t chunk_len,
+    const size_t num_dims,
+    const T *inp,
+    const size_t *dims,
+    const size_t *strides,
+    double *partials
+) {
+    __shared__ double buf[SEGMENT_BLOCK_SIZE];
+
+    // grid stride loop, so the grid can be smaller than the number of segments
+    for (size_t s = blockIdx.x; s < num_segments; s += gridDim.x) {
+        const size_t chunk = s / segs_per_chunk;
+        const size_t seg_start = (s % segs_per_chunk) * seg_len;
+        const size_t seg_end = seg_start + seg_len < chunk_len ? seg_start + seg_len : chunk_len;
+
+        double acc = 0.0;
+        for (size_t j = seg_start + threadIdx.x; j < seg_end; j += blockDim.x) {
+            size_t idx = chunk * chunk_len + j;
+            size_t inp_i = 0;
+            for (size_t d = 0; d < num_dims; d++) {
+                size_t dim_idx = num_dims - 1 - d;
+                inp_i += (idx % dims[dim_idx]) * strides[dim_idx];
+                idx /= dims[dim_idx];
+            }
+            acc += inp[inp_i];
+        }
+        buf[threadIdx.x] = acc;
+        __syncthreads();
+
+        for (unsigned int incr = blockDim.x >> 1; incr > 0; incr >>= 1) {
+            if (threadIdx.x < incr) {
+                buf[threadIdx.x] += buf[threadIdx.x + incr];
+            }
+            __syncthreads();
+        }
+
+        if (threadIdx.x == 0) {
+            partials[s] = buf[0];
+        }
+        // buf is reused by the next segment
+        __syncthreads();
+    }
+}
+
+// Sums the segs_per_chunk partial sums of each chunk into out.
+template<typename T>
+__device__ void sum_partials(
+    const size_t numel,
+    const size_t segs_per_chunk,
+    const T elems_per_thread,
+    const double *partials,
+    T *out
+) {
+    for (size_t i = blockIdx.x * blockDim.x + threadIdx.x; i < numel; i += blockDim.x * gridDim.x) {
+        double acc = 0.0;
+        for (size_t s = 0; s < segs_per_chunk; s++) {
+            acc += partials[i * segs_per_chunk + s];
+        }
+        out[i] = acc * elems_per_thread;
+    }
+}
+
+#define SUM(TYPENAME, FWD, BWD, SEGMENTS, PARTIALS) \
 extern "C" __global__ void FWD( \
     const size_t numel, \
     const size_t num_dims, \
@@ -121,7 +196,29 @@ extern "C" __global__ void BWD( \
     const size_t *out_strides \
 ) { \
     sum_to_bwd(numel, num_dims, elems_per_thread, dims, grad_inp, inp_strides, grad_out, out_strides); \
+} \
+extern "C" __global__ void SEGMENTS( \
+    const size_t num_segments, \
+    const size_t segs_per_chunk, \
+    const size_t seg_len, \
+    const size_t chunk_len, \
+    const size_t num_dims, \
+    const TYPENAME *inp, \
+    const size_t *dims, \
+    const size_t *strides, \
+    double *partials \
+) { \
+    sum_segments(num_segments, segs_per_chunk, seg_len, chunk_len, num_dims, inp, dims, strides, partials); \
+} \
+extern "C" __global__ void PARTIALS( \
+    const size_t numel, \
+    const size_t segs_per_chunk, \
+    const TYPENAME elems_per_thread, \
+    const double *partials, \
+    TYPENAME *out \
+) { \
+    sum_partials(numel, segs_per_chunk, elems_per_thread, partials, out); \
 }
 
-SUM(float, sum_to_fwd_f32, sum_to_bwd_f32);
-SUM(double, sum_to_fwd_f64, sum_to_bwd_f64);
+SUM(float, sum_to_fwd_f32, sum_to_bwd_f32, sum_segments_f32, sum_partials_f32);
+SUM(double, sum_to_fwd_f64, sum_to_bwd_f64, sum_segments_f64, sum_partials_f64);
@@ -0,0 +1,41 @@
+use crate::shapes::{Dtype, Shape};
+use crate::tensor::{
+    cpu::{index_to_i, LendingIterator, NdIndex},
+    Cpu, Tensor, ZerosTensor,
+};
+
+impl<E: Dtype> super::TakeAlongKernel<E> for Cpu {
+    fn forward<Src: Shape, Idx: Shape<Concrete = Src::Concrete>>(
+        &self,
+        ax: usize,
+        inp: &Tensor<Src, E, Self>,
+        idx: &Tensor<Idx, usize, Self>,
+    ) -> Result<Tensor<Idx, E, Self>, Self::Err> {
+        let mut out = self.try_zeros_like(&idx.shape)?;
+        let mut out_iter = out.iter_mut_with_index();
+        while let Some((x, i_out)) = out_iter.next() {
+            let mut i_inp = i_out;
+            i_inp[ax] = idx[i_out];
+            *x = inp[i_inp];
+        }
+        Ok(out)
+    }
+
+    fn backward<Src: Shape, Idx: Shape<Concrete = Src::Concrete>>(
+        &self,
+        ax: usize,
+        inp: &Tensor<Src, E, Self>,
+        grad_inp: &mut Self::Vec<E>,
+        idx: &Tensor<Idx, usize, Self>,
+        out: &Tensor<Idx, E, Self>,
+        grad_out: &Self::Vec<E>,
+    ) -> Result<(), Self::Err> {
+        let mut out_idx = NdIndex::new(out.shape, out.strides);
+        while let Some((i_out, i_idx)) = out_idx.next_with_idx() {
+            let mut i_inp = i_idx;
+            i_inp[ax] = idx[i_idx];
+            grad_inp[index_to_i(&inp.shape, &inp.strides, i_inp)] += grad_out[i_out];
+        }
+        Ok(())
+    }
+}
@@ -0,0 +1,97 @@
+use crate::{
+    shapes::Shape,
+    tensor::{Cuda, Tensor},
+};
+use cudarc::driver::{DeviceSlice, LaunchAsync, LaunchConfig};
+
+const PTX_SRC: &str = include_str!(concat!(env!("OUT_DIR"), "/take_along.ptx"));
+
+macro_rules! impl_cuda_kernels {
+    ($TypeName:ty, $Mod:tt, $Fwd:tt, $Bwd:tt) => {
+        impl super::TakeAlongKernel<$TypeName> for Cuda {
+            fn forward<Src: Shape, Idx: Shape<Concrete = Src::Concrete>>(
+                &self,
+                ax: usize,
+                inp: &Tensor<Src, $TypeName, Self>,
+                idx: &Tensor<Idx, usize, Self>,
+            ) -> Result<Tensor<Idx, $TypeName, Self>, Self::Err> {
+                if !self.dev.has_func($Mod, $Fwd) {
+                    self.dev.load_ptx(PTX_SRC.into(), $Mod, &[$Fwd, $Bwd])?;
+                }
+
+                let numel = idx.shape.num_elements();
+                let mut storage = self.dev.alloc_zeros::<$TypeName>(numel)?;
+
+                let inp_dims = self.dev.htod_copy(inp.shape.concrete().into())?;
+                let idx_dims = self.dev.htod_copy(idx.shape.concrete().into())?;
+                let inp_strides = self.dev.htod_copy(inp.strides.into())?;
+                let idx_strides = self.dev.htod_copy(idx.strides.into())?;
+
+                let fwd_fn = self.dev.get_func($Mod, $Fwd).unwrap();
+                let cfg = LaunchConfig::for_num_elems(numel as u32);
+                let params = (
+                    numel,             // const size_t numel,
+                    inp.data.as_ref(), // const float *inp,
+                    Src::NUM_DIMS,     // const size_t num_dims,
+                    ax,                // const size_t ax,
+                    &inp_dims,         // const size_t *inp_dims,
+                    &inp_strides,      // const size_t *inp_strides,
+                    idx.data.as_ref(), // const size_t *idx,
+                    &idx_dims,         // const size_t *idx_dims,
+                    &idx_strides,      // const size_t *idx_strides,
+                    &mut storage,      // float *out,
+                );
+                unsafe { fwd_fn.launch(cfg, params) }?;
+
+                Ok(self.build_tensor(idx.shape, idx.shape.strides(), storage))
+            }
+
+            fn backward<Src: Shape, Idx: Shape<Concrete = Src::Concrete>>(
+                &self,
+                ax: usize,
+                inp: &Tensor<Src, $TypeName, Self>,
+                grad_inp: &mut Self::Vec<$TypeName>,
+                idx: &Tensor<Idx, usize, Self>,
+                _: &Tensor<Idx, $TypeName, Self>,
+                grad_out: &Self::Vec<$TypeName>,
+            ) -> Result<(), Self::Err> {
+                let bwd_fn = self.dev.get_func($Mod, $Bwd).unwrap();
+                let numel = grad_out.len();
+
+                let inp_dims = self.dev.htod_copy(inp.shape.concrete().into())?;
+                let idx_dims = self.dev.htod_copy(idx.shape.concrete().into())?;
+                let inp_strides = self.dev.htod_copy(inp.strides.into())?;
+                let idx_strides = self.dev.htod_copy(idx.strides.into())?;
+
+                let cfg = LaunchConfig::for_num_elems(numel as u32);
+                let params = (
+                    numel,             // const size_t numel,
+                    grad_inp,          // float *grad_inp,
+                    Src::NUM_DIMS,     // const size_t num_dims,
+                    ax,                // const size_t ax,
+                    &inp_dims,         // const size_t *inp_dims,
+                    &inp_strides,      // const size_t *inp_strides,
+                    idx.data.as_ref(), // const size_t *idx,
+                    &idx_dims,         // const size_t *idx_dims,
+                    &idx_strides,      // const size_t *idx_strides,
+                    grad_out,          // const float *grad_out,
+                );
+                unsafe { bwd_fn.launch(cfg, params) }?;
+                Ok(())
+            }
+        }
+    };
+}
+
+impl_cuda_kernels!(
+    f32,
+    "take_along_f32",
+    "take_along_fwd_f32",
+    "take_along_bwd_f32"
+);
+impl_cuda_kernels!(
+    f64,
+    "take_along_f64",
+    "take_along_fwd_f64",
+    "take_along_bwd_f64"
+);
@@ -0,0 +1,193 @@
+#![allow(clippy::type_complexity)]
+
+mod cpu_kernel;
+
+#[cfg(feature = "cuda")]
+mod cuda_kernel;
+
+use crate::{gradients::Tape, shapes::*, tensor::*};
+
+pub trait TakeAlongKernel<E: Dtype>: DeviceStorage {
+    fn forward<Src: Shape, Idx: Shape<Concrete = Src::Concrete>>(
+        &self,
+        ax: usize,
+        inp: &Tensor<Src, E, Self>,
+        idx: &Tensor<Idx, usize, Self>,
+    ) -> Result<Tensor<Idx, E, Self>, Self::Err>;
+    fn backward<Src: Shape, Idx: Shape<Concrete = Src::Concrete>>(
+        &self,
+        ax: usize,
+        inp: &Tensor<Src, E, Self>,
+        grad_inp: &mut Self::Vec<E>,
+        idx: &Tensor<Idx, usize, Self>,
+        out: &Tensor<Idx, E, Self>,
+        grad_out: &Self::Vec<E>,
+    ) -> Result<(), Self::Err>;
+}
+
+/// Pick values along a single axis using an index tensor of the same rank,
+/// where `out[i][j][k] = inp[i][idx[i][j][k]][k]` when taking along axis 1.
+///
+/// The index has the same shape as the input, except along the taken axis,
+/// where it can be any size. The output has the same shape as the index.
+///
+/// **Pytorch equivalent**: `torch.gather(inp, dim, idx)`
+pub trait TakeAlongAxis<D: DeviceStorage>: HasErr + HasShape {
+    /// Take values along axis `Ax`:
+    /// ```rust
+    /// # use dfdx::prelude::*;
+    /// # let dev: Cpu = Default::default();
+    /// let a: Tensor<Rank2<2, 3>, f32, _> = dev.tensor([[1.0, 2.0, 3.0], [4.0, 5.0, 6.0]]);
+    ///
+    /// let idx: Tensor<Rank2<2, 2>, usize, _> = dev.tensor([[2, 0], [1, 1]]);
+    /// let r = a.clone().take_along_axis::<Axis<1>, _>(idx);
+    /// assert_eq!(r.array(), [[3.0, 1.0], [5.0, 5.0]]);
+    ///
+    /// let idx: Tensor<Rank2<1, 3>, usize, _> = dev.tensor([[1, 0, 1]]);
+    /// let r = a.take_along_axis::<Axis<0>, _>(idx);
+    /// assert_eq!(r.array(), [[4.0, 2.0, 6.0]]);
+    /// ```
+    fn take_along_axis<Ax: Axes<Array = [isize; 1]>, Idx>(
+        self,
+        idx: Tensor<Idx, usize, D>,
+    ) -> Self::WithShape<Idx>
+    where
+        Self::Shape: HasAxes<Ax>,
+        Idx: Shape<Concrete = <Self::Shape as Shape>::Concrete>,
+    {
+        self.try_take_along_axis::<Ax, Idx>(idx).unwrap()
+    }
+
+    /// Fallible version of [TakeAlongAxis::take_along_axis]
+    fn try_take_along_axis<Ax: Axes<Array = [isize; 1]>, Idx>(
+        self,
+        idx: Tensor<Idx, usize, D>,
+    ) -> Result<Self::WithShape<Idx>, Self::Err>
+    where
+        Self::Shape: HasAxes<Ax>,
+        Idx: Shape<Concrete = <Self::Shape as Shape>::Concrete>;
+}
+
+impl<Src: Shape, E: Dtype, D: TakeAlongKernel<E>, T: Tape<E, D>> TakeAlongAxis<D>
+    for Tensor<Src, E, D, T>
+{
+    fn try_take_along_axis<Ax: Axes<Array = [isize; 1]>, Idx>(
+        self,
+        idx: Tensor<Idx, usize, D>,
+    ) -> Result<Self::WithShape<Idx>, Self::Err>
+    where
+        Src: HasAxes<Ax>,
+        Idx: Shape<Concrete = Src::Concrete>,
+    {
+        let ax = Ax::as_array()[0] as usize;
+        let inp_dims = self.shape().concrete();
+        let idx_dims = idx.shape().concrete();
+        for i in 0..Src::NUM_DIMS {
+            if i != ax {
+                assert_eq!(
+                    inp_dims[i], idx_dims[i],
+                    "dimension {i} not the same between input and index"
+                );
+            }
+        }
+
+        let (inp, mut tape) = self.split_tape();
+        let out = inp.device.forward(ax, &inp, &idx)?;
+        let phantom_out = out.clone();
+        tape.try_alloc_grad(&inp)?;
+        tape.try_alloc_grad(&out)?;
+        tape.add_reusable_backward_op(move |grads| {
+            let (grad_inp, grad_out) = grads.mut_and_ref(&inp, &phantom_out);
+            inp.device
+                .backward(ax, &inp, grad_inp, &idx, &phantom_out, grad_out)
+        });
+        Ok(out.put_tape(tape))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::tensor_ops::*;
+    use crate::tests::*;
+
+    #[test]
+    fn test_take_along_argmax() {
+        let dev: TestDevice = Default::default();
+        let t: Tensor<_, TestDtype, _> = dev.tensor([
+            [1.0, 5.0, 3.0, -1.0],
+            [7.0, 0.5, 2.0, 3.0],
+            [0.0, 1.0, 2.0, 9.0],
+        ]);
+
+        // argmax of each row
+        let idx: Tensor<Rank2<3, 1>, usize, _> = dev.tensor([[1], [0], [3]]);
+        let r = t.trace().take_along_axis::<Axis<1>, _>(idx);
+        assert_eq!(r.array(), [[5.0], [7.0], [9.0]]);
+        assert_eq!(r.array(), t.clone().max_keepdim::<Axis<1>>().array());
+
+        let g = (r * dev.tensor([[1.0], [2.0], [3.0]])).sum().backward();
+        assert_eq!(
+            g.get(&t).array(),
+            [
+                [0.0, 1.0, 0.0, 0.0],
+                [2.0, 0.0, 0.0, 0.0],
+                [0.0, 0.0, 0.0, 3.0]
+            ]
+        );
+    }
+
+    #[test]
+    fn test_take_along_repeated_indices() {
+        let dev: TestDevice = Default::default();
+        let t: Tensor<_, TestDtype, _> = dev.tensor([[1.0, 2.0, 3.0], [4.0, 5.0, 6.0]]);
+        let idx: Tensor<Rank2<3, 3>, usize, _> = dev.tensor([[0, 1, 0], [0, 1, 1], [1, 1, 0]]);
+        let r = t.trace().take_along_axis::<Axis<0>, _>(idx);
+        assert_eq!(
+            r.array(),
+            [[1.0, 5.0, 3.0], [1.0, 5.0, 6.0], [4.0, 5.0, 3.0]]
+        );
+        let g = r.exp().sum().backward();
+        let e = t.array().map(|row| row.map(TestDtype::exp));
+        assert_close(
+            &g.get(&t).array(),
+            &[
+                [2.0 * e[0][0], 0.0, 2.0 * e[0][2]],
+                [e[1][0], 3.0 * e[1][1], e[1][2]],
+            ],
+        );
+    }
+
+    #[test]
+    fn test_take_along_3d() {
+        let dev: TestDevice = Default::default();
+        let t: Tensor<Rank3<2, 3, 2>, TestDtype, _> = dev.sample_normal();
+        let idx: Tensor<Rank3<2, 3, 1>, usize, _> = dev.tensor([[[1], [0], [1]], [[0], [0], [1]]]);
+        let r = t.trace().take_along_axis::<Axis<2>, _>(idx);
+        let t_arr = t.array();
+        assert_eq!(
+            r.array(),
+            [
+                [[t_arr[0][0][1]], [t_arr[0][1][0]], [t_arr[0][2][1]]],
+                [[t_arr[1][0][0]], [t_arr[1][1][0]], [t_arr[1][2][1]]],
+            ]
+        );
+        let g = r.sum().backward();
+        assert_eq!(
+            g.get(&t).array(),
+            [
+                [[0.0, 1.0], [1.0, 0.0], [0.0, 1.0]],
+                [[1.0, 0.0], [1.0, 0.0], [0.0, 1.0]],
+            ]
+        );
+    }
+
+    #[test]
+    #[should_panic = "dimension 0 not the same"]
+    fn test_take_along_wrong_index_shape() {
+        let dev: TestDevice = Default::default();
+        let t: Tensor<Rank2<2, 3>, TestDtype, _> = dev.zeros();
+        let idx: Tensor<Rank2<3, 1>, usize, _> = dev.zeros();
+        let _ = t.take_along_axis::<Axis<1>, _>(idx);
+    }
+}
@@ -0,0 +1,113 @@
+#include "cuda_utils.cuh"
+
+// converts the contiguous index `i` into `out` (which has the same shape as `idx`)
+// into the strided positions in `idx` and `inp`.
+__device__ void get_take_along_indices(
+    unsigned int i,
+    const size_t num_dims,
+    const size_t ax,
+    const size_t *inp_strides,
+    const size_t *idx,
+    const size_t *idx_dims,
+    const size_t *idx_strides,
+    unsigned int *idx_i,
+    unsigned int *inp_i
+) {
+    unsigned int i_idx = 0;
+    unsigned int i_inp = 0;
+    for (unsigned int d = 0; d < num_dims; d++) {
+        unsigned int dim_idx = num_dims - 1 - d;
+        unsigned int j = i % idx_dims[dim_idx];
+        i_idx += j * idx_strides[dim_idx];
+        if (dim_idx != ax) {
+            i_inp += j * inp_strides[dim_idx];
+        }
+        i /= idx_dims[dim_idx];
+    }
+    *idx_i = i_idx;
+    *inp_i = i_inp + idx[i_idx] * inp_strides[ax];
+}
+
+template<typename T>
+__device__ void take_along_fwd(
+    const size_t numel,
+    const T *inp,
+    const size_t num_dims,
+    const size_t ax,
+    const size_t *inp_dims,
+    const size_t *inp_strides,
+    const size_t *idx,
+    const size_t *idx_dims,
+    const size_t *idx_strides,
+    T *out
+) {
+    unsigned int i = blockIdx.x * blockDim.x + threadIdx.x;
+    if (i >= numel) {
+        return;
+    }
+
+    unsigned int idx_i;
+    unsigned int inp_i;
+    get_take_along_indices(i, num_dims, ax, inp_strides, idx, idx_dims, idx_strides, &idx_i, &inp_i);
+    assert(idx[idx_i] < inp_dims[ax]);
+
+    out[i] = inp[inp_i];
+}
+
+template<typename T>
+__device__ void take_along_bwd(
+    const size_t numel,
+    T *grad_inp,
+    const size_t num_dims,
+    const size_t ax,
+    const size_t *inp_dims,
+    const size_t *inp_strides,
+    const size_t *idx,
+    const size_t *idx_dims,
+    const size_t *idx_strides,
+    const T *grad_out
+) {
+    unsigned int i = blockIdx.x * blockDim.x + threadIdx.x;
+    if (i >= numel) {
+        return;
+    }
+
+    unsigned int idx_i;
+    unsigned int inp_i;
+    get_take_along_indices(i, num_dims, ax, inp_strides, idx, idx_dims, idx_strides, &idx_i, &inp_i);
+
+    atomicAdd(grad_inp + inp_i, grad_out[i]);
+}
+
+#define TAKE_ALONG(TYPENAME, FWD, BWD) \
+extern "C" __global__ void FWD( \
+    const size_t numel, \
+    const TYPENAME *inp, \
+    const size_t num_dims, \
+    const size_t ax, \
+    const size_t *inp_dims, \
+    const size_t *inp_strides, \
+    const size_t *idx, \
+    const size_t *idx_dims, \
+    const size_t *idx_strides, \
+    TYPENAME *out \
+) { \
+    take_along_fwd(numel, inp, num_dims, ax, inp_dims, inp_strides, idx, idx_dims, idx_strides, out); \
+} \
+extern "C" __global__ void BWD( \
+    const size_t numel, \
+    TYPENAME *grad_inp, \
+    const size_t num_dims, \
+    const size_t ax, \
+    const size_t *inp_dims, \
+    const size_t *inp_strides, \
+    const size_t *idx, \
+    const size_t *idx_dims, \
+    const size_t *idx_strides, \
+    const TYPENAME *grad_out \
+) { \
+    take_along_bwd(numel, grad_inp, num_dims, ax, inp_dims, inp_strides, idx, idx_dims, idx_strides, grad_out); \
+}
+
+TAKE_ALONG(float, take_along_fwd_f32, take_along_bwd_f32);
+TAKE_ALONG(double, take_along_fwd_f64, take_along_bwd_f64);
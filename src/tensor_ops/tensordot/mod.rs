@@ -0,0 +1,211 @@
+use crate::{
+    gradients::{Merge, Tape},
+    shapes::{Axes, Dtype, HasShape, PermuteShapeTo, Shape},
+    tensor::Tensor,
+};
+
+use super::{Device, PermuteTo, ReshapeTo, TryMatMul};
+
+/// Sums the products of `lhs` and `rhs` over the last `axes` dimensions of `lhs` and the first
+/// `axes` dimensions of `rhs`, which must match. The output shape `Dst` is the remaining
+/// dimensions of `lhs` followed by the remaining dimensions of `rhs`.
+///
+/// `axes = 1` on two matrices is matrix multiplication, and `axes = 2` on two matrices is the sum
+/// of their elementwise product. See [Tensor::tensordot_along] for contracting other axes.
+///
+/// This is computed by reshaping both tensors into matrices and using [super::matmul].
+///
+/// **Pytorch equivalent**: `torch.tensordot(lhs, rhs, dims=axes)`
+///
+/// Examples:
+/// ```rust
+/// # use dfdx::prelude::*;
+/// # let dev: Cpu = Default::default();
+/// let a: Tensor<Rank3<2, 3, 4>, f32, _> = dev.sample_normal();
+/// let b: Tensor<Rank3<3, 4, 5>, f32, _> = dev.sample_normal();
+/// let _: Tensor<Rank2<2, 5>, f32, _> = tensordot(a, b, 2);
+///
+/// let x: Tensor<Rank2<2, 3>, f32, _> = dev.sample_normal();
+/// let y: Tensor<Rank1<4>, f32, _> = dev.sample_normal();
+/// let _: Tensor<Rank3<2, 3, 4>, f32, _> = x.tensordot(y, 0);
+/// ```
+pub fn tensordot<L: Shape, R: Shape, Dst: Shape, E: Dtype, D: Device<E>, T, RT>(
+    lhs: Tensor<L, E, D, T>,
+    rhs: Tensor<R, E, D, RT>,
+    axes: usize,
+) -> Tensor<Dst, E, D, T>
+where
+    T: Tape<E, D> + Merge<RT>,
+    RT: Tape<E, D>,
+{
+    lhs.tensordot(rhs, axes)
+}
+
+impl<L: Shape, E: Dtype, D: Device<E>, T: Tape<E, D>> Tensor<L, E, D, T> {
+    /// See [tensordot]
+    pub fn tensordot<Dst: Shape, R: Shape, RT: Tape<E, D>>(
+        self,
+        rhs: Tensor<R, E, D, RT>,
+        axes: usize,
+    ) -> Tensor<Dst, E, D, T>
+    where
+        T: Merge<RT>,
+    {
+        self.try_tensordot(rhs, axes).unwrap()
+    }
+
+    /// See [tensordot]
+    pub fn try_tensordot<Dst: Shape, R: Shape, RT: Tape<E, D>>(
+        self,
+        rhs: Tensor<R, E, D, RT>,
+        axes: usize,
+    ) -> Result<Tensor<Dst, E, D, T>, D::Err>
+    where
+        T: Merge<RT>,
+    {
+        assert!(
+            axes <= L::NUM_DIMS && axes <= R::NUM_DIMS,
+            "Can't contract {axes} axes of tensors with {} and {} dimensions",
+            L::NUM_DIMS,
+            R::NUM_DIMS
+        );
+        let lhs_dims: std::vec::Vec<usize> = self.shape().concrete().into();
+        let rhs_dims: std::vec::Vec<usize> = rhs.shape().concrete().into();
+        let (lhs_free, lhs_contracted) = lhs_dims.split_at(L::NUM_DIMS - axes);
+        let (rhs_contracted, rhs_free) = rhs_dims.split_at(axes);
+        assert_eq!(lhs_contracted, rhs_contracted);
+
+        assert_eq!(Dst::NUM_DIMS, lhs_free.len() + rhs_free.len());
+        let mut dst: Dst::Concrete = Default::default();
+        for (i, &d) in lhs_free.iter().chain(rhs_free.iter()).enumerate() {
+            dst[i] = d;
+        }
+        let dst = Dst::from_concrete(&dst).expect("Output shape doesn't match the free dimensions");
+
+        let m = lhs_free.iter().product::<usize>();
+        let k = lhs_contracted.iter().product::<usize>();
+        let n = rhs_free.iter().product::<usize>();
+        let lhs = self.try_reshape_like(&(m, k))?;
+        let rhs = rhs.try_reshape_like(&(k, n))?;
+        lhs.try_matmul(rhs)?.try_reshape_like(&dst)
+    }
+
+    /// Permutes `self` with `LhsAx` and `rhs` with `RhsAx` before calling [tensordot], which
+    /// allows contracting any set of matching axes. The axes to contract should be moved to
+    /// the end of `self`, and to the start of `rhs`, in the same order.
+    ///
+    /// Contracting axis 0 of `a` with axis 1 of `b`:
+    /// ```rust
+    /// # use dfdx::prelude::*;
+    /// # let dev: Cpu = Default::default();
+    /// let a: Tensor<Rank2<3, 2>, f32, _> = dev.sample_normal();
+    /// let b: Tensor<Rank2<4, 3>, f32, _> = dev.sample_normal();
+    /// let _: Tensor<Rank2<2, 4>, f32, _> = a
+    ///     .tensordot_along::<Axes2<1, 0>, Axes2<1, 0>, _, _, _, _, _>(b, 1);
+    /// ```
+    pub fn tensordot_along<LhsAx: Axes, RhsAx: Axes, Dst: Shape, LP: Shape, R, RP, RT>(
+        self,
+        rhs: Tensor<R, E, D, RT>,
+        axes: usize,
+    ) -> Tensor<Dst, E, D, T>
+    where
+        L: PermuteShapeTo<LP, LhsAx>,
+        R: Shape + PermuteShapeTo<RP, RhsAx>,
+        RP: Shape,
+        RT: Tape<E, D>,
+        T: Merge<RT>,
+    {
+        self.try_tensordot_along::<LhsAx, RhsAx, Dst, LP, R, RP, RT>(rhs, axes)
+            .unwrap()
+    }
+
+    /// See [Tensor::tensordot_along]
+    pub fn try_tensordot_along<LhsAx: Axes, RhsAx: Axes, Dst: Shape, LP: Shape, R, RP, RT>(
+        self,
+        rhs: Tensor<R, E, D, RT>,
+        axes: usize,
+    ) -> Result<Tensor<Dst, E, D, T>, D::Err>
+    where
+        L: PermuteShapeTo<LP, LhsAx>,
+        R: Shape + PermuteShapeTo<RP, RhsAx>,
+        RP: Shape,
+        RT: Tape<E, D>,
+        T: Merge<RT>,
+    {
+        let lhs = self.try_permute::<LP, LhsAx>()?;
+        let rhs = rhs.try_permute::<RP, RhsAx>()?;
+        lhs.try_tensordot(rhs, axes)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{shapes::*, tensor::*, tensor_ops::*, tests::*};
+
+    #[test]
+    fn test_tensordot_one_axis_is_matmul() {
+        let dev: TestDevice = Default::default();
+        let a: Tensor<Rank2<2, 3>, TestDtype, _> = dev.sample_normal();
+        let b: Tensor<Rank2<3, 4>, TestDtype, _> = dev.sample_normal();
+
+        let r: Tensor<Rank2<2, 4>, _, _, _> = tensordot(a.trace(), b.trace(), 1);
+        let r2 = a.trace().matmul(b.trace());
+        assert_close(&r.array(), &r2.array());
+
+        let g = r.square().mean().backward();
+        let g2 = r2.square().mean().backward();
+        assert_close_with_tolerance(&g.get(&a).array(), &g2.get(&a).array(), 1e-4);
+        assert_close_with_tolerance(&g.get(&b).array(), &g2.get(&b).array(), 1e-4);
+    }
+
+    #[test]
+    fn test_tensordot_two_axes() {
+        let dev: TestDevice = Default::default();
+        let a: Tensor<_, TestDtype, _> = dev.tensor([[1.0, 2.0, 3.0], [-1.0, 0.5, 4.0]]);
+        let b: Tensor<_, TestDtype, _> = dev.tensor([[2.0, -1.0, 0.0], [1.0, 2.0, 0.25]]);
+
+        let r: Tensor<Rank0, _, _, _> = a.trace().tensordot(b.trace(), 2);
+        assert_eq!(r.array(), (a.clone() * b.clone()).sum::<Rank0, _>().array());
+        assert_eq!(r.array(), 1.0);
+
+        let g = r.backward();
+        assert_eq!(g.get(&a).array(), b.array());
+        assert_eq!(g.get(&b).array(), a.array());
+    }
+
+    #[test]
+    fn test_tensordot_batched_contraction() {
+        let dev: TestDevice = Default::default();
+        let a: Tensor<Rank3<2, 3, 4>, TestDtype, _> = dev.sample_normal();
+        let b: Tensor<Rank3<3, 4, 5>, TestDtype, _> = dev.sample_normal();
+
+        let r: Tensor<Rank2<2, 5>, _, _, _> = a.trace().tensordot(b.trace(), 2);
+        let r2 = (a.trace().broadcast::<Rank4<2, 3, 4, 5>, _>() * b.trace().broadcast())
+            .sum::<Rank2<2, 5>, _>();
+        assert_close(&r.array(), &r2.array());
+
+        let g = r.square().mean().backward();
+        let g2 = r2.square().mean().backward();
+        assert_close_with_tolerance(&g.get(&a).array(), &g2.get(&a).array(), 1e-4);
+        assert_close_with_tolerance(&g.get(&b).array(), &g2.get(&b).array(), 1e-4);
+    }
+
+    #[test]
+    fn test_tensordot_along() {
+        let dev: TestDevice = Default::default();
+        let a: Tensor<Rank3<2, 3, 4>, TestDtype, _> = dev.sample_normal();
+        let b: Tensor<Rank2<3, 4>, TestDtype, _> = dev.sample_normal();
+
+        // contract axes (2, 1) of a with axes (1, 0) of b
+        let r: Tensor<Rank1<2>, _, _, _> = a
+            .trace()
+            .tensordot_along::<Axes3<0, 2, 1>, Axes2<1, 0>, _, _, _, _, _>(b.trace(), 2);
+        let r2 = (a.trace() * b.trace().broadcast()).sum::<Rank1<2>, _>();
+        assert_close(&r.array(), &r2.array());
+
+        let g = r.square().mean().backward();
+        let g2 = r2.square().mean().backward();
+        assert_close_with_tolerance(&g.get(&a).array(), &g2.get(&a).array(), 1e-4);
+        assert_close_with_tolerance(&g.get(&b).array(), &g2.get(&b).array(), 1e-4);
+    }
+}
@@ -0,0 +1,94 @@
+use crate::{
+    shapes::{Dim, Dtype, Shape},
+    tensor::{Cpu, Tensor, ZerosTensor},
+};
+
+/// Solves `a * x = b` for each of the `k` columns of `b`, where `a(i, j)` and `b(i, c)`
+/// read the elements of the matrices. Returns `x` as a contiguous `n` by `k` buffer.
+pub(crate) fn substitute<E: Dtype>(
+    n: usize,
+    k: usize,
+    a: impl Fn(usize, usize) -> E,
+    b: impl Fn(usize, usize) -> E,
+    upper: bool,
+) -> std::vec::Vec<E> {
+    let mut x = std::vec![E::default(); n * k];
+    for c in 0..k {
+        for step in 0..n {
+            let i = if upper { n - 1 - step } else { step };
+            let mut sum = b(i, c);
+            let others = if upper { i + 1..n } else { 0..i };
+            for j in others {
+                sum -= a(i, j) * x[j * k + c];
+            }
+            x[i * k + c] = sum / a(i, i);
+        }
+    }
+    x
+}
+
+impl<E: Dtype> super::TriangularSolveKernel<E> for Cpu {
+    fn forward<N: Dim, K: Dim>(
+        &self,
+        a: &Tensor<(N, N), E, Self>,
+        b: &Tensor<(N, K), E, Self>,
+        upper: bool,
+    ) -> Result<Tensor<(N, K), E, Self>, Self::Err> {
+        let (n, k) = (b.shape.0.size(), b.shape.1.size());
+        let [a0, a1] = a.strides;
+        let [b0, b1] = b.strides;
+        let x = substitute(
+            n,
+            k,
+            |i, j| a.data[i * a0 + j * a1],
+            |i, c| b.data[i * b0 + c * b1],
+            upper,
+        );
+        let mut out = self.try_zeros_like(&b.shape)?;
+        std::sync::Arc::make_mut(&mut out.data).copy_from_slice(&x);
+        Ok(out)
+    }
+
+    fn backward<N: Dim, K: Dim>(
+        &self,
+        a: &Tensor<(N, N), E, Self>,
+        grad_a: &mut Self::Vec<E>,
+        b: &Tensor<(N, K), E, Self>,
+        grad_b: &mut Self::Vec<E>,
+        out: &Tensor<(N, K), E, Self>,
+        grad_out: &Self::Vec<E>,
+        upper: bool,
+    ) -> Result<(), Self::Err> {
+        let (n, k) = (b.shape.0.size(), b.shape.1.size());
+        debug_assert_eq!(out.shape.strides(), out.strides);
+        let [a0, a1] = a.strides;
+        let [b0, b1] = b.strides;
+
+        // grad_b = a^-T * grad_out, and a^T is triangular on the other side
+        let gb = substitute(
+            n,
+            k,
+            |i, j| a.data[j * a0 + i * a1],
+            |i, c| grad_out[i * k + c],
+            !upper,
+        );
+        for i in 0..n {
+            for c in 0..k {
+                grad_b[i * b0 + c * b1] += gb[i * k + c];
+            }
+        }
+
+        // grad_a = -grad_b * x^T, only on the triangle that was read
+        for i in 0..n {
+            let cols = if upper { i..n } else { 0..i + 1 };
+            for j in cols {
+                let mut g = E::default();
+                for c in 0..k {
+                    g += gb[i * k + c] * out.data[j * k + c];
+                }
+                grad_a[i * a0 + j * a1] -= g;
+            }
+        }
+        Ok(())
+    }
+}
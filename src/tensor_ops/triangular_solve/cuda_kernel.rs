@@ -0,0 +1,73 @@
+use crate::{
+    shapes::{Dim, Dtype, Shape},
+    tensor::{Cpu, Cuda, CudaError, Tensor, TensorFromVec},
+};
+
+use super::TriangularSolveKernel;
+
+use std::sync::Arc;
+
+/// Copies `t` into a tensor on the host, keeping its strides.
+pub(crate) fn to_host<S: Shape, E: Dtype>(
+    dev: &Cuda,
+    t: &Tensor<S, E, Cuda>,
+) -> Result<Tensor<S, E, Cpu>, CudaError> {
+    Ok(Tensor {
+        id: t.id,
+        data: Arc::new(dev.dev.dtoh_sync_copy(t.data.as_ref())?),
+        shape: t.shape,
+        strides: t.strides,
+        device: dev.cpu.clone(),
+        param_opts: t.param_opts,
+        tape: Default::default(),
+    })
+}
+
+/// Substitution is sequential along the rows, so this runs on the host with the
+/// [Cpu] implementation and copies the results back to the device.
+impl<E: Dtype> TriangularSolveKernel<E> for Cuda
+where
+    Cpu: TriangularSolveKernel<E>,
+{
+    fn forward<N: Dim, K: Dim>(
+        &self,
+        a: &Tensor<(N, N), E, Self>,
+        b: &Tensor<(N, K), E, Self>,
+        upper: bool,
+    ) -> Result<Tensor<(N, K), E, Self>, Self::Err> {
+        let a = to_host(self, a)?;
+        let b = to_host(self, b)?;
+        let out = self.cpu.forward(&a, &b, upper)?;
+        self.try_tensor_from_vec(out.data.as_ref().clone(), out.shape)
+    }
+
+    fn backward<N: Dim, K: Dim>(
+        &self,
+        a: &Tensor<(N, N), E, Self>,
+        grad_a: &mut Self::Vec<E>,
+        b: &Tensor<(N, K), E, Self>,
+        grad_b: &mut Self::Vec<E>,
+        out: &Tensor<(N, K), E, Self>,
+        grad_out: &Self::Vec<E>,
+        upper: bool,
+    ) -> Result<(), Self::Err> {
+        let a_host = to_host(self, a)?;
+        let b_host = to_host(self, b)?;
+        let out_host = to_host(self, out)?;
+        let mut grad_a_host = self.dev.dtoh_sync_copy(grad_a)?;
+        let mut grad_b_host = self.dev.dtoh_sync_copy(grad_b)?;
+        let grad_out_host = self.dev.dtoh_sync_copy(grad_out)?;
+        self.cpu.backward(
+            &a_host,
+            &mut grad_a_host,
+            &b_host,
+            &mut grad_b_host,
+            &out_host,
+            &grad_out_host,
+            upper,
+        )?;
+        self.dev.htod_sync_copy_into(&grad_a_host, grad_a)?;
+        self.dev.htod_sync_copy_into(&grad_b_host, grad_b)?;
+        Ok(())
+    }
+}
@@ -0,0 +1,189 @@
+#![allow(clippy::type_complexity)]
+
+pub(super) mod cpu_kernel;
+
+#[cfg(feature = "cuda")]
+pub(super) mod cuda_kernel;
+
+use crate::{
+    gradients::{Merge, Tape},
+    shapes::{Dim, Dtype},
+    tensor::{DeviceStorage, PutTape, SplitTape, Tensor},
+};
+
+pub trait TriangularSolveKernel<E: Dtype>: DeviceStorage {
+    fn forward<N: Dim, K: Dim>(
+        &self,
+        a: &Tensor<(N, N), E, Self>,
+        b: &Tensor<(N, K), E, Self>,
+        upper: bool,
+    ) -> Result<Tensor<(N, K), E, Self>, Self::Err>;
+
+    #[allow(clippy::too_many_arguments)]
+    fn backward<N: Dim, K: Dim>(
+        &self,
+        a: &Tensor<(N, N), E, Self>,
+        grad_a: &mut Self::Vec<E>,
+        b: &Tensor<(N, K), E, Self>,
+        grad_b: &mut Self::Vec<E>,
+        out: &Tensor<(N, K), E, Self>,
+        grad_out: &Self::Vec<E>,
+        upper: bool,
+    ) -> Result<(), Self::Err>;
+}
+
+/// Solves `a * x = b` for `x`, where `a` is a triangular matrix. Only the upper triangle
+/// (if `upper` is true) or the lower triangle (if `upper` is false) of `a` is read,
+/// including the diagonal, which must not contain zeros.
+///
+/// Each column of `b` is solved with forward substitution (lower) or back
+/// substitution (upper).
+///
+/// The gradients are `grad_b = a^-T * grad_x` and `grad_a = -grad_b * x^T`, where
+/// only the triangle of `a` that was read receives gradients.
+///
+/// **Pytorch equivalent**: `torch.linalg.solve_triangular(a, b, upper=upper)`
+///
+/// Example:
+/// ```rust
+/// # use dfdx::prelude::*;
+/// # let dev: Cpu = Default::default();
+/// let a = dev.tensor([[2.0, 0.0], [1.0, 4.0]]);
+/// let b = dev.tensor([[2.0], [9.0]]);
+/// let x = triangular_solve(a, b, false);
+/// assert_eq!(x.array(), [[1.0], [2.0]]);
+/// ```
+pub fn triangular_solve<N: Dim, K: Dim, E: Dtype, D, T, R>(
+    a: Tensor<(N, N), E, D, T>,
+    b: Tensor<(N, K), E, D, R>,
+    upper: bool,
+) -> Tensor<(N, K), E, D, T>
+where
+    D: TriangularSolveKernel<E>,
+    T: Tape<E, D> + Merge<R>,
+    R: Default,
+{
+    a.triangular_solve(b, upper)
+}
+
+impl<N: Dim, E: Dtype, D: TriangularSolveKernel<E>, T: Tape<E, D>> Tensor<(N, N), E, D, T> {
+    /// See [triangular_solve]
+    pub fn triangular_solve<K: Dim, R: Default>(
+        self,
+        b: Tensor<(N, K), E, D, R>,
+        upper: bool,
+    ) -> Tensor<(N, K), E, D, T>
+    where
+        T: Merge<R>,
+    {
+        self.try_triangular_solve(b, upper).unwrap()
+    }
+
+    /// See [triangular_solve]
+    pub fn try_triangular_solve<K: Dim, R: Default>(
+        self,
+        b: Tensor<(N, K), E, D, R>,
+        upper: bool,
+    ) -> Result<Tensor<(N, K), E, D, T>, D::Err>
+    where
+        T: Merge<R>,
+    {
+        assert_eq!(self.shape.0, self.shape.1);
+        assert_eq!(self.shape.0, b.shape.0);
+        let (a, a_tape) = self.split_tape();
+        let (b, b_tape) = b.split_tape();
+        let mut tape = a_tape.merge(b_tape);
+        let out = a.device.forward(&a, &b, upper)?;
+        let phantom_out = out.clone();
+        tape.try_alloc_grad(&a)?;
+        tape.try_alloc_grad(&b)?;
+        tape.try_alloc_grad(&out)?;
+        tape.add_reusable_backward_op(move |grads| {
+            let (grad_a, grad_b, grad_out) = grads.muts_and_ref(&a, &b, &phantom_out);
+            a.device
+                .backward(&a, grad_a, &b, grad_b, &phantom_out, grad_out, upper)
+        });
+        Ok(out.put_tape(tape))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{shapes::*, tensor::*, tensor_ops::*, tests::*};
+
+    #[test]
+    fn test_lower_triangular_solve() {
+        let dev: TestDevice = Default::default();
+        // the upper triangle is ignored
+        let a: Tensor<_, TestDtype, _> =
+            dev.tensor([[2.0, 9.0, 9.0], [1.0, 3.0, 9.0], [-1.0, 2.0, 4.0]]);
+        let b: Tensor<_, TestDtype, _> = dev.tensor([[2.0, 4.0], [7.0, 2.0], [-1.0, 2.0]]);
+        let x = triangular_solve(a.clone(), b.clone(), false);
+        assert_close(&x.array(), &[[1.0, 2.0], [2.0, 0.0], [-1.0, 1.0]]);
+
+        let a_lower: Tensor<_, TestDtype, _> =
+            dev.tensor([[2.0, 0.0, 0.0], [1.0, 3.0, 0.0], [-1.0, 2.0, 4.0]]);
+        assert_close(&a_lower.matmul(x).array(), &b.array());
+    }
+
+    #[test]
+    fn test_upper_triangular_solve() {
+        let dev: TestDevice = Default::default();
+        let a: Tensor<_, TestDtype, _> =
+            dev.tensor([[2.0, 1.0, -1.0], [0.0, 3.0, 2.0], [0.0, 0.0, 4.0]]);
+        let b: Tensor<_, TestDtype, _> = dev.tensor([[5.0], [4.0], [-4.0]]);
+        let x = a.clone().triangular_solve(b.clone(), true);
+        assert_close(&x.array(), &[[1.0], [2.0], [-1.0]]);
+        assert_close(&a.matmul(x).array(), &b.array());
+    }
+
+    #[test]
+    fn test_triangular_solve_grads() {
+        let dev: TestDevice = Default::default();
+        let a: Tensor<Rank2<3, 3>, TestDtype, _> =
+            dev.tensor([[2.0, 0.0, 0.0], [0.5, 3.0, 0.0], [-1.0, 1.5, 4.0]]);
+        let b: Tensor<Rank2<3, 2>, TestDtype, _> =
+            dev.tensor([[1.0, -2.0], [0.5, 1.0], [2.0, 0.25]]);
+        let w: Tensor<Rank2<3, 2>, TestDtype, _> =
+            dev.tensor([[0.3, -0.7], [1.1, 0.2], [-0.4, 0.9]]);
+        let x = triangular_solve(a.trace(), b.trace(), false);
+        let g = (x * w.clone()).sum::<Rank0, _>().backward();
+        let (g_a, g_b) = (g.get(&a).array(), g.get(&b).array());
+
+        let loss = |a: Tensor<Rank2<3, 3>, TestDtype, _>, b: Tensor<Rank2<3, 2>, TestDtype, _>| {
+            (triangular_solve(a, b, false) * w.clone()).sum::<Rank0, _>()
+        };
+
+        // central finite differences
+        let eps: TestDtype = 1e-2;
+        let a_vec = a.as_vec();
+        let b_vec = b.as_vec();
+        for i in 0..3 {
+            for j in 0..3 {
+                let expected = if j > i {
+                    0.0
+                } else {
+                    let mut plus = a_vec.clone();
+                    let mut minus = a_vec.clone();
+                    plus[i * 3 + j] += eps;
+                    minus[i * 3 + j] -= eps;
+                    let plus = loss(dev.tensor_from_vec(plus, a.shape), b.clone()).array();
+                    let minus = loss(dev.tensor_from_vec(minus, a.shape), b.clone()).array();
+                    (plus - minus) / (2.0 * eps)
+                };
+                assert_close_with_tolerance(&g_a[i][j], &expected, 1e-3);
+            }
+            for j in 0..2 {
+                let mut plus = b_vec.clone();
+                let mut minus = b_vec.clone();
+                plus[i * 2 + j] += eps;
+                minus[i * 2 + j] -= eps;
+                let plus = loss(a.clone(), dev.tensor_from_vec(plus, b.shape)).array();
+                let minus = loss(a.clone(), dev.tensor_from_vec(minus, b.shape)).array();
+                let expected = (plus - minus) / (2.0 * eps);
+                assert_close_with_tolerance(&g_b[i][j], &expected, 1e-3);
+            }
+        }
+    }
+}
@@ -0,0 +1,130 @@
+use super::select_and_gather::RemoveDimKernel;
+use crate::{gradients::Tape, shapes::*, tensor::*};
+
+use std::vec::Vec;
+
+impl<S: Shape, E: Dtype, D: RemoveDimKernel<E> + TensorFromVec<usize>, T: Tape<E, D>>
+    Tensor<S, E, D, T>
+{
+    /// Splits the tensor into one tensor per index along `Ax`, removing that axis.
+    /// The inverse of [TryStack::stack] for `Axis<0>`.
+    ///
+    /// Every output carries the tape, so gradients flow back from any of them, and the
+    /// gradients of the outputs are written into their slice of the input's gradient.
+    /// The tapes are merged back together without running the backward op twice.
+    ///
+    /// **Pytorch equivalent**: `torch.unbind(t, dim=Ax)`
+    ///
+    /// Example:
+    /// ```rust
+    /// # use dfdx::prelude::*;
+    /// # let dev: Cpu = Default::default();
+    /// let a: Tensor<Rank2<2, 3>, f32, _> = dev.tensor([[1.0, 2.0, 3.0], [4.0, 5.0, 6.0]]);
+    /// let cols: Vec<Tensor<Rank1<2>, f32, _>> = a.unbind::<Axis<1>>();
+    /// assert_eq!(cols.len(), 3);
+    /// assert_eq!(cols[0].array(), [1.0, 4.0]);
+    /// assert_eq!(cols[2].array(), [3.0, 6.0]);
+    /// ```
+    pub fn unbind<Ax: Axes<Array = [isize; 1]>>(self) -> Vec<Tensor<S::Removed, E, D, T>>
+    where
+        S: RemoveAxis<Ax> + RemoveDimTo<S::Removed, S::Idx>,
+    {
+        self.try_unbind::<Ax>().unwrap()
+    }
+
+    /// Fallible version of [Tensor::unbind]
+    #[allow(clippy::type_complexity)]
+    pub fn try_unbind<Ax: Axes<Array = [isize; 1]>>(
+        self,
+    ) -> Result<Vec<Tensor<S::Removed, E, D, T>>, D::Err>
+    where
+        S: RemoveAxis<Ax> + RemoveDimTo<S::Removed, S::Idx>,
+    {
+        let ax = Ax::as_array()[0] as usize;
+        let num = self.shape.concrete()[ax];
+        let idx_shape = self.shape.idx_shape();
+        let (inp, mut tape) = self.split_tape();
+
+        let mut idxs = Vec::with_capacity(num);
+        let mut outs = Vec::with_capacity(num);
+        for i in 0..num {
+            let idx = inp
+                .device
+                .try_tensor_from_vec(std::vec![i; idx_shape.num_elements()], idx_shape)?;
+            outs.push(inp.device.forward(&inp, &idx)?);
+            idxs.push(idx);
+        }
+
+        tape.try_alloc_grad(&inp)?;
+        for out in outs.iter() {
+            tape.try_alloc_grad(out)?;
+        }
+        let phantom_outs = outs.clone();
+        tape.add_reusable_backward_op(move |grads| {
+            for (idx, out) in idxs.iter().zip(phantom_outs.iter()) {
+                let (grad_inp, grad_out) = grads.mut_and_ref(&inp, out);
+                inp.device.backward(&inp, grad_inp, idx, out, grad_out)?;
+            }
+            Ok(())
+        });
+        // the last output takes the tape, the others get a duplicate of it
+        let last = outs.pop();
+        let mut tensors = Vec::with_capacity(num);
+        for out in outs {
+            let out_tape = tape.try_duplicate(&out.device)?;
+            tensors.push(out.put_tape(out_tape));
+        }
+        tensors.extend(last.map(|out| out.put_tape(tape)));
+        Ok(tensors)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{shapes::*, tensor::*, tensor_ops::*, tests::*};
+
+    #[test]
+    fn test_unbind_stack() {
+        let dev: TestDevice = Default::default();
+        let x: Tensor<Rank3<3, 2, 4>, TestDtype, _> = dev.sample_normal();
+        let xs = x.clone().unbind::<Axis<0>>();
+        assert_eq!(xs.len(), 3);
+        let r: Tensor<(usize, Const<2>, Const<4>), TestDtype, _> = dev.stack(xs);
+        assert_eq!(r.shape(), &(3, Const, Const));
+        assert_eq!(r.as_vec(), x.as_vec());
+    }
+
+    #[test]
+    fn test_unbind_inner_axis() {
+        let dev: TestDevice = Default::default();
+        let x: Tensor<Rank3<3, 2, 4>, TestDtype, _> = dev.sample_normal();
+        let xs = x.clone().unbind::<Axis<2>>();
+        assert_eq!(xs.len(), 4);
+        let r: Tensor<(usize, Const<3>, Const<2>), TestDtype, _> = dev.stack(xs);
+        let expected = x.permute::<Rank3<4, 3, 2>, _>();
+        assert_eq!(r.as_vec(), expected.as_vec());
+    }
+
+    #[test]
+    fn test_unbind_backward() {
+        let dev: TestDevice = Default::default();
+        let x: Tensor<Rank2<2, 3>, TestDtype, _> = dev.tensor([[1.0, 2.0, 3.0], [4.0, 5.0, 6.0]]);
+        let mut cols = x.trace().unbind::<Axis<1>>().into_iter();
+        let c0 = cols.next().unwrap();
+        let _ = cols.next().unwrap();
+        let c2 = cols.next().unwrap();
+        let loss = c0.sum() + c2.square().sum();
+        let g = loss.backward();
+        assert_close(&g.get(&x).array(), &[[1.0, 0.0, 6.0], [1.0, 0.0, 12.0]]);
+    }
+
+    #[test]
+    fn test_unbind_backward_single_slice() {
+        let dev: TestDevice = Default::default();
+        let x: Tensor<Rank2<2, 3>, TestDtype, _> = dev.tensor([[1.0, 2.0, 3.0], [4.0, 5.0, 6.0]]);
+        let mut rows = x.trace().unbind::<Axis<0>>();
+        let g = rows.remove(1).exp().sum().backward();
+        let e = [4.0, 5.0, 6.0].map(TestDtype::exp);
+        assert_close(&g.get(&x).array(), &[[0.0; 3], e]);
+    }
+}
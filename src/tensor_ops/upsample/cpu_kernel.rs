@@ -0,0 +1,163 @@
+use crate::shapes::*;
+use crate::tensor::{Cpu, Tensor};
+
+use super::{Bilinear, NearestNeighbor, Upsample2DOp};
+
+use std::sync::Arc;
+
+fn make_4d<S: Shape>(strides: S::Concrete) -> [usize; 4] {
+    match S::NUM_DIMS {
+        3 => [0, strides[0], strides[1], strides[2]],
+        4 => [strides[0], strides[1], strides[2], strides[3]],
+        _ => panic!("Only implemented for 3d & 4d arrays"),
+    }
+}
+
+/// The two input indices that output index `o` is interpolated between,
+/// and the weight of the second one.
+fn bilinear_src(o: usize, n_in: usize, n_out: usize, align_corners: bool) -> (usize, usize, f64) {
+    let src = if align_corners {
+        if n_out > 1 {
+            (o * (n_in - 1)) as f64 / (n_out - 1) as f64
+        } else {
+            0.0
+        }
+    } else {
+        let scale = n_in as f64 / n_out as f64;
+        ((o as f64 + 0.5) * scale - 0.5).max(0.0)
+    };
+    let i0 = (src.floor() as usize).min(n_in - 1);
+    let i1 = (i0 + 1).min(n_in - 1);
+    (i0, i1, src - i0 as f64)
+}
+
+impl<E: Dtype> super::Upsample2DKernel<E, NearestNeighbor> for Cpu {
+    fn forward<I: Shape, O: Shape>(
+        &self,
+        op: Upsample2DOp,
+        _: NearestNeighbor,
+        inp: &Tensor<I, E, Self>,
+        out: &mut Tensor<O, E, Self>,
+    ) -> Result<(), Self::Err> {
+        let istr = make_4d::<I>(inp.strides);
+        let ostr = make_4d::<O>(out.strides);
+
+        let buf = inp.data.as_ref();
+        let out_buf = Arc::make_mut(&mut out.data);
+        for b in 0..op.batch {
+            for c in 0..op.chan {
+                for oh in 0..op.h_out {
+                    for ow in 0..op.w_out {
+                        let y = oh / op.scale;
+                        let x = ow / op.scale;
+                        let inp_idx = b * istr[0] + c * istr[1] + y * istr[2] + x * istr[3];
+                        out_buf[b * ostr[0] + c * ostr[1] + oh * ostr[2] + ow * ostr[3]] =
+                            buf[inp_idx];
+                    }
+                }
+            }
+        }
+        Ok(())
+    }
+
+    fn backward<I: Shape, O: Shape>(
+        &self,
+        op: Upsample2DOp,
+        _: NearestNeighbor,
+        inp: &Tensor<I, E, Self>,
+        grad_inp: &mut Self::Vec<E>,
+        out: &Tensor<O, E, Self>,
+        grad_out: &Self::Vec<E>,
+    ) -> Result<(), Self::Err> {
+        let istr = make_4d::<I>(inp.strides);
+        let ostr = make_4d::<O>(out.strides);
+
+        for b in 0..op.batch {
+            for c in 0..op.chan {
+                for oh in 0..op.h_out {
+                    for ow in 0..op.w_out {
+                        let y = oh / op.scale;
+                        let x = ow / op.scale;
+                        let inp_idx = b * istr[0] + c * istr[1] + y * istr[2] + x * istr[3];
+                        grad_inp[inp_idx] +=
+                            grad_out[b * ostr[0] + c * ostr[1] + oh * ostr[2] + ow * ostr[3]];
+                    }
+                }
+            }
+        }
+        Ok(())
+    }
+}
+
+impl<E: Dtype> super::Upsample2DKernel<E, Bilinear> for Cpu {
+    fn forward<I: Shape, O: Shape>(
+        &self,
+        op: Upsample2DOp,
+        mode: Bilinear,
+        inp: &Tensor<I, E, Self>,
+        out: &mut Tensor<O, E, Self>,
+    ) -> Result<(), Self::Err> {
+        let istr = make_4d::<I>(inp.strides);
+        let ostr = make_4d::<O>(out.strides);
+
+        let buf = inp.data.as_ref();
+        let out_buf = Arc::make_mut(&mut out.data);
+        for b in 0..op.batch {
+            for c in 0..op.chan {
+                let base = b * istr[0] + c * istr[1];
+                for oh in 0..op.h_out {
+                    let (y0, y1, ly) = bilinear_src(oh, op.h_in, op.h_out, mode.align_corners);
+                    let ly = E::from_f64(ly).unwrap();
+                    let hy = E::ONE - ly;
+                    for ow in 0..op.w_out {
+                        let (x0, x1, lx) = bilinear_src(ow, op.w_in, op.w_out, mode.align_corners);
+                        let lx = E::from_f64(lx).unwrap();
+                        let hx = E::ONE - lx;
+                        let v00 = buf[base + y0 * istr[2] + x0 * istr[3]];
+                        let v01 = buf[base + y0 * istr[2] + x1 * istr[3]];
+                        let v10 = buf[base + y1 * istr[2] + x0 * istr[3]];
+                        let v11 = buf[base + y1 * istr[2] + x1 * istr[3]];
+                        out_buf[b * ostr[0] + c * ostr[1] + oh * ostr[2] + ow * ostr[3]] =
+                            hy * (hx * v00 + lx * v01) + ly * (hx * v10 + lx * v11);
+                    }
+                }
+            }
+        }
+        Ok(())
+    }
+
+    fn backward<I: Shape, O: Shape>(
+        &self,
+        op: Upsample2DOp,
+        mode: Bilinear,
+        inp: &Tensor<I, E, Self>,
+        grad_inp: &mut Self::Vec<E>,
+        out: &Tensor<O, E, Self>,
+        grad_out: &Self::Vec<E>,
+    ) -> Result<(), Self::Err> {
+        let istr = make_4d::<I>(inp.strides);
+        let ostr = make_4d::<O>(out.strides);
+
+        for b in 0..op.batch {
+            for c in 0..op.chan {
+                let base = b * istr[0] + c * istr[1];
+                for oh in 0..op.h_out {
+                    let (y0, y1, ly) = bilinear_src(oh, op.h_in, op.h_out, mode.align_corners);
+                    let ly = E::from_f64(ly).unwrap();
+                    let hy = E::ONE - ly;
+                    for ow in 0..op.w_out {
+                        let (x0, x1, lx) = bilinear_src(ow, op.w_in, op.w_out, mode.align_corners);
+                        let lx = E::from_f64(lx).unwrap();
+                        let hx = E::ONE - lx;
+                        let g = grad_out[b * ostr[0] + c * ostr[1] + oh * ostr[2] + ow * ostr[3]];
+                        grad_inp[base + y0 * istr[2] + x0 * istr[3]] += hy * hx * g;
+                        grad_inp[base + y0 * istr[2] + x1 * istr[3]] += hy * lx * g;
+                        grad_inp[base + y1 * istr[2] + x0 * istr[3]] += ly * hx * g;
+                        grad_inp[base + y1 * istr[2] + x1 * istr[3]] += ly * lx * g;
+                    }
+                }
+            }
+        }
+        Ok(())
+    }
+}
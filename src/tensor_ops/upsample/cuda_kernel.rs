@@ -0,0 +1,159 @@
+use crate::{
+    shapes::*,
+    tensor::{Cuda, Tensor},
+};
+
+use super::{Bilinear, NearestNeighbor, Upsample2DOp};
+
+use std::sync::Arc;
+
+use cudarc::driver::{DeviceRepr, LaunchAsync, LaunchConfig};
+
+const PTX_SRC: &str = include_str!(concat!(env!("OUT_DIR"), "/upsample2d.ptx"));
+
+unsafe impl DeviceRepr for Upsample2DOp {}
+
+fn make_4d<S: Shape>(strides: S::Concrete) -> [usize; 4] {
+    match S::NUM_DIMS {
+        3 => [0, strides[0], strides[1], strides[2]],
+        4 => [strides[0], strides[1], strides[2], strides[3]],
+        _ => panic!("Only implemented for 3d & 4d arrays"),
+    }
+}
+
+trait HasCudaKernel<E> {
+    const MOD: &'static str;
+    const FNS: &'static [&'static str];
+}
+
+impl HasCudaKernel<f32> for Cuda {
+    const MOD: &'static str = "upsample2d_f32";
+    const FNS: &'static [&'static str] = &[
+        "upsample_nearest2d_fwd_f32",
+        "upsample_nearest2d_bwd_f32",
+        "upsample_bilinear2d_fwd_f32",
+        "upsample_bilinear2d_bwd_f32",
+    ];
+}
+
+impl HasCudaKernel<f64> for Cuda {
+    const MOD: &'static str = "upsample2d_f64";
+    const FNS: &'static [&'static str] = &[
+        "upsample_nearest2d_fwd_f64",
+        "upsample_nearest2d_bwd_f64",
+        "upsample_bilinear2d_fwd_f64",
+        "upsample_bilinear2d_bwd_f64",
+    ];
+}
+
+impl<E: Dtype> super::Upsample2DKernel<E, NearestNeighbor> for Cuda
+where
+    Self: HasCudaKernel<E>,
+{
+    fn forward<I: Shape, O: Shape>(
+        &self,
+        op: Upsample2DOp,
+        _: NearestNeighbor,
+        inp: &Tensor<I, E, Self>,
+        out: &mut Tensor<O, E, Self>,
+    ) -> Result<(), Self::Err> {
+        if !self.dev.has_func(Self::MOD, Self::FNS[0]) {
+            self.dev.load_ptx(PTX_SRC.into(), Self::MOD, Self::FNS)?;
+        }
+
+        let inp_strides = self.dev.htod_copy(make_4d::<I>(inp.strides).into())?;
+        let out_strides = self.dev.htod_copy(make_4d::<O>(out.strides).into())?;
+        let fwd_fn = self.dev.get_func(Self::MOD, Self::FNS[0]).unwrap();
+        let cfg = LaunchConfig::for_num_elems(out.shape().num_elements() as u32);
+        let params = (
+            op,                           // const Upsample2dOp op,
+            &inp_strides,                 // const size_t *inp_strides,
+            &out_strides,                 // const size_t *out_strides,
+            inp.data.as_ref(),            // const float *inp,
+            Arc::make_mut(&mut out.data), // float *out
+        );
+        unsafe { fwd_fn.launch(cfg, params) }?;
+        Ok(())
+    }
+
+    fn backward<I: Shape, O: Shape>(
+        &self,
+        op: Upsample2DOp,
+        _: NearestNeighbor,
+        inp: &Tensor<I, E, Self>,
+        grad_inp: &mut Self::Vec<E>,
+        out: &Tensor<O, E, Self>,
+        grad_out: &Self::Vec<E>,
+    ) -> Result<(), Self::Err> {
+        let inp_strides = self.dev.htod_copy(make_4d::<I>(inp.strides).into())?;
+        let out_strides = self.dev.htod_copy(make_4d::<O>(out.strides).into())?;
+        let bwd_fn = self.dev.get_func(Self::MOD, Self::FNS[1]).unwrap();
+        let cfg = LaunchConfig::for_num_elems(inp.shape().num_elements() as u32);
+        let params = (
+            op,           // const Upsample2dOp op,
+            &inp_strides, // const size_t *inp_strides,
+            &out_strides, // const size_t *out_strides,
+            grad_inp,     // float *grad_inp,
+            grad_out,     // const float *grad_out
+        );
+        unsafe { bwd_fn.launch(cfg, params) }?;
+        Ok(())
+    }
+}
+
+impl<E: Dtype> super::Upsample2DKernel<E, Bilinear> for Cuda
+where
+    Self: HasCudaKernel<E>,
+{
+    fn forward<I: Shape, O: Shape>(
+        &self,
+        op: Upsample2DOp,
+        mode: Bilinear,
+        inp: &Tensor<I, E, Self>,
+        out: &mut Tensor<O, E, Self>,
+    ) -> Result<(), Self::Err> {
+        if !self.dev.has_func(Self::MOD, Self::FNS[2]) {
+            self.dev.load_ptx(PTX_SRC.into(), Self::MOD, Self::FNS)?;
+        }
+
+        let inp_strides = self.dev.htod_copy(make_4d::<I>(inp.strides).into())?;
+        let out_strides = self.dev.htod_copy(make_4d::<O>(out.strides).into())?;
+        let fwd_fn = self.dev.get_func(Self::MOD, Self::FNS[2]).unwrap();
+        let cfg = LaunchConfig::for_num_elems(out.shape().num_elements() as u32);
+        let params = (
+            op,                           // const Upsample2dOp op,
+            mode.align_corners,           // const bool align_corners,
+            &inp_strides,                 // const size_t *inp_strides,
+            &out_strides,                 // const size_t *out_strides,
+            inp.data.as_ref(),            // const float *inp,
+            Arc::make_mut(&mut out.data), // float *out
+        );
+        unsafe { fwd_fn.launch(cfg, params) }?;
+        Ok(())
+    }
+
+    fn backward<I: Shape, O: Shape>(
+        &self,
+        op: Upsample2DOp,
+        mode: Bilinear,
+        inp: &Tensor<I, E, Self>,
+        grad_inp: &mut Self::Vec<E>,
+        out: &Tensor<O, E, Self>,
+        grad_out: &Self::Vec<E>,
+    ) -> Result<(), Self::Err> {
+        let inp_strides = self.dev.htod_copy(make_4d::<I>(inp.strides).into())?;
+        let out_strides = self.dev.htod_copy(make_4d::<O>(out.strides).into())?;
+        let bwd_fn = self.dev.get_func(Self::MOD, Self::FNS[3]).unwrap();
+        let cfg = LaunchConfig::for_num_elems(out.shape().num_elements() as u32);
+        let params = (
+            op,                 // const Upsample2dOp op,
+            mode.align_corners, // const bool align_corners,
+            &inp_strides,       // const size_t *inp_strides,
+            &out_strides,       // const size_t *out_strides,
+            grad_inp,           // float *grad_inp,
+            grad_out,           // const float *grad_out
+        );
+        unsafe { bwd_fn.launch(cfg, params) }?;
+        Ok(())
+    }
+}
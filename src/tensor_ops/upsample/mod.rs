@@ -0,0 +1,321 @@
+mod cpu_kernel;
+
+#[cfg(feature = "cuda")]
+mod cuda_kernel;
+
+use crate::{
+    gradients::Tape,
+    shapes::*,
+    tensor::{DeviceStorage, HasErr, PutTape, SplitTape, Tensor, ZerosTensor},
+};
+
+#[repr(C)]
+#[derive(Debug, Copy, Clone)]
+pub struct Upsample2DOp {
+    pub scale: usize,
+    pub batch: usize,
+    pub chan: usize,
+    pub h_in: usize,
+    pub h_out: usize,
+    pub w_in: usize,
+    pub w_out: usize,
+}
+
+impl Upsample2DOp {
+    fn new(s: usize, [b, c, h_in, w_in]: [usize; 4]) -> Self {
+        Self {
+            scale: s,
+            batch: b,
+            chan: c,
+            h_in,
+            h_out: h_in * s,
+            w_in,
+            w_out: w_in * s,
+        }
+    }
+}
+
+/// Repeats each pixel, see [TryUpsample2D::upsample_nearest2d].
+#[derive(Debug, Default, Copy, Clone)]
+pub struct NearestNeighbor;
+
+/// Interpolates between the 4 closest pixels, see [TryUpsample2D::upsample_bilinear2d].
+#[derive(Debug, Default, Copy, Clone)]
+pub struct Bilinear {
+    pub align_corners: bool,
+}
+
+pub trait Upsample2DKernel<E: Unit, Mode>: DeviceStorage {
+    fn forward<I: Shape, O: Shape>(
+        &self,
+        op: Upsample2DOp,
+        mode: Mode,
+        inp: &Tensor<I, E, Self>,
+        out: &mut Tensor<O, E, Self>,
+    ) -> Result<(), Self::Err>;
+
+    fn backward<I: Shape, O: Shape>(
+        &self,
+        op: Upsample2DOp,
+        mode: Mode,
+        inp: &Tensor<I, E, Self>,
+        grad_inp: &mut Self::Vec<E>,
+        out: &Tensor<O, E, Self>,
+        grad_out: &Self::Vec<E>,
+    ) -> Result<(), Self::Err>;
+}
+
+pub trait ConstUpsample2D<const S: usize, Mode>: HasErr {
+    type Output;
+    fn try_upsample2d(self, mode: Mode) -> Result<Self::Output, Self::Err>;
+}
+
+/// Enlarges the last two (spatial) dimensions by an integer factor `S`.
+/// Supports 3d `(C, H, W)` and 4d `(B, C, H, W)` inputs.
+pub trait TryUpsample2D {
+    /// Upsamples with nearest neighbor interpolation, which repeats each pixel
+    /// in an `S x S` block.
+    ///
+    /// **Pytorch equivalent**: `F.interpolate(x, scale_factor=S, mode="nearest")`
+    ///
+    /// ```rust
+    /// # use dfdx::prelude::*;
+    /// # let dev: Cpu = Default::default();
+    /// let a: Tensor<Rank3<1, 1, 2>, f32, _> = dev.tensor([[[1.0, 2.0]]]);
+    /// let r: Tensor<Rank3<1, 2, 4>, f32, _> = a.upsample_nearest2d::<2>();
+    /// assert_eq!(r.array(), [[[1.0, 1.0, 2.0, 2.0], [1.0, 1.0, 2.0, 2.0]]]);
+    /// ```
+    fn upsample_nearest2d<const S: usize>(self) -> Self::Output
+    where
+        Self: ConstUpsample2D<S, NearestNeighbor>,
+    {
+        self.try_upsample2d(NearestNeighbor).unwrap()
+    }
+    /// Fallible version of [TryUpsample2D::upsample_nearest2d]
+    fn try_upsample_nearest2d<const S: usize>(self) -> Result<Self::Output, Self::Err>
+    where
+        Self: ConstUpsample2D<S, NearestNeighbor>,
+    {
+        self.try_upsample2d(NearestNeighbor)
+    }
+
+    /// Upsamples with bilinear interpolation, where each output pixel is a weighted
+    /// average of the 4 closest input pixels.
+    ///
+    /// With `align_corners`, the corner pixels of the input and output line up exactly,
+    /// otherwise the input pixels are treated as squares that cover the same area as
+    /// the output (and values past the edges are clamped).
+    ///
+    /// **Pytorch equivalent**: `F.interpolate(x, scale_factor=S, mode="bilinear", align_corners=align_corners)`
+    ///
+    /// ```rust
+    /// # use dfdx::prelude::*;
+    /// # let dev: Cpu = Default::default();
+    /// let a: Tensor<Rank3<1, 1, 2>, f32, _> = dev.tensor([[[1.0, 2.0]]]);
+    /// let r: Tensor<Rank3<1, 2, 4>, f32, _> = a.upsample_bilinear2d::<2>(false);
+    /// assert_eq!(r.array(), [[[1.0, 1.25, 1.75, 2.0], [1.0, 1.25, 1.75, 2.0]]]);
+    /// ```
+    fn upsample_bilinear2d<const S: usize>(self, align_corners: bool) -> Self::Output
+    where
+        Self: ConstUpsample2D<S, Bilinear>,
+    {
+        self.try_upsample2d(Bilinear { align_corners }).unwrap()
+    }
+    /// Fallible version of [TryUpsample2D::upsample_bilinear2d]
+    fn try_upsample_bilinear2d<const S: usize>(
+        self,
+        align_corners: bool,
+    ) -> Result<Self::Output, Self::Err>
+    where
+        Self: ConstUpsample2D<S, Bilinear>,
+    {
+        self.try_upsample2d(Bilinear { align_corners })
+    }
+}
+impl<T> TryUpsample2D for T {}
+
+impl<
+        C: Dim,
+        const H: usize,
+        const W: usize,
+        E: Dtype,
+        D: Upsample2DKernel<E, Mode> + ZerosTensor<E>,
+        T: 'static + Tape<E, D>,
+        const S: usize,
+        Mode: 'static + Copy,
+    > ConstUpsample2D<S, Mode> for Tensor<(C, Const<H>, Const<W>), E, D, T>
+where
+    Const<{ H * S }>: Sized,
+    Const<{ W * S }>: Sized,
+{
+    type Output = Tensor<(C, Const<{ H * S }>, Const<{ W * S }>), E, D, T>;
+
+    fn try_upsample2d(self, mode: Mode) -> Result<Self::Output, Self::Err> {
+        let &(chan, _, _) = self.shape();
+        let op = Upsample2DOp::new(S, [1, chan.size(), H, W]);
+        let (inp, mut tape) = self.split_tape();
+        let mut out = inp
+            .device
+            .try_zeros_like(&(chan, Default::default(), Default::default()))?;
+        inp.device.forward(op, mode, &inp, &mut out)?;
+        let phantom_out = out.clone();
+        tape.try_alloc_grad(&inp)?;
+        tape.try_alloc_grad(&out)?;
+        tape.add_reusable_backward_op(move |grads| {
+            let (grad_inp, grad_out) = grads.mut_and_ref(&inp, &phantom_out);
+            inp.device
+                .backward(op, mode, &inp, grad_inp, &phantom_out, grad_out)
+        });
+        Ok(out.put_tape(tape))
+    }
+}
+
+impl<
+        B: Dim,
+        C: Dim,
+        const H: usize,
+        const W: usize,
+        E: Dtype,
+        D: Upsample2DKernel<E, Mode> + ZerosTensor<E>,
+        T: 'static + Tape<E, D>,
+        const S: usize,
+        Mode: 'static + Copy,
+    > ConstUpsample2D<S, Mode> for Tensor<(B, C, Const<H>, Const<W>), E, D, T>
+where
+    Const<{ H * S }>: Sized,
+    Const<{ W * S }>: Sized,
+{
+    type Output = Tensor<(B, C, Const<{ H * S }>, Const<{ W * S }>), E, D, T>;
+
+    fn try_upsample2d(self, mode: Mode) -> Result<Self::Output, Self::Err> {
+        let &(batch, chan, _, _) = self.shape();
+        let op = Upsample2DOp::new(S, [batch.size(), chan.size(), H, W]);
+        let (inp, mut tape) = self.split_tape();
+        let mut out =
+            inp.device
+                .try_zeros_like(&(batch, chan, Default::default(), Default::default()))?;
+        inp.device.forward(op, mode, &inp, &mut out)?;
+        let phantom_out = out.clone();
+        tape.try_alloc_grad(&inp)?;
+        tape.try_alloc_grad(&out)?;
+        tape.add_reusable_backward_op(move |grads| {
+            let (grad_inp, grad_out) = grads.mut_and_ref(&inp, &phantom_out);
+            inp.device
+                .backward(op, mode, &inp, grad_inp, &phantom_out, grad_out)
+        });
+        Ok(out.put_tape(tape))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{tensor::*, tensor_ops::*, tests::*};
+
+    #[test]
+    fn test_upsample2d_4d() {
+        let dev: TestDevice = Default::default();
+        let x: Tensor<Rank4<1, 1, 2, 2>, TestDtype, _> = dev.tensor([[[[1.0, 2.0], [3.0, 4.0]]]]);
+        let r = x.trace().upsample_nearest2d::<2>();
+        assert_eq!(
+            r.array(),
+            [[[
+                [1.0, 1.0, 2.0, 2.0],
+                [1.0, 1.0, 2.0, 2.0],
+                [3.0, 3.0, 4.0, 4.0],
+                [3.0, 3.0, 4.0, 4.0]
+            ]]]
+        );
+        let w: Tensor<Rank4<1, 1, 4, 4>, TestDtype, _> = dev.tensor([[[
+            [1.0, 2.0, 3.0, 4.0],
+            [5.0, 6.0, 7.0, 8.0],
+            [9.0, 10.0, 11.0, 12.0],
+            [13.0, 14.0, 15.0, 16.0],
+        ]]]);
+        let g = (r * w).sum().backward();
+        assert_eq!(g.get(&x).array(), [[[[14.0, 22.0], [46.0, 54.0]]]]);
+    }
+
+    #[test]
+    fn test_upsample2d_3d() {
+        let dev: TestDevice = Default::default();
+        let x: Tensor<Rank3<2, 2, 3>, TestDtype, _> = dev.sample_normal();
+        let r = x.trace().upsample_nearest2d::<3>();
+        let x_arr = x.array();
+        let r_arr = r.array();
+        for c in 0..2 {
+            for i in 0..6 {
+                for j in 0..9 {
+                    assert_eq!(r_arr[c][i][j], x_arr[c][i / 3][j / 3]);
+                }
+            }
+        }
+        let g = r.sum().backward();
+        assert_eq!(g.get(&x).array(), [[[9.0; 3]; 2]; 2]);
+    }
+
+    #[test]
+    fn test_upsample2d_broadcasted() {
+        let dev: TestDevice = Default::default();
+        let x: Tensor<Rank2<2, 3>, TestDtype, _> = dev.sample_normal();
+        let r = x
+            .trace()
+            .broadcast::<Rank3<4, 2, 3>, _>()
+            .upsample_nearest2d::<2>();
+        let g = r.sum().backward();
+        assert_eq!(g.get(&x).array(), [[16.0; 3]; 2]);
+    }
+
+    #[test]
+    fn test_upsample_bilinear2d() {
+        let dev: TestDevice = Default::default();
+        let x: Tensor<Rank4<1, 1, 2, 2>, TestDtype, _> = dev.tensor([[[[1.0, 2.0], [3.0, 4.0]]]]);
+        let r = x.clone().upsample_bilinear2d::<2>(false);
+        assert_close(
+            &r.array(),
+            &[[[
+                [1.0, 1.25, 1.75, 2.0],
+                [1.5, 1.75, 2.25, 2.5],
+                [2.5, 2.75, 3.25, 3.5],
+                [3.0, 3.25, 3.75, 4.0],
+            ]]],
+        );
+        let r = x.upsample_bilinear2d::<2>(true);
+        assert_close(
+            &r.array(),
+            &[[[
+                [1.0, 1.3333334, 1.6666666, 2.0],
+                [1.6666666, 2.0, 2.3333333, 2.6666666],
+                [2.3333333, 2.6666666, 3.0, 3.3333333],
+                [3.0, 3.3333333, 3.6666666, 4.0],
+            ]]],
+        );
+    }
+
+    #[test]
+    fn test_upsample_bilinear2d_backward() {
+        let dev: TestDevice = Default::default();
+        let x: Tensor<Rank3<2, 2, 3>, TestDtype, _> = dev.sample_normal();
+        let w: Tensor<Rank3<2, 6, 9>, TestDtype, _> = dev.sample_normal();
+        for align_corners in [false, true] {
+            let r = x.trace().upsample_bilinear2d::<3>(align_corners);
+            let g = (r * w.clone()).sum().backward();
+
+            // the op is linear, so the gradient of each input is
+            // the weighted sum of upsampling a one hot input
+            let mut expected = [[[0.0; 3]; 2]; 2];
+            for c in 0..2 {
+                for i in 0..2 {
+                    for j in 0..3 {
+                        let mut e = [[[0.0; 3]; 2]; 2];
+                        e[c][i][j] = 1.0;
+                        let e: Tensor<Rank3<2, 2, 3>, TestDtype, _> = dev.tensor(e);
+                        let u = e.upsample_bilinear2d::<3>(align_corners);
+                        expected[c][i][j] = (u * w.clone()).sum::<Rank0, _>().array();
+                    }
+                }
+            }
+            assert_close(&g.get(&x).array(), &expected);
+        }
+    }
+}
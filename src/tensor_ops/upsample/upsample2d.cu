@@ -0,0 +1,236 @@
+#include "cuda_utils.cuh"
+
+struct Upsample2dOp {
+    size_t scale;
+    size_t batch;
+    size_t chan;
+    size_t h_in;
+    size_t h_out;
+    size_t w_in;
+    size_t w_out;
+};
+
+template<typename T>
+__device__ void upsample_nearest2d_fwd(
+    const Upsample2dOp op,
+    const size_t *inp_strides,
+    const size_t *out_strides,
+    const T *inp, // 4d (Batch, Channels, Height, Width)
+    T *out // 4d (Batch, Channels, HeightOut, WidthOut)
+) {
+    unsigned int i = blockIdx.x * blockDim.x + threadIdx.x;
+    const size_t numel = op.batch * op.chan * op.h_out * op.w_out;
+    if (i >= numel) {
+        return;
+    }
+
+    unsigned int idx = i;
+    const size_t ow = idx % op.w_out;
+    idx /= op.w_out;
+    const size_t oh = idx % op.h_out;
+    idx /= op.h_out;
+    const size_t c = idx % op.chan;
+    idx /= op.chan;
+    const size_t b = idx % op.batch;
+
+    const size_t y = oh / op.scale;
+    const size_t x = ow / op.scale;
+    auto inp_i = b * inp_strides[0] + c * inp_strides[1] + y * inp_strides[2] + x * inp_strides[3];
+    out[i] = inp[inp_i];
+}
+
+template<typename T>
+__device__ void upsample_nearest2d_bwd(
+    const Upsample2dOp op,
+    const size_t *inp_strides,
+    const size_t *out_strides,
+    T *grad_inp, // 4d (Batch, Channels, Height, Width)
+    const T *grad_out // 4d (Batch, Channels, HeightOut, WidthOut)
+) {
+    unsigned int i = blockIdx.x * blockDim.x + threadIdx.x;
+    const size_t numel = op.batch * op.chan * op.h_in * op.w_in;
+    if (i >= numel) {
+        return;
+    }
+
+    unsigned int idx = i;
+    const size_t x = idx % op.w_in;
+    idx /= op.w_in;
+    const size_t y = idx % op.h_in;
+    idx /= op.h_in;
+    const size_t c = idx % op.chan;
+    idx /= op.chan;
+    const size_t b = idx % op.batch;
+
+    T tmp = 0.0;
+    for (size_t k1 = 0; k1 < op.scale; k1++) {
+        for (size_t k2 = 0; k2 < op.scale; k2++) {
+            const size_t oh = y * op.scale + k1;
+            const size_t ow = x * op.scale + k2;
+            auto out_i = b * out_strides[0] + c * out_strides[1] + oh * out_strides[2] + ow * out_strides[3];
+            tmp += grad_out[out_i];
+        }
+    }
+
+    auto inp_i = b * inp_strides[0] + c * inp_strides[1] + y * inp_strides[2] + x * inp_strides[3];
+    // broadcasted inputs have strides of 0, so several threads may write to the same element
+    atomicAdd(grad_inp + inp_i, tmp);
+}
+
+// the two input indices that output index `o` is interpolated between, and the weight of the second one
+template<typename T>
+__device__ void bilinear_src(
+    const size_t o,
+    const size_t n_in,
+    const size_t n_out,
+    const bool align_corners,
+    size_t *i0,
+    size_t *i1,
+    T *lambda
+) {
+    T src;
+    if (align_corners) {
+        src = n_out > 1 ? static_cast<T>(o * (n_in - 1)) / static_cast<T>(n_out - 1) : 0.0;
+    } else {
+        src = (static_cast<T>(o) + 0.5) * static_cast<T>(n_in) / static_cast<T>(n_out) - 0.5;
+        src = src < 0.0 ? 0.0 : src;
+    }
+    *i0 = min(static_cast<size_t>(src), n_in - 1);
+    *i1 = min(*i0 + 1, n_in - 1);
+    *lambda = src - static_cast<T>(*i0);
+}
+
+template<typename T>
+__device__ void upsample_bilinear2d_fwd(
+    const Upsample2dOp op,
+    const bool align_corners,
+    const size_t *inp_strides,
+    const size_t *out_strides,
+    const T *inp, // 4d (Batch, Channels, Height, Width)
+    T *out // 4d (Batch, Channels, HeightOut, WidthOut)
+) {
+    unsigned int i = blockIdx.x * blockDim.x + threadIdx.x;
+    const size_t numel = op.batch * op.chan * op.h_out * op.w_out;
+    if (i >= numel) {
+        return;
+    }
+
+    unsigned int idx = i;
+    const size_t ow = idx % op.w_out;
+    idx /= op.w_out;
+    const size_t oh = idx % op.h_out;
+    idx /= op.h_out;
+    const size_t c = idx % op.chan;
+    idx /= op.chan;
+    const size_t b = idx % op.batch;
+
+    size_t y0, y1, x0, x1;
+    T ly, lx;
+    bilinear_src(oh, op.h_in, op.h_out, align_corners, &y0, &y1, &ly);
+    bilinear_src(ow, op.w_in, op.w_out, align_corners, &x0, &x1, &lx);
+    const T hy = 1.0 - ly;
+    const T hx = 1.0 - lx;
+
+    const T *base = inp + b * inp_strides[0] + c * inp_strides[1];
+    const T v00 = base[y0 * inp_strides[2] + x0 * inp_strides[3]];
+    const T v01 = base[y0 * inp_strides[2] + x1 * inp_strides[3]];
+    const T v10 = base[y1 * inp_strides[2] + x0 * inp_strides[3]];
+    const T v11 = base[y1 * inp_strides[2] + x1 * inp_strides[3]];
+    out[i] = hy * (hx * v00 + lx * v01) + ly * (hx * v10 + lx * v11);
+}
+
+template<typename T>
+__device__ void upsample_bilinear2d_bwd(
+    const Upsample2dOp op,
+    const bool align_corners,
+    const size_t *inp_strides,
+    const size_t *out_strides,
+    T *grad_inp, // 4d (Batch, Channels, Height, Width)
+    const T *grad_out // 4d (Batch, Channels, HeightOut, WidthOut)
+) {
+    unsigned int i = blockIdx.x * blockDim.x + threadIdx.x;
+    const size_t numel = op.batch * op.chan * op.h_out * op.w_out;
+    if (i >= numel) {
+        return;
+    }
+
+    unsigned int idx = i;
+    const size_t ow = idx % op.w_out;
+    idx /= op.w_out;
+    const size_t oh = idx % op.h_out;
+    idx /= op.h_out;
+    const size_t c = idx % op.chan;
+    idx /= op.chan;
+    const size_t b = idx % op.batch;
+
+    size_t y0, y1, x0, x1;
+    T ly, lx;
+    bilinear_src(oh, op.h_in, op.h_out, align_corners, &y0, &y1, &ly);
+    bilinear_src(ow, op.w_in, op.w_out, align_corners, &x0, &x1, &lx);
+    const T hy = 1.0 - ly;
+    const T hx = 1.0 - lx;
+
+    const T g = grad_out[b * out_strides[0] + c * out_strides[1] + oh * out_strides[2] + ow * out_strides[3]];
+    T *base = grad_inp + b * inp_strides[0] + c * inp_strides[1];
+    // neighboring output pixels share input pixels, so these need to be atomic
+    atomicAdd(base + y0 * inp_strides[2] + x0 * inp_strides[3], hy * hx * g);
+    atomicAdd(base + y0 * inp_strides[2] + x1 * inp_strides[3], hy * lx * g);
+    atomicAdd(base + y1 * inp_strides[2] + x0 * inp_strides[3], ly * hx * g);
+    atomicAdd(base + y1 * inp_strides[2] + x1 * inp_strides[3], ly * lx * g);
+}
+
+#define UPSAMPLE_OP(TYPENAME, fwd, bwd, fwd_FN, bwd_FN) \
+extern "C" __global__ void fwd( \
+    const Upsample2dOp op, \
+    const size_t *inp_strides, \
+    const size_t *out_strides, \
+    const TYPENAME *inp, \
+    TYPENAME *out \
+) { \
+    fwd_FN(op, inp_strides, out_strides, inp, out); \
+} \
+extern "C" __global__ void bwd( \
+    const Upsample2dOp op, \
+    const size_t *inp_strides, \
+    const size_t *out_strides, \
+    TYPENAME *grad_inp, \
+    const TYPENAME *grad_out \
+) { \
+    bwd_FN(op, inp_strides, out_strides, grad_inp, grad_out); \
+}
+
+UPSAMPLE_OP(
+    float,
+    upsample_nearest2d_fwd_f32, upsample_nearest2d_bwd_f32,
+    upsample_nearest2d_fwd, upsample_nearest2d_bwd
+);
+UPSAMPLE_OP(
+    double,
+    upsample_nearest2d_fwd_f64, upsample_nearest2d_bwd_f64,
+    upsample_nearest2d_fwd, upsample_nearest2d_bwd
+);
+
+#define BILINEAR_OP(TYPENAME, fwd, bwd) \
+extern "C" __global__ void fwd( \
+    const Upsample2dOp op, \
+    const bool align_corners, \
+    const size_t *inp_strides, \
+    const size_t *out_strides, \
+    const TYPENAME *inp, \
+    TYPENAME *out \
+) { \
+    upsample_bilinear2d_fwd(op, align_corners, inp_strides, out_strides, inp, out); \
+} \
+extern "C" __global__ void bwd( \
+    const Upsample2dOp op, \
+    const bool align_corners, \
+    const size_t *inp_strides, \
+    const size_t *out_strides, \
+    TYPENAME *grad_inp, \
+    const TYPENAME *grad_out \
+) { \
+    upsample_bilinear2d_bwd(op, align_corners, inp_strides, out_strides, grad_inp, grad_out); \
+}
+
+BILINEAR_OP(float, upsample_bilinear2d_fwd_f32, upsample_bilinear2d_bwd_f32);
+BILINEAR_OP(double, upsample_bilinear2d_fwd_f64, upsample_bilinear2d_bwd_f64);
@@ -12,12 +12,96 @@ pub trait Backward<E: Dtype, D: DeviceStorage>: HasErr {
     }
     /// Fallible version of [Backward::backward]
     fn try_backward(self) -> Result<Gradients<E, D>, Self::Err>;
+
+    /// Runs backprop, adding the gradients onto the ones already in `grads`.
+    /// Useful for accumulating gradients over multiple batches.
+    ///
+    /// Unlike [Backward::backward], only the gradients of the leaves of the graph (e.g.
+    /// the parameters of a model) are kept in the result. The gradients of intermediate
+    /// tensors are dropped, both from this pass and from `grads`, so the memory used
+    /// doesn't grow with the number of accumulated batches.
+    ///
+    /// See [Gradients::zero_grad] for resetting `grads` afterwards.
+    fn backward_into(self, grads: Gradients<E, D>) -> Gradients<E, D> {
+        self.try_backward_into(grads).unwrap()
+    }
+    /// Fallible version of [Backward::backward_into]
+    fn try_backward_into(self, grads: Gradients<E, D>) -> Result<Gradients<E, D>, Self::Err>;
 }
 
 impl<E: Dtype, D: OneFillStorage<E>> Backward<E, D> for Tensor<Rank0, E, D, OwnedTape<E, D>> {
     fn try_backward(self) -> Result<Gradients<E, D>, Self::Err> {
+        self.into_loss_tape().execute()
+    }
+    fn try_backward_into(self, grads: Gradients<E, D>) -> Result<Gradients<E, D>, Self::Err> {
+        self.into_loss_tape().execute_into(grads)
+    }
+}
+
+impl<E: Dtype, D: OneFillStorage<E>> Tensor<Rank0, E, D, OwnedTape<E, D>> {
+    /// Splits off the tape, seeding the gradient of the loss with 1.
+    fn into_loss_tape(self) -> OwnedTape<E, D> {
         let (t, mut tape) = self.split_tape();
-        tape.add_backward_op(move |grads| t.device.try_fill_with_ones(grads.get_mut(&t)));
-        tape.execute()
+        tape.add_reusable_backward_op(move |grads| t.device.try_fill_with_ones(grads.get_mut(&t)));
+        tape
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{gradients::Gradients, nn::builders::*, nn::*, shapes::*, tensor::*};
+    use crate::{tensor_ops::*, tests::*};
+
+    #[test]
+    fn test_backward_into_accumulates() {
+        let dev: TestDevice = Default::default();
+        let x: Tensor<_, TestDtype, _> = dev.tensor([1.0, -2.0, 3.0]);
+
+        let grads = x.trace().square().sum().backward();
+        assert_close(&grads.get(&x).array(), &[2.0, -4.0, 6.0]);
+
+        let grads = (x.trace() * 3.0).sum().backward_into(grads);
+        assert_close(&grads.get(&x).array(), &[5.0, -1.0, 9.0]);
+    }
+
+    #[test]
+    fn test_zero_grad() {
+        let dev: TestDevice = Default::default();
+        let x: Tensor<_, TestDtype, _> = dev.tensor([1.0, -2.0, 3.0]);
+
+        let mut grads = x.trace().square().sum().backward();
+        grads = x.trace().square().sum().backward_into(grads);
+        assert_close(&grads.get(&x).array(), &[4.0, -8.0, 12.0]);
+
+        grads.zero_grad(&dev);
+        assert_eq!(grads.get(&x).array(), [0.0; 3]);
+
+        let grads = x.trace().square().sum().backward_into(grads);
+        assert_close(&grads.get(&x).array(), &[2.0, -4.0, 6.0]);
+    }
+
+    #[test]
+    fn test_backward_into_drops_intermediates() {
+        let dev: TestDevice = Default::default();
+        let model = dev.build_module::<Linear<3, 2>, TestDtype>();
+        let x: Tensor<Rank2<4, 3>, TestDtype, _> = dev.sample_normal();
+
+        let mut grads: Gradients<TestDtype, TestDevice> = Default::default();
+        for _ in 0..3 {
+            grads = model.forward(x.trace()).exp().mean().backward_into(grads);
+            // only the weight, bias, and `x` are kept
+            assert_eq!(grads.iter().count(), 3);
+        }
+
+        let g = model.forward(x.trace()).exp().mean().backward();
+        assert!(g.iter().count() > 3);
+        assert_close(
+            &grads.get(&model.weight).array(),
+            &(g.get(&model.weight) * 3.0).array(),
+        );
+        assert_close(
+            &grads.get(&model.bias).array(),
+            &(g.get(&model.bias) * 3.0).array(),
+        );
     }
 }
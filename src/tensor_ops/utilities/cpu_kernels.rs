@@ -11,6 +11,15 @@ use crate::{
 pub trait UnaryDerivative<E> {
     fn f(&self, x: &E) -> E;
     fn df(&self, x: &E) -> E;
+
+    /// Applies [UnaryDerivative::f] to every element of `buf` in place.
+    /// Ops can override this with a vectorized version, see [super::cpu_simd].
+    #[inline(always)]
+    fn f_slice(&self, buf: &mut [E]) {
+        for x in buf.iter_mut() {
+            *x = self.f(x);
+        }
+    }
 }
 
 pub trait BinaryDerivative<E> {
@@ -31,13 +40,12 @@ impl<E: Dtype, Op: UnaryDerivative<E>> UnaryKernel<Op, E> for Cpu {
             shape: inp.shape,
             strides: inp.strides,
             device: self.clone(),
+            param_opts: Default::default(),
             tape: Default::default(),
         };
         // NOTE: we can iterate over buf here because we know inp & out
         // have exact same strides due to clone.
-        for x in out.buf_iter_mut() {
-            *x = op.f(x);
-        }
+        op.f_slice(std::sync::Arc::make_mut(&mut out.data).as_mut_slice());
         Ok(out)
     }
 
@@ -55,6 +63,11 @@ impl<E: Dtype, Op: UnaryDerivative<E>> UnaryKernel<Op, E> for Cpu {
         }
         Ok(())
     }
+
+    fn forward_mut<S: Shape>(&self, op: Op, inp: &mut Tensor<S, E, Self>) -> Result<(), Self::Err> {
+        op.f_slice(std::sync::Arc::make_mut(&mut inp.data).as_mut_slice());
+        Ok(())
+    }
 }
 
 impl<E: Dtype, Op: BinaryDerivative<E>> BinaryKernel<Op, E> for Cpu {
@@ -0,0 +1,120 @@
+//! Vectorized versions of hot elementwise functions for the cpu unary kernels.
+//!
+//! With the "nightly" feature these process multiple lanes at a time with `std::simd`,
+//! and handle any leftover elements with the scalar version. Otherwise they
+//! are plain scalar loops.
+//!
+//! These operate on the physical buffer of a tensor. Since unary ops produce an output with
+//! the same strides as the input, this works for both contiguous and strided tensors.
+
+#[cfg(feature = "nightly")]
+use std::simd::{prelude::*, StdFloat};
+
+/// Elementwise functions applied in place to a whole buffer.
+pub trait FloatSlice: num_traits::Float {
+    fn relu_slice(buf: &mut [Self]);
+    fn exp_slice(buf: &mut [Self]);
+    fn sigmoid_slice(buf: &mut [Self]);
+}
+
+/// Applies `$simd` to each full group of `$Lanes` elements of `$buf`, and `$scalar` to the rest.
+macro_rules! map_lanes {
+    ($buf:expr, $F:ty, $Lanes:literal, |$sx:ident| $simd:expr, |$x:ident| $scalar:expr) => {{
+        #[cfg(feature = "nightly")]
+        let buf = {
+            let f = |$sx: Simd<$F, $Lanes>| $simd;
+            let mut chunks = $buf.chunks_exact_mut($Lanes);
+            for chunk in &mut chunks {
+                f(Simd::from_slice(chunk)).copy_to_slice(chunk);
+            }
+            chunks.into_remainder()
+        };
+        #[cfg(not(feature = "nightly"))]
+        let buf = $buf;
+        let f = |$x: $F| $scalar;
+        for x in buf.iter_mut() {
+            *x = f(*x);
+        }
+    }};
+}
+
+macro_rules! simd_float {
+    ($F:ty, $Lanes:literal) => {
+        impl FloatSlice for $F {
+            #[inline]
+            fn relu_slice(buf: &mut [Self]) {
+                map_lanes!(buf, $F, $Lanes, |x| x.simd_max(Simd::splat(0.0)), |x| x
+                    .max(0.0));
+            }
+
+            #[inline]
+            fn exp_slice(buf: &mut [Self]) {
+                map_lanes!(buf, $F, $Lanes, |x| x.exp(), |x| x.exp());
+            }
+
+            #[inline]
+            fn sigmoid_slice(buf: &mut [Self]) {
+                map_lanes!(
+                    buf,
+                    $F,
+                    $Lanes,
+                    |x| Simd::splat(1.0) / (Simd::splat(1.0) + (-x).exp()),
+                    |x| 1.0 / (1.0 + (-x).exp())
+                );
+            }
+        }
+    };
+}
+
+simd_float!(f32, 8);
+simd_float!(f64, 4);
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{shapes::*, tensor::*, tensor_ops::*, tests::*};
+
+    fn check<F: Fn(&mut [TestDtype]), G: Fn(TestDtype) -> TestDtype>(simd: F, scalar: G) {
+        // 37 is not a multiple of any lane count, so the remainder is exercised too
+        let mut inp = [0.0; 37];
+        for (i, x) in inp.iter_mut().enumerate() {
+            *x = (i as TestDtype - 18.0) * 0.37;
+        }
+        let mut out = inp;
+        simd(&mut out);
+        assert_close(&out, &inp.map(scalar));
+    }
+
+    #[test]
+    fn test_slices_match_scalar() {
+        check(TestDtype::relu_slice, |x| x.max(0.0));
+        check(TestDtype::exp_slice, |x| x.exp());
+        check(TestDtype::sigmoid_slice, |x| 1.0 / (1.0 + (-x).exp()));
+    }
+
+    #[test]
+    fn test_strided_matches_contiguous() {
+        let dev: TestDevice = Default::default();
+        let t: Tensor<Rank3<3, 5, 7>, TestDtype, _> = dev.sample_normal();
+        let p = t.clone().permute::<Rank3<7, 3, 5>, _>();
+        assert_close(
+            &p.clone().relu().array(),
+            &t.clone().relu().permute::<Rank3<7, 3, 5>, _>().array(),
+        );
+        assert_close(
+            &p.clone().exp().array(),
+            &t.clone().exp().permute::<Rank3<7, 3, 5>, _>().array(),
+        );
+        assert_close(
+            &p.sigmoid().array(),
+            &t.clone().sigmoid().permute::<Rank3<7, 3, 5>, _>().array(),
+        );
+
+        let b: Tensor<Rank2<5, 7>, TestDtype, _> = dev.sample_normal();
+        let b = b.broadcast::<Rank3<3, 5, 7>, _>();
+        let expected = b
+            .array()
+            .map(|m| m.map(|r| r.map(|x| 1.0 / (1.0 + (-x).exp()))));
+        assert_close(&b.sigmoid().array(), &expected);
+    }
+}
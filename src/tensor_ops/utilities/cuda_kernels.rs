@@ -4,7 +4,7 @@ use crate::{
     tensor_ops::ops::{BinaryKernel, UnaryKernel},
     unique_id::unique_id,
 };
-use cudarc::driver::{CudaSlice, DeviceRepr, DeviceSlice, LaunchAsync, LaunchConfig};
+use cudarc::driver::{CudaSlice, DevicePtrMut, DeviceRepr, DeviceSlice, LaunchAsync, LaunchConfig};
 use std::sync::Arc;
 
 pub trait UnaryOpCudaKernel<E> {
@@ -61,6 +61,7 @@ impl<E: Dtype, K: UnaryOpCudaKernel<E> + DeviceRepr> UnaryKernel<K, E> for Cuda
             shape: inp.shape,
             strides: inp.strides,
             device: self.clone(),
+            param_opts: Default::default(),
             tape: Default::default(),
         })
     }
@@ -79,6 +80,23 @@ impl<E: Dtype, K: UnaryOpCudaKernel<E> + DeviceRepr> UnaryKernel<K, E> for Cuda
         unsafe { bwd_fn.launch(cfg, params) }?;
         Ok(())
     }
+
+    fn forward_mut<S: Shape>(&self, op: K, inp: &mut Tensor<S, E, Self>) -> Result<(), Self::Err> {
+        if !self.dev.has_func(K::MODULE_NAME, K::FWD_FN_NAME) {
+            self.dev
+                .load_ptx(K::PTX_SRC.into(), K::MODULE_NAME, &K::ALL_FN_NAMES)?;
+        }
+
+        let numel = inp.data.len();
+        // each thread reads & writes only its own element, so `inp` and `out` can alias
+        let ptr = *Arc::make_mut(&mut inp.data).device_ptr_mut();
+
+        let fwd_fn = self.dev.get_func(K::MODULE_NAME, K::FWD_FN_NAME).unwrap();
+        let cfg = LaunchConfig::for_num_elems(numel as u32);
+        let params = (op, numel, ptr, ptr);
+        unsafe { fwd_fn.launch(cfg, params) }?;
+        Ok(())
+    }
 }
 
 pub trait BinaryOpCudaKernel<E> {
@@ -152,6 +170,7 @@ impl<E: Dtype, K: BinaryOpCudaKernel<E> + DeviceRepr> BinaryKernel<K, E> for Cud
             shape,
             strides,
             device: self.clone(),
+            param_opts: Default::default(),
             tape: Default::default(),
         })
     }
@@ -45,6 +45,8 @@ pub trait Device<E: Dtype>:
     + UnaryKernel<super::super::sub::ScalarSubKernelOp<E>, E>
     + UnaryKernel<super::super::mul::ScalarMulKernelOp<E>, E>
     + UnaryKernel<super::super::div::ScalarDivKernelOp<E>, E>
+    + UnaryKernel<super::super::maximum::ScalarMaximumKernelOp<E>, E>
+    + UnaryKernel<super::super::minimum::ScalarMinimumKernelOp<E>, E>
 
     // binary arithmetic
     + BinaryKernel<super::super::add::BinaryAddKernelOp, E>
@@ -54,6 +56,7 @@ pub trait Device<E: Dtype>:
 
     // boolean operations
     + super::super::boolean::BooleanKernel
+    + super::super::cmp::ScalarCmpKernel<super::super::cmp::NeKernelOp, E>
 
     // unary
     + UnaryKernel<super::super::abs::AbsKernelOp, E>
@@ -62,12 +65,17 @@ pub trait Device<E: Dtype>:
     + super::super::dropout::DropoutKernel<E>
     + UnaryKernel<super::super::exp::ExpKernelOp, E>
     + UnaryKernel<super::super::ln::LnKernelOp, E>
+    + UnaryKernel<super::super::log2::Log2KernelOp, E>
+    + UnaryKernel<super::super::log10::Log10KernelOp, E>
     + UnaryKernel<super::super::nans_to::NansToKernelOp<E>, E>
     + UnaryKernel<super::super::negate::NegateKernelOp, E>
     + UnaryKernel<super::super::relu::ReLUKernelOp, E>
+    + UnaryKernel<super::super::relu6::ReLU6KernelOp, E>
     + UnaryKernel<super::super::gelu::GeLUKernelOp, E>
+    + UnaryKernel<super::super::gelu::GeLUExactKernelOp, E>
     + UnaryKernel<super::super::sigmoid::SigmoidKernelOp, E>
     + UnaryKernel<super::super::sin::SinKernelOp, E>
+    + UnaryKernel<super::super::rsqrt::RSqrtKernelOp, E>
     + UnaryKernel<super::super::sqrt::SqrtKernelOp, E>
     + UnaryKernel<super::super::square::SquareKernelOp, E>
     + UnaryKernel<super::super::tanh::TanhKernelOp, E>
@@ -79,6 +87,9 @@ pub trait Device<E: Dtype>:
     + BinaryKernel<super::super::huber_error::HuberErrorKernelOp<E>, E>
     + BinaryKernel<super::super::maximum::MaximumKernelOp, E>
     + BinaryKernel<super::super::minimum::MinimumKernelOp, E>
+    + BinaryKernel<super::super::pow::BinaryPowKernelOp, E>
+    + BinaryKernel<super::super::rem::RemKernelOp, E>
+    + BinaryKernel<super::super::rem::FmodKernelOp, E>
     + crate::tensor_ops::axpy::AxpyKernel<E>
 {
 }
@@ -1,5 +1,6 @@
 mod backward;
 pub(crate) mod cpu_kernels;
+pub(crate) mod cpu_simd;
 #[cfg(feature = "cuda")]
 pub(crate) mod cuda_kernels;
 mod device;
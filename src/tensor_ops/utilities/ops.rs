@@ -1,6 +1,9 @@
+use super::super::{
+    broadcast_to::BroadcastKernel, reshape_to::ReshapeKernel, BroadcastTo, ReshapeTo,
+};
 use crate::{
     gradients::{Merge, Tape},
-    shapes::{Dtype, HasShape, Shape},
+    shapes::{Axes, BroadcastShapeTrailing, Dtype, HasShape, ReduceStridesTo, Shape},
     tensor::{DeviceStorage, PutTape, SplitTape, Tensor},
 };
 
@@ -17,6 +20,15 @@ pub trait UnaryKernel<Op, E: Dtype>: DeviceStorage {
         grad_inp: &mut Self::Vec<E>,
         grad_out: &Self::Vec<E>,
     ) -> Result<(), Self::Err>;
+
+    /// Applies the op to the storage of `inp`, which is not shared with any other tensor.
+    ///
+    /// By default this computes the result out of place and replaces the storage of `inp`.
+    fn forward_mut<S: Shape>(&self, op: Op, inp: &mut Tensor<S, E, Self>) -> Result<(), Self::Err> {
+        let out = self.forward(op, inp)?;
+        inp.data = out.data;
+        Ok(())
+    }
 }
 
 pub trait BinaryKernel<Op, E: Dtype>: DeviceStorage {
@@ -53,16 +65,39 @@ pub(crate) fn try_unary_op<
     let phantom_out = out.clone();
     tape.try_alloc_grad(&inp)?;
     tape.try_alloc_grad(&out)?;
-    tape.add_backward_op(move |grads| {
+    tape.add_reusable_backward_op(move |grads| {
         let (grad_inp, grad_out) = grads.mut_and_ref(&inp, &phantom_out);
-        inp.device.backward(op, &inp, grad_inp, grad_out)?;
+        inp.device.backward(op.clone(), &inp, grad_inp, grad_out)?;
         Ok(())
     });
     Ok(out.put_tape(tape))
 }
 
+/// Applies `op` directly to the storage of `inp` if it has no tape and its storage
+/// isn't shared with other tensors. Otherwise behaves exactly like [try_unary_op].
+pub(crate) fn try_unary_op_inplace<
+    Op: 'static + Clone,
+    S: Shape,
+    E: Dtype,
+    D: UnaryKernel<Op, E>,
+    T: Tape<E, D>,
+>(
+    op: Op,
+    inp: Tensor<S, E, D, T>,
+) -> Result<Tensor<S, E, D, T>, D::Err> {
+    if T::OWNS_TAPE {
+        return try_unary_op(op, inp);
+    }
+    let (mut inp, tape) = inp.split_tape();
+    if std::sync::Arc::get_mut(&mut inp.data).is_none() {
+        return try_unary_op(op, inp.put_tape(tape));
+    }
+    inp.device.clone().forward_mut(op, &mut inp)?;
+    Ok(inp.put_tape(tape))
+}
+
 pub(crate) fn try_binary_op<
-    Op: 'static + Copy,
+    Op: 'static + Clone,
     S: Shape,
     E: Dtype,
     D: BinaryKernel<Op, E>,
@@ -77,16 +112,50 @@ pub(crate) fn try_binary_op<
     let (lhs, ltape) = lhs.split_tape();
     let (rhs, rtape) = rhs.split_tape();
     let mut tape = ltape.merge(rtape);
-    let out = lhs.device.forward(op, &lhs, &rhs)?;
+    let out = lhs.device.forward(op.clone(), &lhs, &rhs)?;
     let phantom_out = out.clone();
     tape.try_alloc_grad(&lhs)?;
     tape.try_alloc_grad(&rhs)?;
     tape.try_alloc_grad(&out)?;
-    tape.add_backward_op(move |grads| {
+    tape.add_reusable_backward_op(move |grads| {
         let (grad_lhs, grad_rhs, grad_out) = grads.muts_and_ref(&lhs, &rhs, &phantom_out);
         lhs.device
-            .backward(op, &lhs, grad_lhs, &rhs, grad_rhs, grad_out)?;
+            .backward(op.clone(), &lhs, grad_lhs, &rhs, grad_rhs, grad_out)?;
         Ok(())
     });
     Ok(out.put_tape(tape))
 }
+
+/// [try_binary_op] where `rhs` is first broadcasted to the shape of `lhs` along `Ax`,
+/// which are the leading axes `rhs` is missing and its `Const<1>` dimensions. The
+/// gradient of `rhs` is summed over those axes.
+pub(crate) fn try_broadcast_binary_op<
+    Op: 'static + Clone,
+    S: Shape + BroadcastShapeTrailing<Small, Ax>,
+    Small: Shape,
+    Ax: Axes,
+    E: Dtype,
+    D: BinaryKernel<Op, E> + BroadcastKernel<E> + ReshapeKernel<E>,
+    RhsTape: Tape<E, D>,
+    LhsTape: Tape<E, D> + Merge<RhsTape>,
+>(
+    op: Op,
+    lhs: Tensor<S, E, D, LhsTape>,
+    rhs: Tensor<Small, E, D, RhsTape>,
+) -> Result<Tensor<S, E, D, LhsTape>, D::Err> {
+    let squeezed = <S as ReduceStridesTo<S::Reduced, Ax>>::reduced(lhs.shape());
+    assert!(
+        rhs.shape()
+            .concrete()
+            .into_iter()
+            .filter(|&d| d != 1)
+            .eq(squeezed.concrete().into_iter().filter(|&d| d != 1)),
+        "Can't broadcast {:?} to {:?}",
+        rhs.shape().concrete(),
+        lhs.shape().concrete(),
+    );
+    let rhs = rhs
+        .try_reshape_like(&squeezed)?
+        .try_broadcast_like::<S, Ax>(lhs.shape())?;
+    try_binary_op(op, lhs, rhs)
+}
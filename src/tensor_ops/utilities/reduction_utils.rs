@@ -1,5 +1,5 @@
-use crate::shapes::{Axes, Shape};
-use crate::tensor::cpu::NdIndex;
+use crate::shapes::{Axes, HasAxes, Shape, Unit};
+use crate::tensor::{cpu::NdIndex, Cpu, Tensor};
 #[cfg(feature = "cuda")]
 use std::vec::Vec;
 
@@ -43,6 +43,74 @@ pub(crate) fn index_for_reductions<S: Shape, Ax: Axes>(
     }
 }
 
+/// Number of elements each task processes when reductions are split across threads.
+#[cfg(feature = "rayon")]
+const PAR_CHUNK_LEN: usize = 4096;
+
+/// Reduces every element of `buf` into a single value using `f`, starting from `init`.
+///
+/// With the `rayon` feature `buf` is split into fixed size chunks that are reduced in
+/// parallel, and then the partial results are combined in chunk order. This keeps float
+/// sums deterministic regardless of how many threads are used.
+pub(crate) fn reduce_buf<E: Unit, F: Fn(E, E) -> E + Sync>(buf: &[E], init: E, f: F) -> E {
+    #[cfg(feature = "rayon")]
+    {
+        use rayon::prelude::*;
+        let partials: std::vec::Vec<E> = buf
+            .par_chunks(PAR_CHUNK_LEN)
+            .map(|chunk| chunk.iter().fold(init, |acc, &x| f(acc, x)))
+            .collect();
+        partials.into_iter().fold(init, &f)
+    }
+
+    #[cfg(not(feature = "rayon"))]
+    {
+        buf.iter().fold(init, |acc, &x| f(acc, x))
+    }
+}
+
+/// Reduces all the elements of `inp` along `Ax` into `out` using `f`, starting from `init`.
+///
+/// `out` must be the contiguous buffer of the reduced shape. Each element of `out` is
+/// always reduced sequentially in the same order. With the `rayon` feature, groups
+/// of output elements are processed in parallel.
+pub(crate) fn reduce_into<Src: Shape + HasAxes<Ax>, Ax: Axes, E: Unit, F>(
+    out: &mut [E],
+    inp: &Tensor<Src, E, Cpu>,
+    init: E,
+    f: F,
+) where
+    F: Fn(E, E) -> E + Sync,
+{
+    let num_elems_reduced = <Src as HasAxes<Ax>>::size(&inp.shape);
+    let inp_buf = inp.data.as_ref();
+    let reduce_chunk = |start: usize, chunk: &mut [E]| {
+        let mut idx = index_for_reductions::<Src, Ax>(inp.shape, inp.strides);
+        idx.seek(start * num_elems_reduced);
+        for o in chunk.iter_mut() {
+            let mut tmp = init;
+            for _ in 0..num_elems_reduced {
+                tmp = f(tmp, inp_buf[idx.next().unwrap()]);
+            }
+            *o = tmp;
+        }
+    };
+
+    #[cfg(feature = "rayon")]
+    {
+        use rayon::prelude::*;
+        let chunk_len = (PAR_CHUNK_LEN / num_elems_reduced.max(1)).max(1);
+        out.par_chunks_mut(chunk_len)
+            .enumerate()
+            .for_each(|(i, chunk)| reduce_chunk(i * chunk_len, chunk));
+    }
+
+    #[cfg(not(feature = "rayon"))]
+    {
+        reduce_chunk(0, out);
+    }
+}
+
 /// Moves all axes in Ax to the end of dims and strides and removes broadcasted dimensions
 /// so that a cuda kernel called for each physical element of the input tensor will place elements
 /// to be reduced with each other next to each other in memory.
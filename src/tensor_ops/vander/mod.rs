@@ -0,0 +1,83 @@
+#![allow(clippy::type_complexity)]
+
+use crate::{
+    gradients::{Merge, Tape},
+    shapes::{Axes2, Dim, Dtype},
+    tensor::{PutTape, SplitTape, Tensor},
+};
+
+use super::{Device, PermuteTo, TryStack};
+
+use std::vec::Vec;
+
+/// The Vandermonde matrix of a 1d tensor `x`, where column `j` is `x^j` for `j` in `0..n`.
+///
+/// The result is differentiable with respect to `x`, which makes it useful for
+/// fitting polynomials.
+///
+/// **Pytorch equivalent**: `torch.vander(x, n, increasing=True)`
+///
+/// Example:
+/// ```rust
+/// # use dfdx::prelude::*;
+/// # let dev: Cpu = Default::default();
+/// let x = dev.tensor([1.0, 2.0, 3.0]);
+/// let r = vander(x, 3);
+/// assert_eq!(r.as_vec(), [1.0, 1.0, 1.0, 1.0, 2.0, 4.0, 1.0, 3.0, 9.0]);
+/// ```
+pub fn vander<N: Dim, E: Dtype, D: Device<E>, T: Tape<E, D> + Merge<T>>(
+    x: Tensor<(N,), E, D, T>,
+    n: usize,
+) -> Tensor<(N, usize), E, D, T> {
+    x.vander(n)
+}
+
+impl<N: Dim, E: Dtype, D: Device<E>, T: Tape<E, D> + Merge<T>> Tensor<(N,), E, D, T> {
+    /// See [vander]
+    pub fn vander(self, n: usize) -> Tensor<(N, usize), E, D, T> {
+        self.try_vander(n).unwrap()
+    }
+
+    /// See [vander]
+    pub fn try_vander(self, n: usize) -> Result<Tensor<(N, usize), E, D, T>, D::Err> {
+        assert!(n > 0);
+        let (x, tape) = self.split_tape();
+        // the column of ones has no gradient, so it carries the tape of `x` into the stack
+        let mut cols = Vec::with_capacity(n);
+        cols.push(x.device.try_ones_like(&x.shape)?.put_tape(tape));
+        for j in 1..n {
+            cols.push(x.retaped::<T>().try_powi(j as i32)?);
+        }
+        let cols: Tensor<(usize, N), E, D, T> = x.device.try_stack(cols)?;
+        cols.try_permute::<_, Axes2<1, 0>>()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{shapes::*, tensor::*, tensor_ops::*, tests::*};
+
+    #[test]
+    fn test_vander() {
+        let dev: TestDevice = Default::default();
+        let x: Tensor<Rank1<3>, TestDtype, _> = dev.tensor([-1.0, 0.5, 2.0]);
+        let r = x.trace().vander(4);
+        assert_eq!(r.shape().1, 4);
+        assert_eq!(
+            r.as_vec(),
+            [1.0, -1.0, 1.0, -1.0, 1.0, 0.5, 0.25, 0.125, 1.0, 2.0, 4.0, 8.0]
+        );
+
+        // d(sum(r)) / dx = 0 + 1 + 2x + 3x^2
+        let g = r.sum::<Rank0, _>().backward();
+        assert_close(&g.get(&x).array(), &[2.0, 2.75, 17.0]);
+    }
+
+    #[test]
+    fn test_vander_single_column() {
+        let dev: TestDevice = Default::default();
+        let x: Tensor<Rank1<2>, TestDtype, _> = dev.tensor([3.0, 0.0]);
+        let r = x.vander(1);
+        assert_eq!(r.as_vec(), [1.0, 1.0]);
+    }
+}